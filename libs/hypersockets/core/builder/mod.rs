@@ -1,8 +1,13 @@
 pub mod states;
 
 use crate::client::WebSocketClient;
-use crate::config::ClientConfig;
+use crate::config::{ClientConfig, SubscriptionSet};
+use crate::connection_state::AtomicRouteMetrics;
+use crate::core::heartbeat::HeartbeatPayload;
+use crate::core::pong_tracker::PongTracker;
+use crate::core::route_sender::{OverflowPolicy, RouteSender};
 use crate::traits::*;
+use parking_lot::Mutex;
 use states::*;
 use std::collections::HashMap;
 use std::marker::PhantomData;
@@ -10,6 +15,12 @@ use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use std::time::Duration;
 
+/// Default upper bound on how long `shutdown()` waits for handler queues to drain
+const DEFAULT_SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Default cap on inbound message and frame size (16 MiB)
+const DEFAULT_MAX_MESSAGE_SIZE: usize = 16 << 20;
+
 /// Type-state builder for WebSocketClient with routing
 ///
 /// This builder uses Rust's type system to enforce that required
@@ -30,7 +41,7 @@ where
     handler_builder: Option<Box<dyn std::any::Any + Send>>,
     auth: Option<Arc<dyn AuthProvider>>,
     headers: Option<Arc<dyn HeaderProvider>>,
-    heartbeat: Option<(Duration, WsMessage)>,
+    heartbeat: Option<(Duration, HeartbeatPayload)>,
     passive_ping: Option<Arc<dyn PassivePingDetector>>,
     pong_detector: Option<Arc<dyn PongDetector>>,
     pong_timeout: Option<Duration>,
@@ -39,6 +50,10 @@ where
     subscriptions: Vec<WsMessage>,
     shutdown_flag: Option<Arc<AtomicBool>>,
     halted_flag: Option<Arc<AtomicBool>>,
+    shutdown_drain_timeout: Duration,
+    max_message_size: usize,
+    backoff_reset_after: Duration,
+    start_paused: bool,
 }
 
 impl WebSocketClientBuilder<NoUrl, NoRouter, (), ()> {
@@ -62,6 +77,10 @@ impl WebSocketClientBuilder<NoUrl, NoRouter, (), ()> {
             subscriptions: Vec::new(),
             shutdown_flag: None,
             halted_flag: None,
+            shutdown_drain_timeout: DEFAULT_SHUTDOWN_DRAIN_TIMEOUT,
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+            backoff_reset_after: Duration::ZERO,
+            start_paused: false,
         }
     }
 }
@@ -96,6 +115,10 @@ where
             subscriptions: self.subscriptions,
             shutdown_flag: self.shutdown_flag,
             halted_flag: self.halted_flag,
+            shutdown_drain_timeout: self.shutdown_drain_timeout,
+            max_message_size: self.max_message_size,
+            backoff_reset_after: self.backoff_reset_after,
+            start_paused: self.start_paused,
         }
     }
 }
@@ -107,7 +130,7 @@ pub struct RoutingBuilder<R>
 where
     R: MessageRouter,
 {
-    handlers: HashMap<R::RouteKey, (crossbeam_channel::Sender<R::Message>, crossbeam_channel::Receiver<R::Message>, Box<dyn MessageHandler<R::Message>>)>,
+    handlers: HashMap<R::RouteKey, (RouteSender<R::Message>, crossbeam_channel::Receiver<R::Message>, Box<dyn MessageHandler<R::Message>>)>,
 }
 
 impl<R> RoutingBuilder<R>
@@ -126,12 +149,48 @@ where
         H: MessageHandler<R::Message>,
     {
         let (sender, receiver) = crossbeam_channel::unbounded();
+        let sender = RouteSender::unbounded(sender, Arc::new(AtomicRouteMetrics::new()));
+        self.handlers.insert(route_key, (sender, receiver, Box::new(handler)));
+        self
+    }
+
+    /// Add a handler whose channel holds at most `capacity` messages
+    ///
+    /// Messages arriving while the channel is full are dropped
+    /// (`OverflowPolicy::DropNewest`). Use `bounded_handler_with` to pick
+    /// another policy.
+    pub fn bounded_handler<H>(self, route_key: R::RouteKey, capacity: usize, handler: H) -> Self
+    where
+        H: MessageHandler<R::Message>,
+    {
+        self.bounded_handler_with(route_key, capacity, OverflowPolicy::DropNewest, handler)
+    }
+
+    /// Add a bounded handler with an explicit overflow policy
+    ///
+    /// Drops are counted per route in `WebSocketClient::route_metrics()`.
+    /// A capacity of 0 is treated as 1.
+    pub fn bounded_handler_with<H>(
+        mut self,
+        route_key: R::RouteKey,
+        capacity: usize,
+        policy: OverflowPolicy,
+        handler: H,
+    ) -> Self
+    where
+        H: MessageHandler<R::Message>,
+    {
+        let capacity = capacity.max(1);
+        let (sender, receiver) = crossbeam_channel::bounded(capacity);
+        let metrics = Arc::new(AtomicRouteMetrics::bounded(capacity, policy));
+        let sender = RouteSender::bounded(sender, &receiver, policy, metrics);
         self.handlers.insert(route_key, (sender, receiver, Box::new(handler)));
         self
     }
 
-    fn build(self, _router: Arc<R>, shutdown_flag: Arc<std::sync::atomic::AtomicBool>) -> (HashMap<R::RouteKey, crossbeam_channel::Sender<R::Message>>, Vec<std::thread::JoinHandle<()>>, Option<Arc<std::sync::atomic::AtomicUsize>>) {
+    fn build(self, _router: Arc<R>, shutdown_flag: Arc<std::sync::atomic::AtomicBool>, in_flight: Arc<std::sync::atomic::AtomicUsize>) -> (HashMap<R::RouteKey, RouteSender<R::Message>>, HashMap<R::RouteKey, Arc<AtomicRouteMetrics>>, Vec<std::thread::JoinHandle<()>>, Option<Arc<std::sync::atomic::AtomicUsize>>) {
         let mut senders = HashMap::new();
+        let mut route_metrics = HashMap::new();
         let mut handles = Vec::new();
 
         let handler_count = self.handlers.len();
@@ -142,9 +201,13 @@ where
         };
 
         for (route_key, (sender, receiver, handler)) in self.handlers {
+            let metrics = Arc::clone(sender.metrics());
+            route_metrics.insert(route_key.clone(), Arc::clone(&metrics));
+            let slots = sender.clone();
             senders.insert(route_key.clone(), sender);
 
             let shutdown_flag = Arc::clone(&shutdown_flag);
+            let in_flight = Arc::clone(&in_flight);
             let counter = handlers_not_ready.clone();
 
             let handle = std::thread::spawn(move || {
@@ -157,12 +220,29 @@ where
                 loop {
                     match receiver.recv_timeout(std::time::Duration::from_millis(50)) {
                         Ok(message) => {
-                            if let Err(e) = handler.handle(message) {
+                            slots.release_slot();
+                            let started = std::time::Instant::now();
+                            let result = handler.handle(message);
+                            metrics.record_handled(started.elapsed(), result.is_err());
+                            if let Err(e) = result {
                                 tracing::error!("Handler error for route {:?}: {}", route_key, e);
                             }
                         }
                         Err(crossbeam_channel::RecvTimeoutError::Timeout) => {
-                            if !shutdown_flag.load(std::sync::atomic::Ordering::Acquire) {
+                            // Only exit once no received message can still be routed to us,
+                            // then drain anything that landed in between
+                            if !shutdown_flag.load(std::sync::atomic::Ordering::SeqCst)
+                                && in_flight.load(std::sync::atomic::Ordering::SeqCst) == 0
+                            {
+                                for message in receiver.try_iter() {
+                                    slots.release_slot();
+                                    let started = std::time::Instant::now();
+                                    let result = handler.handle(message);
+                                    metrics.record_handled(started.elapsed(), result.is_err());
+                                    if let Err(e) = result {
+                                        tracing::error!("Handler error for route {:?}: {}", route_key, e);
+                                    }
+                                }
                                 tracing::debug!("Shutdown flag detected, handler thread for route {:?} exiting", route_key);
                                 break;
                             }
@@ -179,7 +259,7 @@ where
             handles.push(handle);
         }
 
-        (senders, handles, handlers_not_ready)
+        (senders, route_metrics, handles, handlers_not_ready)
     }
 }
 
@@ -202,10 +282,10 @@ where
         let routing = configure_routing(routing);
 
         // Store the routing builder as a closure that can be called later
-        type HandlerBuilderFn<R> = Box<dyn FnOnce(Arc<R>, Arc<std::sync::atomic::AtomicBool>) -> (HashMap<<R as MessageRouter>::RouteKey, crossbeam_channel::Sender<<R as MessageRouter>::Message>>, Vec<std::thread::JoinHandle<()>>, Option<Arc<std::sync::atomic::AtomicUsize>>) + Send>;
+        type HandlerBuilderFn<R> = Box<dyn FnOnce(Arc<R>, Arc<std::sync::atomic::AtomicBool>, Arc<std::sync::atomic::AtomicUsize>) -> (HashMap<<R as MessageRouter>::RouteKey, RouteSender<<R as MessageRouter>::Message>>, HashMap<<R as MessageRouter>::RouteKey, Arc<AtomicRouteMetrics>>, Vec<std::thread::JoinHandle<()>>, Option<Arc<std::sync::atomic::AtomicUsize>>) + Send>;
 
-        let handler_builder: HandlerBuilderFn<NewR> = Box::new(move |router_arc: Arc<NewR>, shutdown_flag: Arc<std::sync::atomic::AtomicBool>| {
-            routing.build(router_arc, shutdown_flag)
+        let handler_builder: HandlerBuilderFn<NewR> = Box::new(move |router_arc: Arc<NewR>, shutdown_flag: Arc<std::sync::atomic::AtomicBool>, in_flight: Arc<std::sync::atomic::AtomicUsize>| {
+            routing.build(router_arc, shutdown_flag, in_flight)
         });
 
         // Box it as Any for storage
@@ -229,6 +309,10 @@ where
             subscriptions: self.subscriptions,
             shutdown_flag: self.shutdown_flag,
             halted_flag: self.halted_flag,
+            shutdown_drain_timeout: self.shutdown_drain_timeout,
+            max_message_size: self.max_message_size,
+            backoff_reset_after: self.backoff_reset_after,
+            start_paused: self.start_paused,
         }
     }
 }
@@ -249,8 +333,20 @@ where
         self
     }
 
-    pub fn heartbeat(mut self, interval: Duration, payload: WsMessage) -> Self {
-        self.heartbeat = Some((interval, payload));
+    /// Send the same `payload` every `interval`
+    pub fn heartbeat(self, interval: Duration, payload: WsMessage) -> Self {
+        self.heartbeat_with(interval, move || payload.clone())
+    }
+
+    /// Send a payload built by `payload` every `interval`
+    ///
+    /// The closure runs once per tick, so it can stamp each heartbeat with a
+    /// fresh timestamp or sequence number.
+    pub fn heartbeat_with<F>(mut self, interval: Duration, payload: F) -> Self
+    where
+        F: Fn() -> WsMessage + Send + Sync + 'static,
+    {
+        self.heartbeat = Some((interval, Arc::new(payload)));
         self
     }
 
@@ -306,6 +402,17 @@ where
         self
     }
 
+    /// Only reset the reconnection attempt counter once a connection has
+    /// stayed up for `stable_for`
+    ///
+    /// A connection that drops sooner keeps counting attempts, so a flapping
+    /// server keeps backing off instead of being retried at the base delay.
+    /// Defaults to zero: every successful connection resets the counter.
+    pub fn backoff_reset_after(mut self, stable_for: Duration) -> Self {
+        self.backoff_reset_after = stable_for;
+        self
+    }
+
     pub fn subscription(mut self, message: WsMessage) -> Self {
         self.subscriptions.push(message);
         self
@@ -316,6 +423,16 @@ where
         self
     }
 
+    /// Hold back the subscription messages until `WebSocketClient::resume()`
+    ///
+    /// The client still connects and authenticates, so startup of several
+    /// clients can be staged: build them all, then resume in the order the
+    /// feeds should start.
+    pub fn start_paused(mut self, paused: bool) -> Self {
+        self.start_paused = paused;
+        self
+    }
+
     /// Set a custom shutdown flag for coordinated shutdown across components
     ///
     /// By default, the client creates an internal shutdown flag. Use this method
@@ -379,6 +496,26 @@ where
         self.halted_flag = Some(flag);
         self
     }
+
+    /// Set the upper bound `shutdown()` waits for received messages to be handled
+    ///
+    /// On shutdown the reader stops first; messages already received are still
+    /// parsed, routed and handled until the handler queues are empty or this
+    /// timeout elapses (default 5s). Handlers still busy after that are detached.
+    pub fn shutdown_drain_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown_drain_timeout = timeout;
+        self
+    }
+
+    /// Set the largest inbound message (and frame) the client accepts, in bytes
+    ///
+    /// A larger message closes the connection with a `1009 Message Too Big`
+    /// close frame, emits `ClientEvent::MessageTooLarge` and hands over to
+    /// the reconnection strategy. Defaults to 16 MiB.
+    pub fn max_message_size(mut self, bytes: usize) -> Self {
+        self.max_message_size = bytes;
+        self
+    }
 }
 
 // Build method - only available when all required fields are set
@@ -402,36 +539,46 @@ where
             ))
         });
 
+        // Parse tasks still running; handlers wait for this to reach zero before exiting
+        let in_flight_parses = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
         // Build handlers using the closure
-        let (route_senders, handler_handles, handlers_not_ready) = if let Some(builder_any) = self.handler_builder {
+        let (route_senders, route_metrics, handler_handles, handlers_not_ready) = if let Some(builder_any) = self.handler_builder {
             // Downcast from Any back to the concrete closure type
-            type HandlerBuilderFn<R> = Box<dyn FnOnce(Arc<R>, Arc<std::sync::atomic::AtomicBool>) -> (HashMap<<R as MessageRouter>::RouteKey, crossbeam_channel::Sender<<R as MessageRouter>::Message>>, Vec<std::thread::JoinHandle<()>>, Option<Arc<std::sync::atomic::AtomicUsize>>) + Send>;
+            type HandlerBuilderFn<R> = Box<dyn FnOnce(Arc<R>, Arc<std::sync::atomic::AtomicBool>, Arc<std::sync::atomic::AtomicUsize>) -> (HashMap<<R as MessageRouter>::RouteKey, RouteSender<<R as MessageRouter>::Message>>, HashMap<<R as MessageRouter>::RouteKey, Arc<AtomicRouteMetrics>>, Vec<std::thread::JoinHandle<()>>, Option<Arc<std::sync::atomic::AtomicUsize>>) + Send>;
 
             let builder = builder_any
                 .downcast::<HandlerBuilderFn<R>>()
                 .expect("Handler builder type mismatch");
 
-            (*builder)(Arc::clone(&router), Arc::clone(&shutdown_flag))
+            (*builder)(Arc::clone(&router), Arc::clone(&shutdown_flag), Arc::clone(&in_flight_parses))
         } else {
-            (HashMap::new(), Vec::new(), None)
+            (HashMap::new(), HashMap::new(), Vec::new(), None)
         };
 
         let config = ClientConfig {
             url,
             router,
             route_senders,
+            route_metrics,
             auth: self.auth,
             headers: self.headers,
             heartbeat: self.heartbeat,
             passive_ping: self.passive_ping,
             pong_detector: self.pong_detector,
             pong_timeout: self.pong_timeout,
+            pong_tracker: self.pong_timeout.map(|timeout| Arc::new(PongTracker::new(timeout))),
             reconnect_strategy,
             reconnection_delay_offset: self.reconnection_delay_offset,
-            subscriptions: self.subscriptions,
+            subscriptions: Mutex::new(SubscriptionSet::new(self.subscriptions)),
             shutdown_flag,
             halted_flag: self.halted_flag,
             handlers_not_ready,
+            in_flight_parses,
+            shutdown_drain_timeout: self.shutdown_drain_timeout,
+            max_message_size: self.max_message_size,
+            backoff_reset_after: self.backoff_reset_after,
+            subscriptions_paused: AtomicBool::new(self.start_paused),
         };
 
         let mut client = WebSocketClient::new(config).await?;
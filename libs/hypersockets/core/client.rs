@@ -1,14 +1,19 @@
 use crate::config::ClientConfig;
-use crate::connection_state::{AtomicConnectionState, AtomicMetrics, ConnectionState};
+use crate::connection_state::{AtomicConnectionState, AtomicMetrics, ConnectionState, RouteMetrics};
 use crate::core::pong_tracker::PongTracker;
 use crossbeam_channel::{unbounded, Receiver, Sender};
 use futures::{SinkExt, StreamExt};
 use crate::traits::*;
+use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use std::time::{Duration, Instant};
+use tokio_tungstenite::{connect_async_with_config, tungstenite::Message};
 use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::error::{CapacityError, Error as TungsteniteError, ProtocolError};
 use tokio_tungstenite::tungstenite::http;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::{CloseFrame, WebSocketConfig};
 use tracing::{debug, error, info, warn};
 
 /// Internal command messages for client control
@@ -18,6 +23,13 @@ enum ClientCommand {
     Send(WsMessage),
     /// Shutdown the client
     Shutdown,
+    /// Drop the current connection and go through the reconnection strategy
+    Reconnect,
+    /// Send the subscriptions held back by `start_paused`
+    Resume,
+    /// Send a subscribe or unsubscribe message added at `epoch` of the
+    /// subscription set; skipped if a later replay already covered it
+    Subscription { message: WsMessage, epoch: u64 },
     /// Get current metrics
     GetMetrics(Sender<Metrics>),
 }
@@ -33,6 +45,30 @@ pub enum ClientEvent {
     Reconnecting(usize),
     /// Error occurred
     Error(String),
+    /// Server sent a message larger than the configured `max_message_size`;
+    /// the connection is closed and the reconnection strategy takes over
+    MessageTooLarge { size: usize, max_size: usize },
+    /// The reconnection strategy's circuit breaker tripped; the client has
+    /// stopped reconnecting
+    CircuitOpen,
+}
+
+/// Cloneable handle for sending on a client from another task or thread
+///
+/// Obtained from `WebSocketClient::sender`. Sends fail once the client
+/// has shut down.
+#[derive(Debug, Clone)]
+pub struct ClientSender {
+    command_tx: Sender<ClientCommand>,
+}
+
+impl ClientSender {
+    /// Send a message through the WebSocket
+    pub fn send(&self, message: WsMessage) -> Result<()> {
+        self.command_tx
+            .send(ClientCommand::Send(message))
+            .map_err(|e| HyperSocketError::ChannelSend(e.to_string()))
+    }
 }
 
 /// Client metrics snapshot
@@ -42,6 +78,8 @@ pub struct Metrics {
     pub messages_received: u64,
     pub reconnect_count: u64,
     pub connection_state: ConnectionState,
+    /// Messages handled per route, keyed by the route key's `Debug` output
+    pub route_messages: HashMap<String, u64>,
 }
 
 /// High-performance WebSocket client with message routing
@@ -61,8 +99,7 @@ where
     R: MessageRouter<Message = M>,
     M: Send + std::fmt::Debug + 'static,
 {
-    /// Client configuration (kept for potential future API access)
-    #[allow(dead_code)]
+    /// Client configuration
     config: Arc<ClientConfig<R, M>>,
     /// Atomic connection state
     state: Arc<AtomicConnectionState>,
@@ -135,6 +172,89 @@ where
             .map_err(|e| HyperSocketError::ChannelSend(e.to_string()))
     }
 
+    /// Handle for sending from code that cannot borrow the client
+    pub fn sender(&self) -> ClientSender {
+        ClientSender {
+            command_tx: self.command_tx.clone(),
+        }
+    }
+
+    /// Subscribe on the live connection and replay after every reconnect
+    ///
+    /// Subscribing twice with the same message is a no-op. While the
+    /// connection is down, or subscriptions are paused, the message is only
+    /// recorded and goes out with the rest once the client subscribes.
+    pub fn subscribe(&self, message: WsMessage) -> Result<()> {
+        let epoch = {
+            let mut subscriptions = self.config.subscriptions.lock();
+            if !subscriptions.insert(message.clone()) {
+                return Ok(());
+            }
+            subscriptions.epoch
+        };
+        self.command_tx
+            .send(ClientCommand::Subscription { message, epoch })
+            .map_err(|e| HyperSocketError::ChannelSend(e.to_string()))
+    }
+
+    /// Stop replaying `subscription` and send `message` to unsubscribe
+    ///
+    /// `subscription` is the message previously passed to `subscribe` or the
+    /// builder; `message` is the server's unsubscribe request. If the
+    /// connection is down, nothing is sent since the next connection won't
+    /// subscribe to it anyway.
+    pub fn unsubscribe(&self, subscription: &WsMessage, message: WsMessage) -> Result<()> {
+        let epoch = {
+            let mut subscriptions = self.config.subscriptions.lock();
+            subscriptions.remove(subscription);
+            subscriptions.epoch
+        };
+        self.command_tx
+            .send(ClientCommand::Subscription { message, epoch })
+            .map_err(|e| HyperSocketError::ChannelSend(e.to_string()))
+    }
+
+    /// Drop the current connection and reconnect
+    ///
+    /// The reconnection strategy decides the delay, as after any other
+    /// disconnect. Only takes effect while connected; a request made while
+    /// reconnecting applies to the next connection.
+    pub fn reconnect(&self) -> Result<()> {
+        self.command_tx
+            .send(ClientCommand::Reconnect)
+            .map_err(|e| HyperSocketError::ChannelSend(e.to_string()))
+    }
+
+    /// Send the subscriptions held back by `start_paused(true)`
+    ///
+    /// Later reconnects subscribe as usual. Does nothing if the client
+    /// wasn't paused.
+    pub fn resume(&self) -> Result<()> {
+        if !self.config.subscriptions_paused.swap(false, std::sync::atomic::Ordering::AcqRel) {
+            return Ok(());
+        }
+        self.command_tx
+            .send(ClientCommand::Resume)
+            .map_err(|e| HyperSocketError::ChannelSend(e.to_string()))
+    }
+
+    /// Check whether subscriptions are still held back
+    pub fn is_paused(&self) -> bool {
+        self.config.subscriptions_paused.load(std::sync::atomic::Ordering::Acquire)
+    }
+
+    /// PONG tracker, if `pong_timeout` was configured
+    ///
+    /// Exposes round-trip statistics such as `last_rtt()` and `p99_rtt(n)`.
+    pub fn pong_tracker(&self) -> Option<&Arc<PongTracker>> {
+        self.config.pong_tracker.as_ref()
+    }
+
+    /// Get the configuration this client was built with
+    pub fn config(&self) -> &ClientConfig<R, M> {
+        &self.config
+    }
+
     /// Get current connection state
     #[inline]
     pub fn connection_state(&self) -> ConnectionState {
@@ -184,6 +304,7 @@ where
                 messages_received: self.metrics.messages_received(),
                 reconnect_count: self.metrics.reconnect_count(),
                 connection_state: self.state.get(),
+                route_messages: self.config.route_message_counts(),
             })
         } else {
             Metrics {
@@ -191,10 +312,23 @@ where
                 messages_received: self.metrics.messages_received(),
                 reconnect_count: self.metrics.reconnect_count(),
                 connection_state: self.state.get(),
+                route_messages: self.config.route_message_counts(),
             }
         }
     }
 
+    /// Per-route snapshot of messages delivered, handler errors and handler latency
+    ///
+    /// Useful for spotting a slow handler whose route is falling behind:
+    /// `messages_delivered - messages_handled` is the route's queue depth.
+    pub fn route_metrics(&self) -> HashMap<R::RouteKey, RouteMetrics> {
+        self.config
+            .route_metrics
+            .iter()
+            .map(|(key, metrics)| (key.clone(), metrics.snapshot()))
+            .collect()
+    }
+
     /// Try to receive an event (non-blocking)
     pub fn try_recv_event(&self) -> Option<ClientEvent> {
         self.event_rx.try_recv().ok()
@@ -217,7 +351,21 @@ where
     }
 
     /// Shutdown the client
-    pub async fn shutdown(mut self) -> Result<()> {
+    ///
+    /// Equivalent to `shutdown_and_drain` with the configured
+    /// `shutdown_drain_timeout`.
+    pub async fn shutdown(self) -> Result<()> {
+        let timeout = self.config.shutdown_drain_timeout;
+        self.shutdown_and_drain(timeout).await.map(|_| ())
+    }
+
+    /// Shutdown the client, letting handlers finish queued messages
+    ///
+    /// Stops reading from the socket, then waits up to `timeout` for every
+    /// received message to be picked up by its handler and for the handler
+    /// threads to exit. Returns the number of messages still pending when the
+    /// timeout elapsed; those are not guaranteed to be handled.
+    pub async fn shutdown_and_drain(mut self, timeout: Duration) -> Result<usize> {
         info!("Shutting down WebSocket client");
 
         // Set shutdown flag to prevent reconnection
@@ -235,28 +383,44 @@ where
             let _ = handle.await;
         }
 
-        // Give in-flight parse tasks 100ms to complete or be discarded
-        // Parse tasks spawned before shutdown will either:
-        // 1. Complete parsing and check shutdown flag before routing (discarded)
-        // 2. Finish within this grace period
-        // This prevents blocking handler threads with late-arriving work
-        debug!("Waiting 100ms for in-flight parse tasks to complete");
-        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        // Drain phase: the reader is stopped, but messages it already received
+        // may still be parsing or queued. Wait (bounded) until every one of them
+        // has been routed and picked up by its handler.
+        let deadline = Instant::now() + timeout;
+        while !self.config.is_drained() && Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        let dropped = self.config.pending_messages();
+        if dropped > 0 {
+            warn!("Shutdown drain timeout elapsed with {} messages still queued", dropped);
+        }
 
         // Drop config to close handler channels
         // When all Arc references are dropped, route_senders are dropped
         // This closes the channels, causing handler threads to exit
         drop(self.config);
 
-        // Wait for all handler threads to finish processing remaining messages
-        // Handler threads will exit within 50ms (recv_timeout) after detecting shutdown flag
+        // Wait for handler threads to finish the messages they picked up,
+        // detaching any still busy once the drain deadline has passed
         debug!("Waiting for {} handler threads to complete", self.handler_handles.len());
+        let mut detached = 0;
         for handle in self.handler_handles {
-            let _ = handle.join();
+            while !handle.is_finished() && Instant::now() < deadline {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+            if handle.is_finished() {
+                let _ = handle.join();
+            } else {
+                detached += 1;
+            }
         }
 
-        info!("All handlers shut down successfully");
-        Ok(())
+        if detached > 0 {
+            warn!("{} handler threads still busy after drain timeout, detaching", detached);
+        } else {
+            info!("All handlers shut down successfully");
+        }
+        Ok(dropped)
     }
 }
 
@@ -298,6 +462,12 @@ async fn run_client<R, M>(
             let _ = event_tx.send(ClientEvent::Reconnecting(reconnect_attempt));
         }
 
+        let ws_config = WebSocketConfig {
+            max_message_size: Some(config.max_message_size),
+            max_frame_size: Some(config.max_message_size),
+            ..Default::default()
+        };
+
         // Build request with headers if configured
         let connection_result = if let Some(ref header_provider) = config.headers {
             // Generate headers dynamically
@@ -325,29 +495,28 @@ async fn run_client<R, M>(
                     }
 
                     debug!("Connecting with custom headers");
-                    connect_async(request).await
+                    connect_async_with_config(request, Some(ws_config), false).await
                 }
                 Err(e) => {
                     error!("Failed to create request: {}", e);
                     // Fall back to connecting without headers
-                    connect_async(&config.url).await
+                    connect_async_with_config(&config.url, Some(ws_config), false).await
                 }
             }
         } else {
             // Connect without custom headers
-            connect_async(&config.url).await
+            connect_async_with_config(&config.url, Some(ws_config), false).await
         };
 
-        match connection_result {
+        let disconnect_reason = match connection_result {
             Ok((ws_stream, _)) => {
                 info!("Connected to {}", config.url);
                 state.set(ConnectionState::Connected);
                 let _ = event_tx.send(ClientEvent::Connected);
-
-                reconnect_attempt = 0;
+                let connected_at = Instant::now();
 
                 // Handle the connection
-                if let Err(e) = handle_connection(
+                let reason = match handle_connection(
                     ws_stream,
                     Arc::clone(&config),
                     Arc::clone(&state),
@@ -357,19 +526,37 @@ async fn run_client<R, M>(
                 )
                 .await
                 {
-                    error!("Connection error: {}", e);
-                    let _ = event_tx.send(ClientEvent::Error(e.to_string()));
-                }
+                    // Only a shutdown or reconnect command ends a connection cleanly
+                    Ok(()) => DisconnectReason::Requested,
+                    Err(e) => {
+                        error!("Connection error: {}", e);
+                        let reason = classify_disconnect(&e);
+                        if let HyperSocketError::MessageTooLarge { size, max_size } = e {
+                            let _ = event_tx.send(ClientEvent::MessageTooLarge { size, max_size });
+                        }
+                        let _ = event_tx.send(ClientEvent::Error(e.to_string()));
+                        reason
+                    }
+                };
 
                 state.set(ConnectionState::Disconnected);
                 let _ = event_tx.send(ClientEvent::Disconnected);
+
+                config.reconnect_strategy.record_uptime(connected_at.elapsed());
+                reconnect_attempt = attempt_after_disconnect(
+                    reconnect_attempt,
+                    connected_at.elapsed(),
+                    config.backoff_reset_after,
+                );
+                reason
             }
             Err(e) => {
                 error!("Failed to connect: {}", e);
                 let _ = event_tx.send(ClientEvent::Error(e.to_string()));
                 state.set(ConnectionState::Disconnected);
+                classify_disconnect(&map_tungstenite_error(e))
             }
-        }
+        };
 
         // Check if we should reconnect
         if !shutdown_flag.load(std::sync::atomic::Ordering::Acquire) {
@@ -403,11 +590,15 @@ async fn run_client<R, M>(
         }
 
         // Use reconnection strategy
-        if let Some(delay) = config.reconnect_strategy.next_delay(reconnect_attempt) {
+        if let Some(delay) = config
+            .reconnect_strategy
+            .next_delay_for(reconnect_attempt, disconnect_reason)
+        {
             info!(
-                "Reconnecting in {:?} (attempt {})",
+                "Reconnecting in {:?} (attempt {}, after {:?})",
                 delay,
-                reconnect_attempt + 1
+                reconnect_attempt + 1,
+                disconnect_reason
             );
 
             // Check shutdown flag periodically during reconnection delay
@@ -427,6 +618,10 @@ async fn run_client<R, M>(
 
             reconnect_attempt += 1;
             metrics.increment_reconnects();
+        } else if config.reconnect_strategy.is_circuit_open() {
+            error!("Circuit breaker open after repeated fast disconnects, stopping");
+            let _ = event_tx.send(ClientEvent::CircuitOpen);
+            break;
         } else {
             warn!("Reconnection strategy exhausted, stopping");
             break;
@@ -436,6 +631,47 @@ async fn run_client<R, M>(
     info!("Client task exiting");
 }
 
+/// Convert a tungstenite error, keeping transport failures apart from
+/// protocol violations
+fn map_tungstenite_error(e: TungsteniteError) -> HyperSocketError {
+    match e {
+        TungsteniteError::Io(ref io) if io.kind() == std::io::ErrorKind::TimedOut => {
+            HyperSocketError::Timeout(e.to_string())
+        }
+        TungsteniteError::Io(_)
+        | TungsteniteError::Tls(_)
+        | TungsteniteError::ConnectionClosed
+        | TungsteniteError::AlreadyClosed
+        | TungsteniteError::Protocol(ProtocolError::ResetWithoutClosingHandshake) => {
+            HyperSocketError::WebSocket(e.to_string())
+        }
+        e => HyperSocketError::Protocol(e.to_string()),
+    }
+}
+
+/// Reason handed to the reconnection strategy for a connection that ended with `error`
+fn classify_disconnect(error: &HyperSocketError) -> DisconnectReason {
+    match error {
+        HyperSocketError::ServerClosed { code, .. } => DisconnectReason::ServerClose { code: *code },
+        HyperSocketError::PongTimeout(_) => DisconnectReason::PongTimeout,
+        HyperSocketError::Timeout(_) => DisconnectReason::Timeout,
+        HyperSocketError::Protocol(_)
+        | HyperSocketError::MessageTooLarge { .. }
+        | HyperSocketError::AuthenticationFailed(_) => DisconnectReason::ProtocolError,
+        _ => DisconnectReason::IoError,
+    }
+}
+
+/// Attempt counter to continue from after a connection drops: back to zero
+/// if the connection stayed up for `reset_after`, unchanged otherwise
+fn attempt_after_disconnect(attempt: usize, connected_for: Duration, reset_after: Duration) -> usize {
+    if connected_for >= reset_after {
+        0
+    } else {
+        attempt
+    }
+}
+
 /// Handle an active WebSocket connection
 async fn handle_connection<R, M>(
     ws_stream: tokio_tungstenite::WebSocketStream<
@@ -471,28 +707,27 @@ where
         }
     }
 
-    // Send subscription messages if configured
-    for sub in &config.subscriptions {
-        let msg = ws_message_to_tungstenite(sub);
-        write.send(msg).await.map_err(|e| {
-            HyperSocketError::WebSocket(format!("Failed to send subscription: {}", e))
-        })?;
-        metrics.increment_sent();
-        debug!("Sent subscription message");
+    // Send subscription messages if configured, unless held back until resume()
+    let subscribed = !config.subscriptions_paused.load(std::sync::atomic::Ordering::Acquire);
+    if subscribed {
+        send_subscriptions(&mut write, &config, &metrics).await?;
+    } else {
+        debug!("Client paused, holding back subscriptions");
     }
 
-    // Create PONG tracker if configured
-    let pong_tracker: Option<Arc<PongTracker>> = config.pong_timeout.map(|timeout| {
-        Arc::new(PongTracker::new(timeout))
-    });
+    // Start PONG tracking fresh for this connection
+    let pong_tracker = config.pong_tracker.clone();
+    if let Some(ref tracker) = pong_tracker {
+        tracker.reset();
+    }
 
     // Spawn heartbeat task if configured
     let heartbeat_handle = if let Some((interval, payload)) = &config.heartbeat {
         let interval = *interval;
-        let payload = payload.clone();
+        let payload = Arc::clone(payload);
 
         let (handle, shutdown_tx, heartbeat_rx) =
-            crate::heartbeat::spawn_heartbeat(interval, payload, pong_tracker.clone());
+            crate::heartbeat::spawn_heartbeat(interval, payload);
 
         Some((handle, shutdown_tx, heartbeat_rx))
     } else {
@@ -500,17 +735,15 @@ where
     };
 
     // Main message loop
-    let result = message_loop(
-        &mut write,
-        &mut read,
+    let ctx = MessageLoopContext {
         config,
         state,
         metrics,
         command_rx,
-        heartbeat_handle.as_ref().map(|(_, _, rx)| rx),
-        pong_tracker.as_ref(),
-    )
-    .await;
+        heartbeat_rx: heartbeat_handle.as_ref().map(|(_, _, rx)| rx),
+        pong_tracker: pong_tracker.as_ref(),
+    };
+    let result = message_loop(&mut write, &mut read, ctx, subscribed).await;
 
     // Cleanup heartbeat task
     // Send shutdown signal and let it exit gracefully via signal check
@@ -523,6 +756,44 @@ where
     result
 }
 
+/// Send every subscription, starting a new subscription epoch
+async fn send_subscriptions<S, R, M>(
+    write: &mut S,
+    config: &ClientConfig<R, M>,
+    metrics: &AtomicMetrics,
+) -> Result<()>
+where
+    S: futures::Sink<Message, Error = TungsteniteError> + Unpin,
+    R: MessageRouter<Message = M>,
+    M: Send + std::fmt::Debug + 'static,
+{
+    let subscriptions = config.subscriptions.lock().replay();
+    for sub in &subscriptions {
+        let msg = ws_message_to_tungstenite(sub);
+        write.send(msg).await.map_err(|e| {
+            HyperSocketError::WebSocket(format!("Failed to send subscription: {}", e))
+        })?;
+        metrics.increment_sent();
+        debug!("Sent subscription message");
+    }
+    Ok(())
+}
+
+/// Shared client state and per-connection channels the message loop works with
+struct MessageLoopContext<'a, R, M>
+where
+    R: MessageRouter<Message = M>,
+    M: Send + std::fmt::Debug + 'static,
+{
+    config: Arc<ClientConfig<R, M>>,
+    state: Arc<AtomicConnectionState>,
+    metrics: Arc<AtomicMetrics>,
+    command_rx: &'a Receiver<ClientCommand>,
+    /// Heartbeats to forward to the server, if configured
+    heartbeat_rx: Option<&'a Receiver<WsMessage>>,
+    pong_tracker: Option<&'a Arc<PongTracker>>,
+}
+
 /// Main message processing loop
 async fn message_loop<R, M>(
     write: &mut futures::stream::SplitSink<
@@ -532,17 +803,21 @@ async fn message_loop<R, M>(
     read: &mut futures::stream::SplitStream<
         tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
     >,
-    config: Arc<ClientConfig<R, M>>,
-    state: Arc<AtomicConnectionState>,
-    metrics: Arc<AtomicMetrics>,
-    command_rx: &Receiver<ClientCommand>,
-    heartbeat_rx: Option<&Receiver<WsMessage>>,
-    pong_tracker: Option<&Arc<PongTracker>>,
+    ctx: MessageLoopContext<'_, R, M>,
+    mut subscribed: bool,
 ) -> Result<()>
 where
     R: MessageRouter<Message = M>,
     M: Send + std::fmt::Debug + 'static,
 {
+    let MessageLoopContext {
+        config,
+        state,
+        metrics,
+        command_rx,
+        heartbeat_rx,
+        pong_tracker,
+    } = ctx;
     let shutdown_flag = &config.shutdown_flag;
 
     loop {
@@ -571,6 +846,15 @@ where
             // Handle incoming WebSocket messages (highest priority)
             msg = read.next() => {
                 match msg {
+                    Some(Ok(Message::Close(frame))) => {
+                        let (code, reason) = frame
+                            .map(|f| (u16::from(f.code), f.reason.to_string()))
+                            .unwrap_or((u16::from(CloseCode::Status), String::new()));
+                        warn!("Server closed connection with code {}: {}", code, reason);
+                        // Completes the closing handshake
+                        let _ = write.close().await;
+                        return Err(HyperSocketError::ServerClosed { code, reason });
+                    }
                     Some(Ok(msg)) => {
                         metrics.increment_received();
 
@@ -610,52 +894,53 @@ where
                                 }
                             }
 
-                            // Check shutdown flag before spawning parse task
+                            // Register the message before checking the shutdown flag so
+                            // handlers never see zero in-flight work while a message is pending
+                            let in_flight = &config.in_flight_parses;
+                            in_flight.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+
                             // Don't queue new work if shutting down
-                            if !shutdown_flag.load(std::sync::atomic::Ordering::Acquire) {
+                            if !shutdown_flag.load(std::sync::atomic::Ordering::SeqCst) {
+                                in_flight.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
                                 debug!("Shutdown detected, skipping message parsing");
                                 continue;
                             }
 
-                            // Parse and route message
-                            let router = Arc::clone(&config.router);
-                            let route_senders = config.route_senders.clone();
-                            let shutdown_flag_parse = Arc::clone(&shutdown_flag);
-
-                            tokio::spawn(async move {
-                                // Parse the WebSocket message
-                                match router.parse(ws_msg).await {
-                                    Ok(message) => {
-                                        // Check shutdown flag before routing (atomic load, ~1ns)
-                                        // Don't route messages if shutdown was triggered during parse
-                                        if !shutdown_flag_parse.load(std::sync::atomic::Ordering::Acquire) {
-                                            debug!("Shutdown detected after parse, discarding message");
-                                            return;
-                                        }
-
-                                        // Get route key
-                                        let route_key = router.route_key(&message);
-
-                                        // Route to appropriate handler channel
-                                        if let Some(sender) = route_senders.get(&route_key) {
-                                            // Send message to handler
-                                            // If send fails, channel is closed which only happens during shutdown
-                                            // We silently ignore these errors as they're expected during graceful shutdown
-                                            let _ = sender.send(message);
-                                        } else {
-                                            warn!("No handler configured for route key: {:?}", route_key);
-                                        }
-                                    }
-                                    Err(e) => {
-                                        error!("Parse error: {}", e);
+                            // Parse and route inline, so messages reach handlers in arrival
+                            // order and a full `OverflowPolicy::Block` route pauses reading
+                            match config.router.parse(ws_msg).await {
+                                Ok(message) => {
+                                    // Messages received before shutdown are still routed;
+                                    // shutdown() waits for handlers to drain them
+                                    let route_key = config.router.route_key(&message);
+
+                                    if let Some(sender) = config.route_senders.get(&route_key) {
+                                        // Applies the route's overflow policy. A failed send means the
+                                        // channel closed, which only happens during graceful shutdown
+                                        sender.deliver(message).await;
+                                    } else {
+                                        warn!("No handler configured for route key: {:?}", route_key);
                                     }
                                 }
-                            });
+                                Err(e) => {
+                                    error!("Parse error: {}", e);
+                                }
+                            }
+                            in_flight.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
                         }
                     }
+                    Some(Err(TungsteniteError::Capacity(CapacityError::MessageTooLong { size, max_size }))) => {
+                        warn!("Inbound message of {} bytes exceeds limit of {} bytes, closing", size, max_size);
+                        let close = Message::Close(Some(CloseFrame {
+                            code: CloseCode::Size,
+                            reason: "message too big".into(),
+                        }));
+                        let _ = write.send(close).await;
+                        return Err(HyperSocketError::MessageTooLarge { size, max_size });
+                    }
                     Some(Err(e)) => {
                         error!("WebSocket error: {}", e);
-                        return Err(HyperSocketError::WebSocket(e.to_string()));
+                        return Err(map_tungstenite_error(e));
                     }
                     None => {
                         warn!("WebSocket stream closed");
@@ -682,12 +967,37 @@ where
                             state.set(ConnectionState::ShuttingDown);
                             return Ok(());
                         }
+                        Ok(ClientCommand::Resume) => {
+                            // A resume issued while disconnected was already
+                            // honoured when this connection subscribed
+                            if !subscribed {
+                                info!("Resuming, sending subscriptions");
+                                send_subscriptions(write, &config, &metrics).await?;
+                                subscribed = true;
+                            }
+                        }
+                        Ok(ClientCommand::Subscription { message, epoch }) => {
+                            // Requests from before the last replay, or while
+                            // paused, are covered by the subscription set
+                            if subscribed && epoch == config.subscriptions.lock().epoch {
+                                write.send(ws_message_to_tungstenite(&message)).await.map_err(|e| {
+                                    HyperSocketError::WebSocket(format!("Failed to send subscription: {}", e))
+                                })?;
+                                metrics.increment_sent();
+                            }
+                        }
+                        Ok(ClientCommand::Reconnect) => {
+                            info!("Received reconnect command, closing connection");
+                            let _ = write.send(Message::Close(None)).await;
+                            return Ok(());
+                        }
                         Ok(ClientCommand::GetMetrics(tx)) => {
                             let _ = tx.send(Metrics {
                                 messages_sent: metrics.messages_sent(),
                                 messages_received: metrics.messages_received(),
                                 reconnect_count: metrics.reconnect_count(),
                                 connection_state: state.get(),
+                                route_messages: config.route_message_counts(),
                             });
                         }
                         Err(crossbeam_channel::TryRecvError::Empty) => break,
@@ -707,6 +1017,9 @@ where
                             HyperSocketError::WebSocket(format!("Failed to send heartbeat: {}", e))
                         })?;
                         metrics.increment_sent();
+                        if let Some(tracker) = pong_tracker {
+                            tracker.record_ping_sent();
+                        }
                         debug!("Heartbeat sent successfully");
                     }
                 }
@@ -721,8 +1034,8 @@ where
                             "PONG timeout - no PONG received for {} - connection appears dead, triggering reconnect",
                             since_pong
                         );
-                        return Err(HyperSocketError::ConnectionClosed(
-                            "PONG timeout - connection appears dead".to_string()
+                        return Err(HyperSocketError::PongTimeout(
+                            "connection appears dead".to_string()
                         ));
                     }
                 }
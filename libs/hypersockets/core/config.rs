@@ -1,9 +1,56 @@
+use crate::connection_state::AtomicRouteMetrics;
+use crate::core::heartbeat::HeartbeatPayload;
+use crate::core::pong_tracker::PongTracker;
+use crate::core::route_sender::RouteSender;
 use crate::traits::*;
+use parking_lot::Mutex;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, AtomicUsize};
 use std::sync::Arc;
 use std::time::Duration;
 
+/// Subscriptions replayed on every connection
+///
+/// `epoch` advances each time the whole set is sent, so a subscribe or
+/// unsubscribe queued before a replay can tell it was already covered.
+#[derive(Debug, Default)]
+pub(crate) struct SubscriptionSet {
+    pub(crate) messages: Vec<WsMessage>,
+    pub(crate) epoch: u64,
+}
+
+impl SubscriptionSet {
+    pub(crate) fn new(messages: Vec<WsMessage>) -> Self {
+        let mut set = Self::default();
+        for message in messages {
+            set.insert(message);
+        }
+        set
+    }
+
+    /// Add `message` unless already present; returns whether it was added
+    pub(crate) fn insert(&mut self, message: WsMessage) -> bool {
+        if self.messages.contains(&message) {
+            return false;
+        }
+        self.messages.push(message);
+        true
+    }
+
+    /// Remove `message`; returns whether it was present
+    pub(crate) fn remove(&mut self, message: &WsMessage) -> bool {
+        let len = self.messages.len();
+        self.messages.retain(|m| m != message);
+        self.messages.len() != len
+    }
+
+    /// Snapshot the set for sending and start a new epoch
+    pub(crate) fn replay(&mut self) -> Vec<WsMessage> {
+        self.epoch += 1;
+        self.messages.clone()
+    }
+}
+
 /// Configuration for WebSocketClient with routing support
 ///
 /// This struct holds all the configuration needed to create and run
@@ -21,7 +68,10 @@ where
     pub(crate) router: Arc<R>,
 
     /// Channel senders mapped by route key (for routing messages)
-    pub(crate) route_senders: HashMap<R::RouteKey, crossbeam_channel::Sender<M>>,
+    pub(crate) route_senders: HashMap<R::RouteKey, RouteSender<M>>,
+
+    /// Per-route delivery and handler metrics, keyed like `route_senders`
+    pub(crate) route_metrics: HashMap<R::RouteKey, Arc<AtomicRouteMetrics>>,
 
     /// Optional authentication provider
     pub(crate) auth: Option<Arc<dyn AuthProvider>>,
@@ -29,8 +79,8 @@ where
     /// Optional header provider for dynamic HTTP headers
     pub(crate) headers: Option<Arc<dyn HeaderProvider>>,
 
-    /// Optional heartbeat configuration (interval, payload)
-    pub(crate) heartbeat: Option<(Duration, WsMessage)>,
+    /// Optional heartbeat configuration (interval, payload producer)
+    pub(crate) heartbeat: Option<(Duration, HeartbeatPayload)>,
 
     /// Optional passive ping detector
     pub(crate) passive_ping: Option<Arc<dyn PassivePingDetector>>,
@@ -42,8 +92,15 @@ where
     /// This is applied BEFORE the reconnection strategy delay
     pub(crate) reconnection_delay_offset: Duration,
 
-    /// Subscription messages to send after connection/auth
-    pub(crate) subscriptions: Vec<WsMessage>,
+    /// How long a connection must stay up before the attempt counter resets
+    pub(crate) backoff_reset_after: Duration,
+
+    /// Subscription messages to send after connection/auth, including
+    /// those added later with `WebSocketClient::subscribe`
+    pub(crate) subscriptions: Mutex<SubscriptionSet>,
+
+    /// Subscriptions are held back until `WebSocketClient::resume()`
+    pub(crate) subscriptions_paused: AtomicBool,
 
     /// Shutdown flag - when false, prevents reconnection attempts
     /// This allows graceful shutdown and external shutdown coordination
@@ -60,6 +117,18 @@ where
 
     /// PONG timeout - if no PONG received within this duration after PING, connection is unhealthy
     pub(crate) pong_timeout: Option<Duration>,
+
+    /// PONG health and round-trip tracking, shared across reconnects
+    pub(crate) pong_tracker: Option<Arc<PongTracker>>,
+
+    /// Number of received messages still being parsed and routed to a handler
+    pub(crate) in_flight_parses: Arc<AtomicUsize>,
+
+    /// Upper bound on how long shutdown waits for handler queues to drain
+    pub(crate) shutdown_drain_timeout: Duration,
+
+    /// Largest inbound message or frame in bytes before the connection is dropped
+    pub(crate) max_message_size: usize,
 }
 
 impl<R, M> ClientConfig<R, M>
@@ -82,14 +151,29 @@ where
         self.heartbeat.is_some()
     }
 
+    /// Get the heartbeat interval, if heartbeat is configured
+    pub fn heartbeat_interval(&self) -> Option<Duration> {
+        self.heartbeat.as_ref().map(|(interval, _)| *interval)
+    }
+
+    /// Get the PONG timeout, if configured
+    pub fn pong_timeout(&self) -> Option<Duration> {
+        self.pong_timeout
+    }
+
     /// Check if passive ping detection is configured
     pub fn has_passive_ping(&self) -> bool {
         self.passive_ping.is_some()
     }
 
+    /// Get the inbound message size limit in bytes
+    pub fn max_message_size(&self) -> usize {
+        self.max_message_size
+    }
+
     /// Get the number of configured subscriptions
     pub fn subscription_count(&self) -> usize {
-        self.subscriptions.len()
+        self.subscriptions.lock().messages.len()
     }
 
     /// Get the number of configured handlers
@@ -97,6 +181,27 @@ where
         self.route_senders.len()
     }
 
+    /// Messages handled per route, keyed by the route key's `Debug` output
+    pub fn route_message_counts(&self) -> HashMap<String, u64> {
+        self.route_metrics
+            .iter()
+            .map(|(key, metrics)| (format!("{:?}", key), metrics.snapshot().messages_handled))
+            .collect()
+    }
+
+    /// Check whether every received message has been routed and every handler queue is empty
+    pub fn is_drained(&self) -> bool {
+        self.in_flight_parses.load(std::sync::atomic::Ordering::SeqCst) == 0
+            && self.route_senders.values().all(|sender| sender.is_empty())
+    }
+
+    /// Messages received but not yet picked up by a handler: still parsing
+    /// or waiting in a handler queue
+    pub fn pending_messages(&self) -> usize {
+        self.in_flight_parses.load(std::sync::atomic::Ordering::SeqCst)
+            + self.route_senders.values().map(|sender| sender.len()).sum::<usize>()
+    }
+
     /// Check if PONG tracking is configured
     pub fn has_pong_tracking(&self) -> bool {
         self.pong_detector.is_some() && self.pong_timeout.is_some()
@@ -1,4 +1,6 @@
 use std::sync::atomic::{AtomicU8, Ordering};
+use crate::core::route_sender::OverflowPolicy;
+use std::time::Duration;
 
 /// Connection state enum
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -189,3 +191,91 @@ impl Default for AtomicMetrics {
         Self::new()
     }
 }
+
+/// Snapshot of one route's handler metrics
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RouteMetrics {
+    /// Messages routed to this route's handler channel
+    pub messages_delivered: u64,
+    /// Messages the handler has finished with
+    pub messages_handled: u64,
+    /// Messages discarded by the overflow policy of a bounded route
+    pub messages_dropped: u64,
+    /// `handle()` calls that returned an error
+    pub handler_errors: u64,
+    /// Slowest single `handle()` call
+    pub max_handler_latency: Duration,
+    /// Mean `handle()` duration, zero before the first message
+    pub avg_handler_latency: Duration,
+    /// Channel capacity, `None` for unbounded routes
+    pub capacity: Option<usize>,
+    /// Overflow policy, `None` for unbounded routes
+    pub overflow_policy: Option<OverflowPolicy>,
+}
+
+/// Lock-free per-route metrics, updated by the reader and the handler thread
+#[derive(Debug, Default)]
+pub struct AtomicRouteMetrics {
+    delivered: AtomicU64,
+    handled: AtomicU64,
+    errors: AtomicU64,
+    dropped: AtomicU64,
+    total_latency_ns: AtomicU64,
+    max_latency_ns: AtomicU64,
+    capacity: Option<usize>,
+    overflow_policy: Option<OverflowPolicy>,
+}
+
+impl AtomicRouteMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Metrics for a bounded route, recording its capacity and policy
+    pub fn bounded(capacity: usize, policy: OverflowPolicy) -> Self {
+        Self {
+            capacity: Some(capacity),
+            overflow_policy: Some(policy),
+            ..Self::default()
+        }
+    }
+
+    /// Count a message sent to the handler channel
+    #[inline]
+    pub fn record_delivered(&self) {
+        self.delivered.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count a message discarded by the overflow policy
+    #[inline]
+    pub fn record_dropped(&self) {
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record one `handle()` call and whether it failed
+    #[inline]
+    pub fn record_handled(&self, latency: Duration, failed: bool) {
+        let nanos = u64::try_from(latency.as_nanos()).unwrap_or(u64::MAX);
+        self.handled.fetch_add(1, Ordering::Relaxed);
+        self.total_latency_ns.fetch_add(nanos, Ordering::Relaxed);
+        self.max_latency_ns.fetch_max(nanos, Ordering::Relaxed);
+        if failed {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn snapshot(&self) -> RouteMetrics {
+        let handled = self.handled.load(Ordering::Relaxed);
+        let total_ns = self.total_latency_ns.load(Ordering::Relaxed);
+        RouteMetrics {
+            messages_delivered: self.delivered.load(Ordering::Relaxed),
+            messages_handled: handled,
+            messages_dropped: self.dropped.load(Ordering::Relaxed),
+            handler_errors: self.errors.load(Ordering::Relaxed),
+            max_handler_latency: Duration::from_nanos(self.max_latency_ns.load(Ordering::Relaxed)),
+            avg_handler_latency: Duration::from_nanos(total_ns.checked_div(handled).unwrap_or(0)),
+            capacity: self.capacity,
+            overflow_policy: self.overflow_policy,
+        }
+    }
+}
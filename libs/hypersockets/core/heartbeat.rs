@@ -25,7 +25,9 @@
 //!
 //! # Usage
 //!
-//! Heartbeat is configured via the builder and both interval AND payload are required:
+//! Heartbeat is configured via the builder and both interval AND payload are required.
+//! Use `heartbeat_with` instead when the payload changes per tick (timestamps,
+//! sequence numbers):
 //!
 //! ```ignore
 //! let client = hypersockets::builder()
@@ -41,12 +43,14 @@
 //! ```
 
 use crossbeam_channel::{Receiver, Sender};
-use crate::core::pong_tracker::PongTracker;
 use crate::traits::WsMessage;
 use std::sync::Arc;
 use std::time::Duration;
 use tracing::debug;
 
+/// Produces the payload for each heartbeat tick
+pub type HeartbeatPayload = Arc<dyn Fn() -> WsMessage + Send + Sync>;
+
 /// Heartbeat task that sends periodic messages
 ///
 /// This function runs in a dedicated Tokio task and sends the configured
@@ -54,22 +58,22 @@ use tracing::debug;
 ///
 /// The task will:
 /// 1. Wait for the first interval (skips immediate first tick)
-/// 2. On each tick, send the payload through the channel
-/// 3. Record PING sent time if pong_tracker is provided
-/// 4. Continue until shutdown signal received or channel closed
+/// 2. On each tick, build the payload and send it through the channel
+/// 3. Continue until shutdown signal received or channel closed
+///
+/// PING times for PONG tracking are recorded by the main loop when the
+/// payload actually goes out, not when it is queued here.
 ///
 /// # Arguments
 /// * `interval` - Duration between heartbeat messages
-/// * `payload` - The message to send on each heartbeat
+/// * `payload` - Called on each heartbeat to produce the message
 /// * `heartbeat_tx` - Channel to send heartbeat messages to main loop
 /// * `shutdown_rx` - Channel to receive shutdown signal
-/// * `pong_tracker` - Optional tracker for PONG response monitoring
 pub async fn heartbeat_task(
     interval: Duration,
-    payload: WsMessage,
+    payload: HeartbeatPayload,
     heartbeat_tx: Sender<WsMessage>,
     shutdown_rx: Receiver<()>,
-    pong_tracker: Option<Arc<PongTracker>>,
 ) {
     let mut ticker = tokio::time::interval(interval);
     // Skip the first immediate tick - wait for the first interval
@@ -100,15 +104,10 @@ pub async fn heartbeat_task(
 
         // Send heartbeat payload
         debug!("Heartbeat tick - sending payload");
-        if heartbeat_tx.send(payload.clone()).is_err() {
+        if heartbeat_tx.send(payload()).is_err() {
             debug!("Heartbeat channel closed, shutting down heartbeat task");
             break;
         }
-
-        // Record PING sent time for PONG tracking
-        if let Some(ref tracker) = pong_tracker {
-            tracker.record_ping_sent();
-        }
     }
 
     debug!("Heartbeat task exiting");
@@ -120,18 +119,16 @@ pub async fn heartbeat_task(
 ///
 /// # Arguments
 /// * `interval` - Duration between heartbeat messages
-/// * `payload` - The message to send on each heartbeat
-/// * `pong_tracker` - Optional tracker for PONG response monitoring
+/// * `payload` - Called on each heartbeat to produce the message
 pub fn spawn_heartbeat(
     interval: Duration,
-    payload: WsMessage,
-    pong_tracker: Option<Arc<PongTracker>>,
+    payload: HeartbeatPayload,
 ) -> (tokio::task::JoinHandle<()>, Sender<()>, Receiver<WsMessage>) {
     let (shutdown_tx, shutdown_rx) = crossbeam_channel::bounded(1);
     let (heartbeat_tx, heartbeat_rx) = crossbeam_channel::unbounded();
 
     let handle = tokio::spawn(async move {
-        heartbeat_task(interval, payload, heartbeat_tx, shutdown_rx, pong_tracker).await;
+        heartbeat_task(interval, payload, heartbeat_tx, shutdown_rx).await;
     });
 
     (handle, shutdown_tx, heartbeat_rx)
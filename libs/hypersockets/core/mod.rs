@@ -50,13 +50,17 @@ pub mod config;
 pub mod connection_state;
 pub mod heartbeat;
 pub mod pong_tracker;
+pub mod route_sender;
 
 // Re-export main types
 pub use builder::{states, RoutingBuilder, WebSocketClientBuilder};
-pub use client::{ClientEvent, Metrics, WebSocketClient};
+pub use client::{ClientEvent, ClientSender, Metrics, WebSocketClient};
 pub use config::ClientConfig;
-pub use connection_state::{AtomicConnectionState, AtomicMetrics, ConnectionState};
+pub use connection_state::{
+    AtomicConnectionState, AtomicMetrics, AtomicRouteMetrics, ConnectionState, RouteMetrics,
+};
 pub use pong_tracker::PongTracker;
+pub use route_sender::OverflowPolicy;
 
 // Re-export traits for convenience
 pub use crate::traits::*;
@@ -2,11 +2,24 @@
 //!
 //! Tracks PONG responses to detect dead/zombie WebSocket connections.
 //! A connection is considered unhealthy if no PONG is received within
-//! the configured timeout after a PING was sent.
+//! the configured timeout after a PING was sent. Recent PING->PONG round
+//! trips are kept for latency statistics.
 
+use parking_lot::Mutex;
+use std::collections::VecDeque;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
+/// Number of round-trip samples kept
+pub const RTT_HISTORY_LEN: usize = 128;
+
+/// Outstanding PING and the most recent round trips, oldest first
+#[derive(Default)]
+struct RttWindow {
+    pending_ping: Option<Instant>,
+    samples: VecDeque<Duration>,
+}
+
 /// Tracks PONG responses to detect dead connections
 ///
 /// Uses atomic operations for lock-free access from multiple tasks.
@@ -21,6 +34,8 @@ pub struct PongTracker {
     last_pong_received_ms: AtomicU64,
     /// Timeout threshold - if no PONG within this duration after PING, connection is unhealthy
     timeout: Duration,
+    /// Round-trip samples (only touched once per PING and PONG)
+    rtt: Mutex<RttWindow>,
 }
 
 impl PongTracker {
@@ -37,6 +52,7 @@ impl PongTracker {
             last_ping_sent_ms: AtomicU64::new(0),
             last_pong_received_ms: AtomicU64::new(0),
             timeout,
+            rtt: Mutex::new(RttWindow::default()),
         }
     }
 
@@ -44,16 +60,69 @@ impl PongTracker {
     ///
     /// Call this immediately after sending a PING message.
     pub fn record_ping_sent(&self) {
-        let ms = self.epoch.elapsed().as_millis() as u64;
+        self.record_ping_sent_at(Instant::now());
+    }
+
+    /// Record that a PING was sent at `at`
+    pub fn record_ping_sent_at(&self, at: Instant) {
+        let ms = at.saturating_duration_since(self.epoch).as_millis() as u64;
         self.last_ping_sent_ms.store(ms, Ordering::Release);
+        self.rtt.lock().pending_ping = Some(at);
     }
 
     /// Record that a PONG was just received
     ///
     /// Call this when a PONG message is detected in the message stream.
     pub fn record_pong_received(&self) {
-        let ms = self.epoch.elapsed().as_millis() as u64;
+        self.record_pong_received_at(Instant::now());
+    }
+
+    /// Record that a PONG was received at `at`
+    ///
+    /// The first PONG after a PING adds a round-trip sample; unsolicited
+    /// PONGs only count towards health.
+    pub fn record_pong_received_at(&self, at: Instant) {
+        let ms = at.saturating_duration_since(self.epoch).as_millis() as u64;
         self.last_pong_received_ms.store(ms, Ordering::Release);
+
+        let mut rtt = self.rtt.lock();
+        if let Some(ping) = rtt.pending_ping.take() {
+            if rtt.samples.len() == RTT_HISTORY_LEN {
+                rtt.samples.pop_front();
+            }
+            rtt.samples.push_back(at.saturating_duration_since(ping));
+        }
+    }
+
+    /// Most recent PING->PONG round trip
+    pub fn last_rtt(&self) -> Option<Duration> {
+        self.rtt.lock().samples.back().copied()
+    }
+
+    /// Mean of the last `window` round trips (fewer if not yet recorded)
+    pub fn avg_rtt(&self, window: usize) -> Option<Duration> {
+        let samples = self.recent_rtts(window);
+        if samples.is_empty() {
+            return None;
+        }
+        Some(samples.iter().sum::<Duration>() / samples.len() as u32)
+    }
+
+    /// 99th percentile (nearest rank) of the last `window` round trips
+    pub fn p99_rtt(&self, window: usize) -> Option<Duration> {
+        let mut samples = self.recent_rtts(window);
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_unstable();
+        let rank = (samples.len() * 99).div_ceil(100);
+        Some(samples[rank - 1])
+    }
+
+    fn recent_rtts(&self, window: usize) -> Vec<Duration> {
+        let rtt = self.rtt.lock();
+        let skip = rtt.samples.len().saturating_sub(window);
+        rtt.samples.iter().skip(skip).copied().collect()
     }
 
     /// Check if the connection appears healthy
@@ -110,10 +179,12 @@ impl PongTracker {
 
     /// Reset the tracker state
     ///
-    /// Call this when reconnecting to start fresh.
+    /// Call this when reconnecting to start fresh. Round-trip samples are
+    /// kept so latency trends span reconnects.
     pub fn reset(&self) {
         self.last_ping_sent_ms.store(0, Ordering::Release);
         self.last_pong_received_ms.store(0, Ordering::Release);
+        self.rtt.lock().pending_ping = None;
     }
 }
 
@@ -164,6 +235,46 @@ mod tests {
         assert!(tracker.is_healthy());
     }
 
+    #[test]
+    fn test_rtt_statistics() {
+        let tracker = PongTracker::new(Duration::from_secs(15));
+        assert!(tracker.last_rtt().is_none());
+        assert!(tracker.avg_rtt(10).is_none());
+
+        // Round trips of 1ms..=100ms
+        let start = Instant::now();
+        for i in 1..=100u64 {
+            let ping = start + Duration::from_secs(i);
+            tracker.record_ping_sent_at(ping);
+            tracker.record_pong_received_at(ping + Duration::from_millis(i));
+        }
+
+        assert_eq!(tracker.last_rtt(), Some(Duration::from_millis(100)));
+        assert_eq!(tracker.avg_rtt(4), Some(Duration::from_micros(98_500)));
+        assert_eq!(tracker.avg_rtt(1000), Some(Duration::from_micros(50_500)));
+        assert_eq!(tracker.p99_rtt(100), Some(Duration::from_millis(99)));
+        assert_eq!(tracker.p99_rtt(10), Some(Duration::from_millis(100)));
+        assert!(tracker.p99_rtt(0).is_none());
+
+        // A PONG without an outstanding PING adds no sample
+        tracker.record_pong_received_at(start + Duration::from_secs(200));
+        assert_eq!(tracker.last_rtt(), Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn test_rtt_history_is_bounded() {
+        let tracker = PongTracker::new(Duration::from_secs(15));
+        let start = Instant::now();
+        for i in 0..(RTT_HISTORY_LEN as u64 + 10) {
+            let ping = start + Duration::from_secs(i);
+            tracker.record_ping_sent_at(ping);
+            tracker.record_pong_received_at(ping + Duration::from_millis(5));
+        }
+
+        assert_eq!(tracker.recent_rtts(usize::MAX).len(), RTT_HISTORY_LEN);
+        assert_eq!(tracker.avg_rtt(usize::MAX), Some(Duration::from_millis(5)));
+    }
+
     #[test]
     fn test_time_since_last_pong() {
         let tracker = PongTracker::new(Duration::from_secs(15));
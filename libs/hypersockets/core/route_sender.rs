@@ -0,0 +1,219 @@
+use crate::connection_state::AtomicRouteMetrics;
+use crossbeam_channel::{Receiver, Sender, TrySendError};
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+/// What a bounded route does when its handler channel is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Discard the incoming message
+    DropNewest,
+    /// Evict the oldest queued message to make room; suits snapshot
+    /// streams where only the latest message matters
+    DropOldest,
+    /// Wait until the handler frees a slot; the reader stops reading the
+    /// socket meanwhile, pushing back on the server
+    Block,
+}
+
+/// Sending half of a route's handler channel
+///
+/// Unbounded routes always accept. Bounded routes apply their
+/// `OverflowPolicy` when full and count discarded messages as drops.
+pub(crate) struct RouteSender<M> {
+    sender: Sender<M>,
+    /// Kept only for `DropOldest`, to evict from the front of the queue
+    evict: Option<Receiver<M>>,
+    /// Free queue slots of a `Block` route; a send awaits one and the
+    /// handler returns it (`release_slot`) once it takes the message
+    slots: Option<Arc<Semaphore>>,
+    policy: Option<OverflowPolicy>,
+    metrics: Arc<AtomicRouteMetrics>,
+}
+
+impl<M> Clone for RouteSender<M> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            evict: self.evict.clone(),
+            slots: self.slots.clone(),
+            policy: self.policy,
+            metrics: Arc::clone(&self.metrics),
+        }
+    }
+}
+
+impl<M> RouteSender<M> {
+    pub(crate) fn unbounded(sender: Sender<M>, metrics: Arc<AtomicRouteMetrics>) -> Self {
+        Self {
+            sender,
+            evict: None,
+            slots: None,
+            policy: None,
+            metrics,
+        }
+    }
+
+    pub(crate) fn bounded(
+        sender: Sender<M>,
+        receiver: &Receiver<M>,
+        policy: OverflowPolicy,
+        metrics: Arc<AtomicRouteMetrics>,
+    ) -> Self {
+        let evict = (policy == OverflowPolicy::DropOldest).then(|| receiver.clone());
+        let slots = (policy == OverflowPolicy::Block)
+            .then(|| Arc::new(Semaphore::new(sender.capacity().unwrap_or(1))));
+        Self {
+            sender,
+            evict,
+            slots,
+            policy: Some(policy),
+            metrics,
+        }
+    }
+
+    pub(crate) fn metrics(&self) -> &Arc<AtomicRouteMetrics> {
+        &self.metrics
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.sender.is_empty()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.sender.len()
+    }
+
+    /// Called by the handler for each message it takes off the channel
+    pub(crate) fn release_slot(&self) {
+        if let Some(slots) = &self.slots {
+            slots.add_permits(1);
+        }
+    }
+
+    /// Queue `message` for the handler, applying the overflow policy
+    ///
+    /// Returns false if the message was dropped or the handler is gone.
+    pub(crate) async fn deliver(&self, message: M) -> bool {
+        // Counted before sending so a fast handler never reports more
+        // handled than delivered
+        self.metrics.record_delivered();
+
+        if let Some(slots) = &self.slots {
+            // Never closed, so this only waits for the handler to free a slot
+            match slots.acquire().await {
+                Ok(permit) => permit.forget(),
+                Err(_) => return false,
+            }
+        }
+
+        let mut message = message;
+        loop {
+            match self.sender.try_send(message) {
+                Ok(()) => return true,
+                // Only happens during shutdown, once the handler thread is gone
+                Err(TrySendError::Disconnected(_)) => return false,
+                Err(TrySendError::Full(returned)) => match self.policy {
+                    Some(OverflowPolicy::DropNewest) | None => {
+                        self.metrics.record_dropped();
+                        return false;
+                    }
+                    Some(OverflowPolicy::DropOldest) => {
+                        if let Some(evict) = &self.evict {
+                            if evict.try_recv().is_ok() {
+                                self.metrics.record_dropped();
+                            }
+                        }
+                        message = returned;
+                    }
+                    // Unreachable while slots match the capacity; treat as a drop
+                    Some(OverflowPolicy::Block) => {
+                        drop(returned);
+                        self.metrics.record_dropped();
+                        return false;
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounded_route(
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> (RouteSender<u32>, Receiver<u32>, Arc<AtomicRouteMetrics>) {
+        let (sender, receiver) = crossbeam_channel::bounded(capacity);
+        let metrics = Arc::new(AtomicRouteMetrics::bounded(capacity, policy));
+        let route = RouteSender::bounded(sender, &receiver, policy, Arc::clone(&metrics));
+        (route, receiver, metrics)
+    }
+
+    #[tokio::test]
+    async fn test_drop_newest_discards_incoming() {
+        let (route, receiver, metrics) = bounded_route(2, OverflowPolicy::DropNewest);
+
+        assert!(route.deliver(1).await);
+        assert!(route.deliver(2).await);
+        assert!(!route.deliver(3).await);
+
+        assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![1, 2]);
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.messages_dropped, 1);
+        assert_eq!(snapshot.overflow_policy, Some(OverflowPolicy::DropNewest));
+        assert_eq!(snapshot.capacity, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_drop_oldest_keeps_latest() {
+        let (route, receiver, metrics) = bounded_route(2, OverflowPolicy::DropOldest);
+
+        for i in 1..=5 {
+            assert!(route.deliver(i).await);
+        }
+
+        assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![4, 5]);
+        assert_eq!(metrics.snapshot().messages_dropped, 3);
+        assert_eq!(metrics.snapshot().messages_delivered, 5);
+    }
+
+    #[tokio::test]
+    async fn test_block_waits_for_space() {
+        let (route, receiver, metrics) = bounded_route(1, OverflowPolicy::Block);
+        assert!(route.deliver(1).await);
+
+        let blocked = tokio::spawn({
+            let route = route.clone();
+            async move { route.deliver(2).await }
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        assert!(!blocked.is_finished());
+
+        // Taking a message off the channel alone frees no slot
+        assert_eq!(receiver.recv().unwrap(), 1);
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        assert!(!blocked.is_finished());
+
+        route.release_slot();
+        assert!(blocked.await.unwrap());
+        assert_eq!(receiver.recv().unwrap(), 2);
+        assert_eq!(metrics.snapshot().messages_dropped, 0);
+    }
+
+    #[tokio::test]
+    async fn test_unbounded_never_drops() {
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        let metrics = Arc::new(AtomicRouteMetrics::new());
+        let route = RouteSender::unbounded(sender, Arc::clone(&metrics));
+
+        for i in 0..100 {
+            assert!(route.deliver(i).await);
+        }
+        assert_eq!(receiver.len(), 100);
+        assert_eq!(metrics.snapshot().messages_dropped, 0);
+        assert_eq!(metrics.snapshot().overflow_policy, None);
+    }
+}
@@ -76,6 +76,12 @@ where
     halted_flag: Arc<AtomicBool>,
     /// Set of currently disconnected client IDs
     disconnected_clients: Arc<RwLock<HashSet<ClientId>>>,
+    /// Clients whose disconnects don't affect the halted flag
+    non_critical_clients: Arc<RwLock<HashSet<ClientId>>>,
+    /// Set of currently disconnected non-critical client IDs
+    non_critical_down: Arc<RwLock<HashSet<ClientId>>>,
+    /// Reconnect every client when any one of them disconnects
+    coordinated_reconnect: Arc<AtomicBool>,
     /// State monitor task handle
     monitor_handle: Arc<RwLock<Option<tokio::task::JoinHandle<()>>>>,
 }
@@ -121,6 +127,9 @@ where
             shutdown_flag: Arc::clone(&shutdown_flag),
             halted_flag: Arc::clone(&halted_flag),
             disconnected_clients: Arc::clone(&disconnected_clients),
+            non_critical_clients: Arc::new(RwLock::new(HashSet::new())),
+            non_critical_down: Arc::new(RwLock::new(HashSet::new())),
+            coordinated_reconnect: Arc::new(AtomicBool::new(false)),
             monitor_handle: Arc::new(RwLock::new(None)),
         };
 
@@ -130,6 +139,28 @@ where
         manager
     }
 
+    /// Enable or disable coordinated reconnects (disabled by default)
+    ///
+    /// When enabled, a client dropping its connection makes every other
+    /// connected client reconnect too, so feeds that must present a
+    /// consistent view resync together. Disconnects caused by the
+    /// coordinated reconnect itself don't trigger another round.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let manager = ClientManager::new(shutdown_flag.clone())
+    ///     .with_coordinated_reconnect(true);
+    /// ```
+    pub fn with_coordinated_reconnect(self, enabled: bool) -> Self {
+        self.coordinated_reconnect.store(enabled, Ordering::Release);
+        self
+    }
+
+    /// Check whether coordinated reconnects are enabled
+    pub fn is_coordinated_reconnect(&self) -> bool {
+        self.coordinated_reconnect.load(Ordering::Acquire)
+    }
+
     /// Get a reference to the shutdown flag
     ///
     /// This flag is shared with all clients managed by this manager.
@@ -147,8 +178,8 @@ where
 
     /// Get a reference to the halted flag
     ///
-    /// The halted flag indicates when ANY managed client is disconnected
-    /// (but not shutting down). It's automatically managed by the state
+    /// The halted flag indicates when ANY critical managed client is
+    /// disconnected (but not shutting down). It's automatically managed by the state
     /// monitoring task.
     ///
     /// - `true`: One or more clients are disconnected and reconnecting
@@ -203,6 +234,14 @@ where
         self.disconnected_clients.read().len()
     }
 
+    /// Get the count of non-critical clients currently disconnected
+    ///
+    /// These are tracked apart from `get_disconnected_clients()` and never
+    /// set the halted flag.
+    pub fn non_critical_down_count(&self) -> usize {
+        self.non_critical_down.read().len()
+    }
+
     /// Add a new client to the manager
     ///
     /// **Important**: Clients should be built with the same shutdown flag
@@ -227,6 +266,27 @@ where
     /// manager.add_client("my-client", client)?;
     /// ```
     pub fn add_client(&self, id: impl Into<ClientId>, client: WebSocketClient<R, M>) -> Result<()> {
+        self.add_client_weighted(id, client, true)
+    }
+
+    /// Add a client, choosing whether its disconnects halt the manager
+    ///
+    /// Critical clients behave as with `add_client`. A non-critical client
+    /// still reconnects on its own, but its disconnects are only counted in
+    /// `non_critical_down_count()` and don't set the halted flag or start a
+    /// coordinated reconnect.
+    ///
+    /// # Example
+    /// ```ignore
+    /// manager.add_client_weighted("market", market_client, true)?;
+    /// manager.add_client_weighted("sports", sports_client, false)?;
+    /// ```
+    pub fn add_client_weighted(
+        &self,
+        id: impl Into<ClientId>,
+        client: WebSocketClient<R, M>,
+        critical: bool,
+    ) -> Result<()> {
         let id = id.into();
         let mut clients = self.clients.write();
 
@@ -237,8 +297,11 @@ where
             )));
         }
 
+        if !critical {
+            self.non_critical_clients.write().insert(id.clone());
+        }
         clients.insert(id.clone(), client);
-        info!("Added client '{}'", id);
+        info!("Added {} client '{}'", if critical { "critical" } else { "non-critical" }, id);
         Ok(())
     }
 
@@ -251,6 +314,8 @@ where
 
         if let Some(client) = client {
             debug!("Removing client '{}'", id);
+            self.non_critical_clients.write().remove(id);
+            self.non_critical_down.write().remove(id);
             client.shutdown().await?;
             info!("Removed client '{}'", id);
             Ok(())
@@ -379,16 +444,24 @@ where
     /// and updates the halted_flag and disconnected_clients set accordingly.
     ///
     /// The monitor only tracks clients that have a halted_flag configured.
+    /// It also drives coordinated reconnects, which apply to every client.
     fn spawn_state_monitor(&self) {
         let clients = Arc::clone(&self.clients);
         let shutdown_flag = Arc::clone(&self.shutdown_flag);
         let halted_flag = Arc::clone(&self.halted_flag);
         let disconnected_clients = Arc::clone(&self.disconnected_clients);
+        let non_critical_clients = Arc::clone(&self.non_critical_clients);
+        let non_critical_down = Arc::clone(&self.non_critical_down);
+        let coordinated_reconnect = Arc::clone(&self.coordinated_reconnect);
         let monitor_handle = Arc::clone(&self.monitor_handle);
 
         let handle = tokio::spawn(async move {
             debug!("State monitor task started");
 
+            // Clients told to reconnect by a coordinated reconnect, whose
+            // upcoming disconnect must not start another round
+            let mut resyncing: HashSet<ClientId> = HashSet::new();
+
             loop {
                 // Check shutdown flag first
                 if !shutdown_flag.load(Ordering::Acquire) {
@@ -412,6 +485,42 @@ where
 
                 // Process events
                 for (client_id, event, client_halted_flag) in events {
+                    let critical = !non_critical_clients.read().contains(&client_id);
+
+                    if matches!(event, ClientEvent::Disconnected)
+                        && !resyncing.remove(&client_id)
+                        && critical
+                        && coordinated_reconnect.load(Ordering::Acquire)
+                        && shutdown_flag.load(Ordering::Acquire)
+                    {
+                        let clients_lock = clients.read();
+                        let mut count = 0;
+                        for (id, client) in clients_lock.iter() {
+                            if *id != client_id && client.is_connected() && client.reconnect().is_ok() {
+                                resyncing.insert(id.clone());
+                                count += 1;
+                            }
+                        }
+                        info!("Client '{}' disconnected, reconnecting {} other clients", client_id, count);
+                    }
+
+                    // Non-critical clients are tracked apart and never halt,
+                    // whether or not they share the halted flag
+                    if !critical {
+                        match event {
+                            ClientEvent::Disconnected => {
+                                debug!("Non-critical client '{}' disconnected", client_id);
+                                non_critical_down.write().insert(client_id);
+                            }
+                            ClientEvent::Connected => {
+                                debug!("Non-critical client '{}' connected", client_id);
+                                non_critical_down.write().remove(&client_id);
+                            }
+                            _ => {}
+                        }
+                        continue;
+                    }
+
                     // Only process events for clients with halted_flag configured
                     if client_halted_flag.is_none() {
                         continue;
@@ -22,9 +22,12 @@ pub use traits::*;
 pub use core::{
     builder, client, config, connection_state, heartbeat,
     builder::{states, RoutingBuilder, WebSocketClientBuilder},
-    client::{ClientEvent, Metrics, WebSocketClient},
+    client::{ClientEvent, ClientSender, Metrics, WebSocketClient},
     config::ClientConfig,
-    connection_state::{AtomicConnectionState, AtomicMetrics, ConnectionState},
+    route_sender::OverflowPolicy,
+    connection_state::{
+        AtomicConnectionState, AtomicMetrics, AtomicRouteMetrics, ConnectionState, RouteMetrics,
+    },
 };
 
 // Re-export manager
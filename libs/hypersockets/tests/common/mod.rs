@@ -2,8 +2,14 @@
 //!
 //! This module provides shared utilities for testing WebSocket functionality.
 
+#![allow(dead_code)]
+
+use hypersockets::{MessageHandler, MessageRouter, WsMessage};
+use parking_lot::Mutex;
+use std::fmt::Debug;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::net::TcpListener;
 use tokio::sync::Notify;
 
@@ -17,6 +23,57 @@ macro_rules! verbose_println {
     };
 }
 
+/// Router that parses every message to `()`
+pub struct NoopRouter;
+
+#[async_trait::async_trait]
+impl MessageRouter for NoopRouter {
+    type Message = ();
+    type RouteKey = ();
+
+    async fn parse(&self, _message: WsMessage) -> hypersockets::Result<Self::Message> {
+        Ok(())
+    }
+
+    fn route_key(&self, _message: &Self::Message) -> Self::RouteKey {}
+}
+
+/// Router that passes text messages through as strings
+pub struct EchoRouter;
+
+#[async_trait::async_trait]
+impl MessageRouter for EchoRouter {
+    type Message = String;
+    type RouteKey = ();
+
+    async fn parse(&self, message: WsMessage) -> hypersockets::Result<Self::Message> {
+        Ok(message.as_text().unwrap_or_default().to_string())
+    }
+
+    fn route_key(&self, _message: &Self::Message) -> Self::RouteKey {}
+}
+
+/// Handler that drops every message
+pub struct NoopHandler;
+
+impl<M: Send + Debug + 'static> MessageHandler<M> for NoopHandler {
+    fn handle(&mut self, _message: M) -> hypersockets::Result<()> {
+        Ok(())
+    }
+}
+
+/// Handler that records every message in arrival order
+pub struct RecordingHandler {
+    pub received: Arc<Mutex<Vec<String>>>,
+}
+
+impl MessageHandler<String> for RecordingHandler {
+    fn handle(&mut self, message: String) -> hypersockets::Result<()> {
+        self.received.lock().push(message);
+        Ok(())
+    }
+}
+
 /// A simple mock WebSocket server for testing
 pub struct MockWsServer {
     pub addr: SocketAddr,
@@ -59,7 +116,7 @@ impl MockWsServer {
     }
 
     async fn handle_connection(stream: tokio::net::TcpStream, shutdown: Arc<Notify>) {
-        use futures_util::{SinkExt, StreamExt};
+        use futures::{SinkExt, StreamExt};
         use tokio_tungstenite::accept_async;
 
         let ws_stream = match accept_async(stream).await {
@@ -119,6 +176,32 @@ impl Drop for MockWsServer {
     }
 }
 
+/// Accepts connections and closes the n-th one after `holds[n]`; connections
+/// past the end of the script stay open
+pub async fn start_flapping_server(holds: Vec<Duration>) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let mut holds = holds.into_iter();
+        while let Ok((stream, _)) = listener.accept().await {
+            let hold = holds.next();
+            tokio::spawn(async move {
+                let Ok(ws) = tokio_tungstenite::accept_async(stream).await else {
+                    return;
+                };
+                match hold {
+                    Some(hold) => tokio::time::sleep(hold).await,
+                    None => std::future::pending::<()>().await,
+                }
+                drop(ws);
+            });
+        }
+    });
+
+    addr
+}
+
 /// Test fixture for connection states
 pub mod fixtures {
     use hypersockets::core::connection_state::{AtomicConnectionState, ConnectionState};
@@ -0,0 +1,79 @@
+//! Integration tests for resetting the reconnection backoff
+//!
+//! A flapping server closes each connection after a scripted hold time, and a
+//! recording strategy captures the attempt number of every reconnect delay.
+
+mod common;
+
+use common::{NoopHandler, NoopRouter, start_flapping_server};
+use hypersockets::core::builder;
+use hypersockets::traits::reconnect::ExponentialBackoff;
+use hypersockets::ReconnectionStrategy;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Exponential backoff that records every delay it hands out
+struct RecordingBackoff {
+    inner: ExponentialBackoff,
+    delays: Arc<Mutex<Vec<Duration>>>,
+}
+
+impl ReconnectionStrategy for RecordingBackoff {
+    fn next_delay(&self, attempt: usize) -> Option<Duration> {
+        let delay = self.inner.next_delay(attempt)?;
+        self.delays.lock().push(delay);
+        Some(delay)
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    fn should_reconnect(&self, attempt: usize) -> bool {
+        self.inner.should_reconnect(attempt)
+    }
+}
+
+#[tokio::test]
+async fn test_backoff_resets_after_stable_connection() {
+    const BASE: Duration = Duration::from_millis(20);
+    const RESET_AFTER: Duration = Duration::from_millis(300);
+
+    // Three quick drops grow the backoff, then one connection outlives the
+    // reset window before dropping
+    let addr = start_flapping_server(vec![
+        Duration::ZERO,
+        Duration::ZERO,
+        Duration::ZERO,
+        RESET_AFTER * 2,
+    ])
+    .await;
+
+    let delays = Arc::new(Mutex::new(Vec::new()));
+    let client = builder()
+        .url(format!("ws://{}", addr))
+        .router(NoopRouter, |routing| routing.handler((), NoopHandler))
+        .reconnect_strategy(RecordingBackoff {
+            inner: ExponentialBackoff::new(BASE, Duration::from_secs(10), None),
+            delays: Arc::clone(&delays),
+        })
+        .backoff_reset_after(RESET_AFTER)
+        .build()
+        .await
+        .unwrap();
+
+    let start = Instant::now();
+    while delays.lock().len() < 4 && start.elapsed() < Duration::from_secs(10) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    let delays = delays.lock().clone();
+    assert!(delays.len() >= 4, "only {} reconnects", delays.len());
+    // Unstable drops keep backing off
+    assert_eq!(&delays[..3], &[BASE, BASE * 2, BASE * 4]);
+    // The drop after a stable connection starts again from the base
+    assert_eq!(delays[3], BASE);
+
+    client.shutdown().await.unwrap();
+}
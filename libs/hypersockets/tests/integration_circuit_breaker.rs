@@ -0,0 +1,89 @@
+//! Integration tests for the reconnection circuit breaker
+
+mod common;
+
+use common::{NoopHandler, NoopRouter, start_flapping_server};
+use hypersockets::core::builder;
+use hypersockets::traits::reconnect::{CircuitBreaker, FixedDelay};
+use hypersockets::{ClientEvent, ReconnectionStrategy};
+use std::time::{Duration, Instant};
+
+const WINDOW: Duration = Duration::from_secs(2);
+
+fn breaker() -> CircuitBreaker<FixedDelay> {
+    CircuitBreaker::new(FixedDelay::new(Duration::from_millis(10), None), WINDOW, 5)
+}
+
+#[test]
+fn test_breaker_trips_after_rapid_failures() {
+    let strategy = breaker();
+
+    for attempt in 0..4 {
+        strategy.record_uptime(Duration::from_millis(100));
+        assert!(!strategy.is_circuit_open());
+        assert_eq!(strategy.next_delay(attempt), Some(Duration::from_millis(10)));
+    }
+
+    strategy.record_uptime(Duration::from_millis(100));
+    assert_eq!(strategy.rapid_failures(), 5);
+    assert!(strategy.is_circuit_open());
+    assert!(!strategy.should_reconnect(5));
+    assert_eq!(strategy.next_delay(5), None);
+}
+
+#[test]
+fn test_stable_connection_clears_rapid_failures() {
+    let strategy = breaker();
+
+    for _ in 0..4 {
+        strategy.record_uptime(Duration::ZERO);
+    }
+    strategy.record_uptime(WINDOW);
+    assert_eq!(strategy.rapid_failures(), 0);
+
+    // The count starts over, so four more rapid failures don't trip it
+    for _ in 0..4 {
+        strategy.record_uptime(Duration::ZERO);
+    }
+    assert!(!strategy.is_circuit_open());
+
+    let mut strategy = strategy;
+    strategy.record_uptime(Duration::ZERO);
+    assert!(strategy.is_circuit_open());
+    strategy.reset();
+    assert!(!strategy.is_circuit_open());
+    assert_eq!(strategy.rapid_failures(), 0);
+}
+
+#[tokio::test]
+async fn test_client_stops_when_breaker_trips() {
+    // Every connection is closed as soon as it's accepted
+    let addr = start_flapping_server(vec![Duration::ZERO; 10]).await;
+
+    let client = builder()
+        .url(format!("ws://{}", addr))
+        .router(NoopRouter, |routing| routing.handler((), NoopHandler))
+        .reconnect_strategy(breaker())
+        .build()
+        .await
+        .unwrap();
+
+    let mut disconnects = 0;
+    let mut circuit_open = false;
+    let start = Instant::now();
+    while !circuit_open && start.elapsed() < Duration::from_secs(5) {
+        match client.try_recv_event() {
+            Some(ClientEvent::Disconnected) => disconnects += 1,
+            Some(ClientEvent::CircuitOpen) => circuit_open = true,
+            Some(_) => {}
+            None => tokio::time::sleep(Duration::from_millis(10)).await,
+        }
+    }
+
+    assert!(circuit_open);
+    assert_eq!(disconnects, 5);
+    assert_eq!(client.metrics().reconnect_count, 4);
+    assert!(!client.is_connected());
+
+    client.shutdown().await.unwrap();
+}
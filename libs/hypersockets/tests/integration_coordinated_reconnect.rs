@@ -0,0 +1,96 @@
+//! Integration tests for coordinated reconnects in `ClientManager`
+//!
+//! One client talks to a flapping server that drops its first connection;
+//! the others talk to stable mock servers.
+
+mod common;
+
+use common::{MockWsServer, NoopHandler, NoopRouter, start_flapping_server};
+use hypersockets::core::builder;
+use hypersockets::traits::reconnect::FixedDelay;
+use hypersockets::{ClientManager, WebSocketClient};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+async fn client(url: String, shutdown_flag: &Arc<AtomicBool>) -> WebSocketClient<NoopRouter, ()> {
+    builder()
+        .url(url)
+        .router(NoopRouter, |routing| routing.handler((), NoopHandler))
+        .reconnect_strategy(FixedDelay::new(Duration::from_millis(50), None))
+        .shutdown_flag(Arc::clone(shutdown_flag))
+        .build()
+        .await
+        .unwrap()
+}
+
+/// Start a manager with a "flaky" client whose first connection drops after
+/// 500ms and two clients on stable servers
+async fn start_manager(
+    coordinated: bool,
+) -> (ClientManager<NoopRouter, ()>, Vec<MockWsServer>) {
+    let shutdown_flag = Arc::new(AtomicBool::new(true));
+    let manager = ClientManager::new(Arc::clone(&shutdown_flag)).with_coordinated_reconnect(coordinated);
+
+    let flaky_addr = start_flapping_server(vec![Duration::from_millis(500)]).await;
+    let servers = vec![MockWsServer::start().await, MockWsServer::start().await];
+
+    manager
+        .add_client("flaky", client(format!("ws://{}", flaky_addr), &shutdown_flag).await)
+        .unwrap();
+    manager.add_client("market", client(servers[0].ws_url(), &shutdown_flag).await).unwrap();
+    manager.add_client("user", client(servers[1].ws_url(), &shutdown_flag).await).unwrap();
+
+    (manager, servers)
+}
+
+fn reconnects(manager: &ClientManager<NoopRouter, ()>, id: &str) -> u64 {
+    manager.get_metrics(id).unwrap().reconnect_count
+}
+
+#[tokio::test]
+async fn test_one_failure_reconnects_all_clients() {
+    let (manager, _servers) = start_manager(true).await;
+    assert!(manager.is_coordinated_reconnect());
+
+    let start = Instant::now();
+    while !(reconnects(&manager, "market") >= 1 && reconnects(&manager, "user") >= 1)
+        && start.elapsed() < Duration::from_secs(5)
+    {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+
+    assert_eq!(reconnects(&manager, "flaky"), 1);
+    assert_eq!(reconnects(&manager, "market"), 1);
+    assert_eq!(reconnects(&manager, "user"), 1);
+
+    // Everyone comes back, and the resync doesn't cascade into more rounds
+    let start = Instant::now();
+    while manager.connected_count() < 3 && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(manager.connected_count(), 3);
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(reconnects(&manager, "flaky"), 1);
+    assert_eq!(reconnects(&manager, "market"), 1);
+    assert_eq!(reconnects(&manager, "user"), 1);
+
+    manager.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_failure_is_isolated_without_policy() {
+    let (manager, _servers) = start_manager(false).await;
+
+    let start = Instant::now();
+    while reconnects(&manager, "flaky") < 1 && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(reconnects(&manager, "flaky"), 1);
+
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(reconnects(&manager, "market"), 0);
+    assert_eq!(reconnects(&manager, "user"), 0);
+
+    manager.shutdown().await.unwrap();
+}
@@ -0,0 +1,105 @@
+//! Integration tests for classifying disconnects
+//!
+//! A scripted server ends successive connections in different ways, and a
+//! recording strategy captures the reason the client hands to it.
+
+mod common;
+
+use common::{NoopHandler, NoopRouter};
+use futures::SinkExt;
+use hypersockets::core::builder;
+use hypersockets::{DisconnectReason, ReconnectionStrategy};
+use parking_lot::Mutex;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Reconnects quickly and records every reason it is given
+struct RecordingStrategy {
+    reasons: Arc<Mutex<Vec<DisconnectReason>>>,
+}
+
+impl ReconnectionStrategy for RecordingStrategy {
+    fn next_delay(&self, _attempt: usize) -> Option<Duration> {
+        Some(Duration::from_millis(20))
+    }
+
+    fn next_delay_for(&self, attempt: usize, reason: DisconnectReason) -> Option<Duration> {
+        self.reasons.lock().push(reason);
+        self.next_delay(attempt)
+    }
+
+    fn reset(&mut self) {}
+
+    fn should_reconnect(&self, _attempt: usize) -> bool {
+        true
+    }
+}
+
+/// First connection: closed with 1008 (policy violation). Second: TCP dropped
+/// without a close frame. Later connections stay open.
+async fn start_scripted_server() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let mut connection = 0;
+        while let Ok((stream, _)) = listener.accept().await {
+            connection += 1;
+            let script = connection;
+            tokio::spawn(async move {
+                let Ok(mut ws) = tokio_tungstenite::accept_async(stream).await else {
+                    return;
+                };
+                match script {
+                    1 => {
+                        let _ = ws
+                            .send(Message::Close(Some(CloseFrame {
+                                code: CloseCode::Policy,
+                                reason: "not allowed".into(),
+                            })))
+                            .await;
+                        tokio::time::sleep(Duration::from_millis(100)).await;
+                    }
+                    2 => tokio::time::sleep(Duration::from_millis(50)).await,
+                    _ => std::future::pending::<()>().await,
+                }
+                drop(ws);
+            });
+        }
+    });
+
+    addr
+}
+
+#[tokio::test]
+async fn test_client_classifies_disconnects() {
+    let addr = start_scripted_server().await;
+    let reasons = Arc::new(Mutex::new(Vec::new()));
+
+    let client = builder()
+        .url(format!("ws://{}", addr))
+        .router(NoopRouter, |routing| routing.handler((), NoopHandler))
+        .reconnect_strategy(RecordingStrategy {
+            reasons: Arc::clone(&reasons),
+        })
+        .build()
+        .await
+        .unwrap();
+
+    let start = Instant::now();
+    while reasons.lock().len() < 2 && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    assert_eq!(
+        reasons.lock()[..2],
+        [DisconnectReason::ServerClose { code: 1008 }, DisconnectReason::IoError]
+    );
+
+    client.shutdown().await.unwrap();
+}
@@ -0,0 +1,95 @@
+//! Integration tests for subscriptions added after the client is built
+//!
+//! The mock server echoes every message, so each subscription the client
+//! sends comes back and is recorded by the handler in send order.
+
+mod common;
+
+use common::{EchoRouter, MockWsServer, RecordingHandler};
+use hypersockets::core::builder;
+use hypersockets::WsMessage;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+fn text(s: &str) -> WsMessage {
+    WsMessage::Text(s.to_string())
+}
+
+async fn wait_for(received: &Mutex<Vec<String>>, count: usize) {
+    let start = Instant::now();
+    while received.lock().len() < count && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+#[tokio::test]
+async fn test_dynamic_subscriptions_replay_after_reconnect() {
+    let server = MockWsServer::start().await;
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let handler = RecordingHandler {
+        received: Arc::clone(&received),
+    };
+
+    let client = builder()
+        .url(server.ws_url())
+        .router(EchoRouter, move |routing| routing.handler((), handler))
+        .subscription(text("sub:btc"))
+        .build()
+        .await
+        .unwrap();
+    wait_for(&received, 1).await;
+
+    // Sent live once; the duplicate is ignored
+    client.subscribe(text("sub:eth")).unwrap();
+    client.subscribe(text("sub:eth")).unwrap();
+    wait_for(&received, 2).await;
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(*received.lock(), vec!["sub:btc", "sub:eth"]);
+    assert_eq!(client.config().subscription_count(), 2);
+
+    // A forced reconnect replays both
+    received.lock().clear();
+    client.reconnect().unwrap();
+    wait_for(&received, 2).await;
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(*received.lock(), vec!["sub:btc", "sub:eth"]);
+
+    // Unsubscribed messages are no longer replayed
+    received.lock().clear();
+    client.unsubscribe(&text("sub:btc"), text("unsub:btc")).unwrap();
+    wait_for(&received, 1).await;
+    client.reconnect().unwrap();
+    wait_for(&received, 2).await;
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(*received.lock(), vec!["unsub:btc", "sub:eth"]);
+    assert_eq!(client.config().subscription_count(), 1);
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_subscribe_while_disconnected_flushes_once_on_connect() {
+    let server = MockWsServer::start().await;
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let handler = RecordingHandler {
+        received: Arc::clone(&received),
+    };
+
+    let client = builder()
+        .url(server.ws_url())
+        .router(EchoRouter, move |routing| routing.handler((), handler))
+        .build()
+        .await
+        .unwrap();
+
+    // Queued before the first connection completes
+    client.subscribe(text("sub:sol")).unwrap();
+
+    wait_for(&received, 1).await;
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(*received.lock(), vec!["sub:sol"]);
+    assert_eq!(client.metrics().messages_sent, 1);
+
+    client.shutdown().await.unwrap();
+}
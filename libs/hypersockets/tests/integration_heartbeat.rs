@@ -0,0 +1,74 @@
+//! Integration tests for heartbeat payloads
+//!
+//! The mock server echoes every message, so each heartbeat the client sends
+//! comes back and is recorded by the handler in send order.
+
+mod common;
+
+use common::{EchoRouter, MockWsServer, RecordingHandler};
+use hypersockets::core::builder;
+use hypersockets::WsMessage;
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn test_heartbeat_payload_computed_each_tick() {
+    let server = MockWsServer::start().await;
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let handler = RecordingHandler {
+        received: Arc::clone(&received),
+    };
+
+    let sequence = Arc::new(AtomicU64::new(0));
+    let client = builder()
+        .url(server.ws_url())
+        .router(EchoRouter, move |routing| routing.handler((), handler))
+        .heartbeat_with(Duration::from_millis(50), {
+            let sequence = Arc::clone(&sequence);
+            move || {
+                let seq = sequence.fetch_add(1, Ordering::SeqCst) + 1;
+                WsMessage::Text(format!("hb-{}", seq))
+            }
+        })
+        .build()
+        .await
+        .unwrap();
+
+    let start = Instant::now();
+    while received.lock().len() < 3 && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    let received = received.lock().clone();
+    assert!(received.len() >= 3, "only {} heartbeats", received.len());
+    assert_eq!(&received[..3], &["hb-1", "hb-2", "hb-3"]);
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_static_heartbeat_repeats_payload() {
+    let server = MockWsServer::start().await;
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let handler = RecordingHandler {
+        received: Arc::clone(&received),
+    };
+
+    let client = builder()
+        .url(server.ws_url())
+        .router(EchoRouter, move |routing| routing.handler((), handler))
+        .heartbeat(Duration::from_millis(50), WsMessage::Text("PING".into()))
+        .build()
+        .await
+        .unwrap();
+
+    let start = Instant::now();
+    while received.lock().len() < 2 && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(&received.lock()[..2], &["PING", "PING"]);
+
+    client.shutdown().await.unwrap();
+}
@@ -0,0 +1,67 @@
+//! Integration tests for the inbound message size limit
+//!
+//! The mock server echoes whatever it receives, so sending an oversized
+//! message makes the server send an oversized frame back.
+
+mod common;
+
+use common::{EchoRouter, MockWsServer, NoopHandler};
+use hypersockets::core::builder;
+use hypersockets::{ClientEvent, WsMessage};
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn test_oversized_frame_surfaces_limit_event() {
+    const LIMIT: usize = 1024;
+
+    let server = MockWsServer::start().await;
+    let client = builder()
+        .url(server.ws_url())
+        .router(EchoRouter, |routing| routing.handler((), NoopHandler))
+        .max_message_size(LIMIT)
+        .build()
+        .await
+        .unwrap();
+    assert_eq!(client.config().max_message_size(), LIMIT);
+
+    let start = Instant::now();
+    while !client.is_connected() && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    // Within the limit: echoed back and handled normally
+    client.send(WsMessage::Text("small".into())).unwrap();
+    // Over the limit: the echo trips the limit
+    client.send(WsMessage::Text("x".repeat(LIMIT * 4))).unwrap();
+
+    let mut violation = None;
+    let start = Instant::now();
+    while violation.is_none() && start.elapsed() < Duration::from_secs(5) {
+        match client.try_recv_event() {
+            Some(ClientEvent::MessageTooLarge { size, max_size }) => {
+                violation = Some((size, max_size));
+            }
+            Some(_) => {}
+            None => tokio::time::sleep(Duration::from_millis(10)).await,
+        }
+    }
+
+    let (size, max_size) = violation.expect("no MessageTooLarge event");
+    assert!(size > LIMIT);
+    assert_eq!(max_size, LIMIT);
+    assert!(client.metrics().messages_received >= 1);
+
+    // The connection is dropped and the reconnection strategy takes over
+    let mut reconnecting = false;
+    let start = Instant::now();
+    while !reconnecting && start.elapsed() < Duration::from_secs(5) {
+        match client.try_recv_event() {
+            Some(ClientEvent::Reconnecting(_)) => reconnecting = true,
+            Some(_) => {}
+            None => tokio::time::sleep(Duration::from_millis(10)).await,
+        }
+    }
+    assert!(reconnecting);
+
+    client.shutdown().await.unwrap();
+}
@@ -3,7 +3,8 @@
 //! These tests verify reconnection behavior with different strategies.
 
 use hypersockets::traits::reconnect::{
-    ExponentialBackoff, FixedDelay, NeverReconnect, ReconnectionStrategy,
+    DisconnectReason, ExponentialBackoff, ExponentialBackoffJittered, FixedDelay, NeverReconnect,
+    ReconnectionStrategy,
 };
 use std::time::Duration;
 
@@ -236,3 +237,129 @@ fn test_mixed_strategy_simulation() {
 
     verbose_println!("  Mixed strategy simulation successful");
 }
+
+#[test]
+fn test_jittered_backoff_within_computed_bounds() {
+    verbose_println!("Testing jittered backoff bounds...");
+
+    let strategy = ExponentialBackoffJittered::new(
+        Duration::from_millis(100),
+        Duration::from_secs(2),
+        None,
+    )
+    .with_seed(42);
+
+    let expected_bounds = [100, 200, 400, 800, 1600, 2000, 2000];
+    let mut delays = Vec::new();
+
+    for (attempt, &bound_ms) in expected_bounds.iter().enumerate() {
+        let bound = Duration::from_millis(bound_ms);
+        assert_eq!(strategy.computed_delay(attempt), bound);
+
+        let delay = strategy.next_delay(attempt).unwrap();
+        verbose_println!("  Attempt {}: {:?} (bound {:?})", attempt, delay, bound);
+        assert!(delay <= bound, "Delay {:?} above bound at attempt {}", delay, attempt);
+        delays.push(delay);
+    }
+
+    // Same seed, same sequence
+    let replay = ExponentialBackoffJittered::new(
+        Duration::from_millis(100),
+        Duration::from_secs(2),
+        None,
+    )
+    .with_seed(42);
+    let replayed: Vec<_> = (0..expected_bounds.len())
+        .map(|attempt| replay.next_delay(attempt).unwrap())
+        .collect();
+    assert_eq!(delays, replayed);
+
+    // Jitter actually spreads the delays
+    assert!(delays.iter().zip(&expected_bounds).any(|(d, &b)| d.as_millis() < b as u128));
+}
+
+#[test]
+fn test_jittered_backoff_multiplier_and_max_attempts() {
+    let strategy = ExponentialBackoffJittered::new(
+        Duration::from_millis(100),
+        Duration::from_secs(60),
+        Some(3),
+    )
+    .with_multiplier(3.0)
+    .with_seed(7);
+
+    assert_eq!(strategy.computed_delay(2), Duration::from_millis(900));
+    assert!(strategy.next_delay(2).unwrap() <= Duration::from_millis(900));
+    assert!(strategy.should_reconnect(2));
+    assert!(!strategy.should_reconnect(3));
+    assert!(strategy.next_delay(3).is_none());
+
+    // Huge attempt counts saturate at max_delay instead of overflowing
+    let unbounded = ExponentialBackoffJittered::new(
+        Duration::from_millis(100),
+        Duration::from_secs(60),
+        None,
+    );
+    assert_eq!(unbounded.computed_delay(10_000), Duration::from_secs(60));
+    assert!(unbounded.next_delay(usize::MAX).unwrap() <= Duration::from_secs(60));
+}
+
+/// Backs off hard after a policy-violation close, retries quickly otherwise
+struct ReasonAwareStrategy {
+    base: ExponentialBackoff,
+}
+
+impl ReconnectionStrategy for ReasonAwareStrategy {
+    fn next_delay(&self, attempt: usize) -> Option<Duration> {
+        self.base.next_delay(attempt)
+    }
+
+    fn next_delay_for(&self, attempt: usize, reason: DisconnectReason) -> Option<Duration> {
+        match reason {
+            DisconnectReason::ServerClose { code: 1008 } => Some(Duration::from_secs(30)),
+            DisconnectReason::ServerClose { code: 1000 | 1001 } => Some(Duration::ZERO),
+            _ => self.next_delay(attempt),
+        }
+    }
+
+    fn reset(&mut self) {}
+
+    fn should_reconnect(&self, attempt: usize) -> bool {
+        self.base.should_reconnect(attempt)
+    }
+}
+
+#[test]
+fn test_strategy_distinguishes_disconnect_reasons() {
+    let strategy = ReasonAwareStrategy {
+        base: ExponentialBackoff::new(Duration::from_millis(100), Duration::from_secs(10), None),
+    };
+
+    let policy = strategy
+        .next_delay_for(0, DisconnectReason::ServerClose { code: 1008 })
+        .unwrap();
+    let io = strategy.next_delay_for(0, DisconnectReason::IoError).unwrap();
+    assert!(policy > io, "{:?} should exceed {:?}", policy, io);
+
+    let restart = strategy
+        .next_delay_for(0, DisconnectReason::ServerClose { code: 1001 })
+        .unwrap();
+    assert_eq!(restart, Duration::ZERO);
+}
+
+#[test]
+fn test_default_next_delay_for_ignores_reason() {
+    let strategy = ExponentialBackoff::new(Duration::from_millis(100), Duration::from_secs(10), Some(3));
+
+    for reason in [
+        DisconnectReason::Timeout,
+        DisconnectReason::ServerClose { code: 1008 },
+        DisconnectReason::ProtocolError,
+        DisconnectReason::PongTimeout,
+        DisconnectReason::IoError,
+    ] {
+        for attempt in 0..4 {
+            assert_eq!(strategy.next_delay_for(attempt, reason), strategy.next_delay(attempt));
+        }
+    }
+}
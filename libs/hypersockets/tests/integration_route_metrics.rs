@@ -0,0 +1,143 @@
+//! Integration tests for per-route handler metrics
+//!
+//! The mock server echoes every message, so messages sent by the client come
+//! back and are routed by their prefix.
+
+mod common;
+
+use common::MockWsServer;
+use hypersockets::core::builder;
+use hypersockets::{HyperSocketError, MessageHandler, MessageRouter, WsMessage};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Route {
+    Trade,
+    Book,
+}
+
+struct PrefixRouter;
+
+#[async_trait::async_trait]
+impl MessageRouter for PrefixRouter {
+    type Message = String;
+    type RouteKey = Route;
+
+    async fn parse(&self, message: WsMessage) -> hypersockets::Result<Self::Message> {
+        Ok(message.as_text().unwrap_or_default().to_string())
+    }
+
+    fn route_key(&self, message: &Self::Message) -> Self::RouteKey {
+        if message.starts_with("trade") {
+            Route::Trade
+        } else {
+            Route::Book
+        }
+    }
+}
+
+/// Sleeps per message and fails on messages ending in "bad"
+struct TimedHandler {
+    delay: Duration,
+}
+
+impl MessageHandler<String> for TimedHandler {
+    fn handle(&mut self, message: String) -> hypersockets::Result<()> {
+        std::thread::sleep(self.delay);
+        if message.ends_with("bad") {
+            return Err(HyperSocketError::Other("bad message".into()));
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_route_metrics_track_each_route() {
+    let server = MockWsServer::start().await;
+    let client = builder()
+        .url(server.ws_url())
+        .router(PrefixRouter, |routing| {
+            routing
+                .handler(Route::Trade, TimedHandler { delay: Duration::ZERO })
+                .handler(Route::Book, TimedHandler { delay: Duration::from_millis(5) })
+        })
+        .build()
+        .await
+        .unwrap();
+
+    let start = Instant::now();
+    while !client.is_connected() && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    for i in 0..3 {
+        client.send(WsMessage::Text(format!("trade-{}", i))).unwrap();
+    }
+    client.send(WsMessage::Text("book-0".into())).unwrap();
+    client.send(WsMessage::Text("book-bad".into())).unwrap();
+
+    let start = Instant::now();
+    let metrics = loop {
+        let metrics = client.route_metrics();
+        let handled: u64 = metrics.values().map(|m| m.messages_handled).sum();
+        if handled == 5 || start.elapsed() > Duration::from_secs(5) {
+            break metrics;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    };
+
+    let trade = &metrics[&Route::Trade];
+    assert_eq!(trade.messages_delivered, 3);
+    assert_eq!(trade.messages_handled, 3);
+    assert_eq!(trade.handler_errors, 0);
+
+    let book = &metrics[&Route::Book];
+    assert_eq!(book.messages_delivered, 2);
+    assert_eq!(book.messages_handled, 2);
+    assert_eq!(book.handler_errors, 1);
+    assert!(book.max_handler_latency >= Duration::from_millis(5));
+    assert!(book.avg_handler_latency >= Duration::from_millis(5));
+    assert!(book.max_handler_latency >= trade.max_handler_latency);
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_metrics_count_messages_per_route() {
+    let server = MockWsServer::start().await;
+    let client = builder()
+        .url(server.ws_url())
+        .router(PrefixRouter, |routing| {
+            routing
+                .handler(Route::Trade, TimedHandler { delay: Duration::ZERO })
+                .handler(Route::Book, TimedHandler { delay: Duration::ZERO })
+        })
+        .build()
+        .await
+        .unwrap();
+
+    let start = Instant::now();
+    while !client.is_connected() && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    for i in 0..4 {
+        client.send(WsMessage::Text(format!("trade-{}", i))).unwrap();
+    }
+    client.send(WsMessage::Text("book-0".into())).unwrap();
+
+    let start = Instant::now();
+    let counts = loop {
+        let counts = client.metrics().route_messages;
+        if counts.values().sum::<u64>() == 5 || start.elapsed() > Duration::from_secs(5) {
+            break counts;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    };
+
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts["Trade"], 4);
+    assert_eq!(counts["Book"], 1);
+
+    client.shutdown().await.unwrap();
+}
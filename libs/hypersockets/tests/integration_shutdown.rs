@@ -0,0 +1,148 @@
+//! Integration tests for graceful shutdown
+//!
+//! These tests verify that messages received before shutdown are handled
+//! before `shutdown()` returns.
+
+mod common;
+
+use common::{EchoRouter, MockWsServer};
+use hypersockets::core::builder;
+use hypersockets::{MessageHandler, WsMessage};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Handler that takes a while per message, so a queue builds up
+struct SlowHandler {
+    processed: Arc<AtomicUsize>,
+}
+
+impl MessageHandler<String> for SlowHandler {
+    fn handle(&mut self, _message: String) -> hypersockets::Result<()> {
+        std::thread::sleep(Duration::from_millis(30));
+        self.processed.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_shutdown_drains_queued_messages() {
+    const MESSAGES: usize = 10;
+
+    let server = MockWsServer::start().await;
+    let processed = Arc::new(AtomicUsize::new(0));
+    let handler = SlowHandler {
+        processed: Arc::clone(&processed),
+    };
+
+    let client = builder()
+        .url(server.ws_url())
+        .router(EchoRouter, move |routing| routing.handler((), handler))
+        .shutdown_drain_timeout(Duration::from_secs(5))
+        .build()
+        .await
+        .unwrap();
+
+    let start = Instant::now();
+    while !client.is_connected() && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    for i in 0..MESSAGES {
+        client.send(WsMessage::Text(format!("msg-{}", i))).unwrap();
+    }
+
+    // Wait until the reader has received every echo, then shut down while
+    // most of them are still queued behind the slow handler
+    let start = Instant::now();
+    while client.metrics().messages_received < MESSAGES as u64
+        && start.elapsed() < Duration::from_secs(5)
+    {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert!(processed.load(Ordering::SeqCst) < MESSAGES);
+
+    client.shutdown().await.unwrap();
+
+    assert_eq!(processed.load(Ordering::SeqCst), MESSAGES);
+}
+
+#[tokio::test]
+async fn test_shutdown_and_drain_processes_queued_messages() {
+    const MESSAGES: usize = 10;
+
+    let server = MockWsServer::start().await;
+    let processed = Arc::new(AtomicUsize::new(0));
+    let handler = SlowHandler {
+        processed: Arc::clone(&processed),
+    };
+
+    let client = builder()
+        .url(server.ws_url())
+        .router(EchoRouter, move |routing| routing.handler((), handler))
+        .build()
+        .await
+        .unwrap();
+
+    let start = Instant::now();
+    while !client.is_connected() && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    for i in 0..MESSAGES {
+        client.send(WsMessage::Text(format!("msg-{}", i))).unwrap();
+    }
+
+    let start = Instant::now();
+    while client.metrics().messages_received < MESSAGES as u64
+        && start.elapsed() < Duration::from_secs(5)
+    {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert!(processed.load(Ordering::SeqCst) < MESSAGES);
+
+    let dropped = client.shutdown_and_drain(Duration::from_secs(5)).await.unwrap();
+
+    assert_eq!(dropped, 0);
+    assert_eq!(processed.load(Ordering::SeqCst), MESSAGES);
+}
+
+#[tokio::test]
+async fn test_shutdown_and_drain_reports_dropped_on_timeout() {
+    const MESSAGES: usize = 20;
+
+    let server = MockWsServer::start().await;
+    let processed = Arc::new(AtomicUsize::new(0));
+    let handler = SlowHandler {
+        processed: Arc::clone(&processed),
+    };
+
+    let client = builder()
+        .url(server.ws_url())
+        .router(EchoRouter, move |routing| routing.handler((), handler))
+        .build()
+        .await
+        .unwrap();
+
+    let start = Instant::now();
+    while !client.is_connected() && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    for i in 0..MESSAGES {
+        client.send(WsMessage::Text(format!("msg-{}", i))).unwrap();
+    }
+
+    let start = Instant::now();
+    while client.metrics().messages_received < MESSAGES as u64
+        && start.elapsed() < Duration::from_secs(5)
+    {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+
+    // 20 messages at 30ms each cannot drain within 100ms
+    let dropped = client.shutdown_and_drain(Duration::from_millis(100)).await.unwrap();
+
+    assert!(dropped > 0);
+    assert!(dropped < MESSAGES);
+}
@@ -0,0 +1,60 @@
+//! Integration tests for starting a client with subscriptions held back
+//!
+//! The mock server echoes every message, so a subscription that was sent
+//! comes back and reaches the handler.
+
+mod common;
+
+use common::{EchoRouter, MockWsServer, RecordingHandler};
+use hypersockets::core::builder;
+use hypersockets::WsMessage;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn test_paused_client_subscribes_on_resume() {
+    let server = MockWsServer::start().await;
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let handler = RecordingHandler {
+        received: Arc::clone(&received),
+    };
+
+    let client = builder()
+        .url(server.ws_url())
+        .router(EchoRouter, move |routing| routing.handler((), handler))
+        .subscription(WsMessage::Text("subscribe:trades".into()))
+        .start_paused(true)
+        .build()
+        .await
+        .unwrap();
+    assert!(client.is_paused());
+
+    let start = Instant::now();
+    while !client.is_connected() && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert!(client.is_connected());
+
+    // Connected, but nothing sent while paused
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(client.metrics().messages_sent, 0);
+    assert!(received.lock().is_empty());
+
+    client.resume().unwrap();
+    assert!(!client.is_paused());
+
+    let start = Instant::now();
+    while received.lock().is_empty() && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(*received.lock(), vec!["subscribe:trades".to_string()]);
+    assert_eq!(client.metrics().messages_sent, 1);
+
+    // Resuming again is a no-op
+    client.resume().unwrap();
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(client.metrics().messages_sent, 1);
+
+    client.shutdown().await.unwrap();
+}
@@ -0,0 +1,113 @@
+//! Integration tests for critical and non-critical clients in `ClientManager`
+//!
+//! The critical client talks to a stable mock server; the non-critical one
+//! talks to a flapping server that drops its first connection.
+
+mod common;
+
+use common::{MockWsServer, NoopHandler, NoopRouter, start_flapping_server};
+use hypersockets::core::builder;
+use hypersockets::traits::reconnect::FixedDelay;
+use hypersockets::{ClientManager, WebSocketClient};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+async fn client(
+    url: String,
+    manager: &ClientManager<NoopRouter, ()>,
+) -> WebSocketClient<NoopRouter, ()> {
+    builder()
+        .url(url)
+        .router(NoopRouter, |routing| routing.handler((), NoopHandler))
+        .reconnect_strategy(FixedDelay::new(Duration::from_millis(500), None))
+        .shutdown_flag(Arc::clone(manager.shutdown_flag()))
+        .halted_flag(manager.halted_flag())
+        .build()
+        .await
+        .unwrap()
+}
+
+/// A client that does not share the manager's halted flag
+async fn unflagged_client(
+    url: String,
+    manager: &ClientManager<NoopRouter, ()>,
+) -> WebSocketClient<NoopRouter, ()> {
+    builder()
+        .url(url)
+        .router(NoopRouter, |routing| routing.handler((), NoopHandler))
+        .reconnect_strategy(FixedDelay::new(Duration::from_millis(500), None))
+        .shutdown_flag(Arc::clone(manager.shutdown_flag()))
+        .build()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_non_critical_disconnect_does_not_halt() {
+    let manager = ClientManager::new(Arc::new(AtomicBool::new(true)));
+    let server = MockWsServer::start().await;
+    let sports_addr = start_flapping_server(vec![Duration::from_millis(300)]).await;
+
+    manager
+        .add_client_weighted("market", client(server.ws_url(), &manager).await, true)
+        .unwrap();
+    manager
+        .add_client_weighted("sports", client(format!("ws://{}", sports_addr), &manager).await, false)
+        .unwrap();
+
+    // The sports feed drops and waits out its reconnect delay
+    let start = Instant::now();
+    while manager.non_critical_down_count() == 0 && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(manager.non_critical_down_count(), 1);
+    assert!(!manager.is_halted());
+    assert_eq!(manager.disconnected_count(), 0);
+
+    // It is still reconnected, and the trading feed never noticed
+    let start = Instant::now();
+    while manager.non_critical_down_count() > 0 && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(manager.non_critical_down_count(), 0);
+    assert_eq!(manager.get_metrics("sports").unwrap().reconnect_count, 1);
+    assert_eq!(manager.get_metrics("market").unwrap().reconnect_count, 0);
+    assert!(!manager.is_halted());
+
+    manager.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_non_critical_client_without_halted_flag_is_tracked() {
+    let manager = ClientManager::new(Arc::new(AtomicBool::new(true)));
+    let server = MockWsServer::start().await;
+    let sports_addr = start_flapping_server(vec![Duration::from_millis(300)]).await;
+
+    manager
+        .add_client_weighted("market", client(server.ws_url(), &manager).await, true)
+        .unwrap();
+    manager
+        .add_client_weighted(
+            "sports",
+            unflagged_client(format!("ws://{}", sports_addr), &manager).await,
+            false,
+        )
+        .unwrap();
+
+    let start = Instant::now();
+    while manager.non_critical_down_count() == 0 && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(manager.non_critical_down_count(), 1);
+    assert!(!manager.is_halted());
+
+    let start = Instant::now();
+    while manager.non_critical_down_count() > 0 && start.elapsed() < Duration::from_secs(5) {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(manager.non_critical_down_count(), 0);
+    assert!(!manager.is_halted());
+
+    manager.shutdown().await.unwrap();
+}
@@ -11,6 +11,18 @@ pub enum HyperSocketError {
     #[error("Connection closed: {0}")]
     ConnectionClosed(String),
 
+    /// Server closed the connection with a close frame
+    #[error("Server closed connection with code {code}: {reason}")]
+    ServerClosed { code: u16, reason: String },
+
+    /// Server violated the WebSocket protocol or rejected the handshake
+    #[error("Protocol error: {0}")]
+    Protocol(String),
+
+    /// No PONG received within the configured timeout
+    #[error("PONG timeout: {0}")]
+    PongTimeout(String),
+
     /// Authentication failed
     #[error("Authentication failed: {0}")]
     AuthenticationFailed(String),
@@ -35,6 +47,10 @@ pub enum HyperSocketError {
     #[error("Reconnection failed after {attempts} attempts: {reason}")]
     ReconnectionFailed { attempts: usize, reason: String },
 
+    /// Inbound message exceeded the configured size limit
+    #[error("Message too large: {size} bytes > {max_size} bytes")]
+    MessageTooLarge { size: usize, max_size: usize },
+
     /// Timeout error
     #[error("Operation timed out: {0}")]
     Timeout(String),
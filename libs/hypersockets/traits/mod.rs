@@ -45,6 +45,9 @@ pub use headers::{HeaderProvider, Headers, NoHeaders};
 pub use parser::{MessageParser, NoOpParser, WsMessage};
 pub use passive_ping::{JsonPassivePing, NoOpPassivePing, PassivePingDetector, TextPassivePing};
 pub use pong_detector::{NoOpPongDetector, PongDetector, TextPongDetector};
-pub use reconnect::{ExponentialBackoff, FixedDelay, NeverReconnect, ReconnectionStrategy};
+pub use reconnect::{
+    CircuitBreaker, DisconnectReason, ExponentialBackoff, ExponentialBackoffJittered, FixedDelay, NeverReconnect,
+    ReconnectionStrategy,
+};
 pub use router::{MessageHandler, MessageRouter};
 pub use state::{NoOpState, StateHandler};
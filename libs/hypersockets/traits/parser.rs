@@ -3,7 +3,7 @@ use async_trait::async_trait;
 
 /// Type alias for WebSocket messages
 /// Can be Text or Binary data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WsMessage {
     Text(String),
     Binary(Vec<u8>),
@@ -1,5 +1,26 @@
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::time::Duration;
 
+/// Why the previous connection ended (or failed to start)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DisconnectReason {
+    /// An operation timed out, e.g. the TCP connect
+    Timeout,
+    /// The server sent a close frame with this code (1005 if it sent none)
+    ServerClose { code: u16 },
+    /// The server broke the WebSocket protocol or rejected the handshake
+    ProtocolError,
+    /// No PONG arrived within the configured timeout
+    PongTimeout,
+    /// The transport failed or dropped without a close frame
+    IoError,
+    /// The client asked to reconnect (`WebSocketClient::reconnect`)
+    Requested,
+}
+
 /// Trait for defining reconnection strategies
 ///
 /// Implement this trait to control how the client should
@@ -15,9 +36,34 @@ pub trait ReconnectionStrategy: Send + Sync {
     /// * `None` - Stop reconnecting
     fn next_delay(&self, attempt: usize) -> Option<Duration>;
 
+    /// Get the delay before the next reconnection attempt, knowing why the
+    /// previous connection ended
+    ///
+    /// This is what the client calls. The default ignores the reason and
+    /// defers to `next_delay`; override it to, say, back off harder after a
+    /// policy-violation close than after a dropped socket.
+    fn next_delay_for(&self, attempt: usize, reason: DisconnectReason) -> Option<Duration> {
+        let _ = reason;
+        self.next_delay(attempt)
+    }
+
     /// Reset the strategy state (called after successful connection)
     fn reset(&mut self);
 
+    /// Called when a connection ends, with how long it was up
+    ///
+    /// The default does nothing; strategies that care about connection
+    /// stability, like `CircuitBreaker`, track it here.
+    fn record_uptime(&self, uptime: Duration) {
+        let _ = uptime;
+    }
+
+    /// Whether the strategy stopped reconnecting because a circuit tripped,
+    /// as opposed to running out of attempts
+    fn is_circuit_open(&self) -> bool {
+        false
+    }
+
     /// Check if we should continue reconnecting
     ///
     /// # Arguments
@@ -80,6 +126,85 @@ impl ReconnectionStrategy for ExponentialBackoff {
     }
 }
 
+/// Exponential backoff with full jitter
+///
+/// Each delay is drawn uniformly from `[0, computed]`, where `computed` is
+/// initial_delay * multiplier^attempt capped at max_delay. Spreading the
+/// delays keeps many clients that dropped together from reconnecting in
+/// lockstep.
+#[derive(Debug)]
+pub struct ExponentialBackoffJittered {
+    initial_delay: Duration,
+    max_delay: Duration,
+    multiplier: f64,
+    max_attempts: Option<usize>,
+    rng: Mutex<StdRng>,
+}
+
+impl ExponentialBackoffJittered {
+    /// Create a new jittered exponential backoff strategy
+    ///
+    /// # Arguments
+    /// * `initial_delay` - The upper bound of the first delay
+    /// * `max_delay` - The maximum upper bound of any delay
+    /// * `max_attempts` - Maximum number of attempts (None = unlimited)
+    pub fn new(
+        initial_delay: Duration,
+        max_delay: Duration,
+        max_attempts: Option<usize>,
+    ) -> Self {
+        Self {
+            initial_delay,
+            max_delay,
+            multiplier: 2.0,
+            max_attempts,
+            rng: Mutex::new(StdRng::from_entropy()),
+        }
+    }
+
+    /// Growth factor per attempt (default 2.0, values below 1.0 are treated as 1.0)
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Seed the jitter RNG, making the delay sequence reproducible
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+        self
+    }
+
+    /// Upper bound of the delay for `attempt`, before jitter
+    pub fn computed_delay(&self, attempt: usize) -> Duration {
+        let factor = self.multiplier.powi(attempt.min(i32::MAX as usize) as i32);
+        let delay = self.initial_delay.as_secs_f64() * factor;
+        if delay.is_finite() && delay < self.max_delay.as_secs_f64() {
+            Duration::from_secs_f64(delay)
+        } else {
+            self.max_delay
+        }
+    }
+}
+
+impl ReconnectionStrategy for ExponentialBackoffJittered {
+    fn next_delay(&self, attempt: usize) -> Option<Duration> {
+        if !self.should_reconnect(attempt) {
+            return None;
+        }
+
+        let computed = self.computed_delay(attempt);
+        Some(computed.mul_f64(self.rng.lock().gen::<f64>()))
+    }
+
+    fn reset(&mut self) {
+        // No state to reset for exponential backoff
+    }
+
+    fn should_reconnect(&self, attempt: usize) -> bool {
+        self.max_attempts.is_none_or(|max| attempt < max)
+    }
+}
+
 /// Fixed delay reconnection strategy
 ///
 /// Always waits the same amount of time between reconnection attempts
@@ -136,3 +261,86 @@ impl ReconnectionStrategy for NeverReconnect {
         false
     }
 }
+
+/// Circuit breaker around another reconnection strategy
+///
+/// Counts connections that die within `min_uptime` of connecting. After
+/// `max_rapid_failures` of them in a row the circuit opens and reconnecting
+/// stops, so a server that closes every connection immediately can't keep
+/// the client spinning. A connection that outlives `min_uptime` clears the
+/// count. Delays otherwise come from the inner strategy.
+#[derive(Debug)]
+pub struct CircuitBreaker<S> {
+    inner: S,
+    min_uptime: Duration,
+    max_rapid_failures: usize,
+    rapid_failures: AtomicUsize,
+    open: AtomicBool,
+}
+
+impl<S: ReconnectionStrategy> CircuitBreaker<S> {
+    /// Wrap `inner`
+    ///
+    /// # Arguments
+    /// * `inner` - Strategy providing the delays while the circuit is closed
+    /// * `min_uptime` - Connections shorter than this count as rapid failures
+    /// * `max_rapid_failures` - Consecutive rapid failures that open the circuit
+    pub fn new(inner: S, min_uptime: Duration, max_rapid_failures: usize) -> Self {
+        Self {
+            inner,
+            min_uptime,
+            max_rapid_failures: max_rapid_failures.max(1),
+            rapid_failures: AtomicUsize::new(0),
+            open: AtomicBool::new(false),
+        }
+    }
+
+    /// Consecutive rapid failures seen so far
+    pub fn rapid_failures(&self) -> usize {
+        self.rapid_failures.load(Ordering::Acquire)
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<S: ReconnectionStrategy> ReconnectionStrategy for CircuitBreaker<S> {
+    fn next_delay(&self, attempt: usize) -> Option<Duration> {
+        if self.is_circuit_open() {
+            return None;
+        }
+        self.inner.next_delay(attempt)
+    }
+
+    fn next_delay_for(&self, attempt: usize, reason: DisconnectReason) -> Option<Duration> {
+        if self.is_circuit_open() {
+            return None;
+        }
+        self.inner.next_delay_for(attempt, reason)
+    }
+
+    fn reset(&mut self) {
+        self.rapid_failures.store(0, Ordering::Release);
+        self.open.store(false, Ordering::Release);
+        self.inner.reset();
+    }
+
+    fn should_reconnect(&self, attempt: usize) -> bool {
+        !self.is_circuit_open() && self.inner.should_reconnect(attempt)
+    }
+
+    fn record_uptime(&self, uptime: Duration) {
+        self.inner.record_uptime(uptime);
+
+        if uptime >= self.min_uptime {
+            self.rapid_failures.store(0, Ordering::Release);
+        } else if self.rapid_failures.fetch_add(1, Ordering::AcqRel) + 1 >= self.max_rapid_failures {
+            self.open.store(true, Ordering::Release);
+        }
+    }
+
+    fn is_circuit_open(&self) -> bool {
+        self.open.load(Ordering::Acquire) || self.inner.is_circuit_open()
+    }
+}
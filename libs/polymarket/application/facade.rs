@@ -10,7 +10,7 @@ use crate::infrastructure::{
     init_tracing, init_tracing_with_level, Heartbeat, MarketDatabase, ShutdownManager,
 };
 use std::sync::Arc;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 // Note: Configuration and tracking services are now in application::sniper module
 
@@ -133,24 +133,27 @@ impl EventSyncApp {
                 // Process markets for this event (inheriting tags, description, and game_id from parent event)
                 if let Some(markets) = &event.markets {
                     for market in markets {
-                        if let Ok(db_market) =
-                            crate::application::sync::EventSyncService::market_to_db_market(
-                                market,
-                                event_tags_json.clone(),
-                                event_description.clone(),
-                                event_game_id,
-                            )
-                        {
-                            debug!(
-                                market_id = %db_market.id,
-                                question = %db_market.question,
-                                event_id = %event_id,
-                                "Processing market"
-                            );
-
-                            // Collect link
-                            event_market_links.push((event_id.clone(), db_market.id.clone()));
-                            db_markets.push(db_market);
+                        match crate::application::sync::EventSyncService::market_to_db_market(
+                            market,
+                            event_tags_json.clone(),
+                            event_description.clone(),
+                            event_game_id,
+                        ) {
+                            Ok(db_market) => {
+                                debug!(
+                                    market_id = %db_market.id,
+                                    question = %db_market.question,
+                                    event_id = %event_id,
+                                    "Processing market"
+                                );
+
+                                // Collect link
+                                event_market_links.push((event_id.clone(), db_market.id.clone()));
+                                db_markets.push(db_market);
+                            }
+                            Err(e) => {
+                                warn!(event_id = %event_id, error = %e, "Skipping malformed market");
+                            }
                         }
                     }
                 }
@@ -165,12 +168,18 @@ impl EventSyncApp {
             debug!(count = events_upserted, "Batch upserted events");
 
             // Batch upsert all markets
-            let markets_upserted = self
+            let market_counts = self
                 .sync_service
                 .database
                 .batch_upsert_markets(&db_markets)
                 .await?;
-            debug!(count = markets_upserted, "Batch upserted markets");
+            let markets_upserted = market_counts.written();
+            debug!(
+                inserted = market_counts.inserted,
+                updated = market_counts.updated,
+                unchanged = market_counts.unchanged,
+                "Batch upserted markets"
+            );
 
             // Batch link events to markets
             let links_created = self
@@ -20,8 +20,8 @@ pub use sync::{EventSyncService, MarketSyncService};
 
 // Re-export pluggable strategies system
 pub use strategies::{
-    create_strategy, Strategy, StrategyContext, StrategyError, StrategyResult,
-    StrategyType, UpOrDownStrategy,
+    create_strategy, Strategy, StrategyContext, StrategyError, StrategyMetrics, StrategyResult,
+    StrategySummary, StrategyType, UpOrDownStrategy,
 };
 
 // Re-export infrastructure managers
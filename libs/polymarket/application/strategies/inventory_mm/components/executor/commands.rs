@@ -26,8 +26,9 @@ pub enum ExecutorCommand {
     /// Place a single limit order
     PlaceLimit(LimitOrder),
 
-    /// Execute a merge (convert YES+NO tokens to USDC)
-    Merge { condition_id: String, amount: f64 },
+    /// Execute a merge (convert YES+NO tokens to USDC), reported done once it
+    /// has `min_confirmations` confirmations
+    Merge { condition_id: String, amount: f64, min_confirmations: usize },
 
     /// Graceful shutdown
     Shutdown,
@@ -8,10 +8,12 @@ use tokio::runtime::Runtime;
 use tracing::{info, warn, error, debug};
 
 use super::commands::{ExecutorCommand, ExecutorResult};
+use super::rate_limiter::OrderRateLimiter;
 use crate::application::strategies::inventory_mm::types::{SolverOutput, LimitOrder, Side};
+use crate::application::strategies::StrategyMetrics;
 use crate::infrastructure::client::clob::TradingClient;
 use crate::infrastructure::client::ctf::{merge as ctf_merge, usdc_to_raw};
-use crate::infrastructure::SharedOrderState;
+use crate::infrastructure::{MarketHalts, OwnBalanceFlow, SharedOrderState, SharedPositionTracker};
 
 /// Lightweight executor handle for quoters (Clone-able).
 /// Does NOT have shutdown capability - only main strategy can shutdown.
@@ -75,9 +77,9 @@ impl QuoterExecutorHandle {
     }
 
     /// Execute a merge (convert YES+NO tokens to USDC).
-    pub fn merge(&self, condition_id: String, amount: f64) -> Result<(), ExecutorError> {
+    pub fn merge(&self, condition_id: String, amount: f64, min_confirmations: usize) -> Result<(), ExecutorError> {
         self.command_tx
-            .send(ExecutorCommand::Merge { condition_id, amount })
+            .send(ExecutorCommand::Merge { condition_id, amount, min_confirmations })
             .map_err(|_| ExecutorError::ChannelClosed)
     }
 }
@@ -129,8 +131,8 @@ impl ExecutorHandle {
     }
 
     /// Execute a merge (convert YES+NO tokens to USDC)
-    pub fn merge(&self, condition_id: String, amount: f64) -> Result<(), ExecutorError> {
-        self.send(ExecutorCommand::Merge { condition_id, amount })
+    pub fn merge(&self, condition_id: String, amount: f64, min_confirmations: usize) -> Result<(), ExecutorError> {
+        self.send(ExecutorCommand::Merge { condition_id, amount, min_confirmations })
     }
 
     /// Shutdown the executor gracefully with timeout
@@ -189,6 +191,16 @@ pub struct Executor {
     /// This fixes the issue where WebSocket CANCELLATION messages are delayed/dropped
     /// causing the OMS to keep stale "Open" order status.
     order_state: Option<SharedOrderState>,
+    /// Optional cap on placements per window (per market, since each quoter owns its executor)
+    rate_limiter: Option<OrderRateLimiter>,
+    /// Strategy metrics credited with placements and confirmed merges
+    metrics: Option<Arc<StrategyMetrics>>,
+    /// Kill switches and the condition id of the market this executor quotes
+    market_halt: Option<(MarketHalts, String)>,
+    /// Credited with confirmed merge proceeds so the balance watcher ignores them
+    balance_flow: Option<OwnBalanceFlow>,
+    /// Position tracker and the market's (up, down) token ids, reduced by confirmed merges
+    position_tracker: Option<(SharedPositionTracker, (String, String))>,
 }
 
 impl Executor {
@@ -202,6 +214,24 @@ impl Executor {
     /// When the REST API confirms cancellations, the executor will update the OMS directly
     /// instead of waiting for WebSocket CANCELLATION messages (which may be delayed/dropped).
     pub fn spawn_with_order_state(trading: Arc<TradingClient>, order_state: Option<SharedOrderState>) -> ExecutorHandle {
+        Self::spawn_with_rate_limit(trading, order_state, None, None, None, None, None)
+    }
+
+    /// Spawn the executor with an optional placement rate limiter.
+    /// Limit orders beyond the limiter's cap are rejected with a `rate_limit` error.
+    /// Placements and confirmed merges are recorded into `metrics` when given.
+    /// While `market_halt` reports its market halted, limit orders are rejected
+    /// with a `halted` error. Confirmed merge proceeds are recorded to `balance_flow`,
+    /// and the merged pairs are taken off both legs in `position_tracker`.
+    pub fn spawn_with_rate_limit(
+        trading: Arc<TradingClient>,
+        order_state: Option<SharedOrderState>,
+        rate_limiter: Option<OrderRateLimiter>,
+        metrics: Option<Arc<StrategyMetrics>>,
+        market_halt: Option<(MarketHalts, String)>,
+        balance_flow: Option<OwnBalanceFlow>,
+        position_tracker: Option<(SharedPositionTracker, (String, String))>,
+    ) -> ExecutorHandle {
         let (command_tx, command_rx) = unbounded();
 
         let runtime = Runtime::new().expect("Failed to create tokio runtime");
@@ -211,6 +241,11 @@ impl Executor {
             trading,
             runtime,
             order_state,
+            rate_limiter,
+            metrics,
+            market_halt,
+            balance_flow,
+            position_tracker,
         };
 
         let thread_handle = thread::Builder::new()
@@ -227,7 +262,7 @@ impl Executor {
     }
 
     /// Main run loop - blocks on channel, processes commands
-    fn run(self) {
+    fn run(mut self) {
         info!("[Executor] Started on thread {:?}", thread::current().id());
 
         loop {
@@ -266,7 +301,7 @@ impl Executor {
     }
 
     /// Process a single command
-    fn process_command(&self, command: ExecutorCommand) -> ExecutorResult {
+    fn process_command(&mut self, command: ExecutorCommand) -> ExecutorResult {
         let mut result = ExecutorResult::new();
 
         match command {
@@ -315,15 +350,26 @@ impl Executor {
                 result.merge(self.execute_limits(&[order]));
             }
 
-            ExecutorCommand::Merge { condition_id, amount } => {
+            ExecutorCommand::Merge { condition_id, amount, min_confirmations } => {
                 if amount <= 0.0 {
                     result.add_error("merge", format!("Invalid merge amount: {}", amount));
                     error!("[Executor] Invalid merge amount: {}", amount);
                 } else {
                     let raw_amount = usdc_to_raw(amount);
-                    match self.runtime.block_on(ctf_merge(&condition_id, false, raw_amount)) {
+                    // Blocks until confirmed so merge_tx is only reported for a settled merge
+                    match self.runtime.block_on(ctf_merge(&condition_id, false, raw_amount, min_confirmations)) {
                         Ok(tx_hash) => {
                             result.merge_tx = Some(format!("{:x}", tx_hash));
+                            if let Some(ref metrics) = self.metrics {
+                                metrics.record_merge(amount);
+                            }
+                            // Each merged pair pays out $1.00
+                            if let Some(ref flow) = self.balance_flow {
+                                flow.record(amount);
+                            }
+                            if let Some((ref tracker, (ref up, ref down))) = self.position_tracker {
+                                tracker.write().apply_merge(up, down, amount);
+                            }
                             info!("[Executor] Merge tx: {:x}", tx_hash);
                         }
                         Err(e) => {
@@ -418,31 +464,55 @@ impl Executor {
     }
 
     /// Execute limit orders individually (more reliable than batch)
-    fn execute_limits(&self, orders: &[LimitOrder]) -> ExecutorResult {
+    fn execute_limits(&mut self, orders: &[LimitOrder]) -> ExecutorResult {
         let mut result = ExecutorResult::new();
         if orders.is_empty() {
             return result;
         }
 
+        if let Some((halts, condition_id)) = &self.market_halt {
+            if halts.is_halted(condition_id) {
+                for order in orders {
+                    let token_short = &order.token_id[..8.min(order.token_id.len())];
+                    result.add_error("halted", format!("{}: market {} is halted", token_short, condition_id));
+                }
+                warn!("[Executor] ✗ Rejected {} orders: market {} is halted", orders.len(), condition_id);
+                return result;
+            }
+        }
+
         debug!("[Executor] Placing {} limit orders individually", orders.len());
 
         // Place each order individually for reliability
         for order in orders {
             let token_short = &order.token_id[..8.min(order.token_id.len())];
 
+            if let Some(limiter) = self.rate_limiter.as_mut() {
+                if let Err(e) = limiter.try_acquire(Instant::now()) {
+                    result.add_error("rate_limit", format!("{}: {}", token_short, e));
+                    warn!(
+                        "[Executor] ✗ Rejected {} @ ${:.2}: {}",
+                        token_short, order.price, e
+                    );
+                    continue;
+                }
+            }
+
             info!(
                 "[Executor] Placing: {} @ ${:.2} for {:.1} shares",
                 token_short, order.price, order.size
             );
 
-            // Use place_order_with_fee with 1000 bps (10%) for 15-min crypto markets
-            // Polymarket requires feeRateBps in signed orders even for maker orders
+            // Quotes are post-only: a book that moved under us must reject the
+            // order rather than fill it as a taker.
+            // 1000 bps (10%) fee for 15-min crypto markets - Polymarket requires
+            // feeRateBps in signed orders even for maker orders
             let side = match order.side {
                 Side::Buy => crate::infrastructure::client::clob::Side::Buy,
                 Side::Sell => crate::infrastructure::client::clob::Side::Sell,
             };
             let place_result = self.runtime.block_on(
-                self.trading.place_order_with_fee(
+                self.trading.place_post_only_order(
                     &order.token_id,
                     order.price,
                     order.size,
@@ -456,6 +526,9 @@ impl Executor {
                 Ok(response) => {
                     if response.success {
                         result.placed_count += 1;
+                        if let Some(ref metrics) = self.metrics {
+                            metrics.record_order();
+                        }
                         if let Some(ref order_id) = response.order_id {
                             result.placed_ids.push(order_id.clone());
 
@@ -492,6 +565,14 @@ impl Executor {
                         );
                     }
                 }
+                Err(e) if e.is_post_only_rejection() => {
+                    // The book moved through our price; the next solve requotes
+                    result.add_error("post_only", format!("{}: {}", token_short, e));
+                    warn!(
+                        "[Executor] ✗ Post-only {} @ ${:.2} would cross the book, skipped",
+                        token_short, order.price
+                    );
+                }
                 Err(e) => {
                     result.add_error("place_limit", format!("{}: {}", token_short, e));
                     error!(
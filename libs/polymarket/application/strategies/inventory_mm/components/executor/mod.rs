@@ -2,6 +2,8 @@
 
 mod executor;
 mod commands;
+mod rate_limiter;
 
 pub use executor::{Executor, ExecutorHandle, QuoterExecutorHandle, ExecutorError};
 pub use commands::{ExecutorCommand, ExecutorResult};
+pub use rate_limiter::{OrderRateLimiter, RateLimitExceeded};
@@ -0,0 +1,107 @@
+//! Sliding-window order rate limiter.
+//!
+//! Each quoter owns its executor, so a limiter on the executor caps placements
+//! per market ("at most N orders per minute") and stops a single market from
+//! overtrading.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Placement rejected because the market hit its order rate cap
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimitExceeded {
+    pub max_orders: usize,
+    pub window: Duration,
+    /// Time until the oldest placement leaves the window
+    pub retry_after: Duration,
+}
+
+impl std::fmt::Display for RateLimitExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "rate limit reached ({} orders per {}s), retry in {}ms",
+            self.max_orders,
+            self.window.as_secs(),
+            self.retry_after.as_millis()
+        )
+    }
+}
+
+impl std::error::Error for RateLimitExceeded {}
+
+/// Counts placements over a sliding window and rejects those beyond the cap
+#[derive(Debug, Clone)]
+pub struct OrderRateLimiter {
+    max_orders: usize,
+    window: Duration,
+    placements: VecDeque<Instant>,
+}
+
+impl OrderRateLimiter {
+    pub fn new(max_orders: usize, window: Duration) -> Self {
+        Self {
+            max_orders,
+            window,
+            placements: VecDeque::with_capacity(max_orders),
+        }
+    }
+
+    /// At most `max_orders` placements per minute
+    pub fn per_minute(max_orders: usize) -> Self {
+        Self::new(max_orders, Duration::from_secs(60))
+    }
+
+    /// Record a placement at `now`, or reject it if the window is full
+    pub fn try_acquire(&mut self, now: Instant) -> Result<(), RateLimitExceeded> {
+        while let Some(&oldest) = self.placements.front() {
+            if now.duration_since(oldest) >= self.window {
+                self.placements.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        if self.placements.len() >= self.max_orders {
+            let retry_after = self
+                .placements
+                .front()
+                .map(|&oldest| self.window.saturating_sub(now.duration_since(oldest)))
+                .unwrap_or(self.window);
+            return Err(RateLimitExceeded {
+                max_orders: self.max_orders,
+                window: self.window,
+                retry_after,
+            });
+        }
+
+        self.placements.push_back(now);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_beyond_cap_until_window_advances() {
+        let mut limiter = OrderRateLimiter::per_minute(3);
+        let t0 = Instant::now();
+
+        for i in 0..3 {
+            assert!(limiter.try_acquire(t0 + Duration::from_secs(i * 10)).is_ok());
+        }
+
+        let err = limiter.try_acquire(t0 + Duration::from_secs(30)).unwrap_err();
+        assert_eq!(err.retry_after, Duration::from_secs(30));
+        assert!(err.to_string().contains("3 orders per 60s"));
+
+        // Still inside the window of the first placement
+        assert!(limiter.try_acquire(t0 + Duration::from_secs(59)).is_err());
+
+        // First placement expires, one slot frees up
+        assert!(limiter.try_acquire(t0 + Duration::from_secs(60)).is_ok());
+        assert!(limiter.try_acquire(t0 + Duration::from_secs(61)).is_err());
+    }
+}
@@ -2,4 +2,4 @@
 
 pub mod tracker;
 
-pub use tracker::{InFlightTracker, OpenOrderInfo, price_to_key};
+pub use tracker::{InFlightReconcileResult, InFlightTracker, OpenOrderInfo, price_to_key};
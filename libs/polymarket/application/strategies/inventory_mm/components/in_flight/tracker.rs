@@ -1,8 +1,8 @@
 //! In-flight order tracker to prevent duplicate commands.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::time::{Duration, Instant};
-use tracing::debug;
+use tracing::{debug, warn};
 
 /// Converts price to integer key for HashMap (avoids float comparison issues)
 /// 0.7823 → 7823
@@ -29,16 +29,38 @@ pub struct InFlightTracker {
     /// Pending placements: (token_id, price_key) → sent timestamp
     pending_placements: HashMap<(String, i64), Instant>,
 
+    /// Levels resting on the exchange that the OMS hasn't seen (missed ack),
+    /// as of the last `reconcile_with_exchange`
+    unacked_levels: HashSet<(String, i64)>,
+
     /// Time-to-live for pending entries
     ttl: Duration,
 }
 
+/// Outcome of `InFlightTracker::reconcile_with_exchange`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InFlightReconcileResult {
+    /// Pending placements the exchange has no order for
+    pub cleared_placements: usize,
+    /// Pending cancels for orders the exchange no longer has
+    pub cleared_cancels: usize,
+    /// Exchange levels re-added because nothing tracked them
+    pub readded_levels: usize,
+}
+
+impl InFlightReconcileResult {
+    pub fn has_changes(&self) -> bool {
+        self.cleared_placements + self.cleared_cancels + self.readded_levels > 0
+    }
+}
+
 impl InFlightTracker {
     /// Create a new tracker with the specified TTL.
     pub fn new(ttl: Duration) -> Self {
         Self {
             pending_cancels: HashMap::new(),
             pending_placements: HashMap::new(),
+            unacked_levels: HashSet::new(),
             ttl,
         }
     }
@@ -162,6 +184,7 @@ impl InFlightTracker {
     /// capacity until TTL expires, blocking new placements at different prices.
     pub fn placement_cancelled(&mut self, token_id: &str, price: f64) {
         let key = (token_id.to_string(), price_to_key(price));
+        self.unacked_levels.remove(&key);
         if self.pending_placements.remove(&key).is_some() {
             debug!(
                 "[InFlight] Placement cleared (cancelled): token={}, price={:.2}",
@@ -176,6 +199,7 @@ impl InFlightTracker {
     /// and block new placements, causing severe imbalance issues.
     pub fn placement_filled(&mut self, token_id: &str, price: f64) {
         let key = (token_id.to_string(), price_to_key(price));
+        self.unacked_levels.remove(&key);
         if self.pending_placements.remove(&key).is_some() {
             debug!(
                 "[InFlight] Placement cleared (filled): token={}, price={:.2}",
@@ -293,6 +317,9 @@ impl InFlightTracker {
             should_keep
         });
 
+        // The OMS tracks these levels again, no need to hold them here
+        self.unacked_levels.retain(|level| !open_price_levels.contains(level));
+
         let removed_placements = before_placements - self.pending_placements.len();
         if removed_placements > 0 {
             debug!(
@@ -302,6 +329,75 @@ impl InFlightTracker {
         }
     }
 
+    /// Reconcile against the exchange's open orders (REST is authoritative).
+    ///
+    /// A missed ack leaves a pending entry holding a slot until TTL, and a
+    /// missed WebSocket placement leaves a live order the OMS never counts.
+    /// This fixes both:
+    /// 1. Pending placements sent before `queried_at` (minus grace) with no
+    ///    exchange order at that level are cleared
+    /// 2. Pending cancels for orders the exchange no longer has are cleared
+    /// 3. Exchange levels nothing tracks are re-added, so they count toward
+    ///    capacity until the OMS sees them or the next reconciliation
+    ///
+    /// `queried_at` is when the REST request was sent; entries registered
+    /// after that could not have been in the response and are left alone.
+    /// `oms_orders` are the open orders the OMS already counts; their levels
+    /// are never re-added, so they aren't counted twice.
+    pub fn reconcile_with_exchange(
+        &mut self,
+        exchange_orders: &[OpenOrderInfo],
+        oms_orders: &[OpenOrderInfo],
+        queried_at: Instant,
+    ) -> InFlightReconcileResult {
+        let grace_period = Duration::from_millis(1500);
+        let exchange_levels: HashSet<(String, i64)> = exchange_orders
+            .iter()
+            .map(|o| (o.token_id.clone(), price_to_key(o.price)))
+            .collect();
+        let exchange_oids: HashSet<&str> = exchange_orders
+            .iter()
+            .map(|o| o.order_id.as_str())
+            .collect();
+
+        let before_placements = self.pending_placements.len();
+        self.pending_placements.retain(|level, sent_at| {
+            let in_response = *sent_at + grace_period <= queried_at;
+            !in_response || exchange_levels.contains(level)
+        });
+        let cleared_placements = before_placements - self.pending_placements.len();
+
+        let before_cancels = self.pending_cancels.len();
+        self.pending_cancels.retain(|oid, sent_at| {
+            *sent_at > queried_at || exchange_oids.contains(oid.as_str())
+        });
+        let cleared_cancels = before_cancels - self.pending_cancels.len();
+
+        let oms_levels: HashSet<(String, i64)> = oms_orders
+            .iter()
+            .map(|o| (o.token_id.clone(), price_to_key(o.price)))
+            .collect();
+        let previous = std::mem::take(&mut self.unacked_levels);
+        self.unacked_levels = exchange_levels
+            .into_iter()
+            .filter(|level| !self.pending_placements.contains_key(level) && !oms_levels.contains(level))
+            .collect();
+        let readded_levels = self.unacked_levels.difference(&previous).count();
+
+        let result = InFlightReconcileResult {
+            cleared_placements,
+            cleared_cancels,
+            readded_levels,
+        };
+        if result.has_changes() {
+            warn!(
+                "[InFlight] Reconciled with exchange: cleared {} placements, {} cancels; re-added {} levels",
+                cleared_placements, cleared_cancels, readded_levels
+            );
+        }
+        result
+    }
+
     /// Clear ALL pending placements and cancels.
     /// Use this after nuclear cancel to reset state.
     pub fn clear_all_pending(&mut self) {
@@ -309,6 +405,7 @@ impl InFlightTracker {
         let placement_count = self.pending_placements.len();
         self.pending_cancels.clear();
         self.pending_placements.clear();
+        self.unacked_levels.clear();
         debug!(
             "[InFlight] Cleared ALL pending: {} cancels, {} placements",
             cancel_count, placement_count
@@ -320,6 +417,7 @@ impl InFlightTracker {
     pub fn clear_pending_for_token(&mut self, token_id: &str) {
         let before = self.pending_placements.len();
         self.pending_placements.retain(|(tid, _), _| tid != token_id);
+        self.unacked_levels.retain(|(tid, _)| tid != token_id);
         let removed = before - self.pending_placements.len();
         if removed > 0 {
             debug!(
@@ -354,7 +452,8 @@ impl InFlightTracker {
 
     /// Get pending price levels (as price keys) for a specific token (not expired).
     /// Used for capacity checking - these levels should count toward the max level cap
-    /// even if they haven't appeared in OMS yet.
+    /// even if they haven't appeared in OMS yet. Includes levels re-added by
+    /// `reconcile_with_exchange`.
     pub fn pending_price_levels_for_token(&self, token_id: &str) -> std::collections::HashSet<i64> {
         let now = Instant::now();
         self.pending_placements.iter()
@@ -362,6 +461,11 @@ impl InFlightTracker {
                 tid == token_id && now.duration_since(**sent_at) < self.ttl
             })
             .map(|((_, price_key), _)| *price_key)
+            .chain(
+                self.unacked_levels.iter()
+                    .filter(|(tid, _)| tid == token_id)
+                    .map(|(_, price_key)| *price_key),
+            )
             .collect()
     }
 }
@@ -663,6 +767,74 @@ mod tests {
         assert!(!tracker.is_placement_pending("up_token", 0.55));
     }
 
+    #[test]
+    fn test_reconcile_clears_stale_in_flight_entries() {
+        let mut tracker = InFlightTracker::new(Duration::from_secs(10));
+
+        // Acks for these never arrived; the exchange has no such orders
+        tracker.should_place("up_token", 0.55);
+        tracker.should_cancel("order-gone");
+        tracker.should_cancel("order-live");
+        let queried_at = Instant::now() + Duration::from_secs(2);
+
+        let exchange = vec![OpenOrderInfo::new("order-live".into(), "up_token".into(), 0.50)];
+        let result = tracker.reconcile_with_exchange(&exchange, &[], queried_at);
+
+        assert_eq!(result.cleared_placements, 1);
+        assert_eq!(result.cleared_cancels, 1);
+        assert!(!tracker.is_placement_pending("up_token", 0.55));
+        assert!(!tracker.is_cancel_pending("order-gone"));
+        assert!(tracker.is_cancel_pending("order-live"));
+        assert!(tracker.should_place("up_token", 0.55));
+    }
+
+    #[test]
+    fn test_reconcile_keeps_recent_and_readds_untracked_levels() {
+        let mut tracker = InFlightTracker::new(Duration::from_secs(10));
+        let queried_at = Instant::now();
+        // Sent after the query went out, so it can't be in the response yet
+        tracker.should_place("up_token", 0.55);
+
+        // Resting on the exchange but never seen by the OMS
+        let exchange = vec![OpenOrderInfo::new("order-1".into(), "up_token".into(), 0.50)];
+        let result = tracker.reconcile_with_exchange(&exchange, &[], queried_at);
+
+        assert_eq!(result.cleared_placements, 0);
+        assert_eq!(result.readded_levels, 1);
+        assert!(tracker.is_placement_pending("up_token", 0.55));
+        let levels = tracker.pending_price_levels_for_token("up_token");
+        assert_eq!(levels, HashSet::from([5000, 5500]));
+
+        // Already tracked, so a repeat reconciliation doesn't count it again
+        assert_eq!(tracker.reconcile_with_exchange(&exchange, &[], queried_at).readded_levels, 0);
+
+        // Dropped once the OMS catches up
+        tracker.cleanup_from_orders(&exchange);
+        assert_eq!(
+            tracker.pending_price_levels_for_token("up_token"),
+            HashSet::from([5500])
+        );
+    }
+
+    #[test]
+    fn test_reconcile_skips_levels_the_oms_already_tracks() {
+        let mut tracker = InFlightTracker::new(Duration::from_secs(10));
+        let exchange = vec![
+            OpenOrderInfo::new("order-1".into(), "up_token".into(), 0.50),
+            OpenOrderInfo::new("order-2".into(), "up_token".into(), 0.48),
+        ];
+        // The OMS already counts order-1
+        let oms = vec![exchange[0].clone()];
+
+        let result = tracker.reconcile_with_exchange(&exchange, &oms, Instant::now());
+
+        assert_eq!(result.readded_levels, 1);
+        assert_eq!(
+            tracker.pending_price_levels_for_token("up_token"),
+            HashSet::from([4800])
+        );
+    }
+
     #[test]
     fn test_stats() {
         let mut tracker = InFlightTracker::new(Duration::from_secs(10));
@@ -1,5 +1,8 @@
 //! Merger component - monitors inventory and triggers merges.
 
+use std::sync::Arc;
+
+use parking_lot::RwLock;
 use tracing::{info, debug};
 
 use crate::application::strategies::inventory_mm::types::InventorySnapshot;
@@ -20,6 +23,23 @@ pub struct MergerConfig {
 
     /// Maximum combined avg cost (1.0 - min_profit_margin)
     pub max_combined_cost: f64,
+
+    /// Taker fee paid when the pairs were bought, in basis points of cost.
+    /// Average prices exclude fees, so they are netted out here.
+    pub fee_rate_bps: f64,
+
+    /// Gas cost of one merge transaction in USD, used until a live
+    /// estimate is available (see `MergeGasCost`)
+    pub gas_cost_usd: f64,
+
+    /// POL price in USD used to convert live gas estimates
+    pub pol_price_usd: f64,
+
+    /// How often the live merge gas estimate is refreshed (seconds)
+    pub gas_refresh_secs: u64,
+
+    /// Block confirmations a merge needs before it counts as settled
+    pub min_confirmations: usize,
 }
 
 impl Default for MergerConfig {
@@ -29,6 +49,11 @@ impl Default for MergerConfig {
             max_merge_imbalance: 0.3,
             min_profit_margin: 0.01,
             max_combined_cost: 0.99,
+            fee_rate_bps: 0.0,
+            gas_cost_usd: 0.005,
+            pol_price_usd: 0.25,
+            gas_refresh_secs: 60,
+            min_confirmations: 1,
         }
     }
 }
@@ -81,6 +106,30 @@ impl MergerConfig {
                 self.max_merge_imbalance
             ));
         }
+        if self.fee_rate_bps < 0.0 {
+            return Err(format!(
+                "fee_rate_bps must not be negative, got {}",
+                self.fee_rate_bps
+            ));
+        }
+        if self.gas_cost_usd < 0.0 {
+            return Err(format!(
+                "gas_cost_usd must not be negative, got {}",
+                self.gas_cost_usd
+            ));
+        }
+        if self.pol_price_usd < 0.0 {
+            return Err(format!(
+                "pol_price_usd must not be negative, got {}",
+                self.pol_price_usd
+            ));
+        }
+        if self.gas_refresh_secs == 0 {
+            return Err("gas_refresh_secs must be at least 1".to_string());
+        }
+        if self.min_confirmations == 0 {
+            return Err("min_confirmations must be at least 1".to_string());
+        }
         Ok(())
     }
 }
@@ -94,7 +143,7 @@ pub struct MergeDecision {
     /// Number of pairs to merge
     pub pairs_to_merge: f64,
 
-    /// Expected profit from merge
+    /// Expected profit from merge, net of fees and gas
     pub expected_profit: f64,
 
     /// Reason for decision (for logging)
@@ -121,19 +170,62 @@ impl MergeDecision {
     }
 }
 
+/// Latest live gas cost of one merge in USD, shared across quoters.
+///
+/// Empty until the first estimate arrives; mergers fall back to the
+/// configured `gas_cost_usd` meanwhile.
+#[derive(Debug, Clone, Default)]
+pub struct MergeGasCost(Arc<RwLock<Option<f64>>>);
+
+impl MergeGasCost {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store an estimate in POL (from `ctf::estimate_merge_gas_cost`),
+    /// converted at `pol_price_usd`
+    pub fn set_native(&self, cost_pol: f64, pol_price_usd: f64) {
+        *self.0.write() = Some(cost_pol * pol_price_usd);
+    }
+
+    /// Latest estimate in USD, if any
+    pub fn usd(&self) -> Option<f64> {
+        *self.0.read()
+    }
+}
+
 /// Merger component - pure decision logic for when to merge YES+NO tokens.
 /// Stateless: does not store market-specific info, only config.
 pub struct Merger {
     config: MergerConfig,
+    gas_cost: MergeGasCost,
 }
 
 impl Merger {
     pub fn new(config: MergerConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            gas_cost: MergeGasCost::new(),
+        }
+    }
+
+    /// Price merges with this live gas estimate instead of the configured one
+    pub fn with_gas_cost(mut self, gas_cost: MergeGasCost) -> Self {
+        self.gas_cost = gas_cost;
+        self
     }
 
-    /// Check if we should merge based on current inventory.
+    /// Check if we should merge based on current inventory, using the
+    /// live gas estimate, or the configured gas cost until there is one.
     pub fn check_merge(&self, inventory: &InventorySnapshot) -> MergeDecision {
+        let gas_cost_usd = self.gas_cost.usd().unwrap_or(self.config.gas_cost_usd);
+        self.check_merge_with_gas(inventory, gas_cost_usd)
+    }
+
+    /// Check if we should merge given a gas cost estimate in USD.
+    ///
+    /// Merges whose profit doesn't cover fees plus gas are skipped.
+    pub fn check_merge_with_gas(&self, inventory: &InventorySnapshot, gas_cost_usd: f64) -> MergeDecision {
         let delta = inventory.imbalance();
         let pairs = inventory.pairs_available();
         let combined_cost = inventory.combined_avg_cost();
@@ -167,16 +259,23 @@ impl Merger {
             ));
         }
 
-        // All checks pass - calculate profit and merge
-        let profit_per_pair = 1.0 - combined_cost;
-        let total_profit = pairs * profit_per_pair;
+        // Check 4: Profitable net of fees and gas?
+        let gross_profit = pairs * (1.0 - combined_cost);
+        let fees = pairs * combined_cost * self.config.fee_rate_bps / 10_000.0;
+        let net_profit = gross_profit - fees - gas_cost_usd;
+        if net_profit <= EPSILON {
+            return MergeDecision::no_merge(format!(
+                "Not profitable net of costs: gross ${:.4} - fees ${:.4} - gas ${:.4}",
+                gross_profit, fees, gas_cost_usd
+            ));
+        }
 
         info!(
-            "[Merger] Merge opportunity: {} pairs @ ${:.4} combined = ${:.4} profit",
-            pairs, combined_cost, total_profit
+            "[Merger] Merge opportunity: {} pairs @ ${:.4} combined = ${:.4} net profit (gas ${:.4})",
+            pairs, combined_cost, net_profit, gas_cost_usd
         );
 
-        MergeDecision::merge(pairs, total_profit)
+        MergeDecision::merge(pairs, net_profit)
     }
 
     /// Get config reference
@@ -278,4 +377,68 @@ mod tests {
         assert!(decision.should_merge);
         assert!((decision.expected_profit - 1.0).abs() < 0.01); // 50 * 0.02
     }
+
+    #[test]
+    fn test_check_merge_skips_when_gas_exceeds_profit() {
+        let merger = default_merger();
+        let inventory = InventorySnapshot {
+            up_size: 10.0,
+            up_avg_price: 0.52,
+            down_size: 10.0,
+            down_avg_price: 0.46, // Combined = 0.98, gross = 10 * 0.02 = $0.20
+        };
+
+        // Profitable gross and net of the default gas estimate
+        let decision = merger.check_merge(&inventory);
+        assert!(decision.should_merge);
+        assert!((decision.expected_profit - 0.195).abs() < 1e-9);
+
+        // A gas spike eats the whole profit
+        let decision = merger.check_merge_with_gas(&inventory, 0.25);
+        assert!(!decision.should_merge);
+        assert!(decision.reason.contains("Not profitable net of costs"));
+    }
+
+    #[test]
+    fn test_check_merge_uses_live_gas_estimate() {
+        let gas_cost = MergeGasCost::new();
+        let merger = default_merger().with_gas_cost(gas_cost.clone());
+        let inventory = InventorySnapshot {
+            up_size: 10.0,
+            up_avg_price: 0.52,
+            down_size: 10.0,
+            down_avg_price: 0.46, // gross = $0.20
+        };
+
+        // No estimate yet: the configured $0.005 applies
+        assert!((merger.check_merge(&inventory).expected_profit - 0.195).abs() < 1e-9);
+
+        // 0.1 POL at $0.25 = $0.025
+        gas_cost.set_native(0.1, 0.25);
+        assert!((merger.check_merge(&inventory).expected_profit - 0.175).abs() < 1e-9);
+
+        // 1 POL at $0.25 eats the $0.20 profit
+        gas_cost.set_native(1.0, 0.25);
+        let decision = merger.check_merge(&inventory);
+        assert!(!decision.should_merge);
+        assert!(decision.reason.contains("gas $0.2500"));
+    }
+
+    #[test]
+    fn test_check_merge_nets_out_fees() {
+        let merger = Merger::new(MergerConfig {
+            fee_rate_bps: 250.0, // 2.5% of the $9.80 cost basis = $0.245
+            ..MergerConfig::default()
+        });
+        let inventory = InventorySnapshot {
+            up_size: 10.0,
+            up_avg_price: 0.52,
+            down_size: 10.0,
+            down_avg_price: 0.46,
+        };
+
+        let decision = merger.check_merge_with_gas(&inventory, 0.0);
+        assert!(!decision.should_merge);
+        assert!(decision.reason.contains("fees $0.2450"));
+    }
 }
@@ -2,4 +2,4 @@
 
 mod merger;
 
-pub use merger::{Merger, MergerConfig, MergeDecision};
+pub use merger::{Merger, MergerConfig, MergeDecision, MergeGasCost};
@@ -8,8 +8,8 @@ pub mod taker;
 pub mod data_logger;
 
 pub use solver::solve;
-pub use executor::{Executor, ExecutorHandle, QuoterExecutorHandle, ExecutorError, ExecutorResult};
-pub use merger::{Merger, MergerConfig, MergeDecision};
-pub use in_flight::{InFlightTracker, OpenOrderInfo, price_to_key};
+pub use executor::{Executor, ExecutorHandle, QuoterExecutorHandle, ExecutorError, ExecutorResult, OrderRateLimiter};
+pub use merger::{Merger, MergerConfig, MergeDecision, MergeGasCost};
+pub use in_flight::{InFlightReconcileResult, InFlightTracker, OpenOrderInfo, price_to_key};
 pub use taker::{TakerTask, TakerConfig};
 pub use data_logger::{MarketDataLogger, MarketTick};
@@ -289,9 +289,46 @@ pub fn calculate_quotes(input: &SolverInput) -> QuoteLadder {
         debug!("[Solver] DOWN quotes skipped: skip_down=true (q={:.2})", q);
     }
 
+    // ═══════════════════════════════════════════════════════════════
+    // DEFENSIVE: CAPITAL CAP
+    // Keep total BUY notional across both ladders under max_committed
+    // ═══════════════════════════════════════════════════════════════
+    if config.max_committed > 0.0 {
+        apply_capital_cap(&mut ladder, config.max_committed);
+    }
+
     ladder
 }
 
+/// Scale every quote's size by the same factor so the ladder's total notional
+/// fits within `max_committed`.
+///
+/// Sizes are floored to whole shares to stay under the cap; quotes that fall
+/// below the minimum order size are dropped.
+fn apply_capital_cap(ladder: &mut QuoteLadder, max_committed: f64) {
+    let committed: f64 = ladder
+        .up_quotes
+        .iter()
+        .chain(ladder.down_quotes.iter())
+        .map(|quote| quote.price * quote.size)
+        .sum();
+    if committed <= max_committed {
+        return;
+    }
+
+    let scale = max_committed / committed;
+    info!(
+        "[Solver] Capital cap: ladder notional ${:.2} > ${:.2}, scaling sizes by {:.3}",
+        committed, max_committed, scale
+    );
+    for quotes in [&mut ladder.up_quotes, &mut ladder.down_quotes] {
+        for quote in quotes.iter_mut() {
+            quote.size = (quote.size * scale).floor();
+        }
+        quotes.retain(|quote| quote.size >= MIN_ORDER_SIZE);
+    }
+}
+
 /// Build a ladder of bids for a single token using 4-layer logic.
 ///
 /// Bids are calculated from best_bid (not best_ask) with offset subtracted.
@@ -424,6 +461,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_capital_cap_scales_ladder_proportionally() {
+        let mut input = default_input();
+        let uncapped = calculate_quotes(&input);
+        let notional = |ladder: &QuoteLadder| -> f64 {
+            ladder
+                .up_quotes
+                .iter()
+                .chain(ladder.down_quotes.iter())
+                .map(|q| q.price * q.size)
+                .sum()
+        };
+        let full = notional(&uncapped);
+        assert_eq!(uncapped.total_quotes(), 6);
+
+        // Half the budget: same levels, sizes halved
+        input.config.max_committed = full / 2.0;
+        let capped = calculate_quotes(&input);
+        assert_eq!(capped.total_quotes(), uncapped.total_quotes());
+        assert!(notional(&capped) <= full / 2.0);
+        for (before, after) in uncapped
+            .up_quotes
+            .iter()
+            .chain(uncapped.down_quotes.iter())
+            .zip(capped.up_quotes.iter().chain(capped.down_quotes.iter()))
+        {
+            assert_eq!(after.price, before.price);
+            assert_eq!(after.size, (before.size / 2.0).floor());
+        }
+
+        // A budget too small for any minimum-size order quotes nothing
+        input.config.max_committed = 1.0;
+        assert!(calculate_quotes(&input).is_empty());
+
+        // A generous cap leaves the ladder untouched
+        input.config.max_committed = full * 2.0;
+        assert_eq!(notional(&calculate_quotes(&input)), full);
+    }
+
     #[test]
     fn test_round_to_tick() {
         assert_eq!(round_to_tick(0.456, 0.01), 0.45);
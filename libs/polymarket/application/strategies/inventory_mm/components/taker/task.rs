@@ -10,6 +10,7 @@ const PRICE_EPSILON: f64 = 1e-4;
 
 use super::config::TakerConfig;
 use crate::application::strategies::inventory_mm::quoter::context::MarketInfo;
+use crate::application::strategies::StrategyMetrics;
 use crate::infrastructure::{
     SharedOrderbooks, SharedOrderState, SharedPositionTracker,
     UserOrderStatus as OrderStatus,
@@ -28,6 +29,8 @@ pub struct TakerTask {
     shutdown_flag: Arc<AtomicBool>,
     /// Tracks whether a FOK order is currently pending to prevent duplicate orders
     fok_pending: Arc<AtomicBool>,
+    /// Strategy-wide activity counters
+    metrics: Arc<StrategyMetrics>,
 }
 
 impl TakerTask {
@@ -50,9 +53,16 @@ impl TakerTask {
             orderbooks,
             shutdown_flag,
             fok_pending: Arc::new(AtomicBool::new(false)),
+            metrics: Arc::new(StrategyMetrics::new()),
         }
     }
 
+    /// Record FOK placements into the strategy's shared metrics.
+    pub fn with_metrics(mut self, metrics: Arc<StrategyMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     /// Main run loop - call from spawned task.
     pub async fn run(self) {
         if !self.config.enabled {
@@ -193,10 +203,14 @@ impl TakerTask {
         let token_id = underweight_token.clone();
         let market_desc = self.market.short_desc();
         let fok_pending = Arc::clone(&self.fok_pending);
+        let metrics = Arc::clone(&self.metrics);
 
         tokio::spawn(async move {
             let result = trading.buy_fok(&token_id, ask_price, take_size).await;
             fok_pending.store(false, Ordering::Release);
+            if matches!(&result, Ok(r) if r.order_id.is_some()) {
+                metrics.record_order();
+            }
 
             match result {
                 Ok(r) if r.status.as_deref() == Some("matched") => {
@@ -4,8 +4,14 @@ use serde::{Deserialize, Serialize};
 
 use super::components::merger::MergerConfig;
 use super::components::taker::TakerConfig;
+use super::strategy::MAX_MARKETS_PER_CATEGORY;
 use super::types::SolverConfig;
 
+/// Symbols and timeframes the sliding-window market query serves; any other
+/// spec would never match an active market
+const SUPPORTED_SYMBOLS: [&str; 2] = ["BTC", "ETH"];
+const SUPPORTED_TIMEFRAMES: [&str; 2] = ["15M", "1H"];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketSpec {
     pub symbol: String,
@@ -20,6 +26,31 @@ impl MarketSpec {
     pub fn new(symbol: impl Into<String>, timeframe: impl Into<String>, count: usize) -> Self {
         Self { symbol: symbol.into(), timeframe: timeframe.into(), count }
     }
+
+    /// Check that the spec names a market category the strategy can trade
+    pub fn validate(&self) -> Result<(), String> {
+        if !SUPPORTED_SYMBOLS.iter().any(|s| s.eq_ignore_ascii_case(&self.symbol)) {
+            return Err(format!(
+                "unsupported symbol '{}', expected one of: {}",
+                self.symbol,
+                SUPPORTED_SYMBOLS.join(", ")
+            ));
+        }
+        if !SUPPORTED_TIMEFRAMES.iter().any(|t| t.eq_ignore_ascii_case(&self.timeframe)) {
+            return Err(format!(
+                "unsupported timeframe '{}', expected one of: {}",
+                self.timeframe,
+                SUPPORTED_TIMEFRAMES.join(", ")
+            ));
+        }
+        if self.count == 0 || self.count as i64 > MAX_MARKETS_PER_CATEGORY {
+            return Err(format!(
+                "count must be in 1..={}, got {}",
+                MAX_MARKETS_PER_CATEGORY, self.count
+            ));
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -47,6 +78,10 @@ pub struct InventoryMMConfig {
     // === Data Logging (for backtesting) ===
     #[serde(default)]
     pub data_logging: DataLoggingConfig,
+
+    // === Risk ===
+    /// Maximum limit orders placed per minute per market (0 = unlimited)
+    pub max_orders_per_minute: usize,
 }
 
 /// Configuration for market data logging (for Python backtesting).
@@ -87,6 +122,7 @@ impl Default for InventoryMMConfig {
             merger: MergerConfig::default(),
             taker: TakerConfig::default(),
             data_logging: DataLoggingConfig::default(),
+            max_orders_per_minute: 0,
         }
     }
 }
@@ -112,11 +148,21 @@ impl InventoryMMConfig {
         self
     }
 
+    pub fn with_max_orders_per_minute(mut self, max_orders_per_minute: usize) -> Self {
+        self.max_orders_per_minute = max_orders_per_minute;
+        self
+    }
+
     pub fn with_order_size(mut self, order_size: f64) -> Self {
         self.solver.order_size = order_size;
         self
     }
 
+    pub fn with_max_committed(mut self, max_committed: f64) -> Self {
+        self.solver.max_committed = max_committed;
+        self
+    }
+
     pub fn with_base_spread(mut self, base_spread: f64) -> Self {
         self.solver.base_spread = base_spread;
         self
@@ -147,6 +193,45 @@ impl InventoryMMConfig {
         self
     }
 
+    /// Validate market specs and sizing; errors name the offending field
+    pub fn validate(&self) -> Result<(), String> {
+        if self.markets.is_empty() {
+            return Err("markets must not be empty".to_string());
+        }
+        for (i, spec) in self.markets.iter().enumerate() {
+            spec.validate().map_err(|e| format!("markets[{}]: {}", i, e))?;
+            let duplicate = self.markets[..i].iter().any(|other| {
+                other.symbol.eq_ignore_ascii_case(&spec.symbol)
+                    && other.timeframe.eq_ignore_ascii_case(&spec.timeframe)
+            });
+            if duplicate {
+                return Err(format!(
+                    "markets[{}]: duplicate spec for {} {}",
+                    i, spec.symbol, spec.timeframe
+                ));
+            }
+        }
+        if self.poll_interval_secs == 0 {
+            return Err("poll_interval_secs must be positive".to_string());
+        }
+        if self.tick_interval_ms == 0 {
+            return Err("tick_interval_ms must be positive".to_string());
+        }
+        if self.solver.order_size <= 0.0 {
+            return Err(format!(
+                "solver.order_size must be positive, got {}",
+                self.solver.order_size
+            ));
+        }
+        if self.solver.max_committed < 0.0 {
+            return Err(format!(
+                "solver.max_committed must not be negative, got {}",
+                self.solver.max_committed
+            ));
+        }
+        self.merger.validate().map_err(|e| format!("merger.{}", e))
+    }
+
     pub fn is_symbol_enabled(&self, symbol: &str) -> bool {
         self.markets.iter().any(|m| m.symbol.eq_ignore_ascii_case(symbol))
     }
@@ -192,4 +277,51 @@ mod tests {
         assert!(config.is_symbol_enabled("ETH"));
         assert!(!config.is_symbol_enabled("SOL"));
     }
+
+    #[test]
+    fn test_validate_accepts_valid_specs() {
+        let config = InventoryMMConfig::default().with_markets(vec![
+            MarketSpec::new("btc", "15m", 3),
+            MarketSpec::new("ETH", "1H", 1),
+        ]);
+        assert_eq!(config.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_rejects_malformed_specs() {
+        let cases = [
+            (MarketSpec::new("DOGE", "15M", 3), "markets[0]: unsupported symbol 'DOGE'"),
+            (MarketSpec::new("BTC", "4H", 3), "markets[0]: unsupported timeframe '4H'"),
+            (MarketSpec::new("BTC", "15M", 0), "markets[0]: count must be in 1..=5, got 0"),
+            (MarketSpec::new("BTC", "15M", 6), "markets[0]: count must be in 1..=5, got 6"),
+        ];
+        for (spec, expected) in cases {
+            let err = InventoryMMConfig::default()
+                .with_markets(vec![spec])
+                .validate()
+                .unwrap_err();
+            assert!(err.starts_with(expected), "{}", err);
+        }
+
+        let duplicate = InventoryMMConfig::default().with_markets(vec![
+            MarketSpec::new("BTC", "15M", 3),
+            MarketSpec::new("btc", "15m", 2),
+        ]);
+        assert_eq!(
+            duplicate.validate().unwrap_err(),
+            "markets[1]: duplicate spec for btc 15m"
+        );
+
+        let empty = InventoryMMConfig::default().with_markets(vec![]);
+        assert_eq!(empty.validate().unwrap_err(), "markets must not be empty");
+    }
+
+    #[test]
+    fn test_validate_rejects_non_positive_sizes() {
+        let err = InventoryMMConfig::default().with_order_size(0.0).validate().unwrap_err();
+        assert_eq!(err, "solver.order_size must be positive, got 0");
+
+        let err = InventoryMMConfig::default().with_min_merge_size(-1.0).validate().unwrap_err();
+        assert_eq!(err, "merger.min_merge_size must be positive, got -1");
+    }
 }
@@ -11,7 +11,7 @@ pub mod quoter;
 // Re-exports for convenience
 pub use config::{InventoryMMConfig, MarketSpec, DataLoggingConfig};
 pub use strategy::{InventoryMMStrategy, extract_solver_input};
-pub use components::{solve, Executor, ExecutorHandle, QuoterExecutorHandle, Merger, MergerConfig, MergeDecision, MarketDataLogger, MarketTick};
+pub use components::{solve, Executor, ExecutorHandle, QuoterExecutorHandle, Merger, MergerConfig, MergeDecision, MergeGasCost, MarketDataLogger, MarketTick};
 pub use types::{
     SolverInput, SolverOutput, SolverConfig,
     InventorySnapshot, OrderbookSnapshot, OrderSnapshot, OpenOrder,
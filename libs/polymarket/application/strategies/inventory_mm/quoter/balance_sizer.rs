@@ -0,0 +1,74 @@
+//! Balance-driven quote sizing.
+//!
+//! A deposit or withdrawal changes how much capital backs the quotes. The
+//! sizer follows the `BalanceManager` watcher and scales the base order size
+//! proportionally to the balance seen when the quoter started, so a quoter
+//! requotes with updated sizing whenever the wallet balance moves.
+
+use tokio::sync::watch;
+
+pub struct BalanceSizer {
+    rx: watch::Receiver<f64>,
+    base_order_size: f64,
+    /// Balance the base order size was configured for
+    reference_balance: f64,
+}
+
+impl BalanceSizer {
+    /// Create a sizer anchored at the receiver's current balance.
+    pub fn new(mut rx: watch::Receiver<f64>, base_order_size: f64) -> Self {
+        let reference_balance = *rx.borrow_and_update();
+        Self {
+            rx,
+            base_order_size,
+            reference_balance,
+        }
+    }
+
+    /// Order size for a given balance.
+    pub fn order_size_for(&self, balance: f64) -> f64 {
+        if self.reference_balance <= 0.0 {
+            return self.base_order_size;
+        }
+        (self.base_order_size * balance / self.reference_balance).max(0.0)
+    }
+
+    /// Returns the new order size if the balance changed since the last poll.
+    ///
+    /// `Some` means the quoter should requote.
+    pub fn poll(&mut self) -> Option<f64> {
+        if !self.rx.has_changed().unwrap_or(false) {
+            return None;
+        }
+        let balance = *self.rx.borrow_and_update();
+
+        // First non-zero balance (watcher started before the initial fetch)
+        if self.reference_balance <= 0.0 {
+            self.reference_balance = balance;
+        }
+        Some(self.order_size_for(balance))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_balance_change_schedules_requote() {
+        let (tx, rx) = watch::channel(1000.0);
+        let mut sizer = BalanceSizer::new(rx, 50.0);
+
+        // No change yet: nothing to do
+        assert_eq!(sizer.poll(), None);
+
+        // Withdrawal halves the balance -> requote at half size
+        tx.send(500.0).unwrap();
+        assert_eq!(sizer.poll(), Some(25.0));
+        assert_eq!(sizer.poll(), None);
+
+        // Deposit -> requote at larger size
+        tx.send(2000.0).unwrap();
+        assert_eq!(sizer.poll(), Some(100.0));
+    }
+}
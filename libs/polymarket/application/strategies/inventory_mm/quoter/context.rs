@@ -3,8 +3,11 @@
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use chrono::{DateTime, Utc};
+use tokio::sync::watch;
 
-use crate::infrastructure::{SharedOrderState, SharedPositionTracker, SharedOraclePrices};
+use crate::application::strategies::inventory_mm::components::MergeGasCost;
+use crate::application::strategies::StrategyMetrics;
+use crate::infrastructure::{MarketHalts, OwnBalanceFlow, SharedOrderState, SharedPositionTracker, SharedOraclePrices};
 use crate::infrastructure::client::clob::TradingClient;
 
 /// Information about a specific market that a Quoter is managing.
@@ -80,6 +83,16 @@ pub struct QuoterContext {
     pub shutdown_flag: Arc<AtomicBool>,
     /// Shared oracle prices (ChainLink + Binance feeds)
     pub oracle_prices: SharedOraclePrices,
+    /// Wallet balance changes (from `BalanceManager::spawn_watcher`); triggers requotes
+    pub balance_rx: Option<watch::Receiver<f64>>,
+    /// Our own USDC flow, netted out of `balance_rx`; executors credit merges to it
+    pub balance_flow: OwnBalanceFlow,
+    /// Strategy-wide activity counters (placements, merges)
+    pub metrics: Arc<StrategyMetrics>,
+    /// Per-market kill switches; executors place nothing on a halted market
+    pub market_halts: MarketHalts,
+    /// Live merge gas cost; merge checks use it once it has an estimate
+    pub merge_gas_cost: MergeGasCost,
 }
 
 impl QuoterContext {
@@ -96,9 +109,44 @@ impl QuoterContext {
             position_tracker,
             shutdown_flag,
             oracle_prices,
+            balance_rx: None,
+            balance_flow: OwnBalanceFlow::new(),
+            metrics: Arc::new(StrategyMetrics::new()),
+            market_halts: MarketHalts::new(),
+            merge_gas_cost: MergeGasCost::new(),
         }
     }
 
+    /// Requote with updated sizing whenever the wallet balance changes externally.
+    /// `balance_flow` is the flow the watcher nets out; confirmed merges are recorded to it.
+    pub fn with_balance_watcher(
+        mut self,
+        balance_rx: watch::Receiver<f64>,
+        balance_flow: OwnBalanceFlow,
+    ) -> Self {
+        self.balance_rx = Some(balance_rx);
+        self.balance_flow = balance_flow;
+        self
+    }
+
+    /// Record placements and merges into the strategy's shared metrics.
+    pub fn with_metrics(mut self, metrics: Arc<StrategyMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Consult the strategy's shared kill switches before placing.
+    pub fn with_market_halts(mut self, market_halts: MarketHalts) -> Self {
+        self.market_halts = market_halts;
+        self
+    }
+
+    /// Price merges with this shared live gas estimate.
+    pub fn with_merge_gas_cost(mut self, merge_gas_cost: MergeGasCost) -> Self {
+        self.merge_gas_cost = merge_gas_cost;
+        self
+    }
+
     pub fn is_running(&self) -> bool {
         self.shutdown_flag.load(std::sync::atomic::Ordering::Acquire)
     }
@@ -3,10 +3,12 @@
 //! Provides a simplified interface for quoters to subscribe to orderbook updates
 //! for their specific token pair (up_token, down_token).
 
+use std::collections::HashSet;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use anyhow::Result;
+use crossbeam_channel::{unbounded, Sender};
 use tracing::{debug, info, warn, trace};
 
 use hypersockets::core::*;
@@ -14,6 +16,7 @@ use hypersockets::{MessageHandler, MessageRouter, TextPongDetector, WsMessage};
 
 use crate::infrastructure::SharedOrderbooks;
 use crate::infrastructure::client::clob::orderbook::Orderbook;
+use crate::infrastructure::client::clob::sniper_ws::spawn_resync_responder;
 use crate::infrastructure::client::clob::sniper_ws_types::{
     BookSnapshot, MarketSubscription, PriceChangeEvent, SniperMessage,
 };
@@ -122,6 +125,10 @@ pub struct QuoterHandler {
     orderbooks: SharedOrderbooks,
     first_snapshot_received: Arc<AtomicBool>,
     message_count: u64,
+    /// Notified with asset ids whose book was dropped and needs a snapshot
+    resync_tx: Option<Sender<String>>,
+    /// Dropped books whose deltas are ignored until the next snapshot
+    awaiting_resync: HashSet<String>,
 }
 
 impl QuoterHandler {
@@ -135,6 +142,42 @@ impl QuoterHandler {
             orderbooks,
             first_snapshot_received,
             message_count: 0,
+            resync_tx: None,
+            awaiting_resync: HashSet::new(),
+        }
+    }
+
+    /// Send asset ids of dropped (crossed or otherwise invalid) books to `resync_tx`.
+    pub fn with_resync_notifier(mut self, resync_tx: Sender<String>) -> Self {
+        self.resync_tx = Some(resync_tx);
+        self
+    }
+
+    /// Withdraw invalid books so the solver never quotes off them, and ask for a resync.
+    fn drop_invalid_books<'a>(&mut self, asset_ids: impl Iterator<Item = &'a str>) {
+        let mut dropped = Vec::new();
+        {
+            let mut obs = self.orderbooks.write();
+            for asset_id in asset_ids {
+                let Some(Err(err)) = obs.get(asset_id).map(Orderbook::validate) else {
+                    continue;
+                };
+                obs.remove(asset_id);
+                warn!(
+                    "[QuoterWS {}] Invalid book for {}...: {}, dropping until resync",
+                    self.market_id,
+                    &asset_id[..8.min(asset_id.len())],
+                    err
+                );
+                dropped.push(asset_id);
+            }
+        }
+        for asset_id in dropped {
+            if self.awaiting_resync.insert(asset_id.to_string()) {
+                if let Some(ref tx) = self.resync_tx {
+                    let _ = tx.send(asset_id.to_string());
+                }
+            }
         }
     }
 
@@ -157,6 +200,7 @@ impl QuoterHandler {
                 .entry(snapshot.asset_id.clone())
                 .or_insert_with(|| Orderbook::new(snapshot.asset_id.clone()));
             orderbook.process_snapshot(&snapshot.bids, &snapshot.asks);
+            self.awaiting_resync.remove(&snapshot.asset_id);
 
             // Log orderbook state after processing
             debug!(
@@ -167,6 +211,8 @@ impl QuoterHandler {
                 orderbook.best_ask()
             );
         }
+        drop(obs);
+        self.drop_invalid_books(snapshots.iter().map(|s| s.asset_id.as_str()));
 
         self.first_snapshot_received.store(true, Ordering::Release);
     }
@@ -176,6 +222,9 @@ impl QuoterHandler {
     fn handle_price_change(&mut self, event: &PriceChangeEvent) {
         let mut obs = self.orderbooks.write();
         for change in &event.price_changes {
+            if self.awaiting_resync.contains(&change.asset_id) {
+                continue;
+            }
             let orderbook = obs
                 .entry(change.asset_id.clone())
                 .or_insert_with(|| Orderbook::new(change.asset_id.clone()));
@@ -188,6 +237,9 @@ impl QuoterHandler {
                 &change.best_ask,
             );
         }
+        drop(obs);
+
+        self.drop_invalid_books(event.price_changes.iter().map(|c| c.asset_id.as_str()));
     }
 }
 
@@ -246,12 +298,15 @@ pub async fn build_quoter_ws_client(
 ) -> Result<QuoterWsClient> {
     let first_snapshot_received = Arc::new(AtomicBool::new(false));
 
+    // Crossed books are dropped by the handler and resubscribed by a responder
+    let (resync_tx, resync_rx) = unbounded();
     let router = QuoterRouter::new(config.market_id.clone());
     let handler = QuoterHandler::new(
         config.market_id.clone(),
         orderbooks,
         Arc::clone(&first_snapshot_received),
-    );
+    )
+    .with_resync_notifier(resync_tx);
 
     let token_ids = config.token_ids();
     info!(
@@ -285,6 +340,8 @@ pub async fn build_quoter_ws_client(
         .build()
         .await?;
 
+    spawn_resync_responder(resync_rx, client.sender(), config.market_id.clone(), None);
+
     // Yield to allow the spawned client task to start running
     tokio::task::yield_now().await;
 
@@ -336,6 +393,76 @@ pub async fn wait_for_snapshot(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::infrastructure::client::clob::sniper_ws_types::PriceChange;
+    use crate::infrastructure::client::clob::types::PriceLevel;
+    use parking_lot::RwLock;
+    use std::collections::HashMap;
+
+    fn level(price: &str) -> PriceLevel {
+        PriceLevel {
+            price: price.to_string(),
+            size: "100".to_string(),
+        }
+    }
+
+    fn snapshot(asset_id: &str) -> BookSnapshot {
+        BookSnapshot {
+            market: "market-123".to_string(),
+            asset_id: asset_id.to_string(),
+            bids: vec![level("0.48"), level("0.49")],
+            asks: vec![level("0.52"), level("0.51")],
+            event_type: "book".to_string(),
+            timestamp: None,
+            hash: None,
+            last_trade_price: None,
+        }
+    }
+
+    fn bid(asset_id: &str, price: &str, best_ask: &str) -> PriceChangeEvent {
+        PriceChangeEvent {
+            market: "market-123".to_string(),
+            price_changes: vec![PriceChange {
+                asset_id: asset_id.to_string(),
+                price: price.to_string(),
+                size: "25".to_string(),
+                side: "BUY".to_string(),
+                hash: None,
+                best_bid: price.to_string(),
+                best_ask: best_ask.to_string(),
+            }],
+            timestamp: "0".to_string(),
+            event_type: "price_change".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_crossed_book_is_withheld_from_the_quoter_until_resynced() {
+        let (resync_tx, resync_rx) = unbounded();
+        let orderbooks: SharedOrderbooks = Arc::new(RwLock::new(HashMap::new()));
+        let mut handler = QuoterHandler::new(
+            "market-123".to_string(),
+            orderbooks.clone(),
+            Arc::new(AtomicBool::new(false)),
+        )
+        .with_resync_notifier(resync_tx);
+
+        handler.handle(SniperMessage::BookSnapshots(vec![snapshot("up")])).unwrap();
+        handler.handle(SniperMessage::PriceChange(bid("up", "0.50", "0.51"))).unwrap();
+        assert!(!orderbooks.read().get("up").unwrap().is_crossed());
+        assert!(resync_rx.try_recv().is_err());
+
+        // Bid through the ask: the book is withdrawn and one resync requested
+        handler.handle(SniperMessage::PriceChange(bid("up", "0.53", "0.51"))).unwrap();
+        assert!(orderbooks.read().get("up").is_none());
+        assert_eq!(resync_rx.try_recv().unwrap(), "up");
+
+        handler.handle(SniperMessage::PriceChange(bid("up", "0.50", "0.51"))).unwrap();
+        assert!(orderbooks.read().get("up").is_none());
+        assert!(resync_rx.try_recv().is_err());
+
+        handler.handle(SniperMessage::BookSnapshots(vec![snapshot("up")])).unwrap();
+        assert!(!orderbooks.read().get("up").unwrap().is_crossed());
+    }
 
     #[test]
     fn test_quoter_ws_config() {
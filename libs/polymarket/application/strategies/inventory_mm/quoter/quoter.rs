@@ -6,16 +6,18 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
+use futures::FutureExt;
 use parking_lot::RwLock;
 use tokio::task::JoinHandle;
 use tracing::{info, warn, debug, error};
 
+use super::balance_sizer::BalanceSizer;
 use super::context::{QuoterContext, MarketInfo};
 use super::orderbook_ws::{QuoterWsConfig, QuoterWsClient, build_quoter_ws_client, wait_for_snapshot};
 use crate::application::strategies::inventory_mm::components::{
     solve, Merger, MergerConfig, InFlightTracker, OpenOrderInfo, ExecutorError,
     TakerTask, TakerConfig, price_to_key,
-    Executor, ExecutorHandle, QuoterExecutorHandle,
+    Executor, ExecutorHandle, QuoterExecutorHandle, OrderRateLimiter,
     MarketDataLogger, MarketTick,
 };
 use crate::application::strategies::inventory_mm::types::{
@@ -23,8 +25,12 @@ use crate::application::strategies::inventory_mm::types::{
 };
 use crate::application::strategies::inventory_mm::config::DataLoggingConfig;
 use crate::infrastructure::{parse_timestamp_to_i64, SharedOrderbooks, UserOrderStatus as OrderStatus, OracleType};
+use crate::infrastructure::client::clob::{TradingClient, OpenOrderParams};
 use chrono::Utc;
 
+/// How often in-flight entries are reconciled against REST open orders
+const IN_FLIGHT_RECONCILE_INTERVAL: Duration = Duration::from_secs(30);
+
 enum TickResult {
     Continue,
     ExecutorDead,
@@ -41,6 +47,46 @@ fn count_distinct_price_levels(orders: &[OpenOrder]) -> usize {
     price_keys.len()
 }
 
+/// Fetch this market's open orders from REST for in-flight reconciliation.
+/// Returns None if either request fails (partial results would clear live entries).
+async fn fetch_exchange_orders(
+    trading: Arc<TradingClient>,
+    token_ids: [String; 2],
+) -> Option<Vec<OpenOrderInfo>> {
+    let mut orders = Vec::new();
+    for token_id in token_ids {
+        let params = OpenOrderParams {
+            asset_id: Some(token_id.clone()),
+            ..Default::default()
+        };
+        let fetched = match trading.get_orders(Some(&params)).await {
+            Ok(fetched) => fetched,
+            Err(e) => {
+                warn!("[Quoter] In-flight reconcile REST fetch failed: {}", e);
+                return None;
+            }
+        };
+        orders.extend(fetched.iter().filter_map(|o| {
+            let order_id = o.get("id")?.as_str()?.to_string();
+            let price = match o.get("price")? {
+                serde_json::Value::String(s) => s.parse().ok()?,
+                v => v.as_f64()?,
+            };
+            Some(OpenOrderInfo::new(order_id, token_id.clone(), price))
+        }));
+    }
+    Some(orders)
+}
+
+/// Open bids the OMS tracks for both tokens, as in-flight tracker entries.
+fn oms_open_orders(input: &SolverInput) -> Vec<OpenOrderInfo> {
+    input.up_orders.bids.iter()
+        .map(|o| OpenOrderInfo::new(o.order_id.clone(), input.up_token_id.clone(), o.price))
+        .chain(input.down_orders.bids.iter()
+            .map(|o| OpenOrderInfo::new(o.order_id.clone(), input.down_token_id.clone(), o.price)))
+        .collect()
+}
+
 /// Get distinct price levels from a list of orders.
 fn get_price_levels(orders: &[OpenOrder]) -> std::collections::HashSet<i64> {
     orders
@@ -73,6 +119,14 @@ pub struct Quoter {
     data_logger: Option<MarketDataLogger>,
     /// Dry-run mode: log data but don't execute any orders
     dry_run: bool,
+    /// Resizes quotes on wallet balance changes (if a balance watcher is wired)
+    balance_sizer: Option<BalanceSizer>,
+    /// Maximum limit orders per minute for this market (0 = unlimited)
+    max_orders_per_minute: usize,
+    /// Running REST fetch for in-flight reconciliation, with when it was sent
+    in_flight_reconcile: Option<(JoinHandle<Option<Vec<OpenOrderInfo>>>, Instant)>,
+    /// When the last in-flight reconciliation was started
+    last_in_flight_reconcile: Instant,
 }
 
 impl Quoter {
@@ -87,6 +141,10 @@ impl Quoter {
         ctx: QuoterContext,
         data_logging_config: DataLoggingConfig,
     ) -> Self {
+        let balance_sizer = ctx
+            .balance_rx
+            .clone()
+            .map(|rx| BalanceSizer::new(rx, config.order_size));
         Self {
             market,
             config,
@@ -96,7 +154,7 @@ impl Quoter {
             merge_cooldown_secs,
             orderbooks: Arc::new(RwLock::new(HashMap::new())),
             in_flight_tracker: InFlightTracker::with_default_ttl(),
-            merger: Merger::new(merger_config),
+            merger: Merger::new(merger_config).with_gas_cost(ctx.merge_gas_cost.clone()),
             merge_pending_until: None,
             ctx,
             last_logged_delta: None,
@@ -105,9 +163,19 @@ impl Quoter {
             dry_run: data_logging_config.dry_run,
             data_logging_config,
             data_logger: None,      // Created in run() if enabled
+            balance_sizer,
+            max_orders_per_minute: 0,
+            in_flight_reconcile: None,
+            last_in_flight_reconcile: Instant::now(),
         }
     }
 
+    /// Cap limit order placements for this market (0 = unlimited).
+    pub fn with_max_orders_per_minute(mut self, max_orders_per_minute: usize) -> Self {
+        self.max_orders_per_minute = max_orders_per_minute;
+        self
+    }
+
     /// Get the market info.
     pub fn market(&self) -> &MarketInfo {
         &self.market
@@ -154,9 +222,19 @@ impl Quoter {
 
         // 1. Spawn per-market executor (own thread for order execution)
         // This ensures markets don't block each other during execution
-        let executor_handle = Executor::spawn_with_order_state(
+        let rate_limiter = (self.max_orders_per_minute > 0)
+            .then(|| OrderRateLimiter::per_minute(self.max_orders_per_minute));
+        let executor_handle = Executor::spawn_with_rate_limit(
             Arc::clone(&self.ctx.trading),
             Some(self.ctx.order_state.clone()),
+            rate_limiter,
+            Some(Arc::clone(&self.ctx.metrics)),
+            Some((self.ctx.market_halts.clone(), self.market.condition_id.clone())),
+            Some(self.ctx.balance_flow.clone()),
+            Some((
+                self.ctx.position_tracker.clone(),
+                (self.market.up_token_id.clone(), self.market.down_token_id.clone()),
+            )),
         );
         self.executor = Some(executor_handle.quoter_handle());
         self.executor_handle = Some(executor_handle);
@@ -199,7 +277,8 @@ impl Quoter {
                 self.ctx.position_tracker.clone(),
                 Arc::clone(&self.orderbooks),
                 Arc::clone(&self.ctx.shutdown_flag),
-            );
+            )
+            .with_metrics(Arc::clone(&self.ctx.metrics));
             info!("[Quoter:{}] Spawning TakerTask", market_desc);
             Some(tokio::spawn(async move {
                 taker_task.run().await;
@@ -234,6 +313,13 @@ impl Quoter {
             // Build input from shared state
             let input = self.extract_input();
 
+            // Balance changed: pull resting quotes so the next tick requotes at the new size
+            if let Some(order_size) = self.balance_sizer.as_mut().and_then(|s| s.poll()) {
+                self.requote_with_size(&input, order_size);
+                tokio::time::sleep(tick_duration).await;
+                continue;
+            }
+
             // Log market tick data if data logging is enabled
             if self.data_logger.is_some() {
                 let oracle_price = self.get_oracle_price();
@@ -265,6 +351,8 @@ impl Quoter {
                 self.last_logged_delta = Some(delta);
             }
 
+            self.poll_in_flight_reconcile(&input);
+
             // Run tick
             match self.tick(&input) {
                 (Some(output), TickResult::Continue) => {
@@ -291,11 +379,81 @@ impl Quoter {
         }
 
         // Cleanup on exit
+        if let Some((handle, _)) = self.in_flight_reconcile.take() {
+            handle.abort();
+        }
         self.cleanup(Some(ws_client), taker_handle).await;
 
         info!("[Quoter:{}] Stopped", market_desc);
     }
 
+    /// Periodically reconcile the in-flight tracker with REST open orders.
+    ///
+    /// A missed ack can otherwise hold a slot until TTL, or leave a live order
+    /// the capacity check never sees. The fetch runs in the background; its
+    /// result is applied on the first tick after it completes.
+    fn poll_in_flight_reconcile(&mut self, input: &SolverInput) {
+        if self.dry_run {
+            return;
+        }
+
+        match self.in_flight_reconcile.take() {
+            Some((handle, queried_at)) if handle.is_finished() => {
+                if let Some(Ok(Some(orders))) = handle.now_or_never() {
+                    self.in_flight_tracker.reconcile_with_exchange(
+                        &orders,
+                        &oms_open_orders(input),
+                        queried_at,
+                    );
+                }
+            }
+            Some(pending) => self.in_flight_reconcile = Some(pending),
+            None if self.last_in_flight_reconcile.elapsed() >= IN_FLIGHT_RECONCILE_INTERVAL => {
+                let queried_at = Instant::now();
+                let handle = tokio::spawn(fetch_exchange_orders(
+                    Arc::clone(&self.ctx.trading),
+                    [self.market.up_token_id.clone(), self.market.down_token_id.clone()],
+                ));
+                self.in_flight_reconcile = Some((handle, queried_at));
+                self.last_in_flight_reconcile = queried_at;
+            }
+            None => {}
+        }
+    }
+
+    /// Apply a new base order size and cancel resting quotes so they are replaced.
+    fn requote_with_size(&mut self, input: &SolverInput, order_size: f64) {
+        let market_desc = self.market.short_desc();
+        info!(
+            "[Quoter:{}] Balance changed, requoting with order_size {:.2} (was {:.2})",
+            market_desc, order_size, self.config.order_size
+        );
+        self.config.order_size = order_size;
+
+        if self.dry_run {
+            return;
+        }
+
+        if !input.up_orders.bids.is_empty() {
+            if let Err(e) = self.executor().cancel_token_orders(self.market.up_token_id.clone()) {
+                warn!("[Quoter:{}] Failed to cancel UP orders for requote: {}", market_desc, e);
+            }
+            for order in &input.up_orders.bids {
+                self.in_flight_tracker.mark_cancel_pending(&order.order_id);
+                self.in_flight_tracker.placement_cancelled(&input.up_token_id, order.price);
+            }
+        }
+        if !input.down_orders.bids.is_empty() {
+            if let Err(e) = self.executor().cancel_token_orders(self.market.down_token_id.clone()) {
+                warn!("[Quoter:{}] Failed to cancel DOWN orders for requote: {}", market_desc, e);
+            }
+            for order in &input.down_orders.bids {
+                self.in_flight_tracker.mark_cancel_pending(&order.order_id);
+                self.in_flight_tracker.placement_cancelled(&input.down_token_id, order.price);
+            }
+        }
+    }
+
     /// Extract SolverInput from shared state.
     ///
     /// NOTE: This reads from OMS, position tracker, and orderbooks with separate locks.
@@ -506,12 +664,7 @@ impl Quoter {
             return (Some(SolverOutput::empty()), TickResult::Continue);
         }
 
-        let open_orders: Vec<OpenOrderInfo> = input.up_orders.bids.iter()
-            .map(|o| OpenOrderInfo::new(o.order_id.clone(), input.up_token_id.clone(), o.price))
-            .chain(input.down_orders.bids.iter()
-                .map(|o| OpenOrderInfo::new(o.order_id.clone(), input.down_token_id.clone(), o.price)))
-            .collect();
-        self.in_flight_tracker.cleanup_from_orders(&open_orders);
+        self.in_flight_tracker.cleanup_from_orders(&oms_open_orders(input));
 
         // POSITION-BASED CANCELLATION: When approaching max_position, cancel orders on that side
         // to stop the bleeding from fills. The solver only stops NEW placements but doesn't
@@ -818,6 +971,7 @@ impl Quoter {
                 match self.executor().merge(
                     self.market.condition_id.clone(),
                     decision.pairs_to_merge,
+                    self.merger.config().min_confirmations,
                 ) {
                     Ok(()) => {
                         self.merge_pending_until = Some(now + Duration::from_secs(self.merge_cooldown_secs));
@@ -913,6 +1067,7 @@ impl Quoter {
             if let Err(e) = self.executor().merge(
                 self.market.condition_id.clone(),
                 decision.pairs_to_merge,
+                self.merger.config().min_confirmations,
             ) {
                 warn!("[Quoter:{}] Final merge failed: {}", market_desc, e);
             }
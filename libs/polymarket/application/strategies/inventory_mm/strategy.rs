@@ -10,7 +10,8 @@ use chrono::{DateTime, Utc};
 use tokio::task::JoinHandle;
 use tracing::{info, warn, debug, error};
 
-use super::config::InventoryMMConfig;
+use super::components::MergeGasCost;
+use super::config::{InventoryMMConfig, MarketSpec};
 use super::quoter::{Quoter, QuoterContext, MarketInfo};
 use super::types::{
     SolverInput, InventorySnapshot, OrderbookSnapshot, OrderSnapshot, OpenOrder,
@@ -18,6 +19,7 @@ use super::types::{
 use crate::application::strategies::traits::{Strategy, StrategyContext, StrategyResult, StrategyError};
 use crate::application::strategies::up_or_down::{CryptoAsset, Timeframe};
 use crate::application::strategies::up_or_down::services::get_price_to_beat;
+use crate::infrastructure::client::ctf::estimate_merge_gas_cost;
 use crate::infrastructure::{
     spawn_order_reconciliation_task, spawn_position_reconciliation_task, ReconciliationConfig,
     SharedOrderbooks, SharedOrderState, SharedPositionTracker, UserOrderStatus as OrderStatus,
@@ -25,7 +27,13 @@ use crate::infrastructure::{
 };
 
 /// Maximum markets to fetch per category from DB
-const MAX_MARKETS_PER_CATEGORY: i64 = 5;
+pub(super) const MAX_MARKETS_PER_CATEGORY: i64 = 5;
+
+/// How often quoters check the wallet balance for changes
+const BALANCE_WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Minimum USDC balance change that triggers a requote
+const BALANCE_REQUOTE_MIN_CHANGE: f64 = 1.0;
 
 /// Main strategy - implements Strategy trait.
 /// Manages multiple quoters, one per market.
@@ -43,6 +51,10 @@ pub struct InventoryMMStrategy {
     reconciliation_handle: Option<JoinHandle<()>>,
     order_reconciliation_handle: Option<JoinHandle<()>>,
 
+    // Live merge gas estimate, refreshed by `gas_refresh_handle`
+    merge_gas_cost: MergeGasCost,
+    gas_refresh_handle: Option<JoinHandle<()>>,
+
     // Oracle prices (ChainLink for 15-min markets)
     oracle_prices: Option<SharedOraclePrices>,
 }
@@ -56,10 +68,37 @@ impl InventoryMMStrategy {
             tracked_markets: HashSet::new(),
             reconciliation_handle: None,
             order_reconciliation_handle: None,
+            merge_gas_cost: MergeGasCost::new(),
+            gas_refresh_handle: None,
             oracle_prices: None,
         }
     }
 
+    /// Refresh the shared merge gas estimate every `gas_refresh_secs` until shutdown.
+    fn spawn_gas_refresh(&self, ctx: &StrategyContext) -> JoinHandle<()> {
+        let gas_cost = self.merge_gas_cost.clone();
+        let pol_price_usd = self.config.merger.pol_price_usd;
+        let interval = Duration::from_secs(self.config.merger.gas_refresh_secs);
+        let shutdown = ctx.shutdown.clone();
+
+        tokio::spawn(async move {
+            while shutdown.is_running() {
+                match estimate_merge_gas_cost().await {
+                    Ok(cost_pol) => {
+                        gas_cost.set_native(cost_pol, pol_price_usd);
+                        debug!(
+                            "[InventoryMM] Merge gas estimate: {:.6} POL = ${:.4}",
+                            cost_pol,
+                            cost_pol * pol_price_usd
+                        );
+                    }
+                    Err(e) => warn!("[InventoryMM] Failed to estimate merge gas cost: {}", e),
+                }
+                shutdown.interruptible_sleep(interval).await;
+            }
+        })
+    }
+
     /// Spawn a quoter for a market.
     fn spawn_quoter(&mut self, market: MarketInfo, ctx: QuoterContext) {
         let market_id = market.market_id.clone();
@@ -74,6 +113,7 @@ impl InventoryMMStrategy {
         let snapshot_timeout_secs = self.config.snapshot_timeout_secs;
         let merge_cooldown_secs = self.config.merge_cooldown_secs;
         let data_logging_config = self.config.data_logging.clone();
+        let max_orders_per_minute = self.config.max_orders_per_minute;
 
         // Register the token pair for this market (enables merge detection)
         ctx.position_tracker.write().register_token_pair(
@@ -97,7 +137,8 @@ impl InventoryMMStrategy {
                 merge_cooldown_secs,
                 ctx,
                 data_logging_config,
-            );
+            )
+            .with_max_orders_per_minute(max_orders_per_minute);
             quoter.run().await;
         });
 
@@ -133,15 +174,7 @@ impl InventoryMMStrategy {
 
         for market in markets {
             // Parse tags to get symbol/timeframe for counting
-            let tags_str = match &market.tags {
-                Some(t) => t.as_str(),
-                None => continue,
-            };
-            let tags: Vec<serde_json::Value> = match serde_json::from_str(tags_str) {
-                Ok(t) => t,
-                Err(_) => continue,
-            };
-            let (symbol, timeframe) = match extract_symbol_timeframe(&tags) {
+            let (symbol, timeframe) = match market_category(&market) {
                 Some(st) => st,
                 None => continue,
             };
@@ -272,6 +305,13 @@ impl InventoryMMStrategy {
         Ok(result)
     }
 
+    /// Fail unless every configured spec has an active market in the DB.
+    async fn check_markets_available(&self, ctx: &StrategyContext) -> StrategyResult<()> {
+        let markets = ctx.database.get_sliding_window_markets(MAX_MARKETS_PER_CATEGORY).await?;
+        let available: HashSet<(String, String)> = markets.iter().filter_map(market_category).collect();
+        check_specs_available(&self.config.markets, &available).map_err(StrategyError::Config)
+    }
+
     /// Fetch the price_to_beat (threshold) for a market from Polymarket API.
     /// This is the opening price used to determine UP/DOWN resolution.
     /// Retries up to 3 times with exponential backoff.
@@ -338,6 +378,8 @@ impl Strategy for InventoryMMStrategy {
     async fn initialize(&mut self, ctx: &StrategyContext) -> StrategyResult<()> {
         info!("[InventoryMM] Initializing strategy");
 
+        self.check_markets_available(ctx).await?;
+
         // NOTE: Each quoter now spawns its own executor thread for order execution.
         // This ensures markets are independent and don't block each other.
 
@@ -394,6 +436,11 @@ impl Strategy for InventoryMMStrategy {
             warn!("[InventoryMM] Order reconciliation task already running, skipping spawn");
         }
 
+        // Spawn the live merge gas estimator
+        if self.gas_refresh_handle.is_none() {
+            self.gas_refresh_handle = Some(self.spawn_gas_refresh(ctx));
+        }
+
         // Spawn oracle price trackers (ChainLink + Binance)
         if self.oracle_prices.is_none() {
             info!("[InventoryMM] Starting oracle price trackers");
@@ -429,7 +476,18 @@ impl Strategy for InventoryMMStrategy {
             ctx.position_tracker.clone(),
             ctx.shutdown_flag.clone(),
             oracle_prices,
-        );
+        )
+        .with_balance_watcher(
+            ctx.balance_manager.read().spawn_watcher(
+                BALANCE_WATCH_INTERVAL,
+                BALANCE_REQUOTE_MIN_CHANGE,
+                ctx.shutdown_flag.clone(),
+            ),
+            ctx.balance_manager.read().own_flow(),
+        )
+        .with_metrics(ctx.metrics.clone())
+        .with_market_halts(ctx.market_halts.clone())
+        .with_merge_gas_cost(self.merge_gas_cost.clone());
 
         let poll_interval = Duration::from_secs(self.config.poll_interval_secs);
 
@@ -477,6 +535,9 @@ impl Strategy for InventoryMMStrategy {
             info!("[InventoryMM] Aborting order reconciliation task");
             handle.abort();
         }
+        if let Some(handle) = self.gas_refresh_handle.take() {
+            handle.abort();
+        }
 
         // Wait for all quoter tasks to finish (they check shutdown flag)
         // Each quoter will shutdown its own executor during cleanup
@@ -493,6 +554,40 @@ impl Strategy for InventoryMMStrategy {
     }
 }
 
+/// (symbol, timeframe) of a market, from its tags
+fn market_category(market: &crate::domain::DbMarket) -> Option<(String, String)> {
+    let tags: Vec<serde_json::Value> = serde_json::from_str(market.tags.as_deref()?).ok()?;
+    extract_symbol_timeframe(&tags)
+}
+
+/// Check that each spec's (symbol, timeframe) is among the `available`
+/// categories, naming every spec that is not
+fn check_specs_available(
+    specs: &[MarketSpec],
+    available: &HashSet<(String, String)>,
+) -> Result<(), String> {
+    let missing: Vec<String> = specs
+        .iter()
+        .enumerate()
+        .filter(|(_, spec)| {
+            !available.contains(&(spec.symbol.to_uppercase(), spec.timeframe.to_uppercase()))
+        })
+        .map(|(i, spec)| {
+            format!(
+                "inventory_mm.markets[{}]: no active {} {} market in the database",
+                i,
+                spec.symbol.to_uppercase(),
+                spec.timeframe.to_uppercase()
+            )
+        })
+        .collect();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(missing.join("; "))
+    }
+}
+
 /// Extract symbol and timeframe from market tags.
 /// Returns (symbol, timeframe) if found.
 fn extract_symbol_timeframe(tags: &[serde_json::Value]) -> Option<(String, String)> {
@@ -669,6 +764,24 @@ mod tests {
         assert!(extract_symbol_timeframe(&tags).is_none());
     }
 
+    #[test]
+    fn test_check_specs_available_names_missing_specs() {
+        let available: HashSet<(String, String)> =
+            [("BTC".to_string(), "15M".to_string())].into_iter().collect();
+
+        let specs = vec![MarketSpec::new("btc", "15m", 3)];
+        assert!(check_specs_available(&specs, &available).is_ok());
+
+        let specs = vec![
+            MarketSpec::new("BTC", "15M", 3),
+            MarketSpec::new("ETH", "1H", 1),
+        ];
+        assert_eq!(
+            check_specs_available(&specs, &available).unwrap_err(),
+            "inventory_mm.markets[1]: no active ETH 1H market in the database"
+        );
+    }
+
     #[test]
     fn test_strategy_creation() {
         let config = InventoryMMConfig::default();
@@ -202,6 +202,10 @@ pub struct SolverConfig {
     /// This is a HARD LIMIT on directional exposure. Set to 0.0 for unlimited.
     pub max_delta: f64,
 
+    /// Maximum total BUY notional (price * size, USDC) across both ladders.
+    /// Over-budget ladders are scaled down proportionally. Set to 0.0 for unlimited.
+    pub max_committed: f64,
+
     // ═══════════════════════════════════════════════════════════════
     // DEFENSIVE LAYERS
     // ═══════════════════════════════════════════════════════════════
@@ -280,6 +284,7 @@ impl Default for SolverConfig {
             max_position: 0.0,           // 0 = unlimited
             max_imbalance: 0.8,          // Stop at 80% imbalance
             max_delta: 30.0,             // Stop quoting overweight side at 30 token delta
+            max_committed: 0.0,          // 0 = unlimited
 
             // Defensive Layers
             max_combined_avg: 0.93,          // Block if quote_price + other_avg > 93%
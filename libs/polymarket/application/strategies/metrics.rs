@@ -0,0 +1,198 @@
+//! Strategy session metrics
+//!
+//! `StrategyMetrics` accumulates order, fill and merge activity for one
+//! strategy run and is logged as a `StrategySummary` on shutdown. Strategies
+//! record placements and merges themselves; fills, volume and realized P&L
+//! arrive through the position tracker by registering the metrics as its
+//! `PositionEventCallback`.
+
+use crate::infrastructure::client::user::{PositionEvent, PositionEventCallback};
+use parking_lot::Mutex;
+use std::fmt;
+
+/// Snapshot of a strategy's activity
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StrategySummary {
+    /// Orders accepted by the exchange
+    pub orders_placed: u64,
+    /// Matched fills applied to positions
+    pub fills: u64,
+    /// Filled notional in USDC (size * price)
+    pub volume: f64,
+    /// Realized P&L booked by the position tracker on fills
+    pub realized_pnl: f64,
+    /// Completed on-chain merges
+    pub merges: u64,
+    /// Up/Down pairs converted back to USDC by those merges
+    pub merged_pairs: f64,
+}
+
+impl fmt::Display for StrategySummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} orders placed | {} fills | ${:.2} volume | ${:.2} realized P&L | {} merges ({:.2} pairs)",
+            self.orders_placed,
+            self.fills,
+            self.volume,
+            self.realized_pnl,
+            self.merges,
+            self.merged_pairs
+        )
+    }
+}
+
+/// Thread-safe accumulator shared by a strategy and its trackers
+#[derive(Debug, Default)]
+pub struct StrategyMetrics {
+    summary: Mutex<StrategySummary>,
+}
+
+impl StrategyMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an order accepted by the exchange
+    pub fn record_order(&self) {
+        self.summary.lock().orders_placed += 1;
+    }
+
+    /// Record a fill of `size` shares at `price` and the P&L it realized
+    pub fn record_fill(&self, size: f64, price: f64, realized_pnl: f64) {
+        let mut summary = self.summary.lock();
+        summary.fills += 1;
+        summary.volume += size * price;
+        summary.realized_pnl += realized_pnl;
+    }
+
+    /// Record a confirmed merge of `pairs` Up/Down pairs
+    pub fn record_merge(&self, pairs: f64) {
+        let mut summary = self.summary.lock();
+        summary.merges += 1;
+        summary.merged_pairs += pairs;
+    }
+
+    /// Current totals
+    pub fn summary(&self) -> StrategySummary {
+        *self.summary.lock()
+    }
+}
+
+impl PositionEventCallback for StrategyMetrics {
+    fn on_position_updated(&self, event: &PositionEvent) {
+        if let PositionEvent::Updated {
+            old_position,
+            new_position,
+            fill,
+            ..
+        } = event
+        {
+            let realized_before = old_position.as_ref().map_or(0.0, |p| p.realized_pnl);
+            self.record_fill(fill.size, fill.price, new_position.realized_pnl - realized_before);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::strategies::{Strategy, StrategyContext, StrategyResult};
+    use crate::infrastructure::client::user::{Fill, PositionTracker, Side, TradeStatus};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+
+    fn fill(trade_id: &str, side: Side, price: f64, size: f64) -> Fill {
+        Fill {
+            trade_id: trade_id.to_string(),
+            asset_id: "token-up".to_string(),
+            market: "market-1".to_string(),
+            side,
+            outcome: "Up".to_string(),
+            price,
+            size,
+            status: TradeStatus::Matched,
+            taker_order_id: "taker-1".to_string(),
+            trader_side: "MAKER".to_string(),
+            fee_rate_bps: 0.0,
+            transaction_hash: None,
+            maker_orders: vec![],
+            match_time: "2025-01-01T00:00:00Z".to_string(),
+            timestamp: "2025-01-01T00:00:00Z".to_string(),
+            owner: "owner-1".to_string(),
+        }
+    }
+
+    /// Places an order each tick and gets filled by a scripted tracker
+    struct MockStrategy {
+        metrics: Arc<StrategyMetrics>,
+        tracker: PositionTracker,
+        fills: Vec<Fill>,
+    }
+
+    impl MockStrategy {
+        fn tick(&mut self) {
+            self.metrics.record_order();
+            if !self.fills.is_empty() {
+                let fill = self.fills.remove(0);
+                let (event, _) = self.tracker.apply_fill(&fill);
+                self.tracker.fire_callback(&event);
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Strategy for MockStrategy {
+        fn name(&self) -> &str {
+            "mock"
+        }
+
+        fn description(&self) -> &str {
+            "Test strategy"
+        }
+
+        async fn start(&mut self, _ctx: &StrategyContext) -> StrategyResult<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_summary_reflects_recorded_activity() {
+        let metrics = Arc::new(StrategyMetrics::new());
+        let mut strategy = MockStrategy {
+            metrics: Arc::clone(&metrics),
+            tracker: PositionTracker::with_callback(metrics.clone()),
+            fills: vec![
+                fill("t1", Side::Buy, 0.40, 10.0),
+                fill("t2", Side::Sell, 0.50, 10.0),
+            ],
+        };
+
+        for _ in 0..3 {
+            strategy.tick();
+        }
+        metrics.record_merge(5.0);
+
+        let summary = strategy.shutdown(&metrics).await.unwrap();
+
+        assert_eq!(summary.orders_placed, 3);
+        assert_eq!(summary.fills, 2);
+        assert!((summary.volume - 9.0).abs() < 1e-9);
+        assert!((summary.realized_pnl - 1.0).abs() < 1e-9);
+        assert_eq!(summary.merges, 1);
+        assert!((summary.merged_pairs - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_duplicate_trades_are_not_counted() {
+        let metrics = Arc::new(StrategyMetrics::new());
+        let mut tracker = PositionTracker::with_callback(metrics.clone());
+
+        for _ in 0..2 {
+            let (event, _) = tracker.apply_fill(&fill("t1", Side::Buy, 0.40, 10.0));
+            tracker.fire_callback(&event);
+        }
+
+        assert_eq!(metrics.summary().fills, 1);
+    }
+}
@@ -3,12 +3,14 @@
 //! Pluggable strategy system for the market sniper.
 
 pub mod inventory_mm;
+pub mod metrics;
 pub mod sports_sniping;
 pub mod traits;
 pub mod up_or_down;
 
 // Re-exports
 pub use inventory_mm::InventoryMMStrategy;
+pub use metrics::{StrategyMetrics, StrategySummary};
 pub use sports_sniping::SportsSnipingStrategy;
 pub use traits::{Strategy, StrategyContext, StrategyError, StrategyResult};
 pub use up_or_down::UpOrDownStrategy;
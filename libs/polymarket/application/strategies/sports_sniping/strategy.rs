@@ -149,8 +149,8 @@ impl Strategy for SportsSnipingStrategy {
                                 let trading = Arc::clone(self.trading.as_ref().unwrap());
                                 let balance_manager =
                                     Arc::clone(self.balance_manager.as_ref().unwrap());
-                                let order_pct = self.config.order_pct_of_collateral;
-                                let bid_threshold = self.config.bid_threshold;
+                                let config = self.config.clone();
+                                let metrics = Arc::clone(&ctx.metrics);
 
                                 // Spawn a tracker task for each market
                                 tokio::spawn(async move {
@@ -160,8 +160,8 @@ impl Strategy for SportsSnipingStrategy {
                                         shutdown_flag,
                                         trading,
                                         balance_manager,
-                                        order_pct,
-                                        bid_threshold,
+                                        config,
+                                        metrics,
                                     )
                                     .await
                                     {
@@ -1,6 +1,8 @@
 use super::super::services::log_winning_token;
 use super::winner_analyzer::analyze_orderbooks_for_winner;
+use crate::application::strategies::StrategyMetrics;
 use crate::domain::DbMarket;
+use crate::infrastructure::config::SportsSnipingConfig;
 use crate::infrastructure::client::TradingClient;
 use crate::infrastructure::{
     build_ws_client, BalanceManager, FullTimeEvent, MarketTrackerConfig, SharedOrderbooks,
@@ -63,8 +65,8 @@ pub async fn run_sports_market_tracker(
     shutdown_flag: Arc<AtomicBool>,
     trading: Arc<TradingClient>,
     balance_manager: Arc<RwLock<BalanceManager>>,
-    order_pct: f64,
-    bid_threshold: f64,
+    config: SportsSnipingConfig,
+    metrics: Arc<StrategyMetrics>,
 ) -> anyhow::Result<()> {
     // Parse market data
     let token_ids = market.parse_token_ids()?;
@@ -91,7 +93,8 @@ pub async fn run_sports_market_tracker(
         token_ids.clone(),
         outcomes.clone(),
         &market.end_date,
-    )?;
+    )?
+    .with_connection(config.connection.clone());
 
     // Create shared orderbooks and precisions
     let orderbooks: SharedOrderbooks = Arc::new(RwLock::new(HashMap::new()));
@@ -135,7 +138,7 @@ pub async fn run_sports_market_tracker(
     // Check if best bid meets threshold
     let should_execute = match &winner {
         Some(w) => match w.best_bid {
-            Some((price, _)) => price > bid_threshold,
+            Some((price, _)) => price > config.bid_threshold,
             None => false,
         },
         None => false,
@@ -150,7 +153,7 @@ pub async fn run_sports_market_tracker(
 
         debug!(
             "[Sports Tracker] Not executing for market {} - best bid {} is not above threshold {:.2}",
-            market.id, bid_info, bid_threshold
+            market.id, bid_info, config.bid_threshold
         );
 
         // Cleanup and return
@@ -182,7 +185,7 @@ pub async fn run_sports_market_tracker(
 
         // Calculate order size from balance percentage
         let current_balance = balance_manager.read().current_balance();
-        let size = (current_balance * order_pct).round().max(1.0);
+        let size = (current_balance * config.order_pct_of_collateral).round().max(1.0);
 
         info!(
             "[Sports Tracker] Placing order for {} @ ${:.4} x ${:.2} (precision: {})",
@@ -191,6 +194,9 @@ pub async fn run_sports_market_tracker(
 
         match trading.buy(&w.token_id, price, size).await {
             Ok(response) => {
+                if response.order_id.is_some() {
+                    metrics.record_order();
+                }
                 info!(
                     "[Sports Tracker] ✅ Order placed successfully for market {}: {:?}",
                     market.id, response
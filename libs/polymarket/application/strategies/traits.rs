@@ -2,7 +2,9 @@
 //!
 //! Defines the contract that all sniper strategies must implement.
 
-use crate::infrastructure::BalanceManager;
+use super::metrics::{StrategyMetrics, StrategySummary};
+use crate::domain::MarketParseError;
+use crate::infrastructure::{BalanceManager, MarketHalts};
 use crate::infrastructure::client::clob::TradingClient;
 use crate::infrastructure::client::user::{SharedOrderState, SharedPositionTracker};
 use crate::infrastructure::database::{DatabaseError, MarketDatabase};
@@ -12,6 +14,7 @@ use parking_lot::RwLock;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use thiserror::Error;
+use tracing::info;
 
 /// Result type for strategy operations
 pub type StrategyResult<T> = Result<T, StrategyError>;
@@ -28,6 +31,9 @@ pub enum StrategyError {
     #[error("Strategy interrupted by shutdown")]
     Shutdown,
 
+    #[error("Market parse error: {0}")]
+    MarketParse(#[from] MarketParseError),
+
     #[error("Strategy error: {0}")]
     Other(#[from] anyhow::Error),
 }
@@ -48,6 +54,10 @@ pub struct StrategyContext {
     pub order_state: SharedOrderState,
     /// Real-time position tracker
     pub position_tracker: SharedPositionTracker,
+    /// Session activity, summarized on shutdown
+    pub metrics: Arc<StrategyMetrics>,
+    /// Per-market kill switches consulted before placing
+    pub market_halts: MarketHalts,
 }
 
 impl StrategyContext {
@@ -67,9 +77,23 @@ impl StrategyContext {
             balance_manager,
             order_state,
             position_tracker,
+            metrics: Arc::new(StrategyMetrics::new()),
+            market_halts: MarketHalts::new(),
         }
     }
 
+    /// Share `metrics` with the position tracker's callback so fills are counted
+    pub fn with_metrics(mut self, metrics: Arc<StrategyMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Share `halts` (e.g. one mirrored from the database) with the strategy
+    pub fn with_market_halts(mut self, halts: MarketHalts) -> Self {
+        self.market_halts = halts;
+        self
+    }
+
     /// Check if the strategy should continue running
     pub fn is_running(&self) -> bool {
         self.shutdown.is_running()
@@ -112,6 +136,15 @@ pub trait Strategy: Send + Sync {
         Ok(())
     }
 
+    /// Called last, after `stop()`, with the session's metrics
+    ///
+    /// The default implementation logs the summary and returns it.
+    async fn shutdown(&mut self, metrics: &StrategyMetrics) -> StrategyResult<StrategySummary> {
+        let summary = metrics.summary();
+        info!("[{}] Session summary: {}", self.name(), summary);
+        Ok(summary)
+    }
+
     /// Optional: Called once before `start()` for initialization
     async fn initialize(&mut self, _ctx: &StrategyContext) -> StrategyResult<()> {
         Ok(())
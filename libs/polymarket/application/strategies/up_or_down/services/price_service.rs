@@ -293,52 +293,64 @@ pub fn get_oracle_price(
 // Oracle Health Tracking
 // =============================================================================
 
-/// Check if the SPECIFIC oracle for this market is fresh enough for trading.
+/// Check if the SPECIFIC oracle feed for this market is fresh enough for trading.
 ///
-/// Each market uses only one oracle (ChainLink OR Binance) for resolution.
-/// This checks if that specific oracle has received data recently.
+/// Each market resolves on one symbol of one oracle (e.g. ChainLink BTC).
+/// This checks that symbol's last tick, so a stalled BTC feed is caught even
+/// while ChainLink ETH keeps updating.
 ///
 /// # Arguments
 /// * `oracle_prices` - Shared oracle price manager
 /// * `oracle_source` - Which oracle to check (from market context)
+/// * `crypto_asset` - Which symbol to check (from market context)
 /// * `max_age_secs` - Maximum allowed age of oracle data in seconds
 ///
 /// # Returns
-/// True if the oracle has received data within max_age_secs, false otherwise
+/// True if the symbol ticked within max_age_secs, false if it is stale or
+/// has never ticked
 pub fn is_market_oracle_fresh(
     oracle_prices: &Option<SharedOraclePrices>,
     oracle_source: OracleSource,
+    crypto_asset: CryptoAsset,
     max_age_secs: u64,
 ) -> bool {
     let Some(prices) = oracle_prices else {
         return false;
     };
 
-    // Skip check for unknown oracle sources
-    let Some(oracle_type) = oracle_source.to_oracle_type() else {
+    // Skip check for unknown oracle sources or assets
+    let (Some(oracle_type), Some(symbol)) =
+        (oracle_source.to_oracle_type(), crypto_asset.oracle_symbol())
+    else {
         return true;
     };
 
-    let prices = prices.read();
     let max_age = Duration::from_secs(max_age_secs);
-    prices.is_oracle_healthy(oracle_type, max_age)
+    prices
+        .read()
+        .age(oracle_type, symbol)
+        .is_some_and(|age| age < max_age)
 }
 
-/// Get the age of the last update for the specific oracle this market uses.
+/// Get the age of the last tick for the oracle feed this market uses.
 ///
 /// # Arguments
 /// * `oracle_prices` - Shared oracle price manager
 /// * `oracle_source` - Which oracle to check (from market context)
+/// * `crypto_asset` - Which symbol to check (from market context)
 ///
 /// # Returns
-/// Duration since last update, or None if oracle source is unknown
+/// Duration since last tick, or None if the oracle source or asset is
+/// unknown or the symbol has never ticked
 pub fn get_market_oracle_age(
     oracle_prices: &Option<SharedOraclePrices>,
     oracle_source: OracleSource,
+    crypto_asset: CryptoAsset,
 ) -> Option<Duration> {
     let prices = oracle_prices.as_ref()?;
 
     let oracle_type = oracle_source.to_oracle_type()?;
+    let symbol = crypto_asset.oracle_symbol()?;
 
-    Some(prices.read().oracle_age(oracle_type))
+    prices.read().age(oracle_type, symbol)
 }
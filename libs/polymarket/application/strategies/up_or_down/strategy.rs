@@ -68,7 +68,7 @@ impl UpOrDownStrategy {
 
     /// Fetch markets matching the required tags
     async fn fetch_matching_markets(&self, ctx: &StrategyContext) -> StrategyResult<Vec<DbMarket>> {
-        let markets = ctx.database.get_markets_by_tags(REQUIRED_TAGS).await?;
+        let markets = ctx.database.get_markets_by_tags(REQUIRED_TAGS, &[]).await?;
         Ok(markets)
     }
 
@@ -91,7 +91,9 @@ impl UpOrDownStrategy {
     }
 
     /// Add new markets to tracking, returns count of newly added
-    fn add_new_markets(&mut self, markets: Vec<DbMarket>) -> usize {
+    ///
+    /// Fails on a malformed market only when `outcomes_parse` is strict.
+    fn add_new_markets(&mut self, markets: Vec<DbMarket>) -> StrategyResult<usize> {
         let mut added = 0;
         for market in markets {
             // Only add if we haven't seen this market ID before
@@ -99,18 +101,11 @@ impl UpOrDownStrategy {
                 // Parse end time
                 if let Ok(end_time) = DateTime::parse_from_rfc3339(&market.end_date) {
                     // Validate token_ids and outcomes can be parsed
-                    let token_ids = match market.parse_token_ids() {
-                        Ok(ids) => ids,
-                        Err(e) => {
-                            warn!("Failed to parse token_ids for market {}: {}", market.id, e);
-                            continue;
-                        }
-                    };
-
-                    if let Err(e) = market.parse_outcomes() {
-                        warn!("Failed to parse outcomes for market {}: {}", market.id, e);
+                    let Some((_, token_ids)) =
+                        market.parse_outcomes_and_tokens(self.config.outcomes_parse)?
+                    else {
                         continue;
-                    }
+                    };
 
                     // Skip markets without valid token pairs
                     if token_ids.len() < 2 {
@@ -145,7 +140,7 @@ impl UpOrDownStrategy {
                 }
             }
         }
-        added
+        Ok(added)
     }
 
     /// Check markets within delta_t window and spawn WebSocket trackers.
@@ -232,6 +227,7 @@ impl UpOrDownStrategy {
             let position_tracker = Some(ctx.position_tracker.clone());
             let order_state = Some(ctx.order_state.clone());
             let risk_manager = self.risk_manager_handle.clone();
+            let metrics = Arc::clone(&ctx.metrics);
 
             // Register token pair for this market (enables merge detection)
             if let Some(ref condition_id) = tracked.market.condition_id {
@@ -266,6 +262,7 @@ impl UpOrDownStrategy {
                     position_tracker,
                     order_state,
                     risk_manager,
+                    metrics,
                 )
                 .await
                 {
@@ -335,7 +332,8 @@ impl Strategy for UpOrDownStrategy {
             Arc::clone(&ctx.trading),
             self.oracle_prices.clone().unwrap(),
             self.config.oracle_bps_price_threshold,
-        );
+        )
+        .with_market_halts(ctx.market_halts.clone());
         self.risk_manager_handle = Some(risk_manager);
         info!("Risk manager started successfully");
 
@@ -348,7 +346,7 @@ impl Strategy for UpOrDownStrategy {
         // Initial market fetch
         let markets = self.fetch_matching_markets(ctx).await?;
         let active = self.filter_active_markets(markets.clone());
-        let added = self.add_new_markets(active.clone());
+        let added = self.add_new_markets(active.clone())?;
 
         info!(
             total_matching = markets.len(),
@@ -370,7 +368,7 @@ impl Strategy for UpOrDownStrategy {
             match self.fetch_matching_markets(ctx).await {
                 Ok(markets) => {
                     let active = self.filter_active_markets(markets);
-                    let new_count = self.add_new_markets(active);
+                    let new_count = self.add_new_markets(active)?;
 
                     if new_count > 0 {
                         info!(
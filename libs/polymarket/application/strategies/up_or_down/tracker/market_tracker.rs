@@ -6,24 +6,26 @@ use crate::application::strategies::up_or_down::services::{
     get_market_oracle_age, get_price_to_beat, log_market_ended,
 };
 use crate::application::strategies::up_or_down::tracker::{
-    check_all_orderbooks, check_risk, guardian_check, place_order, upgrade_order_on_tick_change,
+    all_orderbooks_empty, check_all_orderbooks, check_risk, guardian_check, place_order,
+    select_cheaper_side, upgrade_order_on_tick_change,
 };
 use crate::application::strategies::up_or_down::types::{
-    MarketTrackerContext, OrderInfo, TrackerState, TrackingLoopExit, MAX_RECONNECT_ATTEMPTS,
-    STALENESS_THRESHOLD_SECS,
+    MarketTrackerContext, OrderInfo, OrderKind, TrackerHandles, TrackerState, TrackingLoopExit,
+    MAX_RECONNECT_ATTEMPTS, STALENESS_THRESHOLD_SECS,
 };
+use crate::application::strategies::StrategyMetrics;
 use crate::domain::DbMarket;
 use crate::infrastructure::client::clob::TradingClient;
 use crate::infrastructure::config::UpOrDownConfig;
 use crate::infrastructure::client::user::{SharedOrderState, SharedPositionTracker};
 use crate::infrastructure::{
-    build_ws_client, decimal_places, handle_client_event, BalanceManager, MarketTrackerConfig,
-    RiskManagerHandle, SharedOraclePrices, SharedOrderbooks, SharedPrecisions, TickSizeChangeEvent,
+    build_sniper_ws, decimal_places, handle_client_event, is_market_closed, BalanceManager,
+    MarketTrackerConfig, PriceDivergence, RiskManagerHandle, SharedOraclePrices, SharedOrderbooks,
+    SharedPrecisions, SniperSharedState, TickSizeChangeEvent,
 };
 use chrono::Utc;
 use crossbeam_channel::{unbounded, Receiver};
 use parking_lot::RwLock;
-use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration as StdDuration, Instant};
@@ -71,10 +73,11 @@ pub async fn run_market_tracker(
     _position_tracker: Option<SharedPositionTracker>,
     order_state: Option<SharedOrderState>,
     risk_manager: Option<RiskManagerHandle>,
+    metrics: Arc<StrategyMetrics>,
 ) -> anyhow::Result<()> {
     // Initialize context and state
     let outcomes = market.parse_outcomes()?;
-    let mut ctx = MarketTrackerContext::new(&market, &config, outcomes.clone())?;
+    let mut ctx = MarketTrackerContext::new(&market, &config, outcomes.clone())?.with_metrics(metrics);
     let mut state = TrackerState::new();
 
     // Build WebSocket configuration
@@ -85,7 +88,9 @@ pub async fn run_market_tracker(
         ctx.token_ids.clone(),
         outcomes,
         &market.end_date,
-    )?;
+    )?
+    .with_price_scale(config.price_scale)
+    .with_connection(config.connection.clone());
 
     // Fetch the price to beat for this market
     fetch_and_set_price_to_beat(&mut ctx, &market).await;
@@ -126,7 +131,7 @@ pub async fn run_market_tracker(
         }
 
         // Create WebSocket connection
-        let conn_result = match create_ws_connection(&ws_config, &ctx.market_id).await {
+        let conn_result = match create_ws_connection(&ws_config, &ctx.market_id, &shutdown_flag).await {
             Ok(result) => result,
             Err(e) => {
                 error!("[WS {}] Failed to connect: {}", ctx.market_id, e);
@@ -240,31 +245,22 @@ fn log_tracker_startup(ctx: &MarketTrackerContext, ws_config: &MarketTrackerConf
 async fn create_ws_connection(
     ws_config: &MarketTrackerConfig,
     market_id: &str,
+    shutdown_flag: &Arc<AtomicBool>,
 ) -> anyhow::Result<ConnectionResult> {
-    let orderbooks: SharedOrderbooks = Arc::new(RwLock::new(HashMap::new()));
-    let precisions: SharedPrecisions = Arc::new(RwLock::new(HashMap::new()));
-    let first_snapshot_received = Arc::new(AtomicBool::new(false));
-
     // Create channel for tick_size_change events
     let (tick_size_tx, tick_size_rx) = unbounded::<TickSizeChangeEvent>();
+    let shared = SniperSharedState::new().with_tick_size_tx(tick_size_tx);
 
-    let client = build_ws_client(
-        ws_config,
-        Arc::clone(&orderbooks),
-        Arc::clone(&precisions),
-        Some(tick_size_tx),
-        Arc::clone(&first_snapshot_received),
-    )
-    .await?;
+    let client = build_sniper_ws(ws_config, &shared, Arc::clone(shutdown_flag)).await?;
 
     info!("[WS {}] Connected and subscribed", market_id);
 
     Ok(ConnectionResult {
         client,
-        orderbooks,
-        precisions,
+        orderbooks: shared.orderbooks,
+        precisions: shared.precisions,
         tick_size_rx,
-        first_snapshot_received,
+        first_snapshot_received: shared.first_snapshot_received,
     })
 }
 
@@ -328,6 +324,15 @@ async fn run_tracking_loop(
     let connection_start = Instant::now();
     let mut seen_updates_since_connect = false;
     let mut last_oracle_warning: Option<Instant> = None;
+    let handles = TrackerHandles {
+        orderbooks: &conn.orderbooks,
+        precisions: &conn.precisions,
+        oracle_prices,
+        trading,
+        balance_manager,
+        order_state: order_state.as_ref(),
+        risk_manager,
+    };
 
     let exit_reason = loop {
         // Check shutdown flag (highest priority)
@@ -356,6 +361,12 @@ async fn run_tracking_loop(
             break TrackingLoopExit::MarketEnded;
         }
 
+        // Stop acting immediately if the feed reported the market closed
+        if is_market_closed(&conn.orderbooks, &ctx.token_ids) {
+            info!("[WS {}] Market closed by feed, stopping tracker", ctx.market_id);
+            break TrackingLoopExit::MarketClosed;
+        }
+
         // Handle WebSocket events
         if let Some(event) = conn.client.try_recv_event() {
             if !handle_client_event(event, &ctx.market_id) {
@@ -375,8 +386,15 @@ async fn run_tracking_loop(
                 new_precision
             );
 
-            // Check if we have an order for this token that could be upgraded
-            if let Some(current_order) = state.order_placed.get(&event.asset_id).cloned() {
+            // Check if we have an order for this token that could be upgraded.
+            // Cheaper-side orders sit at the ask they were placed at and must
+            // not be re-placed at one tick below $1.
+            if let Some(current_order) = state
+                .order_placed
+                .get(&event.asset_id)
+                .filter(|order| order.is_favored())
+                .cloned()
+            {
                 // Only proceed if upgrade is actually needed (new precision is higher)
                 if new_precision > current_order.precision {
                     // Skip order state check for recently-placed orders (WebSocket has slight delay)
@@ -451,30 +469,28 @@ async fn run_tracking_loop(
         // Note: This just logs warnings - pre_order_risk_check blocks orders at 10s
         check_oracle_health(oracle_prices, ctx, &mut last_oracle_warning);
 
-        // Check orderbooks and get tokens needing orders
-        let (tokens_to_order, all_empty) =
-            check_all_orderbooks(&conn.orderbooks, state, ctx).await;
+        if let Some(max_price) = ctx.cheaper_side_max_price {
+            // Exit if market has ended (all orderbooks empty)
+            if all_orderbooks_empty(&conn.orderbooks, ctx) {
+                log_market_ended(ctx);
+                break TrackingLoopExit::AllOrderbooksEmpty;
+            }
 
-        // Exit if market has ended (all orderbooks empty)
-        if all_empty {
-            log_market_ended(ctx);
-            break TrackingLoopExit::AllOrderbooksEmpty;
-        }
+            process_cheaper_side(max_price, state, ctx, &handles).await;
+        } else {
+            // Check orderbooks and get tokens needing orders
+            let (tokens_to_order, all_empty) =
+                check_all_orderbooks(&conn.orderbooks, state, ctx).await;
 
-        // Process tokens that exceeded threshold
-        process_order_candidates(
-            tokens_to_order,
-            &conn.orderbooks,
-            &conn.precisions,
-            state,
-            ctx,
-            oracle_prices,
-            trading,
-            balance_manager,
-            order_state.as_ref(),
-            risk_manager,
-        )
-        .await;
+            // Exit if market has ended (all orderbooks empty)
+            if all_empty {
+                log_market_ended(ctx);
+                break TrackingLoopExit::AllOrderbooksEmpty;
+            }
+
+            // Process tokens that exceeded threshold
+            process_order_candidates(tokens_to_order, state, ctx, &handles).await;
+        }
 
         // Monitor for risk on placed orders
         check_risk(&conn.orderbooks, state, ctx, oracle_prices, trading).await;
@@ -503,8 +519,8 @@ fn check_oracle_health(
     ctx: &MarketTrackerContext,
     last_oracle_warning: &mut Option<Instant>,
 ) -> bool {
-    let Some(age) = get_market_oracle_age(oracle_prices, ctx.oracle_source) else {
-        // Unknown oracle source - skip health check
+    let Some(age) = get_market_oracle_age(oracle_prices, ctx.oracle_source, ctx.crypto_asset) else {
+        // Unknown oracle source/asset or no tick yet - the pre-order check blocks on the latter
         return true;
     };
 
@@ -539,6 +555,24 @@ fn check_oracle_health(
     }
 }
 
+/// ChainLink/Binance gap for this market's asset when it exceeds the
+/// configured maximum, `None` if the check is disabled or prices agree.
+fn oracle_divergence(
+    oracle_prices: &Option<SharedOraclePrices>,
+    ctx: &MarketTrackerContext,
+) -> Option<f64> {
+    if ctx.max_oracle_divergence_bps <= 0.0 {
+        return None;
+    }
+    let prices = oracle_prices.as_ref()?;
+    let symbol = ctx.crypto_asset.oracle_symbol()?;
+    let divergence = PriceDivergence::new(Arc::clone(prices));
+    if !divergence.is_diverged(symbol, ctx.max_oracle_divergence_bps) {
+        return None;
+    }
+    divergence.divergence_bps(symbol)
+}
+
 /// Check if any orderbooks are stale (haven't received updates recently).
 /// Returns (is_stale, has_activity).
 fn check_orderbook_staleness(
@@ -578,20 +612,14 @@ fn check_orderbook_staleness(
 /// Process tokens that are candidates for order placement.
 async fn process_order_candidates(
     tokens_to_order: Vec<(String, String, f64)>,
-    orderbooks: &SharedOrderbooks,
-    precisions: &SharedPrecisions,
     state: &mut TrackerState,
     ctx: &MarketTrackerContext,
-    _oracle_prices: &Option<SharedOraclePrices>,
-    trading: &Arc<TradingClient>,
-    balance_manager: &Arc<RwLock<BalanceManager>>,
-    order_state: Option<&SharedOrderState>,
-    risk_manager: &Option<RiskManagerHandle>,
+    handles: &TrackerHandles<'_>,
 ) {
     for (token_id, outcome_name, elapsed) in tokens_to_order {
         // Re-check orderbook and capture liquidity before placing order
         let (still_no_asks, best_bid, liq_at_99) = {
-            let obs = orderbooks.read();
+            let obs = handles.orderbooks.read();
             match obs.get(&token_id) {
                 Some(ob) => (
                     ob.asks.is_empty(),
@@ -621,28 +649,8 @@ async fn process_order_candidates(
             ctx.market_id, top_bid_str, liq_at_99
         );
 
-        // Pre-order risk check using RiskManager - ALWAYS check, no bypass
-        let risk_check_passed = match (risk_manager, ctx.price_to_beat) {
-            (Some(rm), Some(ptb)) => rm.pre_placement_check(ptb, ctx.oracle_source, ctx.crypto_asset),
-            _ => true, // No risk manager or no price_to_beat - allow order
-        };
-
-        if !risk_check_passed {
-            info!(
-                "[WS {}] Skipping order for {} - pre-placement risk check failed",
-                ctx.market_id, outcome_name
-            );
-            state.threshold_triggered.remove(&token_id);
-            state.no_asks_timers.remove(&token_id);
-            continue;
-        }
-
-        // Check if trading is halted due to balance drop
-        if balance_manager.read().is_halted() {
-            info!(
-                "[WS {}] Order blocked - trading halted due to balance drop",
-                ctx.market_id
-            );
+        let kind = OrderKind::Favored;
+        if order_blocked(&outcome_name, kind, ctx, handles) {
             state.threshold_triggered.remove(&token_id);
             state.no_asks_timers.remove(&token_id);
             continue;
@@ -650,26 +658,137 @@ async fn process_order_candidates(
 
         // Place the order
         if let Some((order_id, precision)) =
-            place_order(trading, &token_id, &outcome_name, elapsed, ctx, precisions, balance_manager, order_state).await
+            place_order(handles, &token_id, &outcome_name, elapsed, None, ctx).await
         {
             state.order_placed.insert(token_id.clone(), OrderInfo::new(order_id, precision));
+            register_with_risk_manager(ctx, handles.risk_manager);
+        }
+    }
+}
 
-            // Register market with risk manager for continuous monitoring now that we have an order
-            if let (Some(rm), Some(price_to_beat)) = (risk_manager, ctx.price_to_beat) {
-                if ctx.token_ids.len() >= 2 {
-                    if let Err(e) = rm.register_market(
-                        ctx.market_id.clone(),
-                        price_to_beat,
-                        ctx.oracle_source,
-                        ctx.crypto_asset,
-                        ctx.market_end_time,
-                        [ctx.token_ids[0].clone(), ctx.token_ids[1].clone()],
-                    ) {
-                        warn!("[WS {}] Failed to register with risk manager: {}", ctx.market_id, e);
-                    } else {
-                        info!("[WS {}] Registered with risk manager after order placement", ctx.market_id);
-                    }
-                }
+/// Buy the cheaper side of the market at its best ask (cheaper-side mode).
+///
+/// Places at most one order per market: once a side has been bought, a
+/// guardian cancel does not re-enter.
+async fn process_cheaper_side(
+    max_price: f64,
+    state: &mut TrackerState,
+    ctx: &MarketTrackerContext,
+    handles: &TrackerHandles<'_>,
+) {
+    if !state.order_placed.is_empty() || !state.threshold_triggered.is_empty() {
+        return;
+    }
+
+    let Some((token_id, ask)) = select_cheaper_side(&handles.orderbooks.read(), &ctx.token_ids, max_price)
+    else {
+        return;
+    };
+    let outcome_name = ctx.get_outcome_name(&token_id);
+    info!(
+        "[WS {}] Cheaper side is {} with best ask ${} (max ${})",
+        ctx.market_id, outcome_name, ask, max_price
+    );
+
+    let kind = OrderKind::CheaperSide;
+    if order_blocked(&outcome_name, kind, ctx, handles) {
+        return;
+    }
+
+    // Not registered with the risk manager: its monitor cancels orders when
+    // the oracle nears price_to_beat, which only protects favored-side bids.
+    state.threshold_triggered.insert(token_id.clone());
+    if let Some((order_id, precision)) =
+        place_order(handles, &token_id, &outcome_name, 0.0, Some(ask), ctx).await
+    {
+        state
+            .order_placed
+            .insert(token_id.clone(), OrderInfo::cheaper_side(order_id, precision, ask));
+    } else {
+        // Placement failed - allow another attempt
+        state.threshold_triggered.remove(&token_id);
+    }
+}
+
+/// Run the pre-placement gates (kill switch, oracle divergence, risk check,
+/// balance halt), logging the reason when an order for `outcome_name` is blocked.
+///
+/// The oracle-proximity risk check guards favored-side bids only and is
+/// skipped for cheaper-side orders.
+fn order_blocked(
+    outcome_name: &str,
+    kind: OrderKind,
+    ctx: &MarketTrackerContext,
+    handles: &TrackerHandles<'_>,
+) -> bool {
+    let risk_manager = handles.risk_manager;
+    // Per-market kill switch
+    let market_halted = match (risk_manager, ctx.condition_id.as_deref()) {
+        (Some(rm), Some(condition_id)) => rm.is_market_halted(condition_id),
+        _ => false,
+    };
+
+    if market_halted {
+        info!(
+            "[WS {}] Order blocked for {} - market halted by kill switch",
+            ctx.market_id, outcome_name
+        );
+        return true;
+    }
+
+    // Pause the asset while ChainLink and Binance disagree
+    if let Some(bps) = oracle_divergence(handles.oracle_prices, ctx) {
+        warn!(
+            "[WS {}] Order blocked for {} - {} oracles diverged by {:.1} bps (max {:.1})",
+            ctx.market_id, outcome_name, ctx.crypto_asset, bps, ctx.max_oracle_divergence_bps
+        );
+        return true;
+    }
+
+    // Pre-order risk check using RiskManager - ALWAYS check, no bypass
+    let risk_check_passed = match (kind, risk_manager, ctx.price_to_beat) {
+        (OrderKind::Favored, Some(rm), Some(ptb)) => {
+            rm.pre_placement_check(ptb, ctx.oracle_source, ctx.crypto_asset)
+        }
+        _ => true, // Cheaper side, no risk manager or no price_to_beat - allow order
+    };
+
+    if !risk_check_passed {
+        info!(
+            "[WS {}] Skipping order for {} - pre-placement risk check failed",
+            ctx.market_id, outcome_name
+        );
+        return true;
+    }
+
+    // Check if trading is halted due to balance drop
+    if handles.balance_manager.read().is_halted() {
+        info!(
+            "[WS {}] Order blocked - trading halted due to balance drop",
+            ctx.market_id
+        );
+        return true;
+    }
+
+    false
+}
+
+/// Register the market with the risk manager for continuous monitoring
+/// now that we have an order
+fn register_with_risk_manager(ctx: &MarketTrackerContext, risk_manager: &Option<RiskManagerHandle>) {
+    if let (Some(rm), Some(price_to_beat)) = (risk_manager, ctx.price_to_beat) {
+        if ctx.token_ids.len() >= 2 {
+            if let Err(e) = rm.register_market(
+                ctx.market_id.clone(),
+                price_to_beat,
+                ctx.oracle_source,
+                ctx.crypto_asset,
+                ctx.market_end_time,
+                [ctx.token_ids[0].clone(), ctx.token_ids[1].clone()],
+            ) {
+                warn!("[WS {}] Failed to register with risk manager: {}", ctx.market_id, e);
+            } else {
+                info!("[WS {}] Registered with risk manager after order placement", ctx.market_id);
             }
         }
     }
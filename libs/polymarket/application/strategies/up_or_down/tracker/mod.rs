@@ -5,5 +5,7 @@ mod orderbook_checker;
 mod risk_manager;
 
 pub use market_tracker::run_market_tracker;
-pub use orderbook_checker::{calculate_dynamic_threshold, check_all_orderbooks};
+pub use orderbook_checker::{
+    all_orderbooks_empty, calculate_dynamic_threshold, check_all_orderbooks, select_cheaper_side,
+};
 pub use risk_manager::{check_risk, guardian_check, place_order, upgrade_order_on_tick_change};
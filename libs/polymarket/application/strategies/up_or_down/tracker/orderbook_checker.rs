@@ -6,8 +6,10 @@ use crate::application::strategies::up_or_down::services::log_no_asks_started;
 use crate::application::strategies::up_or_down::types::{
     MarketTrackerContext, OrderbookCheckResult, TrackerState, FINAL_SECONDS_BYPASS,
 };
+use crate::domain::orderbook::Orderbook;
 use crate::infrastructure::SharedOrderbooks;
 use chrono::Utc;
+use std::collections::HashMap;
 use std::time::Instant;
 use tracing::{debug, info};
 
@@ -188,3 +190,102 @@ pub async fn check_all_orderbooks(
 
     (tokens_to_order, all_empty)
 }
+
+/// Whether every tracked orderbook has neither bids nor asks (market ended).
+pub fn all_orderbooks_empty(orderbooks: &SharedOrderbooks, ctx: &MarketTrackerContext) -> bool {
+    let obs = orderbooks.read();
+    ctx.token_ids
+        .iter()
+        .filter_map(|token_id| obs.get(token_id))
+        .all(|orderbook| orderbook.asks.is_empty() && orderbook.bids.is_empty())
+}
+
+// =============================================================================
+// Cheaper Side Selection
+// =============================================================================
+
+/// Tolerance for treating two best asks as tied
+const ASK_TIE_EPSILON: f64 = 1e-9;
+
+/// Pick the side of a binary market to buy in cheaper-side mode.
+///
+/// Compares the best asks of both tokens and returns the token with the lower
+/// one, with that ask, if it is below `max_price`. The lower ask is the one
+/// furthest below the threshold, so it carries the most edge.
+///
+/// Returns `None` when the market is not binary, either book has no asks,
+/// the asks tie, or the cheaper ask is not below `max_price`.
+pub fn select_cheaper_side(
+    orderbooks: &HashMap<String, Orderbook>,
+    token_ids: &[String],
+    max_price: f64,
+) -> Option<(String, f64)> {
+    let [first, second] = token_ids else {
+        return None;
+    };
+    let first_ask = orderbooks.get(first)?.best_ask()?.0;
+    let second_ask = orderbooks.get(second)?.best_ask()?.0;
+
+    if (first_ask - second_ask).abs() < ASK_TIE_EPSILON {
+        return None;
+    }
+
+    let (token_id, ask) = if first_ask < second_ask {
+        (first, first_ask)
+    } else {
+        (second, second_ask)
+    };
+    (ask < max_price).then(|| (token_id.clone(), ask))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::orderbook::PriceLevel;
+
+    fn book(token_id: &str, ask: &str) -> (String, Orderbook) {
+        let mut orderbook = Orderbook::new(token_id.to_string());
+        orderbook.process_snapshot(
+            &[PriceLevel {
+                price: "0.01".to_string(),
+                size: "100".to_string(),
+            }],
+            &[PriceLevel {
+                price: ask.to_string(),
+                size: "100".to_string(),
+            }],
+        );
+        (token_id.to_string(), orderbook)
+    }
+
+    fn tokens() -> Vec<String> {
+        vec!["up".to_string(), "down".to_string()]
+    }
+
+    #[test]
+    fn test_selects_cheaper_side_of_asymmetric_books() {
+        let books = HashMap::from([book("up", "0.72"), book("down", "0.31")]);
+        assert_eq!(
+            select_cheaper_side(&books, &tokens(), 0.40),
+            Some(("down".to_string(), 0.31))
+        );
+
+        let books = HashMap::from([book("up", "0.12"), book("down", "0.90")]);
+        assert_eq!(
+            select_cheaper_side(&books, &tokens(), 0.40),
+            Some(("up".to_string(), 0.12))
+        );
+    }
+
+    #[test]
+    fn test_skips_tie_and_both_above_threshold() {
+        let tied = HashMap::from([book("up", "0.30"), book("down", "0.30")]);
+        assert_eq!(select_cheaper_side(&tied, &tokens(), 0.40), None);
+
+        let expensive = HashMap::from([book("up", "0.55"), book("down", "0.48")]);
+        assert_eq!(select_cheaper_side(&expensive, &tokens(), 0.40), None);
+
+        let one_sided = HashMap::from([book("up", "0.20")]);
+        assert_eq!(select_cheaper_side(&one_sided, &tokens(), 0.40), None);
+    }
+}
@@ -9,11 +9,10 @@ use crate::application::strategies::up_or_down::services::{
 };
 use crate::application::strategies::up_or_down::tracker::calculate_dynamic_threshold;
 use crate::application::strategies::up_or_down::types::{
-    MarketTrackerContext, OrderInfo, TrackerState, FINAL_SECONDS_BYPASS,
+    MarketTrackerContext, OrderInfo, TrackerHandles, TrackerState, FINAL_SECONDS_BYPASS,
 };
 use crate::infrastructure::client::clob::TradingClient;
-use crate::infrastructure::client::user::SharedOrderState;
-use crate::infrastructure::{BalanceManager, SharedOraclePrices, SharedOrderbooks, SharedPrecisions};
+use crate::infrastructure::{BalanceManager, SharedOraclePrices, SharedOrderbooks};
 use chrono::Utc;
 use parking_lot::RwLock;
 use std::sync::Arc;
@@ -44,13 +43,13 @@ pub fn pre_order_risk_check(
     // CRITICAL: Check oracle data freshness FIRST
     // This protects against zombie WebSocket connections where the socket appears
     // connected but no data is flowing.
-    if !is_market_oracle_fresh(oracle_prices, ctx.oracle_source, MAX_ORACLE_AGE_SECS) {
-        let age = get_market_oracle_age(oracle_prices, ctx.oracle_source)
+    if !is_market_oracle_fresh(oracle_prices, ctx.oracle_source, ctx.crypto_asset, MAX_ORACLE_AGE_SECS) {
+        let age = get_market_oracle_age(oracle_prices, ctx.oracle_source, ctx.crypto_asset)
             .map(|d| format!("{:.1}s", d.as_secs_f64()))
-            .unwrap_or_else(|| "unknown".to_string());
+            .unwrap_or_else(|| "never ticked".to_string());
         warn!(
-            "[WS {}] Pre-check FAIL: {} oracle data is STALE ({} old, max {}s allowed)",
-            ctx.market_id, ctx.oracle_source, age, MAX_ORACLE_AGE_SECS
+            "[WS {}] Pre-check FAIL: {} {} oracle data is STALE ({} old, max {}s allowed)",
+            ctx.market_id, ctx.oracle_source, ctx.crypto_asset, age, MAX_ORACLE_AGE_SECS
         );
         return false;
     }
@@ -165,13 +164,15 @@ pub async fn check_risk(
         return false;
     }
 
-    // Signal 1: Check bid levels per token
+    // Signal 1: Check bid levels per token (favored-side orders only; a
+    // cheaper-side order is expected to sit on the weaker book)
     let bid_data: Vec<(String, Vec<f64>)> = {
         let obs = orderbooks.read();
         state
             .order_placed
-            .keys()
-            .filter_map(|token_id| {
+            .iter()
+            .filter(|(_, order)| order.is_favored())
+            .filter_map(|(token_id, _)| {
                 obs.get(token_id).and_then(|orderbook| {
                     let bid_levels = orderbook.bids.levels();
                     if bid_levels.len() > 1 {
@@ -239,47 +240,47 @@ pub async fn check_risk(
 
 /// Place a buy order for a token.
 ///
+/// With `limit_price` the order is sized so it spends the collateral
+/// fraction at that price; without it, it bids one tick below $1.
+///
 /// Returns (order_id, precision) if successful, None if failed.
 pub async fn place_order(
-    trading: &TradingClient,
+    handles: &TrackerHandles<'_>,
     token_id: &str,
     outcome_name: &str,
     elapsed: f64,
+    limit_price: Option<f64>,
     ctx: &MarketTrackerContext,
-    precisions: &SharedPrecisions,
-    balance_manager: &Arc<RwLock<BalanceManager>>,
-    order_state: Option<&SharedOrderState>,
 ) -> Option<(String, u8)> {
     let dynamic_threshold = calculate_dynamic_threshold(ctx);
     log_placing_order(ctx, token_id, outcome_name, elapsed, dynamic_threshold);
 
     // Get precision for this token (default to 2)
     let precision = {
-        let precs = precisions.read();
+        let precs = handles.precisions.read();
         *precs.get(token_id).unwrap_or(&2)
     };
 
     // Calculate price: 0.99 for precision 2, 0.999 for precision 3, etc.
-    let price = 1.0 - 10_f64.powi(-(precision as i32));
+    let price = limit_price.unwrap_or_else(|| 1.0 - 10_f64.powi(-(precision as i32)));
 
     // Calculate order size from current balance
-    let current_balance = balance_manager.read().current_balance();
-    let order_size = (current_balance * ctx.order_pct_of_collateral).round();
-    // Ensure minimum order size of 1
-    let order_size = order_size.max(1.0);
+    let current_balance = handles.balance_manager.read().current_balance();
+    let order_size = order_size(current_balance * ctx.order_pct_of_collateral, limit_price);
 
     info!(
-        "[WS {}] Order size: ${:.0} ({:.0}% of ${:.2} balance)",
-        ctx.market_id, order_size, ctx.order_pct_of_collateral * 100.0, current_balance
+        "[WS {}] Order size: {:.0} @ ${} ({:.0}% of ${:.2} balance)",
+        ctx.market_id, order_size, price, ctx.order_pct_of_collateral * 100.0, current_balance
     );
 
-    match trading.buy(token_id, price, order_size).await {
+    match handles.trading.buy(token_id, price, order_size).await {
         Ok(response) => {
             log_order_success(ctx, token_id, outcome_name, &response);
             if let Some(ref order_id) = response.order_id {
+                ctx.metrics.record_order();
                 // Pre-register the order_id so trades can be matched immediately
                 // (prevents race condition where TRADE arrives before PLACEMENT)
-                if let Some(state) = order_state {
+                if let Some(state) = handles.order_state {
                     state.write().pre_register_order(order_id, token_id);
                 }
             }
@@ -292,6 +293,17 @@ pub async fn place_order(
     }
 }
 
+/// Shares to buy with `budget`: whole shares at `limit_price` (never
+/// spending more than the budget), or one share per dollar for a near-$1
+/// bid. At least one share.
+fn order_size(budget: f64, limit_price: Option<f64>) -> f64 {
+    let size = match limit_price {
+        Some(price) => (budget / price).floor(),
+        None => budget.round(),
+    };
+    size.max(1.0)
+}
+
 // =============================================================================
 // Order Cancellation
 // =============================================================================
@@ -420,6 +432,7 @@ pub async fn upgrade_order_on_tick_change(
     match trading.buy(token_id, new_price, order_size).await {
         Ok(response) => {
             if let Some(order_id) = response.order_id {
+                ctx.metrics.record_order();
                 info!(
                     "[WS {}] Upgraded order placed for {}: {}",
                     ctx.market_id, outcome_name, order_id
@@ -548,3 +561,17 @@ pub async fn guardian_check(
 
     cancelled_any
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limit_price_size_never_exceeds_budget() {
+        // 10 / 0.3 = 33.33 shares: 33 costs $9.90, rounding would spend $10.20
+        assert_eq!(order_size(10.0, Some(0.3)), 33.0);
+        assert_eq!(order_size(10.0, Some(0.4)), 25.0);
+        assert_eq!(order_size(0.1, Some(0.3)), 1.0);
+        assert_eq!(order_size(10.6, None), 11.0);
+    }
+}
@@ -8,5 +8,6 @@ pub use market_metadata::{
     REQUIRED_TAGS, STALENESS_THRESHOLD_SECS,
 };
 pub use tracker::{
-    MarketTrackerContext, OrderbookCheckResult, OrderInfo, TrackerState, TrackingLoopExit,
+    MarketTrackerContext, OrderbookCheckResult, OrderInfo, OrderKind, TrackerHandles,
+    TrackerState, TrackingLoopExit,
 };
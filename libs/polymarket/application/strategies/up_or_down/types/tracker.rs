@@ -3,10 +3,18 @@
 //! Contains the context, state, and result types used during market tracking.
 
 use super::market_metadata::{CryptoAsset, OracleSource, Timeframe};
+use crate::application::strategies::StrategyMetrics;
 use crate::domain::DbMarket;
+use crate::infrastructure::client::clob::TradingClient;
+use crate::infrastructure::client::user::SharedOrderState;
 use crate::infrastructure::config::UpOrDownConfig;
+use crate::infrastructure::{
+    BalanceManager, RiskManagerHandle, SharedOraclePrices, SharedOrderbooks, SharedPrecisions,
+};
 use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use std::time::Instant;
 
 // =============================================================================
@@ -16,6 +24,8 @@ use std::time::Instant;
 /// Context holding immutable market information for the tracker
 pub struct MarketTrackerContext {
     pub market_id: String,
+    /// Condition id (key for the per-market kill switch)
+    pub condition_id: Option<String>,
     pub market_question: String,
     pub market_url: String,
     pub oracle_source: OracleSource,
@@ -39,6 +49,12 @@ pub struct MarketTrackerContext {
     pub order_pct_of_collateral: f64,
     /// Guardian safety threshold in basis points (cancels if oracle within this of price_to_beat)
     pub guardian_safety_bps: f64,
+    /// Max ChainLink/Binance gap in basis points before orders are blocked (0 = disabled)
+    pub max_oracle_divergence_bps: f64,
+    /// Buy only the cheaper side below this price (None = no-asks snipe)
+    pub cheaper_side_max_price: Option<f64>,
+    /// Strategy-wide activity counters
+    pub metrics: Arc<StrategyMetrics>,
 }
 
 impl MarketTrackerContext {
@@ -73,6 +89,7 @@ impl MarketTrackerContext {
 
         Ok(Self {
             market_id: market.id.clone(),
+            condition_id: market.condition_id.clone(),
             market_question: market.question.clone(),
             market_url,
             oracle_source: OracleSource::from_description(&market.description),
@@ -88,9 +105,18 @@ impl MarketTrackerContext {
             oracle_bps_price_threshold: config.oracle_bps_price_threshold,
             order_pct_of_collateral: config.order_pct_of_collateral,
             guardian_safety_bps: config.guardian_safety_bps,
+            max_oracle_divergence_bps: config.max_oracle_divergence_bps,
+            cheaper_side_max_price: config.cheaper_side_max_price,
+            metrics: Arc::new(StrategyMetrics::new()),
         })
     }
 
+    /// Record activity into the strategy's shared metrics
+    pub fn with_metrics(mut self, metrics: Arc<StrategyMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     /// Get the outcome name for a token ID
     pub fn get_outcome_name(&self, token_id: &str) -> String {
         self.outcome_map
@@ -117,25 +143,54 @@ impl MarketTrackerContext {
 // Order Info
 // =============================================================================
 
+/// Which entry logic placed an order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderKind {
+    /// Near-$1 bid on the side whose asks drained (default mode)
+    Favored,
+    /// Buy of the cheaper side at its best ask (cheaper-side mode)
+    CheaperSide,
+}
+
 #[derive(Debug, Clone)]
 pub struct OrderInfo {
     pub order_id: String,
     pub precision: u8,
+    pub kind: OrderKind,
+    /// Limit price the order was placed at
+    pub limit_price: f64,
     /// When this order was placed (for skipping OMS checks on fresh orders)
     pub placed_at: Instant,
 }
 
 impl OrderInfo {
+    /// Favored-side order at one tick below $1 for `precision`
     pub fn new(order_id: String, precision: u8) -> Self {
         Self {
             order_id,
             precision,
+            kind: OrderKind::Favored,
+            limit_price: 1.0 - 10_f64.powi(-(precision as i32)),
             placed_at: Instant::now(),
         }
     }
 
+    /// Cheaper-side order at `limit_price`
+    pub fn cheaper_side(order_id: String, precision: u8, limit_price: f64) -> Self {
+        Self {
+            kind: OrderKind::CheaperSide,
+            limit_price,
+            ..Self::new(order_id, precision)
+        }
+    }
+
+    pub fn is_favored(&self) -> bool {
+        self.kind == OrderKind::Favored
+    }
+
+    /// A favored-side bid at $0.999+
     pub fn is_high_confidence(&self) -> bool {
-        self.precision >= 3
+        self.is_favored() && self.limit_price >= 0.999 - f64::EPSILON
     }
 
     /// Check if order was placed recently (within given seconds)
@@ -145,6 +200,21 @@ impl OrderInfo {
     }
 }
 
+// =============================================================================
+// Tracker Handles
+// =============================================================================
+
+/// Shared feeds and clients the tracking loop places orders through
+pub struct TrackerHandles<'a> {
+    pub orderbooks: &'a SharedOrderbooks,
+    pub precisions: &'a SharedPrecisions,
+    pub oracle_prices: &'a Option<SharedOraclePrices>,
+    pub trading: &'a Arc<TradingClient>,
+    pub balance_manager: &'a Arc<RwLock<BalanceManager>>,
+    pub order_state: Option<&'a SharedOrderState>,
+    pub risk_manager: &'a Option<RiskManagerHandle>,
+}
+
 // =============================================================================
 // Tracker State
 // =============================================================================
@@ -155,7 +225,7 @@ pub struct TrackerState {
     pub no_asks_timers: HashMap<String, Instant>,
     /// Tokens that have exceeded the no-asks threshold
     pub threshold_triggered: HashSet<String>,
-    /// Orders placed: token_id -> (order_id, precision)
+    /// Orders placed: token_id -> order
     pub order_placed: HashMap<String, OrderInfo>,
 }
 
@@ -220,6 +290,8 @@ pub enum TrackingLoopExit {
     WebSocketDisconnected,
     StaleOrderbook,
     TooLate,
+    /// Feed reported the market resolved/closed mid-session
+    MarketClosed,
 }
 
 impl TrackingLoopExit {
@@ -232,6 +304,7 @@ impl TrackingLoopExit {
             TrackingLoopExit::WebSocketDisconnected => "ws_disconnected",
             TrackingLoopExit::StaleOrderbook => "stale_orderbook",
             TrackingLoopExit::TooLate => "too_late",
+            TrackingLoopExit::MarketClosed => "market_closed",
         }
     }
 
@@ -243,3 +316,24 @@ impl TrackingLoopExit {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cheaper_side_orders_are_never_high_confidence() {
+        let favored = OrderInfo::new("a".to_string(), 3);
+        assert!(favored.is_high_confidence());
+        assert!((favored.limit_price - 0.999).abs() < 1e-12);
+
+        let cheaper = OrderInfo::cheaper_side("b".to_string(), 3, 0.312);
+        assert_eq!(cheaper.kind, OrderKind::CheaperSide);
+        assert_eq!(cheaper.limit_price, 0.312);
+        assert!(!cheaper.is_high_confidence());
+
+        let mut state = TrackerState::new();
+        state.order_placed.insert("token".to_string(), cheaper);
+        assert!(!state.has_high_confidence_order());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::domain::models::{DbEvent, DbMarket};
 use crate::infrastructure::database::MarketDatabase;
 use crate::infrastructure::client::gamma::types::{Event, Market};
+use crate::infrastructure::client::gamma::ConversionError;
 use reqwest::Client;
 use std::sync::Arc;
 use std::time::Instant;
@@ -197,7 +198,13 @@ impl EventSyncService {
 
                 // Save each market (inheriting tags, description, and game_id from parent event)
                 for market in markets {
-                     let db_market = Self::market_to_db_market(market, event_tags_json.clone(), event_description.clone(), event_game_id)?;
+                     let db_market = match Self::market_to_db_market(market, event_tags_json.clone(), event_description.clone(), event_game_id) {
+                         Ok(db_market) => db_market,
+                         Err(e) => {
+                             warn!("Skipping market of event {}: {}", event_id, e);
+                             continue;
+                         }
+                     };
                      if let Err(e) = self.database.upsert_market(db_market).await {
                          warn!("Failed to save market: {}", e);
                      }
@@ -259,39 +266,12 @@ impl EventSyncService {
         event_tags: Option<String>,
         event_description: Option<String>,
         event_game_id: Option<i64>,
-    ) -> anyhow::Result<DbMarket> {
-        let now = Utc::now().to_rfc3339();
-
-        let outcomes_json = market
-            .outcomes
-            .as_ref()
-            .map(|v| serde_json::to_string(v).unwrap_or_else(|_| "[]".to_string()))
-            .unwrap_or_else(|| "[]".to_string());
-
-        let token_ids_json = market.clob_token_ids.clone().unwrap_or_else(|| "[]".to_string());
-
+    ) -> Result<DbMarket, ConversionError> {
         Ok(DbMarket {
-            id: market.id.clone().unwrap_or_default(),
-            condition_id: market.condition_id.clone(),
-            question: market.question.clone().unwrap_or_default(),
             description: event_description,
-            slug: market.slug.clone(),
-            start_date: market.start_date.clone().unwrap_or_else(|| now.clone()),
-            end_date: market.end_date.clone().unwrap_or_else(|| now.clone()),
-            resolution_time: market.end_date.clone().unwrap_or_else(|| now.clone()),
-            active: market.active.unwrap_or(false),
-            closed: market.closed.unwrap_or(false),
-            archived: market.archived.unwrap_or(false),
-            market_type: None,
-            category: None,
-            liquidity: market.liquidity.clone(),
-            volume: market.volume.clone(),
-            outcomes: outcomes_json,
-            token_ids: token_ids_json,
             tags: event_tags,
-            last_updated: now.clone(),
-            created_at: market.created_at.clone().unwrap_or(now),
             game_id: event_game_id,
+            ..DbMarket::try_from_gamma(market)?
         })
     }
 }
@@ -1,6 +1,6 @@
 use crate::domain::models::{DbMarket, SyncStats};
-use crate::infrastructure::database::{DatabaseError, MarketDatabase, Result};
-use crate::infrastructure::client::{GammaClient, GammaMarket};
+use crate::infrastructure::database::{DatabaseError, MarketDatabase, Result, UpsertCounts, MARKET_SYNC};
+use crate::infrastructure::client::GammaClient;
 use chrono::Utc;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -39,32 +39,44 @@ impl MarketSyncService {
         info!("   Fetched {} markets from Gamma API", gamma_markets.len());
 
         // Convert to DB format and insert
-        let mut inserted = 0;
+        let mut counts = UpsertCounts::default();
+        let mut failed = 0;
         for gamma_market in &gamma_markets {
-            match Self::convert_gamma_to_db(gamma_market) {
-                Ok(db_market) => {
-                    if let Err(e) = self.database.upsert_market(db_market).await {
+            match DbMarket::try_from_gamma(gamma_market) {
+                Ok(db_market) => match self.database.upsert_market(db_market).await {
+                    Ok(outcome) => counts.record(outcome),
+                    Err(e) => {
                         warn!("Failed to insert market {}: {}", gamma_market.id.as_ref().unwrap_or(&"unknown".to_string()), e);
-                    } else {
-                        inserted += 1;
                     }
-                }
+                },
                 Err(e) => {
-                    warn!("Failed to convert market {}: {}", gamma_market.id.as_ref().unwrap_or(&"unknown".to_string()), e);
+                    failed += 1;
+                    warn!("Skipping Gamma market: {}", e);
                 }
             }
         }
 
+        let enriched = self.enrich_tags().await;
+
         // Update last sync timestamp
-        *self.last_sync.write().await = Some(Utc::now());
+        self.record_success().await;
 
         let duration = start.elapsed();
-        info!("✅ Initial sync complete: {} markets in {:?}", inserted, duration);
+        info!(
+            "✅ Initial sync complete: {} markets written, {} unchanged, {} rejected in {:?}",
+            counts.written(),
+            counts.unchanged,
+            failed,
+            duration
+        );
 
         Ok(SyncStats {
             markets_fetched: gamma_markets.len(),
-            markets_inserted: inserted,
-            markets_updated: 0,
+            markets_inserted: counts.inserted,
+            markets_updated: counts.updated,
+            markets_skipped: counts.unchanged,
+            markets_enriched: enriched,
+            markets_failed: failed,
             duration,
         })
     }
@@ -92,49 +104,69 @@ impl MarketSyncService {
         debug!("Fetched {} new markets", new_markets.len());
 
         // Update database
-        let mut inserted = 0;
-        let mut updated = 0;
+        let mut counts = UpsertCounts::default();
+        let mut failed = 0;
 
         for gamma_market in &new_markets {
-            match Self::convert_gamma_to_db(gamma_market) {
-                Ok(db_market) => {
-                    // Check if market exists
-                    let exists = self.database.get_market(&db_market.id).await.is_ok();
-
-                    if let Err(e) = self.database.upsert_market(db_market).await {
+            match DbMarket::try_from_gamma(gamma_market) {
+                Ok(db_market) => match self.database.upsert_market(db_market).await {
+                    Ok(outcome) => counts.record(outcome),
+                    Err(e) => {
                         warn!("Failed to upsert market {}: {}", gamma_market.id.as_ref().unwrap_or(&"unknown".to_string()), e);
-                    } else if exists {
-                        updated += 1;
-                    } else {
-                        inserted += 1;
                     }
-                }
+                },
                 Err(e) => {
-                    warn!("Failed to convert market {}: {}", gamma_market.id.as_ref().unwrap_or(&"unknown".to_string()), e);
+                    failed += 1;
+                    warn!("Skipping Gamma market: {}", e);
                 }
             }
         }
 
+        let enriched = self.enrich_tags().await;
+
         // Update last sync timestamp
-        *self.last_sync.write().await = Some(Utc::now());
+        self.record_success().await;
 
         let duration = start.elapsed();
 
-        if inserted > 0 || updated > 0 {
+        if counts.written() > 0 || failed > 0 {
             info!(
-                "Incremental sync: {} new, {} updated in {:?}",
-                inserted, updated, duration
+                "Incremental sync: {} new, {} updated, {} unchanged, {} rejected in {:?}",
+                counts.inserted, counts.updated, counts.unchanged, failed, duration
             );
         }
 
         Ok(SyncStats {
             markets_fetched: new_markets.len(),
-            markets_inserted: inserted,
-            markets_updated: updated,
+            markets_inserted: counts.inserted,
+            markets_updated: counts.updated,
+            markets_skipped: counts.unchanged,
+            markets_enriched: enriched,
+            markets_failed: failed,
             duration,
         })
     }
 
+    /// Stamp a completed sync locally and in `sync_state` for the freshness watchdog
+    async fn record_success(&self) {
+        let now = Utc::now();
+        *self.last_sync.write().await = Some(now);
+        if let Err(e) = self.database.record_sync_success(MARKET_SYNC, now).await {
+            warn!("Failed to record market sync time: {}", e);
+        }
+    }
+
+    /// Copy Gamma event tags onto linked markets, which are synced without tags
+    async fn enrich_tags(&self) -> usize {
+        match self.database.enrich_market_tags().await {
+            Ok(enriched) => enriched as usize,
+            Err(e) => {
+                warn!("Failed to enrich market tags: {}", e);
+                0
+            }
+        }
+    }
+
     /// Background sync loop - runs incremental sync at regular intervals
     pub async fn start_sync_loop(self: Arc<Self>, interval: Duration) {
         info!("Starting background sync loop (interval: {:?})", interval);
@@ -151,6 +183,10 @@ impl MarketSyncService {
                         );
                     }
                 }
+                // The breaker already logged the outage when it opened
+                Err(e) if self.gamma_client.is_circuit_open() => {
+                    debug!("Sync skipped: {}", e);
+                }
                 Err(e) => {
                     warn!("Sync failed: {}", e);
                 }
@@ -158,46 +194,6 @@ impl MarketSyncService {
         }
     }
 
-    /// Convert Gamma API market to database format
-    fn convert_gamma_to_db(gamma: &GammaMarket) -> std::result::Result<DbMarket, String> {
-        let now = Utc::now().to_rfc3339();
-
-        // Serialize outcomes to JSON
-        let outcomes_json = if let Some(ref outcomes) = gamma.outcomes {
-            serde_json::to_string(outcomes)
-                .map_err(|e| format!("Failed to serialize outcomes: {}", e))?
-        } else {
-            "[]".to_string()
-        };
-
-        // clob_token_ids is already a string in the new schema
-        let token_ids_json = gamma.clob_token_ids.clone().unwrap_or_else(|| "[]".to_string());
-
-        Ok(DbMarket {
-            id: gamma.id.clone().unwrap_or_default(),
-            condition_id: gamma.condition_id.clone(),
-            question: gamma.question.clone().unwrap_or_default(),
-            description: None, // Markets synced directly from Gamma don't have parent event description
-            slug: gamma.slug.clone(),
-            start_date: gamma.start_date.clone().unwrap_or_else(|| now.clone()),
-            end_date: gamma.end_date.clone().unwrap_or_else(|| now.clone()),
-            resolution_time: gamma.end_date.clone().unwrap_or_else(|| now.clone()),
-            active: gamma.active.unwrap_or(false),
-            closed: gamma.closed.unwrap_or(false),
-            archived: gamma.archived.unwrap_or(false),
-            market_type: None, // Not present in new Market struct
-            category: None, // Not present in new Market struct
-            liquidity: gamma.liquidity.clone(),
-            volume: gamma.volume.clone(),
-            outcomes: outcomes_json,
-            token_ids: token_ids_json,
-            tags: None, // Markets synced directly from Gamma don't have tags
-            last_updated: now.clone(),
-            created_at: now,
-            game_id: None, // Markets synced directly from Gamma don't have parent event game_id
-        })
-    }
-
     /// Get last sync time
     pub async fn last_sync_time(&self) -> Option<chrono::DateTime<Utc>> {
         *self.last_sync.read().await
@@ -1,5 +1,7 @@
 pub mod events;
 pub mod markets;
+pub mod watchdog;
 
 pub use events::EventSyncService;
 pub use markets::MarketSyncService;
+pub use watchdog::{FreshnessSource, FreshnessWatchdog, StaleDataAlert};
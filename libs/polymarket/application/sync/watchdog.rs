@@ -0,0 +1,170 @@
+//! Data-freshness watchdog for the markets table
+//!
+//! If the syncer dies silently, `markets` stops being updated and strategies
+//! keep trading on old data. The watchdog periodically compares the market
+//! syncer's last successful run (`sync_state`) against a maximum age and
+//! raises an alert (error log plus an optional webhook POST) when the table
+//! goes stale. It alerts once per stale period and logs again when data is
+//! fresh.
+//!
+//! The sync run is used rather than `markets.last_updated`, which only moves
+//! when some market's content changes.
+
+use crate::infrastructure::database::{MarketDatabase, Result, MARKET_SYNC};
+use chrono::{DateTime, Utc};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+/// Where the watchdog reads the newest update time from
+#[async_trait::async_trait]
+pub trait FreshnessSource: Send + Sync {
+    async fn latest_update(&self) -> Result<Option<DateTime<Utc>>>;
+}
+
+#[async_trait::async_trait]
+impl FreshnessSource for MarketDatabase {
+    async fn latest_update(&self) -> Result<Option<DateTime<Utc>>> {
+        self.last_sync_success(MARKET_SYNC).await
+    }
+}
+
+/// Raised when the markets table is older than allowed
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaleDataAlert {
+    /// Last successful market sync, `None` if none was recorded
+    pub last_update: Option<DateTime<Utc>>,
+    pub max_age: Duration,
+}
+
+impl std::fmt::Display for StaleDataAlert {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.last_update {
+            Some(ts) => write!(
+                f,
+                "markets table is stale: last successful sync {} (max age {}s)",
+                ts.to_rfc3339(),
+                self.max_age.as_secs()
+            ),
+            None => write!(f, "markets table is stale: no successful sync recorded"),
+        }
+    }
+}
+
+pub struct FreshnessWatchdog<S: FreshnessSource> {
+    source: Arc<S>,
+    max_age: Duration,
+    webhook_url: Option<String>,
+    http: reqwest::Client,
+    /// Whether an alert was raised for the current stale period
+    alerted: AtomicBool,
+}
+
+impl<S: FreshnessSource + 'static> FreshnessWatchdog<S> {
+    pub fn new(source: Arc<S>, max_age: Duration) -> Self {
+        Self {
+            source,
+            max_age,
+            webhook_url: None,
+            http: reqwest::Client::new(),
+            alerted: AtomicBool::new(false),
+        }
+    }
+
+    /// Also POST alerts as `{"text": ...}` to this URL
+    pub fn with_webhook(mut self, url: impl Into<String>) -> Self {
+        self.webhook_url = Some(url.into());
+        self
+    }
+
+    /// Check freshness at `now`, returning an alert when newly stale
+    pub async fn check(&self, now: DateTime<Utc>) -> Result<Option<StaleDataAlert>> {
+        let last_update = self.source.latest_update().await?;
+        let stale = match last_update {
+            Some(ts) => (now - ts).to_std().is_ok_and(|age| age > self.max_age),
+            None => true,
+        };
+
+        if !stale {
+            if self.alerted.swap(false, Ordering::SeqCst) {
+                info!("[Watchdog] Markets table is fresh again");
+            }
+            return Ok(None);
+        }
+
+        if self.alerted.swap(true, Ordering::SeqCst) {
+            return Ok(None); // Already alerted for this stale period
+        }
+
+        let alert = StaleDataAlert {
+            last_update,
+            max_age: self.max_age,
+        };
+        error!("[Watchdog] {}", alert);
+        self.send_webhook(&alert).await;
+        Ok(Some(alert))
+    }
+
+    async fn send_webhook(&self, alert: &StaleDataAlert) {
+        let Some(url) = &self.webhook_url else {
+            return;
+        };
+        let body = serde_json::json!({ "text": alert.to_string() });
+        if let Err(e) = self.http.post(url).json(&body).send().await {
+            warn!("[Watchdog] Failed to send webhook alert: {}", e);
+        }
+    }
+
+    /// Run the check every `interval` until `running` flips to false
+    pub fn spawn(self, interval: Duration, running: Arc<AtomicBool>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            while running.load(Ordering::Acquire) {
+                if let Err(e) = self.check(Utc::now()).await {
+                    warn!("[Watchdog] Freshness check failed: {}", e);
+                }
+                tokio::time::sleep(interval).await;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex;
+
+    struct MockSource(Mutex<Option<DateTime<Utc>>>);
+
+    #[async_trait::async_trait]
+    impl FreshnessSource for MockSource {
+        async fn latest_update(&self) -> Result<Option<DateTime<Utc>>> {
+            Ok(*self.0.lock())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stale_table_alerts_and_fresh_stays_quiet() {
+        let now = Utc::now();
+        let source = Arc::new(MockSource(Mutex::new(Some(now - chrono::Duration::seconds(30)))));
+        let watchdog = FreshnessWatchdog::new(Arc::clone(&source), Duration::from_secs(300));
+
+        // Fresh: quiet
+        assert_eq!(watchdog.check(now).await.unwrap(), None);
+
+        // Stale: one alert, not repeated while still stale
+        let stale_at = now - chrono::Duration::minutes(10);
+        *source.0.lock() = Some(stale_at);
+        let alert = watchdog.check(now).await.unwrap().unwrap();
+        assert_eq!(alert.last_update, Some(stale_at));
+        assert!(alert.to_string().contains("stale"));
+        assert_eq!(watchdog.check(now).await.unwrap(), None);
+
+        // Recovers, then a new stale period alerts again
+        *source.0.lock() = Some(now);
+        assert_eq!(watchdog.check(now).await.unwrap(), None);
+        *source.0.lock() = None;
+        assert!(watchdog.check(now).await.unwrap().is_some());
+    }
+}
@@ -17,9 +17,11 @@ use crate::infrastructure::client::user::{
     PositionTracker, SharedPositionTracker, PositionTrackerBridge,
     spawn_user_order_tracker,
 };
-use crate::infrastructure::MarketDatabase;
+use crate::infrastructure::{BalanceManager, MarketDatabase, MarketHalts};
 
-use super::state::MarketInfo;
+use super::manual_order::{submit_manual_order, OrderPrompt, PromptAction};
+use super::config::VisualizerConfig;
+use super::state::{DerivedStats, MarketInfo, OrderbookView, SidebarSearch};
 use crate::application::strategies::inventory_mm::quoter::{
     QuoterWsConfig, QuoterWsClient, build_quoter_ws_client, wait_for_snapshot,
 };
@@ -35,7 +37,9 @@ pub struct App {
     /// Database for market metadata
     pub database: Arc<MarketDatabase>,
     /// Trading client for order operations
-    trading_client: TradingClient,
+    trading_client: Arc<TradingClient>,
+    /// Free USDC balance monitor
+    balance_manager: BalanceManager,
     /// WebSocket clients (keep them alive)
     ws_clients: Vec<QuoterWsClient>,
     /// Markets we're active in
@@ -52,11 +56,27 @@ pub struct App {
     pub initialized: bool,
     /// Status message to show in footer
     pub status_message: Option<String>,
+    /// Active manual order prompt (captures keyboard input while open)
+    pub order_prompt: Option<OrderPrompt>,
+    /// Sidebar search/filter state
+    pub search: SidebarSearch,
+    /// Visualizer settings (refresh intervals)
+    pub config: VisualizerConfig,
+    /// Header figures recomputed on each refresh tick
+    pub stats: DerivedStats,
+    /// Orderbook rendering mode (table or heatmap)
+    pub orderbook_view: OrderbookView,
+    /// Per-market kill switch (blocks manual orders on halted markets)
+    pub market_halts: MarketHalts,
 }
 
 impl App {
     /// Initialize the application with real-time components
-    pub async fn initialize(runtime: Handle, database_url: &str) -> Result<Self> {
+    pub async fn initialize(
+        runtime: Handle,
+        database_url: &str,
+        config: VisualizerConfig,
+    ) -> Result<Self> {
         // true = keep running, false = shutdown requested
         let shutdown_flag = Arc::new(AtomicBool::new(true));
 
@@ -67,10 +87,19 @@ impl App {
 
         // Initialize trading client (for REST and auth)
         info!("[Visualizer] Initializing trading client...");
-        let trading_client = TradingClient::from_env().await?;
+        let trading_client = Arc::new(TradingClient::from_env().await?);
         let rest_client = trading_client.rest();
         let auth = trading_client.auth();
 
+        // Start balance monitoring (for the header gauge)
+        let mut balance_manager = BalanceManager::default();
+        if let Err(e) = balance_manager
+            .start(trading_client.clone(), shutdown_flag.clone())
+            .await
+        {
+            warn!("[Visualizer] Failed to start balance manager: {}", e);
+        }
+
         // Create position tracker
         info!("[Visualizer] Creating position tracker...");
         let position_tracker: SharedPositionTracker = Arc::new(RwLock::new(PositionTracker::new()));
@@ -136,12 +165,23 @@ impl App {
             }
         }
 
+        let mut stats = DerivedStats::default();
+        stats.recompute(&order_state, &position_tracker);
+
+        // Halts are shared with the trading process through the database
+        let market_halts = MarketHalts::new();
+        match database.get_halted_markets().await {
+            Ok(halted) => halted.iter().for_each(|condition_id| market_halts.halt(condition_id)),
+            Err(e) => warn!("[Visualizer] Failed to load market halts: {}", e),
+        }
+
         Ok(Self {
             order_state,
             position_tracker,
             orderbooks,
             database,
             trading_client,
+            balance_manager,
             ws_clients,
             markets,
             selected_index: 0,
@@ -150,6 +190,12 @@ impl App {
             runtime,
             initialized: true,
             status_message: None,
+            order_prompt: None,
+            search: SidebarSearch::default(),
+            config,
+            stats,
+            orderbook_view: OrderbookView::default(),
+            market_halts,
         })
     }
 
@@ -213,7 +259,7 @@ impl App {
                             token_ids[down_idx].clone(),
                             outcomes[up_idx].clone(),
                             outcomes[down_idx].clone(),
-                        ));
+                        ).with_slug(db_market.slug.clone()));
                     } else {
                         warn!(
                             "[Visualizer] Market {} has insufficient outcomes ({}) or token_ids ({})",
@@ -242,16 +288,20 @@ impl App {
         lower == "yes" || lower.contains("up") || lower.contains("above") || lower.contains("over")
     }
 
-    /// Navigate to next market
+    /// Navigate to next market (restricted to search matches when a filter is active)
     pub fn next_market(&mut self) {
-        if !self.markets.is_empty() {
+        if self.search.is_active() {
+            self.next_search_match();
+        } else if !self.markets.is_empty() {
             self.selected_index = (self.selected_index + 1) % self.markets.len();
         }
     }
 
-    /// Navigate to previous market
+    /// Navigate to previous market (restricted to search matches when a filter is active)
     pub fn prev_market(&mut self) {
-        if !self.markets.is_empty() {
+        if self.search.is_active() {
+            self.prev_search_match();
+        } else if !self.markets.is_empty() {
             self.selected_index = if self.selected_index == 0 {
                 self.markets.len() - 1
             } else {
@@ -260,6 +310,45 @@ impl App {
         }
     }
 
+    /// Indices of markets shown in the sidebar under the current search
+    pub fn visible_market_indices(&self) -> Vec<usize> {
+        self.search.visible_indices(&self.markets)
+    }
+
+    /// Start typing a new search query (`/`)
+    pub fn start_search(&mut self) {
+        self.search.start();
+    }
+
+    /// Finish typing the query and jump to the first match
+    pub fn commit_search(&mut self) {
+        self.search.commit();
+        if let Some(&first) = self.visible_market_indices().first() {
+            self.selected_index = first;
+        } else if self.search.is_active() {
+            self.status_message = Some(format!("No markets match '{}'", self.search.query));
+        }
+    }
+
+    /// Clear the search and restore the full market list
+    pub fn clear_search(&mut self) {
+        self.search.clear();
+    }
+
+    /// Jump to the next search match (`n`)
+    pub fn next_search_match(&mut self) {
+        if let Some(i) = self.search.next_match(&self.markets, self.selected_index) {
+            self.selected_index = i;
+        }
+    }
+
+    /// Jump to the previous search match (`N`)
+    pub fn prev_search_match(&mut self) {
+        if let Some(i) = self.search.prev_match(&self.markets, self.selected_index) {
+            self.selected_index = i;
+        }
+    }
+
     /// Remove markets that have no orders and no positions
     fn remove_inactive_markets(&mut self) {
         let mut indices_to_remove: Vec<usize> = Vec::new();
@@ -352,9 +441,29 @@ impl App {
         }
     }
 
-    /// Get currently selected market
+    /// Auto-refresh tick: recompute derived header fields even when no events arrive
+    pub fn tick(&mut self) {
+        self.stats.recompute(&self.order_state, &self.position_tracker);
+    }
+
+    /// Toggle between table and heatmap orderbook views
+    pub fn toggle_orderbook_view(&mut self) {
+        self.orderbook_view = self.orderbook_view.toggle();
+    }
+
+    /// Current free USDC balance
+    pub fn free_balance(&self) -> f64 {
+        self.balance_manager.current_balance()
+    }
+
+    /// Get currently selected market, or `None` while the search filter hides it
+    ///
+    /// Keeps dump, kill-switch and order keys from acting on a market the
+    /// sidebar is not showing when a committed filter matches nothing.
     pub fn get_selected_market(&self) -> Option<&MarketInfo> {
-        self.markets.get(self.selected_index)
+        self.markets
+            .get(self.selected_index)
+            .filter(|market| self.search.shows(market))
     }
 
     /// Check if OMS is connected
@@ -362,25 +471,6 @@ impl App {
         self.initialized
     }
 
-    /// Get total open order count
-    pub fn get_total_order_count(&self) -> usize {
-        let oms = self.order_state.read();
-        let mut count = 0;
-        for asset_id in oms.asset_ids() {
-            for order in oms.get_bids(&asset_id) {
-                if order.is_open() {
-                    count += 1;
-                }
-            }
-            for order in oms.get_asks(&asset_id) {
-                if order.is_open() {
-                    count += 1;
-                }
-            }
-        }
-        count
-    }
-
     /// Get orderbook levels for a token
     /// Returns (asks, bids, spread) - asks sorted low to high, bids sorted high to low
     pub fn get_orderbook_levels(&self, token_id: &str) -> (Vec<(f64, f64)>, Vec<(f64, f64)>, Option<f64>) {
@@ -548,8 +638,12 @@ impl App {
         }
     }
 
-    /// Cancel all open orders
-    pub fn cancel_all_orders(&self) {
+    /// Cancel all open orders; does nothing while the search filter matches no market
+    pub fn cancel_all_orders(&mut self) {
+        if self.visible_market_indices().is_empty() && self.search.is_active() {
+            self.status_message = Some(format!("No markets match '{}'", self.search.query));
+            return;
+        }
         let _ = self.runtime.block_on(async {
             self.trading_client.cancel_all().await
         });
@@ -603,10 +697,79 @@ impl App {
         self.status_message = Some(result);
     }
 
+    /// Toggle the kill switch for the selected market
+    pub fn toggle_market_halt(&mut self) {
+        let Some(market) = self.get_selected_market() else {
+            self.status_message = Some("No market selected".to_string());
+            return;
+        };
+
+        let name = market.display_name.clone();
+        let condition_id = market.condition_id.clone();
+        let halted = !self.market_halts.is_halted(&condition_id);
+        if let Err(e) = self
+            .runtime
+            .block_on(self.database.set_market_halted(&condition_id, halted))
+        {
+            self.status_message = Some(format!("Failed to update halt for {}: {}", name, e));
+            return;
+        }
+
+        self.market_halts.toggle(&condition_id);
+        self.status_message = Some(if halted {
+            format!("{} HALTED", name)
+        } else {
+            format!("{} resumed", name)
+        });
+    }
+
+    /// Open the manual order prompt for the selected market
+    /// `is_up` selects which outcome token the order targets
+    pub fn start_manual_order(&mut self, is_up: bool) {
+        let Some(market) = self.get_selected_market() else {
+            self.status_message = Some("No market selected".to_string());
+            return;
+        };
+
+        if self.market_halts.is_halted(&market.condition_id) {
+            self.status_message = Some("Market halted - press K to resume".to_string());
+            return;
+        }
+
+        let (token_id, outcome) = if is_up {
+            (market.up_token_id.clone(), market.up_outcome.clone())
+        } else {
+            (market.down_token_id.clone(), market.down_outcome.clone())
+        };
+
+        self.order_prompt = Some(OrderPrompt::new(token_id, outcome));
+        self.status_message = None;
+    }
+
+    /// Feed a prompt action back into the app, submitting the order if confirmed
+    pub fn apply_prompt_action(&mut self, action: PromptAction) {
+        match action {
+            PromptAction::Pending => {}
+            PromptAction::Cancelled => {
+                self.order_prompt = None;
+                self.status_message = Some("Order cancelled".to_string());
+            }
+            PromptAction::Submit(order) => {
+                self.order_prompt = None;
+                let backend = self.trading_client.clone();
+                let result = self.runtime.block_on(async {
+                    submit_manual_order(backend.as_ref(), &order).await
+                });
+                self.status_message = Some(result);
+            }
+        }
+    }
+
     /// Shutdown the application
     pub fn shutdown(&mut self) {
         info!("[Visualizer] Shutting down...");
         self.shutdown_flag.store(false, Ordering::Release);
+        self.runtime.block_on(self.balance_manager.stop());
 
         // Shutdown WebSocket clients by draining and consuming them
         let clients = std::mem::take(&mut self.ws_clients);
@@ -0,0 +1,72 @@
+//! Visualizer configuration
+//!
+//! All settings have sensible defaults and can be overridden via environment
+//! variables, matching how the binary already reads `VISUALIZER_DATABASE_URL`.
+
+use std::time::Duration;
+
+/// Default interval for the auto-refresh tick (header counts, P&L)
+const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Default interval for re-discovering markets from orders
+const DEFAULT_MARKET_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Default fraction of free balance above which exposure is flagged red
+const DEFAULT_EXPOSURE_WARN_FRACTION: f64 = 0.5;
+
+/// Runtime settings for the visualizer
+#[derive(Debug, Clone)]
+pub struct VisualizerConfig {
+    /// How often derived fields (header counts, P&L) are recomputed
+    pub refresh_interval: Duration,
+    /// How often markets are re-discovered from orders/positions
+    pub market_refresh_interval: Duration,
+    /// Exposure / balance ratio above which the header gauge turns red
+    pub exposure_warn_fraction: f64,
+}
+
+impl Default for VisualizerConfig {
+    fn default() -> Self {
+        Self {
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+            market_refresh_interval: DEFAULT_MARKET_REFRESH_INTERVAL,
+            exposure_warn_fraction: DEFAULT_EXPOSURE_WARN_FRACTION,
+        }
+    }
+}
+
+impl VisualizerConfig {
+    /// Build config from defaults, overridden by environment variables:
+    /// - `VISUALIZER_REFRESH_MS`: auto-refresh tick in milliseconds
+    /// - `VISUALIZER_MARKET_REFRESH_SECS`: market re-discovery interval in seconds
+    /// - `VISUALIZER_EXPOSURE_WARN_FRACTION`: exposure/balance ratio that turns the gauge red
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+
+        if let Some(ms) = env_u64("VISUALIZER_REFRESH_MS") {
+            config.refresh_interval = Duration::from_millis(ms.max(1));
+        }
+        if let Some(secs) = env_u64("VISUALIZER_MARKET_REFRESH_SECS") {
+            config.market_refresh_interval = Duration::from_secs(secs.max(1));
+        }
+        if let Some(fraction) = std::env::var("VISUALIZER_EXPOSURE_WARN_FRACTION")
+            .ok()
+            .and_then(|v| v.trim().parse::<f64>().ok())
+            .filter(|f| *f > 0.0)
+        {
+            config.exposure_warn_fraction = fraction;
+        }
+
+        config
+    }
+
+    /// Set the auto-refresh tick interval
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = interval;
+        self
+    }
+}
+
+fn env_u64(key: &str) -> Option<u64> {
+    std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+}
@@ -0,0 +1,389 @@
+//! Manual order entry for the visualizer
+//!
+//! Lets the operator place a small order on the selected market from the TUI.
+//! The prompt walks through price -> size -> confirmation, and submission goes
+//! through an `ExecutionBackend` so it can be exercised without a live client.
+
+use crate::infrastructure::client::clob::{
+    CancelResponse, OpenOrder, OrderPlacementResponse, OrderType, Side, TradingClient,
+    TradingError,
+};
+
+/// Order operations behind manual entry and `RiskManager::flatten_all`
+#[async_trait::async_trait]
+pub trait ExecutionBackend: Send + Sync {
+    /// Place a limit order and return the exchange response
+    async fn place_order(
+        &self,
+        token_id: &str,
+        price: f64,
+        size: f64,
+        side: Side,
+        order_type: OrderType,
+    ) -> Result<OrderPlacementResponse, TradingError>;
+
+    /// Open orders on the account
+    async fn get_orders(&self) -> Result<Vec<OpenOrder>, TradingError>;
+
+    /// Cancel every open order on the account
+    async fn cancel_all(&self) -> Result<CancelResponse, TradingError>;
+}
+
+#[async_trait::async_trait]
+impl ExecutionBackend for TradingClient {
+    async fn place_order(
+        &self,
+        token_id: &str,
+        price: f64,
+        size: f64,
+        side: Side,
+        order_type: OrderType,
+    ) -> Result<OrderPlacementResponse, TradingError> {
+        TradingClient::place_order(self, token_id, price, size, side, order_type).await
+    }
+
+    async fn get_orders(&self) -> Result<Vec<OpenOrder>, TradingError> {
+        TradingClient::get_orders(self, None).await
+    }
+
+    async fn cancel_all(&self) -> Result<CancelResponse, TradingError> {
+        TradingClient::cancel_all(self).await
+    }
+}
+
+/// A fully specified manual order, ready to submit
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManualOrder {
+    pub token_id: String,
+    pub outcome: String,
+    pub price: f64,
+    pub size: f64,
+    pub side: Side,
+}
+
+/// Which field the prompt is currently collecting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptStage {
+    Price,
+    Size,
+    Confirm,
+}
+
+/// Outcome of feeding a key into the prompt
+#[derive(Debug, Clone, PartialEq)]
+pub enum PromptAction {
+    /// Prompt still collecting input
+    Pending,
+    /// User confirmed - submit this order
+    Submit(ManualOrder),
+    /// User aborted the prompt
+    Cancelled,
+}
+
+/// Interactive prompt state for a manual order
+#[derive(Debug, Clone)]
+pub struct OrderPrompt {
+    token_id: String,
+    outcome: String,
+    side: Side,
+    stage: PromptStage,
+    input: String,
+    price: Option<f64>,
+    size: Option<f64>,
+    error: Option<String>,
+}
+
+impl OrderPrompt {
+    /// Start a new buy prompt for the given token
+    pub fn new(token_id: String, outcome: String) -> Self {
+        Self {
+            token_id,
+            outcome,
+            side: Side::Buy,
+            stage: PromptStage::Price,
+            input: String::new(),
+            price: None,
+            size: None,
+            error: None,
+        }
+    }
+
+    /// Current stage of the prompt
+    pub fn stage(&self) -> PromptStage {
+        self.stage
+    }
+
+    /// Toggle between buy and sell
+    pub fn toggle_side(&mut self) {
+        self.side = match self.side {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        };
+    }
+
+    /// Append a character to the current input (digits and '.' only)
+    pub fn push_char(&mut self, c: char) {
+        if self.stage != PromptStage::Confirm && (c.is_ascii_digit() || c == '.') {
+            self.input.push(c);
+            self.error = None;
+        }
+    }
+
+    /// Remove the last character of the current input
+    pub fn backspace(&mut self) {
+        self.input.pop();
+    }
+
+    /// Handle Enter: validate the current field and move to the next stage
+    pub fn advance(&mut self) -> PromptAction {
+        match self.stage {
+            PromptStage::Price => match self.input.parse::<f64>() {
+                Ok(price) if price > 0.0 && price < 1.0 => {
+                    self.price = Some(price);
+                    self.input.clear();
+                    self.stage = PromptStage::Size;
+                }
+                _ => self.error = Some("price must be in (0, 1)".to_string()),
+            },
+            PromptStage::Size => match self.input.parse::<f64>() {
+                Ok(size) if size > 0.0 => {
+                    self.size = Some(size);
+                    self.input.clear();
+                    self.stage = PromptStage::Confirm;
+                }
+                _ => self.error = Some("size must be positive".to_string()),
+            },
+            PromptStage::Confirm => return self.confirm(),
+        }
+        PromptAction::Pending
+    }
+
+    /// Handle the confirmation answer ('y' submits, anything else cancels)
+    pub fn answer(&mut self, yes: bool) -> PromptAction {
+        if self.stage != PromptStage::Confirm {
+            return PromptAction::Pending;
+        }
+        if yes {
+            self.confirm()
+        } else {
+            PromptAction::Cancelled
+        }
+    }
+
+    fn confirm(&self) -> PromptAction {
+        match (self.price, self.size) {
+            (Some(price), Some(size)) => PromptAction::Submit(ManualOrder {
+                token_id: self.token_id.clone(),
+                outcome: self.outcome.clone(),
+                price,
+                size,
+                side: self.side,
+            }),
+            _ => PromptAction::Cancelled,
+        }
+    }
+
+    /// Render the prompt as a single status line
+    pub fn display(&self) -> String {
+        let side = side_label(self.side);
+        let line = match self.stage {
+            PromptStage::Price => format!(
+                "{} {} | price: {}_ (Enter=next Tab=side Esc=cancel)",
+                side, self.outcome, self.input
+            ),
+            PromptStage::Size => format!(
+                "{} {} @ {:.4} | size: {}_ (Enter=next Esc=cancel)",
+                side,
+                self.outcome,
+                self.price.unwrap_or(0.0),
+                self.input
+            ),
+            PromptStage::Confirm => format!(
+                "{} {:.2} {} @ {:.4}? (y/n)",
+                side,
+                self.size.unwrap_or(0.0),
+                self.outcome,
+                self.price.unwrap_or(0.0)
+            ),
+        };
+
+        match &self.error {
+            Some(err) => format!("{} [{}]", line, err),
+            None => line,
+        }
+    }
+}
+
+fn side_label(side: Side) -> &'static str {
+    match side {
+        Side::Buy => "BUY",
+        Side::Sell => "SELL",
+    }
+}
+
+/// Submit a manual order through the backend and format the result for the status line
+pub async fn submit_manual_order(backend: &dyn ExecutionBackend, order: &ManualOrder) -> String {
+    let side = side_label(order.side);
+    match backend
+        .place_order(&order.token_id, order.price, order.size, order.side, OrderType::GTC)
+        .await
+    {
+        Ok(resp) if resp.success => format!(
+            "{} {:.2} {} @ {:.4} placed ({})",
+            side,
+            order.size,
+            order.outcome,
+            order.price,
+            resp.order_id.as_deref().unwrap_or("no id")
+        ),
+        Ok(resp) => format!(
+            "{} rejected: {}",
+            side,
+            resp.error_msg.as_deref().unwrap_or("unknown error")
+        ),
+        Err(e) => format!("{} failed: {}", side, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex;
+
+    /// One `place_order` call seen by the mock
+    #[derive(Debug, PartialEq)]
+    struct PlacedCall {
+        token_id: String,
+        price: f64,
+        size: f64,
+        side: Side,
+        order_type: OrderType,
+    }
+
+    /// Records every call and returns a canned response
+    struct MockBackend {
+        calls: Mutex<Vec<PlacedCall>>,
+        success: bool,
+    }
+
+    impl MockBackend {
+        fn new(success: bool) -> Self {
+            Self {
+                calls: Mutex::new(Vec::new()),
+                success,
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ExecutionBackend for MockBackend {
+        async fn place_order(
+            &self,
+            token_id: &str,
+            price: f64,
+            size: f64,
+            side: Side,
+            order_type: OrderType,
+        ) -> Result<OrderPlacementResponse, TradingError> {
+            self.calls.lock().push(PlacedCall {
+                token_id: token_id.to_string(),
+                price,
+                size,
+                side,
+                order_type,
+            });
+            Ok(OrderPlacementResponse {
+                order_id: self.success.then(|| "0xabc".to_string()),
+                success: self.success,
+                error_msg: (!self.success).then(|| "not enough balance".to_string()),
+                status: None,
+                order_hashes: None,
+            })
+        }
+
+        async fn get_orders(&self) -> Result<Vec<OpenOrder>, TradingError> {
+            Ok(Vec::new())
+        }
+
+        async fn cancel_all(&self) -> Result<CancelResponse, TradingError> {
+            Ok(CancelResponse {
+                canceled: Vec::new(),
+                not_canceled: Default::default(),
+            })
+        }
+    }
+
+    fn type_str(prompt: &mut OrderPrompt, s: &str) {
+        for c in s.chars() {
+            prompt.push_char(c);
+        }
+    }
+
+    fn fill_prompt(prompt: &mut OrderPrompt) -> ManualOrder {
+        type_str(prompt, "0.45");
+        assert_eq!(prompt.advance(), PromptAction::Pending);
+        type_str(prompt, "5");
+        assert_eq!(prompt.advance(), PromptAction::Pending);
+        assert_eq!(prompt.stage(), PromptStage::Confirm);
+        match prompt.answer(true) {
+            PromptAction::Submit(order) => order,
+            other => panic!("expected submit, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_prompt_rejects_invalid_price() {
+        let mut prompt = OrderPrompt::new("tok".to_string(), "Up".to_string());
+        type_str(&mut prompt, "1.5");
+        assert_eq!(prompt.advance(), PromptAction::Pending);
+        assert_eq!(prompt.stage(), PromptStage::Price);
+        assert!(prompt.display().contains("price must be"));
+    }
+
+    #[test]
+    fn test_prompt_cancel_on_no() {
+        let mut prompt = OrderPrompt::new("tok".to_string(), "Up".to_string());
+        type_str(&mut prompt, "0.5");
+        prompt.advance();
+        type_str(&mut prompt, "2");
+        prompt.advance();
+        assert_eq!(prompt.answer(false), PromptAction::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_submit_passes_order_to_backend() {
+        let backend = MockBackend::new(true);
+        let mut prompt = OrderPrompt::new("token-up".to_string(), "Up".to_string());
+        let order = fill_prompt(&mut prompt);
+
+        let status = submit_manual_order(&backend, &order).await;
+
+        let calls = backend.calls.lock();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(
+            calls[0],
+            PlacedCall {
+                token_id: "token-up".to_string(),
+                price: 0.45,
+                size: 5.0,
+                side: Side::Buy,
+                order_type: OrderType::GTC,
+            }
+        );
+        assert!(status.contains("placed"));
+        assert!(status.contains("0xabc"));
+    }
+
+    #[tokio::test]
+    async fn test_submit_reports_rejection() {
+        let backend = MockBackend::new(false);
+        let mut prompt = OrderPrompt::new("token-down".to_string(), "Down".to_string());
+        prompt.toggle_side();
+        let order = fill_prompt(&mut prompt);
+
+        let status = submit_manual_order(&backend, &order).await;
+
+        assert_eq!(backend.calls.lock()[0].side, Side::Sell);
+        assert_eq!(status, "SELL rejected: not enough balance");
+    }
+}
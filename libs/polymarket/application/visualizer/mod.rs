@@ -4,8 +4,13 @@
 //! Uses the same real-time WebSocket components as the strategy.
 
 pub mod app;
+pub mod config;
+pub mod manual_order;
 pub mod state;
+pub mod terminal;
 pub mod ui;
 
 pub use app::App;
-pub use state::{MarketInfo, VisualizerState};
+pub use config::VisualizerConfig;
+pub use manual_order::{ExecutionBackend, ManualOrder, OrderPrompt, PromptAction};
+pub use state::{DerivedStats, MarketInfo, OrderbookView, SidebarSearch, VisualizerState};
@@ -2,6 +2,8 @@
 
 use std::collections::HashMap;
 
+use crate::infrastructure::client::user::{SharedOrderState, SharedPositionTracker};
+
 /// Information about a market we're active in
 #[derive(Debug, Clone)]
 pub struct MarketInfo {
@@ -13,6 +15,7 @@ pub struct MarketInfo {
     pub up_outcome: String,
     pub down_outcome: String,
     pub display_name: String,
+    pub slug: Option<String>,
 }
 
 impl MarketInfo {
@@ -37,9 +40,27 @@ impl MarketInfo {
             up_outcome,
             down_outcome,
             display_name,
+            slug: None,
         }
     }
 
+    /// Attach the market slug (used for sidebar search)
+    pub fn with_slug(mut self, slug: Option<String>) -> Self {
+        self.slug = slug;
+        self
+    }
+
+    /// Case-insensitive match of a query against the question and slug
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.question.to_lowercase().contains(&query)
+            || self
+                .slug
+                .as_ref()
+                .map(|s| s.to_lowercase().contains(&query))
+                .unwrap_or(false)
+    }
+
     /// Create a short display name from the question
     fn create_display_name(question: &str) -> String {
         // Try to extract a meaningful short name
@@ -62,6 +83,139 @@ impl MarketInfo {
     }
 }
 
+/// How the orderbook ladder is rendered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OrderbookView {
+    /// Price/size table (default)
+    #[default]
+    Table,
+    /// Each level's size drawn as a bar scaled to the largest level
+    Heatmap,
+}
+
+impl OrderbookView {
+    /// Switch to the other view
+    pub fn toggle(self) -> Self {
+        match self {
+            OrderbookView::Table => OrderbookView::Heatmap,
+            OrderbookView::Heatmap => OrderbookView::Table,
+        }
+    }
+}
+
+/// Vim-style sidebar search (`/` to edit, `n`/`N` to jump between matches)
+#[derive(Debug, Clone, Default)]
+pub struct SidebarSearch {
+    /// Current query (empty = no filter)
+    pub query: String,
+    /// Whether keystrokes are currently being typed into the query
+    pub editing: bool,
+}
+
+impl SidebarSearch {
+    /// Begin editing a fresh query
+    pub fn start(&mut self) {
+        self.query.clear();
+        self.editing = true;
+    }
+
+    /// Stop editing, keeping the query as the active filter
+    pub fn commit(&mut self) {
+        self.editing = false;
+    }
+
+    /// Drop the query and restore the full list
+    pub fn clear(&mut self) {
+        self.query.clear();
+        self.editing = false;
+    }
+
+    /// Whether a filter is currently applied
+    pub fn is_active(&self) -> bool {
+        !self.query.is_empty()
+    }
+
+    /// Whether `market` is visible under the current filter
+    pub fn shows(&self, market: &MarketInfo) -> bool {
+        !self.is_active() || market.matches(&self.query)
+    }
+
+    /// Indices of markets visible under the current filter
+    pub fn visible_indices(&self, markets: &[MarketInfo]) -> Vec<usize> {
+        markets
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| self.shows(m))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Next visible index after `current`, wrapping around
+    pub fn next_match(&self, markets: &[MarketInfo], current: usize) -> Option<usize> {
+        let visible = self.visible_indices(markets);
+        visible
+            .iter()
+            .copied()
+            .find(|&i| i > current)
+            .or_else(|| visible.first().copied())
+    }
+
+    /// Previous visible index before `current`, wrapping around
+    pub fn prev_match(&self, markets: &[MarketInfo], current: usize) -> Option<usize> {
+        let visible = self.visible_indices(markets);
+        visible
+            .iter()
+            .rev()
+            .copied()
+            .find(|&i| i < current)
+            .or_else(|| visible.last().copied())
+    }
+}
+
+/// Derived figures shown in the header, recomputed on every refresh tick
+#[derive(Debug, Clone, Default)]
+pub struct DerivedStats {
+    /// Open orders across all assets
+    pub order_count: usize,
+    /// Tokens with a non-flat position
+    pub position_count: usize,
+    /// Realized P&L across all positions
+    pub realized_pnl: f64,
+    /// Total exposure at entry prices (sum of |size| * avg entry), from the
+    /// position tracker; `PositionManager` only redeems and keeps no sizes
+    pub exposure: f64,
+    /// Number of ticks processed so far
+    pub ticks: u64,
+}
+
+impl DerivedStats {
+    /// Recompute all fields from the live OMS and position tracker
+    pub fn recompute(&mut self, order_state: &SharedOrderState, position_tracker: &SharedPositionTracker) {
+        self.order_count = {
+            let oms = order_state.read();
+            oms.asset_ids()
+                .iter()
+                .map(|asset_id| oms.get_open_orders(asset_id).len())
+                .sum()
+        };
+
+        let tracker = position_tracker.read();
+        self.position_count = tracker
+            .get_all_positions()
+            .iter()
+            .filter(|p| !p.is_flat())
+            .count();
+        self.realized_pnl = tracker.get_total_realized_pnl();
+        self.exposure = tracker
+            .get_all_positions()
+            .iter()
+            .map(|p| p.size.abs() * p.avg_entry_price)
+            .sum();
+
+        self.ticks += 1;
+    }
+}
+
 /// State shared across the visualizer
 pub struct VisualizerState {
     /// Markets discovered from orders/positions
@@ -89,3 +243,101 @@ impl Default for VisualizerState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::client::user::{OrderStateStore, PositionTracker, Side};
+    use parking_lot::RwLock;
+    use std::sync::Arc;
+
+    fn market(question: &str, slug: &str) -> MarketInfo {
+        MarketInfo::new(
+            format!("cond-{}", slug),
+            format!("id-{}", slug),
+            question.to_string(),
+            "up".to_string(),
+            "down".to_string(),
+            "Up".to_string(),
+            "Down".to_string(),
+        )
+        .with_slug(Some(slug.to_string()))
+    }
+
+    fn markets() -> Vec<MarketInfo> {
+        vec![
+            market("Bitcoin Up or Down - 10AM ET", "btc-updown-15m-1"),
+            market("Ethereum Up or Down - 10AM ET", "eth-updown-15m-1"),
+            market("Will the Lakers win?", "nba-lal-bos"),
+            market("Bitcoin Up or Down - 11AM ET", "btc-updown-15m-2"),
+        ]
+    }
+
+    #[test]
+    fn test_search_filters_visible_markets() {
+        let markets = markets();
+        let mut search = SidebarSearch::default();
+        assert_eq!(search.visible_indices(&markets), vec![0, 1, 2, 3]);
+
+        search.start();
+        search.query.push_str("BITCOIN");
+        search.commit();
+        assert_eq!(search.visible_indices(&markets), vec![0, 3]);
+
+        // Slug matches too
+        search.query = "nba".to_string();
+        assert_eq!(search.visible_indices(&markets), vec![2]);
+
+        search.clear();
+        assert_eq!(search.visible_indices(&markets), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_search_jumps_between_matches() {
+        let markets = markets();
+        let search = SidebarSearch {
+            query: "btc".to_string(),
+            editing: false,
+        };
+
+        assert_eq!(search.next_match(&markets, 0), Some(3));
+        assert_eq!(search.next_match(&markets, 3), Some(0));
+        assert_eq!(search.prev_match(&markets, 3), Some(0));
+        assert_eq!(search.prev_match(&markets, 0), Some(3));
+
+        let none = SidebarSearch {
+            query: "xyz".to_string(),
+            editing: false,
+        };
+        assert_eq!(none.next_match(&markets, 0), None);
+        assert!(!markets.iter().any(|m| none.shows(m)));
+    }
+
+    #[test]
+    fn test_stats_recompute_on_tick() {
+        let order_state: SharedOrderState = Arc::new(RwLock::new(OrderStateStore::new()));
+        let position_tracker: SharedPositionTracker = Arc::new(RwLock::new(PositionTracker::new()));
+        let mut stats = DerivedStats::default();
+
+        stats.recompute(&order_state, &position_tracker);
+        assert_eq!(stats.order_count, 0);
+        assert_eq!(stats.position_count, 0);
+        assert_eq!(stats.ticks, 1);
+
+        // State changes while quiet - nothing redraws until the next tick
+        order_state
+            .write()
+            .pre_register_order_with_details("o1", "tok-up", 0.45, 10.0, Side::Buy);
+        order_state
+            .write()
+            .pre_register_order_with_details("o2", "tok-down", 0.52, 5.0, Side::Sell);
+        position_tracker.write().hydrate_position("tok-up", 25.0, 0.40);
+        assert_eq!(stats.order_count, 0);
+
+        stats.recompute(&order_state, &position_tracker);
+        assert_eq!(stats.order_count, 2);
+        assert_eq!(stats.position_count, 1);
+        assert!((stats.exposure - 10.0).abs() < 1e-9);
+        assert_eq!(stats.ticks, 2);
+    }
+}
@@ -0,0 +1,56 @@
+//! Terminal setup/teardown for the visualizer
+//!
+//! Ratatui puts the terminal in raw mode on the alternate screen. If the app
+//! panics without undoing that, the user's shell is left unusable, so the
+//! panic hook restores the terminal before the default hook prints the panic.
+
+use std::io::{self, Write};
+
+use crossterm::{
+    cursor::Show,
+    event::DisableMouseCapture,
+    execute,
+    terminal::{disable_raw_mode, LeaveAlternateScreen},
+};
+
+/// Write the escape sequences that leave the alternate screen, stop mouse
+/// capture and show the cursor again
+pub fn write_restore_sequence<W: Write>(out: &mut W) -> io::Result<()> {
+    execute!(out, LeaveAlternateScreen, DisableMouseCapture, Show)
+}
+
+/// Fully restore the terminal: disable raw mode, then emit the restore sequence on stdout
+pub fn restore_terminal() -> io::Result<()> {
+    disable_raw_mode()?;
+    write_restore_sequence(&mut io::stdout())
+}
+
+/// Install a panic hook that restores the terminal before running the default hook
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        // Best effort - we are already panicking
+        let _ = restore_terminal();
+        default_hook(info);
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restore_sequence_emitted() {
+        let mut out = Vec::new();
+        write_restore_sequence(&mut out).unwrap();
+        let seq = String::from_utf8(out).unwrap();
+
+        let leave_alt = seq.find("\x1b[?1049l").expect("leave alternate screen");
+        let mouse_off = seq.find("\x1b[?1000l").expect("disable mouse capture");
+        let show_cursor = seq.find("\x1b[?25h").expect("show cursor");
+
+        // Leave the alternate screen first so the cursor is shown on the main screen
+        assert!(leave_alt < mouse_off);
+        assert!(mouse_off < show_cursor);
+    }
+}
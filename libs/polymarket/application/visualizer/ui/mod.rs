@@ -6,6 +6,7 @@ pub mod sidebar;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
@@ -34,20 +35,48 @@ fn draw_header(frame: &mut Frame, app: &App, area: Rect) {
     let status_color = if connected { Color::Green } else { Color::Yellow };
 
     let market_count = app.markets.len();
-    let order_count = app.get_total_order_count();
 
     let header_text = format!(
-        " Status: {} | Markets: {} | Orders: {}",
-        status, market_count, order_count
+        " Status: {} | Markets: {} | Orders: {} | Positions: {} | Realized: {:+.2}",
+        status,
+        market_count,
+        app.stats.order_count,
+        app.stats.position_count,
+        app.stats.realized_pnl
     );
 
-    let header = Paragraph::new(header_text)
-        .style(Style::default().fg(status_color))
-        .block(Block::default().borders(Borders::ALL).title(" MM Visualizer "));
+    let (gauge_text, over_limit) = format_balance_gauge(
+        app.free_balance(),
+        app.stats.exposure,
+        app.config.exposure_warn_fraction,
+    );
+    let gauge_color = if over_limit { Color::Red } else { status_color };
+
+    let header = Paragraph::new(Line::from(vec![
+        Span::styled(header_text, Style::default().fg(status_color)),
+        Span::styled(gauge_text, Style::default().fg(gauge_color)),
+    ]))
+    .block(Block::default().borders(Borders::ALL).title(" MM Visualizer "));
 
     frame.render_widget(header, area);
 }
 
+/// Format the balance/exposure gauge for the header.
+/// Returns the text and whether exposure exceeds `warn_fraction` of the free balance.
+pub fn format_balance_gauge(balance: f64, exposure: f64, warn_fraction: f64) -> (String, bool) {
+    let over_limit = exposure > balance * warn_fraction;
+    let ratio = if balance > 0.0 {
+        format!("{:.0}%", exposure / balance * 100.0)
+    } else {
+        "n/a".to_string()
+    };
+
+    (
+        format!(" | USDC: ${:.2} | Exposure: ${:.2} ({})", balance, exposure, ratio),
+        over_limit,
+    )
+}
+
 fn draw_main(frame: &mut Frame, app: &App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -73,8 +102,10 @@ fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
     let status = app.status_message.as_deref().unwrap_or("");
     let position_summary = app.get_position_summary();
 
-    let footer_text = if status.is_empty() {
-        format!(" {} | q=quit j/k=nav r=refresh x=cancel d=dump", position_summary)
+    let footer_text = if let Some(prompt) = &app.order_prompt {
+        format!(" ORDER: {}", prompt.display())
+    } else if status.is_empty() {
+        format!(" {} | q=quit j/k=nav r=refresh x=cancel d=dump o/O=order K=halt /=search n/N=match h=heatmap", position_summary)
     } else {
         format!(" {} | {}", position_summary, status)
     };
@@ -84,3 +115,29 @@ fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
 
     frame.render_widget(footer, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_balance_gauge_within_limit() {
+        let (text, over) = format_balance_gauge(1000.0, 250.0, 0.5);
+        assert_eq!(text, " | USDC: $1000.00 | Exposure: $250.00 (25%)");
+        assert!(!over);
+    }
+
+    #[test]
+    fn test_balance_gauge_over_limit() {
+        let (text, over) = format_balance_gauge(200.0, 150.0, 0.5);
+        assert_eq!(text, " | USDC: $200.00 | Exposure: $150.00 (75%)");
+        assert!(over);
+    }
+
+    #[test]
+    fn test_balance_gauge_zero_balance() {
+        let (text, over) = format_balance_gauge(0.0, 10.0, 0.5);
+        assert_eq!(text, " | USDC: $0.00 | Exposure: $10.00 (n/a)");
+        assert!(over);
+    }
+}
@@ -8,10 +8,12 @@ use ratatui::{
     Frame,
 };
 
-use crate::application::visualizer::{App, MarketInfo};
+use crate::application::visualizer::{App, MarketInfo, OrderbookView};
 
 const MAX_LEVELS: usize = 10;
 const PRICE_EPSILON: f64 = 0.0001;
+/// Columns used by the heatmap prefix (" 0.5000 12345.6 ")
+const HEATMAP_PREFIX_WIDTH: u16 = 17;
 
 /// Draw the orderbook for a selected market
 pub fn draw(frame: &mut Frame, app: &App, market: &MarketInfo, area: Rect) {
@@ -76,16 +78,33 @@ fn draw_token_ladder(frame: &mut Frame, app: &App, market: &MarketInfo, is_up: b
         ])
         .split(inner);
 
+    // Heatmap bars are scaled to the largest visible level on either side
+    let max_size = asks
+        .iter()
+        .take(MAX_LEVELS)
+        .chain(bids.iter().take(MAX_LEVELS))
+        .map(|(_, size)| *size)
+        .fold(0.0, f64::max);
+    let bar_max = inner.width.saturating_sub(HEATMAP_PREFIX_WIDTH) as usize;
+    let view = app.orderbook_view;
+
+    let render_level = |price: f64, size: f64, is_bid: bool| {
+        let our_size = get_our_size_at_price(&our_orders, price);
+        match view {
+            OrderbookView::Table => format_level(price, size, our_size, is_bid),
+            OrderbookView::Heatmap => {
+                format_heat_level(price, size, our_size, is_bid, max_size, bar_max)
+            }
+        }
+    };
+
     // Draw asks (lowest price closest to spread - take best asks and reverse for display)
     // Asks come sorted ascending (lowest first), we want lowest at bottom near spread
     let ask_lines: Vec<Line> = asks
         .iter()
         .take(MAX_LEVELS)
         .rev()  // Reverse so lowest price is at bottom (closest to spread)
-        .map(|(price, size)| {
-            let our_size = get_our_size_at_price(&our_orders, *price);
-            format_level(*price, *size, our_size, false)
-        })
+        .map(|(price, size)| render_level(*price, *size, false))
         .collect();
 
     let asks_widget = Paragraph::new(ask_lines);
@@ -101,10 +120,7 @@ fn draw_token_ladder(frame: &mut Frame, app: &App, market: &MarketInfo, is_up: b
     let bid_lines: Vec<Line> = bids
         .iter()
         .take(MAX_LEVELS)
-        .map(|(price, size)| {
-            let our_size = get_our_size_at_price(&our_orders, *price);
-            format_level(*price, *size, our_size, true)
-        })
+        .map(|(price, size)| render_level(*price, *size, true))
         .collect();
 
     let bids_widget = Paragraph::new(bid_lines);
@@ -163,6 +179,39 @@ fn format_level(price: f64, total_size: f64, our_size: Option<f64>, is_bid: bool
     }
 }
 
+/// Width of a heatmap bar, scaled so the largest level fills `bar_max` columns
+fn bar_width(size: f64, max_size: f64, bar_max: usize) -> usize {
+    // A pane too narrow for any bar
+    if bar_max == 0 || max_size <= 0.0 || size <= 0.0 {
+        return 0;
+    }
+    let width = ((size / max_size) * bar_max as f64).round() as usize;
+    // Non-empty levels always get at least one block so they stay visible
+    width.clamp(1, bar_max)
+}
+
+/// Format a single price level as a heatmap bar
+fn format_heat_level(
+    price: f64,
+    total_size: f64,
+    our_size: Option<f64>,
+    is_bid: bool,
+    max_size: f64,
+    bar_max: usize,
+) -> Line<'static> {
+    let base_color = if is_bid { Color::Green } else { Color::Red };
+    // Levels with our orders get a blue bar, same as the table highlight
+    let bar_color = if our_size.is_some() { Color::Blue } else { base_color };
+
+    let label = format!(" {:.4} {:>8.1} ", price, total_size);
+    let bar = "█".repeat(bar_width(total_size, max_size, bar_max));
+
+    Line::from(vec![
+        Span::styled(label, Style::default().fg(base_color)),
+        Span::styled(bar, Style::default().fg(bar_color)),
+    ])
+}
+
 /// Get our aggregated size at a specific price level (sum of all orders at this price)
 fn get_our_size_at_price(our_orders: &[(f64, f64)], price: f64) -> Option<f64> {
     let total: f64 = our_orders
@@ -177,3 +226,44 @@ fn get_our_size_at_price(our_orders: &[(f64, f64)], price: f64) -> Option<f64> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bar_len(line: &Line) -> usize {
+        line.spans[1].content.chars().count()
+    }
+
+    #[test]
+    fn test_heatmap_bars_scale_with_size() {
+        // Known book: largest level (200) fills the full bar width
+        let levels = [(0.50, 200.0), (0.49, 100.0), (0.48, 50.0), (0.47, 0.5)];
+        let max_size = 200.0;
+        let bar_max = 40;
+
+        let widths: Vec<usize> = levels
+            .iter()
+            .map(|(p, s)| bar_len(&format_heat_level(*p, *s, None, true, max_size, bar_max)))
+            .collect();
+
+        assert_eq!(widths, vec![40, 20, 10, 1]);
+    }
+
+    #[test]
+    fn test_heatmap_label_and_highlight() {
+        let line = format_heat_level(0.55, 120.0, Some(10.0), false, 120.0, 8);
+        assert_eq!(line.spans[0].content, " 0.5500    120.0 ");
+        assert_eq!(line.spans[0].content.chars().count(), HEATMAP_PREFIX_WIDTH as usize);
+        assert_eq!(line.spans[1].style.fg, Some(Color::Blue));
+        assert_eq!(bar_len(&line), 8);
+    }
+
+    #[test]
+    fn test_bar_width_edge_cases() {
+        assert_eq!(bar_width(0.0, 100.0, 10), 0);
+        assert_eq!(bar_width(10.0, 0.0, 10), 0);
+        assert_eq!(bar_width(500.0, 100.0, 10), 10);
+        assert_eq!(bar_width(50.0, 100.0, 0), 0);
+    }
+}
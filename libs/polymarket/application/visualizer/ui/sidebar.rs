@@ -12,11 +12,12 @@ use crate::application::visualizer::App;
 
 /// Draw the sidebar with market list
 pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
-    let items: Vec<ListItem> = app
-        .markets
+    let visible = app.visible_market_indices();
+
+    let items: Vec<ListItem> = visible
         .iter()
-        .enumerate()
-        .map(|(i, market)| {
+        .map(|&i| {
+            let market = &app.markets[i];
             let is_selected = i == app.selected_index;
 
             let style = if is_selected {
@@ -28,7 +29,12 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
                 Style::default().fg(Color::White)
             };
 
-            let prefix = if is_selected { "> " } else { "  " };
+            let prefix = match (is_selected, app.market_halts.is_halted(&market.condition_id)) {
+                (true, true) => ">x",
+                (true, false) => "> ",
+                (false, true) => " x",
+                (false, false) => "  ",
+            };
 
             // Truncate market name to fit sidebar
             let name = if market.display_name.len() > 24 {
@@ -46,13 +52,21 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
         })
         .collect();
 
+    let title = if app.search.editing {
+        format!(" /{}_ ", app.search.query)
+    } else if app.search.is_active() {
+        format!(" Markets /{} ({}) ", app.search.query, visible.len())
+    } else {
+        " Markets ".to_string()
+    };
+
     let list = List::new(items)
-        .block(Block::default().borders(Borders::ALL).title(" Markets "))
+        .block(Block::default().borders(Borders::ALL).title(title))
         .highlight_style(Style::default().add_modifier(Modifier::BOLD));
 
     // Create a list state for the selected item
     let mut state = ListState::default();
-    state.select(Some(app.selected_index));
+    state.select(visible.iter().position(|&i| i == app.selected_index));
 
     frame.render_stateful_widget(list, area, &mut state);
 }
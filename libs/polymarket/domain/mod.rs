@@ -5,11 +5,16 @@
 
 pub mod models;
 pub mod orderbook;
+pub mod price;
 pub mod sniper_market;
 pub mod strategy;
+pub mod timestamp;
 
 // Re-export domain models
-pub use models::{DbEvent, DbMarket, MarketFilters, SyncStats};
+pub use models::{DbEvent, DbMarket, MarketFilters, MarketParseError, ParseStrictness, SyncStats, TagQuery};
+
+pub use price::{normalize_price, parse_price, PriceError, PriceScale};
+pub use timestamp::DbTimestamp;
 
 // Re-export domain entities
 pub use sniper_market::SniperMarket;
@@ -1,5 +1,32 @@
+use super::timestamp::DbTimestamp;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::warn;
+
+/// How markets with malformed `outcomes`/`token_ids` JSON are handled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParseStrictness {
+    /// Log a warning and skip the market
+    #[default]
+    Lenient,
+    /// Fail with an error naming the market
+    Strict,
+}
+
+/// Parsed `(outcomes, token_ids)` of a market
+pub type OutcomesAndTokens = (Vec<String>, Vec<String>);
+
+/// A market's outcomes or token IDs could not be parsed
+#[derive(Error, Debug)]
+#[error("Market {market_id} has malformed {field}: {source}")]
+pub struct MarketParseError {
+    pub market_id: String,
+    pub field: &'static str,
+    #[source]
+    pub source: serde_json::Error,
+}
 
 /// Database representation of a market
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
@@ -33,6 +60,16 @@ impl DbMarket {
         DateTime::parse_from_rfc3339(&self.resolution_time).map(|dt| dt.with_timezone(&Utc))
     }
 
+    /// resolution_time as a typed timestamp
+    pub fn resolution_timestamp(&self) -> DbTimestamp {
+        DbTimestamp::new(self.resolution_time.as_str())
+    }
+
+    /// last_updated as a typed timestamp
+    pub fn last_updated_timestamp(&self) -> DbTimestamp {
+        DbTimestamp::new(self.last_updated.as_str())
+    }
+
     /// Get outcomes as Vec
     /// Handles both single-encoded and double-encoded JSON strings
     pub fn parse_outcomes(&self) -> Result<Vec<String>, serde_json::Error> {
@@ -60,6 +97,41 @@ impl DbMarket {
         serde_json::from_str(&self.token_ids)
     }
 
+    /// Parse outcomes and token IDs together
+    ///
+    /// A malformed field is an error in strict mode; in lenient mode it is
+    /// logged and `None` is returned so the caller skips the market.
+    pub fn parse_outcomes_and_tokens(
+        &self,
+        strictness: ParseStrictness,
+    ) -> Result<Option<OutcomesAndTokens>, MarketParseError> {
+        let parsed = self
+            .parse_outcomes()
+            .map_err(|source| self.parse_error("outcomes", source))
+            .and_then(|outcomes| {
+                self.parse_token_ids()
+                    .map(|token_ids| (outcomes, token_ids))
+                    .map_err(|source| self.parse_error("token_ids", source))
+            });
+
+        match (parsed, strictness) {
+            (Ok(parsed), _) => Ok(Some(parsed)),
+            (Err(e), ParseStrictness::Strict) => Err(e),
+            (Err(e), ParseStrictness::Lenient) => {
+                warn!("{}, skipping", e);
+                Ok(None)
+            }
+        }
+    }
+
+    fn parse_error(&self, field: &'static str, source: serde_json::Error) -> MarketParseError {
+        MarketParseError {
+            market_id: self.id.clone(),
+            field,
+            source,
+        }
+    }
+
     /// Get tags as JSON Value
     pub fn parse_tags(&self) -> Result<serde_json::Value, serde_json::Error> {
         match &self.tags {
@@ -133,9 +205,65 @@ pub struct SyncStats {
     pub markets_fetched: usize,
     pub markets_inserted: usize,
     pub markets_updated: usize,
+    /// Markets identical to the stored row, not rewritten
+    pub markets_skipped: usize,
+    /// Markets that inherited tags from their Gamma event
+    pub markets_enriched: usize,
+    /// Gamma records rejected by `DbMarket::try_from_gamma`
+    pub markets_failed: usize,
     pub duration: std::time::Duration,
 }
 
+/// Tag predicate for market lookups
+///
+/// Combines as `ALL(all) AND ANY(any) AND NOT ANY(exclude)`; empty lists
+/// are ignored, e.g. "Crypto AND Recurring but NOT Resolved" is
+/// `TagQuery::all(&["Crypto", "Recurring"]).excluding(&["Resolved"])`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TagQuery {
+    /// Market must carry every one of these
+    pub all: Vec<String>,
+    /// Market must carry at least one of these
+    pub any: Vec<String>,
+    /// Market must carry none of these
+    pub exclude: Vec<String>,
+}
+
+impl TagQuery {
+    pub fn all(labels: &[&str]) -> Self {
+        Self {
+            all: to_owned_labels(labels),
+            ..Default::default()
+        }
+    }
+
+    pub fn any(labels: &[&str]) -> Self {
+        Self {
+            any: to_owned_labels(labels),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_any(mut self, labels: &[&str]) -> Self {
+        self.any.extend(to_owned_labels(labels));
+        self
+    }
+
+    pub fn excluding(mut self, labels: &[&str]) -> Self {
+        self.exclude.extend(to_owned_labels(labels));
+        self
+    }
+
+    /// True when nothing selects markets (exclusions alone don't count)
+    pub fn is_empty(&self) -> bool {
+        self.all.is_empty() && self.any.is_empty()
+    }
+}
+
+fn to_owned_labels(labels: &[&str]) -> Vec<String> {
+    labels.iter().map(|label| label.to_string()).collect()
+}
+
 /// Query filters for markets
 #[derive(Debug, Clone, Default)]
 pub struct MarketFilters {
@@ -145,6 +273,10 @@ pub struct MarketFilters {
     pub min_resolution_time: Option<DateTime<Utc>>,
     pub max_resolution_time: Option<DateTime<Utc>>,
     pub category: Option<String>,
+    pub min_liquidity: Option<f64>,
+    pub max_liquidity: Option<f64>,
+    pub min_volume: Option<f64>,
+    pub max_volume: Option<f64>,
 }
 
 impl MarketFilters {
@@ -155,19 +287,19 @@ impl MarketFilters {
         let mut idx = 1;
 
         if let Some(active) = self.active {
-            conditions.push(format!("active = ${}", idx));
+            conditions.push(format!("active = ${}::boolean", idx));
             params.push(if active { "true" } else { "false" }.to_string());
             idx += 1;
         }
 
         if let Some(closed) = self.closed {
-            conditions.push(format!("closed = ${}", idx));
+            conditions.push(format!("closed = ${}::boolean", idx));
             params.push(if closed { "true" } else { "false" }.to_string());
             idx += 1;
         }
 
         if let Some(archived) = self.archived {
-            conditions.push(format!("archived = ${}", idx));
+            conditions.push(format!("archived = ${}::boolean", idx));
             params.push(if archived { "true" } else { "false" }.to_string());
             idx += 1;
         }
@@ -187,6 +319,25 @@ impl MarketFilters {
         if let Some(ref category) = self.category {
             conditions.push(format!("category = ${}", idx));
             params.push(category.clone());
+            idx += 1;
+        }
+
+        // liquidity and volume are stored as strings
+        let ranges = [
+            ("liquidity", ">=", self.min_liquidity),
+            ("liquidity", "<=", self.max_liquidity),
+            ("volume", ">=", self.min_volume),
+            ("volume", "<=", self.max_volume),
+        ];
+        for (column, op, bound) in ranges {
+            if let Some(bound) = bound {
+                conditions.push(format!(
+                    "CAST(NULLIF({}, '') AS DOUBLE PRECISION) {} ${}::double precision",
+                    column, op, idx
+                ));
+                params.push(bound.to_string());
+                idx += 1;
+            }
         }
 
         let where_clause = if conditions.is_empty() {
@@ -212,11 +363,71 @@ mod tests {
         };
 
         let (clause, params) = filters.build_where_clause();
-        assert!(clause.contains("active = ?"));
-        assert!(clause.contains("closed = ?"));
+        assert!(clause.contains("active = $1"));
+        assert!(clause.contains("closed = $2"));
         assert_eq!(params.len(), 2);
     }
 
+    #[test]
+    fn test_market_filters_ranges_keep_param_order() {
+        let filters = MarketFilters {
+            category: Some("Crypto".to_string()),
+            min_liquidity: Some(1000.0),
+            max_volume: Some(50000.5),
+            ..Default::default()
+        };
+
+        let (clause, params) = filters.build_where_clause();
+        assert_eq!(
+            clause,
+            "WHERE category = $1 \
+             AND CAST(NULLIF(liquidity, '') AS DOUBLE PRECISION) >= $2::double precision \
+             AND CAST(NULLIF(volume, '') AS DOUBLE PRECISION) <= $3::double precision"
+        );
+        assert_eq!(params, vec!["Crypto", "1000", "50000.5"]);
+    }
+
+    #[test]
+    fn test_parse_strictness_on_malformed_market() {
+        let market = DbMarket {
+            id: "bad-market".to_string(),
+            condition_id: None,
+            question: "Malformed?".to_string(),
+            description: None,
+            slug: None,
+            start_date: "2025-01-01T00:00:00Z".to_string(),
+            end_date: "2025-01-02T00:00:00Z".to_string(),
+            resolution_time: "2025-01-02T00:00:00Z".to_string(),
+            active: true,
+            closed: false,
+            archived: false,
+            market_type: None,
+            category: None,
+            liquidity: None,
+            volume: None,
+            outcomes: r#"["Yes","No"]"#.to_string(),
+            token_ids: "[0x1, 0x2".to_string(),
+            tags: None,
+            last_updated: "2025-01-01T00:00:00Z".to_string(),
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+            game_id: None,
+        };
+
+        // Lenient (default): skipped
+        assert!(market
+            .parse_outcomes_and_tokens(ParseStrictness::default())
+            .unwrap()
+            .is_none());
+
+        // Strict: error names the market and the field
+        let err = market
+            .parse_outcomes_and_tokens(ParseStrictness::Strict)
+            .unwrap_err();
+        assert_eq!(err.market_id, "bad-market");
+        assert_eq!(err.field, "token_ids");
+        assert!(err.to_string().contains("bad-market"));
+    }
+
     #[test]
     fn test_parse_outcomes_single_encoded() {
         let market = DbMarket {
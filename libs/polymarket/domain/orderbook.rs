@@ -4,6 +4,29 @@
 
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
+use thiserror::Error;
+
+// =============================================================================
+// Errors
+// =============================================================================
+
+/// Inconsistency found in a reconstructed orderbook
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum OrderBookError {
+    #[error("Crossed book: best bid {best_bid} >= best ask {best_ask}")]
+    Crossed { best_bid: f64, best_ask: f64 },
+
+    #[error("Negative size {size} at {side} level {price}")]
+    NegativeSize { side: &'static str, price: f64, size: f64 },
+
+    #[error("{side} level {index} at {price} is out of order after {previous}")]
+    OutOfOrder {
+        side: &'static str,
+        index: usize,
+        price: f64,
+        previous: f64,
+    },
+}
 
 // =============================================================================
 // Price Level - Basic unit of orderbook
@@ -114,6 +137,11 @@ impl OrderbookSide {
         }
     }
 
+    /// Keep only the best `depth` levels, dropping the rest
+    pub fn truncate(&mut self, depth: usize) {
+        self.levels.truncate(depth);
+    }
+
     /// Get best price level (first element)
     #[inline]
     pub fn best(&self) -> Option<(f64, f64)> {
@@ -142,6 +170,46 @@ impl OrderbookSide {
     pub fn total_liquidity(&self) -> f64 {
         self.levels.iter().map(|(_, s)| s).sum()
     }
+
+    /// Check that sizes are non-negative and prices strictly best-first
+    pub fn validate(&self) -> Result<(), OrderBookError> {
+        let side = if self.is_bid { "bid" } else { "ask" };
+
+        for (index, &(price, size)) in self.levels.iter().enumerate() {
+            if size < 0.0 {
+                return Err(OrderBookError::NegativeSize { side, price, size });
+            }
+            if index == 0 {
+                continue;
+            }
+            let previous = self.levels[index - 1].0;
+            let in_order = if self.is_bid { price < previous } else { price > previous };
+            if !in_order {
+                return Err(OrderBookError::OutOfOrder {
+                    side,
+                    index,
+                    price,
+                    previous,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+// =============================================================================
+// LastTrade - Most recent match for an asset
+// =============================================================================
+
+/// Most recent trade (maker/taker match) seen for an asset
+#[derive(Debug, Clone, PartialEq)]
+pub struct LastTrade {
+    pub price: f64,
+    pub size: f64,
+    /// Taker side: "BUY" or "SELL"
+    pub side: String,
+    /// Exchange timestamp in milliseconds
+    pub timestamp_ms: i64,
 }
 
 // =============================================================================
@@ -161,6 +229,10 @@ pub struct Orderbook {
     authoritative_best_bid: Option<f64>,
     /// Authoritative best_ask from exchange (updated via price_change events)
     authoritative_best_ask: Option<f64>,
+    /// Most recent trade on this asset (from last_trade_price events)
+    last_trade: Option<LastTrade>,
+    /// Set once the feed reports the market resolved/closed
+    closed: bool,
 }
 
 impl Orderbook {
@@ -173,6 +245,8 @@ impl Orderbook {
             last_updated: Instant::now(),
             authoritative_best_bid: None,
             authoritative_best_ask: None,
+            last_trade: None,
+            closed: false,
         }
     }
 
@@ -224,6 +298,33 @@ impl Orderbook {
         }
     }
 
+    /// Truncate both sides to the top `depth` levels.
+    /// Levels are kept sorted best-first, so the best bid/ask are unaffected.
+    pub fn truncate(&mut self, depth: usize) {
+        self.bids.truncate(depth);
+        self.asks.truncate(depth);
+    }
+
+    /// Record the most recent trade
+    pub fn set_last_trade(&mut self, trade: LastTrade) {
+        self.last_trade = Some(trade);
+    }
+
+    /// Most recent trade seen for this asset, if any
+    pub fn last_trade(&self) -> Option<&LastTrade> {
+        self.last_trade.as_ref()
+    }
+
+    /// Mark this asset's market as closed (no further trading)
+    pub fn mark_closed(&mut self) {
+        self.closed = true;
+    }
+
+    /// Whether the feed has reported this asset's market closed
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
     /// Get seconds since last update
     pub fn seconds_since_update(&self) -> f64 {
         self.last_updated.elapsed().as_secs_f64()
@@ -278,6 +379,28 @@ impl Orderbook {
         }
     }
 
+    /// Whether the best bid is at or above the best ask.
+    /// A real book never crosses, so this means the local copy is desynced.
+    pub fn is_crossed(&self) -> bool {
+        matches!(
+            (self.best_bid(), self.best_ask()),
+            (Some((bid, _)), Some((ask, _))) if bid >= ask
+        )
+    }
+
+    /// Check the book is safe to trade on: no negative sizes, levels sorted
+    /// best-first on both sides, and best bid strictly below best ask.
+    pub fn validate(&self) -> Result<(), OrderBookError> {
+        self.bids.validate()?;
+        self.asks.validate()?;
+        match (self.best_bid(), self.best_ask()) {
+            (Some((best_bid, _)), Some((best_ask, _))) if best_bid >= best_ask => {
+                Err(OrderBookError::Crossed { best_bid, best_ask })
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Calculate mid price
     pub fn mid_price(&self) -> Option<f64> {
         match (self.best_bid(), self.best_ask()) {
@@ -468,4 +591,83 @@ mod tests {
         let best_ask = ob.best_ask().unwrap();
         assert!((best_ask.0 - 0.755).abs() < TEST_TOLERANCE);
     }
+
+    #[test]
+    fn test_orderbook_is_crossed() {
+        let mut ob = Orderbook::new("test".to_string());
+        assert!(!ob.is_crossed());
+
+        ob.process_snapshot(&[make_level("0.74", "100")], &[make_level("0.76", "100")]);
+        assert!(!ob.is_crossed());
+
+        // A bid at the ask crosses the book
+        ob.process_update("BUY", "0.76", "50");
+        assert!(ob.is_crossed());
+    }
+
+    #[test]
+    fn test_validate_accepts_consistent_book() {
+        let mut ob = Orderbook::new("test".to_string());
+        assert_eq!(ob.validate(), Ok(()));
+
+        ob.process_snapshot(
+            &[make_level("0.73", "200"), make_level("0.74", "100")],
+            &[make_level("0.77", "200"), make_level("0.76", "100")],
+        );
+        assert_eq!(ob.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_rejects_crossed_book() {
+        let mut ob = Orderbook::new("test".to_string());
+        ob.process_snapshot(&[make_level("0.74", "100")], &[make_level("0.76", "100")]);
+        ob.process_update("BUY", "0.78", "50");
+
+        assert_eq!(
+            ob.validate(),
+            Err(OrderBookError::Crossed {
+                best_bid: 0.78,
+                best_ask: 0.76
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_crossed_authoritative_prices() {
+        let mut ob = Orderbook::new("test".to_string());
+        ob.process_snapshot(&[make_level("0.74", "100")], &[make_level("0.76", "100")]);
+        ob.process_update_with_best("BUY", "0.74", "120", "0.76", "0.76");
+
+        assert!(matches!(ob.validate(), Err(OrderBookError::Crossed { .. })));
+    }
+
+    #[test]
+    fn test_validate_rejects_negative_size() {
+        let mut ob = Orderbook::new("test".to_string());
+        ob.process_snapshot(&[make_level("0.74", "100")], &[make_level("0.76", "100")]);
+        ob.process_update("SELL", "0.77", "-5");
+
+        assert_eq!(
+            ob.validate(),
+            Err(OrderBookError::NegativeSize {
+                side: "ask",
+                price: 0.77,
+                size: -5.0
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_out_of_order_levels() {
+        let mut ob = Orderbook::new("test".to_string());
+        ob.process_snapshot(&[make_level("0.74", "100")], &[make_level("0.76", "100")]);
+        // Updates keep levels sorted, so corrupt the ordering directly
+        ob.bids.process_update(0.73, 10.0);
+        ob.bids.levels.swap(0, 1);
+
+        assert!(matches!(
+            ob.validate(),
+            Err(OrderBookError::OutOfOrder { side: "bid", index: 1, .. })
+        ));
+    }
 }
@@ -0,0 +1,117 @@
+//! Outcome price normalization
+//!
+//! Outcome prices are probabilities in [0, 1], but some feeds quote them in
+//! cents (0-100). Mixing the two sizes orders 100x off, so every ingested
+//! price goes through `normalize_price` with the feed's `PriceScale` first.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+
+/// How a feed denominates outcome prices
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PriceScale {
+    /// Already in [0, 1]
+    #[default]
+    Unit,
+    /// Cents in [0, 100]
+    Cents,
+    /// Values above 1 are cents, the rest are already in [0, 1].
+    /// Ambiguous at 1 (one cent vs. certainty), so only use it for feeds
+    /// that never quote a price of exactly one cent.
+    Auto,
+}
+
+impl fmt::Display for PriceScale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PriceScale::Unit => "unit",
+            PriceScale::Cents => "cents",
+            PriceScale::Auto => "auto",
+        })
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum PriceError {
+    #[error("Price {value} is out of range for {scale} scale")]
+    OutOfRange { value: f64, scale: PriceScale },
+
+    #[error("Price is not a finite number: {0}")]
+    NotFinite(f64),
+
+    #[error("Invalid price '{0}'")]
+    Unparseable(String),
+}
+
+/// Canonicalize a price quoted in `scale` to [0, 1]
+pub fn normalize_price(value: f64, scale: PriceScale) -> Result<f64, PriceError> {
+    if !value.is_finite() {
+        return Err(PriceError::NotFinite(value));
+    }
+
+    let normalized = match scale {
+        PriceScale::Unit => value,
+        PriceScale::Cents => value / 100.0,
+        PriceScale::Auto if value > 1.0 => value / 100.0,
+        PriceScale::Auto => value,
+    };
+
+    if (0.0..=1.0).contains(&normalized) {
+        Ok(normalized)
+    } else {
+        Err(PriceError::OutOfRange { value, scale })
+    }
+}
+
+/// Parse and canonicalize a price given as a string (as most APIs send them)
+pub fn parse_price(raw: &str, scale: PriceScale) -> Result<f64, PriceError> {
+    let value = raw
+        .trim()
+        .parse::<f64>()
+        .map_err(|_| PriceError::Unparseable(raw.to_string()))?;
+    normalize_price(value, scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cents_are_normalized() {
+        assert_eq!(normalize_price(50.0, PriceScale::Cents), Ok(0.50));
+        assert_eq!(normalize_price(50.0, PriceScale::Auto), Ok(0.50));
+        assert_eq!(parse_price("50", PriceScale::Cents), Ok(0.50));
+    }
+
+    #[test]
+    fn test_unit_prices_pass_through() {
+        assert_eq!(normalize_price(0.5, PriceScale::Unit), Ok(0.5));
+        assert_eq!(normalize_price(0.5, PriceScale::Auto), Ok(0.5));
+        assert_eq!(parse_price("0.5", PriceScale::Unit), Ok(0.5));
+        assert_eq!(normalize_price(1.0, PriceScale::Auto), Ok(1.0));
+    }
+
+    #[test]
+    fn test_out_of_range_prices_are_rejected() {
+        assert_eq!(
+            normalize_price(50.0, PriceScale::Unit),
+            Err(PriceError::OutOfRange {
+                value: 50.0,
+                scale: PriceScale::Unit
+            })
+        );
+        assert!(normalize_price(-0.1, PriceScale::Unit).is_err());
+        assert!(normalize_price(101.0, PriceScale::Cents).is_err());
+        assert!(normalize_price(150.0, PriceScale::Auto).is_err());
+        assert!(matches!(
+            normalize_price(f64::NAN, PriceScale::Auto),
+            Err(PriceError::NotFinite(_))
+        ));
+        assert_eq!(
+            parse_price("fifty", PriceScale::Cents),
+            Err(PriceError::Unparseable("fifty".to_string()))
+        );
+    }
+}
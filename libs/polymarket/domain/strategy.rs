@@ -2,7 +2,7 @@
 //!
 //! Contains business entities and errors for trading strategies
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use thiserror::Error;
 
 // ==================== ERRORS ====================
@@ -40,6 +40,9 @@ pub enum RiskError {
     #[error("Daily loss limit reached: -${0}")]
     DailyLossLimitReached(f64),
 
+    #[error("Daily order limit reached ({0} orders)")]
+    DailyOrderLimitReached(usize),
+
     #[error("Trading is halted due to risk limits")]
     TradingHalted,
 }
@@ -81,6 +84,8 @@ pub struct RiskConfig {
     pub max_bet_per_market: f64,
     pub daily_loss_limit: f64,
     pub min_profit_cents: f64,
+    /// Hard cap on orders placed per UTC day (0 = unlimited)
+    pub max_orders_per_day: usize,
 }
 
 /// Daily statistics for risk management
@@ -91,6 +96,10 @@ pub struct DailyStats {
     pub losses: usize,
     pub pnl: f64,
     pub win_rate: f64,
+    /// Orders placed today (counted against `RiskConfig::max_orders_per_day`)
+    pub orders_placed: usize,
+    /// UTC day these stats cover
+    pub date: NaiveDate,
 }
 
 impl DailyStats {
@@ -108,6 +117,65 @@ impl DailyStats {
             losses,
             pnl,
             win_rate,
+            orders_placed: 0,
+            date: Utc::now().date_naive(),
+        }
+    }
+
+    /// Reset all counters if `now` falls on a later UTC day
+    pub fn roll_over(&mut self, now: DateTime<Utc>) {
+        let today = now.date_naive();
+        if today != self.date {
+            *self = Self::new(0, 0, 0.0);
+            self.date = today;
+        }
+    }
+
+    /// Count an order placement, or block it once `max_orders_per_day`
+    /// (see `RiskConfig::max_orders_per_day`, 0 = unlimited) is reached today
+    pub fn record_order(&mut self, max_orders_per_day: usize, now: DateTime<Utc>) -> Result<(), RiskError> {
+        self.roll_over(now);
+
+        if max_orders_per_day > 0 && self.orders_placed >= max_orders_per_day {
+            return Err(RiskError::DailyOrderLimitReached(max_orders_per_day));
+        }
+
+        self.orders_placed += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_daily_order_cap_blocks_until_reset() {
+        let now = Utc::now();
+        let mut stats = DailyStats::new(0, 0, 0.0);
+
+        for _ in 0..3 {
+            assert!(stats.record_order(3, now).is_ok());
+        }
+        assert!(matches!(
+            stats.record_order(3, now),
+            Err(RiskError::DailyOrderLimitReached(3))
+        ));
+        assert_eq!(stats.orders_placed, 3);
+
+        // Next UTC day resets the counter
+        let tomorrow = now + chrono::Duration::days(1);
+        assert!(stats.record_order(3, tomorrow).is_ok());
+        assert_eq!(stats.orders_placed, 1);
+        assert_eq!(stats.date, tomorrow.date_naive());
+    }
+
+    #[test]
+    fn test_zero_order_cap_is_unlimited() {
+        let mut stats = DailyStats::new(0, 0, 0.0);
+
+        for _ in 0..1000 {
+            assert!(stats.record_order(0, Utc::now()).is_ok());
         }
     }
 }
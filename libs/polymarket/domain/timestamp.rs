@@ -0,0 +1,136 @@
+//! RFC3339 timestamp newtype for database models
+//!
+//! Timestamps are stored as RFC3339 text. `DbTimestamp` keeps that exact
+//! string (so it encodes/decodes as TEXT and serializes unchanged) but
+//! compares by instant, so `...Z` and `...+00:00` for the same moment are
+//! equal and ordering doesn't depend on string formatting.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type)]
+#[serde(transparent)]
+#[sqlx(transparent)]
+pub struct DbTimestamp(String);
+
+impl DbTimestamp {
+    /// Wrap a stored string without validating it
+    pub fn new(raw: impl Into<String>) -> Self {
+        Self(raw.into())
+    }
+
+    pub fn now() -> Self {
+        Self::from(Utc::now())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    pub fn as_datetime(&self) -> Result<DateTime<Utc>, chrono::ParseError> {
+        DateTime::parse_from_rfc3339(&self.0).map(|dt| dt.with_timezone(&Utc))
+    }
+}
+
+impl From<DateTime<Utc>> for DbTimestamp {
+    fn from(dt: DateTime<Utc>) -> Self {
+        Self(dt.to_rfc3339_opts(SecondsFormat::AutoSi, false))
+    }
+}
+
+impl From<String> for DbTimestamp {
+    fn from(raw: String) -> Self {
+        Self(raw)
+    }
+}
+
+impl fmt::Display for DbTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// Valid timestamps compare by instant; unparseable ones sort after all valid
+// ones and compare by their raw string.
+impl Ord for DbTimestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.as_datetime(), other.as_datetime()) {
+            (Ok(a), Ok(b)) => a.cmp(&b),
+            (Ok(_), Err(_)) => Ordering::Less,
+            (Err(_), Ok(_)) => Ordering::Greater,
+            (Err(_), Err(_)) => self.0.cmp(&other.0),
+        }
+    }
+}
+
+impl PartialOrd for DbTimestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for DbTimestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for DbTimestamp {}
+
+impl Hash for DbTimestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.as_datetime() {
+            Ok(dt) => dt.hash(state),
+            Err(_) => self.0.hash(state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format() {
+        let ts = DbTimestamp::new("2025-01-02T03:04:05Z");
+        let dt = ts.as_datetime().unwrap();
+        assert_eq!(dt.to_rfc3339(), "2025-01-02T03:04:05+00:00");
+        assert_eq!(DbTimestamp::from(dt).as_str(), "2025-01-02T03:04:05+00:00");
+
+        assert!(DbTimestamp::new("yesterday").as_datetime().is_err());
+        assert!(DbTimestamp::now().as_datetime().is_ok());
+    }
+
+    #[test]
+    fn test_compares_by_instant() {
+        let zulu = DbTimestamp::new("2025-01-02T03:04:05Z");
+        let offset = DbTimestamp::new("2025-01-02T03:04:05+00:00");
+        let cet = DbTimestamp::new("2025-01-02T04:04:05+01:00");
+        assert_eq!(zulu, offset);
+        assert_eq!(zulu, cet);
+
+        // As strings the +01:00 one sorts later; as instants it is earlier
+        let later = DbTimestamp::new("2025-01-02T03:30:00+00:00");
+        let earlier_text_larger = DbTimestamp::new("2025-01-02T04:00:00+01:00");
+        assert!(earlier_text_larger < later);
+
+        // Garbage sorts last
+        assert!(later < DbTimestamp::new("not a time"));
+    }
+
+    #[test]
+    fn test_serde_keeps_raw_string() {
+        let ts = DbTimestamp::new("2025-01-02T03:04:05Z");
+        let json = serde_json::to_string(&ts).unwrap();
+        assert_eq!(json, "\"2025-01-02T03:04:05Z\"");
+        let back: DbTimestamp = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.as_str(), "2025-01-02T03:04:05Z");
+    }
+}
@@ -0,0 +1,62 @@
+//! Exponential backoff shared by retrying clients
+//!
+//! The CLOB REST client (`RetryPolicy`) and the database (`PoolOptions`)
+//! both retry with the same schedule: a base delay doubled for each further
+//! retry, up to a retry limit.
+
+use std::time::Duration;
+
+/// How often and how long to back off between retries of an operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackoffPolicy {
+    /// Retries after the first attempt (0 = single-shot)
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for each further one
+    pub base_delay: Duration,
+}
+
+impl BackoffPolicy {
+    pub const fn new(max_retries: u32, base_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            base_delay,
+        }
+    }
+
+    /// Never retry
+    pub const fn none() -> Self {
+        Self::new(0, Duration::ZERO)
+    }
+
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Backoff before retry number `retry` (1-based)
+    pub fn delay_for(&self, retry: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_doubles_per_retry_and_saturates() {
+        let policy = BackoffPolicy::new(3, Duration::from_millis(100));
+        assert_eq!(policy.delay_for(1), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(2), Duration::from_millis(200));
+        assert_eq!(policy.delay_for(3), Duration::from_millis(400));
+        // The multiplier saturates instead of overflowing
+        assert_eq!(policy.delay_for(64), policy.delay_for(40));
+        assert_eq!(BackoffPolicy::none().max_retries, 0);
+    }
+}
@@ -4,10 +4,12 @@
 //! Halts trading when balance drops below a configurable threshold of the peak.
 
 use crate::infrastructure::client::clob::TradingClient;
+use crate::infrastructure::client::user::{PositionEvent, PositionEventCallback, Side};
 use parking_lot::RwLock;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::watch;
 use tokio::task::JoinHandle;
 use tracing::{debug, info, warn};
 
@@ -25,6 +27,45 @@ pub struct BalanceManager {
     halt_for_trading_balance: Arc<AtomicBool>,
     halt_threshold: f64,
     task_handle: Option<JoinHandle<()>>,
+    own_flow: OwnBalanceFlow,
+}
+
+/// Running total of USDC this process moved itself (fills, merges)
+///
+/// `BalanceManager::spawn_watcher` nets it out of the sampled balance so only
+/// external changes (deposits, withdrawals) are published. Register it as a
+/// `PositionEventCallback` to count fills; record merges with `record`.
+#[derive(Debug, Clone, Default)]
+pub struct OwnBalanceFlow {
+    total: Arc<RwLock<f64>>,
+}
+
+impl OwnBalanceFlow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a USDC change caused by our own trading (positive = received)
+    pub fn record(&self, delta: f64) {
+        *self.total.write() += delta;
+    }
+
+    /// Net USDC moved by our own trading so far
+    pub fn total(&self) -> f64 {
+        *self.total.read()
+    }
+}
+
+impl PositionEventCallback for OwnBalanceFlow {
+    fn on_position_updated(&self, event: &PositionEvent) {
+        if let PositionEvent::Updated { fill, .. } = event {
+            let notional = fill.size * fill.price;
+            self.record(match fill.side {
+                Side::Buy => -notional,
+                Side::Sell => notional,
+            });
+        }
+    }
 }
 
 impl BalanceManager {
@@ -39,6 +80,7 @@ impl BalanceManager {
             halt_for_trading_balance: Arc::new(AtomicBool::new(false)),
             halt_threshold,
             task_handle: None,
+            own_flow: OwnBalanceFlow::new(),
         }
     }
 
@@ -189,6 +231,66 @@ impl BalanceManager {
     pub fn halt_flag(&self) -> Arc<AtomicBool> {
         Arc::clone(&self.halt_for_trading_balance)
     }
+
+    /// Flow of our own fills and merges, netted out by `spawn_watcher`
+    pub fn own_flow(&self) -> OwnBalanceFlow {
+        self.own_flow.clone()
+    }
+
+    /// Spawn a task that publishes external balance changes (deposits, withdrawals)
+    ///
+    /// Samples the current balance every `poll_interval` and nets out
+    /// `own_flow`, so our own fills and merges are not reported. The balance is
+    /// published once that external part moved by at least `min_change` since
+    /// the last publish and held on two consecutive samples, which rides out
+    /// the lag between a fill arriving and the polled balance catching up. The
+    /// receiver starts with the current balance marked as seen, so only later
+    /// changes are reported. Runs until `shutdown_flag` goes false.
+    pub fn spawn_watcher(
+        &self,
+        poll_interval: Duration,
+        min_change: f64,
+        shutdown_flag: Arc<AtomicBool>,
+    ) -> watch::Receiver<f64> {
+        let current = Arc::clone(&self.balance_current);
+        let own_flow = self.own_flow.clone();
+        let (tx, rx) = watch::channel(*current.read());
+
+        tokio::spawn(async move {
+            let mut published_external = *current.read() - own_flow.total();
+            let mut pending: Option<f64> = None;
+
+            while shutdown_flag.load(Ordering::Acquire) {
+                tokio::time::sleep(poll_interval).await;
+
+                let balance = *current.read();
+                let external = balance - own_flow.total();
+                if (external - published_external).abs() < min_change {
+                    pending = None;
+                    continue;
+                }
+
+                // Publish only once the same external change is seen twice
+                match pending {
+                    Some(previous) if (external - previous).abs() < min_change => {
+                        debug!(
+                            "BalanceManager: external balance change ${:.2} (balance ${:.2})",
+                            external - published_external,
+                            balance
+                        );
+                        published_external = external;
+                        pending = None;
+                        if tx.send(balance).is_err() {
+                            break; // Every subscriber dropped
+                        }
+                    }
+                    _ => pending = Some(external),
+                }
+            }
+        });
+
+        rx
+    }
 }
 
 impl Default for BalanceManager {
@@ -196,3 +298,88 @@ impl Default for BalanceManager {
         Self::new(0.10) // Default to 10% threshold
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::client::user::{Fill, PositionTracker, TradeStatus};
+
+    fn buy_fill(price: f64, size: f64) -> Fill {
+        Fill {
+            trade_id: "t1".to_string(),
+            asset_id: "token-up".to_string(),
+            market: "market-1".to_string(),
+            side: Side::Buy,
+            outcome: "Up".to_string(),
+            price,
+            size,
+            status: TradeStatus::Matched,
+            taker_order_id: "taker-1".to_string(),
+            trader_side: "MAKER".to_string(),
+            fee_rate_bps: 0.0,
+            transaction_hash: None,
+            maker_orders: vec![],
+            match_time: "2025-01-01T00:00:00Z".to_string(),
+            timestamp: "2025-01-01T00:00:00Z".to_string(),
+            owner: "owner-1".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_watcher_publishes_balance_change() {
+        let manager = BalanceManager::default();
+        *manager.balance_current.write() = 100.0;
+        let shutdown_flag = Arc::new(AtomicBool::new(true));
+
+        let mut rx = manager.spawn_watcher(Duration::from_millis(10), 1.0, shutdown_flag.clone());
+        assert!(!rx.has_changed().unwrap());
+
+        // Below min_change: not published
+        *manager.balance_current.write() = 100.5;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!rx.has_changed().unwrap());
+
+        // Deposit
+        *manager.balance_current.write() = 250.0;
+        tokio::time::timeout(Duration::from_secs(1), rx.changed())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(*rx.borrow_and_update(), 250.0);
+
+        shutdown_flag.store(false, Ordering::Release);
+    }
+
+    #[tokio::test]
+    async fn test_own_fill_does_not_trigger_a_requote() {
+        let manager = BalanceManager::default();
+        *manager.balance_current.write() = 100.0;
+        let shutdown_flag = Arc::new(AtomicBool::new(true));
+        let mut rx = manager.spawn_watcher(Duration::from_millis(10), 1.0, shutdown_flag.clone());
+
+        // Buy 20 @ 0.50 lands on the user WebSocket, then in the polled balance
+        let flow = manager.own_flow();
+        let mut tracker = PositionTracker::with_callback(Arc::new(flow.clone()));
+        let (event, _) = tracker.apply_fill(&buy_fill(0.50, 20.0));
+        tracker.fire_callback(&event);
+        *manager.balance_current.write() = 90.0;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!rx.has_changed().unwrap());
+
+        // A confirmed merge of 30 pairs pays USDC back
+        flow.record(30.0);
+        *manager.balance_current.write() = 120.0;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!rx.has_changed().unwrap());
+
+        // A withdrawal is still reported
+        *manager.balance_current.write() = 60.0;
+        tokio::time::timeout(Duration::from_secs(1), rx.changed())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(*rx.borrow_and_update(), 60.0);
+
+        shutdown_flag.store(false, Ordering::Release);
+    }
+}
@@ -205,7 +205,9 @@ impl PolymarketAuth {
         Ok(headers)
     }
 
-    /// Get current Unix timestamp in seconds
+    /// Get current Unix timestamp in seconds (local clock)
+    ///
+    /// `RestClient::timestamp` adds the client's exchange clock offset.
     pub fn current_timestamp() -> u64 {
         SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -4,6 +4,7 @@
 //! Similar to OraclePriceManager but optimized for direct Binance feed.
 
 use super::types::BinanceAsset;
+use crate::infrastructure::client::oracle::{PriceHistory, DEFAULT_PRICE_HISTORY_LEN};
 use parking_lot::RwLock;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -151,6 +152,12 @@ pub struct BinancePriceManager {
 
     /// Connection health state
     pub health: BinanceHealthState,
+
+    /// Recent prices per symbol
+    history: HashMap<String, PriceHistory>,
+
+    /// Prices kept per history buffer
+    history_len: usize,
 }
 
 impl Default for BinancePriceManager {
@@ -165,9 +172,17 @@ impl BinancePriceManager {
         Self {
             prices: HashMap::with_capacity(4), // BTC, ETH, SOL, XRP
             health: BinanceHealthState::default(),
+            history: HashMap::with_capacity(4),
+            history_len: DEFAULT_PRICE_HISTORY_LEN,
         }
     }
 
+    /// Keep the last `len` prices per symbol (default 256)
+    pub fn with_history_len(mut self, len: usize) -> Self {
+        self.history_len = len;
+        self
+    }
+
     /// Update price for a symbol
     pub fn update_price(
         &mut self,
@@ -179,7 +194,13 @@ impl BinancePriceManager {
     ) {
         let entry = BinancePriceEntry::new(value, binance_timestamp, trade_id, is_sell);
         self.health.record_update(entry.latency_ms);
-        self.prices.insert(symbol.to_uppercase(), entry);
+        let symbol = symbol.to_uppercase();
+        let history_len = self.history_len;
+        self.history
+            .entry(symbol.clone())
+            .or_insert_with(|| PriceHistory::new(history_len))
+            .push(value);
+        self.prices.insert(symbol, entry);
     }
 
     /// Get price for a symbol
@@ -192,6 +213,19 @@ impl BinancePriceManager {
         self.get_price(asset.symbol())
     }
 
+    /// Up to `n` most recent prices for a symbol, oldest first
+    pub fn recent_prices(&self, symbol: &str, n: usize) -> Vec<f64> {
+        self.history
+            .get(&symbol.to_uppercase())
+            .map(|history| history.recent(n))
+            .unwrap_or_default()
+    }
+
+    /// Standard deviation of returns over the recent price history
+    pub fn volatility(&self, symbol: &str) -> Option<f64> {
+        self.history.get(&symbol.to_uppercase())?.volatility()
+    }
+
     /// Check if connection is healthy (received data recently)
     pub fn is_healthy(&self, max_age: Duration) -> bool {
         self.health.time_since_update() < max_age
@@ -396,4 +430,28 @@ mod tests {
         assert!(all.contains_key("BTC"));
         assert!(all.contains_key("ETH"));
     }
+
+    #[test]
+    fn test_price_history_and_volatility() {
+        let mut manager = BinancePriceManager::new().with_history_len(4);
+
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+
+        for (i, price) in [50.0, 100.0, 110.0, 99.0, 108.9].into_iter().enumerate() {
+            manager.update_price("ETH", price, now_ms, i as u64, false);
+        }
+
+        assert_eq!(manager.recent_prices("eth", 10), vec![100.0, 110.0, 99.0, 108.9]);
+        assert_eq!(manager.recent_prices("ETH", 2), vec![99.0, 108.9]);
+
+        // Returns +10%, -10%, +10%: mean 1/30, sample variance 0.04 / 3
+        let vol = manager.volatility("ETH").unwrap();
+        assert!((vol - (0.04_f64 / 3.0).sqrt()).abs() < 1e-9);
+
+        assert!(manager.recent_prices("BTC", 10).is_empty());
+        assert_eq!(manager.volatility("BTC"), None);
+    }
 }
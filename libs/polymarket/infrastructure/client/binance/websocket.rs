@@ -239,6 +239,14 @@ fn handle_client_event(event: ClientEvent) -> bool {
             warn!("[Binance WS] Error: {}", err);
             true
         }
+        ClientEvent::MessageTooLarge { size, max_size } => {
+            warn!("[Binance WS] Message too large: {} bytes (max {})", size, max_size);
+            true
+        }
+        ClientEvent::CircuitOpen => {
+            warn!("[Binance WS] Circuit breaker open, stopped reconnecting");
+            false
+        }
     }
 }
 
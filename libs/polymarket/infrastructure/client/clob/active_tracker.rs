@@ -0,0 +1,423 @@
+//! Auto-subscribing market tracker driven by the markets database
+//!
+//! Instead of tracking an explicit list of tokens, this tracker periodically
+//! reads the active (or expiring-soon) market set from the database and diffs it
+//! against the current subscriptions: new markets get a WebSocket connection,
+//! markets that left the window are unsubscribed and their books dropped.
+
+use super::sniper_ws::{
+    build_ws_client, MarketTrackerConfig, SharedOrderbooks, SharedPrecisions,
+    SniperConnectionConfig, SniperRouter,
+};
+use super::sniper_ws_types::SniperMessage;
+use crate::domain::DbMarket;
+use crate::infrastructure::MarketDatabase;
+use anyhow::Result;
+use hypersockets::WebSocketClient;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+/// WebSocket client type used per subscribed market
+type MarketWsClient = WebSocketClient<SniperRouter, SniperMessage>;
+
+// =============================================================================
+// Market Source
+// =============================================================================
+
+/// Source of the market set the tracker should be subscribed to
+#[async_trait::async_trait]
+pub trait ActiveMarketSource: Send + Sync {
+    async fn active_markets(&self) -> Result<Vec<DbMarket>>;
+}
+
+/// Reads the active set from `MarketDatabase`.
+///
+/// With a window, only markets resolving within `window_secs` are included;
+/// without one, every active market is.
+pub struct DbMarketSource {
+    database: Arc<MarketDatabase>,
+    window_secs: Option<f64>,
+}
+
+impl DbMarketSource {
+    /// Track all active markets
+    pub fn active(database: Arc<MarketDatabase>) -> Self {
+        Self {
+            database,
+            window_secs: None,
+        }
+    }
+
+    /// Track only markets expiring within `window_secs`
+    pub fn expiring_within(database: Arc<MarketDatabase>, window_secs: f64) -> Self {
+        Self {
+            database,
+            window_secs: Some(window_secs),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ActiveMarketSource for DbMarketSource {
+    async fn active_markets(&self) -> Result<Vec<DbMarket>> {
+        let markets = match self.window_secs {
+            Some(secs) => self.database.get_markets_expiring_soon(secs).await?,
+            None => self.database.get_active_markets().await?,
+        };
+        Ok(markets)
+    }
+}
+
+// =============================================================================
+// Subscriber
+// =============================================================================
+
+/// Opens and closes per-market feeds
+#[async_trait::async_trait]
+pub trait MarketSubscriber: Send {
+    async fn subscribe(&mut self, market: &DbMarket) -> Result<()>;
+    async fn unsubscribe(&mut self, market_id: &str);
+}
+
+/// Subscriber that opens one CLOB WebSocket per market, writing into shared books
+pub struct WsMarketSubscriber {
+    orderbooks: SharedOrderbooks,
+    precisions: SharedPrecisions,
+    max_depth: Option<usize>,
+    connection: SniperConnectionConfig,
+    clients: HashMap<String, (MarketWsClient, Vec<String>)>,
+}
+
+impl WsMarketSubscriber {
+    pub fn new(orderbooks: SharedOrderbooks, precisions: SharedPrecisions) -> Self {
+        Self {
+            orderbooks,
+            precisions,
+            max_depth: None,
+            connection: SniperConnectionConfig::default(),
+            clients: HashMap::new(),
+        }
+    }
+
+    /// Limit stored book depth for every subscribed market
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Heartbeat, pong and reconnection settings for every connection
+    pub fn with_connection(mut self, connection: SniperConnectionConfig) -> Self {
+        self.connection = connection;
+        self
+    }
+
+    /// Shut down every open connection
+    pub async fn shutdown_all(&mut self) {
+        let ids: Vec<String> = self.clients.keys().cloned().collect();
+        for id in ids {
+            self.unsubscribe(&id).await;
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl MarketSubscriber for WsMarketSubscriber {
+    async fn subscribe(&mut self, market: &DbMarket) -> Result<()> {
+        let token_ids = market.parse_token_ids()?;
+        let outcomes = market.parse_outcomes()?;
+
+        let mut config = MarketTrackerConfig::new(
+            market.id.clone(),
+            market.question.clone(),
+            market.slug.clone(),
+            token_ids.clone(),
+            outcomes,
+            &market.resolution_time,
+        )?
+        .with_connection(self.connection.clone());
+        if let Some(depth) = self.max_depth {
+            config = config.with_max_depth(depth);
+        }
+
+        let client = build_ws_client(
+            &config,
+            self.orderbooks.clone(),
+            self.precisions.clone(),
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .await?;
+
+        self.clients.insert(market.id.clone(), (client, token_ids));
+        Ok(())
+    }
+
+    async fn unsubscribe(&mut self, market_id: &str) {
+        if let Some((client, token_ids)) = self.clients.remove(market_id) {
+            if let Err(e) = client.shutdown().await {
+                warn!("[ActiveTracker] Error shutting down {}: {}", market_id, e);
+            }
+
+            let mut obs = self.orderbooks.write();
+            let mut precs = self.precisions.write();
+            for token_id in &token_ids {
+                obs.remove(token_id);
+                precs.remove(token_id);
+            }
+        }
+    }
+}
+
+// =============================================================================
+// Diffing
+// =============================================================================
+
+/// Compute which markets to subscribe and which market ids to drop
+pub fn diff_subscriptions<'a>(
+    current: &HashSet<String>,
+    desired: &'a [DbMarket],
+) -> (Vec<&'a DbMarket>, Vec<String>) {
+    let desired_ids: HashSet<&str> = desired.iter().map(|m| m.id.as_str()).collect();
+
+    let to_add = desired
+        .iter()
+        .filter(|m| !current.contains(&m.id))
+        .collect();
+    let to_remove = current
+        .iter()
+        .filter(|id| !desired_ids.contains(id.as_str()))
+        .cloned()
+        .collect();
+
+    (to_add, to_remove)
+}
+
+/// Run one refresh cycle: fetch the market set and reconcile subscriptions
+pub async fn sync_subscriptions<S, M>(
+    source: &S,
+    subscriber: &mut M,
+    subscribed: &mut HashSet<String>,
+) -> Result<()>
+where
+    S: ActiveMarketSource + ?Sized,
+    M: MarketSubscriber + ?Sized,
+{
+    let markets = source.active_markets().await?;
+    let (to_add, to_remove) = diff_subscriptions(subscribed, &markets);
+
+    for market_id in to_remove {
+        info!("[ActiveTracker] Market {} left the active set, unsubscribing", market_id);
+        subscriber.unsubscribe(&market_id).await;
+        subscribed.remove(&market_id);
+    }
+
+    for market in to_add {
+        match subscriber.subscribe(market).await {
+            Ok(()) => {
+                info!("[ActiveTracker] Subscribed to {} ({})", market.id, market.question);
+                subscribed.insert(market.id.clone());
+            }
+            Err(e) => {
+                // Not inserted, so the next cycle retries it
+                warn!("[ActiveTracker] Failed to subscribe {}: {}", market.id, e);
+            }
+        }
+    }
+
+    debug!("[ActiveTracker] {} markets subscribed", subscribed.len());
+    Ok(())
+}
+
+// =============================================================================
+// Entry Points
+// =============================================================================
+
+/// Spawn a tracker that keeps `subscriber` in sync with `source` every `refresh_interval`.
+///
+/// Runs until `shutdown_flag` goes false (true = keep running), then unsubscribes everything.
+pub fn spawn_active_market_tracker_with<S, M>(
+    source: Arc<S>,
+    mut subscriber: M,
+    refresh_interval: Duration,
+    shutdown_flag: Arc<AtomicBool>,
+) -> JoinHandle<()>
+where
+    S: ActiveMarketSource + ?Sized + 'static,
+    M: MarketSubscriber + 'static,
+{
+    tokio::spawn(async move {
+        let mut subscribed: HashSet<String> = HashSet::new();
+
+        while shutdown_flag.load(Ordering::Acquire) {
+            if let Err(e) = sync_subscriptions(source.as_ref(), &mut subscriber, &mut subscribed).await
+            {
+                warn!("[ActiveTracker] Failed to refresh market set: {}", e);
+            }
+            sleep_while_running(refresh_interval, &shutdown_flag).await;
+        }
+
+        for market_id in subscribed.drain() {
+            subscriber.unsubscribe(&market_id).await;
+        }
+        info!("[ActiveTracker] Stopped");
+    })
+}
+
+/// Sleep for `duration`, returning early once `shutdown_flag` goes false
+async fn sleep_while_running(duration: Duration, shutdown_flag: &AtomicBool) {
+    const CHECK_INTERVAL: Duration = Duration::from_millis(50);
+    let deadline = tokio::time::Instant::now() + duration;
+
+    while shutdown_flag.load(Ordering::Acquire) {
+        let now = tokio::time::Instant::now();
+        if now >= deadline {
+            break;
+        }
+        tokio::time::sleep(CHECK_INTERVAL.min(deadline - now)).await;
+    }
+}
+
+/// Spawn a tracker that subscribes to the database's active market set.
+///
+/// Orderbooks for every subscribed token are written into the returned
+/// `SharedOrderbooks`; markets leaving the set have their books removed.
+pub fn spawn_active_market_tracker(
+    database: Arc<MarketDatabase>,
+    window_secs: Option<f64>,
+    refresh_interval: Duration,
+    shutdown_flag: Arc<AtomicBool>,
+) -> (SharedOrderbooks, JoinHandle<()>) {
+    let orderbooks: SharedOrderbooks = Default::default();
+    let precisions: SharedPrecisions = Default::default();
+
+    let source = match window_secs {
+        Some(secs) => DbMarketSource::expiring_within(database, secs),
+        None => DbMarketSource::active(database),
+    };
+    let subscriber = WsMarketSubscriber::new(orderbooks.clone(), precisions);
+
+    let handle = spawn_active_market_tracker_with(
+        Arc::new(source),
+        subscriber,
+        refresh_interval,
+        shutdown_flag,
+    );
+
+    (orderbooks, handle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database::tests::test_market;
+    use parking_lot::Mutex;
+
+    /// Database market fixture with a token pair derived from `id`
+    fn market(id: &str) -> DbMarket {
+        DbMarket {
+            condition_id: Some(format!("0x{}", id)),
+            token_ids: format!(r#"["{0}-yes","{0}-no"]"#, id),
+            ..test_market(id)
+        }
+    }
+
+    /// Market source backed by a mutable list
+    #[derive(Default)]
+    struct MockSource {
+        markets: Mutex<Vec<DbMarket>>,
+    }
+
+    #[async_trait::async_trait]
+    impl ActiveMarketSource for MockSource {
+        async fn active_markets(&self) -> Result<Vec<DbMarket>> {
+            Ok(self.markets.lock().clone())
+        }
+    }
+
+    /// Subscriber that records the current subscription set
+    #[derive(Clone, Default)]
+    struct MockSubscriber {
+        active: Arc<Mutex<HashSet<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl MarketSubscriber for MockSubscriber {
+        async fn subscribe(&mut self, market: &DbMarket) -> Result<()> {
+            self.active.lock().insert(market.id.clone());
+            Ok(())
+        }
+
+        async fn unsubscribe(&mut self, market_id: &str) {
+            self.active.lock().remove(market_id);
+        }
+    }
+
+    #[test]
+    fn test_diff_subscriptions() {
+        let current: HashSet<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        let desired = vec![market("b"), market("c")];
+
+        let (to_add, to_remove) = diff_subscriptions(&current, &desired);
+
+        assert_eq!(to_add.iter().map(|m| m.id.as_str()).collect::<Vec<_>>(), vec!["c"]);
+        assert_eq!(to_remove, vec!["a".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_market_added_mid_run_gets_subscribed() {
+        let source = Arc::new(MockSource::default());
+        source.markets.lock().push(market("m1"));
+
+        let subscriber = MockSubscriber::default();
+        let active = subscriber.active.clone();
+        let shutdown_flag = Arc::new(AtomicBool::new(true));
+
+        let handle = spawn_active_market_tracker_with(
+            source.clone(),
+            subscriber,
+            Duration::from_millis(10),
+            shutdown_flag.clone(),
+        );
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(*active.lock(), HashSet::from(["m1".to_string()]));
+
+        // DB gains a market and drops the first one
+        *source.markets.lock() = vec![market("m2")];
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(*active.lock(), HashSet::from(["m2".to_string()]));
+
+        shutdown_flag.store(false, Ordering::Release);
+        handle.await.unwrap();
+        assert!(active.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_does_not_wait_out_the_refresh_interval() {
+        let source = Arc::new(MockSource::default());
+        source.markets.lock().push(market("m1"));
+        let subscriber = MockSubscriber::default();
+        let active = subscriber.active.clone();
+        let shutdown_flag = Arc::new(AtomicBool::new(true));
+
+        let handle = spawn_active_market_tracker_with(
+            source,
+            subscriber,
+            Duration::from_secs(60),
+            shutdown_flag.clone(),
+        );
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(active.lock().len(), 1);
+
+        shutdown_flag.store(false, Ordering::Release);
+        tokio::time::timeout(Duration::from_secs(1), handle)
+            .await
+            .expect("tracker kept sleeping after shutdown")
+            .unwrap();
+        assert!(active.lock().is_empty());
+    }
+}
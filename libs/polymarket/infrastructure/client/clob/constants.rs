@@ -30,6 +30,13 @@ pub const USDC_ADDRESS: &str = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174";
 /// Zero address (for public orders)
 pub const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
 
+// ============================================================================
+// API Limits
+// ============================================================================
+
+/// Maximum number of orders the CLOB accepts in one `POST /orders` request
+pub const MAX_BATCH_ORDERS: usize = 15;
+
 // ============================================================================
 // EIP-712 Domain Constants
 // ============================================================================
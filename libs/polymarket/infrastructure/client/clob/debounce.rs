@@ -0,0 +1,150 @@
+//! Per-token debounce for orderbook update notifications
+//!
+//! Busy markets can change a book hundreds of times per second. The shared
+//! orderbooks always hold the latest state, but consumers that react to
+//! *notifications* only need to wake up once per interval. The debouncer
+//! coalesces updates per token: the first update opens a window, later ones
+//! replace it, and the latest is emitted when the window closes.
+
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+/// Notification that a token's book changed
+#[derive(Debug, Clone, PartialEq)]
+pub struct BookUpdate {
+    pub asset_id: String,
+    pub best_bid: Option<f64>,
+    pub best_ask: Option<f64>,
+}
+
+/// Coalesces updates per token into at most one per interval
+pub struct UpdateDebouncer {
+    interval: Duration,
+    /// Latest update per token and when its window closes
+    pending: HashMap<String, (BookUpdate, Instant)>,
+}
+
+impl UpdateDebouncer {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Record an update, replacing any pending one for the same token
+    pub fn push(&mut self, update: BookUpdate, now: Instant) {
+        let deadline = now + self.interval;
+        self.pending
+            .entry(update.asset_id.clone())
+            .and_modify(|(pending, _)| *pending = update.clone())
+            .or_insert((update, deadline));
+    }
+
+    /// Take every update whose window has closed
+    pub fn take_due(&mut self, now: Instant) -> Vec<BookUpdate> {
+        let due: Vec<String> = self
+            .pending
+            .iter()
+            .filter(|(_, (_, deadline))| *deadline <= now)
+            .map(|(asset_id, _)| asset_id.clone())
+            .collect();
+
+        due.into_iter()
+            .filter_map(|asset_id| self.pending.remove(&asset_id).map(|(update, _)| update))
+            .collect()
+    }
+
+    /// Earliest time a pending update becomes due
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.pending.values().map(|(_, deadline)| *deadline).min()
+    }
+}
+
+/// Spawn a thread that debounces `input` into `output`.
+///
+/// The thread exits once `input` disconnects (flushing whatever is pending)
+/// or `output` is dropped.
+pub fn spawn_update_debouncer(
+    input: Receiver<BookUpdate>,
+    output: Sender<BookUpdate>,
+    interval: Duration,
+) -> std::thread::JoinHandle<()> {
+    std::thread::spawn(move || {
+        let mut debouncer = UpdateDebouncer::new(interval);
+
+        loop {
+            let wait = debouncer
+                .next_deadline()
+                .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+                .unwrap_or(interval);
+
+            match input.recv_timeout(wait) {
+                Ok(update) => debouncer.push(update, Instant::now()),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => {
+                    let far_future = Instant::now() + interval;
+                    for update in debouncer.take_due(far_future) {
+                        let _ = output.send(update);
+                    }
+                    debug!("[Debounce] Input closed, exiting");
+                    return;
+                }
+            }
+
+            for update in debouncer.take_due(Instant::now()) {
+                if output.send(update).is_err() {
+                    return; // Consumer gone
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossbeam_channel::unbounded;
+
+    fn update(asset_id: &str, best_bid: f64) -> BookUpdate {
+        BookUpdate {
+            asset_id: asset_id.to_string(),
+            best_bid: Some(best_bid),
+            best_ask: None,
+        }
+    }
+
+    #[test]
+    fn test_debouncer_keeps_latest_per_token() {
+        let mut debouncer = UpdateDebouncer::new(Duration::from_millis(100));
+        let t0 = Instant::now();
+
+        debouncer.push(update("a", 0.40), t0);
+        debouncer.push(update("b", 0.60), t0);
+        debouncer.push(update("a", 0.45), t0 + Duration::from_millis(50));
+
+        assert!(debouncer.take_due(t0 + Duration::from_millis(99)).is_empty());
+
+        let mut due = debouncer.take_due(t0 + Duration::from_millis(100));
+        due.sort_by(|x, y| x.asset_id.cmp(&y.asset_id));
+        assert_eq!(due, vec![update("a", 0.45), update("b", 0.60)]);
+        assert_eq!(debouncer.next_deadline(), None);
+    }
+
+    #[test]
+    fn test_burst_within_interval_emits_one_update() {
+        let (tx, rx) = unbounded();
+        let (out_tx, out) = unbounded();
+        spawn_update_debouncer(rx, out_tx, Duration::from_millis(100));
+
+        for i in 0..10 {
+            tx.send(update("tok", i as f64)).unwrap();
+        }
+
+        let first = out.recv_timeout(Duration::from_millis(500)).unwrap();
+        assert_eq!(first, update("tok", 9.0));
+        assert!(out.recv_timeout(Duration::from_millis(200)).is_err());
+    }
+}
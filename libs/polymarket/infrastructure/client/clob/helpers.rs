@@ -6,15 +6,30 @@
 use reqwest::RequestBuilder;
 use std::collections::HashMap;
 
-use super::rest::RestError;
+use super::rest::{parse_retry_after, RestError};
 
 /// Extract error message from a failed API response
+///
+/// A 429 becomes `RestError::RateLimited` with the response's `Retry-After`.
 pub async fn extract_api_error(response: reqwest::Response, context: &str) -> RestError {
+    let status = response.status().as_u16();
+    if status == 429 {
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok());
+        return RestError::RateLimited {
+            retry_after: parse_retry_after(retry_after),
+        };
+    }
     let error_text = response
         .text()
         .await
         .unwrap_or_else(|_| "Unknown error".to_string());
-    RestError::ApiError(format!("{}: {}", context, error_text))
+    RestError::HttpStatus {
+        status,
+        message: format!("{}: {}", context, error_text),
+    }
 }
 
 /// Check if response is successful, returning the response or an error
@@ -47,3 +62,26 @@ pub async fn parse_json<T: serde::de::DeserializeOwned>(
         .await
         .map_err(|e| RestError::DeserializeFailed(e.to_string()))
 }
+
+/// Convert a failed blocking (ureq) request into a `RestError`
+///
+/// A 429 becomes `RestError::RateLimited`, any other error status an
+/// `HttpStatus` carrying the response body (where the exchange explains the
+/// rejection), and transport failures an `ApiError`; messages are prefixed
+/// with `context`.
+pub fn ureq_error(err: ureq::Error, context: &str) -> RestError {
+    match err {
+        ureq::Error::Status(429, response) => RestError::RateLimited {
+            retry_after: parse_retry_after(response.header("Retry-After")),
+        },
+        ureq::Error::Status(status, response) => RestError::HttpStatus {
+            status,
+            message: format!(
+                "{}: {}",
+                context,
+                response.into_string().unwrap_or_else(|_| "Unknown error".to_string())
+            ),
+        },
+        other => RestError::ApiError(format!("{}: {}", context, other)),
+    }
+}
@@ -11,9 +11,15 @@
 //! - `order_builder/`: EIP-712 order signing (split into mod, types, signing, encoding, payload)
 //! - `trading`: High-level trading client with simplified API
 //! - `sniper_ws`: WebSocket orderbook tracking utilities
+//! - `active_tracker`: DB-driven tracker that follows the active market set
+//! - `debounce`: Per-token coalescing of book update notifications
+//! - `nonce`: Caches the maker's current exchange nonce for order signing
 
+pub mod active_tracker;
 pub mod constants;
+pub mod debounce;
 mod helpers;
+pub mod nonce;
 pub mod order_builder;
 pub mod orderbook;
 pub mod rest;
@@ -23,15 +29,24 @@ pub mod trading;
 pub mod types;
 
 // Re-export main types
+pub use active_tracker::{
+    spawn_active_market_tracker, spawn_active_market_tracker_with, ActiveMarketSource,
+    DbMarketSource, MarketSubscriber, WsMarketSubscriber,
+};
 pub use constants::*;
+pub use debounce::{spawn_update_debouncer, BookUpdate, UpdateDebouncer};
 pub use hypersockets::WebSocketClient;
-pub use order_builder::{Order, OrderBuilder, SignedOrder};
-pub use rest::RestClient;
+pub use nonce::{NonceManager, NonceSource};
+pub use order_builder::{Order, OrderBuilder, SignedOrder, TimeInForce};
+pub use rest::{
+    ClockSkew, ClockSyncConfig, EndpointClass, HttpPoolConfig, RateLimiter, RestClient, RetryPolicy,
+};
 pub use sniper_ws::{
-    build_ws_client, decimal_places, handle_client_event, max_precision_in_levels,
-    MarketTrackerConfig, SharedOrderbooks, SharedPrecisions, SniperHandler, SniperRoute,
-    SniperRouter,
+    build_sniper_ws, build_ws_client, decimal_places, handle_client_event, is_market_closed,
+    last_trade, max_precision_in_levels, request_book_resync, MarketTrackerConfig,
+    SharedOrderbooks, SharedPrecisions, SniperConnectionConfig, SniperHandler, SniperRoute,
+    SniperRouter, SniperSharedState, MARKET_WS_URL,
 };
-pub use sniper_ws_types::{SniperMessage, TickSizeChangeEvent};
+pub use sniper_ws_types::{MarketResolvedEvent, SniperMessage, TickSizeChangeEvent};
 pub use trading::{BatchOrderResult, TradingClient, TradingError};
 pub use types::*;
@@ -0,0 +1,148 @@
+//! Order nonce caching
+//!
+//! Orders carry a maker nonce, and the exchange only accepts orders whose
+//! nonce equals the maker's current on-chain nonce (`nonces[maker]`). That
+//! value only changes when the maker calls `incrementNonce` to invalidate
+//! every outstanding order, so every order is signed with the same nonce.
+//! The manager fetches it once and caches it; `refresh` re-reads it after a
+//! nonce rejection and `invalidate` drops it after an `incrementNonce`.
+
+use super::trading::Result;
+use tokio::sync::Mutex;
+use tracing::debug;
+
+/// Where the manager reads the exchange's current nonce from
+#[async_trait::async_trait]
+pub trait NonceSource: Send + Sync {
+    async fn fetch_nonce(&self) -> Result<u64>;
+}
+
+/// Caches the maker's current exchange nonce
+#[derive(Debug, Default)]
+pub struct NonceManager {
+    /// Cached exchange nonce, `None` until fetched
+    current: Mutex<Option<u64>>,
+}
+
+impl NonceManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Nonce to sign the next order with, fetching it on first use
+    pub async fn current(&self, source: &dyn NonceSource) -> Result<u64> {
+        let mut current = self.current.lock().await;
+        if let Some(nonce) = *current {
+            return Ok(nonce);
+        }
+        let fetched = source.fetch_nonce().await?;
+        debug!("Nonce manager seeded from exchange: {}", fetched);
+        *current = Some(fetched);
+        Ok(fetched)
+    }
+
+    /// Re-read the exchange nonce, e.g. after an order was rejected for it
+    pub async fn refresh(&self, source: &dyn NonceSource) -> Result<u64> {
+        let mut current = self.current.lock().await;
+        let fetched = source.fetch_nonce().await?;
+        debug!("Nonce manager refreshed: {:?} -> {}", *current, fetched);
+        *current = Some(fetched);
+        Ok(fetched)
+    }
+
+    /// Drop the cached nonce so the next order re-fetches it
+    ///
+    /// Call after `incrementNonce`, which cancels every order signed with
+    /// the old value.
+    pub async fn invalidate(&self) {
+        *self.current.lock().await = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    struct MockSource {
+        nonce: AtomicU64,
+        fetches: AtomicUsize,
+    }
+
+    impl MockSource {
+        fn new(nonce: u64) -> Self {
+            Self {
+                nonce: AtomicU64::new(nonce),
+                fetches: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl NonceSource for MockSource {
+        async fn fetch_nonce(&self) -> Result<u64> {
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+            tokio::task::yield_now().await;
+            Ok(self.nonce.load(Ordering::SeqCst))
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_callers_share_one_fetched_nonce() {
+        let manager = Arc::new(NonceManager::new());
+        let source = Arc::new(MockSource::new(42));
+
+        let handles: Vec<_> = (0..64)
+            .map(|_| {
+                let manager = Arc::clone(&manager);
+                let source = Arc::clone(&source);
+                tokio::spawn(async move {
+                    let mut nonces = Vec::new();
+                    for _ in 0..10 {
+                        nonces.push(manager.current(source.as_ref()).await.unwrap());
+                    }
+                    nonces
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert!(handle.await.unwrap().iter().all(|&n| n == 42));
+        }
+        assert_eq!(source.fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_follows_the_exchange() {
+        let manager = NonceManager::new();
+        let source = MockSource::new(5);
+
+        assert_eq!(manager.current(&source).await.unwrap(), 5);
+        assert_eq!(manager.current(&source).await.unwrap(), 5);
+
+        // Exchange moved on (incrementNonce elsewhere): follow it
+        source.nonce.store(6, Ordering::SeqCst);
+        assert_eq!(manager.current(&source).await.unwrap(), 5);
+        assert_eq!(manager.refresh(&source).await.unwrap(), 6);
+        assert_eq!(manager.current(&source).await.unwrap(), 6);
+
+        // A lower exchange value is taken as-is too
+        source.nonce.store(3, Ordering::SeqCst);
+        assert_eq!(manager.refresh(&source).await.unwrap(), 3);
+        assert_eq!(manager.current(&source).await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_refetches_on_next_use() {
+        let manager = NonceManager::new();
+        let source = MockSource::new(7);
+
+        assert_eq!(manager.current(&source).await.unwrap(), 7);
+        source.nonce.store(8, Ordering::SeqCst);
+        manager.invalidate().await;
+
+        assert_eq!(manager.current(&source).await.unwrap(), 8);
+        assert_eq!(source.fetches.load(Ordering::SeqCst), 2);
+    }
+}
@@ -17,14 +17,15 @@ mod signing;
 mod types;
 
 pub use payload::{build_batch_order_payload, build_order_payload};
-pub use types::{Order, OrderBuilderError, Result, SignedOrder};
+pub use types::{Order, OrderBuilderError, Result, SignedOrder, TimeInForce};
 
 use super::constants::*;
-use super::types::Side;
+use super::types::{Side, SignatureType};
 use crate::infrastructure::client::auth::PolymarketAuth;
 use ethers::types::{Address, H256, U256};
 use rand::Rng;
 use signing::compute_eip712_hash;
+use std::time::{SystemTime, UNIX_EPOCH};
 #[cfg(test)]
 use signing::{compute_domain_separator, compute_struct_hash};
 
@@ -37,9 +38,13 @@ pub struct OrderBuilder {
     /// Chain ID (137 for Polygon)
     chain_id: u64,
     /// Signature type (default: POLY_PROXY)
-    signature_type: u8,
+    signature_type: SignatureType,
     /// Whether the market uses neg_risk exchange (affects EIP-712 domain)
     neg_risk: bool,
+    /// Overrides the random salt (tests, deterministic replay)
+    salt_source: Option<Box<dyn Fn() -> U256 + Send + Sync>>,
+    /// Exchange clock offset applied when checking expirations
+    clock_offset_secs: i64,
 }
 
 impl OrderBuilder {
@@ -59,8 +64,10 @@ impl OrderBuilder {
             signer,
             maker,
             chain_id,
-            signature_type: SIGNATURE_TYPE_POLY_PROXY,
+            signature_type: SignatureType::PolyProxy,
             neg_risk,
+            salt_source: None,
+            clock_offset_secs: 0,
         }
     }
 
@@ -70,8 +77,10 @@ impl OrderBuilder {
             signer: address,
             maker: address,
             chain_id,
-            signature_type: SIGNATURE_TYPE_EOA,
+            signature_type: SignatureType::Eoa,
             neg_risk,
+            salt_source: None,
+            clock_offset_secs: 0,
         }
     }
 
@@ -80,17 +89,40 @@ impl OrderBuilder {
             signer,
             maker,
             chain_id,
-            signature_type: SIGNATURE_TYPE_POLY_GNOSIS_SAFE,
+            signature_type: SignatureType::PolyGnosisSafe,
             neg_risk,
+            salt_source: None,
+            clock_offset_secs: 0,
         }
     }
 
     /// Set signature type
-    pub fn with_signature_type(mut self, signature_type: u8) -> Self {
+    pub fn with_signature_type(mut self, signature_type: SignatureType) -> Self {
         self.signature_type = signature_type;
         self
     }
 
+    /// Get the signature type orders are signed with
+    pub fn signature_type(&self) -> SignatureType {
+        self.signature_type
+    }
+
+    /// Use `source` for order salts instead of the random default
+    ///
+    /// Salts make otherwise identical orders distinct, so only inject a fixed
+    /// source for tests or deterministic replay.
+    pub fn with_salt_source(mut self, source: impl Fn() -> U256 + Send + Sync + 'static) -> Self {
+        self.salt_source = Some(Box::new(source));
+        self
+    }
+
+    /// Check expirations against local time shifted by `offset_secs`
+    /// (see `RestClient::clock_offset`)
+    pub fn with_clock_offset(mut self, offset_secs: i64) -> Self {
+        self.clock_offset_secs = offset_secs;
+        self
+    }
+
     /// Set neg_risk flag
     pub fn with_neg_risk(mut self, neg_risk: bool) -> Self {
         self.neg_risk = neg_risk;
@@ -107,7 +139,8 @@ impl OrderBuilder {
     /// * `side` - BUY or SELL
     /// * `nonce` - Current nonce from exchange
     /// * `fee_rate_bps` - Fee rate in basis points (default: 0)
-    /// * `expiration` - Expiration timestamp (0 = no expiration)
+    /// * `time_in_force` - Sets `orderType` and the signed expiration; a `Gtd`
+    ///   expiration must be in the future
     pub fn build_signed_order(
         &self,
         auth: &PolymarketAuth,
@@ -117,7 +150,7 @@ impl OrderBuilder {
         side: Side,
         nonce: u64,
         fee_rate_bps: Option<u64>,
-        expiration: Option<u64>,
+        time_in_force: TimeInForce,
     ) -> Result<SignedOrder> {
         // Validate inputs
         if price <= 0.0 || price >= 1.0 {
@@ -132,41 +165,127 @@ impl OrderBuilder {
                 size
             )));
         }
+        time_in_force.validate(unix_now().saturating_add_signed(self.clock_offset_secs))?;
 
-        // Parse token ID
-        let token_id_u256 = U256::from_dec_str(token_id).map_err(|e| {
-            OrderBuilderError::InvalidTokenId(format!("Failed to parse token ID: {}", e))
-        })?;
+        let token_id = parse_token_id(token_id)?;
 
         // Calculate amounts
-        let (maker_amount, taker_amount) = self.calculate_amounts(price, size, side);
+        let amounts = self.calculate_amounts(price, size, side);
+
+        let order = self.assemble_order(
+            token_id,
+            amounts,
+            side,
+            nonce,
+            fee_rate_bps,
+            time_in_force.expiration(),
+        );
 
-        // Generate random salt
-        let salt = self.generate_salt();
+        // Sign the order
+        let signature = self.sign_order(auth, &order)?;
 
-        // Build order
-        let order = Order {
-            salt,
+        Ok(SignedOrder {
+            order,
+            signature,
+            order_type: time_in_force.order_type(),
+            neg_risk: self.neg_risk,
+            post_only: false,
+        })
+    }
+
+    /// Build and sign a marketable FOK order
+    ///
+    /// # Arguments
+    /// * `auth` - Authentication manager for signing
+    /// * `token_id` - ERC1155 token ID (as string)
+    /// * `side` - BUY or SELL
+    /// * `amount` - USDC to spend for a BUY, tokens to sell for a SELL
+    /// * `price` - Reference price (e.g. best ask for a BUY, best bid for a SELL)
+    /// * `max_slippage` - Fraction the fill price may move against `price`
+    ///   (0.02 = 2%)
+    /// * `nonce` - Current nonce from exchange
+    /// * `fee_rate_bps` - Fee rate in basis points (default: 0)
+    #[allow(clippy::too_many_arguments)]
+    pub fn build_market_order(
+        &self,
+        auth: &PolymarketAuth,
+        token_id: &str,
+        side: Side,
+        amount: f64,
+        price: f64,
+        max_slippage: f64,
+        nonce: u64,
+        fee_rate_bps: Option<u64>,
+    ) -> Result<SignedOrder> {
+        if price <= 0.0 || price >= 1.0 {
+            return Err(OrderBuilderError::InvalidPrice(format!(
+                "Price must be between 0 and 1, got: {}",
+                price
+            )));
+        }
+        if amount <= 0.0 {
+            return Err(OrderBuilderError::InvalidSize(format!(
+                "Amount must be positive, got: {}",
+                amount
+            )));
+        }
+        if !(0.0..1.0).contains(&max_slippage) {
+            return Err(OrderBuilderError::InvalidPrice(format!(
+                "Slippage must be in [0, 1), got: {}",
+                max_slippage
+            )));
+        }
+
+        let token_id = parse_token_id(token_id)?;
+        let amounts = self.calculate_market_amounts(amount, price, max_slippage, side);
+        let time_in_force = TimeInForce::Fok;
+
+        let order = self.assemble_order(
+            token_id,
+            amounts,
+            side,
+            nonce,
+            fee_rate_bps,
+            time_in_force.expiration(),
+        );
+        let signature = self.sign_order(auth, &order)?;
+
+        Ok(SignedOrder {
+            order,
+            signature,
+            order_type: time_in_force.order_type(),
+            neg_risk: self.neg_risk,
+            post_only: false,
+        })
+    }
+
+    /// Fill in the order struct around pre-computed amounts
+    fn assemble_order(
+        &self,
+        token_id: U256,
+        (maker_amount, taker_amount): (U256, U256),
+        side: Side,
+        nonce: u64,
+        fee_rate_bps: Option<u64>,
+        expiration: u64,
+    ) -> Order {
+        Order {
+            salt: self.generate_salt(),
             maker: self.maker,
             signer: self.signer,
             taker: zero_address(), // ZERO_ADDRESS for public orders
-            token_id: token_id_u256,
+            token_id,
             maker_amount,
             taker_amount,
-            expiration: U256::from(expiration.unwrap_or(0)),
+            expiration: U256::from(expiration),
             nonce: U256::from(nonce),
             fee_rate_bps: U256::from(fee_rate_bps.unwrap_or(0)),
             side: match side {
                 Side::Buy => SIDE_BUY,
                 Side::Sell => SIDE_SELL,
             },
-            signature_type: self.signature_type,
-        };
-
-        // Sign the order
-        let signature = self.sign_order(auth, &order)?;
-
-        Ok(SignedOrder { order, signature })
+            signature_type: self.signature_type.into(),
+        }
     }
 
     /// Calculate maker and taker amounts based on side
@@ -190,12 +309,44 @@ impl OrderBuilder {
         }
     }
 
+    /// Calculate worst-case amounts for a marketable order
+    ///
+    /// Same 6-decimal rounding as `calculate_amounts`.
+    ///
+    /// For BUY orders (`amount` is USDC):
+    /// - makerAmount = amount (USDC to spend)
+    /// - takerAmount = amount / worst_price, worst_price = price * (1 + slippage)
+    ///   capped at 1 (min tokens to receive)
+    ///
+    /// For SELL orders (`amount` is tokens):
+    /// - makerAmount = amount (tokens to sell)
+    /// - takerAmount = amount * worst_price, worst_price = price * (1 - slippage)
+    ///   (min USDC to receive)
+    fn calculate_market_amounts(&self, amount: f64, price: f64, max_slippage: f64, side: Side) -> (U256, U256) {
+        let amount_scaled = (amount * DECIMAL_MULTIPLIER as f64).round() as u128;
+
+        match side {
+            Side::Buy => {
+                let worst_price = (price * (1.0 + max_slippage)).min(1.0);
+                let min_tokens = (amount / worst_price * DECIMAL_MULTIPLIER as f64).round() as u128;
+                (U256::from(amount_scaled), U256::from(min_tokens))
+            }
+            Side::Sell => {
+                let worst_price = price * (1.0 - max_slippage);
+                let min_usdc = (amount * worst_price * DECIMAL_MULTIPLIER as f64).round() as u128;
+                (U256::from(amount_scaled), U256::from(min_usdc))
+            }
+        }
+    }
+
     /// Generate a random salt matching Python's format
     ///
     /// Python uses: round(timestamp * random()) which produces a 32-bit-ish value
     /// We generate a similar small random number to stay compatible.
     fn generate_salt(&self) -> U256 {
-        use std::time::{SystemTime, UNIX_EPOCH};
+        if let Some(source) = &self.salt_source {
+            return source();
+        }
 
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -235,6 +386,18 @@ impl OrderBuilder {
     }
 }
 
+/// Parse a decimal ERC1155 token ID
+fn parse_token_id(token_id: &str) -> Result<U256> {
+    U256::from_dec_str(token_id).map_err(|e| {
+        OrderBuilderError::InvalidTokenId(format!("Failed to parse token ID: {}", e))
+    })
+}
+
+/// Current unix time in seconds (local clock)
+fn unix_now() -> u64 {
+    PolymarketAuth::current_timestamp()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -275,6 +438,101 @@ mod tests {
         assert_eq!(taker_amount, U256::from(50_000_000u64));
     }
 
+    #[test]
+    fn test_market_amount_calculation_buy() {
+        let builder = OrderBuilder::new(
+            Address::zero(),
+            Address::zero(),
+            POLYGON_CHAIN_ID,
+            false,
+        );
+
+        // Spend $50 at $0.50 with 2% slippage: worst price $0.51
+        let (maker_amount, taker_amount) = builder.calculate_market_amounts(50.0, 0.5, 0.02, Side::Buy);
+
+        // maker pays USDC: 50 * 1_000_000 = 50_000_000
+        assert_eq!(maker_amount, U256::from(50_000_000u64));
+        // taker provides at least 50 / 0.51 = 98.039216 tokens
+        assert_eq!(taker_amount, U256::from(98_039_216u64));
+
+        // Worst price is capped at $1: at least 1 token per dollar
+        let (_, taker_amount) = builder.calculate_market_amounts(50.0, 0.99, 0.05, Side::Buy);
+        assert_eq!(taker_amount, U256::from(50_000_000u64));
+    }
+
+    #[test]
+    fn test_market_amount_calculation_sell() {
+        let builder = OrderBuilder::new(
+            Address::zero(),
+            Address::zero(),
+            POLYGON_CHAIN_ID,
+            false,
+        );
+
+        // Sell 100 tokens at $0.50 with 2% slippage: worst price $0.49
+        let (maker_amount, taker_amount) = builder.calculate_market_amounts(100.0, 0.5, 0.02, Side::Sell);
+
+        // maker provides tokens: 100 * 1_000_000 = 100_000_000
+        assert_eq!(maker_amount, U256::from(100_000_000u64));
+        // taker pays at least 100 * 0.49 = 49 USDC
+        assert_eq!(taker_amount, U256::from(49_000_000u64));
+
+        // No slippage matches the limit order amounts
+        let market = builder.calculate_market_amounts(100.0, 0.5, 0.0, Side::Sell);
+        assert_eq!(market, builder.calculate_amounts(0.5, 100.0, Side::Sell));
+    }
+
+    #[test]
+    fn test_market_order_is_fok() {
+        let auth = PolymarketAuth::new(TEST_PRIVATE_KEY, POLYGON_CHAIN_ID).unwrap();
+        let builder = OrderBuilder::new_eoa(auth.address().unwrap(), POLYGON_CHAIN_ID, false);
+
+        let signed = builder
+            .build_market_order(
+                &auth,
+                TEST_TOKEN_ID,
+                Side::Buy,
+                10.0,
+                0.5,
+                0.01,
+                7,
+                Some(25),
+            )
+            .unwrap();
+        assert_eq!(signed.order.expiration, U256::zero());
+        assert_eq!(signed.order.nonce, U256::from(7));
+        assert_eq!(signed.order.fee_rate_bps, U256::from(25));
+        assert_eq!(build_order_payload(&signed, "owner-key")["orderType"], "FOK");
+
+        assert!(matches!(
+            builder.build_market_order(&auth, TEST_TOKEN_ID, Side::Buy, 10.0, 0.5, 1.0, 0, None),
+            Err(OrderBuilderError::InvalidPrice(_))
+        ));
+        assert!(matches!(
+            builder.build_market_order(&auth, TEST_TOKEN_ID, Side::Sell, 0.0, 0.5, 0.01, 0, None),
+            Err(OrderBuilderError::InvalidSize(_))
+        ));
+    }
+
+    #[test]
+    fn test_post_only_payload() {
+        let signed = build_with(TimeInForce::Gtc).unwrap();
+        assert!(build_order_payload(&signed, "owner-key").get("postOnly").is_none());
+
+        let post_only = signed.with_post_only(true).unwrap();
+        let payload = build_order_payload(&post_only, "owner-key");
+        assert_eq!(payload["postOnly"], true);
+        assert_eq!(payload["orderType"], "GTC");
+        assert_eq!(build_batch_order_payload(&[post_only], "owner-key").unwrap()[0][0], payload);
+
+        for time_in_force in [TimeInForce::Fok, TimeInForce::Fak] {
+            assert!(matches!(
+                build_with(time_in_force).unwrap().with_post_only(true),
+                Err(OrderBuilderError::InvalidPostOnly(_))
+            ));
+        }
+    }
+
     #[test]
     fn test_salt_generation_uniqueness() {
         let builder = OrderBuilder::new(
@@ -289,6 +547,121 @@ mod tests {
 
         // Salts should be different (extremely high probability)
         assert_ne!(salt1, salt2);
+
+        // Rapid calls stay uncorrelated
+        let salts: std::collections::HashSet<U256> = (0..100).map(|_| builder.generate_salt()).collect();
+        assert_eq!(salts.len(), 100);
+    }
+
+    #[test]
+    fn test_build_signed_order_with_fixed_salt_matches_python() {
+        // Same order as test_signature_matches_python, built end to end
+        let auth = PolymarketAuth::new(TEST_PRIVATE_KEY, POLYGON_CHAIN_ID).unwrap();
+        let maker = auth.address().unwrap();
+        let builder = OrderBuilder::new_eoa(maker, POLYGON_CHAIN_ID, false)
+            .with_salt_source(|| U256::from(12345u64));
+
+        let signed = builder
+            .build_signed_order(&auth, TEST_TOKEN_ID, 0.41, 40.0, Side::Buy, 0, None, TimeInForce::Gtc)
+            .unwrap();
+
+        assert_eq!(signed.order.salt, U256::from(12345u64));
+        assert_eq!(
+            signed.signature.to_lowercase(),
+            "0x069db5e77ee9b663b7c2d9bb388b156b314d42d39d3f968edcba9ebbd662b8856a116138dc95883183889d48d615b1f4ead5a35d18b439ab0a2b45b794744d151b"
+        );
+    }
+
+    const TEST_PRIVATE_KEY: &str = "0x257091039adf0d3df1f3171508f7db838782ee9b4f6ad61054be773e7541d90a";
+    const TEST_TOKEN_ID: &str = "87681536460342357667165150330318852851476971055929009934844581402585803923513";
+
+    fn build_with(time_in_force: TimeInForce) -> Result<SignedOrder> {
+        let auth = PolymarketAuth::new(TEST_PRIVATE_KEY, POLYGON_CHAIN_ID).unwrap();
+        let maker = auth.address().unwrap();
+        OrderBuilder::new_eoa(maker, POLYGON_CHAIN_ID, false).build_signed_order(
+            &auth,
+            TEST_TOKEN_ID,
+            0.5,
+            10.0,
+            Side::Buy,
+            0,
+            None,
+            time_in_force,
+        )
+    }
+
+    #[test]
+    fn test_time_in_force_payloads() {
+        let gtd_expiration = unix_now() + 3600;
+        for (time_in_force, order_type, expiration) in [
+            (TimeInForce::Gtc, "GTC", 0),
+            (TimeInForce::Gtd { expiration: gtd_expiration }, "GTD", gtd_expiration),
+            (TimeInForce::Fok, "FOK", 0),
+            (TimeInForce::Fak, "FAK", 0),
+        ] {
+            let signed = build_with(time_in_force).unwrap();
+            assert_eq!(signed.order.expiration, U256::from(expiration));
+
+            let payload = build_order_payload(&signed, "owner-key");
+            assert_eq!(payload["orderType"], order_type);
+            assert_eq!(payload["order"]["expiration"], expiration.to_string());
+            assert_eq!(payload["owner"], "owner-key");
+
+            let batch = build_batch_order_payload(&[signed], "owner-key").unwrap();
+            assert_eq!(batch.len(), 1);
+            assert_eq!(batch[0][0], payload);
+        }
+    }
+
+    #[test]
+    fn test_batch_rejects_mixed_signature_types_and_domains() {
+        let eoa = build_with(TimeInForce::Gtc).unwrap();
+
+        let mut proxy = eoa.clone();
+        proxy.order.signature_type = SIGNATURE_TYPE_POLY_PROXY;
+        let result = build_batch_order_payload(&[eoa.clone(), proxy], "owner-key");
+        assert!(matches!(result, Err(OrderBuilderError::InconsistentBatch(_))));
+
+        let mut neg_risk = eoa.clone();
+        neg_risk.neg_risk = true;
+        let result = build_batch_order_payload(&[eoa.clone(), neg_risk], "owner-key");
+        assert!(matches!(result, Err(OrderBuilderError::InconsistentBatch(_))));
+
+        assert!(build_batch_order_payload(&[eoa.clone(), eoa], "owner-key").is_ok());
+    }
+
+    #[test]
+    fn test_batch_splits_at_max_batch_orders() {
+        let signed = build_with(TimeInForce::Gtc).unwrap();
+        let orders = vec![signed; MAX_BATCH_ORDERS * 2 + 2];
+
+        let batches = build_batch_order_payload(&orders, "owner-key").unwrap();
+        let sizes: Vec<usize> = batches.iter().map(|b| b.as_array().unwrap().len()).collect();
+        assert_eq!(sizes, vec![MAX_BATCH_ORDERS, MAX_BATCH_ORDERS, 2]);
+
+        assert!(build_batch_order_payload(&[], "owner-key").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_gtd_expiration_in_past_rejected() {
+        let result = build_with(TimeInForce::Gtd { expiration: unix_now() - 1 });
+        assert!(matches!(result, Err(OrderBuilderError::InvalidExpiration(_))));
+
+        let result = build_with(TimeInForce::Gtd { expiration: 0 });
+        assert!(matches!(result, Err(OrderBuilderError::InvalidExpiration(_))));
+    }
+
+    #[test]
+    fn test_time_in_force_from_order_type() {
+        use super::super::types::OrderType;
+
+        assert_eq!(TimeInForce::try_from(OrderType::GTC).unwrap(), TimeInForce::Gtc);
+        assert_eq!(TimeInForce::try_from(OrderType::FOK).unwrap(), TimeInForce::Fok);
+        assert_eq!(TimeInForce::try_from(OrderType::FAK).unwrap(), TimeInForce::Fak);
+        assert!(matches!(
+            TimeInForce::try_from(OrderType::GTD),
+            Err(OrderBuilderError::InvalidExpiration(_))
+        ));
     }
 
     #[test]
@@ -1,41 +1,57 @@
 //! API payload builders for order submission
 
-use super::types::SignedOrder;
-use super::super::types::OrderType;
+use super::super::constants::MAX_BATCH_ORDERS;
+use super::types::{OrderBuilderError, Result, SignedOrder};
 
 /// Build the JSON payload for placing an order
 ///
 /// Matches official rs-clob-client format:
 /// {"order": {...}, "orderType": "...", "owner": "..."}
-pub fn build_order_payload(
-    signed_order: &SignedOrder,
-    owner: &str,
-    order_type: OrderType,
-) -> serde_json::Value {
+/// with `"postOnly": true` appended for post-only orders
+pub fn build_order_payload(signed_order: &SignedOrder, owner: &str) -> serde_json::Value {
     // Match official rs-clob-client field order (no deferExec)
     let mut map = serde_json::Map::new();
     map.insert("order".to_string(), signed_order.to_api_json());
-    map.insert("orderType".to_string(), serde_json::Value::String(order_type.as_str().to_string()));
+    map.insert("orderType".to_string(), serde_json::Value::String(signed_order.order_type.as_str().to_string()));
     map.insert("owner".to_string(), serde_json::Value::String(owner.to_string()));
+    if signed_order.post_only {
+        map.insert("postOnly".to_string(), serde_json::Value::Bool(true));
+    }
     serde_json::Value::Object(map)
 }
 
-/// Build the JSON payload for placing multiple orders
-pub fn build_batch_order_payload(
-    signed_orders: &[(SignedOrder, OrderType)],
-    owner: &str,
-) -> serde_json::Value {
-    let orders: Vec<serde_json::Value> = signed_orders
-        .iter()
-        .map(|(order, order_type)| {
-            // Match official rs-clob-client field order (no deferExec)
-            let mut map = serde_json::Map::new();
-            map.insert("order".to_string(), order.to_api_json());
-            map.insert("orderType".to_string(), serde_json::Value::String(order_type.as_str().to_string()));
-            map.insert("owner".to_string(), serde_json::Value::String(owner.to_string()));
-            serde_json::Value::Object(map)
-        })
-        .collect();
+/// Build the JSON payloads for placing multiple orders
+///
+/// The CLOB rejects batches that mix signature types or exchange domains, so
+/// every order must share the first order's `signatureType` and neg_risk flag.
+/// Inputs larger than `MAX_BATCH_ORDERS` are split into several payloads, each
+/// sent as its own request, preserving order.
+pub fn build_batch_order_payload(signed_orders: &[SignedOrder], owner: &str) -> Result<Vec<serde_json::Value>> {
+    if let Some(first) = signed_orders.first() {
+        for (i, order) in signed_orders.iter().enumerate().skip(1) {
+            if order.order.signature_type != first.order.signature_type {
+                return Err(OrderBuilderError::InconsistentBatch(format!(
+                    "order {} has signature type {}, expected {}",
+                    i, order.order.signature_type, first.order.signature_type
+                )));
+            }
+            if order.neg_risk != first.neg_risk {
+                return Err(OrderBuilderError::InconsistentBatch(format!(
+                    "order {} has neg_risk={}, expected {}",
+                    i, order.neg_risk, first.neg_risk
+                )));
+            }
+        }
+    }
 
-    serde_json::json!(orders)
+    Ok(signed_orders
+        .chunks(MAX_BATCH_ORDERS)
+        .map(|chunk| {
+            let orders: Vec<serde_json::Value> = chunk
+                .iter()
+                .map(|order| build_order_payload(order, owner))
+                .collect();
+            serde_json::json!(orders)
+        })
+        .collect())
 }
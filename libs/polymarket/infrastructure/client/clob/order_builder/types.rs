@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use super::super::constants::*;
+use super::super::types::OrderType;
 
 #[derive(Error, Debug)]
 pub enum OrderBuilderError {
@@ -23,10 +24,85 @@ pub enum OrderBuilderError {
 
     #[error("Serialization error: {0}")]
     SerializationError(String),
+
+    #[error("Invalid expiration: {0}")]
+    InvalidExpiration(String),
+
+    #[error("Inconsistent batch: {0}")]
+    InconsistentBatch(String),
+
+    #[error("Invalid post-only order: {0}")]
+    InvalidPostOnly(String),
 }
 
 pub type Result<T> = std::result::Result<T, OrderBuilderError>;
 
+/// How long an order rests on the book, as the CLOB understands it
+///
+/// Only `Gtd` carries an expiration; every other variant signs with
+/// expiration 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeInForce {
+    /// Good till cancelled
+    #[default]
+    Gtc,
+    /// Good till `expiration` (unix seconds)
+    Gtd { expiration: u64 },
+    /// Fill the whole size immediately or cancel
+    Fok,
+    /// Fill what's available immediately, cancel the rest
+    Fak,
+}
+
+impl TimeInForce {
+    /// The `orderType` sent with the order
+    pub fn order_type(&self) -> OrderType {
+        match self {
+            TimeInForce::Gtc => OrderType::GTC,
+            TimeInForce::Gtd { .. } => OrderType::GTD,
+            TimeInForce::Fok => OrderType::FOK,
+            TimeInForce::Fak => OrderType::FAK,
+        }
+    }
+
+    /// Expiration timestamp to sign (0 = no expiration)
+    pub fn expiration(&self) -> u64 {
+        match self {
+            TimeInForce::Gtd { expiration } => *expiration,
+            TimeInForce::Gtc | TimeInForce::Fok | TimeInForce::Fak => 0,
+        }
+    }
+
+    /// Check the expiration against the current unix time
+    pub fn validate(&self, now: u64) -> Result<()> {
+        match self {
+            TimeInForce::Gtd { expiration } if *expiration <= now => {
+                Err(OrderBuilderError::InvalidExpiration(format!(
+                    "GTD expiration {} is not in the future (now: {})",
+                    expiration, now
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl TryFrom<OrderType> for TimeInForce {
+    type Error = OrderBuilderError;
+
+    /// GTD has no expiration to take from an `OrderType`; build it directly
+    fn try_from(order_type: OrderType) -> Result<Self> {
+        match order_type {
+            OrderType::GTC => Ok(TimeInForce::Gtc),
+            OrderType::FOK => Ok(TimeInForce::Fok),
+            OrderType::FAK => Ok(TimeInForce::Fak),
+            OrderType::GTD => Err(OrderBuilderError::InvalidExpiration(
+                "GTD orders need an expiration".to_string(),
+            )),
+        }
+    }
+}
+
 /// CTF Exchange Order matching the on-chain EIP-712 struct
 ///
 /// Field order and types must match exactly:
@@ -79,9 +155,31 @@ pub struct Order {
 pub struct SignedOrder {
     pub order: Order,
     pub signature: String,
+    /// Sent as `orderType` alongside the order
+    pub order_type: OrderType,
+    /// Whether it was signed for the neg_risk exchange domain
+    pub neg_risk: bool,
+    /// Sent as `postOnly`: the exchange rejects the order instead of
+    /// letting it take liquidity
+    pub post_only: bool,
 }
 
 impl SignedOrder {
+    /// Mark the order post-only (maker only)
+    ///
+    /// Only resting orders (GTC/GTD) can be post-only; FOK and FAK exist to
+    /// take, so combining them is rejected.
+    pub fn with_post_only(mut self, post_only: bool) -> Result<Self> {
+        if post_only && matches!(self.order_type, OrderType::FOK | OrderType::FAK) {
+            return Err(OrderBuilderError::InvalidPostOnly(format!(
+                "{} orders cannot be post-only",
+                self.order_type.as_str()
+            )));
+        }
+        self.post_only = post_only;
+        Ok(self)
+    }
+
     /// Convert to JSON-serializable format for API
     ///
     /// Field formats match Polymarket API expectations (from py_order_utils):
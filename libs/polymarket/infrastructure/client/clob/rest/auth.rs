@@ -4,26 +4,29 @@ use ethers::types::Address;
 use super::super::super::auth::PolymarketAuth;
 use super::super::helpers::{extract_api_error, parse_json, with_headers};
 use super::super::types::*;
-use super::{RestClient, Result, RestError};
+use super::{EndpointClass, RestClient, Result, RestError};
 use tracing::debug;
 
 impl RestClient {
     /// Create or derive API key credentials (L2 auth)
     pub async fn create_api_key(&self, auth: &PolymarketAuth) -> Result<ApiCredentials> {
         let url = format!("{}/auth/api-key", self.base_url);
-        let timestamp = PolymarketAuth::current_timestamp();
+        let timestamp = self.timestamp();
 
         debug!("Creating API key");
 
         let headers = auth.l1_headers(timestamp, 0).await?;
         let req = with_headers(self.client().post(&url), headers);
-        let response = req.send().await?;
+        self.rate_limited(EndpointClass::Read, async move {
+            let response = req.send().await?;
 
-        if !response.status().is_success() {
-            return Err(extract_api_error(response, "Failed to create API key").await);
-        }
+            if !response.status().is_success() {
+                return Err(extract_api_error(response, "Failed to create API key").await);
+            }
 
-        parse_json(response).await
+            parse_json(response).await
+        })
+        .await
     }
 
     /// Get API credentials (either derive from existing or create new)
@@ -41,39 +44,48 @@ impl RestClient {
     /// Derive API key (deterministic from private key)
     pub async fn derive_api_key(&self, auth: &PolymarketAuth) -> Result<ApiCredentials> {
         let url = format!("{}/auth/derive-api-key", self.base_url);
-        let timestamp = PolymarketAuth::current_timestamp();
 
         debug!("Deriving API key");
 
-        let headers = auth.l1_headers(timestamp, 0).await?;
-        let req = with_headers(self.client().get(&url), headers);
-        let response = req.send().await?;
+        let url = &url;
+        self.get_with_retry("derive_api_key", |client| async move {
+            let timestamp = self.timestamp();
+            let headers = auth.l1_headers(timestamp, 0).await?;
+            let response = with_headers(client.get(url), headers).send().await?;
 
-        if !response.status().is_success() {
-            return Err(extract_api_error(response, "Failed to derive API key").await);
-        }
+            if !response.status().is_success() {
+                return Err(extract_api_error(response, "Failed to derive API key").await);
+            }
 
-        parse_json(response).await
+            parse_json(response).await
+        })
+        .await
     }
 
     /// Get maker's current nonce from the exchange
-    pub async fn get_nonce(&self, auth: &PolymarketAuth) -> Result<u64> {
-        let maker = format!("{:?}", auth.address());
+    ///
+    /// Use maker_address (proxy wallet when set) - nonces are tracked per maker
+    pub async fn get_nonce(&self, auth: &PolymarketAuth, maker: Address) -> Result<u64> {
+        let maker = format!("{:?}", maker);
         let path = format!("/nonce?maker={}", maker);
         let url = format!("{}{}", self.base_url, path);
-        let timestamp = PolymarketAuth::current_timestamp();
 
         debug!("Fetching nonce for maker {}", maker);
 
-        let headers = auth.l2_headers(timestamp, "GET", &path, "")?;
-        let req = with_headers(self.client().get(&url), headers);
-        let response = req.send().await?;
+        let (url, path) = (&url, &path);
+        let nonce_resp: NonceResponse = self
+            .get_with_retry("get_nonce", |client| async move {
+                let timestamp = self.timestamp();
+                let headers = auth.l2_headers(timestamp, "GET", path, "")?;
+                let response = with_headers(client.get(url), headers).send().await?;
 
-        if !response.status().is_success() {
-            return Err(extract_api_error(response, "Failed to fetch nonce").await);
-        }
+                if !response.status().is_success() {
+                    return Err(extract_api_error(response, "Failed to fetch nonce").await);
+                }
 
-        let nonce_resp: NonceResponse = parse_json(response).await?;
+                parse_json(response).await
+            })
+            .await?;
         nonce_resp
             .nonce
             .parse()
@@ -94,12 +106,16 @@ impl RestClient {
         debug!("Fetching positions from: {}", url);
 
         // Data API doesn't require authentication - just GET with address
-        let response = self.client().get(&url).send().await?;
+        let url = &url;
+        self.get_with_retry("get_positions", |client| async move {
+            let response = client.get(url).send().await?;
 
-        if !response.status().is_success() {
-            return Err(extract_api_error(response, "Failed to fetch positions").await);
-        }
+            if !response.status().is_success() {
+                return Err(extract_api_error(response, "Failed to fetch positions").await);
+            }
 
-        parse_json(response).await
+            parse_json(response).await
+        })
+        .await
     }
 }
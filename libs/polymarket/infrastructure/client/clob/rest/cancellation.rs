@@ -2,8 +2,9 @@
 //! Uses dedicated OS threads to isolate HTTP requests from tokio runtime
 
 use super::super::super::auth::PolymarketAuth;
+use super::super::helpers::ureq_error;
 use super::super::types::CancelResponse;
-use super::{RestClient, RestError, Result};
+use super::{EndpointClass, RestClient, RestError, Result};
 use serde_json::json;
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
@@ -17,7 +18,7 @@ impl RestClient {
         order_id: &str,
     ) -> Result<CancelResponse> {
         let url = format!("{}/order", self.base_url);
-        let timestamp = PolymarketAuth::current_timestamp();
+        let timestamp = self.timestamp();
 
         debug!("🗑️ Canceling order {}", order_id);
 
@@ -44,7 +45,7 @@ impl RestClient {
         }
 
         let url = format!("{}/orders", self.base_url);
-        let timestamp = PolymarketAuth::current_timestamp();
+        let timestamp = self.timestamp();
 
         debug!("🗑️ Canceling {} orders", order_ids.len());
 
@@ -59,7 +60,7 @@ impl RestClient {
     /// Cancel all open orders using dedicated thread
     pub async fn cancel_all_orders(&self, auth: &PolymarketAuth) -> Result<CancelResponse> {
         let url = format!("{}/cancel-all", self.base_url);
-        let timestamp = PolymarketAuth::current_timestamp();
+        let timestamp = self.timestamp();
 
         debug!("🗑️ Canceling all orders");
 
@@ -76,7 +77,7 @@ impl RestClient {
         asset_id: Option<&str>,
     ) -> Result<CancelResponse> {
         let url = format!("{}/cancel-market-orders", self.base_url);
-        let timestamp = PolymarketAuth::current_timestamp();
+        let timestamp = self.timestamp();
 
         debug!("Canceling market orders: market={:?}, asset_id={:?}", market, asset_id);
 
@@ -99,69 +100,72 @@ impl RestClient {
         headers: HashMap<String, String>,
         body: String,
     ) -> Result<CancelResponse> {
-        let start = Instant::now();
-
-        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.rate_limited(EndpointClass::Order, async move {
+            let start = Instant::now();
+
+            let (tx, rx) = tokio::sync::oneshot::channel();
+
+            std::thread::spawn(move || {
+                debug!("⏳ [Cancel thread] Starting DELETE request to {}", url);
+                let thread_start = Instant::now();
+
+                let result = (|| -> Result<CancelResponse> {
+                    let mut request = ureq::request("DELETE", &url)
+                        .set("Content-Type", "application/json")
+                        .set("User-Agent", "rs_clob_client")
+                        .set("Accept", "*/*");
+
+                    for (key, value) in &headers {
+                        request = request.set(key, value);
+                    }
+
+                    let response = if body.is_empty() {
+                        request
+                            .timeout(Duration::from_secs(15))
+                            .call()
+                            .map_err(|e| ureq_error(e, "DELETE request failed"))?
+                    } else {
+                        request
+                            .timeout(Duration::from_secs(15))
+                            .send_string(&body)
+                            .map_err(|e| ureq_error(e, "DELETE request failed"))?
+                    };
+
+                    let status = response.status();
+                    let response_body = response.into_string()
+                        .map_err(|e| RestError::ApiError(format!("Failed to read response: {}", e)))?;
+
+                    debug!("📥 [Cancel thread] Got response: status={}, body_len={}", status, response_body.len());
+
+                    if status == 200 || status == 201 {
+                        serde_json::from_str(&response_body)
+                            .map_err(|e| RestError::ApiError(format!("Failed to parse response: {} - body: {}", e, response_body)))
+                    } else {
+                        Err(RestError::ApiError(format!("Cancel failed with status {}: {}", status, response_body)))
+                    }
+                })();
+
+                debug!("📥 [Cancel thread] DELETE completed in {:?}", thread_start.elapsed());
+
+                let _ = tx.send(result);
+            });
 
-        std::thread::spawn(move || {
-            debug!("⏳ [Cancel thread] Starting DELETE request to {}", url);
-            let thread_start = Instant::now();
+            let result = rx.await
+                .map_err(|_| RestError::ApiError("Cancel thread channel closed".to_string()))?;
 
-            let result = (|| -> std::result::Result<CancelResponse, String> {
-                let mut request = ureq::request("DELETE", &url)
-                    .set("Content-Type", "application/json")
-                    .set("User-Agent", "rs_clob_client")
-                    .set("Accept", "*/*");
+            let elapsed = start.elapsed();
 
-                for (key, value) in &headers {
-                    request = request.set(key, value);
+            match result {
+                Ok(response) => {
+                    debug!("✅ Cancel request successful in {:?}", elapsed);
+                    Ok(response)
                 }
-
-                let response = if body.is_empty() {
-                    request
-                        .timeout(Duration::from_secs(15))
-                        .call()
-                        .map_err(|e| format!("DELETE request failed: {}", e))?
-                } else {
-                    request
-                        .timeout(Duration::from_secs(15))
-                        .send_string(&body)
-                        .map_err(|e| format!("DELETE request failed: {}", e))?
-                };
-
-                let status = response.status();
-                let response_body = response.into_string()
-                    .map_err(|e| format!("Failed to read response: {}", e))?;
-
-                debug!("📥 [Cancel thread] Got response: status={}, body_len={}", status, response_body.len());
-
-                if status == 200 || status == 201 {
-                    serde_json::from_str(&response_body)
-                        .map_err(|e| format!("Failed to parse response: {} - body: {}", e, response_body))
-                } else {
-                    Err(format!("Cancel failed with status {}: {}", status, response_body))
+                Err(e) => {
+                    error!("❌ Cancel request failed after {:?}: {}", elapsed, e);
+                    Err(e)
                 }
-            })();
-
-            debug!("📥 [Cancel thread] DELETE completed in {:?}", thread_start.elapsed());
-
-            let _ = tx.send(result);
-        });
-
-        let result = rx.await
-            .map_err(|_| RestError::ApiError("Cancel thread channel closed".to_string()))?;
-
-        let elapsed = start.elapsed();
-
-        match result {
-            Ok(response) => {
-                debug!("✅ Cancel request successful in {:?}", elapsed);
-                Ok(response)
             }
-            Err(e) => {
-                error!("❌ Cancel request failed after {:?}: {}", elapsed, e);
-                Err(RestError::ApiError(e))
-            }
-        }
+        })
+        .await
     }
 }
@@ -4,17 +4,32 @@
 //! - `orders`: Order placement methods
 //! - `auth`: API key management
 //! - `cancellation`: Order cancellation methods
+//! - `retry`: Retry policy for idempotent GETs
+//! - `rate_limit`: Token-bucket rate limits per endpoint class
+//! - `time_sync`: Clock skew check against the exchange `/time`
 
 mod auth;
 mod cancellation;
 mod orders;
 mod queries;
+mod rate_limit;
+mod retry;
+mod time_sync;
+
+pub use rate_limit::{EndpointClass, RateLimiter, DEFAULT_RETRY_AFTER};
+pub(crate) use rate_limit::parse_retry_after;
+pub use retry::RetryPolicy;
+pub use time_sync::{ClockSkew, ClockSyncConfig};
 
 use super::helpers::{parse_json, require_success};
 use super::types::*;
+use crate::infrastructure::database::OrderLog;
 use parking_lot::RwLock;
 use reqwest::Client;
+use std::collections::HashMap;
 use std::error::Error as StdError;
+use std::sync::atomic::AtomicI64;
+use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
 use tracing::{debug, info, warn};
@@ -69,9 +84,48 @@ fn describe_reqwest_error(err: &reqwest::Error) -> String {
     }
 }
 
+/// Connection pool tuning for the CLOB HTTP client
+///
+/// `None` keeps the reqwest default. Keeping idle connections warm avoids
+/// fresh TLS handshakes during bursts of order placements.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct HttpPoolConfig {
+    /// Maximum idle connections kept per host
+    pub max_idle_per_host: Option<usize>,
+    /// How long an idle connection is kept before being closed
+    pub idle_timeout: Option<Duration>,
+}
+
+impl HttpPoolConfig {
+    pub fn with_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.max_idle_per_host = Some(max_idle);
+        self
+    }
+
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Build from environment variables, unset ones keeping the default:
+    /// - `HTTP_POOL_MAX_IDLE_PER_HOST`: idle connections kept per host
+    /// - `HTTP_POOL_IDLE_TIMEOUT_SECS`: seconds an idle connection is kept
+    pub fn from_env() -> Self {
+        let env_u64 = |key: &str| {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+        Self {
+            max_idle_per_host: env_u64("HTTP_POOL_MAX_IDLE_PER_HOST").map(|n| n as usize),
+            idle_timeout: env_u64("HTTP_POOL_IDLE_TIMEOUT_SECS").map(Duration::from_secs),
+        }
+    }
+}
+
 /// Build HTTP client matching official rs-clob-client exactly
 /// The official client uses minimal settings with NO custom timeouts
-fn build_http_client() -> Client {
+fn build_http_client(pool: &HttpPoolConfig) -> Client {
     use reqwest::header;
 
     let mut headers = header::HeaderMap::new();
@@ -94,10 +148,14 @@ fn build_http_client() -> Client {
     );
 
     // Match official client: NO custom timeouts, use reqwest defaults
-    Client::builder()
-        .default_headers(headers)
-        .build()
-        .expect("Failed to build HTTP client")
+    let mut builder = Client::builder().default_headers(headers);
+    if let Some(max_idle) = pool.max_idle_per_host {
+        builder = builder.pool_max_idle_per_host(max_idle);
+    }
+    if let Some(timeout) = pool.idle_timeout {
+        builder = builder.pool_idle_timeout(timeout);
+    }
+    builder.build().expect("Failed to build HTTP client")
 }
 
 #[derive(Error, Debug)]
@@ -108,11 +166,63 @@ pub enum RestError {
     #[error("API error: {0}")]
     ApiError(String),
 
+    #[error("API error ({status}): {message}")]
+    HttpStatus { status: u16, message: String },
+
+    #[error("Rate limited, retry after {retry_after:?}")]
+    RateLimited { retry_after: Duration },
+
+    #[error("Clock skew of {offset_secs}s against the exchange exceeds {max_secs}s")]
+    ClockSkew { offset_secs: i64, max_secs: u64 },
+
     #[error("Authentication failed: {0}")]
     AuthFailed(#[from] super::super::auth::AuthError),
 
     #[error("Deserialization failed: {0}")]
     DeserializeFailed(String),
+
+    /// A multi-request batch failed part way; `placed` are the orders
+    /// that reached the book before `source` and are live
+    #[error("Batch failed after {} orders were placed: {source}", .placed.len())]
+    PartialBatch {
+        placed: Vec<OrderPlacementResponse>,
+        #[source]
+        source: Box<RestError>,
+    },
+}
+
+impl RestError {
+    /// HTTP status of a non-success response
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            RestError::HttpStatus { status, .. } => Some(*status),
+            RestError::RateLimited { .. } => Some(429),
+            _ => None,
+        }
+    }
+
+    /// The request never got a response (connection failure or timeout)
+    pub fn is_connect_or_timeout(&self) -> bool {
+        matches!(self, RestError::RequestFailed(e) if e.is_connect() || e.is_timeout())
+    }
+
+    /// The exchange refused a post-only order because it would have taken
+    /// liquidity. Expected in a fast book: the quote is simply not placed.
+    pub fn is_post_only_rejection(&self) -> bool {
+        match self {
+            RestError::ApiError(message) | RestError::HttpStatus { message, .. } => {
+                is_post_only_rejection_message(message)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Whether an exchange error message is a post-only rejection
+/// ("invalid post-only order: order crosses book")
+pub fn is_post_only_rejection_message(message: &str) -> bool {
+    let message = message.to_ascii_lowercase();
+    message.contains("post-only") || message.contains("post only")
 }
 
 pub type Result<T> = std::result::Result<T, RestError>;
@@ -120,16 +230,81 @@ pub type Result<T> = std::result::Result<T, RestError>;
 /// REST API client for Polymarket CLOB
 ///
 /// Uses a persistent HTTP connection with auto-recreation on failure.
+/// Read-only GETs are retried per the `RetryPolicy`; order placement and
+/// cancellation are always sent once. Both go through a per-class
+/// `RateLimiter`.
 pub struct RestClient {
     pub(crate) base_url: String,
     client: RwLock<Client>,
+    pool: HttpPoolConfig,
+    retry_policy: RetryPolicy,
+    read_limiter: RateLimiter,
+    order_limiter: RateLimiter,
+    order_log: Option<Arc<dyn OrderLog>>,
+    /// Seconds added to local time for signed timestamps (see `timestamp`)
+    clock_offset_secs: AtomicI64,
 }
 
 impl RestClient {
     pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_pool_config(base_url, HttpPoolConfig::default())
+    }
+
+    /// Create a client with custom connection pool settings
+    ///
+    /// The settings are kept across `recreate_client`.
+    pub fn with_pool_config(base_url: impl Into<String>, pool: HttpPoolConfig) -> Self {
         Self {
             base_url: base_url.into(),
-            client: RwLock::new(build_http_client()),
+            client: RwLock::new(build_http_client(&pool)),
+            pool,
+            retry_policy: RetryPolicy::default(),
+            read_limiter: RateLimiter::unlimited(),
+            order_limiter: RateLimiter::unlimited(),
+            order_log: None,
+            clock_offset_secs: AtomicI64::new(0),
+        }
+    }
+
+    /// Set how GET requests are retried (never applied to orders)
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Rate limit an endpoint class (unlimited by default)
+    pub fn with_rate_limiter(mut self, class: EndpointClass, limiter: RateLimiter) -> Self {
+        match class {
+            EndpointClass::Read => self.read_limiter = limiter,
+            EndpointClass::Order => self.order_limiter = limiter,
+        }
+        self
+    }
+
+    /// Record every order placement (and its result) in `order_log`
+    ///
+    /// Writes happen on a background task so a slow database never delays
+    /// placement; failed writes are logged and dropped.
+    pub fn with_order_log(mut self, order_log: Arc<dyn OrderLog>) -> Self {
+        self.order_log = Some(order_log);
+        self
+    }
+
+    /// Connection pool settings the HTTP client is built with
+    pub fn pool_config(&self) -> HttpPoolConfig {
+        self.pool
+    }
+
+    /// Retry policy applied to GET requests
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Rate limiter applied to an endpoint class
+    pub fn rate_limiter(&self, class: EndpointClass) -> &RateLimiter {
+        match class {
+            EndpointClass::Read => &self.read_limiter,
+            EndpointClass::Order => &self.order_limiter,
         }
     }
 
@@ -141,7 +316,7 @@ impl RestClient {
     /// Recreate the HTTP client (forces new DNS resolution and connection)
     pub fn recreate_client(&self) {
         info!("[RestClient] Recreating HTTP client to force fresh connection");
-        let new_client = build_http_client();
+        let new_client = build_http_client(&self.pool);
         *self.client.write() = new_client;
         info!("[RestClient] HTTP client recreated successfully");
     }
@@ -202,10 +377,14 @@ impl RestClient {
 
         debug!("Fetching markets from {}", url);
 
-        let response = self.client().get(&url).send().await?;
-        let response = require_success(response, "Failed to fetch markets").await?;
-
-        let simplified: Vec<SimplifiedMarket> = parse_json(response).await?;
+        let url = &url;
+        let simplified: Vec<SimplifiedMarket> = self
+            .get_with_retry("get_markets", |client| async move {
+                let response = client.get(url).send().await?;
+                let response = require_success(response, "Failed to fetch markets").await?;
+                parse_json(response).await
+            })
+            .await?;
 
         // Convert to Market structs
         let mut markets = Vec::new();
@@ -229,10 +408,14 @@ impl RestClient {
 
         debug!("Fetching market {} from {}", condition_id, url);
 
-        let response = self.client().get(&url).send().await?;
-        let response = require_success(response, "Failed to fetch market").await?;
-
-        let simplified: SimplifiedMarket = parse_json(response).await?;
+        let url = &url;
+        let simplified: SimplifiedMarket = self
+            .get_with_retry("get_market", |client| async move {
+                let response = client.get(url).send().await?;
+                let response = require_success(response, "Failed to fetch market").await?;
+                parse_json(response).await
+            })
+            .await?;
 
         simplified
             .into_market()
@@ -245,10 +428,59 @@ impl RestClient {
 
         debug!("Fetching orderbook for token {} from {}", token_id, url);
 
-        let response = self.client().get(&url).send().await?;
-        let response = require_success(response, "Failed to fetch orderbook").await?;
+        let url = &url;
+        self.get_with_retry("get_orderbook", |client| async move {
+            let response = client.get(url).send().await?;
+            let response = require_success(response, "Failed to fetch orderbook").await?;
+            parse_json(response).await
+        })
+        .await
+    }
+
+    /// Get orderbooks for several tokens in one request
+    ///
+    /// Books come back in `token_ids` order. A token the exchange returned no
+    /// book for gets `OrderBook::empty`; entries that fail to parse are
+    /// skipped with a warning, like `get_markets`.
+    pub async fn get_orderbooks(&self, token_ids: &[&str]) -> Result<Vec<OrderBook>> {
+        if token_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let url = format!("{}/books", self.base_url);
+        let body: Vec<serde_json::Value> = token_ids
+            .iter()
+            .map(|id| serde_json::json!({ "token_id": id }))
+            .collect();
+
+        debug!("Fetching {} orderbooks from {}", token_ids.len(), url);
+
+        let (url, body) = (&url, &body);
+        let entries: Vec<serde_json::Value> = self
+            .get_with_retry("get_orderbooks", |client| async move {
+                let response = client.post(url).json(body).send().await?;
+                let response = require_success(response, "Failed to fetch orderbooks").await?;
+                parse_json(response).await
+            })
+            .await?;
 
-        parse_json(response).await
+        let mut books: HashMap<String, OrderBook> = HashMap::with_capacity(entries.len());
+        for entry in entries {
+            match serde_json::from_value::<OrderBook>(entry) {
+                Ok(book) => {
+                    books.insert(book.asset_id.clone(), book);
+                }
+                Err(e) => {
+                    warn!("Failed to parse orderbook: {}", e);
+                    continue;
+                }
+            }
+        }
+
+        Ok(token_ids
+            .iter()
+            .map(|id| books.get(*id).cloned().unwrap_or_else(|| OrderBook::empty(*id)))
+            .collect())
     }
 
     /// Get neg_risk status for a token (affects EIP-712 domain for signing)
@@ -257,15 +489,18 @@ impl RestClient {
 
         debug!("Fetching neg_risk for token {}", token_id);
 
-        let response = self
-            .client()
-            .get(&url)
-            .timeout(Duration::from_secs(5))
-            .send()
+        let url = &url;
+        let neg_risk_resp: NegRiskResponse = self
+            .get_with_retry("get_neg_risk", |client| async move {
+                let response = client
+                    .get(url)
+                    .timeout(Duration::from_secs(5))
+                    .send()
+                    .await?;
+                let response = require_success(response, "Failed to fetch neg_risk").await?;
+                parse_json(response).await
+            })
             .await?;
-        let response = require_success(response, "Failed to fetch neg_risk").await?;
-
-        let neg_risk_resp: NegRiskResponse = parse_json(response).await?;
         Ok(neg_risk_resp.neg_risk)
     }
 }
@@ -273,10 +508,480 @@ impl RestClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::io::Write;
+    use crate::infrastructure::backoff::BackoffPolicy;
+    use crate::infrastructure::client::auth::PolymarketAuth;
+    use crate::infrastructure::client::clob::order_builder::{OrderBuilder, SignedOrder, TimeInForce};
+    use crate::infrastructure::database::{MarketDatabase, OrderLogEntry};
+    use crate::mock_clob::{MockClobServer, MockResponse};
+    use hyper::Method;
+    use serde_json::json;
+    use std::sync::Arc;
+    use std::time::Instant;
 
     #[test]
     fn test_client_creation() {
         let client = RestClient::new("https://clob.polymarket.com");
         assert_eq!(client.base_url, "https://clob.polymarket.com");
     }
+
+    fn unavailable() -> MockResponse {
+        MockResponse::text(503, "unavailable")
+    }
+
+    /// Mock server answering the first `failures` neg-risk lookups with 503,
+    /// then `{"neg_risk": true}`
+    async fn flaky_server(failures: usize) -> MockClobServer {
+        let server = MockClobServer::start().await;
+        server.set_neg_risk("1", true);
+        server.respond_sequence(Method::GET, "/neg-risk", vec![unavailable(); failures]);
+        server
+    }
+
+    fn retry_on_5xx() -> RetryPolicy {
+        RetryPolicy::default()
+            .with_base_delay(Duration::from_millis(1))
+            .with_retry_on(|e| e.is_connect_or_timeout() || e.status().is_some_and(|s| s >= 500))
+    }
+
+    #[tokio::test]
+    async fn test_get_retries_until_success() {
+        let server = flaky_server(2).await;
+        let client = RestClient::new(server.url()).with_retry_policy(retry_on_5xx());
+
+        assert!(client.get_neg_risk("1").await.unwrap());
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_get_gives_up_after_max_retries() {
+        let server = flaky_server(3).await;
+        let client = RestClient::new(server.url()).with_retry_policy(retry_on_5xx().with_max_retries(2));
+
+        let err = client.get_neg_risk("1").await.unwrap_err();
+        assert_eq!(err.status(), Some(503));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_default_policy_only_retries_connection_errors() {
+        let server = flaky_server(2).await;
+        let client = RestClient::new(server.url());
+
+        let err = client.get_neg_risk("1").await.unwrap_err();
+        assert_eq!(err.status(), Some(503));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_retry_policy_builders_set_backoff() {
+        let policy = RetryPolicy::default()
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_retries(5);
+        assert_eq!(policy.backoff, BackoffPolicy::new(5, Duration::from_millis(100)));
+        assert_eq!(RetryPolicy::none().backoff.max_retries, 0);
+    }
+
+    fn test_auth() -> PolymarketAuth {
+        PolymarketAuth::from_api_credentials(ApiCredentials {
+            key: "key".to_string(),
+            secret: "c2VjcmV0".to_string(),
+            passphrase: "pass".to_string(),
+        })
+    }
+
+    fn test_order_args() -> OrderArgs {
+        OrderArgs {
+            token_id: "1".to_string(),
+            price: 0.5,
+            size: 10.0,
+            side: Side::Buy,
+            fee_rate_bps: None,
+            nonce: None,
+            expiration: None,
+            post_only: false,
+        }
+    }
+
+    #[test]
+    fn test_rate_limiter_bucket() {
+        let limiter = RateLimiter::new(2, 10.0);
+        assert!(limiter.try_acquire().is_none());
+        assert!(limiter.try_acquire().is_none());
+        let wait = limiter.try_acquire().unwrap();
+        assert!(wait > Duration::ZERO && wait <= Duration::from_millis(100));
+
+        limiter.pause(Duration::from_secs(5));
+        assert!(limiter.try_acquire().unwrap() > Duration::from_secs(4));
+
+        let unlimited = RateLimiter::unlimited();
+        for _ in 0..1000 {
+            assert!(unlimited.try_acquire().is_none());
+        }
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after(Some("2")), Duration::from_secs(2));
+        assert_eq!(parse_retry_after(Some("0.5")), Duration::from_millis(500));
+        assert_eq!(parse_retry_after(Some("Wed, 21 Oct 2015 07:28:00 GMT")), DEFAULT_RETRY_AFTER);
+        assert_eq!(parse_retry_after(None), DEFAULT_RETRY_AFTER);
+    }
+
+    #[tokio::test]
+    async fn test_order_limiter_spaces_out_orders() {
+        let server = MockClobServer::start().await;
+        let client = RestClient::new(server.url())
+            .with_rate_limiter(EndpointClass::Order, RateLimiter::new(1, 20.0));
+        let auth = test_auth();
+
+        let start = Instant::now();
+        for _ in 0..5 {
+            let response = client
+                .place_order(&auth, &test_order_args(), OrderType::GTC)
+                .await
+                .unwrap();
+            assert!(response.success);
+        }
+
+        // One permit up front, then one every 50ms
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert_eq!(server.requests().len(), 5);
+
+        // Reads have their own (unlimited) bucket
+        assert!(client.rate_limiter(EndpointClass::Read).try_acquire().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_429_surfaces_rate_limited_and_pauses_class() {
+        let server = MockClobServer::start().await;
+        server.set_neg_risk("1", true);
+        server.respond_sequence(
+            Method::GET,
+            "/neg-risk",
+            vec![MockResponse::text(429, "").with_header("Retry-After", "0.3")],
+        );
+        let client = RestClient::new(server.url());
+
+        let err = client.get_neg_risk("1").await.unwrap_err();
+        assert!(matches!(
+            err,
+            RestError::RateLimited { retry_after } if retry_after == Duration::from_millis(300)
+        ));
+        assert_eq!(err.status(), Some(429));
+
+        let start = Instant::now();
+        assert!(client.get_neg_risk("1").await.unwrap());
+        assert!(start.elapsed() >= Duration::from_millis(250));
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_429_on_cancel_surfaces_rate_limited() {
+        let server = MockClobServer::start().await;
+        server.respond(
+            Method::DELETE,
+            "/order",
+            MockResponse::text(429, "").with_header("Retry-After", "2"),
+        );
+        let client = RestClient::new(server.url());
+
+        let err = client.cancel_order(&test_auth(), "0x1").await.unwrap_err();
+        assert!(matches!(
+            err,
+            RestError::RateLimited { retry_after } if retry_after == Duration::from_secs(2)
+        ));
+        assert!(client.rate_limiter(EndpointClass::Order).try_acquire().is_some());
+    }
+
+    const TEST_PRIVATE_KEY: &str = "0x257091039adf0d3df1f3171508f7db838782ee9b4f6ad61054be773e7541d90a";
+
+    /// Order log keeping entries in memory
+    #[derive(Default)]
+    struct RecordingOrderLog(parking_lot::Mutex<Vec<OrderLogEntry>>);
+
+    #[async_trait::async_trait]
+    impl OrderLog for RecordingOrderLog {
+        async fn log_order(
+            &self,
+            entry: OrderLogEntry,
+        ) -> crate::infrastructure::database::Result<()> {
+            self.0.lock().push(entry);
+            Ok(())
+        }
+    }
+
+    /// Place one signed GTC buy against a server answering `live`
+    async fn place_logged_order(order_log: Arc<dyn OrderLog>) -> SignedOrder {
+        let server = MockClobServer::start().await;
+        server.respond(
+            Method::POST,
+            "/order",
+            MockResponse::json(200, json!({ "orderID": "0xabc", "success": true, "status": "live" })),
+        );
+        let client = RestClient::new(server.url()).with_order_log(order_log);
+
+        let mut auth = PolymarketAuth::new(TEST_PRIVATE_KEY, 137).unwrap();
+        auth.set_api_key(test_auth().api_key().unwrap().clone());
+        let signed = OrderBuilder::new_eoa(auth.address().unwrap(), 137, false)
+            .build_signed_order(&auth, "1234", 0.45, 10.0, Side::Buy, 0, None, TimeInForce::Gtc)
+            .unwrap();
+
+        client.submit_signed_order(&auth, &signed, 0).await.unwrap();
+        signed
+    }
+
+    #[tokio::test]
+    async fn test_signed_post_only_rejection_is_recognized() {
+        let server = MockClobServer::start().await;
+        server.respond(
+            Method::POST,
+            "/order",
+            MockResponse::json(400, json!({ "error": "invalid post-only order: order crosses book" })),
+        );
+        let client = RestClient::new(server.url());
+
+        let mut auth = PolymarketAuth::new(TEST_PRIVATE_KEY, 137).unwrap();
+        auth.set_api_key(test_auth().api_key().unwrap().clone());
+        let signed = OrderBuilder::new_eoa(auth.address().unwrap(), 137, false)
+            .build_signed_order(&auth, "1234", 0.55, 10.0, Side::Buy, 0, None, TimeInForce::Gtc)
+            .and_then(|signed| signed.with_post_only(true))
+            .unwrap();
+
+        let err = client.submit_signed_order(&auth, &signed, 0).await.unwrap_err();
+        assert!(err.is_post_only_rejection(), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_placed_order_is_written_to_order_log() {
+        let order_log = Arc::new(RecordingOrderLog::default());
+        let signed = place_logged_order(order_log.clone()).await;
+
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while order_log.0.lock().is_empty() && Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        let entries = order_log.0.lock();
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.token_id, "1234");
+        assert_eq!(entry.side, "BUY");
+        assert_eq!(entry.order_type, "GTC");
+        assert_eq!(entry.signature, signed.signature);
+        assert_eq!(entry.order_id.as_deref(), Some("0xabc"));
+        assert!(entry.success);
+        assert_eq!(entry.status.as_deref(), Some("live"));
+        assert_eq!(entry.error, None);
+
+        let params: serde_json::Value = serde_json::from_str(&entry.params).unwrap();
+        assert_eq!(params["tokenId"], "1234");
+        assert_eq!(params["makerAmount"], signed.order.maker_amount.to_string());
+        assert!(params.get("signature").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_order_is_written_to_order_log() {
+        let order_log = Arc::new(RecordingOrderLog::default());
+        let server = MockClobServer::start().await;
+        server.respond(
+            Method::POST,
+            "/order",
+            MockResponse::json(200, json!({ "orderID": "0xdef", "success": true })),
+        );
+        let client = RestClient::new(server.url()).with_order_log(order_log.clone());
+
+        client
+            .place_order(&test_auth(), &test_order_args(), OrderType::GTC)
+            .await
+            .unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while order_log.0.lock().is_empty() && Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        let entries = order_log.0.lock();
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.token_id, "1");
+        assert_eq!(entry.side, "BUY");
+        assert_eq!(entry.order_type, "GTC");
+        assert!(entry.signature.is_empty());
+        assert_eq!(entry.order_id.as_deref(), Some("0xdef"));
+        assert!(entry.success);
+
+        let params: serde_json::Value = serde_json::from_str(&entry.params).unwrap();
+        assert_eq!(params["price"], 0.5);
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires DATABASE_URL pointing at a Postgres instance
+    async fn test_placed_order_is_written_to_orders_log_table() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL not set");
+        let db = Arc::new(MarketDatabase::new(&url).await.unwrap());
+
+        let signed = place_logged_order(db.clone()).await;
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let logged = loop {
+            let recent = db.get_recent_orders(20).await.unwrap();
+            if let Some(row) = recent.into_iter().find(|row| row.signature == signed.signature) {
+                break row;
+            }
+            assert!(Instant::now() < deadline, "order was not logged");
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        };
+
+        assert_eq!(logged.token_id, "1234");
+        assert_eq!(logged.side, "BUY");
+        assert_eq!(logged.order_type, "GTC");
+        assert_eq!(logged.order_id.as_deref(), Some("0xabc"));
+        assert!(logged.success);
+        assert_eq!(logged.status.as_deref(), Some("live"));
+        assert!(chrono::DateTime::parse_from_rfc3339(&logged.placed_at).is_ok());
+
+        sqlx::query("DELETE FROM orders_log WHERE id = $1")
+            .bind(logged.id)
+            .execute(db.pool())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_get_orderbooks_preserves_input_order() {
+        let books = r#"[
+            {"market": "0xc1", "asset_id": "down", "bids": [{"price": "0.48", "size": "100"}], "asks": []},
+            {"market": "0xc1", "asset_id": "broken", "bids": "not-a-list", "asks": []},
+            {"market": "0xc1", "asset_id": "up", "bids": [], "asks": [{"price": "0.53", "size": "25"}]}
+        ]"#;
+        let server = MockClobServer::start().await;
+        server.respond(Method::POST, "/books", MockResponse::text(200, books));
+        let client = RestClient::new(server.url());
+
+        let result = client.get_orderbooks(&["up", "down", "missing"]).await.unwrap();
+
+        assert_eq!(server.requests().len(), 1);
+        let ids: Vec<&str> = result.iter().map(|b| b.asset_id.as_str()).collect();
+        assert_eq!(ids, ["up", "down", "missing"]);
+        assert_eq!(result[0].asks[0].price, "0.53");
+        assert_eq!(result[1].bids[0].size, "100");
+        assert!(result[2].is_empty());
+        assert!(client.get_orderbooks(&[]).await.unwrap().is_empty());
+    }
+
+    /// Log sink shared with a test subscriber
+    #[derive(Clone, Default)]
+    struct LogBuffer(Arc<parking_lot::Mutex<Vec<u8>>>);
+
+    impl Write for LogBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Mock server whose `/time` runs `skew_secs` ahead of the local clock
+    async fn skewed_time_server(skew_secs: i64) -> MockClobServer {
+        let server = MockClobServer::start().await;
+        let now = PolymarketAuth::current_timestamp() as i64;
+        server.respond(Method::GET, "/time", MockResponse::json(200, json!(now + skew_secs)));
+        server
+    }
+
+    #[tokio::test]
+    async fn test_clock_sync_warns_and_applies_offset() {
+        let logs = LogBuffer::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let server = skewed_time_server(120).await;
+        let client = RestClient::new(server.url());
+        let config = ClockSyncConfig::default()
+            .with_max_skew(None)
+            .with_apply_offset(true);
+
+        let skew = client.check_clock_sync(&config).await.unwrap();
+
+        assert!((119..=121).contains(&skew.offset_secs), "offset {}", skew.offset_secs);
+        assert!(skew.exceeds(config.warn_threshold));
+        assert_eq!(client.clock_offset(), skew.offset_secs);
+        let ahead = client.timestamp() as i64 - PolymarketAuth::current_timestamp() as i64;
+        assert!((119..=121).contains(&ahead), "ahead {}", ahead);
+
+        // The offset belongs to this client only
+        assert_eq!(RestClient::new("http://unused").clock_offset(), 0);
+
+        let output = String::from_utf8(logs.0.lock().clone()).unwrap();
+        assert!(output.contains("WARN"), "{}", output);
+        assert!(output.contains("behind the exchange clock"), "{}", output);
+    }
+
+    #[tokio::test]
+    async fn test_clock_sync_fails_past_max_skew() {
+        let server = skewed_time_server(-45).await;
+        let client = RestClient::new(server.url());
+
+        let err = client
+            .check_clock_sync(&ClockSyncConfig::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            RestError::ClockSkew { offset_secs, max_secs: 30 } if (-46..=-44).contains(&offset_secs)
+        ));
+
+        // Small skews pass without failing
+        let server = skewed_time_server(1).await;
+        let client = RestClient::new(server.url());
+        let skew = client.check_clock_sync(&ClockSyncConfig::default()).await.unwrap();
+        assert!(!skew.exceeds(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_pool_config_survives_recreate() {
+        let pool = HttpPoolConfig::default()
+            .with_max_idle_per_host(4)
+            .with_idle_timeout(Duration::from_secs(120));
+        let client = RestClient::with_pool_config("https://clob.polymarket.com", pool);
+        assert_eq!(client.pool_config(), pool);
+
+        client.recreate_client();
+        assert_eq!(client.pool_config(), pool);
+        assert_eq!(RestClient::new("x").pool_config(), HttpPoolConfig::default());
+    }
+
+    #[tokio::test]
+    async fn test_pool_settings_control_connection_reuse() {
+        // Warm pool: sequential requests share one connection
+        let server = MockClobServer::start().await;
+        let client = RestClient::with_pool_config(
+            server.url(),
+            HttpPoolConfig::default().with_max_idle_per_host(1),
+        );
+        for _ in 0..5 {
+            client.health_check().await.unwrap();
+        }
+        assert_eq!(server.connections(), 1);
+
+        // No idle connections allowed, also after recreation: one per request
+        let server = MockClobServer::start().await;
+        let client = RestClient::with_pool_config(
+            server.url(),
+            HttpPoolConfig::default().with_max_idle_per_host(0),
+        );
+        client.recreate_client();
+        for _ in 0..5 {
+            client.health_check().await.unwrap();
+        }
+        assert_eq!(server.connections(), 5);
+    }
 }
@@ -1,15 +1,123 @@
 //! Order placement methods for RestClient
 
 use super::super::super::auth::PolymarketAuth;
-use super::super::helpers::{extract_api_error, parse_json, with_headers};
-use super::super::order_builder::{build_batch_order_payload, build_order_payload, OrderBuilder, SignedOrder};
+use super::super::helpers::{extract_api_error, parse_json, ureq_error, with_headers};
+use super::super::order_builder::{build_batch_order_payload, build_order_payload, OrderBuilder, SignedOrder, TimeInForce};
 use super::super::types::*;
-use super::{RestClient, Result, RestError};
+use super::{EndpointClass, RestClient, Result, RestError};
+use super::super::constants::MAX_BATCH_ORDERS;
+use crate::infrastructure::database::OrderLogEntry;
 use serde_json::json;
 use std::time::Instant;
-use tracing::{debug, info, error};
+use tracing::{debug, info, error, warn};
+
+/// Audit log entry for one signed order and what the exchange answered
+fn order_log_entry(
+    signed_order: &SignedOrder,
+    result: std::result::Result<&OrderPlacementResponse, &RestError>,
+) -> OrderLogEntry {
+    let mut params = signed_order.to_api_json();
+    if let Some(fields) = params.as_object_mut() {
+        fields.remove("signature");
+    }
+    let side = params["side"].as_str().unwrap_or_default().to_string();
+
+    let (order_id, success, status, error) = match result {
+        Ok(response) => (
+            response.order_id.clone().filter(|id| !id.is_empty()),
+            response.success,
+            response.status.clone(),
+            response.error_msg.clone().filter(|msg| !msg.is_empty()),
+        ),
+        Err(e) => (None, false, None, Some(e.to_string())),
+    };
+
+    OrderLogEntry {
+        token_id: signed_order.order.token_id.to_string(),
+        side,
+        order_type: signed_order.order_type.as_str().to_string(),
+        params: params.to_string(),
+        signature: signed_order.signature.clone(),
+        order_id,
+        success,
+        status,
+        error,
+    }
+}
+
+/// Audit log entry for an unsigned order placed through `place_order`
+fn unsigned_order_log_entry(
+    order_args: &OrderArgs,
+    order_type: OrderType,
+    result: std::result::Result<&OrderResponse, &RestError>,
+) -> OrderLogEntry {
+    let params = serde_json::to_value(order_args).unwrap_or_default();
+    let side = params["side"].as_str().unwrap_or_default().to_string();
+
+    let (order_id, success, error) = match result {
+        Ok(response) => (
+            Some(response.order_id.clone()).filter(|id| !id.is_empty()),
+            response.success,
+            response.error_msg.clone().filter(|msg| !msg.is_empty()),
+        ),
+        Err(e) => (None, false, Some(e.to_string())),
+    };
+
+    OrderLogEntry {
+        token_id: order_args.token_id.clone(),
+        side,
+        order_type: order_type.as_str().to_string(),
+        params: params.to_string(),
+        signature: String::new(),
+        order_id,
+        success,
+        status: None,
+        error,
+    }
+}
 
 impl RestClient {
+    /// Hand placements to the order log, if one is configured
+    ///
+    /// On success `result` holds one response per order, in order.
+    fn record_placements(
+        &self,
+        signed_orders: &[SignedOrder],
+        result: std::result::Result<&[OrderPlacementResponse], &RestError>,
+    ) {
+        if self.order_log.is_none() {
+            return;
+        }
+
+        let entries: Vec<OrderLogEntry> = match result {
+            Ok(responses) => signed_orders
+                .iter()
+                .zip(responses)
+                .map(|(order, response)| order_log_entry(order, Ok(response)))
+                .collect(),
+            Err(e) => signed_orders
+                .iter()
+                .map(|order| order_log_entry(order, Err(e)))
+                .collect(),
+        };
+        self.write_order_log(entries);
+    }
+
+    /// Write `entries` to the order log on a background task
+    fn write_order_log(&self, entries: Vec<OrderLogEntry>) {
+        let Some(order_log) = self.order_log.clone() else {
+            return;
+        };
+
+        tokio::spawn(async move {
+            for entry in entries {
+                if let Err(e) = order_log.log_order(entry).await {
+                    warn!("[RestClient] Failed to write order log: {}", e);
+                }
+            }
+        });
+    }
+
     /// Place a limit order
     pub async fn place_order(
         &self,
@@ -18,14 +126,17 @@ impl RestClient {
         order_type: OrderType,
     ) -> Result<OrderResponse> {
         let url = format!("{}/order", self.base_url);
-        let timestamp = PolymarketAuth::current_timestamp();
+        let timestamp = self.timestamp();
 
         debug!("Placing {:?} order for token {}", order_type, order_args.token_id);
 
-        let body_json = json!({
+        let mut body_json = json!({
             "order": order_args,
             "orderType": order_type,
         });
+        if order_args.post_only {
+            body_json["postOnly"] = json!(true);
+        }
         let body = serde_json::to_string(&body_json)
             .map_err(|e| RestError::ApiError(e.to_string()))?;
 
@@ -34,13 +145,26 @@ impl RestClient {
             self.client().post(&url).header("Content-Type", "application/json"),
             headers,
         );
-        let response = req.body(body).send().await?;
+        let result = self
+            .rate_limited(EndpointClass::Order, async move {
+                let response = req.body(body).send().await?;
 
-        if !response.status().is_success() {
-            return Err(extract_api_error(response, "Failed to place order").await);
-        }
+                if !response.status().is_success() {
+                    return Err(extract_api_error(response, "Failed to place order").await);
+                }
+
+                parse_json(response).await
+            })
+            .await;
 
-        parse_json(response).await
+        if self.order_log.is_some() {
+            self.write_order_log(vec![unsigned_order_log_entry(
+                order_args,
+                order_type,
+                result.as_ref(),
+            )]);
+        }
+        result
     }
 
     /// Place a market order (buy/sell by amount)
@@ -86,12 +210,15 @@ impl RestClient {
             fee_rate_bps: None,
             nonce: None,
             expiration: None,
+            post_only: false,
         };
 
         self.place_order(auth, &order_args, order_type).await
     }
 
     /// Place a signed order using EIP-712 signing
+    ///
+    /// `nonce` must be the maker's current exchange nonce (see `get_nonce`).
     pub async fn place_signed_order(
         &self,
         auth: &PolymarketAuth,
@@ -102,20 +229,22 @@ impl RestClient {
         side: Side,
         order_type: OrderType,
         fee_rate_bps: Option<u64>,
+        nonce: u64,
     ) -> Result<OrderPlacementResponse> {
-        let timestamp = PolymarketAuth::current_timestamp();
-        let nonce = 0u64;
+        let timestamp = self.timestamp();
 
         debug!(
-            "Building signed order: token={}, price={}, size={}, side={:?}",
-            token_id, price, size, side
+            "Building signed order: token={}, price={}, size={}, side={:?}, nonce={}",
+            token_id, price, size, side, nonce
         );
 
-        let signed_order = order_builder
-            .build_signed_order(auth, token_id, price, size, side, nonce, fee_rate_bps, None)
+        let signed_order = TimeInForce::try_from(order_type)
+            .and_then(|tif| {
+                order_builder.build_signed_order(auth, token_id, price, size, side, nonce, fee_rate_bps, tif)
+            })
             .map_err(|e| RestError::ApiError(format!("Failed to build order: {}", e)))?;
 
-        self.submit_signed_order(auth, &signed_order, order_type, timestamp)
+        self.submit_signed_order(auth, &signed_order, timestamp)
             .await
     }
 
@@ -125,7 +254,6 @@ impl RestClient {
         &self,
         auth: &PolymarketAuth,
         signed_order: &SignedOrder,
-        order_type: OrderType,
         timestamp: u64,
     ) -> Result<OrderPlacementResponse> {
         let url = format!("{}/order", self.base_url);
@@ -134,7 +262,7 @@ impl RestClient {
             .api_key()
             .ok_or_else(|| RestError::ApiError("API key not set".to_string()))?;
 
-        let payload = build_order_payload(signed_order, &api_key.key, order_type);
+        let payload = build_order_payload(signed_order, &api_key.key);
         let body = serde_json::to_string(&payload)
             .map_err(|e| RestError::ApiError(format!("Failed to serialize order: {}", e)))?;
 
@@ -145,202 +273,242 @@ impl RestClient {
         debug!("   Body length: {} bytes", body.len());
         debug!("   Full body: {}", body);
 
-        let start = Instant::now();
+        let result = self.rate_limited(EndpointClass::Order, async move {
+            let start = Instant::now();
 
-        // Use a completely separate OS thread with oneshot channel
-        // This isolates the HTTP request from the tokio runtime entirely
-        let (tx, rx) = tokio::sync::oneshot::channel();
+            // Use a completely separate OS thread with oneshot channel
+            // This isolates the HTTP request from the tokio runtime entirely
+            let (tx, rx) = tokio::sync::oneshot::channel();
 
-        let headers_clone = headers.clone();
+            let headers_clone = headers.clone();
 
-        std::thread::spawn(move || {
-            debug!("⏳ [Dedicated thread] Starting HTTP request...");
-            let thread_start = Instant::now();
+            std::thread::spawn(move || {
+                debug!("⏳ [Dedicated thread] Starting HTTP request...");
+                let thread_start = Instant::now();
 
-            // Use ureq (blocking HTTP client) in dedicated thread
-            let result = (|| -> std::result::Result<OrderPlacementResponse, String> {
-                let mut request = ureq::post(&url)
-                    .set("Content-Type", "application/json")
-                    .set("User-Agent", "rs_clob_client")
-                    .set("Accept", "*/*");
+                // Use ureq (blocking HTTP client) in dedicated thread
+                let result = (|| -> Result<OrderPlacementResponse> {
+                    let mut request = ureq::post(&url)
+                        .set("Content-Type", "application/json")
+                        .set("User-Agent", "rs_clob_client")
+                        .set("Accept", "*/*");
 
-                for (key, value) in &headers_clone {
-                    request = request.set(key, value);
-                }
+                    for (key, value) in &headers_clone {
+                        request = request.set(key, value);
+                    }
 
-                debug!("⏳ [Dedicated thread] Sending request...");
+                    debug!("⏳ [Dedicated thread] Sending request...");
 
-                let response = request
-                    .timeout(std::time::Duration::from_secs(15))
-                    .send_string(&body)
-                    .map_err(|e| format!("HTTP request failed: {}", e))?;
+                    let response = request
+                        .timeout(std::time::Duration::from_secs(15))
+                        .send_string(&body)
+                        .map_err(|e| ureq_error(e, "HTTP request failed"))?;
 
-                let status = response.status();
-                let response_body = response.into_string()
-                    .map_err(|e| format!("Failed to read response: {}", e))?;
+                    let status = response.status();
+                    let response_body = response.into_string()
+                        .map_err(|e| RestError::ApiError(format!("Failed to read response: {}", e)))?;
 
-                debug!("📥 [Dedicated thread] Got response: status={}, body_len={}", status, response_body.len());
+                    debug!("📥 [Dedicated thread] Got response: status={}, body_len={}", status, response_body.len());
 
-                if status == 200 || status == 201 {
-                    serde_json::from_str(&response_body)
-                        .map_err(|e| format!("Failed to parse response: {} - body: {}", e, response_body))
-                } else {
-                    Err(format!("Order failed with status {}: {}", status, response_body))
-                }
-            })();
+                    if status == 200 || status == 201 {
+                        serde_json::from_str(&response_body)
+                            .map_err(|e| RestError::ApiError(format!("Failed to parse response: {} - body: {}", e, response_body)))
+                    } else {
+                        Err(RestError::ApiError(format!("Order failed with status {}: {}", status, response_body)))
+                    }
+                })();
 
-            debug!("📥 [Dedicated thread] HTTP completed in {:?}", thread_start.elapsed());
+                debug!("📥 [Dedicated thread] HTTP completed in {:?}", thread_start.elapsed());
 
-            // Send result back to async context
-            let _ = tx.send(result);
-        });
+                // Send result back to async context
+                let _ = tx.send(result);
+            });
 
-        // Wait for the dedicated thread to complete
-        let result = rx.await
-            .map_err(|_| RestError::ApiError("Thread channel closed".to_string()))?;
+            // Wait for the dedicated thread to complete
+            let result = rx.await
+                .map_err(|_| RestError::ApiError("Thread channel closed".to_string()))?;
 
-        let elapsed = start.elapsed();
+            let elapsed = start.elapsed();
 
-        match result {
-            Ok(response) => {
-                debug!("✅ Order request successful in {:?}", elapsed);
-                Ok(response)
-            }
-            Err(e) => {
-                error!("❌ Order request failed after {:?}: {}", elapsed, e);
-                Err(RestError::ApiError(e))
+            match result {
+                Ok(response) => {
+                    debug!("✅ Order request successful in {:?}", elapsed);
+                    Ok(response)
+                }
+                Err(e) => {
+                    error!("❌ Order request failed after {:?}: {}", elapsed, e);
+                    Err(e)
+                }
             }
-        }
+        })
+        .await;
+
+        self.record_placements(
+            std::slice::from_ref(signed_order),
+            result.as_ref().map(std::slice::from_ref),
+        );
+        result
     }
 
     /// Submit multiple pre-built signed orders to the exchange
+    ///
+    /// Orders beyond `MAX_BATCH_ORDERS` are sent as further batch requests;
+    /// responses come back in input order. If a later request fails, the
+    /// responses of the requests already placed are returned in
+    /// `RestError::PartialBatch` so the caller can track those live orders.
     pub async fn submit_batch_orders(
         &self,
         auth: &PolymarketAuth,
-        signed_orders: &[(SignedOrder, OrderType)],
+        signed_orders: &[SignedOrder],
         timestamp: u64,
     ) -> Result<Vec<OrderPlacementResponse>> {
-        let url = format!("{}/orders", self.base_url);
-
         let api_key = auth
             .api_key()
             .ok_or_else(|| RestError::ApiError("API key not set".to_string()))?;
 
-        let payload = build_batch_order_payload(signed_orders, &api_key.key);
-        let body = serde_json::to_string(&payload)
+        let payloads = build_batch_order_payload(signed_orders, &api_key.key)
+            .map_err(|e| RestError::ApiError(format!("Invalid batch: {}", e)))?;
+
+        let mut responses = Vec::with_capacity(signed_orders.len());
+        for (orders, payload) in signed_orders.chunks(MAX_BATCH_ORDERS).zip(&payloads) {
+            let result = self.submit_batch_payload(auth, payload, timestamp).await;
+            self.record_placements(orders, result.as_deref());
+            match result {
+                Ok(placed) => responses.extend(placed),
+                Err(e) if responses.is_empty() => return Err(e),
+                Err(e) => {
+                    return Err(RestError::PartialBatch {
+                        placed: responses,
+                        source: Box::new(e),
+                    })
+                }
+            }
+        }
+        Ok(responses)
+    }
+
+    /// Send one batch payload built by `build_batch_order_payload`
+    async fn submit_batch_payload(
+        &self,
+        auth: &PolymarketAuth,
+        payload: &serde_json::Value,
+        timestamp: u64,
+    ) -> Result<Vec<OrderPlacementResponse>> {
+        let url = format!("{}/orders", self.base_url);
+
+        let body = serde_json::to_string(payload)
             .map_err(|e| RestError::ApiError(format!("Failed to serialize orders: {}", e)))?;
 
         let headers = auth.l2_headers(timestamp, "POST", "/orders", &body)?;
 
-        debug!("📤 SENDING BATCH ORDER REQUEST ({} orders)", signed_orders.len());
+        let order_count = payload.as_array().map_or(0, |orders| orders.len());
+        debug!("📤 SENDING BATCH ORDER REQUEST ({} orders)", order_count);
         debug!("   URL: {}", url);
         debug!("   Body length: {} bytes", body.len());
         debug!("   Full body: {}", body);
 
-        let start = Instant::now();
+        self.rate_limited(EndpointClass::Order, async move {
+            let start = Instant::now();
 
-        let (tx, rx) = tokio::sync::oneshot::channel();
-        let headers_clone = headers.clone();
+            let (tx, rx) = tokio::sync::oneshot::channel();
+            let headers_clone = headers.clone();
 
-        std::thread::spawn(move || {
-            debug!("⏳ [Dedicated thread] Starting batch HTTP request...");
-            let thread_start = Instant::now();
+            std::thread::spawn(move || {
+                debug!("⏳ [Dedicated thread] Starting batch HTTP request...");
+                let thread_start = Instant::now();
 
-            let result = (|| -> std::result::Result<Vec<OrderPlacementResponse>, String> {
-                let mut request = ureq::post(&url)
-                    .set("Content-Type", "application/json")
-                    .set("User-Agent", "rs_clob_client")
-                    .set("Accept", "*/*");
+                let result = (|| -> Result<Vec<OrderPlacementResponse>> {
+                    let mut request = ureq::post(&url)
+                        .set("Content-Type", "application/json")
+                        .set("User-Agent", "rs_clob_client")
+                        .set("Accept", "*/*");
 
-                for (key, value) in &headers_clone {
-                    request = request.set(key, value);
-                }
+                    for (key, value) in &headers_clone {
+                        request = request.set(key, value);
+                    }
 
-                debug!("⏳ [Dedicated thread] Sending batch request...");
+                    debug!("⏳ [Dedicated thread] Sending batch request...");
 
-                let response = request
-                    .timeout(std::time::Duration::from_secs(30))
-                    .send_string(&body)
-                    .map_err(|e| format!("HTTP request failed: {}", e))?;
+                    let response = request
+                        .timeout(std::time::Duration::from_secs(30))
+                        .send_string(&body)
+                        .map_err(|e| ureq_error(e, "HTTP request failed"))?;
 
-                let status = response.status();
-                let response_body = response.into_string()
-                    .map_err(|e| format!("Failed to read response: {}", e))?;
+                    let status = response.status();
+                    let response_body = response.into_string()
+                        .map_err(|e| RestError::ApiError(format!("Failed to read response: {}", e)))?;
 
-                debug!("📥 [Dedicated thread] Got response: status={}, body_len={}", status, response_body.len());
+                    debug!("📥 [Dedicated thread] Got response: status={}, body_len={}", status, response_body.len());
 
-                if status == 200 || status == 201 {
-                    serde_json::from_str(&response_body)
-                        .map_err(|e| format!("Failed to parse response: {} - body: {}", e, response_body))
-                } else {
-                    Err(format!("Batch order failed with status {}: {}", status, response_body))
-                }
-            })();
+                    if status == 200 || status == 201 {
+                        serde_json::from_str(&response_body)
+                            .map_err(|e| RestError::ApiError(format!("Failed to parse response: {} - body: {}", e, response_body)))
+                    } else {
+                        Err(RestError::ApiError(format!("Batch order failed with status {}: {}", status, response_body)))
+                    }
+                })();
 
-            debug!("📥 [Dedicated thread] Batch HTTP completed in {:?}", thread_start.elapsed());
-            let _ = tx.send(result);
-        });
+                debug!("📥 [Dedicated thread] Batch HTTP completed in {:?}", thread_start.elapsed());
+                let _ = tx.send(result);
+            });
 
-        let result = rx.await
-            .map_err(|_| RestError::ApiError("Thread channel closed".to_string()))?;
+            let result = rx.await
+                .map_err(|_| RestError::ApiError("Thread channel closed".to_string()))?;
 
-        let elapsed = start.elapsed();
+            let elapsed = start.elapsed();
 
-        match result {
-            Ok(responses) => {
-                debug!("✅ Batch order request successful in {:?} ({} orders)", elapsed, responses.len());
-                Ok(responses)
-            }
-            Err(e) => {
-                error!("❌ Batch order request failed after {:?}: {}", elapsed, e);
-                Err(RestError::ApiError(e))
+            match result {
+                Ok(responses) => {
+                    debug!("✅ Batch order request successful in {:?} ({} orders)", elapsed, responses.len());
+                    Ok(responses)
+                }
+                Err(e) => {
+                    error!("❌ Batch order request failed after {:?}: {}", elapsed, e);
+                    Err(e)
+                }
             }
-        }
+        })
+        .await
     }
 
-    /// Place multiple orders in a batch (max 15)
+    /// Place multiple orders in batches of at most `MAX_BATCH_ORDERS`
+    ///
+    /// Every order is signed with `nonce`, the maker's current exchange nonce.
     pub async fn place_batch_orders(
         &self,
         auth: &PolymarketAuth,
         order_builder: &OrderBuilder,
         orders: Vec<(String, f64, f64, Side, OrderType)>,
         fee_rate_bps: Option<u64>,
+        nonce: u64,
     ) -> Result<Vec<OrderPlacementResponse>> {
         if orders.is_empty() {
             return Ok(Vec::new());
         }
 
-        if orders.len() > 15 {
-            return Err(RestError::ApiError(
-                "Maximum 15 orders per batch".to_string(),
-            ));
-        }
-
-        let timestamp = PolymarketAuth::current_timestamp();
-
-        // Nonce starts at 0 for each batch (client-managed, not fetched from server)
-        let mut nonce = 0u64;
+        let timestamp = self.timestamp();
 
-        debug!("Building batch of {} orders, starting nonce={}", orders.len(), nonce);
+        debug!("Building batch of {} orders, nonce={}", orders.len(), nonce);
 
-        let mut signed_orders: Vec<(SignedOrder, OrderType)> = Vec::with_capacity(orders.len());
+        let mut signed_orders: Vec<SignedOrder> = Vec::with_capacity(orders.len());
 
         for (token_id, price, size, side, order_type) in orders {
-            let signed_order = order_builder
-                .build_signed_order(
-                    auth,
-                    &token_id,
-                    price,
-                    size,
-                    side,
-                    nonce,
-                    fee_rate_bps,
-                    None,
-                )
+            let signed_order = TimeInForce::try_from(order_type)
+                .and_then(|tif| {
+                    order_builder.build_signed_order(
+                        auth,
+                        &token_id,
+                        price,
+                        size,
+                        side,
+                        nonce,
+                        fee_rate_bps,
+                        tif,
+                    )
+                })
                 .map_err(|e| RestError::ApiError(format!("Failed to build order: {}", e)))?;
 
-            signed_orders.push((signed_order, order_type));
-            nonce += 1;
+            signed_orders.push(signed_order);
         }
 
         self.submit_batch_orders(auth, &signed_orders, timestamp).await
@@ -353,6 +521,7 @@ impl RestClient {
         order_builder: &OrderBuilder,
         token_id: &str,
         amount_usd: f64,
+        nonce: u64,
     ) -> Result<OrderPlacementResponse> {
         let orderbook = self.get_orderbook(token_id).await?;
         let best_ask = orderbook
@@ -372,6 +541,7 @@ impl RestClient {
             Side::Buy,
             OrderType::FOK,
             None,
+            nonce,
         )
         .await
     }
@@ -383,6 +553,7 @@ impl RestClient {
         order_builder: &OrderBuilder,
         token_id: &str,
         size: f64,
+        nonce: u64,
     ) -> Result<OrderPlacementResponse> {
         let orderbook = self.get_orderbook(token_id).await?;
         let best_bid = orderbook
@@ -401,6 +572,7 @@ impl RestClient {
             Side::Sell,
             OrderType::FOK,
             None,
+            nonce,
         )
         .await
     }
@@ -35,17 +35,21 @@ impl RestClient {
 
         let query_string = query_parts.join("&");
         let url = format!("{}/data/orders?{}", self.base_url, query_string);
-        let timestamp = PolymarketAuth::current_timestamp();
+        let url = &url;
 
-        let headers = auth.l2_headers(timestamp, "GET", "/data/orders", "")?;
-        let req = with_headers(self.client().get(&url), headers);
-        let response = req.send().await?;
+        self.get_with_retry("get_orders", |client| async move {
+            // Fresh timestamp per attempt so retried requests stay signed
+            let timestamp = self.timestamp();
+            let headers = auth.l2_headers(timestamp, "GET", "/data/orders", "")?;
+            let response = with_headers(client.get(url), headers).send().await?;
 
-        if !response.status().is_success() {
-            return Err(extract_api_error(response, "Failed to fetch orders").await);
-        }
+            if !response.status().is_success() {
+                return Err(extract_api_error(response, "Failed to fetch orders").await);
+            }
 
-        parse_json(response).await
+            parse_json(response).await
+        })
+        .await
     }
 
     /// Fetch all open orders (auto-pagination)
@@ -75,17 +79,21 @@ impl RestClient {
     pub async fn get_order(&self, auth: &PolymarketAuth, order_id: &str) -> Result<OpenOrder> {
         let path = format!("/data/order/{}", order_id);
         let url = format!("{}{}", self.base_url, path);
-        let timestamp = PolymarketAuth::current_timestamp();
+        let (url, path) = (&url, &path);
 
-        let headers = auth.l2_headers(timestamp, "GET", &path, "")?;
-        let req = with_headers(self.client().get(&url), headers);
-        let response = req.send().await?;
+        self.get_with_retry("get_order", |client| async move {
+            // Fresh timestamp per attempt so retried requests stay signed
+            let timestamp = self.timestamp();
+            let headers = auth.l2_headers(timestamp, "GET", path, "")?;
+            let response = with_headers(client.get(url), headers).send().await?;
 
-        if !response.status().is_success() {
-            return Err(extract_api_error(response, "Failed to fetch order").await);
-        }
+            if !response.status().is_success() {
+                return Err(extract_api_error(response, "Failed to fetch order").await);
+            }
 
-        parse_json(response).await
+            parse_json(response).await
+        })
+        .await
     }
 
     /// Fetch trades (single page)
@@ -123,17 +131,21 @@ impl RestClient {
 
         let query_string = query_parts.join("&");
         let url = format!("{}/data/trades?{}", self.base_url, query_string);
-        let timestamp = PolymarketAuth::current_timestamp();
+        let url = &url;
 
-        let headers = auth.l2_headers(timestamp, "GET", "/data/trades", "")?;
-        let req = with_headers(self.client().get(&url), headers);
-        let response = req.send().await?;
+        self.get_with_retry("get_trades", |client| async move {
+            // Fresh timestamp per attempt so retried requests stay signed
+            let timestamp = self.timestamp();
+            let headers = auth.l2_headers(timestamp, "GET", "/data/trades", "")?;
+            let response = with_headers(client.get(url), headers).send().await?;
 
-        if !response.status().is_success() {
-            return Err(extract_api_error(response, "Failed to fetch trades").await);
-        }
+            if !response.status().is_success() {
+                return Err(extract_api_error(response, "Failed to fetch trades").await);
+            }
 
-        parse_json(response).await
+            parse_json(response).await
+        })
+        .await
     }
 
     /// Fetch all trades (auto-pagination)
@@ -188,16 +200,20 @@ impl RestClient {
         } else {
             format!("{}/balance-allowance?{}", self.base_url, query_parts.join("&"))
         };
-        let timestamp = PolymarketAuth::current_timestamp();
+        let url = &url;
 
-        let headers = auth.l2_headers(timestamp, "GET", "/balance-allowance", "")?;
-        let req = with_headers(self.client().get(&url), headers);
-        let response = req.send().await?;
+        self.get_with_retry("get_balance_allowance", |client| async move {
+            // Fresh timestamp per attempt so retried requests stay signed
+            let timestamp = self.timestamp();
+            let headers = auth.l2_headers(timestamp, "GET", "/balance-allowance", "")?;
+            let response = with_headers(client.get(url), headers).send().await?;
 
-        if !response.status().is_success() {
-            return Err(extract_api_error(response, "Failed to fetch balance/allowance").await);
-        }
+            if !response.status().is_success() {
+                return Err(extract_api_error(response, "Failed to fetch balance/allowance").await);
+            }
 
-        parse_json(response).await
+            parse_json(response).await
+        })
+        .await
     }
 }
@@ -0,0 +1,163 @@
+//! Client-side rate limiting for CLOB endpoints
+//!
+//! Reads and order placement/cancellation are limited separately by token
+//! buckets; every request awaits a permit from its class before going out.
+//! A 429 pauses the bucket for the server's `Retry-After`, so a burst that
+//! slipped past the configured rate backs off instead of hammering the API.
+
+use super::{RestClient, RestError, Result};
+use parking_lot::Mutex;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// Pause applied when a 429 carries no usable `Retry-After`
+pub const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// Endpoint classes with independent rate limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndpointClass {
+    /// Market data, order/trade queries, auth lookups
+    Read,
+    /// Order placement and cancellation
+    Order,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+    paused_until: Option<Instant>,
+}
+
+/// Token bucket rate limiter
+///
+/// Holds up to `capacity` permits, refilled at `per_second`.
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: f64,
+    per_second: f64,
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    /// Bucket of `capacity` permits refilled at `per_second`
+    pub fn new(capacity: u32, per_second: f64) -> Self {
+        assert!(capacity > 0, "rate limiter capacity must be positive");
+        assert!(per_second > 0.0, "rate limiter refill rate must be positive");
+        let capacity = capacity as f64;
+        Self {
+            capacity,
+            per_second,
+            bucket: Mutex::new(Bucket {
+                tokens: capacity,
+                last_refill: Instant::now(),
+                paused_until: None,
+            }),
+        }
+    }
+
+    /// No rate limit; still honors 429 pauses
+    pub fn unlimited() -> Self {
+        Self {
+            capacity: f64::INFINITY,
+            per_second: f64::INFINITY,
+            bucket: Mutex::new(Bucket {
+                tokens: f64::INFINITY,
+                last_refill: Instant::now(),
+                paused_until: None,
+            }),
+        }
+    }
+
+    pub fn capacity(&self) -> f64 {
+        self.capacity
+    }
+
+    pub fn per_second(&self) -> f64 {
+        self.per_second
+    }
+
+    /// Wait until a permit is available and take it
+    pub async fn acquire(&self) {
+        while let Some(wait) = self.try_acquire() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Take a permit, or return how long to wait before trying again
+    pub fn try_acquire(&self) -> Option<Duration> {
+        let now = Instant::now();
+        let mut bucket = self.bucket.lock();
+
+        if let Some(until) = bucket.paused_until {
+            if until > now {
+                return Some(until - now);
+            }
+            bucket.paused_until = None;
+        }
+
+        if bucket.tokens < self.capacity {
+            let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+            bucket.tokens = (bucket.tokens + elapsed * self.per_second).min(self.capacity);
+        }
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            None
+        } else {
+            Some(Duration::from_secs_f64((1.0 - bucket.tokens) / self.per_second))
+        }
+    }
+
+    /// Hold back all permits for `duration` (e.g. a 429's `Retry-After`)
+    ///
+    /// An earlier pause that ends later is kept.
+    pub fn pause(&self, duration: Duration) {
+        let until = Instant::now() + duration;
+        let mut bucket = self.bucket.lock();
+        if bucket.paused_until.is_none_or(|current| current < until) {
+            bucket.paused_until = Some(until);
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::unlimited()
+    }
+}
+
+/// Parse a `Retry-After` value given in (possibly fractional) seconds
+pub(crate) fn parse_retry_after(value: Option<&str>) -> Duration {
+    value
+        .and_then(|v| v.trim().parse::<f64>().ok())
+        .filter(|secs| secs.is_finite() && *secs >= 0.0)
+        .map(Duration::from_secs_f64)
+        .unwrap_or(DEFAULT_RETRY_AFTER)
+}
+
+impl RestClient {
+    /// Send a request under the rate limit of its endpoint class
+    ///
+    /// Awaits a permit first; a `RateLimited` response pauses the class for
+    /// the server's `Retry-After`.
+    pub(crate) async fn rate_limited<T, Fut>(&self, class: EndpointClass, request: Fut) -> Result<T>
+    where
+        Fut: Future<Output = Result<T>>,
+    {
+        let limiter = self.rate_limiter(class);
+        limiter.acquire().await;
+
+        let result = request.await;
+        if let Err(RestError::RateLimited { retry_after }) = &result {
+            warn!(
+                "[RestClient] {:?} endpoints rate limited, pausing for {:?}",
+                class, retry_after
+            );
+            limiter.pause(*retry_after);
+        }
+        result
+    }
+}
@@ -0,0 +1,107 @@
+//! Retry policy for idempotent GET requests
+//!
+//! Only read-only requests go through `get_with_retry`. Order placement and
+//! cancellation stay single-shot: a POST that timed out may still have been
+//! accepted, and resending it could double-spend.
+
+use super::{EndpointClass, RestClient, RestError, Result};
+use crate::infrastructure::backoff::BackoffPolicy;
+use reqwest::Client;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+
+/// When and how often GET requests are retried
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// Retry count and delays
+    pub backoff: BackoffPolicy,
+    /// Whether an error is worth retrying
+    pub retry_on: Arc<dyn Fn(&RestError) -> bool + Send + Sync>,
+}
+
+impl Default for RetryPolicy {
+    /// Up to 3 retries on connect/timeout errors, starting at 200ms
+    fn default() -> Self {
+        Self {
+            backoff: BackoffPolicy::new(3, Duration::from_millis(200)),
+            retry_on: Arc::new(RestError::is_connect_or_timeout),
+        }
+    }
+}
+
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("backoff", &self.backoff)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RetryPolicy {
+    /// Never retry
+    pub fn none() -> Self {
+        Self {
+            backoff: BackoffPolicy::none(),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.backoff = self.backoff.with_max_retries(max_retries);
+        self
+    }
+
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.backoff = self.backoff.with_base_delay(base_delay);
+        self
+    }
+
+    pub fn with_retry_on(mut self, retry_on: impl Fn(&RestError) -> bool + Send + Sync + 'static) -> Self {
+        self.retry_on = Arc::new(retry_on);
+        self
+    }
+
+    /// Retry before which the HTTP client is recreated, half-way through
+    fn recreate_at(&self) -> u32 {
+        self.backoff.max_retries.div_ceil(2)
+    }
+}
+
+impl RestClient {
+    /// Run an idempotent GET under the retry policy
+    ///
+    /// `request` gets the current HTTP client on every attempt, so the one
+    /// recreation half-way through the sequence (like `ensure_connectivity`)
+    /// takes effect for the remaining retries.
+    pub(crate) async fn get_with_retry<T, F, Fut>(&self, what: &str, mut request: F) -> Result<T>
+    where
+        F: FnMut(Client) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let policy = &self.retry_policy;
+        let backoff = &policy.backoff;
+        let mut retry = 0;
+
+        loop {
+            let attempt = request(self.client());
+            match self.rate_limited(EndpointClass::Read, attempt).await {
+                Ok(value) => return Ok(value),
+                Err(e) if retry < backoff.max_retries && (policy.retry_on)(&e) => {
+                    retry += 1;
+                    let delay = backoff.delay_for(retry);
+                    warn!(
+                        "[RestClient] {} failed (retry {}/{} in {:?}): {}",
+                        what, retry, backoff.max_retries, delay, e
+                    );
+                    if retry == policy.recreate_at() {
+                        self.recreate_client();
+                    }
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
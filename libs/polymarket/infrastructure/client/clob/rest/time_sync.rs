@@ -0,0 +1,167 @@
+//! Clock synchronization check against the CLOB `/time` endpoint
+//!
+//! L2 header timestamps and GTD expirations are compared against the
+//! exchange clock, so a skewed local clock gets orders rejected. The check
+//! measures the skew, warns or fails past configured thresholds, and can
+//! apply the skew as an offset to the client's `RestClient::timestamp`.
+
+use super::super::super::auth::PolymarketAuth;
+use super::super::helpers::require_success;
+use super::{RestClient, RestError, Result};
+use std::sync::atomic::Ordering;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::{debug, info, warn};
+
+/// Thresholds for `RestClient::check_clock_sync`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClockSyncConfig {
+    /// Skew at which a warning is logged
+    pub warn_threshold: Duration,
+    /// Skew at which the check fails (`None` = never)
+    pub max_skew: Option<Duration>,
+    /// Apply the measured skew as this client's clock offset (see `RestClient::timestamp`)
+    pub apply_offset: bool,
+}
+
+impl Default for ClockSyncConfig {
+    /// Warn at 2s, fail at 30s, leave the local clock alone
+    fn default() -> Self {
+        Self {
+            warn_threshold: Duration::from_secs(2),
+            max_skew: Some(Duration::from_secs(30)),
+            apply_offset: false,
+        }
+    }
+}
+
+impl ClockSyncConfig {
+    pub fn with_warn_threshold(mut self, threshold: Duration) -> Self {
+        self.warn_threshold = threshold;
+        self
+    }
+
+    pub fn with_max_skew(mut self, max_skew: Option<Duration>) -> Self {
+        self.max_skew = max_skew;
+        self
+    }
+
+    pub fn with_apply_offset(mut self, apply: bool) -> Self {
+        self.apply_offset = apply;
+        self
+    }
+}
+
+/// Measured difference between the exchange clock and the local clock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockSkew {
+    /// Exchange time in unix seconds
+    pub server_time: u64,
+    /// Local time in unix seconds, at the midpoint of the request
+    pub local_time: u64,
+    /// `server_time - local_time`; positive when the local clock is behind
+    pub offset_secs: i64,
+}
+
+impl ClockSkew {
+    /// Absolute skew
+    pub fn magnitude(&self) -> Duration {
+        Duration::from_secs(self.offset_secs.unsigned_abs())
+    }
+
+    /// Whether the skew is at least `threshold`
+    pub fn exceeds(&self, threshold: Duration) -> bool {
+        self.magnitude() >= threshold
+    }
+}
+
+/// Local wall-clock time in unix seconds (uncorrected)
+fn local_secs() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs_f64()
+}
+
+impl RestClient {
+    /// Current unix time in seconds, corrected by this client's clock offset
+    ///
+    /// Used for L2 header timestamps and order expiry checks.
+    pub fn timestamp(&self) -> u64 {
+        PolymarketAuth::current_timestamp().saturating_add_signed(self.clock_offset())
+    }
+
+    /// Offset (server time - local time) applied by `timestamp`
+    pub fn clock_offset(&self) -> i64 {
+        self.clock_offset_secs.load(Ordering::Relaxed)
+    }
+
+    /// Shift `timestamp` by `offset_secs`; `check_clock_sync` sets it when
+    /// asked to apply the measured skew
+    pub fn set_clock_offset(&self, offset_secs: i64) {
+        self.clock_offset_secs.store(offset_secs, Ordering::Relaxed);
+    }
+
+    /// Get the exchange time in unix seconds
+    pub async fn get_server_time(&self) -> Result<u64> {
+        let url = format!("{}/time", self.base_url);
+
+        let url = &url;
+        self.get_with_retry("get_server_time", |client| async move {
+            let response = client
+                .get(url)
+                .timeout(Duration::from_secs(5))
+                .send()
+                .await?;
+            let response = require_success(response, "Failed to fetch server time").await?;
+            let text = response.text().await?;
+            text.trim()
+                .trim_matches('"')
+                .parse::<u64>()
+                .map_err(|e| RestError::DeserializeFailed(format!("bad server time '{}': {}", text.trim(), e)))
+        })
+        .await
+    }
+
+    /// Compare the local clock with the exchange clock
+    ///
+    /// Logs a warning when the skew reaches `warn_threshold` and fails with
+    /// `RestError::ClockSkew` past `max_skew`. With `apply_offset`, the skew is
+    /// applied to this client's `timestamp` first, so a caller that
+    /// tolerates the error still signs with exchange-aligned time.
+    pub async fn check_clock_sync(&self, config: &ClockSyncConfig) -> Result<ClockSkew> {
+        let before = local_secs();
+        let server_time = self.get_server_time().await?;
+        let local_time = ((before + local_secs()) / 2.0).round() as u64;
+
+        let skew = ClockSkew {
+            server_time,
+            local_time,
+            offset_secs: server_time as i64 - local_time as i64,
+        };
+
+        if skew.exceeds(config.warn_threshold) {
+            warn!(
+                "[RestClient] Local clock is {}s {} the exchange clock (server={}, local={})",
+                skew.offset_secs.unsigned_abs(),
+                if skew.offset_secs > 0 { "behind" } else { "ahead of" },
+                server_time,
+                local_time
+            );
+        } else {
+            debug!("[RestClient] Clock skew {}s", skew.offset_secs);
+        }
+
+        if config.apply_offset {
+            self.set_clock_offset(skew.offset_secs);
+            info!("[RestClient] Applying clock offset of {}s", skew.offset_secs);
+        }
+
+        match config.max_skew {
+            Some(max) if skew.exceeds(max) => Err(RestError::ClockSkew {
+                offset_secs: skew.offset_secs,
+                max_secs: max.as_secs(),
+            }),
+            _ => Ok(skew),
+        }
+    }
+}
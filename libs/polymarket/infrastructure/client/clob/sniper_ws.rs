@@ -3,18 +3,21 @@
 //! This module provides reusable WebSocket components for tracking Polymarket orderbooks.
 //! The types are designed to be used by strategies that need real-time orderbook data.
 
-use super::orderbook::Orderbook;
+use super::debounce::{spawn_update_debouncer, BookUpdate};
+use super::orderbook::{LastTrade, Orderbook};
 use super::sniper_ws_types::{
-    BookSnapshot, LastTradePriceEvent, MarketSubscription, PriceChangeEvent, SniperMessage,
-    TickSizeChangeEvent,
+    BookSnapshot, LastTradePriceEvent, MarketResolvedEvent, MarketSubscription, PriceChange,
+    PriceChangeEvent, SniperMessage, TickSizeChangeEvent,
 };
 use super::types::PriceLevel;
+use crate::domain::price::{parse_price, PriceScale};
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use crossbeam_channel::Sender;
+use crossbeam_channel::{unbounded, Receiver, Sender};
 use hypersockets::core::*;
-use hypersockets::{MessageHandler, MessageRouter, TextPongDetector, WsMessage};
-use std::collections::HashMap;
+use hypersockets::{ExponentialBackoff, MessageHandler, MessageRouter, TextPongDetector, WsMessage};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
@@ -27,6 +30,9 @@ pub type SharedOrderbooks = Arc<RwLock<HashMap<String, Orderbook>>>;
 /// Shared precisions per token (number of decimal places)
 pub type SharedPrecisions = Arc<RwLock<HashMap<String, u8>>>;
 
+/// Polymarket market channel endpoint
+pub const MARKET_WS_URL: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/market";
+
 // =============================================================================
 // Precision Helper Functions
 // =============================================================================
@@ -54,6 +60,74 @@ pub fn max_precision_in_levels(levels: &[PriceLevel]) -> u8 {
 // Configuration
 // =============================================================================
 
+/// Connection health settings for the market WebSocket.
+///
+/// Defaults match the original hardcoded values: PING every 5s, unhealthy
+/// after 15s without PONG, exponential backoff 1s..60s for up to 10 attempts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SniperConnectionConfig {
+    pub heartbeat_interval: Duration,
+    pub pong_timeout: Duration,
+    pub reconnect_initial_delay: Duration,
+    pub reconnect_max_delay: Duration,
+    /// None = retry forever
+    pub reconnect_max_attempts: Option<usize>,
+}
+
+impl Default for SniperConnectionConfig {
+    fn default() -> Self {
+        Self {
+            heartbeat_interval: Duration::from_secs(5),
+            pong_timeout: Duration::from_secs(15),
+            reconnect_initial_delay: Duration::from_secs(1),
+            reconnect_max_delay: Duration::from_secs(60),
+            reconnect_max_attempts: Some(10),
+        }
+    }
+}
+
+impl SniperConnectionConfig {
+    /// Build from defaults, overridden by environment variables:
+    /// - `SNIPER_WS_HEARTBEAT_MS`: PING interval in milliseconds
+    /// - `SNIPER_WS_PONG_TIMEOUT_MS`: max wait for PONG in milliseconds
+    /// - `SNIPER_WS_RECONNECT_INITIAL_MS` / `SNIPER_WS_RECONNECT_MAX_MS`: backoff bounds
+    /// - `SNIPER_WS_RECONNECT_MAX_ATTEMPTS`: attempt limit (0 = unlimited)
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+
+        if let Some(ms) = env_u64("SNIPER_WS_HEARTBEAT_MS") {
+            config.heartbeat_interval = Duration::from_millis(ms.max(1));
+        }
+        if let Some(ms) = env_u64("SNIPER_WS_PONG_TIMEOUT_MS") {
+            config.pong_timeout = Duration::from_millis(ms.max(1));
+        }
+        if let Some(ms) = env_u64("SNIPER_WS_RECONNECT_INITIAL_MS") {
+            config.reconnect_initial_delay = Duration::from_millis(ms);
+        }
+        if let Some(ms) = env_u64("SNIPER_WS_RECONNECT_MAX_MS") {
+            config.reconnect_max_delay = Duration::from_millis(ms);
+        }
+        if let Some(attempts) = env_u64("SNIPER_WS_RECONNECT_MAX_ATTEMPTS") {
+            config.reconnect_max_attempts = (attempts > 0).then_some(attempts as usize);
+        }
+
+        config
+    }
+
+    /// Reconnection strategy described by this config
+    pub fn reconnect_strategy(&self) -> ExponentialBackoff {
+        ExponentialBackoff::new(
+            self.reconnect_initial_delay,
+            self.reconnect_max_delay,
+            self.reconnect_max_attempts,
+        )
+    }
+}
+
+fn env_u64(key: &str) -> Option<u64> {
+    std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+}
+
 /// Configuration for a market tracker
 pub struct MarketTrackerConfig {
     pub market_id: String,
@@ -62,10 +136,28 @@ pub struct MarketTrackerConfig {
     pub token_ids: Vec<String>,
     pub outcomes: Vec<String>,
     pub resolution_time: DateTime<Utc>,
+    /// Max price levels stored per side (None = full depth)
+    pub max_depth: Option<usize>,
+    /// WebSocket endpoint (defaults to the Polymarket market channel)
+    pub ws_url: String,
+    /// Heartbeat, pong and reconnection settings
+    pub connection: SniperConnectionConfig,
+    /// Coalesce book update notifications per token to one per interval (None = every update)
+    pub update_debounce: Option<Duration>,
+    /// Drop invalid books (crossed, unsorted, negative sizes) and books that
+    /// no longer match the exchange's top of book after a price_change (a
+    /// missed delta), and wait for a fresh snapshot
+    pub check_crossed_books: bool,
+    /// Denomination of the feed's prices; canonicalized to [0, 1] on ingestion
+    pub price_scale: PriceScale,
 }
 
 impl MarketTrackerConfig {
-    /// Create a new tracker configuration
+    /// Create a new tracker configuration.
+    ///
+    /// Uses the default connection settings; pass configured ones (e.g.
+    /// `SniperConnectionConfig::from_env()` read at config load) with
+    /// `with_connection`.
     pub fn new(
         market_id: String,
         market_question: String,
@@ -84,9 +176,51 @@ impl MarketTrackerConfig {
             token_ids,
             outcomes,
             resolution_time,
+            max_depth: None,
+            ws_url: MARKET_WS_URL.to_string(),
+            connection: SniperConnectionConfig::default(),
+            update_debounce: None,
+            check_crossed_books: true,
+            price_scale: PriceScale::Unit,
         })
     }
 
+    /// Only store the top `depth` levels per side of each book
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Connect to a different endpoint (e.g. a local mock server)
+    pub fn with_ws_url(mut self, url: impl Into<String>) -> Self {
+        self.ws_url = url.into();
+        self
+    }
+
+    /// Deliver at most one book update notification per token per `interval`
+    pub fn with_update_debounce(mut self, interval: Duration) -> Self {
+        self.update_debounce = Some(interval);
+        self
+    }
+
+    /// Enable or disable crossed-book detection (on by default)
+    pub fn with_crossed_book_check(mut self, enabled: bool) -> Self {
+        self.check_crossed_books = enabled;
+        self
+    }
+
+    /// Read feed prices in `scale` (unit by default)
+    pub fn with_price_scale(mut self, scale: PriceScale) -> Self {
+        self.price_scale = scale;
+        self
+    }
+
+    /// Override heartbeat, pong and reconnection settings
+    pub fn with_connection(mut self, connection: SniperConnectionConfig) -> Self {
+        self.connection = connection;
+        self
+    }
+
     /// Build a mapping from token_id to outcome name (e.g., "Yes", "No")
     pub fn build_outcome_map(&self) -> HashMap<String, String> {
         self.token_ids
@@ -169,6 +303,13 @@ impl MessageRouter for SniperRouter {
             }
         }
 
+        // Try to parse as market_resolved event
+        if let Ok(resolved) = serde_json::from_str::<MarketResolvedEvent>(text) {
+            if resolved.event_type == "market_resolved" {
+                return Ok(SniperMessage::MarketResolved(resolved));
+            }
+        }
+
         // Unknown message
         debug!("[WS {}] Unknown message: {}", self.market_id, text);
         Ok(SniperMessage::Unknown(text.to_string()))
@@ -183,6 +324,28 @@ impl MessageRouter for SniperRouter {
 // Handler - Processes and logs messages
 // =============================================================================
 
+/// Exchange book state a local book was last brought to
+struct BookState {
+    timestamp_ms: Option<i64>,
+    /// Exchange hash identifying the state
+    hash: Option<String>,
+}
+
+/// Whether a local best price disagrees with the exchange's `reported` one.
+///
+/// Zero or unparseable reports carry no information. An empty local side
+/// only counts when the full book is kept: with `max_depth` the levels
+/// behind a removed best may simply have been truncated away.
+fn diverges(local: Option<f64>, reported: &str, max_depth: Option<usize>) -> bool {
+    let Some(reported) = reported.parse::<f64>().ok().filter(|p| *p > 0.0) else {
+        return false;
+    };
+    match local {
+        Some(local) => (local - reported).abs() > 1e-9,
+        None => max_depth.is_none(),
+    }
+}
+
 /// Handler for processing sniper messages
 pub struct SniperHandler {
     market_id: String,
@@ -192,8 +355,23 @@ pub struct SniperHandler {
     /// Optional channel to forward tick_size_change events to main loop
     tick_size_tx: Option<Sender<TickSizeChangeEvent>>,
     message_count: u64,
-    /// Track last trade prices per asset
-    last_trade_prices: HashMap<String, (String, String)>, // asset_id -> (price, size)
+    /// Max levels stored per side (None = full depth)
+    max_depth: Option<usize>,
+    /// Optional channel notified after each book change
+    update_tx: Option<Sender<BookUpdate>>,
+    /// Whether invalid books (see `Orderbook::validate`) and books behind a
+    /// sequence gap are dropped until the next snapshot
+    check_crossed: bool,
+    /// Optional channel receiving asset ids whose book needs a resync
+    resync_tx: Option<Sender<String>>,
+    /// Assets whose book was dropped; updates are ignored until a snapshot
+    awaiting_resync: HashSet<String>,
+    /// Exchange timestamp (ms) of the last snapshot applied per asset
+    snapshot_ms: HashMap<String, i64>,
+    /// Newest exchange book state applied per asset
+    book_states: HashMap<String, BookState>,
+    /// Denomination of incoming prices
+    price_scale: PriceScale,
 
     first_snapshot_received: Arc<AtomicBool>,
 }
@@ -212,17 +390,291 @@ impl SniperHandler {
             precisions,
             tick_size_tx,
             message_count: 0,
-            last_trade_prices: HashMap::new(),
+            max_depth: None,
+            update_tx: None,
+            check_crossed: true,
+            resync_tx: None,
+            awaiting_resync: HashSet::new(),
+            snapshot_ms: HashMap::new(),
+            book_states: HashMap::new(),
+            price_scale: PriceScale::Unit,
             first_snapshot_received,
         }
     }
 
+    /// Truncate stored books to the top `depth` levels per side.
+    ///
+    /// Best prices stay accurate: levels are kept best-first and price_change
+    /// events carry the exchange's authoritative best bid/ask. Levels beyond the
+    /// depth are dropped until the next snapshot re-sends them.
+    pub fn with_max_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Send a `BookUpdate` for every asset whose book changes
+    pub fn with_update_notifier(mut self, update_tx: Option<Sender<BookUpdate>>) -> Self {
+        self.update_tx = update_tx;
+        self
+    }
+
+    /// Drop crossed, unsorted or gapped books instead of serving them (on by default)
+    pub fn with_crossed_book_check(mut self, enabled: bool) -> Self {
+        self.check_crossed = enabled;
+        self
+    }
+
+    /// Send the asset id of every dropped book to this channel.
+    ///
+    /// The owner of the client should answer with `request_book_resync`;
+    /// clients from `build_ws_client` and `build_sniper_ws` do so themselves.
+    pub fn with_resync_notifier(mut self, resync_tx: Option<Sender<String>>) -> Self {
+        self.resync_tx = resync_tx;
+        self
+    }
+
+    /// Canonicalize incoming prices from `scale` to [0, 1] (unit by default).
+    ///
+    /// Levels and trades whose price is unparseable or out of range for the
+    /// scale are dropped with a warning.
+    pub fn with_price_scale(mut self, scale: PriceScale) -> Self {
+        self.price_scale = scale;
+        self
+    }
+
+    /// Canonical form of a feed price, or `None` if it is invalid
+    fn canonical_price(&self, asset_id: &str, raw: &str) -> Option<String> {
+        match parse_price(raw, self.price_scale) {
+            Ok(_) if self.price_scale == PriceScale::Unit => Some(raw.to_string()),
+            Ok(price) => Some(price.to_string()),
+            Err(e) => {
+                warn!("[WS {}] Dropping price for {}: {}", self.market_id, asset_id, e);
+                None
+            }
+        }
+    }
+
+    fn canonical_levels(&self, asset_id: &str, levels: &[PriceLevel]) -> Vec<PriceLevel> {
+        levels
+            .iter()
+            .filter_map(|level| {
+                Some(PriceLevel {
+                    price: self.canonical_price(asset_id, &level.price)?,
+                    size: level.size.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Whether a feed price can be used as is: a valid price on the unit scale
+    fn is_canonical(&self, raw: &str) -> bool {
+        self.price_scale == PriceScale::Unit && parse_price(raw, PriceScale::Unit).is_ok()
+    }
+
+    /// Snapshots with every level price canonicalized, borrowed unchanged
+    /// when every price already is
+    fn canonical_snapshots<'a>(&self, snapshots: &'a [BookSnapshot]) -> Cow<'a, [BookSnapshot]> {
+        let canonical = snapshots.iter().all(|snapshot| {
+            snapshot
+                .bids
+                .iter()
+                .chain(&snapshot.asks)
+                .all(|level| self.is_canonical(&level.price))
+        });
+        if canonical {
+            return Cow::Borrowed(snapshots);
+        }
+        snapshots
+            .iter()
+            .map(|snapshot| BookSnapshot {
+                bids: self.canonical_levels(&snapshot.asset_id, &snapshot.bids),
+                asks: self.canonical_levels(&snapshot.asset_id, &snapshot.asks),
+                ..snapshot.clone()
+            })
+            .collect()
+    }
+
+    /// The event with canonical prices; changes with an invalid price are
+    /// dropped and an invalid best bid/ask is blanked (the book ignores it).
+    /// Borrowed unchanged when every price already is canonical.
+    fn canonical_price_change<'a>(&self, event: &'a PriceChangeEvent) -> Cow<'a, PriceChangeEvent> {
+        let canonical = event.price_changes.iter().all(|change| {
+            self.is_canonical(&change.price)
+                && [&change.best_bid, &change.best_ask]
+                    .iter()
+                    .all(|best| best.is_empty() || self.is_canonical(best))
+        });
+        if canonical {
+            return Cow::Borrowed(event);
+        }
+        let price_changes = event
+            .price_changes
+            .iter()
+            .filter_map(|change| {
+                let price = self.canonical_price(&change.asset_id, &change.price)?;
+                let best = |raw: &str| {
+                    parse_price(raw, self.price_scale)
+                        .map(|p| p.to_string())
+                        .unwrap_or_default()
+                };
+                Some(PriceChange {
+                    price,
+                    best_bid: best(&change.best_bid),
+                    best_ask: best(&change.best_ask),
+                    ..change.clone()
+                })
+            })
+            .collect();
+        Cow::Owned(PriceChangeEvent {
+            price_changes,
+            ..event.clone()
+        })
+    }
+
+    /// Remove invalid books from the shared map and request a resync.
+    ///
+    /// Updates for a dropped asset are ignored until its next snapshot, so
+    /// strategies never see a book rebuilt from deltas on top of bad state.
+    fn drop_invalid_books<'a>(&mut self, asset_ids: impl Iterator<Item = &'a str>) {
+        if !self.check_crossed {
+            return;
+        }
+        let mut dropped = Vec::new();
+        {
+            let mut obs = self.orderbooks.write();
+            for asset_id in asset_ids {
+                let Some(Err(err)) = obs.get(asset_id).map(Orderbook::validate) else {
+                    continue;
+                };
+                let book = obs.remove(asset_id);
+                warn!(
+                    "[WS {}] Invalid book for {}: {} ({}), dropping until resync",
+                    self.market_id,
+                    asset_id,
+                    err,
+                    book.map(|b| b.format_summary()).unwrap_or_default()
+                );
+                dropped.push(asset_id);
+            }
+        }
+        for asset_id in dropped {
+            self.request_resync(asset_id);
+        }
+    }
+
+    /// Whether `event` predates the snapshot currently applied for `asset_id`.
+    ///
+    /// The market channel carries no sequence numbers, and deltas can reach
+    /// the handler out of order, so a late timestamp is not evidence of a
+    /// lost message. A delta older than the snapshot is already reflected in
+    /// it, though, and applying it again would corrupt the book.
+    fn predates_snapshot(&self, asset_id: &str, event: &PriceChangeEvent) -> bool {
+        match (self.snapshot_ms.get(asset_id), event.timestamp.parse::<i64>()) {
+            (Some(&snapshot_ms), Ok(timestamp_ms)) => timestamp_ms < snapshot_ms,
+            _ => false,
+        }
+    }
+
+    /// Whether `event` is at least as new as the book state applied for `asset_id`
+    fn is_newest(&self, asset_id: &str, event: &PriceChangeEvent) -> bool {
+        match (self.book_states.get(asset_id), event.timestamp.parse::<i64>()) {
+            (Some(state), Ok(timestamp_ms)) => state.timestamp_ms.is_none_or(|ms| timestamp_ms >= ms),
+            _ => true,
+        }
+    }
+
+    /// Find assets whose book missed a delta.
+    ///
+    /// The market channel carries no sequence numbers; instead every change
+    /// names the book state it produced (`hash`) together with that state's
+    /// best bid/ask. If the local book disagrees with them after the newest
+    /// change is applied, the deltas between the last state applied and
+    /// `hash` were lost. Late deltas describe an older state and are not
+    /// compared, so reordering alone is never reported as a gap.
+    fn detect_sequence_gaps(&mut self, event: &PriceChangeEvent) -> Vec<String> {
+        // The last change per asset describes the state after the whole event
+        let mut newest: Vec<&PriceChange> = Vec::new();
+        for change in event.price_changes.iter().rev() {
+            if !newest.iter().any(|c| c.asset_id == change.asset_id)
+                && !self.awaiting_resync.contains(&change.asset_id)
+                && !self.predates_snapshot(&change.asset_id, event)
+                && self.is_newest(&change.asset_id, event)
+            {
+                newest.push(change);
+            }
+        }
+
+        let mut gapped = Vec::new();
+        let obs = self.orderbooks.read();
+        for change in newest {
+            let Some(book) = obs.get(&change.asset_id) else {
+                continue;
+            };
+            let previous = self.book_states.remove(&change.asset_id);
+            let local_bid = book.bids.best().map(|(price, _)| price);
+            let local_ask = book.asks.best().map(|(price, _)| price);
+            if diverges(local_bid, &change.best_bid, self.max_depth)
+                || diverges(local_ask, &change.best_ask, self.max_depth)
+            {
+                warn!(
+                    "[WS {}] Sequence gap for {}: book {} after {} should have best bid/ask {}/{} but has {:?}/{:?} ({}ms), requesting snapshot",
+                    self.market_id,
+                    change.asset_id,
+                    change.hash.as_deref().unwrap_or("?"),
+                    previous.as_ref().and_then(|s| s.hash.as_deref()).unwrap_or("?"),
+                    change.best_bid,
+                    change.best_ask,
+                    local_bid,
+                    local_ask,
+                    event.timestamp
+                );
+                gapped.push(change.asset_id.clone());
+                continue;
+            }
+            self.book_states.insert(
+                change.asset_id.clone(),
+                BookState {
+                    timestamp_ms: event.timestamp.parse().ok(),
+                    hash: change.hash.clone(),
+                },
+            );
+        }
+        gapped
+    }
+
+    /// Ask the owner for a fresh snapshot, once per dropped book
+    fn request_resync(&mut self, asset_id: &str) {
+        if self.awaiting_resync.insert(asset_id.to_string()) {
+            if let Some(ref tx) = self.resync_tx {
+                let _ = tx.send(asset_id.to_string());
+            }
+        }
+    }
+
+    /// Notify listeners of the current best prices for the given assets
+    fn notify_updates<'a>(&self, asset_ids: impl Iterator<Item = &'a str>) {
+        let Some(ref tx) = self.update_tx else {
+            return;
+        };
+        let obs = self.orderbooks.read();
+        for asset_id in asset_ids {
+            if let Some(ob) = obs.get(asset_id) {
+                let _ = tx.send(BookUpdate {
+                    asset_id: asset_id.to_string(),
+                    best_bid: ob.best_bid().map(|(price, _)| price),
+                    best_ask: ob.best_ask().map(|(price, _)| price),
+                });
+            }
+        }
+    }
+
     /// Process orderbook snapshots and update shared orderbooks
     /// Also detects precision from price levels if current precision is 2 (default)
     fn handle_snapshot(&mut self, snapshots: &[BookSnapshot]) {
         if snapshots.is_empty() {
             return;
         }
+        let snapshots = &*self.canonical_snapshots(snapshots);
 
         // First pass: Calculate all precision updates without holding any locks
         // This minimizes lock contention by doing computation outside the critical section
@@ -256,8 +708,28 @@ impl SniperHandler {
                     .entry(snapshot.asset_id.clone())
                     .or_insert_with(|| Orderbook::new(snapshot.asset_id.clone()));
                 orderbook.process_snapshot(&snapshot.bids, &snapshot.asks);
+                if let Some(depth) = self.max_depth {
+                    orderbook.truncate(depth);
+                }
+                self.awaiting_resync.remove(&snapshot.asset_id);
+                match snapshot.timestamp.as_deref().and_then(|t| t.parse::<i64>().ok()) {
+                    Some(timestamp_ms) => {
+                        self.snapshot_ms.insert(snapshot.asset_id.clone(), timestamp_ms);
+                    }
+                    None => {
+                        self.snapshot_ms.remove(&snapshot.asset_id);
+                    }
+                }
+                self.book_states.insert(
+                    snapshot.asset_id.clone(),
+                    BookState {
+                        timestamp_ms: snapshot.timestamp.as_deref().and_then(|t| t.parse().ok()),
+                        hash: snapshot.hash.clone(),
+                    },
+                );
             }
         } // Write lock released here
+        self.drop_invalid_books(snapshots.iter().map(|s| s.asset_id.as_str()));
 
         // Third pass: Apply precision updates (separate lock, brief hold)
         if !precision_updates.is_empty() {
@@ -268,11 +740,14 @@ impl SniperHandler {
         }
 
         self.first_snapshot_received.swap(true, Ordering::Release);
+        self.notify_updates(snapshots.iter().map(|s| s.asset_id.as_str()));
     }
 
     /// Process price change events and update shared orderbooks
     /// Also detects precision upgrades from price levels
     fn handle_price_change(&mut self, event: &PriceChangeEvent) {
+        let event = &*self.canonical_price_change(event);
+
         // First, detect any precision changes from incoming prices
         for change in &event.price_changes {
             let price_precision = decimal_places(&change.price);
@@ -313,6 +788,16 @@ impl SniperHandler {
         // Then update the orderbooks using authoritative best_bid/best_ask from exchange
         let mut obs = self.orderbooks.write();
         for change in &event.price_changes {
+            if self.awaiting_resync.contains(&change.asset_id) {
+                continue;
+            }
+            if self.predates_snapshot(&change.asset_id, event) {
+                debug!(
+                    "[WS {}] Skipping price_change for {} at {}ms, older than its snapshot",
+                    self.market_id, change.asset_id, event.timestamp
+                );
+                continue;
+            }
             let orderbook = obs
                 .entry(change.asset_id.clone())
                 .or_insert_with(|| Orderbook::new(change.asset_id.clone()));
@@ -323,7 +808,22 @@ impl SniperHandler {
                 &change.best_bid,
                 &change.best_ask,
             );
+            if let Some(depth) = self.max_depth {
+                orderbook.truncate(depth);
+            }
         }
+        drop(obs);
+
+        // Drop books that missed a delta before anything trades on them
+        if self.check_crossed {
+            for asset_id in self.detect_sequence_gaps(event) {
+                self.orderbooks.write().remove(&asset_id);
+                self.request_resync(&asset_id);
+            }
+        }
+
+        self.drop_invalid_books(event.price_changes.iter().map(|c| c.asset_id.as_str()));
+        self.notify_updates(event.price_changes.iter().map(|c| c.asset_id.as_str()));
     }
 
     /// Process tick size change events and update precision
@@ -345,16 +845,67 @@ impl SniperHandler {
         }
     }
 
-    /// Process last trade price events
+    /// Process last trade price events and store them on the asset's orderbook.
+    ///
+    /// Trades with an unparseable price, size or timestamp are skipped, and
+    /// only books that already exist are updated; a trade alone never
+    /// creates an empty book.
     fn handle_last_trade_price(&mut self, event: &LastTradePriceEvent) {
         debug!(
             "[WS {}] Trade: {} {} @ {} (size: {})",
             self.market_id, event.side, event.asset_id, event.price, event.size
         );
-        self.last_trade_prices.insert(
-            event.asset_id.clone(),
-            (event.price.clone(), event.size.clone()),
+
+        let price = match parse_price(&event.price, self.price_scale) {
+            Ok(price) => price,
+            Err(e) => {
+                warn!("[WS {}] Skipping trade for {}: {}", self.market_id, event.asset_id, e);
+                return;
+            }
+        };
+        let (Ok(size), Ok(timestamp_ms)) = (event.size.parse(), event.timestamp.parse()) else {
+            warn!(
+                "[WS {}] Skipping malformed trade for {}: size '{}', timestamp '{}'",
+                self.market_id, event.asset_id, event.size, event.timestamp
+            );
+            return;
+        };
+        let trade = LastTrade {
+            price,
+            size,
+            side: event.side.to_uppercase(),
+            timestamp_ms,
+        };
+
+        match self.orderbooks.write().get_mut(&event.asset_id) {
+            Some(orderbook) => orderbook.set_last_trade(trade),
+            None => debug!(
+                "[WS {}] Ignoring trade for {} before its book snapshot",
+                self.market_id, event.asset_id
+            ),
+        }
+    }
+
+    /// Process market resolved events by marking every asset's book closed
+    fn handle_market_resolved(&mut self, event: &MarketResolvedEvent) {
+        info!(
+            "[WS {}] Market closed (winner: {})",
+            self.market_id,
+            event.winning_outcome.as_deref().unwrap_or("unknown")
         );
+
+        let mut obs = self.orderbooks.write();
+        // Events without asset ids close everything this handler tracks
+        if event.assets_ids.is_empty() {
+            obs.values_mut().for_each(Orderbook::mark_closed);
+            return;
+        }
+        // Only books this handler tracks; other assets are not ours to create
+        for asset_id in &event.assets_ids {
+            if let Some(ob) = obs.get_mut(asset_id) {
+                ob.mark_closed();
+            }
+        }
     }
 }
 
@@ -367,6 +918,7 @@ impl MessageHandler<SniperMessage> for SniperHandler {
             SniperMessage::PriceChange(event) => self.handle_price_change(&event),
             SniperMessage::TickSizeChange(event) => self.handle_tick_size_change(&event),
             SniperMessage::LastTradePrice(event) => self.handle_last_trade_price(&event),
+            SniperMessage::MarketResolved(event) => self.handle_market_resolved(&event),
             SniperMessage::Pong => debug!("[WS {}] Pong received", self.market_id),
             SniperMessage::Unknown(_) => {}
         }
@@ -375,6 +927,22 @@ impl MessageHandler<SniperMessage> for SniperHandler {
     }
 }
 
+/// Get the last trade for a token from shared orderbooks
+pub fn last_trade(orderbooks: &SharedOrderbooks, token_id: &str) -> Option<LastTrade> {
+    orderbooks
+        .read()
+        .get(token_id)
+        .and_then(|ob| ob.last_trade().cloned())
+}
+
+/// Check whether the feed has reported any of the given tokens' market closed
+pub fn is_market_closed(orderbooks: &SharedOrderbooks, token_ids: &[String]) -> bool {
+    let obs = orderbooks.read();
+    token_ids
+        .iter()
+        .any(|t| obs.get(t).map(|ob| ob.is_closed()).unwrap_or(false))
+}
+
 // =============================================================================
 // WebSocket Client Builder
 // =============================================================================
@@ -394,40 +962,81 @@ pub async fn build_ws_client(
     tick_size_tx: Option<Sender<TickSizeChangeEvent>>,
     first_snapshot_received: Arc<AtomicBool>,
 ) -> Result<WebSocketClient<SniperRouter, SniperMessage>> {
-    // Local shutdown flag for this WebSocket client only
-    let local_shutdown_flag = Arc::new(AtomicBool::new(true));
-
-    let router = SniperRouter::new(config.market_id.clone());
     let handler = SniperHandler::new(
         config.market_id.clone(),
         orderbooks,
         precisions,
         tick_size_tx,
         first_snapshot_received,
-    );
+    )
+    .with_max_depth(config.max_depth)
+    .with_crossed_book_check(config.check_crossed_books)
+    .with_price_scale(config.price_scale);
+
+    connect_handler(config, handler, None).await
+}
+
+/// Re-subscribe to `asset_ids` so the exchange sends fresh book snapshots.
+///
+/// Use this to answer resync requests from the book validity checks.
+pub fn request_book_resync(
+    client: &WebSocketClient<SniperRouter, SniperMessage>,
+    asset_ids: Vec<String>,
+) -> Result<()> {
+    send_resync(&client.sender(), asset_ids)
+}
+
+fn send_resync(sender: &ClientSender, asset_ids: Vec<String>) -> Result<()> {
+    let subscription = serde_json::to_string(&MarketSubscription::new(asset_ids))?;
+    sender.send(WsMessage::Text(subscription))?;
+    Ok(())
+}
+
+/// Build and connect a client around an already configured handler
+///
+/// Books the handler drops are resynced by the client itself: a responder
+/// thread re-subscribes their asset ids (see `request_book_resync`) and then
+/// forwards them to `resync_observer`, if any.
+async fn connect_handler(
+    config: &MarketTrackerConfig,
+    handler: SniperHandler,
+    resync_observer: Option<Sender<String>>,
+) -> Result<WebSocketClient<SniperRouter, SniperMessage>> {
+    let (resync_tx, resync_rx) = unbounded();
+    let handler = handler.with_resync_notifier(Some(resync_tx));
+
+    // Local shutdown flag for this WebSocket client only
+    let local_shutdown_flag = Arc::new(AtomicBool::new(true));
+
+    let router = SniperRouter::new(config.market_id.clone());
 
     let subscription = MarketSubscription::new(config.token_ids.clone());
     let subscription_json = serde_json::to_string(&subscription)?;
 
     // Create PONG detector for "PONG" text messages
-    // Timeout is 15s (3x heartbeat interval of 5s)
     let pong_detector = Arc::new(TextPongDetector::new("PONG".to_string()));
 
     let market_id_for_route = config.market_id.clone();
     let market_id_for_log = config.market_id.clone();
     let client = WebSocketClientBuilder::new()
-        .url("wss://ws-subscriptions-clob.polymarket.com/ws/market")
+        .url(config.ws_url.clone())
         .router(router, move |routing| {
             routing.handler(SniperRoute::Market(market_id_for_route.clone()), handler)
         })
-        .heartbeat(Duration::from_secs(5), WsMessage::Text("PING".to_string()))
+        .heartbeat(
+            config.connection.heartbeat_interval,
+            WsMessage::Text("PING".to_string()),
+        )
         .pong_detector(pong_detector)
-        .pong_timeout(Duration::from_secs(15))
+        .pong_timeout(config.connection.pong_timeout)
+        .reconnect_strategy(config.connection.reconnect_strategy())
         .subscription(WsMessage::Text(subscription_json))
         .shutdown_flag(local_shutdown_flag)
         .build()
         .await?;
 
+    spawn_resync_responder(resync_rx, client.sender(), config.market_id.clone(), resync_observer);
+
     // Yield to allow the spawned client task to start running
     // This ensures the WebSocket connection begins before we return
     tokio::task::yield_now().await;
@@ -446,6 +1055,141 @@ pub async fn build_ws_client(
     Ok(client)
 }
 
+/// Spawn a thread answering the handler's resync requests.
+///
+/// Requests queued together go out as one subscription. The thread exits
+/// once the handler (and its sender) is dropped or the client shuts down.
+pub(crate) fn spawn_resync_responder(
+    requests: Receiver<String>,
+    sender: ClientSender,
+    market_id: String,
+    observer: Option<Sender<String>>,
+) -> std::thread::JoinHandle<()> {
+    std::thread::spawn(move || {
+        while let Ok(first) = requests.recv() {
+            let mut asset_ids = vec![first];
+            asset_ids.extend(requests.try_iter());
+
+            info!("[WS {}] Requesting fresh snapshots for {:?}", market_id, asset_ids);
+            if let Err(e) = send_resync(&sender, asset_ids.clone()) {
+                warn!("[WS {}] Resync request failed: {}", market_id, e);
+                return;
+            }
+            if let Some(ref observer) = observer {
+                for asset_id in asset_ids {
+                    let _ = observer.send(asset_id);
+                }
+            }
+        }
+        debug!("[WS {}] Resync responder exiting", market_id);
+    })
+}
+
+/// State shared between a sniper WebSocket client and its consumers
+#[derive(Clone)]
+pub struct SniperSharedState {
+    pub orderbooks: SharedOrderbooks,
+    pub precisions: SharedPrecisions,
+    /// Optional channel for forwarding tick_size_change events
+    pub tick_size_tx: Option<Sender<TickSizeChangeEvent>>,
+    /// Set once the first book snapshot has been applied
+    pub first_snapshot_received: Arc<AtomicBool>,
+    /// Optional channel notified on book changes (debounced per `update_debounce`)
+    pub book_update_tx: Option<Sender<BookUpdate>>,
+    /// Optional channel told the asset ids of dropped books once their
+    /// resync has been requested
+    pub resync_tx: Option<Sender<String>>,
+}
+
+impl SniperSharedState {
+    /// Create empty shared state with no tick size channel
+    pub fn new() -> Self {
+        Self {
+            orderbooks: Arc::new(RwLock::new(HashMap::new())),
+            precisions: Arc::new(RwLock::new(HashMap::new())),
+            tick_size_tx: None,
+            first_snapshot_received: Arc::new(AtomicBool::new(false)),
+            book_update_tx: None,
+            resync_tx: None,
+        }
+    }
+
+    /// Forward tick_size_change events to the given channel
+    pub fn with_tick_size_tx(mut self, tx: Sender<TickSizeChangeEvent>) -> Self {
+        self.tick_size_tx = Some(tx);
+        self
+    }
+
+    /// Send book update notifications to the given channel
+    pub fn with_book_updates(mut self, tx: Sender<BookUpdate>) -> Self {
+        self.book_update_tx = Some(tx);
+        self
+    }
+
+    /// Report asset ids of dropped books to the given channel after the
+    /// client has requested their resync
+    pub fn with_resync_requests(mut self, tx: Sender<String>) -> Self {
+        self.resync_tx = Some(tx);
+        self
+    }
+}
+
+impl Default for SniperSharedState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Build a fully configured sniper WebSocket client tied to a global shutdown flag.
+///
+/// Wraps `build_ws_client` (heartbeat, pong tracking, reconnection, subscription)
+/// and watches `shutdown_flag` (true = keep running): once it goes false the
+/// client's local flag is cleared so it stops reconnecting. The global flag is
+/// never written, so `client.shutdown()` cannot trigger a global shutdown.
+///
+/// Book update notifications go to `state.book_update_tx`, passing through a
+/// debouncer first when `config.update_debounce` is set.
+pub async fn build_sniper_ws(
+    config: &MarketTrackerConfig,
+    state: &SniperSharedState,
+    shutdown_flag: Arc<AtomicBool>,
+) -> Result<WebSocketClient<SniperRouter, SniperMessage>> {
+    let update_tx = match (state.book_update_tx.clone(), config.update_debounce) {
+        (Some(output), Some(interval)) => {
+            // Debouncer exits when the handler (and its sender) is dropped
+            let (raw_tx, raw_rx) = unbounded();
+            spawn_update_debouncer(raw_rx, output, interval);
+            Some(raw_tx)
+        }
+        (output, _) => output,
+    };
+
+    let handler = SniperHandler::new(
+        config.market_id.clone(),
+        Arc::clone(&state.orderbooks),
+        Arc::clone(&state.precisions),
+        state.tick_size_tx.clone(),
+        Arc::clone(&state.first_snapshot_received),
+    )
+    .with_max_depth(config.max_depth)
+    .with_update_notifier(update_tx)
+    .with_crossed_book_check(config.check_crossed_books)
+    .with_price_scale(config.price_scale);
+
+    let client = connect_handler(config, handler, state.resync_tx.clone()).await?;
+
+    let local_flag = Arc::clone(client.shutdown_flag());
+    tokio::spawn(async move {
+        // Exit once either flag goes false (global shutdown or client shut down)
+        while shutdown_flag.load(Ordering::Acquire) && local_flag.load(Ordering::Acquire) {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        local_flag.store(false, Ordering::Release);
+    });
+
+    Ok(client)
+}
+
 // =============================================================================
 // Client Event Handling
 // =============================================================================
@@ -469,6 +1213,14 @@ pub fn handle_client_event(event: ClientEvent, market_id: &str) -> bool {
             warn!("[WS {}] Error: {}", market_id, err);
             true
         }
+        ClientEvent::MessageTooLarge { size, max_size } => {
+            warn!("[WS {}] Message too large: {} bytes (max {})", market_id, size, max_size);
+            true
+        }
+        ClientEvent::CircuitOpen => {
+            warn!("[WS {}] Circuit breaker open, stopped reconnecting", market_id);
+            false
+        }
     }
 }
 
@@ -479,6 +1231,41 @@ pub fn handle_client_event(event: ClientEvent, market_id: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mock_market_ws::{MockMarketWsServer, SNAPSHOT};
+
+    fn level(price: &str, size: &str) -> PriceLevel {
+        PriceLevel {
+            price: price.to_string(),
+            size: size.to_string(),
+        }
+    }
+
+    fn test_handler(max_depth: Option<usize>) -> (SniperHandler, SharedOrderbooks) {
+        let orderbooks: SharedOrderbooks = Arc::new(RwLock::new(HashMap::new()));
+        let handler = SniperHandler::new(
+            "test-market".to_string(),
+            orderbooks.clone(),
+            Arc::new(RwLock::new(HashMap::new())),
+            None,
+            Arc::new(AtomicBool::new(false)),
+        )
+        .with_max_depth(max_depth);
+        (handler, orderbooks)
+    }
+
+    fn deep_snapshot(asset_id: &str) -> BookSnapshot {
+        // 10 bid levels 0.40..0.49 and 10 ask levels 0.51..0.60, deliberately unsorted
+        BookSnapshot {
+            market: "test-market".to_string(),
+            asset_id: asset_id.to_string(),
+            bids: (0..10).map(|i| level(&format!("0.{}", 40 + i), "100")).collect(),
+            asks: (0..10).rev().map(|i| level(&format!("0.{}", 51 + i), "100")).collect(),
+            event_type: "book".to_string(),
+            timestamp: None,
+            hash: None,
+            last_trade_price: None,
+        }
+    }
 
     #[test]
     fn test_decimal_places_tick_sizes() {
@@ -572,4 +1359,462 @@ mod tests {
         ];
         assert_eq!(max_precision_in_levels(&levels), 4);
     }
+
+    #[test]
+    fn test_max_depth_truncates_snapshot() {
+        let (mut handler, orderbooks) = test_handler(Some(3));
+        handler
+            .handle(SniperMessage::BookSnapshots(vec![deep_snapshot("tok")]))
+            .unwrap();
+
+        let obs = orderbooks.read();
+        let book = obs.get("tok").unwrap();
+        assert_eq!(book.bids.len(), 3);
+        assert_eq!(book.asks.len(), 3);
+
+        // Best prices survive truncation
+        assert!((book.best_bid().unwrap().0 - 0.49).abs() < 1e-9);
+        assert!((book.best_ask().unwrap().0 - 0.51).abs() < 1e-9);
+        // Worst retained levels are the 3rd best
+        assert!((book.bids.levels()[2].0 - 0.47).abs() < 1e-9);
+        assert!((book.asks.levels()[2].0 - 0.53).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_max_depth_applies_to_price_changes() {
+        let (mut handler, orderbooks) = test_handler(Some(3));
+        handler
+            .handle(SniperMessage::BookSnapshots(vec![deep_snapshot("tok")]))
+            .unwrap();
+
+        // A new best bid pushes the previous 3rd level out of the window
+        let event = PriceChangeEvent {
+            market: "test-market".to_string(),
+            price_changes: vec![super::super::sniper_ws_types::PriceChange {
+                asset_id: "tok".to_string(),
+                price: "0.50".to_string(),
+                size: "25".to_string(),
+                side: "BUY".to_string(),
+                hash: None,
+                best_bid: "0.50".to_string(),
+                best_ask: "0.51".to_string(),
+            }],
+            timestamp: "0".to_string(),
+            event_type: "price_change".to_string(),
+        };
+        handler.handle(SniperMessage::PriceChange(event)).unwrap();
+
+        let obs = orderbooks.read();
+        let book = obs.get("tok").unwrap();
+        assert_eq!(book.bids.len(), 3);
+        assert_eq!(book.best_bid(), Some((0.50, 25.0)));
+        assert!((book.bids.levels()[2].0 - 0.48).abs() < 1e-9);
+    }
+
+    fn price_change(asset_id: &str, side: &str, price: &str, best_bid: &str, best_ask: &str) -> PriceChangeEvent {
+        PriceChangeEvent {
+            market: "test-market".to_string(),
+            price_changes: vec![super::super::sniper_ws_types::PriceChange {
+                asset_id: asset_id.to_string(),
+                price: price.to_string(),
+                size: "25".to_string(),
+                side: side.to_string(),
+                hash: None,
+                best_bid: best_bid.to_string(),
+                best_ask: best_ask.to_string(),
+            }],
+            timestamp: "0".to_string(),
+            event_type: "price_change".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_crossed_book_is_dropped_and_resynced() {
+        let (resync_tx, resync_rx) = unbounded();
+        let (handler, orderbooks) = test_handler(None);
+        let mut handler = handler.with_resync_notifier(Some(resync_tx));
+        handler
+            .handle(SniperMessage::BookSnapshots(vec![deep_snapshot("tok")]))
+            .unwrap();
+
+        // A normal update keeps the book
+        handler
+            .handle(SniperMessage::PriceChange(price_change("tok", "BUY", "0.50", "0.50", "0.51")))
+            .unwrap();
+        assert!(!orderbooks.read().get("tok").unwrap().is_crossed());
+        assert!(resync_rx.try_recv().is_err());
+
+        // Bid through the ask: book is withdrawn and one resync requested
+        let crossed = price_change("tok", "BUY", "0.52", "0.52", "0.51");
+        handler.handle(SniperMessage::PriceChange(crossed.clone())).unwrap();
+        assert!(orderbooks.read().get("tok").is_none());
+        assert_eq!(resync_rx.try_recv().unwrap(), "tok");
+
+        // Further deltas are ignored until the snapshot arrives
+        handler.handle(SniperMessage::PriceChange(crossed)).unwrap();
+        assert!(orderbooks.read().get("tok").is_none());
+        assert!(resync_rx.try_recv().is_err());
+
+        handler
+            .handle(SniperMessage::BookSnapshots(vec![deep_snapshot("tok")]))
+            .unwrap();
+        let obs = orderbooks.read();
+        let book = obs.get("tok").unwrap();
+        assert!(!book.is_crossed());
+        assert_eq!(book.bids.len(), 10);
+    }
+
+    #[test]
+    fn test_crossed_snapshot_is_dropped() {
+        let (resync_tx, resync_rx) = unbounded();
+        let (handler, orderbooks) = test_handler(None);
+        let mut handler = handler.with_resync_notifier(Some(resync_tx));
+
+        let mut crossed = deep_snapshot("tok");
+        crossed.bids.push(level("0.55", "100"));
+        handler.handle(SniperMessage::BookSnapshots(vec![crossed])).unwrap();
+
+        assert!(orderbooks.read().get("tok").is_none());
+        assert_eq!(resync_rx.try_recv().unwrap(), "tok");
+    }
+
+    #[test]
+    fn test_out_of_order_deltas_keep_the_book() {
+        let (resync_tx, resync_rx) = unbounded();
+        let (handler, orderbooks) = test_handler(None);
+        let mut handler = handler.with_resync_notifier(Some(resync_tx));
+
+        let mut snapshot = deep_snapshot("tok");
+        snapshot.timestamp = Some("1000".to_string());
+        handler.handle(SniperMessage::BookSnapshots(vec![snapshot])).unwrap();
+
+        let mut update = price_change("tok", "BUY", "0.50", "0.50", "0.51");
+        update.timestamp = "2000".to_string();
+        handler.handle(SniperMessage::PriceChange(update)).unwrap();
+
+        // Deltas may be delivered out of order; a late one is still applied
+        let mut late = price_change("tok", "SELL", "0.58", "0.50", "0.51");
+        late.timestamp = "1500".to_string();
+        handler.handle(SniperMessage::PriceChange(late)).unwrap();
+        let book = orderbooks.read().get("tok").cloned().unwrap();
+        assert_eq!(book.best_bid(), Some((0.50, 25.0)));
+        assert!(resync_rx.try_recv().is_err());
+
+        // A delta older than the snapshot is already part of it
+        let mut stale = price_change("tok", "BUY", "0.39", "0.50", "0.51");
+        stale.timestamp = "900".to_string();
+        handler.handle(SniperMessage::PriceChange(stale)).unwrap();
+        assert_eq!(orderbooks.read().get("tok").unwrap().bids.len(), book.bids.len());
+        assert!(resync_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_sequence_gap_is_dropped_and_resynced() {
+        let (resync_tx, resync_rx) = unbounded();
+        let (handler, orderbooks) = test_handler(None);
+        let mut handler = handler.with_resync_notifier(Some(resync_tx));
+
+        let mut snapshot = deep_snapshot("tok");
+        snapshot.timestamp = Some("1000".to_string());
+        snapshot.hash = Some("h0".to_string());
+        handler.handle(SniperMessage::BookSnapshots(vec![snapshot])).unwrap();
+
+        let mut update = price_change("tok", "BUY", "0.50", "0.50", "0.51");
+        update.timestamp = "2000".to_string();
+        update.price_changes[0].hash = Some("h1".to_string());
+        handler.handle(SniperMessage::PriceChange(update)).unwrap();
+        assert!(resync_rx.try_recv().is_err());
+
+        // State h3 has best bid 0.49, so the delta pulling 0.50 (h2) was lost
+        let mut after_gap = price_change("tok", "SELL", "0.58", "0.49", "0.51");
+        after_gap.timestamp = "3000".to_string();
+        after_gap.price_changes[0].hash = Some("h3".to_string());
+        handler.handle(SniperMessage::PriceChange(after_gap)).unwrap();
+        assert!(orderbooks.read().get("tok").is_none());
+        assert_eq!(resync_rx.try_recv().unwrap(), "tok");
+
+        // The next snapshot restores the book
+        handler
+            .handle(SniperMessage::BookSnapshots(vec![deep_snapshot("tok")]))
+            .unwrap();
+        assert_eq!(orderbooks.read().get("tok").unwrap().bids.len(), 10);
+    }
+
+    #[test]
+    fn test_crossed_book_check_can_be_disabled() {
+        let (handler, orderbooks) = test_handler(None);
+        let mut handler = handler.with_crossed_book_check(false);
+        handler
+            .handle(SniperMessage::BookSnapshots(vec![deep_snapshot("tok")]))
+            .unwrap();
+        handler
+            .handle(SniperMessage::PriceChange(price_change("tok", "BUY", "0.52", "0.52", "0.51")))
+            .unwrap();
+        assert!(orderbooks.read().get("tok").unwrap().is_crossed());
+    }
+
+    #[test]
+    fn test_full_depth_by_default() {
+        let (mut handler, orderbooks) = test_handler(None);
+        handler
+            .handle(SniperMessage::BookSnapshots(vec![deep_snapshot("tok")]))
+            .unwrap();
+
+        let obs = orderbooks.read();
+        assert_eq!(obs.get("tok").unwrap().bids.len(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_last_trade_price_updates_shared_state() {
+        let (mut handler, orderbooks) = test_handler(None);
+        handler
+            .handle(SniperMessage::BookSnapshots(vec![deep_snapshot("tok")]))
+            .unwrap();
+        assert_eq!(last_trade(&orderbooks, "tok"), None);
+
+        let text = r#"{"asset_id":"tok","event_type":"last_trade_price","fee_rate_bps":"0","market":"0xabc","price":"0.456","side":"buy","size":"219.21","timestamp":"1750428146322"}"#;
+        let message = SniperRouter::new("test-market".to_string())
+            .parse(WsMessage::Text(text.to_string()))
+            .await
+            .unwrap();
+        handler.handle(message).unwrap();
+
+        let trade = last_trade(&orderbooks, "tok").unwrap();
+        assert!((trade.price - 0.456).abs() < 1e-9);
+        assert!((trade.size - 219.21).abs() < 1e-9);
+        assert_eq!(trade.side, "BUY");
+        assert_eq!(trade.timestamp_ms, 1750428146322);
+
+        // Later trades overwrite, and book snapshots don't clear it
+        handler
+            .handle(SniperMessage::BookSnapshots(vec![deep_snapshot("tok")]))
+            .unwrap();
+        assert_eq!(last_trade(&orderbooks, "tok").unwrap().timestamp_ms, 1750428146322);
+    }
+
+    fn trade(asset_id: &str, price: &str, size: &str) -> LastTradePriceEvent {
+        LastTradePriceEvent {
+            event_type: "last_trade_price".to_string(),
+            asset_id: asset_id.to_string(),
+            market: "0xabc".to_string(),
+            price: price.to_string(),
+            size: size.to_string(),
+            side: "BUY".to_string(),
+            timestamp: "1750428146322".to_string(),
+            fee_rate_bps: None,
+        }
+    }
+
+    #[test]
+    fn test_malformed_trades_and_unknown_books_are_skipped() {
+        let (mut handler, orderbooks) = test_handler(None);
+
+        // No book yet: nothing is stored and no empty book appears
+        handler
+            .handle(SniperMessage::LastTradePrice(trade("tok", "0.45", "10")))
+            .unwrap();
+        assert!(orderbooks.read().is_empty());
+
+        handler
+            .handle(SniperMessage::BookSnapshots(vec![deep_snapshot("tok")]))
+            .unwrap();
+        for (price, size) in [("abc", "10"), ("0.45", "ten"), ("1.5", "10")] {
+            handler
+                .handle(SniperMessage::LastTradePrice(trade("tok", price, size)))
+                .unwrap();
+        }
+        assert_eq!(last_trade(&orderbooks, "tok"), None);
+
+        handler
+            .handle(SniperMessage::LastTradePrice(trade("tok", "0.45", "10")))
+            .unwrap();
+        assert!((last_trade(&orderbooks, "tok").unwrap().price - 0.45).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_subscription_opts_in_to_market_resolved() {
+        let json = serde_json::to_value(MarketSubscription::new(vec!["yes".to_string()])).unwrap();
+        assert_eq!(json["type"], "market");
+        assert_eq!(json["custom_feature_enabled"], true);
+    }
+
+    #[tokio::test]
+    async fn test_market_resolved_marks_market_closed() {
+        let (mut handler, orderbooks) = test_handler(None);
+        handler
+            .handle(SniperMessage::BookSnapshots(vec![
+                deep_snapshot("yes"),
+                deep_snapshot("no"),
+            ]))
+            .unwrap();
+        let tokens = vec!["yes".to_string(), "no".to_string()];
+        assert!(!is_market_closed(&orderbooks, &tokens));
+
+        let text = r#"{"event_type":"market_resolved","market":"0xabc","assets_ids":["yes","no","other"],"winning_asset_id":"yes","winning_outcome":"Yes","timestamp":"1750428146322"}"#;
+        let message = SniperRouter::new("test-market".to_string())
+            .parse(WsMessage::Text(text.to_string()))
+            .await
+            .unwrap();
+        assert!(matches!(message, SniperMessage::MarketResolved(_)));
+        handler.handle(message).unwrap();
+
+        assert!(is_market_closed(&orderbooks, &tokens));
+        assert!(orderbooks.read().get("no").unwrap().is_closed());
+        // No book is created for an asset the handler never tracked
+        assert!(orderbooks.read().get("other").is_none());
+    }
+
+    fn test_config(url: String) -> MarketTrackerConfig {
+        MarketTrackerConfig::new(
+            "test-market".to_string(),
+            "Test?".to_string(),
+            None,
+            vec!["yes".to_string()],
+            vec!["Yes".to_string()],
+            "2030-01-01T00:00:00Z",
+        )
+        .unwrap()
+        .with_ws_url(url)
+    }
+
+    #[tokio::test]
+    async fn test_dropped_book_is_resynced_by_the_client() {
+        let crossed = r#"[{"market":"0xabc","asset_id":"yes","bids":[{"price":"0.60","size":"10"}],"asks":[{"price":"0.55","size":"10"}],"event_type":"book"}]"#;
+        let server = MockMarketWsServer::scripted(vec![crossed, SNAPSHOT]).await;
+        let (observer_tx, observer_rx) = unbounded();
+        let state = SniperSharedState::new().with_resync_requests(observer_tx);
+
+        let config = test_config(server.url());
+        let client = build_sniper_ws(&config, &state, Arc::new(AtomicBool::new(true)))
+            .await
+            .unwrap();
+
+        // The crossed snapshot is dropped and re-requested without help from the caller
+        let start = std::time::Instant::now();
+        while !state.orderbooks.read().contains_key("yes") && start.elapsed() < Duration::from_secs(5) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let book = state.orderbooks.read().get("yes").cloned().unwrap();
+        assert!(!book.is_crossed());
+        assert_eq!(book.best_bid(), Some((0.45, 10.0)));
+        assert_eq!(server.subscriptions(), 2);
+        assert_eq!(observer_rx.recv_timeout(Duration::from_secs(1)).unwrap(), "yes");
+        client.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cents_feed_is_canonicalized_end_to_end() {
+        let cents = r#"[{"market":"0xabc","asset_id":"yes","bids":[{"price":"45","size":"10"},{"price":"150","size":"10"}],"asks":[{"price":"55.5","size":"10"}],"event_type":"book"}]"#;
+        let server = MockMarketWsServer::scripted(vec![cents]).await;
+        let state = SniperSharedState::new();
+        let config = test_config(server.url()).with_price_scale(PriceScale::Cents);
+
+        let client = build_sniper_ws(&config, &state, Arc::new(AtomicBool::new(true)))
+            .await
+            .unwrap();
+
+        let start = std::time::Instant::now();
+        while !state.orderbooks.read().contains_key("yes") && start.elapsed() < Duration::from_secs(5) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let book = state.orderbooks.read().get("yes").cloned().unwrap();
+        assert_eq!(book.best_bid(), Some((0.45, 10.0)));
+        assert_eq!(book.best_ask(), Some((0.555, 10.0)));
+        // 150 cents is out of range and never reaches the book
+        assert_eq!(book.bids.len(), 1);
+        assert_eq!(state.precisions.read().get("yes"), Some(&3));
+        client.shutdown().await.unwrap();
+    }
+
+    #[test]
+    fn test_out_of_range_prices_are_dropped_in_unit_scale() {
+        let (mut handler, orderbooks) = test_handler(None);
+        handler
+            .handle(SniperMessage::BookSnapshots(vec![deep_snapshot("tok")]))
+            .unwrap();
+
+        handler
+            .handle(SniperMessage::PriceChange(price_change("tok", "BUY", "45", "0.49", "0.51")))
+            .unwrap();
+
+        let obs = orderbooks.read();
+        assert_eq!(obs.get("tok").unwrap().bids.len(), 10);
+    }
+
+    #[test]
+    fn test_valid_unit_prices_are_not_copied() {
+        let (handler, _) = test_handler(None);
+        let snapshots = vec![deep_snapshot("tok")];
+        assert!(matches!(handler.canonical_snapshots(&snapshots), Cow::Borrowed(_)));
+        let valid = price_change("tok", "BUY", "0.45", "0.49", "");
+        assert!(matches!(handler.canonical_price_change(&valid), Cow::Borrowed(_)));
+
+        let invalid = price_change("tok", "BUY", "45", "0.49", "0.51");
+        assert!(handler.canonical_price_change(&invalid).price_changes.is_empty());
+
+        let handler = handler.with_price_scale(PriceScale::Cents);
+        let cents = price_change("tok", "BUY", "45", "49", "51");
+        assert_eq!(handler.canonical_price_change(&cents).price_changes[0].price, "0.45");
+    }
+
+    #[tokio::test]
+    async fn test_build_sniper_ws_minimal_config() {
+        let server = MockMarketWsServer::start().await;
+        let config = test_config(server.url());
+        let state = SniperSharedState::new();
+        let shutdown_flag = Arc::new(AtomicBool::new(true));
+
+        let client = build_sniper_ws(&config, &state, shutdown_flag.clone())
+            .await
+            .unwrap();
+        assert!(client.is_connected());
+
+        // Subscription reached the server and the snapshot was applied
+        let start = std::time::Instant::now();
+        while !state.first_snapshot_received.load(Ordering::Acquire)
+            && start.elapsed() < Duration::from_secs(5)
+        {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(state.orderbooks.read().contains_key("yes"));
+
+        // Global shutdown propagates to the client's local flag
+        shutdown_flag.store(false, Ordering::Release);
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(!client.shutdown_flag().load(Ordering::Acquire));
+        client.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_connection_config_reaches_client() {
+        let server = MockMarketWsServer::start().await;
+        let connection = SniperConnectionConfig {
+            heartbeat_interval: Duration::from_millis(750),
+            pong_timeout: Duration::from_secs(3),
+            ..SniperConnectionConfig::default()
+        };
+        let config = MarketTrackerConfig::new(
+            "test-market".to_string(),
+            "Test?".to_string(),
+            None,
+            vec!["yes".to_string()],
+            vec!["Yes".to_string()],
+            "2030-01-01T00:00:00Z",
+        )
+        .unwrap()
+        .with_ws_url(server.url())
+        .with_connection(connection);
+        let state = SniperSharedState::new();
+
+        let client = build_sniper_ws(&config, &state, Arc::new(AtomicBool::new(true)))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            client.config().heartbeat_interval(),
+            Some(Duration::from_millis(750))
+        );
+        assert_eq!(client.config().pong_timeout(), Some(Duration::from_secs(3)));
+        client.shutdown().await.unwrap();
+    }
 }
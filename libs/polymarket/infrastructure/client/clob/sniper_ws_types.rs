@@ -5,6 +5,7 @@
 //! - price_change: Incremental orderbook updates
 //! - tick_size_change: Tick size changes (price reaches limits)
 //! - last_trade_price: Trade execution events
+//! - market_resolved: Market closed/resolved mid-session
 
 use serde::{Deserialize, Serialize};
 use super::types::PriceLevel;
@@ -15,6 +16,8 @@ pub struct MarketSubscription {
     pub assets_ids: Vec<String>,
     #[serde(rename = "type")]
     pub msg_type: String,
+    /// Opts in to the extra events (e.g. `market_resolved`)
+    pub custom_feature_enabled: bool,
 }
 
 impl MarketSubscription {
@@ -22,6 +25,7 @@ impl MarketSubscription {
         Self {
             assets_ids: token_ids,
             msg_type: "market".to_string(),
+            custom_feature_enabled: true,
         }
     }
 }
@@ -104,6 +108,21 @@ pub struct LastTradePriceEvent {
     pub fee_rate_bps: Option<String>,
 }
 
+/// Market resolved event - emitted once when a market closes and resolves
+#[derive(Debug, Clone, Deserialize)]
+pub struct MarketResolvedEvent {
+    pub event_type: String,
+    pub market: String,
+    #[serde(default)]
+    pub assets_ids: Vec<String>,
+    #[serde(default)]
+    pub winning_asset_id: Option<String>,
+    #[serde(default)]
+    pub winning_outcome: Option<String>,
+    #[serde(default)]
+    pub timestamp: Option<String>,
+}
+
 /// Union type for all incoming WebSocket messages
 #[derive(Debug)]
 pub enum SniperMessage {
@@ -115,6 +134,8 @@ pub enum SniperMessage {
     TickSizeChange(TickSizeChangeEvent),
     /// Last trade price event
     LastTradePrice(LastTradePriceEvent),
+    /// Market resolved/closed event
+    MarketResolved(MarketResolvedEvent),
     /// Pong response to our ping
     Pong,
     /// Unknown/unhandled message
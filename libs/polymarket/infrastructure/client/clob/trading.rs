@@ -27,21 +27,49 @@
 //! ```
 
 use super::super::auth::PolymarketAuth;
-use super::order_builder::OrderBuilder;
-use super::rest::{RestClient, RestError};
+use super::nonce::{NonceManager, NonceSource};
+use super::order_builder::{OrderBuilder, TimeInForce};
+use super::rest::{
+    ClockSyncConfig, EndpointClass, HttpPoolConfig, RateLimiter, RestClient, RestError,
+};
 use super::types::{
     ApiCredentials, AssetType, BalanceAllowance, BalanceAllowanceParams, CancelResponse, OpenOrder,
-    OpenOrderParams, OrderPlacementResponse, OrderType, Side, Trade, TradeParams,
+    OpenOrderParams, OrderPlacementResponse, OrderType, Side, SignatureType, Trade, TradeParams,
 };
 use super::POLYGON_CHAIN_ID;
+use crate::domain::{DailyStats, RiskError};
+use crate::infrastructure::config::BotConfig;
+use crate::infrastructure::database::{DatabaseError, MarketDatabase, OrderLog};
 use dashmap::DashMap;
+use chrono::{NaiveTime, Utc};
 use ethers::types::Address;
+use parking_lot::Mutex;
+use std::collections::{HashMap, HashSet};
 use std::env;
+use std::sync::Arc;
 use thiserror::Error;
 use tracing::{debug, info, warn};
 
 const DEFAULT_CLOB_URL: &str = "https://clob.polymarket.com";
 
+/// `MAX_ORDERS_PER_DAY`, or 0 (unlimited) when unset
+fn env_max_orders_per_day() -> Result<usize> {
+    match env::var("MAX_ORDERS_PER_DAY") {
+        Ok(v) => v.trim().parse::<usize>().map_err(|_| {
+            TradingError::InvalidParameter(format!("MAX_ORDERS_PER_DAY must be a count, got: {}", v))
+        }),
+        Err(_) => Ok(0),
+    }
+}
+
+/// `SIGNATURE_TYPE`, or Gnosis Safe when unset
+fn env_signature_type() -> Result<SignatureType> {
+    match env::var("SIGNATURE_TYPE") {
+        Ok(v) => v.parse::<SignatureType>().map_err(TradingError::InvalidParameter),
+        Err(_) => Ok(SignatureType::PolyGnosisSafe),
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum TradingError {
     #[error("Environment variable '{0}' not set")]
@@ -58,6 +86,19 @@ pub enum TradingError {
 
     #[error("Invalid parameter: {0}")]
     InvalidParameter(String),
+
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error("Risk limit: {0}")]
+    RiskLimit(#[from] RiskError),
+}
+
+impl TradingError {
+    /// The exchange refused a post-only order that would have crossed the book
+    pub fn is_post_only_rejection(&self) -> bool {
+        matches!(self, TradingError::RestError(e) if e.is_post_only_rejection())
+    }
 }
 
 pub type Result<T> = std::result::Result<T, TradingError>;
@@ -128,6 +169,14 @@ pub struct TradingClient {
     signer_addr: Address,
     proxy_addr: Option<Address>,
     neg_risk_cache: DashMap<String, bool>,
+    nonce_manager: NonceManager,
+    /// Used to sign orders and to query the collateral balance
+    signature_type: SignatureType,
+    /// Orders allowed per UTC day (0 = unlimited)
+    max_orders_per_day: usize,
+    daily_stats: Mutex<DailyStats>,
+    /// Ids of orders placed through this client (not yet seen cancelled)
+    placed_orders: Mutex<HashSet<String>>,
 }
 
 impl TradingClient {
@@ -140,9 +189,41 @@ impl TradingClient {
     /// - `PROXY_WALLET`: Polymarket proxy wallet (if different from signer)
     /// - `API_KEY`, `API_SECRET`, `API_PASSPHRASE`: Pre-existing API credentials
     /// - `CLOB_URL`: Custom CLOB endpoint (defaults to mainnet)
+    /// - `ORDER_LOG_ENABLED`: `true` to record placed orders in `orders_log`
+    ///   (requires `DATABASE_URL`)
+    /// - `MAX_ORDERS_PER_DAY`: Reject placements past this many per UTC day
+    ///   (0 or unset = unlimited)
+    /// - `SIGNATURE_TYPE`: `eoa`, `poly_proxy` or `poly_gnosis_safe` (or 0/1/2;
+    ///   defaults to `poly_gnosis_safe`); only used without a config, see
+    ///   `from_config`
+    /// - `HTTP_POOL_MAX_IDLE_PER_HOST`, `HTTP_POOL_IDLE_TIMEOUT_SECS`: HTTP
+    ///   connection pool tuning (see `HttpPoolConfig::from_env`)
+    ///
+    /// With a daily cap set, orders are also logged whenever `DATABASE_URL`
+    /// is set, and today's count is rebuilt from `orders_log` so a restart
+    /// does not reset the cap.
     pub async fn from_env() -> Result<Self> {
         dotenv::dotenv().ok();
+        Self::from_env_with(env_max_orders_per_day()?, env_signature_type()?).await
+    }
 
+    /// Create a trading client from environment variables with the settings
+    /// of `config`
+    ///
+    /// A non-zero `risk.max_orders_per_day` takes precedence over
+    /// `MAX_ORDERS_PER_DAY`, and orders are signed with
+    /// `polymarket.signature_type` (`SIGNATURE_TYPE` is not read). See
+    /// `from_env` for the other env vars read.
+    pub async fn from_config(config: &BotConfig) -> Result<Self> {
+        dotenv::dotenv().ok();
+        let max_orders_per_day = match config.risk.max_orders_per_day {
+            0 => env_max_orders_per_day()?,
+            configured => configured,
+        };
+        Self::from_env_with(max_orders_per_day, config.polymarket.signature_type).await
+    }
+
+    async fn from_env_with(max_orders_per_day: usize, signature_type: SignatureType) -> Result<Self> {
         let private_key = env::var("PRIVATE_KEY")
             .map_err(|_| TradingError::EnvVarMissing("PRIVATE_KEY".to_string()))?;
 
@@ -162,7 +243,53 @@ impl TradingClient {
             _ => None,
         };
 
-        Self::new(&private_key, proxy_wallet.as_deref(), &clob_url, existing_creds).await
+        let pool = HttpPoolConfig::from_env();
+        let client = Self::with_pool_config(
+            &private_key,
+            proxy_wallet.as_deref(),
+            &clob_url,
+            existing_creds,
+            pool,
+        )
+        .await?
+            .with_daily_order_limit(max_orders_per_day)
+            .with_signature_type(signature_type);
+
+        let order_log_enabled = env::var("ORDER_LOG_ENABLED")
+            .map(|v| matches!(v.trim().to_lowercase().as_str(), "true" | "1"))
+            .unwrap_or(false);
+        // A daily cap needs the log to rebuild its count after a restart
+        if !order_log_enabled && max_orders_per_day == 0 {
+            return Ok(client);
+        }
+        let db_url = match env::var("DATABASE_URL") {
+            Ok(url) => url,
+            Err(_) if order_log_enabled => {
+                return Err(TradingError::EnvVarMissing("DATABASE_URL".to_string()))
+            }
+            Err(_) => {
+                warn!("DATABASE_URL not set: the daily order count resets on restart");
+                return Ok(client);
+            }
+        };
+        let db = MarketDatabase::new(&db_url).await?;
+        info!("Order log enabled: recording placed orders in orders_log");
+
+        let client = if max_orders_per_day > 0 {
+            let midnight = Utc::now()
+                .date_naive()
+                .and_time(NaiveTime::MIN)
+                .and_utc();
+            let placed_today = db.count_orders_since(midnight).await?;
+            info!(
+                "{} of {} daily orders already placed today",
+                placed_today, max_orders_per_day
+            );
+            client.with_orders_placed_today(placed_today)
+        } else {
+            client
+        };
+        Ok(client.with_order_log(Arc::new(db)))
     }
 
     /// Create a new trading client with explicit parameters
@@ -177,6 +304,26 @@ impl TradingClient {
         proxy_wallet: Option<&str>,
         clob_url: &str,
         existing_creds: Option<ApiCredentials>,
+    ) -> Result<Self> {
+        Self::with_pool_config(
+            private_key,
+            proxy_wallet,
+            clob_url,
+            existing_creds,
+            HttpPoolConfig::default(),
+        )
+        .await
+    }
+
+    /// Create a trading client whose HTTP client uses the `pool` settings
+    ///
+    /// See `new` for the other arguments.
+    pub async fn with_pool_config(
+        private_key: &str,
+        proxy_wallet: Option<&str>,
+        clob_url: &str,
+        existing_creds: Option<ApiCredentials>,
+        pool: HttpPoolConfig,
     ) -> Result<Self> {
         let mut auth = PolymarketAuth::new(private_key, POLYGON_CHAIN_ID)?;
         // address() is guaranteed to return Some when auth is created via new()
@@ -190,7 +337,7 @@ impl TradingClient {
             None => None,
         };
 
-        let rest = RestClient::new(clob_url);
+        let rest = RestClient::with_pool_config(clob_url, pool);
 
         // Set up API credentials
         if let Some(creds) = existing_creds {
@@ -213,6 +360,17 @@ impl TradingClient {
             }
         }
 
+        // Align signing timestamps with the exchange clock; a skew past the
+        // limit means the host clock is broken and orders would be rejected
+        match rest
+            .check_clock_sync(&ClockSyncConfig::default().with_apply_offset(true))
+            .await
+        {
+            Ok(skew) => debug!("Clock skew against CLOB: {}s", skew.offset_secs),
+            Err(e @ RestError::ClockSkew { .. }) => return Err(e.into()),
+            Err(e) => warn!("⚠️  Clock sync check failed: {}", e),
+        }
+
         debug!(
             "TradingClient initialized: signer={:?}, proxy={:?}",
             signer_addr, proxy_addr
@@ -224,9 +382,79 @@ impl TradingClient {
             signer_addr,
             proxy_addr,
             neg_risk_cache: DashMap::new(),
+            nonce_manager: NonceManager::new(),
+            signature_type: SignatureType::PolyGnosisSafe,
+            max_orders_per_day: 0,
+            daily_stats: Mutex::new(DailyStats::new(0, 0, 0.0)),
+            placed_orders: Mutex::new(HashSet::new()),
         })
     }
 
+    /// Reject placements once `max_orders_per_day` orders were sent this
+    /// UTC day (0 = unlimited)
+    pub fn with_daily_order_limit(mut self, max_orders_per_day: usize) -> Self {
+        self.max_orders_per_day = max_orders_per_day;
+        self
+    }
+
+    /// Start today's order count at `count` (e.g. rebuilt from `orders_log`
+    /// after a restart)
+    pub fn with_orders_placed_today(self, count: usize) -> Self {
+        self.daily_stats.lock().orders_placed = count;
+        self
+    }
+
+    /// Sign orders and query balances as `signature_type` (default: Gnosis Safe)
+    pub fn with_signature_type(mut self, signature_type: SignatureType) -> Self {
+        self.signature_type = signature_type;
+        self
+    }
+
+    /// Get the signature type orders are signed with
+    pub fn signature_type(&self) -> SignatureType {
+        self.signature_type
+    }
+
+    /// Count `count` orders against today's cap before they are sent
+    ///
+    /// All or none are counted, so a batch is never partially admitted.
+    fn reserve_orders(&self, count: usize) -> Result<()> {
+        let mut stats = self.daily_stats.lock();
+        let mut reserved = stats.clone();
+        let now = Utc::now();
+        for _ in 0..count {
+            if let Err(e) = reserved.record_order(self.max_orders_per_day, now) {
+                warn!("Order rejected: {}", e);
+                return Err(e.into());
+            }
+        }
+        *stats = reserved;
+        Ok(())
+    }
+
+    /// Remember the ids of successfully placed orders
+    fn track_placed<'a>(&self, responses: impl IntoIterator<Item = &'a OrderPlacementResponse>) {
+        let mut placed = self.placed_orders.lock();
+        placed.extend(
+            responses
+                .into_iter()
+                .filter(|r| r.success)
+                .filter_map(|r| r.order_id.clone()),
+        );
+    }
+
+    /// Rate limit requests of `class` with `limiter` (unlimited by default)
+    pub fn with_rate_limiter(mut self, class: EndpointClass, limiter: RateLimiter) -> Self {
+        self.rest = self.rest.with_rate_limiter(class, limiter);
+        self
+    }
+
+    /// Record every placed order (and its result) in `order_log`
+    pub fn with_order_log(mut self, order_log: Arc<dyn OrderLog>) -> Self {
+        self.rest = self.rest.with_order_log(order_log);
+        self
+    }
+
     /// Get the signer address
     pub fn signer_address(&self) -> Address {
         self.signer_addr
@@ -260,19 +488,20 @@ impl TradingClient {
 
     /// Create an OrderBuilder for the given token
     ///
-    /// Always uses Gnosis Safe signature type (signature_type=2) which is
-    /// required for browser wallet users with proxy wallets.
+    /// Signs with the client's `signature_type`; the default Gnosis Safe type
+    /// is what browser wallet users with proxy wallets need.
     fn order_builder(&self, token_id: &str) -> OrderBuilder {
         let neg_risk = self.get_neg_risk(token_id);
         let maker_addr = self.maker_address();
 
-        // Always use Gnosis Safe signature type
         OrderBuilder::new_gnosis_safe(
             self.signer_addr,
             maker_addr,
             POLYGON_CHAIN_ID,
             neg_risk,
         )
+        .with_signature_type(self.signature_type)
+        .with_clock_offset(self.rest.clock_offset())
     }
 
     /// Place a buy order (GTC by default)
@@ -374,6 +603,77 @@ impl TradingClient {
         order_type: OrderType,
         fee_rate_bps: Option<u64>,
     ) -> Result<OrderPlacementResponse> {
+        self.submit_order(OrderParams {
+            token_id,
+            price,
+            size,
+            side,
+            time_in_force: time_in_force(order_type)?,
+            fee_rate_bps,
+            post_only: false,
+        })
+        .await
+    }
+
+    /// Place a GTD order resting until `expiration` (unix seconds, must be in the future)
+    pub async fn place_gtd_order(
+        &self,
+        token_id: &str,
+        price: f64,
+        size: f64,
+        side: Side,
+        expiration: u64,
+    ) -> Result<OrderPlacementResponse> {
+        self.submit_order(OrderParams {
+            token_id,
+            price,
+            size,
+            side,
+            time_in_force: TimeInForce::Gtd { expiration },
+            fee_rate_bps: None,
+            post_only: false,
+        })
+        .await
+    }
+
+    /// Place a maker-only order with custom fee rate
+    ///
+    /// The exchange rejects the order instead of filling it against the book
+    /// if it would cross the spread; check `RestError::is_post_only_rejection`.
+    /// `order_type` must be GTC; for a post-only GTD order use
+    /// `order(..).gtd(expiration).post_only()`.
+    pub async fn place_post_only_order(
+        &self,
+        token_id: &str,
+        price: f64,
+        size: f64,
+        side: Side,
+        order_type: OrderType,
+        fee_rate_bps: Option<u64>,
+    ) -> Result<OrderPlacementResponse> {
+        self.submit_order(OrderParams {
+            token_id,
+            price,
+            size,
+            side,
+            time_in_force: time_in_force(order_type)?,
+            fee_rate_bps,
+            post_only: true,
+        })
+        .await
+    }
+
+    async fn submit_order(&self, params: OrderParams<'_>) -> Result<OrderPlacementResponse> {
+        let OrderParams {
+            token_id,
+            price,
+            size,
+            side,
+            time_in_force,
+            fee_rate_bps,
+            post_only,
+        } = params;
+
         // Validate inputs
         if price <= 0.0 || price >= 1.0 {
             return Err(TradingError::InvalidParameter(format!(
@@ -388,26 +688,59 @@ impl TradingClient {
             )));
         }
 
+        self.reserve_orders(1)?;
+
         let order_builder = self.order_builder(token_id);
+        let nonce = self.nonce_manager.current(self).await?;
+        let timestamp = self.rest.timestamp();
+
+        debug!(
+            "Building signed order: token={}, price={}, size={}, side={:?}, nonce={}",
+            token_id, price, size, side, nonce
+        );
+
+        let signed_order = order_builder
+            .build_signed_order(&self.auth, token_id, price, size, side, nonce, fee_rate_bps, time_in_force)
+            .and_then(|signed_order| signed_order.with_post_only(post_only))
+            .map_err(|e| RestError::ApiError(format!("Failed to build order: {}", e)))?;
 
         let result = self
             .rest
-            .place_signed_order(
-                &self.auth,
-                &order_builder,
-                token_id,
-                price,
-                size,
-                side,
-                order_type,
-                fee_rate_bps,
-            )
-            .await?;
+            .submit_signed_order(&self.auth, &signed_order, timestamp)
+            .await;
+        self.refresh_nonce_on_rejection(&result, nonce).await;
+        self.track_placed(result.as_ref().ok());
+
+        Ok(result?)
+    }
+
+    /// Re-read the exchange nonce if `result` is a nonce rejection
+    ///
+    /// A rejection means the exchange nonce moved (e.g. `incrementNonce`),
+    /// so the next placement must be signed with the new value.
+    async fn refresh_nonce_on_rejection<T>(
+        &self,
+        result: &std::result::Result<T, RestError>,
+        nonce: u64,
+    ) {
+        if let Err(RestError::ApiError(msg) | RestError::HttpStatus { message: msg, .. }) = result {
+            if msg.to_lowercase().contains("nonce") {
+                warn!("Order rejected for nonce {}, refreshing from exchange", nonce);
+                if let Err(e) = self.nonce_manager.refresh(self).await {
+                    warn!("Nonce refresh failed: {}", e);
+                }
+            }
+        }
+    }
 
-        Ok(result)
+    /// Drop the cached nonce after an on-chain `incrementNonce`
+    ///
+    /// The next placement re-fetches the exchange nonce.
+    pub async fn invalidate_nonce(&self) {
+        self.nonce_manager.invalidate().await;
     }
 
-    /// Place multiple orders in a single batch (max 15)
+    /// Place multiple orders, split into batches of at most `MAX_BATCH_ORDERS`
     pub async fn place_batch_orders(
         &self,
         orders: Vec<(String, f64, f64, Side, OrderType)>,
@@ -420,12 +753,6 @@ impl TradingClient {
             });
         }
 
-        if orders.len() > 15 {
-            return Err(TradingError::InvalidParameter(
-                "Maximum 15 orders per batch".to_string(),
-            ));
-        }
-
         for (token_id, price, size, _, _) in &orders {
             if *price <= 0.0 || *price >= 1.0 {
                 return Err(TradingError::InvalidParameter(format!(
@@ -441,14 +768,23 @@ impl TradingClient {
             }
         }
 
+        self.reserve_orders(orders.len())?;
+
         let token_ids: Vec<String> = orders.iter().map(|(id, _, _, _, _)| id.clone()).collect();
         let order_builder = self.order_builder(&orders[0].0);
+        let nonce = self.nonce_manager.current(self).await?;
 
-        let responses = self
+        let result = self
             .rest
-            .place_batch_orders(&self.auth, &order_builder, orders, fee_rate_bps)
-            .await
-            .map_err(TradingError::from)?;
+            .place_batch_orders(&self.auth, &order_builder, orders, fee_rate_bps, nonce)
+            .await;
+        self.refresh_nonce_on_rejection(&result, nonce).await;
+        match &result {
+            Ok(responses) => self.track_placed(responses),
+            Err(RestError::PartialBatch { placed, .. }) => self.track_placed(placed),
+            Err(_) => {}
+        }
+        let responses = result.map_err(TradingError::from)?;
 
         let mut succeeded = Vec::new();
         let mut failed = Vec::new();
@@ -470,12 +806,16 @@ impl TradingClient {
         token_id: &str,
         amount_usd: f64,
     ) -> Result<OrderPlacementResponse> {
+        self.reserve_orders(1)?;
         let order_builder = self.order_builder(token_id);
+        let nonce = self.nonce_manager.current(self).await?;
         let result = self
             .rest
-            .place_signed_market_buy(&self.auth, &order_builder, token_id, amount_usd)
-            .await?;
-        Ok(result)
+            .place_signed_market_buy(&self.auth, &order_builder, token_id, amount_usd, nonce)
+            .await;
+        self.refresh_nonce_on_rejection(&result, nonce).await;
+        self.track_placed(result.as_ref().ok());
+        Ok(result?)
     }
 
     /// Place a market sell order at best bid
@@ -484,12 +824,16 @@ impl TradingClient {
         token_id: &str,
         size: f64,
     ) -> Result<OrderPlacementResponse> {
+        self.reserve_orders(1)?;
         let order_builder = self.order_builder(token_id);
+        let nonce = self.nonce_manager.current(self).await?;
         let result = self
             .rest
-            .place_signed_market_sell(&self.auth, &order_builder, token_id, size)
-            .await?;
-        Ok(result)
+            .place_signed_market_sell(&self.auth, &order_builder, token_id, size, nonce)
+            .await;
+        self.refresh_nonce_on_rejection(&result, nonce).await;
+        self.track_placed(result.as_ref().ok());
+        Ok(result?)
     }
 
     /// Get access to the underlying REST client for advanced operations
@@ -531,6 +875,40 @@ impl TradingClient {
             .map_err(TradingError::from)
     }
 
+    /// Cancel the still-open orders placed through this client
+    ///
+    /// Orders placed on the same account by other processes (another
+    /// strategy, manual orders) are left alone.
+    pub async fn cancel_placed_orders(&self) -> Result<CancelResponse> {
+        let placed = self.placed_orders.lock().clone();
+        let open: Vec<String> = if placed.is_empty() {
+            Vec::new()
+        } else {
+            self.get_orders(None)
+                .await?
+                .iter()
+                .filter_map(|order| order.get("id").and_then(|id| id.as_str()))
+                .filter(|id| placed.contains(*id))
+                .map(str::to_string)
+                .collect()
+        };
+        // Orders no longer open were filled or cancelled elsewhere
+        self.placed_orders.lock().retain(|id| open.contains(id));
+
+        if open.is_empty() {
+            return Ok(CancelResponse {
+                canceled: Vec::new(),
+                not_canceled: HashMap::new(),
+            });
+        }
+        let response = self.cancel_orders(&open).await?;
+        let mut placed = self.placed_orders.lock();
+        for id in &response.canceled {
+            placed.remove(id);
+        }
+        Ok(response)
+    }
+
     /// Cancel orders for a specific market or asset
     pub async fn cancel_market_orders(
         &self,
@@ -585,7 +963,7 @@ impl TradingClient {
         let params = BalanceAllowanceParams {
             asset_type: Some(AssetType::Collateral),
             token_id: None,
-            signature_type: Some(2), // POLY_GNOSIS_SAFE for proxy wallets
+            signature_type: Some(self.signature_type.into()),
         };
         let balance = self.get_balance_allowance(Some(&params)).await?;
 
@@ -595,6 +973,32 @@ impl TradingClient {
     }
 }
 
+#[async_trait::async_trait]
+impl NonceSource for TradingClient {
+    async fn fetch_nonce(&self) -> Result<u64> {
+        Ok(self.rest.get_nonce(&self.auth, self.maker_address()).await?)
+    }
+}
+
+/// Time in force for an `OrderType`; GTD needs an expiration and has to go
+/// through `place_gtd_order` or `OrderRequest::gtd` instead
+fn time_in_force(order_type: OrderType) -> Result<TimeInForce> {
+    TimeInForce::try_from(order_type)
+        .map_err(|e| RestError::ApiError(format!("Failed to build order: {}", e)).into())
+}
+
+/// A single order as validated, signed and submitted by `submit_order`
+struct OrderParams<'a> {
+    token_id: &'a str,
+    price: f64,
+    size: f64,
+    side: Side,
+    time_in_force: TimeInForce,
+    fee_rate_bps: Option<u64>,
+    /// Reject the order rather than let it take liquidity
+    post_only: bool,
+}
+
 /// Fluent order builder for more complex order configurations
 pub struct OrderRequest<'a> {
     client: &'a TradingClient,
@@ -602,8 +1006,9 @@ pub struct OrderRequest<'a> {
     price: Option<f64>,
     size: Option<f64>,
     side: Option<Side>,
-    order_type: OrderType,
+    time_in_force: TimeInForce,
     fee_rate_bps: Option<u64>,
+    post_only: bool,
 }
 
 impl<'a> OrderRequest<'a> {
@@ -614,8 +1019,9 @@ impl<'a> OrderRequest<'a> {
             price: None,
             size: None,
             side: None,
-            order_type: OrderType::GTC,
+            time_in_force: TimeInForce::Gtc,
             fee_rate_bps: None,
+            post_only: false,
         }
     }
 
@@ -645,25 +1051,25 @@ impl<'a> OrderRequest<'a> {
 
     /// Set order type to GTC (Good Till Cancel)
     pub fn gtc(mut self) -> Self {
-        self.order_type = OrderType::GTC;
+        self.time_in_force = TimeInForce::Gtc;
         self
     }
 
     /// Set order type to FOK (Fill Or Kill)
     pub fn fok(mut self) -> Self {
-        self.order_type = OrderType::FOK;
+        self.time_in_force = TimeInForce::Fok;
         self
     }
 
-    /// Set order type to GTD (Good Till Date)
-    pub fn gtd(mut self) -> Self {
-        self.order_type = OrderType::GTD;
+    /// Set order type to GTD (Good Till Date), expiring at `expiration` (unix seconds)
+    pub fn gtd(mut self, expiration: u64) -> Self {
+        self.time_in_force = TimeInForce::Gtd { expiration };
         self
     }
 
     /// Set order type to FAK (Fill And Kill)
     pub fn fak(mut self) -> Self {
-        self.order_type = OrderType::FAK;
+        self.time_in_force = TimeInForce::Fak;
         self
     }
 
@@ -673,6 +1079,12 @@ impl<'a> OrderRequest<'a> {
         self
     }
 
+    /// Reject the order rather than let it take liquidity
+    pub fn post_only(mut self) -> Self {
+        self.post_only = true;
+        self
+    }
+
     /// Execute the order
     pub async fn execute(self) -> Result<OrderPlacementResponse> {
         let price = self
@@ -686,7 +1098,15 @@ impl<'a> OrderRequest<'a> {
             .ok_or_else(|| TradingError::InvalidParameter("Side not set (use .buy() or .sell())".to_string()))?;
 
         self.client
-            .place_order_with_fee(&self.token_id, price, size, side, self.order_type, self.fee_rate_bps)
+            .submit_order(OrderParams {
+                token_id: &self.token_id,
+                price,
+                size,
+                side,
+                time_in_force: self.time_in_force,
+                fee_rate_bps: self.fee_rate_bps,
+                post_only: self.post_only,
+            })
             .await
     }
 }
@@ -92,6 +92,110 @@ impl OrderType {
     }
 }
 
+/// How orders are signed, matching the exchange's `signatureType` field.
+///
+/// In config it can be written as the raw code (`0`/`1`/`2`) or by name
+/// (`eoa`, `poly_proxy`, `poly_gnosis_safe`); it always serializes to the code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SignatureType {
+    /// Direct wallet signing (signer == maker)
+    Eoa,
+    /// Polymarket proxy wallet
+    #[default]
+    PolyProxy,
+    /// Gnosis Safe proxy wallet
+    PolyGnosisSafe,
+}
+
+impl SignatureType {
+    /// Config/display name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignatureType::Eoa => "eoa",
+            SignatureType::PolyProxy => "poly_proxy",
+            SignatureType::PolyGnosisSafe => "poly_gnosis_safe",
+        }
+    }
+}
+
+impl From<SignatureType> for u8 {
+    fn from(signature_type: SignatureType) -> Self {
+        match signature_type {
+            SignatureType::Eoa => super::constants::SIGNATURE_TYPE_EOA,
+            SignatureType::PolyProxy => super::constants::SIGNATURE_TYPE_POLY_PROXY,
+            SignatureType::PolyGnosisSafe => super::constants::SIGNATURE_TYPE_POLY_GNOSIS_SAFE,
+        }
+    }
+}
+
+impl TryFrom<u8> for SignatureType {
+    type Error = String;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        match code {
+            super::constants::SIGNATURE_TYPE_EOA => Ok(SignatureType::Eoa),
+            super::constants::SIGNATURE_TYPE_POLY_PROXY => Ok(SignatureType::PolyProxy),
+            super::constants::SIGNATURE_TYPE_POLY_GNOSIS_SAFE => Ok(SignatureType::PolyGnosisSafe),
+            other => Err(format!(
+                "invalid signature_type {} (expected 0=eoa, 1=poly_proxy, 2=poly_gnosis_safe)",
+                other
+            )),
+        }
+    }
+}
+
+impl std::str::FromStr for SignatureType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "eoa" => Ok(SignatureType::Eoa),
+            "poly_proxy" | "polyproxy" | "proxy" => Ok(SignatureType::PolyProxy),
+            "poly_gnosis_safe" | "polygnosissafe" | "gnosis_safe" | "safe" => {
+                Ok(SignatureType::PolyGnosisSafe)
+            }
+            other => match other.parse::<u8>() {
+                Ok(code) => SignatureType::try_from(code),
+                Err(_) => Err(format!(
+                    "invalid signature_type '{}' (expected eoa, poly_proxy or poly_gnosis_safe)",
+                    s
+                )),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for SignatureType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for SignatureType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8((*self).into())
+    }
+}
+
+impl<'de> Deserialize<'de> for SignatureType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Code(u64),
+            Name(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Code(code) => u8::try_from(code)
+                .map_err(|_| format!("invalid signature_type {}", code))
+                .and_then(SignatureType::try_from)
+                .map_err(serde::de::Error::custom),
+            Raw::Name(name) => name.parse().map_err(serde::de::Error::custom),
+        }
+    }
+}
+
 /// Order creation request
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderArgs {
@@ -108,6 +212,11 @@ pub struct OrderArgs {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expiration: Option<u64>,
+
+    /// Reject instead of taking liquidity. Sent as `postOnly` next to the
+    /// order rather than inside it.
+    #[serde(skip)]
+    pub post_only: bool,
 }
 
 /// Market order request
@@ -389,6 +498,26 @@ pub struct BestOpportunity {
 }
 
 impl OrderBook {
+    /// Book with no levels, standing in for a token the exchange returned nothing for
+    pub fn empty(asset_id: impl Into<String>) -> Self {
+        Self {
+            market: String::new(),
+            asset_id: asset_id.into(),
+            bids: Vec::new(),
+            asks: Vec::new(),
+            timestamp: None,
+            hash: None,
+            min_order_size: None,
+            tick_size: None,
+            neg_risk: None,
+        }
+    }
+
+    /// Whether the book has no levels on either side
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty() && self.asks.is_empty()
+    }
+
     /// Find the best opportunity (highest probability side)
     pub fn best_opportunity(&self) -> Option<BestOpportunity> {
         let best_bid = self.bids.first().map(|b| b.price_f64()).unwrap_or(0.0);
@@ -426,9 +555,63 @@ impl OrderBook {
         }
     }
 
+    /// Get the best bid (highest price to sell at)
+    ///
+    /// Levels may arrive in any order, so this scans rather than trusting position.
+    /// Levels with an unparseable price are skipped.
+    pub fn best_bid(&self) -> Option<&PriceLevel> {
+        priced_levels(&self.bids)
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, level)| level)
+    }
+
     /// Get the best ask (lowest/cheapest price to buy at)
+    ///
+    /// Levels with an unparseable price are skipped.
     pub fn best_ask(&self) -> Option<&PriceLevel> {
-        self.asks.last()
+        priced_levels(&self.asks)
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, level)| level)
+    }
+
+    /// Midpoint between best bid and best ask, `None` unless both sides are quoted
+    pub fn midpoint(&self) -> Option<f64> {
+        let bid = self.best_bid()?.price_f64();
+        let ask = self.best_ask()?.price_f64();
+        Some((bid + ask) / 2.0)
+    }
+
+    /// Best ask minus best bid, `None` unless both sides are quoted
+    pub fn spread(&self) -> Option<f64> {
+        let bid = self.best_bid()?.price_f64();
+        let ask = self.best_ask()?.price_f64();
+        Some(ask - bid)
+    }
+
+    /// Resting size on `side` from the top of book down to `price`
+    ///
+    /// `Side::Buy` sums bids at or above `price` (what a seller can hit),
+    /// `Side::Sell` sums asks at or below `price` (what a buyer can lift).
+    pub fn depth_at(&self, price: f64, side: Side) -> f64 {
+        match side {
+            Side::Buy => priced_levels(&self.bids)
+                .filter(|(level_price, _)| *level_price >= price)
+                .map(|(_, level)| level.size_f64())
+                .sum(),
+            Side::Sell => priced_levels(&self.asks)
+                .filter(|(level_price, _)| *level_price <= price)
+                .map(|(_, level)| level.size_f64())
+                .sum(),
+        }
+    }
+
+    /// Whether the highest bid is at or above the lowest ask.
+    ///
+    /// Compares across all levels since level order isn't guaranteed.
+    pub fn is_crossed(&self) -> bool {
+        let best_bid = priced_levels(&self.bids).map(|(p, _)| p).fold(f64::NEG_INFINITY, f64::max);
+        let best_ask = priced_levels(&self.asks).map(|(p, _)| p).fold(f64::INFINITY, f64::min);
+        best_bid >= best_ask
     }
 
     /// Calculate total cost to sweep entire ask side of orderbook
@@ -440,10 +623,92 @@ impl OrderBook {
     }
 }
 
+/// Levels paired with their price, skipping those whose price does not parse
+fn priced_levels(levels: &[PriceLevel]) -> impl Iterator<Item = (f64, &PriceLevel)> {
+    levels
+        .iter()
+        .filter_map(|level| Some((level.price.parse::<f64>().ok().filter(|p| p.is_finite())?, level)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn level(price: &str, size: &str) -> PriceLevel {
+        PriceLevel {
+            price: price.to_string(),
+            size: size.to_string(),
+        }
+    }
+
+    /// 0.48 bid / 0.52 ask, 500 each at the top, levels deliberately unsorted
+    fn balanced_orderbook() -> OrderBook {
+        OrderBook {
+            bids: vec![level("0.46", "300"), level("0.48", "500"), level("0.47", "200")],
+            asks: vec![level("0.54", "300"), level("0.52", "500"), level("0.53", "200")],
+            ..OrderBook::empty("token-1")
+        }
+    }
+
+    #[test]
+    fn test_orderbook_best_levels_ignore_order() {
+        let book = balanced_orderbook();
+        assert_eq!(book.best_bid().unwrap().price, "0.48");
+        assert_eq!(book.best_ask().unwrap().price, "0.52");
+        assert!((book.midpoint().unwrap() - 0.50).abs() < 1e-9);
+        assert!((book.spread().unwrap() - 0.04).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_orderbook_best_levels_skip_unparseable_prices() {
+        let book = OrderBook {
+            bids: vec![level("0.48", "500"), level("n/a", "100")],
+            asks: vec![level("", "100"), level("0.52", "500")],
+            ..OrderBook::empty("token-1")
+        };
+        assert_eq!(book.best_bid().unwrap().price, "0.48");
+        assert_eq!(book.best_ask().unwrap().price, "0.52");
+        assert!((book.midpoint().unwrap() - 0.50).abs() < 1e-9);
+        assert!(!book.is_crossed());
+        assert_eq!(book.depth_at(0.50, Side::Sell), 0.0);
+
+        let unpriced = OrderBook {
+            bids: vec![level("n/a", "100")],
+            ..book
+        };
+        assert!(unpriced.best_bid().is_none());
+        assert_eq!(unpriced.midpoint(), None);
+    }
+
+    #[test]
+    fn test_orderbook_depth_at() {
+        let book = balanced_orderbook();
+        assert_eq!(book.depth_at(0.48, Side::Buy), 500.0);
+        assert_eq!(book.depth_at(0.47, Side::Buy), 700.0);
+        assert_eq!(book.depth_at(0.49, Side::Buy), 0.0);
+        assert_eq!(book.depth_at(0.53, Side::Sell), 700.0);
+        assert_eq!(book.depth_at(0.60, Side::Sell), 1000.0);
+        assert_eq!(book.depth_at(0.51, Side::Sell), 0.0);
+    }
+
+    #[test]
+    fn test_orderbook_helpers_on_empty_and_one_sided_books() {
+        let empty = OrderBook::empty("token-1");
+        assert!(empty.best_bid().is_none());
+        assert!(empty.best_ask().is_none());
+        assert_eq!(empty.midpoint(), None);
+        assert_eq!(empty.spread(), None);
+        assert_eq!(empty.depth_at(0.5, Side::Buy), 0.0);
+
+        let bids_only = OrderBook {
+            asks: Vec::new(),
+            ..balanced_orderbook()
+        };
+        assert_eq!(bids_only.best_bid().unwrap().price, "0.48");
+        assert_eq!(bids_only.midpoint(), None);
+        assert_eq!(bids_only.spread(), None);
+    }
+
     #[test]
     fn test_parse_data_api_position_response() {
         // Test with full API response format
@@ -535,4 +800,56 @@ mod tests {
         assert!(size.is_some());
         assert!(size.unwrap().is_f64()); // It's a number, but that's OK
     }
+
+    #[test]
+    fn test_signature_type_round_trips() {
+        for (variant, code, name) in [
+            (SignatureType::Eoa, 0u8, "eoa"),
+            (SignatureType::PolyProxy, 1, "poly_proxy"),
+            (SignatureType::PolyGnosisSafe, 2, "poly_gnosis_safe"),
+        ] {
+            assert_eq!(u8::from(variant), code);
+            assert_eq!(SignatureType::try_from(code), Ok(variant));
+            assert_eq!(name.parse::<SignatureType>(), Ok(variant));
+
+            // Serializes to the code, deserializes from code or name
+            assert_eq!(serde_json::to_string(&variant).unwrap(), code.to_string());
+            let from_code: SignatureType = serde_json::from_str(&code.to_string()).unwrap();
+            let from_name: SignatureType = serde_json::from_str(&format!("\"{}\"", name)).unwrap();
+            assert_eq!(from_code, variant);
+            assert_eq!(from_name, variant);
+        }
+    }
+
+    #[test]
+    fn test_signature_type_rejects_invalid() {
+        assert!(SignatureType::try_from(3).is_err());
+        assert!("metamask".parse::<SignatureType>().is_err());
+        assert!(serde_json::from_str::<SignatureType>("7").is_err());
+        assert!(serde_json::from_str::<SignatureType>("300").is_err());
+        assert!(serde_json::from_str::<SignatureType>("\"proxyy\"").is_err());
+    }
+
+    #[test]
+    fn test_orderbook_is_crossed() {
+        let level = |price: &str| PriceLevel {
+            price: price.to_string(),
+            size: "10".to_string(),
+        };
+        let book = |bids: &[&str], asks: &[&str]| OrderBook {
+            market: "m".to_string(),
+            asset_id: "a".to_string(),
+            bids: bids.iter().map(|p| level(p)).collect(),
+            asks: asks.iter().map(|p| level(p)).collect(),
+            timestamp: None,
+            hash: None,
+            min_order_size: None,
+            tick_size: None,
+            neg_risk: None,
+        };
+
+        assert!(!book(&["0.40", "0.45"], &["0.50", "0.47"]).is_crossed());
+        assert!(book(&["0.40", "0.48"], &["0.50", "0.47"]).is_crossed());
+        assert!(!book(&[], &["0.50"]).is_crossed());
+    }
 }
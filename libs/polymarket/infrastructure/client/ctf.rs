@@ -1,6 +1,6 @@
 //! CTF (Conditional Token Framework) Operations
 //!
-//! Provides split and merge functionality for Polymarket outcome tokens.
+//! Provides split, merge and redeem functionality for Polymarket outcome tokens.
 //!
 //! # Operations
 //!
@@ -12,6 +12,9 @@
 //!   - 1 YES + 1 NO → 1 USDC
 //!   - Useful for exiting positions or arbitrage when YES + NO < $1.00
 //!
+//! - **Redeem**: Convert winning outcome tokens into USDC once the market resolves
+//!   - Executed through the proxy wallet's Gnosis Safe (see `CtfClient::with_safe`)
+//!
 //! # Gas Price
 //!
 //! Gas prices are fetched dynamically from the Polygon network with a configurable
@@ -53,9 +56,12 @@
 use ethers::prelude::*;
 use ethers::contract::abigen;
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 use tracing::{info, debug};
 
+use super::redeem::{RedeemClient, RedeemError};
+
 // Contract addresses on Polygon
 pub const POLYGON_RPC_URL: &str = "https://polygon-rpc.com";
 pub const POLYGON_CHAIN_ID: u64 = 137;
@@ -80,6 +86,17 @@ const MAX_GAS_PRICE_GWEI: u64 = 1200;
 /// USDC has 6 decimal places
 pub const USDC_DECIMALS: u8 = 6;
 
+/// Typical gas used by a merge executed through the Safe (well under GAS_LIMIT)
+pub const MERGE_GAS_ESTIMATE: u64 = 200_000;
+
+/// Confirmations required before an operation counts as settled
+pub const DEFAULT_MIN_CONFIRMATIONS: usize = 1;
+
+/// How long to wait for the first confirmation; each further one adds
+/// `CONFIRMATION_TIMEOUT_PER_BLOCK`
+const CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(60);
+const CONFIRMATION_TIMEOUT_PER_BLOCK: Duration = Duration::from_secs(5);
+
 // Generate contract bindings for CTF
 abigen!(
     ConditionalTokens,
@@ -133,11 +150,22 @@ pub enum CtfError {
 
 pub type Result<T> = std::result::Result<T, CtfError>;
 
+impl From<RedeemError> for CtfError {
+    fn from(e: RedeemError) -> Self {
+        match e {
+            RedeemError::ProviderError(msg) => CtfError::ProviderError(msg),
+            RedeemError::ContractError(msg) => CtfError::ContractError(msg),
+            RedeemError::InvalidConditionId(msg) => CtfError::InvalidConditionId(msg),
+            RedeemError::TransactionFailed(msg) => CtfError::TransactionFailed(msg),
+        }
+    }
+}
+
 // =============================================================================
 // Operation Types
 // =============================================================================
 
-/// Result of a split or merge operation
+/// Result of a split, merge or redeem operation
 #[derive(Debug, Clone)]
 pub struct CtfOperationResult {
     pub operation: CtfOperation,
@@ -153,6 +181,7 @@ pub struct CtfOperationResult {
 pub enum CtfOperation {
     Split,
     Merge,
+    Redeem,
     Approve,
 }
 
@@ -161,6 +190,7 @@ impl std::fmt::Display for CtfOperation {
         match self {
             CtfOperation::Split => write!(f, "Split"),
             CtfOperation::Merge => write!(f, "Merge"),
+            CtfOperation::Redeem => write!(f, "Redeem"),
             CtfOperation::Approve => write!(f, "Approve"),
         }
     }
@@ -170,14 +200,16 @@ impl std::fmt::Display for CtfOperation {
 // CTF Client
 // =============================================================================
 
-/// Client for CTF split/merge operations
+/// Client for CTF split/merge/redeem operations
 pub struct CtfClient<M: Middleware> {
     ctf: ConditionalTokens<M>,
     neg_risk_ctf: ConditionalTokens<M>,
     usdc: ERC20<M>,
     usdc_address: Address,
-    #[allow(dead_code)]
     provider: Arc<M>,
+    /// Safe holding the positions and the key signing its transactions
+    safe: Option<(Address, LocalWallet)>,
+    min_confirmations: usize,
 }
 
 impl<M: Middleware + 'static> CtfClient<M> {
@@ -193,9 +225,23 @@ impl<M: Middleware + 'static> CtfClient<M> {
             usdc: ERC20::new(usdc_address, provider.clone()),
             usdc_address,
             provider,
+            safe: None,
+            min_confirmations: DEFAULT_MIN_CONFIRMATIONS,
         }
     }
 
+    /// Redeem through the Safe at `safe_address`, signing with `wallet`
+    pub fn with_safe(mut self, safe_address: Address, wallet: LocalWallet) -> Self {
+        self.safe = Some((safe_address, wallet));
+        self
+    }
+
+    /// Confirmations `redeem` waits for before reporting success
+    pub fn with_min_confirmations(mut self, min_confirmations: usize) -> Self {
+        self.min_confirmations = min_confirmations.max(1);
+        self
+    }
+
     /// Get the CTF contract address based on neg_risk flag
     pub fn ctf_address(&self, neg_risk: bool) -> Address {
         if neg_risk {
@@ -273,6 +319,45 @@ impl<M: Middleware + 'static> CtfClient<M> {
         Ok((self.ctf_address(neg_risk), call.calldata().unwrap_or_default()))
     }
 
+    /// Redeem resolved outcome tokens into USDC
+    ///
+    /// `amounts[i]` is the held balance of outcome `i` (index set `1 << i`);
+    /// only outcomes with a non-zero amount are redeemed. Positions are held
+    /// by the proxy wallet, so the redeem is executed through the Safe set
+    /// with [`with_safe`](Self::with_safe).
+    ///
+    /// A redeem that was confirmed but paid no USDC is reported through
+    /// `CtfOperationResult::error`.
+    pub async fn redeem(&self, condition_id: &str, neg_risk: bool, amounts: &[U256]) -> Result<CtfOperationResult> {
+        let (safe_address, wallet) = self.safe.as_ref().ok_or_else(|| {
+            CtfError::ProviderError("redeem needs a Safe, see CtfClient::with_safe".to_string())
+        })?;
+
+        let index_sets = redeem_index_sets(amounts);
+        if index_sets.is_empty() {
+            return Err(CtfError::InsufficientBalance(format!(
+                "Nothing to redeem for condition {}", condition_id
+            )));
+        }
+
+        let redemption = RedeemClient::new(self.provider.clone())
+            .with_min_confirmations(self.min_confirmations)
+            .redeem_outcomes_via_safe(*safe_address, condition_id, neg_risk, index_sets, wallet)
+            .await?;
+
+        Ok(CtfOperationResult {
+            operation: CtfOperation::Redeem,
+            condition_id: condition_id.to_string(),
+            amount: amounts.iter().fold(U256::zero(), |acc, a| acc.saturating_add(*a)),
+            neg_risk,
+            tx_hash: Some(redemption.tx_hash),
+            error: redemption
+                .payout
+                .is_zero()
+                .then(|| "Confirmed without a USDC payout".to_string()),
+        })
+    }
+
     /// Get balance of a specific position token
     pub async fn get_position_balance(&self, account: Address, position_id: U256, neg_risk: bool) -> Result<U256> {
         let contract = if neg_risk { &self.neg_risk_ctf } else { &self.ctf };
@@ -292,6 +377,7 @@ impl<M: Middleware + 'static> CtfClient<M> {
 ///
 /// Splits USDC into YES + NO outcome tokens.
 /// Will automatically approve USDC if needed.
+/// Returns once the split has `min_confirmations` confirmations.
 pub async fn split_via_safe(
     safe_address: Address,
     condition_id: &str,
@@ -299,6 +385,7 @@ pub async fn split_via_safe(
     amount: U256,
     wallet: &LocalWallet,
     rpc_url: &str,
+    min_confirmations: usize,
 ) -> Result<TxHash> {
     let provider = Provider::<Http>::try_from(rpc_url)
         .map_err(|e| CtfError::ProviderError(e.to_string()))?;
@@ -332,12 +419,13 @@ pub async fn split_via_safe(
     let (to, data) = client.encode_split_call(condition_id, neg_risk, amount)?;
 
     info!("[CTF] Splitting {} USDC for condition {}", amount, condition_id);
-    execute_safe_tx(safe_address, to, data, wallet, &provider).await
+    execute_safe_tx(safe_address, to, data, wallet, &provider, min_confirmations).await
 }
 
 /// Execute a merge operation via Gnosis Safe
 ///
 /// Merges YES + NO outcome tokens back into USDC.
+/// Returns once the merge has `min_confirmations` confirmations.
 pub async fn merge_via_safe(
     safe_address: Address,
     condition_id: &str,
@@ -345,6 +433,7 @@ pub async fn merge_via_safe(
     amount: U256,
     wallet: &LocalWallet,
     rpc_url: &str,
+    min_confirmations: usize,
 ) -> Result<TxHash> {
     let provider = Provider::<Http>::try_from(rpc_url)
         .map_err(|e| CtfError::ProviderError(e.to_string()))?;
@@ -354,7 +443,7 @@ pub async fn merge_via_safe(
     let (to, data) = client.encode_merge_call(condition_id, neg_risk, amount)?;
 
     info!("[CTF] Merging {} tokens for condition {}", amount, condition_id);
-    execute_safe_tx(safe_address, to, data, wallet, &provider).await
+    execute_safe_tx(safe_address, to, data, wallet, &provider, min_confirmations).await
 }
 
 /// Approve USDC spending for CTF contract via Gnosis Safe
@@ -384,7 +473,7 @@ async fn approve_via_safe_internal<M: Middleware + 'static>(
     let (to, data) = client.encode_approve_call(neg_risk, amount)?;
 
     debug!("[CTF] Approving {} USDC for CTF contract", amount);
-    execute_safe_tx(safe_address, to, data, wallet, provider).await
+    execute_safe_tx(safe_address, to, data, wallet, provider, DEFAULT_MIN_CONFIRMATIONS).await
 }
 
 /// Fetch current gas price from the network and apply multiplier
@@ -414,6 +503,21 @@ async fn get_dynamic_gas_price<M: Middleware + 'static>(provider: &Arc<M>) -> Re
     Ok(U256::from(final_gwei) * U256::from(1_000_000_000u64))
 }
 
+/// Estimate the cost of a merge transaction in POL at the current gas price
+///
+/// Uses the same bounded, multiplied gas price that merges are sent with.
+pub async fn estimate_merge_gas_cost_via<M: Middleware + 'static>(provider: &Arc<M>) -> Result<f64> {
+    let gas_price = get_dynamic_gas_price(provider).await?;
+    Ok(gas_cost_native(gas_price, MERGE_GAS_ESTIMATE))
+}
+
+/// Cost in POL of `gas_units` at `gas_price` wei
+pub fn gas_cost_native(gas_price: U256, gas_units: u64) -> f64 {
+    let wei = gas_price.saturating_mul(U256::from(gas_units));
+    // Whole gwei keeps the value within f64 precision
+    (wei / U256::from(1_000_000_000u64)).as_u128() as f64 / 1e9
+}
+
 /// Execute a transaction via Gnosis Safe
 async fn execute_safe_tx<M: Middleware + 'static>(
     safe_address: Address,
@@ -421,6 +525,7 @@ async fn execute_safe_tx<M: Middleware + 'static>(
     data: Bytes,
     wallet: &LocalWallet,
     provider: &Arc<M>,
+    min_confirmations: usize,
 ) -> Result<TxHash> {
     let safe = GnosisSafe::new(safe_address, provider.clone());
     let nonce = safe.nonce().call().await
@@ -452,18 +557,35 @@ async fn execute_safe_tx<M: Middleware + 'static>(
     let tx_hash = pending_tx.tx_hash();
     debug!("[CTF] Transaction sent: {:?} (gas_price: {} gwei)", tx_hash, gas_price / U256::from(1_000_000_000u64));
 
-    let receipt = tokio::time::timeout(
-        std::time::Duration::from_secs(60),
-        pending_tx,
-    )
-    .await
-    .map_err(|_| CtfError::TransactionFailed(format!("Timeout. TX: {:?}", tx_hash)))?
-    .map_err(|e| CtfError::TransactionFailed(e.to_string()))?
-    .ok_or_else(|| CtfError::TransactionFailed("No receipt".to_string()))?;
+    wait_for_confirmations(pending_tx, min_confirmations).await?;
+    info!("[CTF] Transaction confirmed: {:?}", tx_hash);
+    Ok(tx_hash)
+}
+
+/// Wait until a sent transaction has `min_confirmations` confirmations
+///
+/// Succeeds only for a mined, non-reverted transaction. Callers should not
+/// treat the operation as done (update positions, re-trigger) before this
+/// returns.
+pub async fn wait_for_confirmations<P: JsonRpcClient>(
+    pending_tx: PendingTransaction<'_, P>,
+    min_confirmations: usize,
+) -> Result<TransactionReceipt> {
+    let tx_hash = pending_tx.tx_hash();
+    let min_confirmations = min_confirmations.max(1);
+    let timeout = CONFIRMATION_TIMEOUT + CONFIRMATION_TIMEOUT_PER_BLOCK * (min_confirmations as u32 - 1);
+
+    let receipt = tokio::time::timeout(timeout, pending_tx.confirmations(min_confirmations))
+        .await
+        .map_err(|_| CtfError::TransactionFailed(format!(
+            "Timeout waiting for {} confirmation(s). TX: {:?}", min_confirmations, tx_hash
+        )))?
+        .map_err(|e| CtfError::TransactionFailed(e.to_string()))?
+        .ok_or_else(|| CtfError::TransactionFailed("No receipt".to_string()))?;
 
     if receipt.status == Some(U64::from(1)) {
-        info!("[CTF] Transaction confirmed: {:?}", tx_hash);
-        Ok(tx_hash)
+        debug!("[CTF] {:?} reached {} confirmation(s)", tx_hash, min_confirmations);
+        Ok(receipt)
     } else {
         Err(CtfError::TransactionFailed("Transaction reverted".to_string()))
     }
@@ -542,6 +664,16 @@ fn u256_to_bytes32(value: U256) -> [u8; 32] {
     bytes
 }
 
+/// Index sets for the outcomes with a non-zero amount (outcome `i` → `1 << i`)
+fn redeem_index_sets(amounts: &[U256]) -> Vec<U256> {
+    amounts
+        .iter()
+        .enumerate()
+        .filter(|(_, amount)| !amount.is_zero())
+        .map(|(i, _)| U256::one() << i)
+        .collect()
+}
+
 /// Convert USDC amount (human readable) to raw units.
 /// Panics if amount is negative.
 pub fn usdc_to_raw(amount: f64) -> U256 {
@@ -575,7 +707,7 @@ fn load_proxy_wallet() -> Result<String> {
 }
 
 /// Split USDC into outcome tokens using env credentials
-pub async fn split(condition_id: &str, neg_risk: bool, amount: U256) -> Result<TxHash> {
+pub async fn split(condition_id: &str, neg_risk: bool, amount: U256, min_confirmations: usize) -> Result<TxHash> {
     let private_key = load_private_key()?;
     let proxy_wallet = load_proxy_wallet()?;
 
@@ -588,11 +720,11 @@ pub async fn split(condition_id: &str, neg_risk: bool, amount: U256) -> Result<T
         .parse()
         .map_err(|_| CtfError::ProviderError("Invalid proxy wallet".to_string()))?;
 
-    split_via_safe(safe_address, condition_id, neg_risk, amount, &wallet, POLYGON_RPC_URL).await
+    split_via_safe(safe_address, condition_id, neg_risk, amount, &wallet, POLYGON_RPC_URL, min_confirmations).await
 }
 
 /// Merge outcome tokens back into USDC using env credentials
-pub async fn merge(condition_id: &str, neg_risk: bool, amount: U256) -> Result<TxHash> {
+pub async fn merge(condition_id: &str, neg_risk: bool, amount: U256, min_confirmations: usize) -> Result<TxHash> {
     let private_key = load_private_key()?;
     let proxy_wallet = load_proxy_wallet()?;
 
@@ -605,7 +737,14 @@ pub async fn merge(condition_id: &str, neg_risk: bool, amount: U256) -> Result<T
         .parse()
         .map_err(|_| CtfError::ProviderError("Invalid proxy wallet".to_string()))?;
 
-    merge_via_safe(safe_address, condition_id, neg_risk, amount, &wallet, POLYGON_RPC_URL).await
+    merge_via_safe(safe_address, condition_id, neg_risk, amount, &wallet, POLYGON_RPC_URL, min_confirmations).await
+}
+
+/// Estimate the cost of a merge transaction in POL using the public Polygon RPC
+pub async fn estimate_merge_gas_cost() -> Result<f64> {
+    let provider = Provider::<Http>::try_from(POLYGON_RPC_URL)
+        .map_err(|e| CtfError::ProviderError(e.to_string()))?;
+    estimate_merge_gas_cost_via(&Arc::new(provider)).await
 }
 
 // =============================================================================
@@ -711,4 +850,13 @@ mod tests {
         assert_eq!(to, USDC_ADDRESS.parse::<Address>().unwrap());
         assert!(!data.is_empty());
     }
+
+    #[test]
+    fn test_gas_cost_native() {
+        let gwei = U256::from(1_000_000_000u64);
+        // 200k gas at 50 gwei = 0.01 POL
+        let cost = gas_cost_native(gwei * 50, MERGE_GAS_ESTIMATE);
+        assert!((cost - 0.01).abs() < 1e-12);
+        assert_eq!(gas_cost_native(U256::zero(), MERGE_GAS_ESTIMATE), 0.0);
+    }
 }
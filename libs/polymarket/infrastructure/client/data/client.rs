@@ -1,8 +1,8 @@
 //! Data API client for Polymarket
 //!
-//! Provides access to user positions and related data from the Data API.
+//! Provides access to user positions, activity and related data from the Data API.
 
-use super::types::{Position, PositionFilters};
+use super::types::{Activity, ActivityFilters, Position, PositionFilters};
 use reqwest::Client;
 use std::time::Duration;
 use thiserror::Error;
@@ -271,6 +271,121 @@ impl DataApiClient {
         self.get_all_positions(user, Some(filters)).await
     }
 
+    /// Get one page of activity for a user address
+    ///
+    /// # Arguments
+    /// * `user` - Wallet address (0x-prefixed)
+    /// * `filters` - Optional query filters
+    ///
+    /// # Returns
+    /// Vector of activity entries for the user
+    pub async fn get_activity_page(
+        &self,
+        user: &str,
+        filters: Option<ActivityFilters>,
+    ) -> Result<Vec<Activity>> {
+        let url = format!("{}/activity", self.base_url);
+
+        let mut params = vec![("user".to_string(), user.to_string())];
+
+        if let Some(f) = filters {
+            params.extend(f.to_query_params());
+        }
+
+        debug!("GET {} with {} params", url, params.len());
+
+        let response = self.client.get(&url).query(&params).send().await?;
+
+        let status = response.status();
+
+        if status == 429 {
+            warn!("Rate limit exceeded on Data API");
+            return Err(DataApiError::RateLimitExceeded);
+        }
+
+        if !status.is_success() {
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(DataApiError::ApiError(format!(
+                "Failed to fetch activity ({}): {}",
+                status, error_text
+            )));
+        }
+
+        let activity: Vec<Activity> = response
+            .json()
+            .await
+            .map_err(|e| DataApiError::DeserializeFailed(e.to_string()))?;
+
+        debug!("Fetched {} activity entries for user {}", activity.len(), user);
+        Ok(activity)
+    }
+
+    /// Get a user's activity history (trades, merges, redeems, ...) with
+    /// automatic pagination
+    ///
+    /// # Arguments
+    /// * `user` - Wallet address (0x-prefixed)
+    /// * `filters` - Optional query filters; `limit` sets the page size
+    ///   (default 100, max 500) and `offset` the starting point
+    ///
+    /// # Returns
+    /// Vector of all matching activity entries
+    pub async fn get_activity(
+        &self,
+        user: &str,
+        filters: Option<ActivityFilters>,
+    ) -> Result<Vec<Activity>> {
+        let base_filters = filters.unwrap_or_default();
+        let limit = base_filters.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        let mut offset = base_filters.offset.unwrap_or(0);
+        let mut all_activity = Vec::new();
+
+        info!("Starting paginated activity fetch for user {}", user);
+
+        loop {
+            if offset >= MAX_OFFSET {
+                warn!(
+                    "Reached maximum offset ({}) while fetching activity",
+                    MAX_OFFSET
+                );
+                break;
+            }
+
+            debug!("Fetching activity page: offset={}, limit={}", offset, limit);
+
+            let page_filters = ActivityFilters {
+                limit: Some(limit),
+                offset: Some(offset),
+                ..base_filters.clone()
+            };
+
+            let activity = self.get_activity_page(user, Some(page_filters)).await?;
+
+            let count = activity.len();
+            all_activity.extend(activity);
+
+            // If we got fewer than limit, we've reached the end
+            if count < limit as usize {
+                break;
+            }
+
+            offset += limit;
+
+            // Rate limit protection: small delay between requests
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        info!(
+            "Fetched total of {} activity entries for user {}",
+            all_activity.len(),
+            user
+        );
+        Ok(all_activity)
+    }
+
     /// Health check for the Data API
     pub async fn health_check(&self) -> Result<()> {
         let url = format!("{}/", self.base_url);
@@ -307,6 +422,9 @@ impl Default for DataApiClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::infrastructure::client::data::ActivityType;
+    use crate::mock_clob::{MockClobServer, MockResponse};
+    use hyper::Method;
 
     #[test]
     fn test_client_creation() {
@@ -325,4 +443,51 @@ mod tests {
         let client = DataApiClient::default();
         assert_eq!(client.base_url, DATA_API_BASE_URL);
     }
+
+    fn activity_json(kind: &str, timestamp: i64) -> String {
+        format!(
+            r#"{{"proxyWallet":"0xabc","timestamp":{},"conditionId":"0xdef","type":"{}","size":10,"usdcSize":5,"transactionHash":"0x{}"}}"#,
+            timestamp, kind, timestamp
+        )
+    }
+
+    #[tokio::test]
+    async fn test_get_activity_collects_pages() {
+        let server = MockClobServer::start().await;
+        server.respond_sequence(
+            Method::GET,
+            "/activity",
+            vec![
+                MockResponse::text(
+                    200,
+                    format!("[{},{}]", activity_json("TRADE", 1), activity_json("MERGE", 2)),
+                ),
+                MockResponse::text(200, format!("[{}]", activity_json("REDEEM", 3))),
+            ],
+        );
+        let client = DataApiClient::with_base_url(server.url());
+        let filters = ActivityFilters::new()
+            .with_types(vec![ActivityType::Trade, ActivityType::Merge, ActivityType::Redeem])
+            .with_limit(2);
+
+        let activity = client.get_activity("0xabc", Some(filters)).await.unwrap();
+
+        let types: Vec<ActivityType> = activity.iter().map(|a| a.activity_type).collect();
+        assert_eq!(
+            types,
+            vec![ActivityType::Trade, ActivityType::Merge, ActivityType::Redeem]
+        );
+        assert_eq!(activity[2].timestamp, 3);
+
+        let queries: Vec<String> = server
+            .requests()
+            .into_iter()
+            .map(|request| request.query.unwrap_or_default())
+            .collect();
+        assert_eq!(queries.len(), 2);
+        assert!(queries[0].starts_with("user=0xabc"));
+        assert!(queries[0].contains("type=TRADE%2CMERGE%2CREDEEM"));
+        assert!(queries[0].contains("offset=0"));
+        assert!(queries[1].contains("offset=2"));
+    }
 }
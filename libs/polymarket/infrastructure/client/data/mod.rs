@@ -1,6 +1,6 @@
 //! Data API client for Polymarket
 //!
-//! Provides access to user positions and related data.
+//! Provides access to user positions, activity history and related data.
 //!
 //! # Example
 //!
@@ -17,10 +17,25 @@
 //!     "0x1234...",
 //!     &["condition_id".to_string()],
 //! ).await?;
+//!
+//! // Get trade and merge history
+//! let filters = ActivityFilters::new().with_types(vec![ActivityType::Trade, ActivityType::Merge]);
+//! let activity = client.get_activity("0x1234...", Some(filters)).await?;
+//!
+//! // Poll positions and get notified when one opens, closes or flips
+//! let positions = spawn_position_tracker(Arc::new(client), wallet, shutdown_flag, interval, None).await;
 //! ```
 
 mod client;
+mod tracker;
 mod types;
 
 pub use client::{DataApiClient, DataApiError, Result};
-pub use types::{Position, PositionFilters, PositionSortBy, SortDirection};
+pub use tracker::{
+    diff_positions, spawn_position_tracker, PositionDelta, PositionDeltaCallback,
+    PositionTransition, SharedPositions,
+};
+pub use types::{
+    Activity, ActivityFilters, ActivityType, Position, PositionFilters, PositionSortBy,
+    SortDirection,
+};
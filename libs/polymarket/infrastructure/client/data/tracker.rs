@@ -0,0 +1,230 @@
+//! Polling position tracker for the Data API
+//!
+//! The Data API has no push channel for positions, so the tracker polls
+//! `get_all_positions` on an interval, keeps the latest snapshot in shared
+//! state, and reports positions that open, close or flip sign between polls.
+
+use super::client::DataApiClient;
+use super::types::{Position, PositionFilters};
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+/// Latest positions by asset token ID
+pub type SharedPositions = Arc<RwLock<HashMap<String, Position>>>;
+
+/// How a position's size moved across zero between two polls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionTransition {
+    /// From no position to a non-zero size
+    Opened,
+    /// From a non-zero size to no position
+    Closed,
+    /// From one side of zero to the other
+    Flipped,
+}
+
+/// A position whose size crossed zero between two polls
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionDelta {
+    pub asset: String,
+    pub condition_id: String,
+    /// Size at the previous poll (0.0 if absent)
+    pub old_size: f64,
+    /// Size at the latest poll (0.0 if absent)
+    pub new_size: f64,
+    pub transition: PositionTransition,
+}
+
+/// Callback for position transitions, invoked from the polling task
+pub trait PositionDeltaCallback: Send + Sync {
+    fn on_position_delta(&self, delta: &PositionDelta);
+}
+
+/// Positions that opened, closed or flipped sign from `old` to `new`
+pub fn diff_positions(
+    old: &HashMap<String, Position>,
+    new: &HashMap<String, Position>,
+) -> Vec<PositionDelta> {
+    let size = |positions: &HashMap<String, Position>, asset: &str| {
+        positions.get(asset).map(|p| p.size).unwrap_or(0.0)
+    };
+
+    let mut deltas = Vec::new();
+    let added = new.iter().filter(|(asset, _)| !old.contains_key(*asset));
+    for (asset, position) in old.iter().chain(added) {
+        let old_size = size(old, asset);
+        let new_size = size(new, asset);
+        let transition = match (old_size == 0.0, new_size == 0.0) {
+            (true, false) => PositionTransition::Opened,
+            (false, true) => PositionTransition::Closed,
+            (false, false) if old_size.signum() != new_size.signum() => PositionTransition::Flipped,
+            _ => continue,
+        };
+        deltas.push(PositionDelta {
+            asset: asset.clone(),
+            condition_id: position.condition_id.clone(),
+            old_size,
+            new_size,
+            transition,
+        });
+    }
+    deltas
+}
+
+/// Start polling positions for `wallet` every `interval`
+///
+/// The first snapshot is fetched before returning and fires no callbacks.
+/// A failed poll keeps the previous snapshot, so an outage never reports
+/// positions as closed. Polling stops once `shutdown_flag` is cleared.
+pub async fn spawn_position_tracker(
+    client: Arc<DataApiClient>,
+    wallet: String,
+    shutdown_flag: Arc<AtomicBool>,
+    interval: Duration,
+    callback: Option<Arc<dyn PositionDeltaCallback>>,
+) -> SharedPositions {
+    let positions: SharedPositions = Arc::new(RwLock::new(HashMap::new()));
+
+    info!("[PositionTracker] Hydrating positions for {}...", wallet);
+    match fetch_snapshot(&client, &wallet).await {
+        Some(snapshot) => {
+            info!("[PositionTracker] Hydrated {} positions", snapshot.len());
+            *positions.write() = snapshot;
+        }
+        None => warn!("[PositionTracker] Starting with no positions"),
+    }
+
+    let shared = Arc::clone(&positions);
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(interval).await;
+            if !shutdown_flag.load(Ordering::Acquire) {
+                info!("[PositionTracker] Shutdown signal received");
+                break;
+            }
+
+            let Some(snapshot) = fetch_snapshot(&client, &wallet).await else {
+                continue;
+            };
+            let deltas = {
+                let mut current = shared.write();
+                let deltas = diff_positions(&current, &snapshot);
+                *current = snapshot;
+                deltas
+            };
+
+            for delta in &deltas {
+                debug!(
+                    "[PositionTracker] {:?} {}: {} -> {}",
+                    delta.transition, delta.asset, delta.old_size, delta.new_size
+                );
+                if let Some(ref cb) = callback {
+                    cb.on_position_delta(delta);
+                }
+            }
+        }
+    });
+
+    positions
+}
+
+/// All positions of `wallet` by asset, or `None` if the fetch failed
+async fn fetch_snapshot(client: &DataApiClient, wallet: &str) -> Option<HashMap<String, Position>> {
+    // No size threshold: dust below the API's default of 1 share is still a position
+    let filters = PositionFilters::new().with_size_threshold(0.0);
+    match client.get_all_positions(wallet, Some(filters)).await {
+        Ok(positions) => Some(positions.into_iter().map(|p| (p.asset.clone(), p)).collect()),
+        Err(e) => {
+            warn!("[PositionTracker] Failed to fetch positions: {}", e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_clob::{MockClobServer, MockResponse};
+    use hyper::Method;
+    use parking_lot::Mutex;
+
+    fn position_json(asset: &str, size: f64) -> String {
+        format!(
+            r#"{{"proxyWallet":"0xabc","asset":"{asset}","conditionId":"0xcond-{asset}","size":{size},"avgPrice":0.5,"initialValue":5,"currentValue":5,"cashPnl":0,"percentPnl":0,"totalBought":5,"realizedPnl":0,"percentRealizedPnl":0,"curPrice":0.5,"redeemable":false,"mergeable":false,"title":"Test","slug":"test","outcome":"Yes","outcomeIndex":0,"oppositeOutcome":"No","oppositeAsset":"x","endDate":"2099-01-01","negativeRisk":false}}"#
+        )
+    }
+
+    #[derive(Default)]
+    struct RecordingCallback {
+        deltas: Mutex<Vec<PositionDelta>>,
+    }
+
+    impl PositionDeltaCallback for RecordingCallback {
+        fn on_position_delta(&self, delta: &PositionDelta) {
+            self.deltas.lock().push(delta.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_callback_fires_on_closed_and_opened_positions() {
+        let first = format!("[{},{}]", position_json("held", 10.0), position_json("closing", 5.0));
+        let second = format!("[{},{}]", position_json("held", 12.0), position_json("opening", 3.0));
+        let server = MockClobServer::start().await;
+        server.respond_sequence(Method::GET, "/positions", vec![MockResponse::text(200, first)]);
+        server.respond(Method::GET, "/positions", MockResponse::text(200, second));
+
+        let recorder = Arc::new(RecordingCallback::default());
+        let shutdown = Arc::new(AtomicBool::new(true));
+        let positions = spawn_position_tracker(
+            Arc::new(DataApiClient::with_base_url(server.url())),
+            "0xabc".to_string(),
+            Arc::clone(&shutdown),
+            Duration::from_millis(20),
+            Some(recorder.clone() as Arc<dyn PositionDeltaCallback>),
+        )
+        .await;
+
+        // Hydration fires nothing
+        assert_eq!(positions.read().len(), 2);
+        assert!(recorder.deltas.lock().is_empty());
+
+        for _ in 0..100 {
+            if recorder.deltas.lock().len() >= 2 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        shutdown.store(false, Ordering::Release);
+
+        let mut deltas = recorder.deltas.lock().clone();
+        deltas.sort_by(|a, b| a.asset.cmp(&b.asset));
+        assert_eq!(deltas.len(), 2, "size change without crossing zero must not fire");
+        assert_eq!(deltas[0].asset, "closing");
+        assert_eq!(deltas[0].transition, PositionTransition::Closed);
+        assert_eq!((deltas[0].old_size, deltas[0].new_size), (5.0, 0.0));
+        assert_eq!(deltas[1].asset, "opening");
+        assert_eq!(deltas[1].transition, PositionTransition::Opened);
+
+        let positions = positions.read();
+        assert_eq!(positions["held"].size, 12.0);
+        assert!(!positions.contains_key("closing"));
+    }
+
+    #[test]
+    fn test_diff_detects_sign_flip() {
+        let position = |size: f64| {
+            let position: Position = serde_json::from_str(&position_json("a", size)).unwrap();
+            HashMap::from([("a".to_string(), position)])
+        };
+
+        let deltas = diff_positions(&position(4.0), &position(-2.0));
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(deltas[0].transition, PositionTransition::Flipped);
+
+        assert!(diff_positions(&position(4.0), &position(6.0)).is_empty());
+    }
+}
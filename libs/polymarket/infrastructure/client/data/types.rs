@@ -7,6 +7,10 @@ use serde::{Deserialize, Serialize};
 // =============================================================================
 
 /// Position data from the Data API
+///
+/// The cost-basis and P&L fields (`avg_price`, `current_value`,
+/// `cash_pnl`, `realized_pnl`) default to 0.0 when the API omits them,
+/// which it does for some freshly opened or fully closed positions.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Position {
@@ -22,16 +26,19 @@ pub struct Position {
     /// Position size (number of shares)
     pub size: f64,
 
-    /// Average entry price
+    /// Average entry price (0.0 if omitted)
+    #[serde(default)]
     pub avg_price: f64,
 
     /// Initial position value (entry value)
     pub initial_value: f64,
 
-    /// Current position value
+    /// Current position value (0.0 if omitted)
+    #[serde(default)]
     pub current_value: f64,
 
-    /// Unrealized cash P&L
+    /// Unrealized cash P&L (0.0 if omitted)
+    #[serde(default)]
     pub cash_pnl: f64,
 
     /// Unrealized percentage P&L
@@ -40,7 +47,8 @@ pub struct Position {
     /// Total amount bought
     pub total_bought: f64,
 
-    /// Realized P&L from closed portions
+    /// Realized P&L from closed portions (0.0 if omitted)
+    #[serde(default)]
     pub realized_pnl: f64,
 
     /// Realized P&L as percentage
@@ -93,6 +101,204 @@ pub struct Position {
     pub event_slug: Option<String>,
 }
 
+impl Position {
+    /// Unrealized P&L; the API reports it as `cashPnl`
+    pub fn unrealized_pnl(&self) -> f64 {
+        self.cash_pnl
+    }
+}
+
+// =============================================================================
+// Activity
+// =============================================================================
+
+/// Kind of on-chain activity reported by the Data API
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ActivityType {
+    Trade,
+    Split,
+    Merge,
+    Redeem,
+    Reward,
+    Conversion,
+    /// Any type this client doesn't know yet
+    #[serde(other)]
+    Unknown,
+}
+
+impl ActivityType {
+    /// Convert to API query string value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActivityType::Trade => "TRADE",
+            ActivityType::Split => "SPLIT",
+            ActivityType::Merge => "MERGE",
+            ActivityType::Redeem => "REDEEM",
+            ActivityType::Reward => "REWARD",
+            ActivityType::Conversion => "CONVERSION",
+            ActivityType::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+/// One entry of a user's activity history from the Data API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Activity {
+    /// User's proxy wallet address
+    pub proxy_wallet: String,
+
+    /// Unix timestamp (seconds)
+    pub timestamp: i64,
+
+    /// Market condition ID
+    pub condition_id: String,
+
+    /// Activity type
+    #[serde(rename = "type")]
+    pub activity_type: ActivityType,
+
+    /// Number of shares
+    pub size: f64,
+
+    /// USDC value of the activity
+    pub usdc_size: f64,
+
+    /// On-chain transaction hash
+    pub transaction_hash: String,
+
+    // Trade-only fields; empty or zero for merges, redeems, etc.
+    /// Trade price
+    #[serde(default)]
+    pub price: f64,
+
+    /// Asset token ID
+    #[serde(default)]
+    pub asset: String,
+
+    /// BUY or SELL
+    #[serde(default)]
+    pub side: String,
+
+    /// Outcome index (0 or 1)
+    #[serde(default)]
+    pub outcome_index: Option<i32>,
+
+    /// Market title/question
+    #[serde(default)]
+    pub title: String,
+
+    /// Market URL slug
+    #[serde(default)]
+    pub slug: String,
+
+    /// Outcome name (e.g., "Yes", "No")
+    #[serde(default)]
+    pub outcome: String,
+
+    /// Event slug
+    #[serde(default)]
+    pub event_slug: Option<String>,
+}
+
+/// Query filters for activity requests
+#[derive(Debug, Clone, Default)]
+pub struct ActivityFilters {
+    /// Filter by condition IDs
+    pub market: Option<Vec<String>>,
+
+    /// Only these activity types (all types if unset)
+    pub activity_types: Option<Vec<ActivityType>>,
+
+    /// Earliest timestamp (unix seconds, inclusive)
+    pub start: Option<i64>,
+
+    /// Latest timestamp (unix seconds, inclusive)
+    pub end: Option<i64>,
+
+    /// Results per page (0-500, default: 100)
+    pub limit: Option<u32>,
+
+    /// Pagination offset (0-10000)
+    pub offset: Option<u32>,
+
+    /// Sort direction by timestamp
+    pub sort_direction: Option<SortDirection>,
+}
+
+impl ActivityFilters {
+    /// Create new empty filters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Convert filters to query parameters
+    pub fn to_query_params(&self) -> Vec<(String, String)> {
+        let mut params = Vec::new();
+
+        if let Some(markets) = &self.market {
+            if !markets.is_empty() {
+                params.push(("market".to_string(), markets.join(",")));
+            }
+        }
+
+        if let Some(types) = &self.activity_types {
+            if !types.is_empty() {
+                let types: Vec<&str> = types.iter().map(|t| t.as_str()).collect();
+                params.push(("type".to_string(), types.join(",")));
+            }
+        }
+
+        if let Some(start) = self.start {
+            params.push(("start".to_string(), start.to_string()));
+        }
+
+        if let Some(end) = self.end {
+            params.push(("end".to_string(), end.to_string()));
+        }
+
+        if let Some(limit) = self.limit {
+            params.push(("limit".to_string(), limit.to_string()));
+        }
+
+        if let Some(offset) = self.offset {
+            params.push(("offset".to_string(), offset.to_string()));
+        }
+
+        if let Some(direction) = &self.sort_direction {
+            params.push(("sortDirection".to_string(), direction.as_str().to_string()));
+        }
+
+        params
+    }
+
+    /// Set activity types and return self for builder pattern
+    pub fn with_types(mut self, types: Vec<ActivityType>) -> Self {
+        self.activity_types = Some(types);
+        self
+    }
+
+    /// Set time range and return self for builder pattern
+    pub fn with_time_range(mut self, start: i64, end: i64) -> Self {
+        self.start = Some(start);
+        self.end = Some(end);
+        self
+    }
+
+    /// Set limit (page size) and return self for builder pattern
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set market condition IDs and return self for builder pattern
+    pub fn with_markets(mut self, condition_ids: Vec<String>) -> Self {
+        self.market = Some(condition_ids);
+        self
+    }
+}
+
 // =============================================================================
 // Filters and Sorting
 // =============================================================================
@@ -109,6 +315,9 @@ pub enum PositionSortBy {
     Tokens,
     /// Sort by cash P&L
     CashPnl,
+    /// Sort by unrealized P&L; same API order as `CashPnl`, use
+    /// `SortDirection::Asc` for the biggest losers first
+    UnrealizedPnl,
     /// Sort by percent P&L
     PercentPnl,
     /// Sort by market title
@@ -128,7 +337,7 @@ impl PositionSortBy {
             PositionSortBy::Current => "CURRENT",
             PositionSortBy::Initial => "INITIAL",
             PositionSortBy::Tokens => "TOKENS",
-            PositionSortBy::CashPnl => "CASHPNL",
+            PositionSortBy::CashPnl | PositionSortBy::UnrealizedPnl => "CASHPNL",
             PositionSortBy::PercentPnl => "PERCENTPNL",
             PositionSortBy::Title => "TITLE",
             PositionSortBy::Resolving => "RESOLVING",
@@ -362,11 +571,149 @@ mod tests {
         assert_eq!(position.event_slug, None);
     }
 
+    #[test]
+    fn test_position_pnl_fields() {
+        // Captured from /positions for a partly closed position
+        let json = r#"{
+            "proxyWallet": "0x000000000000000000000000000000000000beef",
+            "asset": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+            "conditionId": "0xe3b423dfad8c22ff75c9899c4e8176f628cf4ad4caa00481764d320e7415f7a9",
+            "size": 412.5,
+            "avgPrice": 0.6231,
+            "initialValue": 257.02875,
+            "currentValue": 189.75,
+            "cashPnl": -67.27875,
+            "percentPnl": -26.1755,
+            "totalBought": 650,
+            "realizedPnl": 31.4212,
+            "percentRealizedPnl": 12.2247,
+            "curPrice": 0.46,
+            "redeemable": false,
+            "mergeable": false,
+            "title": "Will the Fed cut rates in December?",
+            "slug": "will-the-fed-cut-rates-in-december",
+            "icon": "https://example.com/fed.png",
+            "eventId": "35090",
+            "eventSlug": "fed-decision-in-december",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "oppositeOutcome": "No",
+            "oppositeAsset": "60487116984468020978247225474488676749601001829886755968952521846780452448915",
+            "endDate": "2025-12-10",
+            "negativeRisk": true
+        }"#;
+
+        let position: Position = serde_json::from_str(json).expect("Failed to deserialize position");
+
+        assert_eq!(position.avg_price, 0.6231);
+        assert_eq!(position.current_value, 189.75);
+        assert_eq!(position.cash_pnl, -67.27875);
+        assert_eq!(position.unrealized_pnl(), position.cash_pnl);
+        assert_eq!(position.realized_pnl, 31.4212);
+    }
+
+    #[test]
+    fn test_position_pnl_fields_default_to_zero() {
+        let json = r#"{
+            "proxyWallet": "0xabc",
+            "asset": "123",
+            "conditionId": "0xdef",
+            "size": 100,
+            "initialValue": 50,
+            "percentPnl": 0,
+            "totalBought": 50,
+            "percentRealizedPnl": 0,
+            "curPrice": 0.5,
+            "redeemable": false,
+            "mergeable": false,
+            "title": "Test Market",
+            "slug": "test-market",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "oppositeOutcome": "No",
+            "oppositeAsset": "456",
+            "endDate": "2025-01-01",
+            "negativeRisk": false
+        }"#;
+
+        let position: Position = serde_json::from_str(json).expect("Failed to deserialize position");
+
+        assert_eq!(position.avg_price, 0.0);
+        assert_eq!(position.current_value, 0.0);
+        assert_eq!(position.cash_pnl, 0.0);
+        assert_eq!(position.realized_pnl, 0.0);
+    }
+
+    #[test]
+    fn test_activity_deserialization() {
+        let json = r#"[
+            {
+                "proxyWallet": "0xabc",
+                "timestamp": 1723772457,
+                "conditionId": "0xdef",
+                "type": "TRADE",
+                "size": 100,
+                "usdcSize": 55,
+                "transactionHash": "0x123",
+                "price": 0.55,
+                "asset": "456",
+                "side": "BUY",
+                "outcomeIndex": 0,
+                "title": "Test Market",
+                "slug": "test-market",
+                "outcome": "Yes"
+            },
+            {
+                "proxyWallet": "0xabc",
+                "timestamp": 1723772500,
+                "conditionId": "0xdef",
+                "type": "MERGE",
+                "size": 50,
+                "usdcSize": 50,
+                "transactionHash": "0x789"
+            },
+            {
+                "proxyWallet": "0xabc",
+                "timestamp": 1723772600,
+                "conditionId": "0xdef",
+                "type": "SOMETHING_NEW",
+                "size": 0,
+                "usdcSize": 0,
+                "transactionHash": "0x000"
+            }
+        ]"#;
+
+        let activity: Vec<Activity> = serde_json::from_str(json).expect("Failed to deserialize activity");
+
+        assert_eq!(activity[0].activity_type, ActivityType::Trade);
+        assert_eq!(activity[0].price, 0.55);
+        assert_eq!(activity[0].side, "BUY");
+        assert_eq!(activity[1].activity_type, ActivityType::Merge);
+        assert_eq!(activity[1].usdc_size, 50.0);
+        assert_eq!(activity[1].side, "");
+        assert_eq!(activity[2].activity_type, ActivityType::Unknown);
+    }
+
+    #[test]
+    fn test_activity_filters_to_query_params() {
+        let filters = ActivityFilters::new()
+            .with_types(vec![ActivityType::Trade, ActivityType::Merge, ActivityType::Redeem])
+            .with_time_range(100, 200)
+            .with_limit(50);
+
+        let params = filters.to_query_params();
+        assert!(params.iter().any(|(k, v)| k == "type" && v == "TRADE,MERGE,REDEEM"));
+        assert!(params.iter().any(|(k, v)| k == "start" && v == "100"));
+        assert!(params.iter().any(|(k, v)| k == "end" && v == "200"));
+        assert!(params.iter().any(|(k, v)| k == "limit" && v == "50"));
+    }
+
     #[test]
     fn test_position_sort_by_as_str() {
         assert_eq!(PositionSortBy::Current.as_str(), "CURRENT");
         assert_eq!(PositionSortBy::Tokens.as_str(), "TOKENS");
         assert_eq!(PositionSortBy::CashPnl.as_str(), "CASHPNL");
+        assert_eq!(PositionSortBy::UnrealizedPnl.as_str(), "CASHPNL");
     }
 
     #[test]
@@ -0,0 +1,168 @@
+//! Circuit breaker for Gamma API requests
+//!
+//! During a Gamma outage the syncer would otherwise retry every cycle and
+//! log a failure each time. After `failure_threshold` consecutive failures
+//! the breaker opens: requests fail fast for `cooldown` (logged once, on
+//! opening). The first request after the cooldown is let through as a
+//! half-open probe; success closes the breaker, failure re-opens it. A probe
+//! that never reports back (its future was dropped) is written off after
+//! another `cooldown` and a new probe is let through.
+
+use parking_lot::Mutex;
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BreakerState {
+    /// Requests flow; counts consecutive failures
+    Closed { failures: u32 },
+    /// Requests fail fast until the deadline
+    Open { until: Instant },
+    /// One probe request is in flight since `since`
+    HalfOpen { since: Instant },
+}
+
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    state: Mutex<BreakerState>,
+}
+
+impl CircuitBreaker {
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            state: Mutex::new(BreakerState::Closed { failures: 0 }),
+        }
+    }
+
+    pub fn state(&self) -> BreakerState {
+        *self.state.lock()
+    }
+
+    /// Whether a request may go out at `now`.
+    ///
+    /// `Err` carries the time left until the next probe is allowed.
+    pub fn try_acquire(&self, now: Instant) -> Result<(), Duration> {
+        let mut state = self.state.lock();
+        match *state {
+            BreakerState::Closed { .. } => Ok(()),
+            BreakerState::Open { until } if now >= until => {
+                info!("[Gamma] Circuit breaker half-open, probing");
+                *state = BreakerState::HalfOpen { since: now };
+                Ok(())
+            }
+            BreakerState::Open { until } => Err(until - now),
+            BreakerState::HalfOpen { since } if now >= since + self.cooldown => {
+                warn!("[Gamma] Circuit breaker probe never completed, probing again");
+                *state = BreakerState::HalfOpen { since: now };
+                Ok(())
+            }
+            // Only the probe goes out while half-open
+            BreakerState::HalfOpen { since } => Err(since + self.cooldown - now),
+        }
+    }
+
+    pub fn record_success(&self) {
+        let mut state = self.state.lock();
+        if !matches!(*state, BreakerState::Closed { .. }) {
+            info!("[Gamma] Circuit breaker closed");
+        }
+        *state = BreakerState::Closed { failures: 0 };
+    }
+
+    pub fn record_failure(&self, now: Instant) {
+        let mut state = self.state.lock();
+        let failures = match *state {
+            BreakerState::Closed { failures } => failures + 1,
+            BreakerState::HalfOpen { .. } => self.failure_threshold,
+            BreakerState::Open { .. } => return,
+        };
+
+        if failures >= self.failure_threshold {
+            warn!(
+                "[Gamma] Circuit breaker open after {} consecutive failures, failing fast for {:?}",
+                failures, self.cooldown
+            );
+            *state = BreakerState::Open {
+                until: now + self.cooldown,
+            };
+        } else {
+            *state = BreakerState::Closed { failures };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_breaker_opens_then_closes_on_probe_success() {
+        let breaker = CircuitBreaker::new(3, Duration::from_secs(30));
+        let t0 = Instant::now();
+
+        for _ in 0..2 {
+            assert!(breaker.try_acquire(t0).is_ok());
+            breaker.record_failure(t0);
+        }
+        assert_eq!(breaker.state(), BreakerState::Closed { failures: 2 });
+
+        breaker.record_failure(t0);
+        assert!(matches!(breaker.state(), BreakerState::Open { .. }));
+
+        // Fails fast during the cooldown
+        let err = breaker.try_acquire(t0 + Duration::from_secs(10)).unwrap_err();
+        assert_eq!(err, Duration::from_secs(20));
+
+        // Cooldown over: a single probe is let through
+        let t1 = t0 + Duration::from_secs(30);
+        assert!(breaker.try_acquire(t1).is_ok());
+        assert_eq!(breaker.state(), BreakerState::HalfOpen { since: t1 });
+        assert!(breaker.try_acquire(t1).is_err());
+
+        breaker.record_success();
+        assert_eq!(breaker.state(), BreakerState::Closed { failures: 0 });
+        assert!(breaker.try_acquire(t1).is_ok());
+    }
+
+    #[test]
+    fn test_failed_probe_reopens() {
+        let breaker = CircuitBreaker::new(1, Duration::from_secs(5));
+        let t0 = Instant::now();
+
+        breaker.record_failure(t0);
+        let t1 = t0 + Duration::from_secs(5);
+        assert!(breaker.try_acquire(t1).is_ok());
+
+        breaker.record_failure(t1);
+        assert_eq!(
+            breaker.state(),
+            BreakerState::Open {
+                until: t1 + Duration::from_secs(5)
+            }
+        );
+    }
+
+    #[test]
+    fn test_lost_probe_is_replaced_after_cooldown() {
+        let breaker = CircuitBreaker::new(1, Duration::from_secs(5));
+        let t0 = Instant::now();
+
+        breaker.record_failure(t0);
+        let t1 = t0 + Duration::from_secs(5);
+        assert!(breaker.try_acquire(t1).is_ok());
+
+        // The probe never records a result (e.g. its future was dropped)
+        let err = breaker.try_acquire(t1 + Duration::from_secs(2)).unwrap_err();
+        assert_eq!(err, Duration::from_secs(3));
+
+        let t2 = t1 + Duration::from_secs(5);
+        assert!(breaker.try_acquire(t2).is_ok());
+        assert_eq!(breaker.state(), BreakerState::HalfOpen { since: t2 });
+
+        breaker.record_success();
+        assert_eq!(breaker.state(), BreakerState::Closed { failures: 0 });
+    }
+}
@@ -1,7 +1,10 @@
-use super::types::{Event, GammaFilters, Market};
+use super::circuit_breaker::{BreakerState, CircuitBreaker};
+use super::types::{Event, FilterError, GammaFilters, Market, MarketStatus};
 use chrono::{DateTime, Utc};
+use futures::stream::{self, BoxStream, StreamExt};
 use reqwest::Client;
-use std::time::Duration;
+use std::future::Future;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use tracing::{debug, info, warn};
 
@@ -18,14 +21,37 @@ pub enum GammaError {
 
     #[error("Rate limit exceeded")]
     RateLimitExceeded,
+
+    #[error("Circuit breaker open, retry in {0:?}")]
+    CircuitOpen(Duration),
+
+    #[error("Pagination stopped after {0} pages without reaching an empty page")]
+    PageLimitExceeded(usize),
+
+    #[error("Invalid filters: {0}")]
+    InvalidFilters(#[from] FilterError),
 }
 
 pub type Result<T> = std::result::Result<T, GammaError>;
 
+/// Consecutive failures before the circuit breaker opens
+const DEFAULT_BREAKER_THRESHOLD: u32 = 5;
+
+/// How long the breaker fails fast before probing again
+const DEFAULT_BREAKER_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Page size for paginated fetches (max per Gamma API spec)
+const PAGE_LIMIT: usize = 100;
+
+/// Pages a paginated stream fetches before giving up
+const DEFAULT_MAX_PAGES: usize = 1000;
+
 /// Gamma Markets API client
 pub struct GammaClient {
     base_url: String,
     client: Client,
+    breaker: CircuitBreaker,
+    max_pages: usize,
 }
 
 impl GammaClient {
@@ -45,19 +71,50 @@ impl GammaClient {
         Self {
             base_url: base_url.into(),
             client,
+            breaker: CircuitBreaker::new(DEFAULT_BREAKER_THRESHOLD, DEFAULT_BREAKER_COOLDOWN),
+            max_pages: DEFAULT_MAX_PAGES,
+        }
+    }
+
+    /// Override how many pages `fetch_all_markets`/`fetch_all_events` walk
+    /// before failing with `PageLimitExceeded`
+    pub fn with_max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = max_pages;
+        self
+    }
+
+    /// Override the circuit breaker settings
+    pub fn with_circuit_breaker(mut self, failure_threshold: u32, cooldown: Duration) -> Self {
+        self.breaker = CircuitBreaker::new(failure_threshold, cooldown);
+        self
+    }
+
+    /// Whether requests are currently failing fast
+    pub fn is_circuit_open(&self) -> bool {
+        !matches!(self.breaker.state(), BreakerState::Closed { .. })
+    }
+
+    /// Run a request through the circuit breaker
+    ///
+    /// Fails fast with `CircuitOpen` while the breaker is open. Bad payloads
+    /// don't count as failures: Gamma answered, so it isn't down.
+    async fn guarded<T>(&self, request: impl Future<Output = Result<T>>) -> Result<T> {
+        self.breaker
+            .try_acquire(Instant::now())
+            .map_err(GammaError::CircuitOpen)?;
+
+        let result = request.await;
+        match &result {
+            Ok(_) | Err(GammaError::DeserializeFailed(_)) => self.breaker.record_success(),
+            Err(_) => self.breaker.record_failure(Instant::now()),
         }
+        result
     }
 
     /// Fetch ALL active markets with pagination
     pub async fn get_all_active_markets(&self) -> Result<Vec<Market>> {
-        let filters = GammaFilters {
-            active: Some(true),
-            closed: Some(false),
-            archived: Some(false),
-            ..Default::default()
-        };
-
-        self.get_all_markets_with_filters(filters).await
+        self.get_all_markets_with_filters(GammaFilters::with_status(MarketStatus::Active))
+            .await
     }
 
     /// Fetch ALL markets with custom filters and pagination
@@ -97,16 +154,83 @@ impl GammaClient {
         Ok(all_markets)
     }
 
+    /// Stream all markets matching `filters`, fetching pages lazily
+    ///
+    /// Walks `offset`/`limit` until the API returns an empty page. A failed
+    /// page is yielded as an error and ends the stream, as does hitting the
+    /// max-pages cap (`PageLimitExceeded`).
+    pub fn fetch_all_markets(&self, filters: GammaFilters) -> BoxStream<'_, Result<Market>> {
+        self.paginate("markets", move |offset| {
+            self.get_markets_page(PAGE_LIMIT, offset, filters.clone())
+        })
+    }
+
+    /// Stream all open events, fetching pages lazily (see `fetch_all_markets`)
+    pub fn fetch_all_events(&self) -> BoxStream<'_, Result<Event>> {
+        self.paginate("events", |offset| self.get_events_page(PAGE_LIMIT, offset))
+    }
+
+    /// Turn a page fetcher (called with the page offset) into a stream of items
+    fn paginate<'a, T, F, Fut>(&'a self, what: &'static str, fetch_page: F) -> BoxStream<'a, Result<T>>
+    where
+        T: Send + 'a,
+        F: Fn(usize) -> Fut + Send + 'a,
+        Fut: Future<Output = Result<Vec<T>>> + Send + 'a,
+    {
+        let max_pages = self.max_pages;
+
+        stream::unfold(Some(0usize), move |page| {
+            let request = page
+                .filter(|&page| page < max_pages)
+                .map(|page| fetch_page(page * PAGE_LIMIT));
+
+            async move {
+                let page = page?;
+                let Some(request) = request else {
+                    warn!("Stopping {} pagination at the {} page cap", what, max_pages);
+                    return Some((vec![Err(GammaError::PageLimitExceeded(max_pages))], None));
+                };
+
+                if page > 0 {
+                    // Rate limit protection: 100 req/10s = ~100ms between requests
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                }
+
+                debug!("Fetching {} page {}", what, page);
+                match request.await {
+                    Ok(items) if items.is_empty() => {
+                        debug!("Reached end of {} pagination after {} pages", what, page);
+                        None
+                    }
+                    Ok(items) => Some((items.into_iter().map(Ok).collect(), Some(page + 1))),
+                    Err(e) => Some((vec![Err(e)], None)),
+                }
+            }
+        })
+        .flat_map(stream::iter)
+        .boxed()
+    }
+
     /// Fetch single page of markets
     pub async fn get_markets_page(
         &self,
         limit: usize,
         offset: usize,
         filters: GammaFilters,
+    ) -> Result<Vec<Market>> {
+        self.guarded(self.fetch_markets_page(limit, offset, filters))
+            .await
+    }
+
+    async fn fetch_markets_page(
+        &self,
+        limit: usize,
+        offset: usize,
+        filters: GammaFilters,
     ) -> Result<Vec<Market>> {
         let url = format!("{}/markets", self.base_url);
 
-        let mut params = filters.to_query_params();
+        let mut params = filters.to_query_params()?;
         params.push(("limit".to_string(), limit.to_string()));
         params.push(("offset".to_string(), offset.to_string()));
         params.push(("order".to_string(), "id".to_string()));
@@ -190,6 +314,10 @@ impl GammaClient {
 
     /// Fetch single page of events
     pub async fn get_events_page(&self, limit: usize, offset: usize) -> Result<Vec<Event>> {
+        self.guarded(self.fetch_events_page(limit, offset)).await
+    }
+
+    async fn fetch_events_page(&self, limit: usize, offset: usize) -> Result<Vec<Event>> {
         let url = format!("{}/events", self.base_url);
 
         // Store strings before borrowing
@@ -247,6 +375,8 @@ impl GammaClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mock_clob::{MockClobServer, MockResponse};
+    use hyper::Method;
 
     #[test]
     fn test_gamma_client_creation() {
@@ -262,8 +392,162 @@ mod tests {
             ..Default::default()
         };
 
-        let params = filters.to_query_params();
+        let params = filters.to_query_params().unwrap();
         assert!(params.iter().any(|(k, v)| k == "active" && v == "true"));
         assert!(params.iter().any(|(k, v)| k == "closed" && v == "false"));
     }
+
+    fn query_string(filters: &GammaFilters) -> String {
+        filters
+            .to_query_params()
+            .unwrap()
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    #[test]
+    fn test_status_query_strings() {
+        for (status, expected) in [
+            (MarketStatus::Active, "active=true&closed=false&archived=false"),
+            (MarketStatus::Closed, "closed=true&archived=false"),
+            (MarketStatus::Archived, "archived=true"),
+            (MarketStatus::All, ""),
+        ] {
+            assert_eq!(query_string(&GammaFilters::with_status(status)), expected, "{}", status);
+        }
+    }
+
+    #[test]
+    fn test_date_range_query_string() {
+        let start = "2025-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let end = "2025-02-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let filters = GammaFilters {
+            start_date_min: Some(start),
+            end_date_max: Some(end),
+            ..GammaFilters::with_status(MarketStatus::Closed)
+        };
+
+        assert_eq!(
+            query_string(&filters),
+            "closed=true&archived=false&start_date_min=2025-01-01T00:00:00+00:00&end_date_max=2025-02-01T00:00:00+00:00"
+        );
+
+        let reversed = GammaFilters {
+            start_date_min: Some(end),
+            end_date_max: Some(start),
+            ..Default::default()
+        };
+        assert!(matches!(reversed.to_query_params(), Err(FilterError::EmptyDateRange { .. })));
+    }
+
+    #[test]
+    fn test_contradictory_filters_are_rejected() {
+        let archived_open = GammaFilters {
+            closed: Some(false),
+            archived: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(archived_open.to_query_params(), Err(FilterError::ArchivedNotClosed));
+
+        let active_but_closed = GammaFilters {
+            closed: Some(true),
+            ..GammaFilters::with_status(MarketStatus::Active)
+        };
+        assert_eq!(
+            active_but_closed.to_query_params(),
+            Err(FilterError::StatusConflict {
+                status: MarketStatus::Active,
+                param: "closed",
+                value: true,
+            })
+        );
+
+        let archived_but_open = GammaFilters {
+            closed: Some(false),
+            ..GammaFilters::with_status(MarketStatus::Archived)
+        };
+        assert_eq!(archived_but_open.to_query_params(), Err(FilterError::ArchivedNotClosed));
+
+        // Flags that agree with the status are fine
+        let refined = GammaFilters {
+            active: Some(true),
+            ..GammaFilters::with_status(MarketStatus::Active)
+        };
+        assert_eq!(query_string(&refined), "active=true&closed=false&archived=false");
+    }
+
+    /// Mock server answering `path` with `pages` in order, then empty pages
+    async fn pages_server(path: &str, pages: Vec<&'static str>) -> MockClobServer {
+        let server = MockClobServer::start().await;
+        let pages = pages.into_iter().map(|page| MockResponse::text(200, page)).collect();
+        server.respond_sequence(Method::GET, path, pages);
+        server.respond(Method::GET, path, MockResponse::text(200, "[]"));
+        server
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_markets_walks_pages_until_empty() {
+        let server = pages_server(
+            "/markets",
+            vec![
+                r#"[{"id":"1"},{"id":"2"}]"#,
+                r#"[{"id":"3"},{"id":"4"}]"#,
+                r#"[{"id":"5"}]"#,
+            ],
+        )
+        .await;
+        let client = GammaClient::new(server.url());
+
+        let ids: Vec<String> = client
+            .fetch_all_markets(GammaFilters::default())
+            .map(|market| market.unwrap().id.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(ids, ["1", "2", "3", "4", "5"]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_events_stops_at_page_cap() {
+        let server = pages_server(
+            "/events",
+            vec![r#"[{"id":"a"}]"#, r#"[{"id":"b"}]"#, r#"[{"id":"c"}]"#],
+        )
+        .await;
+        let client = GammaClient::new(server.url()).with_max_pages(2);
+
+        let events: Vec<Result<Event>> = client.fetch_all_events().collect().await;
+
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[1].as_ref().unwrap().id.as_deref(), Some("b"));
+        assert!(matches!(events[2], Err(GammaError::PageLimitExceeded(2))));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_markets_yields_http_errors() {
+        let client = GammaClient::new("http://127.0.0.1:1");
+
+        let markets: Vec<Result<Market>> = client.fetch_all_markets(GammaFilters::default()).collect().await;
+
+        assert_eq!(markets.len(), 1);
+        assert!(matches!(markets[0], Err(GammaError::RequestFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_breaker_fails_fast_after_consecutive_failures() {
+        // Nothing listens on port 1: every request is a connection failure
+        let client = GammaClient::new("http://127.0.0.1:1")
+            .with_circuit_breaker(2, Duration::from_secs(60));
+
+        for _ in 0..2 {
+            let err = client.get_events_page(10, 0).await.unwrap_err();
+            assert!(matches!(err, GammaError::RequestFailed(_)));
+        }
+
+        assert!(client.is_circuit_open());
+        let err = client.get_markets_page(10, 0, GammaFilters::default()).await.unwrap_err();
+        assert!(matches!(err, GammaError::CircuitOpen(_)));
+    }
 }
@@ -0,0 +1,288 @@
+//! Gamma market -> database row normalization
+//!
+//! Gamma leaves every field optional, so a record can reach the sync
+//! services without an ID, with an unparseable date, or with no tokens.
+//! `DbMarket::try_from_gamma` rejects those records with a
+//! [`ConversionError`] naming the offending field instead of storing
+//! placeholder values.
+
+use super::types::Market;
+use crate::domain::models::DbMarket;
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+/// A Gamma market could not be converted into a `DbMarket`
+#[derive(Error, Debug)]
+#[error("Market {market_id} has invalid {field}: {reason}")]
+pub struct ConversionError {
+    /// Gamma market ID, or `"unknown"` when the ID itself is missing
+    pub market_id: String,
+    pub field: &'static str,
+    #[source]
+    pub reason: ConversionFailure,
+}
+
+/// Why a single field failed conversion
+#[derive(Error, Debug)]
+pub enum ConversionFailure {
+    #[error("field is missing")]
+    Missing,
+
+    #[error("'{value}' is not an RFC 3339 timestamp: {source}")]
+    InvalidDate {
+        value: String,
+        #[source]
+        source: chrono::ParseError,
+    },
+
+    #[error("not a JSON array of strings: {0}")]
+    MalformedArray(#[source] serde_json::Error),
+
+    #[error("array is empty")]
+    EmptyArray,
+
+    #[error("{outcomes} outcomes but {token_ids} token IDs")]
+    LengthMismatch { outcomes: usize, token_ids: usize },
+}
+
+impl DbMarket {
+    /// Convert a Gamma market, rejecting records the strategies could not trade
+    ///
+    /// `id` and `end_date` are required, dates must be RFC 3339, and
+    /// outcomes and token IDs must be non-empty arrays of equal length.
+    /// A missing `start_date` or `created_at` falls back to now. Event
+    /// tags, description and game ID are left unset for the caller.
+    pub fn try_from_gamma(m: &Market) -> Result<DbMarket, ConversionError> {
+        let market_id = m.id.clone().ok_or_else(|| ConversionError {
+            market_id: "unknown".to_string(),
+            field: "id",
+            reason: ConversionFailure::Missing,
+        })?;
+        let fail = |field: &'static str, reason: ConversionFailure| ConversionError {
+            market_id: market_id.clone(),
+            field,
+            reason,
+        };
+
+        let now = Utc::now().to_rfc3339();
+        let end_date = m
+            .end_date
+            .clone()
+            .ok_or_else(|| fail("end_date", ConversionFailure::Missing))?;
+        check_rfc3339(&end_date).map_err(|reason| fail("end_date", reason))?;
+        let start_date = m.start_date.clone().unwrap_or_else(|| now.clone());
+        check_rfc3339(&start_date).map_err(|reason| fail("start_date", reason))?;
+        let created_at = m.created_at.clone().unwrap_or_else(|| now.clone());
+        check_rfc3339(&created_at).map_err(|reason| fail("created_at", reason))?;
+
+        let raw_outcomes = m
+            .outcomes
+            .as_ref()
+            .ok_or_else(|| fail("outcomes", ConversionFailure::Missing))?;
+        let outcomes = parse_outcomes(raw_outcomes).map_err(|reason| fail("outcomes", reason))?;
+
+        let raw_token_ids = m
+            .clob_token_ids
+            .as_ref()
+            .ok_or_else(|| fail("token_ids", ConversionFailure::Missing))?;
+        let token_ids = parse_string_array(raw_token_ids)
+            .map_err(|reason| fail("token_ids", reason))?;
+
+        if outcomes.len() != token_ids.len() {
+            return Err(fail(
+                "token_ids",
+                ConversionFailure::LengthMismatch {
+                    outcomes: outcomes.len(),
+                    token_ids: token_ids.len(),
+                },
+            ));
+        }
+
+        // Stored in the encodings `parse_outcomes`/`parse_token_ids` read back
+        let outcomes_json = serde_json::to_string(raw_outcomes)
+            .map_err(|e| fail("outcomes", ConversionFailure::MalformedArray(e)))?;
+
+        Ok(DbMarket {
+            id: market_id.clone(),
+            condition_id: m.condition_id.clone(),
+            question: m.question.clone().unwrap_or_default(),
+            description: None,
+            slug: m.slug.clone(),
+            start_date,
+            resolution_time: end_date.clone(),
+            end_date,
+            active: m.active.unwrap_or(false),
+            closed: m.closed.unwrap_or(false),
+            archived: m.archived.unwrap_or(false),
+            market_type: None,
+            category: None,
+            liquidity: m.liquidity.clone(),
+            volume: m.volume.clone(),
+            outcomes: outcomes_json,
+            token_ids: raw_token_ids.clone(),
+            tags: None,
+            last_updated: now,
+            created_at,
+            game_id: None,
+        })
+    }
+}
+
+fn check_rfc3339(value: &str) -> Result<(), ConversionFailure> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|_| ())
+        .map_err(|source| ConversionFailure::InvalidDate {
+            value: value.to_string(),
+            source,
+        })
+}
+
+/// Gamma sends outcomes either as a JSON array or as a JSON-encoded string
+fn parse_outcomes(value: &serde_json::Value) -> Result<Vec<String>, ConversionFailure> {
+    match value {
+        serde_json::Value::String(s) => parse_string_array(s),
+        other => non_empty(
+            serde_json::from_value(other.clone()).map_err(ConversionFailure::MalformedArray)?,
+        ),
+    }
+}
+
+fn parse_string_array(raw: &str) -> Result<Vec<String>, ConversionFailure> {
+    non_empty(serde_json::from_str(raw).map_err(ConversionFailure::MalformedArray)?)
+}
+
+fn non_empty(values: Vec<String>) -> Result<Vec<String>, ConversionFailure> {
+    if values.is_empty() {
+        Err(ConversionFailure::EmptyArray)
+    } else {
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn gamma_market(overrides: serde_json::Value) -> Market {
+        let mut base = json!({
+            "id": "12345",
+            "question": "Will BTC be up at 3pm?",
+            "conditionId": "0xabc",
+            "slug": "btc-up-or-down",
+            "startDate": "2025-01-01T00:00:00Z",
+            "endDate": "2025-01-01T15:00:00Z",
+            "createdAt": "2024-12-31T12:00:00.123Z",
+            "outcomes": "[\"Up\", \"Down\"]",
+            "clobTokenIds": "[\"111\", \"222\"]",
+            "active": true,
+            "closed": false,
+        });
+        for (key, value) in overrides.as_object().unwrap() {
+            if value.is_null() {
+                base.as_object_mut().unwrap().remove(key);
+            } else {
+                base[key] = value.clone();
+            }
+        }
+        serde_json::from_value(base).unwrap()
+    }
+
+    fn conversion_error(overrides: serde_json::Value) -> ConversionError {
+        DbMarket::try_from_gamma(&gamma_market(overrides)).unwrap_err()
+    }
+
+    #[test]
+    fn test_well_formed_market_converts() {
+        let db = DbMarket::try_from_gamma(&gamma_market(json!({}))).unwrap();
+
+        assert_eq!(db.id, "12345");
+        assert_eq!(db.end_date, "2025-01-01T15:00:00Z");
+        assert_eq!(db.resolution_time, db.end_date);
+        assert_eq!(db.created_at, "2024-12-31T12:00:00.123Z");
+        assert!(db.active);
+        assert_eq!(db.parse_outcomes().unwrap(), vec!["Up", "Down"]);
+        assert_eq!(db.parse_token_ids().unwrap(), vec!["111", "222"]);
+    }
+
+    #[test]
+    fn test_outcomes_as_plain_array_are_accepted() {
+        let db = DbMarket::try_from_gamma(&gamma_market(json!({ "outcomes": ["Yes", "No"] })))
+            .unwrap();
+        assert_eq!(db.parse_outcomes().unwrap(), vec!["Yes", "No"]);
+    }
+
+    #[test]
+    fn test_missing_id_is_reported() {
+        let err = conversion_error(json!({ "id": null }));
+        assert_eq!(err.market_id, "unknown");
+        assert_eq!(err.field, "id");
+        assert!(matches!(err.reason, ConversionFailure::Missing));
+    }
+
+    #[test]
+    fn test_missing_end_date_is_reported() {
+        let err = conversion_error(json!({ "endDate": null }));
+        assert_eq!(err.market_id, "12345");
+        assert_eq!(err.field, "end_date");
+        assert!(matches!(err.reason, ConversionFailure::Missing));
+    }
+
+    #[test]
+    fn test_unparseable_dates_name_the_field() {
+        for (key, field) in [
+            ("endDate", "end_date"),
+            ("startDate", "start_date"),
+            ("createdAt", "created_at"),
+        ] {
+            let err = conversion_error(json!({ key: "January 1st" }));
+            assert_eq!(err.field, field);
+            match err.reason {
+                ConversionFailure::InvalidDate { value, .. } => assert_eq!(value, "January 1st"),
+                other => panic!("expected InvalidDate for {}, got {:?}", field, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_missing_start_date_defaults_to_now() {
+        let db = DbMarket::try_from_gamma(&gamma_market(json!({ "startDate": null }))).unwrap();
+        assert!(DateTime::parse_from_rfc3339(&db.start_date).is_ok());
+    }
+
+    #[test]
+    fn test_empty_arrays_are_reported() {
+        let err = conversion_error(json!({ "outcomes": "[]" }));
+        assert_eq!(err.field, "outcomes");
+        assert!(matches!(err.reason, ConversionFailure::EmptyArray));
+
+        let err = conversion_error(json!({ "clobTokenIds": "[]" }));
+        assert_eq!(err.field, "token_ids");
+        assert!(matches!(err.reason, ConversionFailure::EmptyArray));
+    }
+
+    #[test]
+    fn test_missing_and_malformed_arrays_are_reported() {
+        let err = conversion_error(json!({ "clobTokenIds": null }));
+        assert_eq!(err.field, "token_ids");
+        assert!(matches!(err.reason, ConversionFailure::Missing));
+
+        let err = conversion_error(json!({ "clobTokenIds": "111,222" }));
+        assert_eq!(err.field, "token_ids");
+        assert!(matches!(err.reason, ConversionFailure::MalformedArray(_)));
+
+        let err = conversion_error(json!({ "outcomes": 7 }));
+        assert_eq!(err.field, "outcomes");
+        assert!(matches!(err.reason, ConversionFailure::MalformedArray(_)));
+    }
+
+    #[test]
+    fn test_outcome_token_count_mismatch_is_reported() {
+        let err = conversion_error(json!({ "clobTokenIds": "[\"111\"]" }));
+        assert_eq!(err.field, "token_ids");
+        assert!(matches!(
+            err.reason,
+            ConversionFailure::LengthMismatch { outcomes: 2, token_ids: 1 }
+        ));
+    }
+}
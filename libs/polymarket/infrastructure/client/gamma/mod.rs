@@ -2,10 +2,14 @@
 //!
 //! The Gamma API provides market data and event information.
 
+pub mod circuit_breaker;
 pub mod client;
+pub mod convert;
 pub mod types;
 
-pub use client::GammaClient;
+pub use circuit_breaker::{BreakerState, CircuitBreaker};
+pub use client::{GammaClient, GammaError};
+pub use convert::{ConversionError, ConversionFailure};
 
 // Re-export types with Gamma prefix for backward compatibility
 pub use types::Event as GammaEvent;
@@ -13,5 +17,5 @@ pub use types::Market as GammaMarket;
 pub use types::Tag as GammaTag;
 pub use types::Events;
 
-// Re-export GammaFilters
-pub use types::GammaFilters;
+// Re-export filters
+pub use types::{FilterError, GammaFilters, MarketStatus};
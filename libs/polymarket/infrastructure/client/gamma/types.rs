@@ -11,7 +11,10 @@
 //     let model: Events = serde_json::from_str(&json).unwrap();
 // }
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
 
 pub type Events = Vec<Event>;
 
@@ -506,29 +509,109 @@ pub struct Tag {
     pub created_by: Option<f64>,
 }
 
+/// Gamma's (active, closed, archived) params; `None` leaves one out
+type LifecycleFlags = (Option<bool>, Option<bool>, Option<bool>);
+
+/// Lifecycle filter mapped onto Gamma's `active`/`closed`/`archived` params
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MarketStatus {
+    /// active=true, closed=false, archived=false
+    Active,
+    /// closed=true, archived=false
+    Closed,
+    /// archived=true
+    Archived,
+    /// No lifecycle params
+    All,
+}
+
+impl MarketStatus {
+    /// The (active, closed, archived) params this status sends
+    fn flags(self) -> LifecycleFlags {
+        match self {
+            MarketStatus::Active => (Some(true), Some(false), Some(false)),
+            MarketStatus::Closed => (None, Some(true), Some(false)),
+            MarketStatus::Archived => (None, None, Some(true)),
+            MarketStatus::All => (None, None, None),
+        }
+    }
+}
+
+impl fmt::Display for MarketStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MarketStatus::Active => "active",
+            MarketStatus::Closed => "closed",
+            MarketStatus::Archived => "archived",
+            MarketStatus::All => "all",
+        })
+    }
+}
+
+/// Contradictory `GammaFilters`
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum FilterError {
+    #[error("status={status} conflicts with {param}={value}")]
+    StatusConflict {
+        status: MarketStatus,
+        param: &'static str,
+        value: bool,
+    },
+
+    #[error("archived=true conflicts with closed=false: archived markets are always closed")]
+    ArchivedNotClosed,
+
+    #[error("start_date_min {start} is after end_date_max {end}")]
+    EmptyDateRange { start: DateTime<Utc>, end: DateTime<Utc> },
+}
+
 /// Filters for querying Gamma API
+///
+/// `status` is the usual way to pick a lifecycle; the raw `active`/`closed`/
+/// `archived` flags may refine it but not contradict it.
 #[derive(Debug, Clone, Default)]
 pub struct GammaFilters {
+    pub status: Option<MarketStatus>,
     pub active: Option<bool>,
     pub closed: Option<bool>,
     pub archived: Option<bool>,
-    pub start_date_min: Option<chrono::DateTime<chrono::Utc>>,
+    pub start_date_min: Option<DateTime<Utc>>,
+    pub end_date_max: Option<DateTime<Utc>>,
 }
 
 impl GammaFilters {
+    /// Filters selecting markets in `status`
+    pub fn with_status(status: MarketStatus) -> Self {
+        Self {
+            status: Some(status),
+            ..Default::default()
+        }
+    }
+
     /// Build query parameters for HTTP request
-    pub fn to_query_params(&self) -> Vec<(String, String)> {
+    ///
+    /// Fails if the filters contradict each other rather than letting the
+    /// API silently return nothing.
+    pub fn to_query_params(&self) -> Result<Vec<(String, String)>, FilterError> {
+        let (active, closed, archived) = self.lifecycle_flags()?;
+        if let (Some(start), Some(end)) = (self.start_date_min, self.end_date_max) {
+            if start > end {
+                return Err(FilterError::EmptyDateRange { start, end });
+            }
+        }
+
         let mut params = Vec::new();
 
-        if let Some(active) = self.active {
+        if let Some(active) = active {
             params.push(("active".to_string(), active.to_string()));
         }
 
-        if let Some(closed) = self.closed {
+        if let Some(closed) = closed {
             params.push(("closed".to_string(), closed.to_string()));
         }
 
-        if let Some(archived) = self.archived {
+        if let Some(archived) = archived {
             params.push(("archived".to_string(), archived.to_string()));
         }
 
@@ -536,6 +619,34 @@ impl GammaFilters {
             params.push(("start_date_min".to_string(), start_date_min.to_rfc3339()));
         }
 
-        params
+        if let Some(end_date_max) = self.end_date_max {
+            params.push(("end_date_max".to_string(), end_date_max.to_rfc3339()));
+        }
+
+        Ok(params)
+    }
+
+    /// Merge `status` with the raw flags into the (active, closed, archived)
+    /// params to send
+    fn lifecycle_flags(&self) -> Result<LifecycleFlags, FilterError> {
+        let (active, closed, archived) = self.status.map_or((None, None, None), MarketStatus::flags);
+
+        let merge = |param: &'static str, raw: Option<bool>, implied: Option<bool>| match (raw, implied) {
+            (Some(value), Some(expected)) if value != expected => Err(FilterError::StatusConflict {
+                status: self.status.unwrap_or(MarketStatus::All),
+                param,
+                value,
+            }),
+            _ => Ok(raw.or(implied)),
+        };
+
+        let active = merge("active", self.active, active)?;
+        let closed = merge("closed", self.closed, closed)?;
+        let archived = merge("archived", self.archived, archived)?;
+
+        if archived == Some(true) && closed == Some(false) {
+            return Err(FilterError::ArchivedNotClosed);
+        }
+        Ok((active, closed, archived))
     }
 }
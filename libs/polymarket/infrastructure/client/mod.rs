@@ -11,6 +11,7 @@ pub mod gamma;
 pub mod oracle;
 pub mod redeem;
 pub mod sports;
+pub mod unified_feed;
 pub mod user;
 
 pub use auth::PolymarketAuth;
@@ -19,13 +20,17 @@ pub use binance::{
     SharedBinancePrices,
 };
 pub use clob::{RestClient, WebSocketClient, Market, Outcome, OrderBook, PriceLevel, Side, OrderType, OrderArgs, TradingClient, TradingError};
-pub use data::{DataApiClient, Position, PositionFilters, PositionSortBy, SortDirection};
+pub use data::{
+    Activity, ActivityFilters, ActivityType, DataApiClient, Position, PositionFilters,
+    PositionSortBy, SortDirection,
+};
 pub use gamma::{GammaClient, GammaEvent, GammaMarket, GammaTag, GammaFilters};
-pub use oracle::{spawn_oracle_trackers, OraclePriceManager, SharedOraclePrices, OracleType, PriceEntry, CandlestickApiClient};
+pub use oracle::{spawn_oracle_trackers, spawn_oracle_trackers_for, OracleAssets, OraclePriceManager, PriceDivergence, SharedOraclePrices, SharedPrices, OracleType, PriceEntry, CandlestickApiClient};
 pub use sports::{spawn_sports_live_data_tracker, SportsLiveData, SportsLiveDataMessage, SportsRoute};
+pub use unified_feed::{FeedForwarder, IntoUnified, UnifiedFeed, UnifiedMessage};
 // Note: OrderManager and related types moved to infrastructure::order_manager
 pub use redeem::{
-    RedeemClient, RedeemError, RedemptionResult,
+    RedeemClient, RedeemError, RedeemScheduler, Redemption, RedemptionResult,
     create_signer_provider, fetch_redeemable_positions,
     redeem_all_positions, redeem_single, redeem_all,
     redeem_via_safe,
@@ -34,7 +39,7 @@ pub use redeem::{
 pub use ctf::{
     CtfClient, CtfError, CtfOperation, CtfOperationResult,
     split_via_safe, merge_via_safe, approve_via_safe,
-    split, merge,
+    split, merge, wait_for_confirmations,
     usdc_to_raw, usdc_from_raw,
-    USDC_DECIMALS, CTF_CONTRACT, NEG_RISK_CTF_CONTRACT, USDC_ADDRESS,
+    USDC_DECIMALS, DEFAULT_MIN_CONFIRMATIONS, CTF_CONTRACT, NEG_RISK_CTF_CONTRACT, USDC_ADDRESS,
 };
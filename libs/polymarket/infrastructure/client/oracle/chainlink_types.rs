@@ -36,6 +36,16 @@ impl FeedIdMap {
         }
     }
 
+    /// Default mappings restricted to `symbols` (case-insensitive)
+    pub fn with_symbols(symbols: &[String]) -> Self {
+        let mut map = Self::new();
+        map.symbol_to_feed
+            .retain(|symbol, _| symbols.iter().any(|s| s.eq_ignore_ascii_case(symbol)));
+        map.feed_to_symbol
+            .retain(|_, symbol| symbols.iter().any(|s| s.eq_ignore_ascii_case(symbol)));
+        map
+    }
+
     /// Get feed ID for a symbol
     pub fn get_feed_id(&self, symbol: &str) -> Option<&String> {
         self.symbol_to_feed.get(&symbol.to_uppercase())
@@ -154,6 +164,20 @@ mod tests {
         assert!(param.contains("0x000362205e10b3a147d02792eccee483dca6c7b44ecce7012cb8c6e0b68b3ae9"));
     }
 
+    #[test]
+    fn test_feed_ids_param_with_symbols() {
+        let map = FeedIdMap::with_symbols(&["eth".to_string()]);
+        assert_eq!(
+            map.get_feed_ids_param(),
+            "0x000362205e10b3a147d02792eccee483dca6c7b44ecce7012cb8c6e0b68b3ae9"
+        );
+        assert_eq!(map.get_feed_id("BTC"), None);
+        assert_eq!(
+            map.get_symbol("0x000362205e10b3a147d02792eccee483dca6c7b44ecce7012cb8c6e0b68b3ae9"),
+            Some(&"ETH".to_string())
+        );
+    }
+
     #[test]
     fn test_parse_ws_message() {
         let json = r#"{
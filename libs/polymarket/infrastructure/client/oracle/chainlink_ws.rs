@@ -337,14 +337,13 @@ impl MessageHandler<ChainLinkMessage> for ChainLinkHandler {
 /// Build a WebSocket client for ChainLink Data Streams
 async fn build_chainlink_ws_client(
     prices: SharedOraclePrices,
+    feed_map: &FeedIdMap,
 ) -> Result<WebSocketClient<ChainLinkRouter, ChainLinkMessage>> {
     let local_shutdown_flag = Arc::new(AtomicBool::new(true));
 
     // Load auth from environment
     let auth = ChainLinkAuth::from_env()?;
 
-    // Create feed ID map and get the feed IDs parameter
-    let feed_map = FeedIdMap::new();
     let feed_ids = feed_map.get_feed_ids_param();
 
     // Build full WebSocket URL
@@ -392,6 +391,14 @@ fn handle_client_event(event: ClientEvent) -> bool {
             error!("[ChainLink WS] Error: {}", err);
             true
         }
+        ClientEvent::MessageTooLarge { size, max_size } => {
+            error!("[ChainLink WS] Message too large: {} bytes (max {})", size, max_size);
+            true
+        }
+        ClientEvent::CircuitOpen => {
+            error!("[ChainLink WS] Circuit breaker open, stopped reconnecting");
+            false
+        }
     }
 }
 
@@ -399,6 +406,15 @@ fn handle_client_event(event: ClientEvent) -> bool {
 pub async fn spawn_chainlink_tracker(
     prices: SharedOraclePrices,
     shutdown_flag: Arc<AtomicBool>,
+) -> Result<()> {
+    spawn_chainlink_tracker_for(prices, shutdown_flag, FeedIdMap::new()).await
+}
+
+/// Spawn a ChainLink tracker subscribed only to the feeds in `feed_map`
+pub async fn spawn_chainlink_tracker_for(
+    prices: SharedOraclePrices,
+    shutdown_flag: Arc<AtomicBool>,
+    feed_map: FeedIdMap,
 ) -> Result<()> {
     let mut reconnect_attempts: u32 = 0;
 
@@ -421,7 +437,7 @@ pub async fn spawn_chainlink_tracker(
         }
 
         // Build WebSocket client (generates fresh auth headers)
-        let client = match build_chainlink_ws_client(Arc::clone(&prices)).await {
+        let client = match build_chainlink_ws_client(Arc::clone(&prices), &feed_map).await {
             Ok(c) => c,
             Err(e) => {
                 warn!("[ChainLink WS] Failed to connect: {}", e);
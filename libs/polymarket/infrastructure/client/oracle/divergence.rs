@@ -0,0 +1,116 @@
+//! ChainLink / Binance price divergence
+//!
+//! Both oracles should quote the same asset within a few basis points. A
+//! large gap means one of the feeds is wrong or lagging, and trading on
+//! either price is unsafe until they agree again.
+//!
+//! ChainLink prices always come from the `OraclePriceManager`. Binance
+//! prices come from the same manager (Polymarket relay) unless a direct
+//! `BinancePriceManager` feed is attached with `with_binance_feed`.
+
+use super::price_manager::SharedOraclePrices;
+use super::shared_prices::{PriceFeed, SharedPrices};
+use crate::infrastructure::client::binance::SharedBinancePrices;
+
+#[derive(Clone)]
+pub struct PriceDivergence {
+    prices: SharedPrices,
+}
+
+impl PriceDivergence {
+    pub fn new(oracle_prices: SharedOraclePrices) -> Self {
+        Self::from_prices(SharedPrices::new(oracle_prices))
+    }
+
+    /// Compare the sources behind an existing `SharedPrices`
+    pub fn from_prices(prices: SharedPrices) -> Self {
+        Self { prices }
+    }
+
+    /// Read Binance prices from the direct feed instead of the relay
+    pub fn with_binance_feed(self, binance_prices: SharedBinancePrices) -> Self {
+        Self::from_prices(self.prices.with_binance_feed(binance_prices))
+    }
+
+    /// Absolute ChainLink/Binance gap for `asset` (e.g. "BTC") in basis
+    /// points of the ChainLink price, `None` if either price is missing
+    pub fn divergence_bps(&self, asset: &str) -> Option<f64> {
+        let chainlink = self.prices.price_from(PriceFeed::ChainLink, asset)?.value;
+        let binance_feed = if self.prices.binance_prices().is_some() {
+            PriceFeed::BinanceDirect
+        } else {
+            PriceFeed::Binance
+        };
+        let binance = self.prices.price_from(binance_feed, asset)?.value;
+
+        if chainlink <= 0.0 {
+            return None;
+        }
+        Some((chainlink - binance).abs() / chainlink * 10000.0)
+    }
+
+    /// Whether the feeds disagree on `asset` by more than `threshold_bps`.
+    /// Missing prices are not treated as divergence.
+    pub fn is_diverged(&self, asset: &str, threshold_bps: f64) -> bool {
+        self.divergence_bps(asset)
+            .is_some_and(|bps| bps > threshold_bps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::client::binance::BinancePriceManager;
+    use crate::infrastructure::client::oracle::{OraclePriceManager, OracleType};
+    use parking_lot::RwLock;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_divergent_prices_flag_and_convergent_clear() {
+        let oracle: SharedOraclePrices = Arc::new(RwLock::new(OraclePriceManager::new()));
+        let divergence = PriceDivergence::new(Arc::clone(&oracle));
+
+        // No data yet: not diverged
+        assert!(!divergence.is_diverged("BTC", 20.0));
+
+        // 1% apart = 100 bps
+        oracle
+            .write()
+            .update_price(OracleType::ChainLink, "BTC", 100_000.0, 1);
+        oracle
+            .write()
+            .update_price(OracleType::Binance, "BTC", 101_000.0, 1);
+        assert!((divergence.divergence_bps("BTC").unwrap() - 100.0).abs() < 1e-9);
+        assert!(divergence.is_diverged("BTC", 20.0));
+
+        // Converged within 5 bps clears the flag
+        oracle
+            .write()
+            .update_price(OracleType::Binance, "BTC", 100_050.0, 2);
+        assert!(!divergence.is_diverged("BTC", 20.0));
+
+        // Other assets are independent
+        assert!(!divergence.is_diverged("ETH", 20.0));
+    }
+
+    #[test]
+    fn test_direct_binance_feed_overrides_relay() {
+        let oracle: SharedOraclePrices = Arc::new(RwLock::new(OraclePriceManager::new()));
+        let binance: SharedBinancePrices = Arc::new(RwLock::new(BinancePriceManager::new()));
+        oracle
+            .write()
+            .update_price(OracleType::ChainLink, "ETH", 3000.0, 1);
+        oracle
+            .write()
+            .update_price(OracleType::Binance, "ETH", 3000.0, 1);
+
+        let divergence = PriceDivergence::new(oracle).with_binance_feed(Arc::clone(&binance));
+        // Direct feed has no price yet, so the relay price is not consulted
+        assert_eq!(divergence.divergence_bps("ETH"), None);
+
+        binance.write().update_price("ETH", 3030.0, 1, 1, false);
+        assert!(divergence.is_diverged("ETH", 50.0));
+        binance.write().update_price("ETH", 3001.0, 2, 2, false);
+        assert!(!divergence.is_diverged("ETH", 50.0));
+    }
+}
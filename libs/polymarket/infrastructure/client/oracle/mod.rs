@@ -25,8 +25,11 @@
 mod candlestick_api;
 mod chainlink_types;
 mod chainlink_ws;
+mod divergence;
 mod oracle_ws;
+mod price_history;
 mod price_manager;
+mod shared_prices;
 mod types;
 
 // Re-export main types and functions
@@ -34,9 +37,20 @@ pub use candlestick_api::{
     create_shared_candlestick_client, Candle, CandlestickApiClient, SharedCandlestickClient,
 };
 pub use chainlink_types::{ChainLinkMessage, ChainLinkReport, ChainLinkWsMessage, DecodedPrice, FeedIdMap};
-pub use chainlink_ws::{spawn_chainlink_tracker, ChainLinkAuth, ChainLinkRoute};
+pub use chainlink_ws::{
+    spawn_chainlink_tracker, spawn_chainlink_tracker_for, ChainLinkAuth, ChainLinkRoute,
+};
+pub use divergence::PriceDivergence;
 pub use oracle_ws::{
-    parse_binance_symbol, parse_chainlink_symbol, spawn_oracle_trackers, OracleRoute,
+    parse_binance_symbol, parse_chainlink_symbol, spawn_oracle_trackers,
+    spawn_oracle_trackers_for, OracleRoute,
 };
+pub use price_history::{PriceHistory, DEFAULT_PRICE_HISTORY_LEN};
 pub use price_manager::{OracleHealthState, OraclePriceManager, PriceEntry, SharedOraclePrices};
-pub use types::{OracleMessage, OraclePricePayload, OraclePriceUpdate, OracleSubscription, OracleType};
+pub use shared_prices::{
+    FallbackPriceSource, OracleFeed, PriceFeed, PriceQuote, PriceSource, PricesHealth, SharedPrices,
+};
+pub use types::{
+    OracleAssets, OracleMessage, OraclePricePayload, OraclePriceUpdate, OracleSubscription,
+    OracleType,
+};
@@ -4,7 +4,8 @@
 //! crypto price updates from ChainLink and Binance oracles.
 
 use super::price_manager::{OraclePriceManager, SharedOraclePrices};
-use super::types::{OracleMessage, OraclePriceUpdate, OracleSubscription, OracleType};
+use super::chainlink_types::FeedIdMap;
+use super::types::{OracleAssets, OracleMessage, OraclePriceUpdate, OracleSubscription, OracleType};
 use anyhow::Result;
 use hypersockets::core::*;
 use hypersockets::{MessageHandler, MessageRouter, TextPongDetector, WsMessage};
@@ -191,6 +192,7 @@ impl MessageHandler<OracleMessage> for OracleHandler {
 /// inadvertently trigger global shutdown if shared.
 async fn build_oracle_ws_client(
     oracle_type: OracleType,
+    symbols: Option<&[String]>,
     prices: SharedOraclePrices,
 ) -> Result<WebSocketClient<OracleRouter, OracleMessage>> {
     let local_shutdown_flag = Arc::new(AtomicBool::new(true));
@@ -198,7 +200,7 @@ async fn build_oracle_ws_client(
     let router = OracleRouter::new(oracle_type);
     let handler = OracleHandler::new(oracle_type, prices);
 
-    let subscription = OracleSubscription::new(oracle_type);
+    let subscription = OracleSubscription::for_symbols(oracle_type, symbols);
     let subscription_json = serde_json::to_string(&subscription)?;
 
     // Create PONG detector for "PONG" text messages
@@ -246,6 +248,14 @@ fn handle_client_event(event: ClientEvent, oracle_type: OracleType) -> bool {
             warn!("[Oracle {}] Error: {}", oracle_type, err);
             true
         }
+        ClientEvent::MessageTooLarge { size, max_size } => {
+            warn!("[Oracle {}] Message too large: {} bytes (max {})", oracle_type, size, max_size);
+            true
+        }
+        ClientEvent::CircuitOpen => {
+            warn!("[Oracle {}] Circuit breaker open, stopped reconnecting", oracle_type);
+            false
+        }
     }
 }
 
@@ -253,6 +263,7 @@ fn handle_client_event(event: ClientEvent, oracle_type: OracleType) -> bool {
 /// Includes automatic reconnection on disconnect.
 async fn spawn_single_oracle_tracker(
     oracle_type: OracleType,
+    symbols: Option<Vec<String>>,
     prices: SharedOraclePrices,
     shutdown_flag: Arc<AtomicBool>,
 ) -> Result<()> {
@@ -277,7 +288,7 @@ async fn spawn_single_oracle_tracker(
         }
 
         // Build WebSocket client
-        let client = match build_oracle_ws_client(oracle_type, Arc::clone(&prices)).await {
+        let client = match build_oracle_ws_client(oracle_type, symbols.as_deref(), Arc::clone(&prices)).await {
             Ok(c) => c,
             Err(e) => {
                 warn!("[Oracle {}] Failed to connect: {}", oracle_type, e);
@@ -396,41 +407,78 @@ async fn spawn_single_oracle_tracker(
 /// - Binance: Via Polymarket's live data WebSocket relay
 pub async fn spawn_oracle_trackers(
     shutdown_flag: Arc<AtomicBool>,
+) -> Result<SharedOraclePrices> {
+    spawn_oracle_trackers_for(shutdown_flag, &OracleAssets::all()).await
+}
+
+/// Like `spawn_oracle_trackers`, but subscribes only to `assets`.
+///
+/// An oracle with no requested asset is not connected at all.
+pub async fn spawn_oracle_trackers_for(
+    shutdown_flag: Arc<AtomicBool>,
+    assets: &OracleAssets,
 ) -> Result<SharedOraclePrices> {
     // Create shared price manager
     let prices: SharedOraclePrices = Arc::new(RwLock::new(OraclePriceManager::new()));
 
+    let describe = |oracle: OracleType, all: &str| match assets.symbols(oracle) {
+        None => all.to_string(),
+        Some(symbols) if symbols.is_empty() => "disabled".to_string(),
+        Some(symbols) => symbols.join(", "),
+    };
+
     info!("════════════════════════════════════════════════════════════════");
     info!("STARTING ORACLE PRICE TRACKERS");
     info!("════════════════════════════════════════════════════════════════");
-    info!("  ChainLink: Direct Data Streams WebSocket (BTC, ETH, SOL, XRP)");
-    info!("  Binance:   Polymarket relay (crypto_prices)");
+    info!(
+        "  ChainLink: Direct Data Streams WebSocket ({})",
+        describe(OracleType::ChainLink, "BTC, ETH, SOL, XRP")
+    );
+    info!(
+        "  Binance:   Polymarket relay (crypto_prices: {})",
+        describe(OracleType::Binance, "all")
+    );
     info!("════════════════════════════════════════════════════════════════");
 
     // Spawn ChainLink tracker (direct WebSocket connection)
-    let chainlink_prices = Arc::clone(&prices);
-    let chainlink_shutdown = Arc::clone(&shutdown_flag);
-    tokio::spawn(async move {
-        if let Err(e) = super::chainlink_ws::spawn_chainlink_tracker(
-            chainlink_prices,
-            chainlink_shutdown,
-        )
-        .await
-        {
-            warn!("[ChainLink WS] Tracker failed: {}", e);
-        }
-    });
+    if assets.tracks(OracleType::ChainLink) {
+        let feed_map = match assets.symbols(OracleType::ChainLink) {
+            Some(symbols) => FeedIdMap::with_symbols(&symbols),
+            None => FeedIdMap::new(),
+        };
+        let chainlink_prices = Arc::clone(&prices);
+        let chainlink_shutdown = Arc::clone(&shutdown_flag);
+        tokio::spawn(async move {
+            if let Err(e) = super::chainlink_ws::spawn_chainlink_tracker_for(
+                chainlink_prices,
+                chainlink_shutdown,
+                feed_map,
+            )
+            .await
+            {
+                warn!("[ChainLink WS] Tracker failed: {}", e);
+            }
+        });
+    }
 
     // Spawn Binance tracker (via Polymarket relay)
-    let binance_prices = Arc::clone(&prices);
-    let binance_shutdown = Arc::clone(&shutdown_flag);
-    tokio::spawn(async move {
-        if let Err(e) =
-            spawn_single_oracle_tracker(OracleType::Binance, binance_prices, binance_shutdown).await
-        {
-            warn!("[Oracle Binance] Tracker failed: {}", e);
-        }
-    });
+    if assets.tracks(OracleType::Binance) {
+        let binance_symbols = assets.symbols(OracleType::Binance);
+        let binance_prices = Arc::clone(&prices);
+        let binance_shutdown = Arc::clone(&shutdown_flag);
+        tokio::spawn(async move {
+            if let Err(e) = spawn_single_oracle_tracker(
+                OracleType::Binance,
+                binance_symbols,
+                binance_prices,
+                binance_shutdown,
+            )
+            .await
+            {
+                warn!("[Oracle Binance] Tracker failed: {}", e);
+            }
+        });
+    }
 
     Ok(prices)
 }
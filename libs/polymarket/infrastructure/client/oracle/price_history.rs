@@ -0,0 +1,110 @@
+//! Fixed-size price history
+//!
+//! Ring buffer of the most recent prices for one asset, shared by
+//! `OraclePriceManager` and `BinancePriceManager` for volatility estimation.
+
+use std::collections::VecDeque;
+
+/// Default number of prices kept per asset
+pub const DEFAULT_PRICE_HISTORY_LEN: usize = 256;
+
+#[derive(Debug, Clone)]
+pub struct PriceHistory {
+    prices: VecDeque<f64>,
+    capacity: usize,
+}
+
+impl PriceHistory {
+    /// A capacity of 0 is treated as 1
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            prices: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Append a price, evicting the oldest once full
+    pub fn push(&mut self, price: f64) {
+        if self.prices.len() == self.capacity {
+            self.prices.pop_front();
+        }
+        self.prices.push_back(price);
+    }
+
+    /// Up to `n` most recent prices, oldest first
+    pub fn recent(&self, n: usize) -> Vec<f64> {
+        let skip = self.prices.len().saturating_sub(n);
+        self.prices.iter().skip(skip).copied().collect()
+    }
+
+    /// Sample standard deviation of simple returns between consecutive
+    /// prices. `None` until there are at least two returns.
+    pub fn volatility(&self) -> Option<f64> {
+        let returns: Vec<f64> = self
+            .prices
+            .iter()
+            .zip(self.prices.iter().skip(1))
+            .filter(|(prev, _)| **prev != 0.0)
+            .map(|(prev, next)| (next - prev) / prev)
+            .collect();
+        if returns.len() < 2 {
+            return None;
+        }
+
+        let n = returns.len() as f64;
+        let mean = returns.iter().sum::<f64>() / n;
+        let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        Some(variance.sqrt())
+    }
+
+    pub fn len(&self) -> usize {
+        self.prices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.prices.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_buffer_evicts_oldest() {
+        let mut history = PriceHistory::new(3);
+        for price in [1.0, 2.0, 3.0, 4.0] {
+            history.push(price);
+        }
+
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.recent(10), vec![2.0, 3.0, 4.0]);
+        assert_eq!(history.recent(2), vec![3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_volatility_of_returns() {
+        let mut history = PriceHistory::new(8);
+        history.push(100.0);
+        assert_eq!(history.volatility(), None);
+        history.push(110.0);
+        assert_eq!(history.volatility(), None);
+
+        // Returns +10%, -10%: mean 0, sample stddev sqrt(0.02)
+        history.push(99.0);
+        let vol = history.volatility().unwrap();
+        assert!((vol - 0.02_f64.sqrt()).abs() < 1e-12);
+
+        // Flat prices have no volatility
+        let mut flat = PriceHistory::new(8);
+        for _ in 0..5 {
+            flat.push(50.0);
+        }
+        assert_eq!(flat.volatility(), Some(0.0));
+    }
+}
@@ -8,10 +8,13 @@
 //! The price manager tracks the health of each oracle connection:
 //! - `received_at` on each price entry tracks when we received data
 //! - `OracleHealthState` tracks the last update time for each oracle
+//! - `age` / `stale_symbols` report freshness per symbol, since one feed
+//!   can keep ticking while another symbol on the same oracle stalls
 //!
 //! This allows strategies to detect stale data even when the WebSocket
 //! appears connected (zombie connection detection).
 
+use super::price_history::{PriceHistory, DEFAULT_PRICE_HISTORY_LEN};
 use super::types::OracleType;
 use parking_lot::RwLock;
 use std::collections::HashMap;
@@ -105,6 +108,10 @@ pub struct OraclePriceManager {
     pub chainlink_health: OracleHealthState,
     /// Health state for Binance oracle connection
     pub binance_health: OracleHealthState,
+    /// Recent prices per oracle and symbol
+    history: HashMap<(OracleType, String), PriceHistory>,
+    /// Prices kept per history buffer
+    history_len: usize,
 }
 
 impl Default for OraclePriceManager {
@@ -121,9 +128,17 @@ impl OraclePriceManager {
             binance: HashMap::new(),
             chainlink_health: OracleHealthState::new(),
             binance_health: OracleHealthState::new(),
+            history: HashMap::new(),
+            history_len: DEFAULT_PRICE_HISTORY_LEN,
         }
     }
 
+    /// Keep the last `len` prices per oracle and symbol (default 256)
+    pub fn with_history_len(mut self, len: usize) -> Self {
+        self.history_len = len;
+        self
+    }
+
     /// Update a price for the given oracle and symbol
     /// Also updates the health state for that oracle
     pub fn update_price(&mut self, oracle: OracleType, symbol: &str, value: f64, timestamp: u64) {
@@ -132,8 +147,15 @@ impl OraclePriceManager {
             OracleType::ChainLink => (&mut self.chainlink, &mut self.chainlink_health),
             OracleType::Binance => (&mut self.binance, &mut self.binance_health),
         };
-        prices.insert(symbol.to_uppercase(), entry);
+        let symbol = symbol.to_uppercase();
+        prices.insert(symbol.clone(), entry);
         health.record_update();
+
+        let history_len = self.history_len;
+        self.history
+            .entry((oracle, symbol))
+            .or_insert_with(|| PriceHistory::new(history_len))
+            .push(value);
     }
 
     /// Check if a specific oracle has received data recently
@@ -153,6 +175,29 @@ impl OraclePriceManager {
         }
     }
 
+    /// Time since the last tick for a symbol, `None` if it has never ticked
+    pub fn age(&self, oracle: OracleType, symbol: &str) -> Option<Duration> {
+        self.get_price(oracle, symbol).map(|entry| entry.age())
+    }
+
+    /// Symbols whose last tick is older than `max_age`, ChainLink first
+    ///
+    /// Only symbols that have ticked at least once are considered.
+    pub fn stale_symbols(&self, max_age: Duration) -> Vec<(OracleType, String)> {
+        let mut stale = Vec::new();
+        for oracle in [OracleType::ChainLink, OracleType::Binance] {
+            let mut symbols: Vec<&String> = self
+                .get_all_prices(oracle)
+                .iter()
+                .filter(|(_, entry)| entry.is_stale(max_age))
+                .map(|(symbol, _)| symbol)
+                .collect();
+            symbols.sort();
+            stale.extend(symbols.into_iter().map(|symbol| (oracle, symbol.clone())));
+        }
+        stale
+    }
+
     /// Get the message count for a specific oracle
     pub fn oracle_message_count(&self, oracle: OracleType) -> u64 {
         match oracle {
@@ -178,6 +223,21 @@ impl OraclePriceManager {
         prices.get(&symbol.to_uppercase()).copied()
     }
 
+    /// Up to `n` most recent prices for the given oracle and symbol, oldest first
+    pub fn recent_prices(&self, oracle: OracleType, symbol: &str, n: usize) -> Vec<f64> {
+        self.history
+            .get(&(oracle, symbol.to_uppercase()))
+            .map(|history| history.recent(n))
+            .unwrap_or_default()
+    }
+
+    /// Standard deviation of returns over the recent price history
+    pub fn volatility(&self, oracle: OracleType, symbol: &str) -> Option<f64> {
+        self.history
+            .get(&(oracle, symbol.to_uppercase()))?
+            .volatility()
+    }
+
     /// Get all prices for a given oracle
     pub fn get_all_prices(&self, oracle: OracleType) -> &HashMap<String, PriceEntry> {
         match oracle {
@@ -273,4 +333,91 @@ mod tests {
         let manager = OraclePriceManager::new();
         assert!(manager.get_price(OracleType::ChainLink, "XYZ").is_none());
     }
+
+    /// Backdate a symbol's last tick by `age`
+    fn backdate(manager: &mut OraclePriceManager, oracle: OracleType, symbol: &str, age: Duration) {
+        let prices = match oracle {
+            OracleType::ChainLink => &mut manager.chainlink,
+            OracleType::Binance => &mut manager.binance,
+        };
+        prices.get_mut(symbol).unwrap().received_at = Instant::now() - age;
+    }
+
+    #[test]
+    fn test_age_per_symbol() {
+        let mut manager = OraclePriceManager::new();
+
+        manager.update_price(OracleType::ChainLink, "ETH", 3456.78, 1000);
+        manager.update_price(OracleType::ChainLink, "BTC", 100000.0, 1000);
+        backdate(&mut manager, OracleType::ChainLink, "BTC", Duration::from_secs(30));
+
+        let eth_age = manager.age(OracleType::ChainLink, "eth").unwrap();
+        let btc_age = manager.age(OracleType::ChainLink, "BTC").unwrap();
+        assert!(eth_age < Duration::from_secs(1));
+        assert!(btc_age >= Duration::from_secs(30));
+
+        // Never ticked: no age rather than a huge one
+        assert_eq!(manager.age(OracleType::ChainLink, "SOL"), None);
+        assert_eq!(manager.age(OracleType::Binance, "ETH"), None);
+    }
+
+    #[test]
+    fn test_stale_symbols() {
+        let mut manager = OraclePriceManager::new();
+
+        manager.update_price(OracleType::ChainLink, "ETH", 3456.78, 1000);
+        manager.update_price(OracleType::ChainLink, "BTC", 100000.0, 1000);
+        manager.update_price(OracleType::ChainLink, "XRP", 2.1, 1000);
+        manager.update_price(OracleType::Binance, "BTC", 100001.0, 1000);
+        manager.update_price(OracleType::Binance, "SOL", 189.55, 1000);
+
+        backdate(&mut manager, OracleType::ChainLink, "XRP", Duration::from_secs(60));
+        backdate(&mut manager, OracleType::ChainLink, "BTC", Duration::from_secs(20));
+        backdate(&mut manager, OracleType::Binance, "SOL", Duration::from_secs(20));
+
+        assert_eq!(
+            manager.stale_symbols(Duration::from_secs(10)),
+            vec![
+                (OracleType::ChainLink, "BTC".to_string()),
+                (OracleType::ChainLink, "XRP".to_string()),
+                (OracleType::Binance, "SOL".to_string()),
+            ]
+        );
+        assert_eq!(
+            manager.stale_symbols(Duration::from_secs(30)),
+            vec![(OracleType::ChainLink, "XRP".to_string())]
+        );
+        assert!(manager.stale_symbols(Duration::from_secs(120)).is_empty());
+
+        // Ticking again clears staleness; ETH was never stale
+        manager.update_price(OracleType::ChainLink, "XRP", 2.2, 1001);
+        assert_eq!(
+            manager.stale_symbols(Duration::from_secs(30)),
+            Vec::<(OracleType, String)>::new()
+        );
+    }
+
+    #[test]
+    fn test_price_history_and_volatility() {
+        let mut manager = OraclePriceManager::new().with_history_len(3);
+
+        for (i, price) in [90.0, 100.0, 110.0, 99.0].into_iter().enumerate() {
+            manager.update_price(OracleType::ChainLink, "btc", price, i as u64);
+        }
+
+        // Oldest price evicted, lookups are case-insensitive
+        assert_eq!(
+            manager.recent_prices(OracleType::ChainLink, "BTC", 10),
+            vec![100.0, 110.0, 99.0]
+        );
+        assert_eq!(manager.recent_prices(OracleType::ChainLink, "BTC", 1), vec![99.0]);
+
+        // Returns +10%, -10%
+        let vol = manager.volatility(OracleType::ChainLink, "BTC").unwrap();
+        assert!((vol - 0.02_f64.sqrt()).abs() < 1e-12);
+
+        // History is kept per oracle
+        assert!(manager.recent_prices(OracleType::Binance, "BTC", 10).is_empty());
+        assert_eq!(manager.volatility(OracleType::Binance, "BTC"), None);
+    }
 }
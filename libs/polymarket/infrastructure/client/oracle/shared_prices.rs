@@ -0,0 +1,421 @@
+//! Unified price access
+//!
+//! `SharedPrices` wraps the oracle relay prices and, optionally, the direct
+//! Binance feed so consumers can ask for a price by asset without caring
+//! which manager holds it.
+//!
+//! The `PriceSource` trait is the single-feed counterpart: strategy code
+//! written against it runs unchanged on the direct Binance feed, one oracle
+//! of the relay (`OracleFeed`), or a `FallbackPriceSource` combining two.
+
+use super::price_manager::{PriceEntry, SharedOraclePrices};
+use super::types::OracleType;
+use crate::infrastructure::client::binance::{
+    BinancePriceEntry, BinancePriceManager, SharedBinancePrices,
+};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Where a price came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PriceFeed {
+    /// ChainLink via the oracle relay
+    ChainLink,
+    /// Binance via the Polymarket relay
+    Binance,
+    /// Binance direct WebSocket feed
+    BinanceDirect,
+}
+
+impl std::fmt::Display for PriceFeed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PriceFeed::ChainLink => write!(f, "ChainLink"),
+            PriceFeed::Binance => write!(f, "Binance"),
+            PriceFeed::BinanceDirect => write!(f, "Binance (direct)"),
+        }
+    }
+}
+
+impl From<OracleType> for PriceFeed {
+    fn from(oracle: OracleType) -> Self {
+        match oracle {
+            OracleType::ChainLink => PriceFeed::ChainLink,
+            OracleType::Binance => PriceFeed::Binance,
+        }
+    }
+}
+
+/// A price with its source and local age
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceQuote {
+    pub source: PriceFeed,
+    pub value: f64,
+    /// Timestamp reported by the feed, in that feed's own units
+    pub timestamp: u64,
+    /// Receive time minus event time; only the direct Binance feed has it
+    pub latency_ms: Option<i64>,
+    /// Time since the update was received locally
+    pub age: Duration,
+}
+
+impl PriceEntry {
+    /// Quote this entry as coming from `source`
+    pub fn quote(&self, source: PriceFeed) -> PriceQuote {
+        PriceQuote {
+            source,
+            value: self.value,
+            timestamp: self.timestamp,
+            latency_ms: None,
+            age: self.age(),
+        }
+    }
+}
+
+impl BinancePriceEntry {
+    /// Quote this entry as coming from the direct feed
+    pub fn quote(&self) -> PriceQuote {
+        PriceQuote {
+            source: PriceFeed::BinanceDirect,
+            value: self.value,
+            timestamp: self.binance_timestamp,
+            latency_ms: Some(self.latency_ms),
+            age: self.age(),
+        }
+    }
+}
+
+// =============================================================================
+// PriceSource
+// =============================================================================
+
+/// A single price feed keyed by asset symbol (e.g. "BTC", case-insensitive)
+pub trait PriceSource: Send + Sync {
+    /// Latest price for `symbol`, `None` if it has never ticked
+    fn get_price(&self, symbol: &str) -> Option<PriceQuote>;
+
+    /// Time since `symbol` last ticked, `None` if it has never ticked
+    fn age(&self, symbol: &str) -> Option<Duration> {
+        self.get_price(symbol).map(|quote| quote.age)
+    }
+}
+
+impl PriceSource for BinancePriceManager {
+    fn get_price(&self, symbol: &str) -> Option<PriceQuote> {
+        BinancePriceManager::get_price(self, symbol).map(|entry| entry.quote())
+    }
+}
+
+impl<T: PriceSource> PriceSource for RwLock<T> {
+    fn get_price(&self, symbol: &str) -> Option<PriceQuote> {
+        self.read().get_price(symbol)
+    }
+}
+
+impl<T: PriceSource + ?Sized> PriceSource for Arc<T> {
+    fn get_price(&self, symbol: &str) -> Option<PriceQuote> {
+        (**self).get_price(symbol)
+    }
+}
+
+impl<T: PriceSource + ?Sized> PriceSource for Box<T> {
+    fn get_price(&self, symbol: &str) -> Option<PriceQuote> {
+        (**self).get_price(symbol)
+    }
+}
+
+/// One oracle of the relay's `OraclePriceManager` as a `PriceSource`
+///
+/// The manager holds both ChainLink and Binance prices, so the oracle to
+/// read has to be picked up front.
+#[derive(Clone)]
+pub struct OracleFeed {
+    prices: SharedOraclePrices,
+    oracle: OracleType,
+}
+
+impl OracleFeed {
+    pub fn new(prices: SharedOraclePrices, oracle: OracleType) -> Self {
+        Self { prices, oracle }
+    }
+}
+
+impl PriceSource for OracleFeed {
+    fn get_price(&self, symbol: &str) -> Option<PriceQuote> {
+        let entry = self.prices.read().get_price(self.oracle, symbol)?;
+        Some(entry.quote(self.oracle.into()))
+    }
+}
+
+/// Prefers `primary`, switching to `fallback` while `primary` is stale
+///
+/// A quote is fresh when its age is within `max_age`. If neither source is
+/// fresh, the more recent quote is returned so callers can still judge it
+/// by `age`.
+pub struct FallbackPriceSource {
+    primary: Box<dyn PriceSource>,
+    fallback: Box<dyn PriceSource>,
+    max_age: Duration,
+}
+
+impl FallbackPriceSource {
+    pub fn new(
+        primary: impl PriceSource + 'static,
+        fallback: impl PriceSource + 'static,
+        max_age: Duration,
+    ) -> Self {
+        Self {
+            primary: Box::new(primary),
+            fallback: Box::new(fallback),
+            max_age,
+        }
+    }
+}
+
+impl PriceSource for FallbackPriceSource {
+    fn get_price(&self, symbol: &str) -> Option<PriceQuote> {
+        let primary = self.primary.get_price(symbol);
+        if let Some(quote) = primary.filter(|q| q.age <= self.max_age) {
+            return Some(quote);
+        }
+        let fallback = self.fallback.get_price(symbol);
+        match (primary, fallback) {
+            (Some(p), Some(f)) => Some(if f.age < p.age { f } else { p }),
+            (p, f) => p.or(f),
+        }
+    }
+}
+
+// =============================================================================
+// SharedPrices
+// =============================================================================
+
+/// Health of every source; `None` when the direct feed isn't attached
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PricesHealth {
+    pub chainlink: bool,
+    pub binance: bool,
+    pub binance_direct: Option<bool>,
+}
+
+impl PricesHealth {
+    /// Every attached source has received data recently
+    pub fn all_healthy(&self) -> bool {
+        self.chainlink && self.binance && self.binance_direct.unwrap_or(true)
+    }
+
+    /// At least one source has received data recently
+    pub fn any_healthy(&self) -> bool {
+        self.chainlink || self.binance || self.binance_direct.unwrap_or(false)
+    }
+}
+
+#[derive(Clone)]
+pub struct SharedPrices {
+    oracle_prices: SharedOraclePrices,
+    binance_prices: Option<SharedBinancePrices>,
+}
+
+impl SharedPrices {
+    pub fn new(oracle_prices: SharedOraclePrices) -> Self {
+        Self {
+            oracle_prices,
+            binance_prices: None,
+        }
+    }
+
+    /// Also serve prices from the direct Binance feed
+    pub fn with_binance_feed(mut self, binance_prices: SharedBinancePrices) -> Self {
+        self.binance_prices = Some(binance_prices);
+        self
+    }
+
+    pub fn oracle_prices(&self) -> &SharedOraclePrices {
+        &self.oracle_prices
+    }
+
+    pub fn binance_prices(&self) -> Option<&SharedBinancePrices> {
+        self.binance_prices.as_ref()
+    }
+
+    /// Price for `asset` (e.g. "BTC") from one source
+    pub fn price_from(&self, source: PriceFeed, asset: &str) -> Option<PriceQuote> {
+        match source {
+            PriceFeed::ChainLink => {
+                OracleFeed::new(self.oracle_prices.clone(), OracleType::ChainLink).get_price(asset)
+            }
+            PriceFeed::Binance => {
+                OracleFeed::new(self.oracle_prices.clone(), OracleType::Binance).get_price(asset)
+            }
+            PriceFeed::BinanceDirect => self.binance_prices.as_ref()?.get_price(asset),
+        }
+    }
+
+    /// Most recently received price for `asset` across all sources
+    pub fn best_price(&self, asset: &str) -> Option<PriceQuote> {
+        [
+            PriceFeed::ChainLink,
+            PriceFeed::Binance,
+            PriceFeed::BinanceDirect,
+        ]
+        .into_iter()
+        .filter_map(|source| self.price_from(source, asset))
+        .min_by_key(|quote| quote.age)
+    }
+
+    /// Whether each source has received any update within `max_age`
+    pub fn health(&self, max_age: Duration) -> PricesHealth {
+        let oracle = self.oracle_prices.read();
+        PricesHealth {
+            chainlink: oracle.is_oracle_healthy(OracleType::ChainLink, max_age),
+            binance: oracle.is_oracle_healthy(OracleType::Binance, max_age),
+            binance_direct: self
+                .binance_prices
+                .as_ref()
+                .map(|feed| feed.read().is_healthy(max_age)),
+        }
+    }
+
+    /// Shorthand for `health(max_age).all_healthy()`
+    pub fn is_healthy(&self, max_age: Duration) -> bool {
+        self.health(max_age).all_healthy()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::client::binance::BinancePriceManager;
+    use crate::infrastructure::client::oracle::{OraclePriceManager, PriceEntry};
+    use parking_lot::RwLock;
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    fn aged(value: f64, age: Duration) -> PriceEntry {
+        PriceEntry {
+            value,
+            timestamp: 0,
+            received_at: Instant::now() - age,
+        }
+    }
+
+    #[test]
+    fn test_best_price_is_freshest_source() {
+        let oracle: SharedOraclePrices = Arc::new(RwLock::new(OraclePriceManager::new()));
+        let binance: SharedBinancePrices = Arc::new(RwLock::new(BinancePriceManager::new()));
+        {
+            let mut manager = oracle.write();
+            manager
+                .chainlink
+                .insert("BTC".to_string(), aged(100_000.0, Duration::from_secs(5)));
+            manager
+                .binance
+                .insert("BTC".to_string(), aged(100_010.0, Duration::from_secs(2)));
+        }
+        let prices = SharedPrices::new(Arc::clone(&oracle));
+
+        let best = prices.best_price("btc").unwrap();
+        assert_eq!(best.source, PriceFeed::Binance);
+        assert_eq!(best.value, 100_010.0);
+        assert_eq!(
+            prices.price_from(PriceFeed::ChainLink, "BTC").unwrap().value,
+            100_000.0
+        );
+        assert!(prices.price_from(PriceFeed::BinanceDirect, "BTC").is_none());
+
+        // A fresh direct update wins
+        binance.write().update_price("BTC", 100_020.0, 1, 1, false);
+        let prices = prices.with_binance_feed(binance);
+        let best = prices.best_price("BTC").unwrap();
+        assert_eq!(best.source, PriceFeed::BinanceDirect);
+        assert_eq!(best.value, 100_020.0);
+
+        assert!(prices.best_price("DOGE").is_none());
+    }
+
+    #[test]
+    fn test_fallback_prefers_fresh_primary() {
+        let oracle: SharedOraclePrices = Arc::new(RwLock::new(OraclePriceManager::new()));
+        let binance: SharedBinancePrices = Arc::new(RwLock::new(BinancePriceManager::new()));
+        let source = FallbackPriceSource::new(
+            Arc::clone(&binance),
+            OracleFeed::new(Arc::clone(&oracle), OracleType::ChainLink),
+            Duration::from_secs(5),
+        );
+
+        // Nothing ticked anywhere
+        assert_eq!(source.get_price("BTC"), None);
+        assert_eq!(source.age("BTC"), None);
+
+        // Primary never ticked: fallback serves
+        oracle
+            .write()
+            .chainlink
+            .insert("BTC".to_string(), aged(100_000.0, Duration::from_secs(1)));
+        assert_eq!(source.get_price("BTC").unwrap().source, PriceFeed::ChainLink);
+
+        // Fresh primary wins even though the fallback is also fresh
+        binance.write().update_price("BTC", 100_020.0, 1, 1, false);
+        let quote = source.get_price("btc").unwrap();
+        assert_eq!(quote.source, PriceFeed::BinanceDirect);
+        assert_eq!(quote.value, 100_020.0);
+
+        // Stale primary: fallback serves
+        binance.write().prices.get_mut("BTC").unwrap().received_at =
+            Instant::now() - Duration::from_secs(10);
+        let quote = source.get_price("BTC").unwrap();
+        assert_eq!(quote.source, PriceFeed::ChainLink);
+        assert_eq!(quote.value, 100_000.0);
+
+        // Both stale: the more recent quote is returned
+        oracle
+            .write()
+            .chainlink
+            .insert("BTC".to_string(), aged(100_000.0, Duration::from_secs(30)));
+        assert_eq!(source.get_price("BTC").unwrap().source, PriceFeed::BinanceDirect);
+        assert!(source.age("BTC").unwrap() >= Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_quotes_carry_feed_details() {
+        let oracle: SharedOraclePrices = Arc::new(RwLock::new(OraclePriceManager::new()));
+        let binance: SharedBinancePrices = Arc::new(RwLock::new(BinancePriceManager::new()));
+        oracle.write().update_price(OracleType::Binance, "ETH", 3000.0, 1_700_000_000_000);
+        binance.write().update_price("ETH", 3001.0, 1_700_000_000_500, 7, false);
+
+        let relay = OracleFeed::new(oracle, OracleType::Binance).get_price("ETH").unwrap();
+        assert_eq!(relay.source, PriceFeed::Binance);
+        assert_eq!(relay.timestamp, 1_700_000_000_000);
+        assert_eq!(relay.latency_ms, None);
+
+        let direct = binance.get_price("ETH").unwrap();
+        assert_eq!(direct.source, PriceFeed::BinanceDirect);
+        assert_eq!(direct.timestamp, 1_700_000_000_500);
+        assert_eq!(direct.latency_ms, Some(binance.read().prices["ETH"].latency_ms));
+    }
+
+    #[test]
+    fn test_combined_health() {
+        let oracle: SharedOraclePrices = Arc::new(RwLock::new(OraclePriceManager::new()));
+        let binance: SharedBinancePrices = Arc::new(RwLock::new(BinancePriceManager::new()));
+        let max_age = Duration::from_secs(10);
+
+        oracle.write().update_price(OracleType::ChainLink, "ETH", 3000.0, 1);
+        oracle.write().update_price(OracleType::Binance, "ETH", 3000.0, 1);
+        let prices = SharedPrices::new(oracle);
+        assert!(prices.is_healthy(max_age));
+        assert_eq!(prices.health(max_age).binance_direct, None);
+
+        // A stale direct feed drags combined health down
+        binance.write().health.last_update = Instant::now() - Duration::from_secs(60);
+        let prices = prices.with_binance_feed(Arc::clone(&binance));
+        let health = prices.health(max_age);
+        assert!(!health.all_healthy());
+        assert!(health.any_healthy());
+        assert_eq!(health.binance_direct, Some(false));
+
+        binance.write().update_price("ETH", 3000.0, 1, 1, false);
+        assert!(prices.is_healthy(max_age));
+    }
+}
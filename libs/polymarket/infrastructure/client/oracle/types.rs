@@ -31,6 +31,53 @@ impl std::fmt::Display for OracleType {
     }
 }
 
+/// Oracle/asset pairs to subscribe to
+///
+/// The default (`all()`) tracks every asset on both oracles. Symbols are
+/// base assets like "ETH" and are matched case-insensitively.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OracleAssets {
+    /// None = every asset on every oracle
+    pairs: Option<Vec<(OracleType, String)>>,
+}
+
+impl OracleAssets {
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// Track only the given pairs; oracles with no pair are not connected
+    pub fn only<S: Into<String>>(pairs: impl IntoIterator<Item = (OracleType, S)>) -> Self {
+        Self {
+            pairs: Some(
+                pairs
+                    .into_iter()
+                    .map(|(oracle, symbol)| (oracle, symbol.into().to_uppercase()))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Whether any asset is tracked on `oracle`
+    pub fn tracks(&self, oracle: OracleType) -> bool {
+        match &self.pairs {
+            None => true,
+            Some(pairs) => pairs.iter().any(|(o, _)| *o == oracle),
+        }
+    }
+
+    /// Symbols to subscribe to on `oracle` (None = all of them)
+    pub fn symbols(&self, oracle: OracleType) -> Option<Vec<String>> {
+        self.pairs.as_ref().map(|pairs| {
+            pairs
+                .iter()
+                .filter(|(o, _)| *o == oracle)
+                .map(|(_, symbol)| symbol.clone())
+                .collect()
+        })
+    }
+}
+
 /// Subscription entry for a single topic
 #[derive(Debug, Clone, Serialize)]
 pub struct SubscriptionEntry {
@@ -56,9 +103,26 @@ impl OracleSubscription {
     /// Binance uses type "update" and doesn't require filters.
     /// Per Polymarket documentation.
     pub fn new(oracle_type: OracleType) -> Self {
+        Self::for_symbols(oracle_type, None)
+    }
+
+    /// Create a subscription limited to `symbols` (None = all).
+    ///
+    /// Only the Binance topic filters by symbol ("ethusdt,solusdt");
+    /// ChainLink assets are selected by feed ID on the direct connection.
+    pub fn for_symbols(oracle_type: OracleType, symbols: Option<&[String]>) -> Self {
         let (msg_type, filters) = match oracle_type {
             OracleType::ChainLink => ("*".to_string(), Some(String::new())),
-            OracleType::Binance => ("update".to_string(), None),
+            OracleType::Binance => (
+                "update".to_string(),
+                symbols.map(|symbols| {
+                    symbols
+                        .iter()
+                        .map(|s| format!("{}usdt", s.to_lowercase()))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
         };
         Self {
             action: "subscribe".to_string(),
@@ -171,4 +235,29 @@ mod tests {
         assert_eq!(msg.payload.symbol, "solusdt");
         assert!((msg.payload.value - 189.55).abs() < 0.001);
     }
+
+    #[test]
+    fn test_oracle_assets_selection() {
+        let all = OracleAssets::all();
+        assert!(all.tracks(OracleType::ChainLink) && all.tracks(OracleType::Binance));
+        assert_eq!(all.symbols(OracleType::Binance), None);
+
+        let eth = OracleAssets::only([(OracleType::ChainLink, "eth"), (OracleType::Binance, "ETH")]);
+        assert_eq!(eth.symbols(OracleType::ChainLink), Some(vec!["ETH".to_string()]));
+
+        let chainlink_only = OracleAssets::only([(OracleType::ChainLink, "ETH")]);
+        assert!(!chainlink_only.tracks(OracleType::Binance));
+    }
+
+    #[test]
+    fn test_subscription_only_includes_requested_assets() {
+        let assets = OracleAssets::only([(OracleType::Binance, "eth"), (OracleType::Binance, "sol")]);
+        let symbols = assets.symbols(OracleType::Binance);
+        let sub = OracleSubscription::for_symbols(OracleType::Binance, symbols.as_deref());
+        assert_eq!(sub.subscriptions[0].filters.as_deref(), Some("ethusdt,solusdt"));
+
+        let json = serde_json::to_string(&sub).unwrap();
+        assert!(!json.contains("btc"));
+        assert!(!json.contains("xrp"));
+    }
 }
@@ -1,5 +1,8 @@
 //! On-chain redemption for resolved Polymarket positions via Gnosis Safe.
 //!
+//! [`RedeemScheduler`] redeems winning positions automatically once their
+//! market resolves.
+//!
 //! # Gas Price
 //!
 //! Gas prices are fetched dynamically from the Polygon network with a configurable
@@ -7,11 +10,16 @@
 
 use ethers::prelude::*;
 use ethers::contract::abigen;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 use tracing::{info, warn, debug};
 
+use super::ctf::{wait_for_confirmations, DEFAULT_MIN_CONFIRMATIONS};
 use super::data::{DataApiClient, Position};
+use super::user::SharedPositionTracker;
 
 pub const POLYGON_RPC_URL: &str = "https://polygon-rpc.com";
 pub const POLYGON_CHAIN_ID: u64 = 137;
@@ -75,10 +83,20 @@ pub struct RedemptionResult {
     pub error: Option<String>,
 }
 
+/// A confirmed redeem and the USDC it paid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Redemption {
+    pub tx_hash: TxHash,
+    /// Raw USDC (6 decimals) transferred to the Safe by the redeem
+    pub payout: U256,
+}
+
 pub struct RedeemClient<M: Middleware> {
     ctf: ConditionalTokens<M>,
     neg_risk_ctf: ConditionalTokens<M>,
     usdc: Address,
+    provider: Arc<M>,
+    min_confirmations: usize,
 }
 
 impl<M: Middleware + 'static> RedeemClient<M> {
@@ -89,20 +107,33 @@ impl<M: Middleware + 'static> RedeemClient<M> {
 
         Self {
             ctf: ConditionalTokens::new(ctf_address, provider.clone()),
-            neg_risk_ctf: ConditionalTokens::new(neg_risk_address, provider),
+            neg_risk_ctf: ConditionalTokens::new(neg_risk_address, provider.clone()),
             usdc,
+            provider,
+            min_confirmations: DEFAULT_MIN_CONFIRMATIONS,
         }
     }
 
+    /// Confirmations a redeem waits for before it counts as settled
+    pub fn with_min_confirmations(mut self, min_confirmations: usize) -> Self {
+        self.min_confirmations = min_confirmations.max(1);
+        self
+    }
+
     pub fn encode_redeem_call(&self, condition_id: &str, neg_risk: bool) -> Result<(Address, Bytes)> {
+        self.encode_redeem_outcomes_call(condition_id, neg_risk, vec![U256::from(1), U256::from(2)])
+    }
+
+    /// Encode a redeem of the outcomes in `index_sets` (outcome `i` is `1 << i`)
+    pub fn encode_redeem_outcomes_call(
+        &self,
+        condition_id: &str,
+        neg_risk: bool,
+        index_sets: Vec<U256>,
+    ) -> Result<(Address, Bytes)> {
         let condition_id = parse_condition_id(condition_id)?;
         let contract = if neg_risk { &self.neg_risk_ctf } else { &self.ctf };
-        let call = contract.redeem_positions(
-            self.usdc,
-            [0u8; 32],
-            condition_id,
-            vec![U256::from(1), U256::from(2)],
-        );
+        let call = contract.redeem_positions(self.usdc, [0u8; 32], condition_id, index_sets);
 
         let ctf_address: Address = if neg_risk {
             NEG_RISK_CTF_CONTRACT
@@ -123,6 +154,75 @@ impl<M: Middleware + 'static> RedeemClient<M> {
             .map_err(|e| RedeemError::ContractError(e.to_string()))?;
         Ok(denominator > U256::zero())
     }
+
+    /// Redeem both outcomes of `condition_id` through the Gnosis Safe
+    ///
+    /// `wallet` signs the Safe transaction. Succeeds once the transaction
+    /// has `min_confirmations` confirmations, reporting the USDC the Safe
+    /// received; a zero payout means nothing was redeemed.
+    pub async fn redeem_via_safe(
+        &self,
+        safe_address: Address,
+        condition_id: &str,
+        neg_risk: bool,
+        wallet: &LocalWallet,
+    ) -> Result<Redemption> {
+        let index_sets = vec![U256::from(1), U256::from(2)];
+        self.redeem_outcomes_via_safe(safe_address, condition_id, neg_risk, index_sets, wallet)
+            .await
+    }
+
+    /// Redeem the outcomes in `index_sets` of `condition_id` through the
+    /// Gnosis Safe; see [`redeem_via_safe`](Self::redeem_via_safe)
+    pub async fn redeem_outcomes_via_safe(
+        &self,
+        safe_address: Address,
+        condition_id: &str,
+        neg_risk: bool,
+        index_sets: Vec<U256>,
+        wallet: &LocalWallet,
+    ) -> Result<Redemption> {
+        let (ctf_address, call_data) =
+            self.encode_redeem_outcomes_call(condition_id, neg_risk, index_sets)?;
+
+        let safe = GnosisSafe::new(safe_address, self.provider.clone());
+        let nonce = safe.nonce().call().await
+            .map_err(|e| RedeemError::ContractError(e.to_string()))?;
+
+        let safe_tx_hash = compute_safe_tx_hash(
+            safe_address, ctf_address, U256::zero(), call_data.clone(),
+            0, U256::zero(), U256::zero(), U256::zero(),
+            Address::zero(), Address::zero(), nonce, POLYGON_CHAIN_ID,
+        );
+
+        let signature = wallet.sign_hash(H256::from(safe_tx_hash))
+            .map_err(|e| RedeemError::ContractError(e.to_string()))?;
+
+        // Fetch dynamic gas price from network
+        let gas_price = get_dynamic_gas_price(&self.provider).await?;
+
+        let call = safe.exec_transaction(
+            ctf_address, U256::zero(), call_data, 0,
+            U256::zero(), U256::zero(), U256::zero(),
+            Address::zero(), Address::zero(), signature.to_vec().into(),
+        )
+        .gas(U256::from(GAS_LIMIT))
+        .gas_price(gas_price);
+
+        let pending_tx = call.send().await
+            .map_err(|e| RedeemError::ContractError(e.to_string()))?;
+
+        let tx_hash = pending_tx.tx_hash();
+        debug!("[Redeem] Transaction sent: {:?} (gas_price: {} gwei)", tx_hash, gas_price / U256::from(1_000_000_000u64));
+
+        let receipt = wait_for_confirmations(pending_tx, self.min_confirmations)
+            .await
+            .map_err(|e| RedeemError::TransactionFailed(e.to_string()))?;
+
+        let payout = usdc_received(&receipt, self.usdc, safe_address);
+        info!("[Redeem] Transaction confirmed: {:?} (payout: {} raw USDC)", tx_hash, payout);
+        Ok(Redemption { tx_hash, payout })
+    }
 }
 
 /// Fetch current gas price from the network and apply multiplier
@@ -144,6 +244,25 @@ async fn get_dynamic_gas_price<M: Middleware + 'static>(provider: &Arc<M>) -> Re
     Ok(U256::from(final_gwei) * U256::from(1_000_000_000u64))
 }
 
+/// Total USDC `Transfer`s to `recipient` logged in `receipt`
+fn usdc_received(receipt: &TransactionReceipt, usdc: Address, recipient: Address) -> U256 {
+    use ethers::utils::keccak256;
+
+    let transfer_topic = H256::from(keccak256(b"Transfer(address,address,uint256)"));
+    receipt
+        .logs
+        .iter()
+        .filter(|log| {
+            log.address == usdc
+                && log.topics.len() == 3
+                && log.topics[0] == transfer_topic
+                && Address::from(log.topics[2]) == recipient
+        })
+        .fold(U256::zero(), |total, log| {
+            total.saturating_add(U256::from_big_endian(&log.data))
+        })
+}
+
 pub async fn redeem_via_safe(
     safe_address: Address,
     condition_id: &str,
@@ -155,54 +274,10 @@ pub async fn redeem_via_safe(
         .map_err(|e| RedeemError::ProviderError(e.to_string()))?;
     let provider = Arc::new(SignerMiddleware::new(provider, wallet.clone()));
 
-    let client = RedeemClient::new(provider.clone());
-    let (ctf_address, call_data) = client.encode_redeem_call(condition_id, neg_risk)?;
-
-    let safe = GnosisSafe::new(safe_address, provider.clone());
-    let nonce = safe.nonce().call().await
-        .map_err(|e| RedeemError::ContractError(e.to_string()))?;
-
-    let safe_tx_hash = compute_safe_tx_hash(
-        safe_address, ctf_address, U256::zero(), call_data.clone(),
-        0, U256::zero(), U256::zero(), U256::zero(),
-        Address::zero(), Address::zero(), nonce, POLYGON_CHAIN_ID,
-    );
-
-    let signature = wallet.sign_hash(H256::from(safe_tx_hash))
-        .map_err(|e| RedeemError::ContractError(e.to_string()))?;
-
-    // Fetch dynamic gas price from network
-    let gas_price = get_dynamic_gas_price(&provider).await?;
-
-    let call = safe.exec_transaction(
-        ctf_address, U256::zero(), call_data, 0,
-        U256::zero(), U256::zero(), U256::zero(),
-        Address::zero(), Address::zero(), signature.to_vec().into(),
-    )
-    .gas(U256::from(GAS_LIMIT))
-    .gas_price(gas_price);
-
-    let pending_tx = call.send().await
-        .map_err(|e| RedeemError::ContractError(e.to_string()))?;
-
-    let tx_hash = pending_tx.tx_hash();
-    debug!("[Redeem] Transaction sent: {:?} (gas_price: {} gwei)", tx_hash, gas_price / U256::from(1_000_000_000u64));
-
-    let receipt = tokio::time::timeout(
-        std::time::Duration::from_secs(60),
-        pending_tx,
-    )
-    .await
-    .map_err(|_| RedeemError::TransactionFailed(format!("Timeout. TX: {:?}", tx_hash)))?
-    .map_err(|e| RedeemError::TransactionFailed(e.to_string()))?
-    .ok_or_else(|| RedeemError::TransactionFailed("No receipt".to_string()))?;
-
-    if receipt.status == Some(U64::from(1)) {
-        info!("[Redeem] Transaction confirmed: {:?}", tx_hash);
-        Ok(tx_hash)
-    } else {
-        Err(RedeemError::TransactionFailed("Transaction reverted".to_string()))
-    }
+    RedeemClient::new(provider)
+        .redeem_via_safe(safe_address, condition_id, neg_risk, wallet)
+        .await
+        .map(|redemption| redemption.tx_hash)
 }
 
 fn compute_safe_tx_hash(
@@ -384,9 +459,158 @@ pub async fn create_signer_provider(
     Ok(Arc::new(SignerMiddleware::new(provider, wallet.with_chain_id(chain_id))))
 }
 
+/// Redeems winning positions once their market has resolved
+///
+/// Each pass asks the Data API for the Safe's redeemable positions, skips
+/// losing outcomes (priced at zero after resolution), confirms resolution
+/// on-chain and redeems each condition through the Safe. A condition only
+/// counts as redeemed once its redeem is confirmed and paid USDC into the
+/// Safe; until then it is retried on the next pass.
+pub struct RedeemScheduler<M: Middleware> {
+    client: RedeemClient<M>,
+    wallet: LocalWallet,
+    safe_address: Address,
+    data_client: DataApiClient,
+    redeemed: HashSet<String>,
+    /// Closed out for every outcome of a condition once its redeem settles
+    position_tracker: Option<SharedPositionTracker>,
+}
+
+impl<M: Middleware + 'static> RedeemScheduler<M> {
+    /// Create a scheduler redeeming positions held by the Safe at
+    /// `safe_address`, signing Safe transactions with `wallet`
+    pub fn new(client: RedeemClient<M>, wallet: LocalWallet, safe_address: Address) -> Self {
+        Self {
+            client,
+            wallet,
+            safe_address,
+            data_client: DataApiClient::new(),
+            redeemed: HashSet::new(),
+            position_tracker: None,
+        }
+    }
+
+    /// Close out redeemed positions in `position_tracker`
+    pub fn with_position_tracker(mut self, position_tracker: SharedPositionTracker) -> Self {
+        self.position_tracker = Some(position_tracker);
+        self
+    }
+
+    /// Use a custom Data API client
+    pub fn with_data_client(mut self, data_client: DataApiClient) -> Self {
+        self.data_client = data_client;
+        self
+    }
+
+    /// Fetch redeemable positions and redeem them
+    pub async fn run_once(&mut self) -> Result<Vec<RedemptionResult>> {
+        let positions = self
+            .data_client
+            .get_redeemable_positions(&format!("{:?}", self.safe_address))
+            .await
+            .map_err(|e| RedeemError::ProviderError(e.to_string()))?;
+        Ok(self.redeem_positions(&positions).await)
+    }
+
+    /// Redeem every resolved condition in which one of `positions` won
+    pub async fn redeem_positions(&mut self, positions: &[Position]) -> Vec<RedemptionResult> {
+        let mut results = Vec::new();
+        let mut seen = HashSet::new();
+
+        for position in positions {
+            if !position.redeemable
+                || position.cur_price <= 0.0
+                || position.size <= 0.0
+                || self.redeemed.contains(&position.condition_id)
+                || !seen.insert(position.condition_id.as_str())
+            {
+                continue;
+            }
+
+            let condition_id = &position.condition_id;
+            let neg_risk = position.negative_risk;
+            match self.client.is_resolved(condition_id, neg_risk).await {
+                Ok(true) => {}
+                Ok(false) => {
+                    debug!("[Redeem] Condition {} not resolved on-chain yet", condition_id);
+                    continue;
+                }
+                Err(e) => {
+                    warn!("[Redeem] Failed to check resolution of {}: {}", condition_id, e);
+                    continue;
+                }
+            }
+
+            let mut result = RedemptionResult {
+                condition_id: condition_id.clone(),
+                title: position.title.clone(),
+                outcome: position.outcome.clone(),
+                size: position.size,
+                neg_risk,
+                tx_hash: None,
+                error: None,
+            };
+
+            match self
+                .client
+                .redeem_via_safe(self.safe_address, condition_id, neg_risk, &self.wallet)
+                .await
+            {
+                Ok(redemption) if !redemption.payout.is_zero() => {
+                    info!("[Redeem] Redeemed: {} - TX: {:?}", position.title, redemption.tx_hash);
+                    result.tx_hash = Some(redemption.tx_hash);
+                    self.redeemed.insert(condition_id.clone());
+                    self.apply_redeem(condition_id, positions);
+                }
+                Ok(redemption) => {
+                    warn!("[Redeem] {} confirmed without a USDC payout: {:?}", position.title, redemption.tx_hash);
+                    result.tx_hash = Some(redemption.tx_hash);
+                    result.error = Some("Confirmed without a USDC payout".to_string());
+                }
+                Err(e) => {
+                    warn!("[Redeem] Failed: {} - {}", position.title, e);
+                    result.error = Some(e.to_string());
+                }
+            }
+
+            results.push(result);
+        }
+
+        results
+    }
+
+    /// Close out every outcome of the redeemed condition at its resolved
+    /// price (1.0 for the winner, 0.0 for the losers)
+    fn apply_redeem(&self, condition_id: &str, positions: &[Position]) {
+        let Some(ref tracker) = self.position_tracker else {
+            return;
+        };
+        let mut tracker = tracker.write();
+        for position in positions.iter().filter(|p| p.condition_id == condition_id) {
+            tracker.apply_redeem(&position.asset, position.cur_price);
+        }
+    }
+
+    /// Run [`run_once`](Self::run_once) every `interval` while `shutdown_flag` stays set
+    pub async fn run(mut self, interval: Duration, shutdown_flag: Arc<AtomicBool>) {
+        while shutdown_flag.load(Ordering::Acquire) {
+            match self.run_once().await {
+                Ok(results) if !results.is_empty() => {
+                    info!("[Redeem] Redeemed {} condition(s)", results.iter().filter(|r| r.error.is_none()).count());
+                }
+                Ok(_) => {}
+                Err(e) => warn!("[Redeem] Redeem pass failed: {}", e),
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ethers::types::transaction::eip2718::TypedTransaction;
+    use crate::infrastructure::client::user::PositionTracker;
 
     #[test]
     fn test_parse_condition_id() {
@@ -402,4 +626,299 @@ mod tests {
         assert!(NEG_RISK_CTF_CONTRACT.parse::<Address>().is_ok());
         assert!(USDC_ADDRESS.parse::<Address>().is_ok());
     }
+
+    const SAFE: &str = "0x0000000000000000000000000000000000000001";
+    const CONDITION_ID: &str = "0xabcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234abcd1234";
+
+    fn test_wallet() -> LocalWallet {
+        "0000000000000000000000000000000000000000000000000000000000000001"
+            .parse::<LocalWallet>()
+            .unwrap()
+            .with_chain_id(POLYGON_CHAIN_ID)
+    }
+
+    fn test_position(condition_id: &str, outcome_index: i32, cur_price: f64) -> Position {
+        serde_json::from_value(serde_json::json!({
+            "proxyWallet": SAFE,
+            "asset": "1",
+            "conditionId": condition_id,
+            "size": 10.0,
+            "avgPrice": 0.5,
+            "initialValue": 5.0,
+            "currentValue": 10.0 * cur_price,
+            "cashPnl": 0.0,
+            "percentPnl": 0.0,
+            "totalBought": 10.0,
+            "realizedPnl": 0.0,
+            "percentRealizedPnl": 0.0,
+            "curPrice": cur_price,
+            "redeemable": true,
+            "mergeable": false,
+            "title": "Test market",
+            "slug": "test-market",
+            "outcome": if outcome_index == 0 { "Yes" } else { "No" },
+            "outcomeIndex": outcome_index,
+            "oppositeOutcome": if outcome_index == 0 { "No" } else { "Yes" },
+            "oppositeAsset": "2",
+            "endDate": "2026-01-01",
+            "negativeRisk": false,
+        }))
+        .unwrap()
+    }
+
+    /// USDC transfer from the CTF contract to the Safe
+    fn payout_log(amount: U256) -> Log {
+        let topic = |address: &str| H256::from(address.parse::<Address>().unwrap());
+        Log {
+            address: USDC_ADDRESS.parse().unwrap(),
+            topics: vec![
+                H256::from(ethers::utils::keccak256(b"Transfer(address,address,uint256)")),
+                topic(CTF_CONTRACT),
+                topic(SAFE),
+            ],
+            data: Bytes::from(u256_to_bytes32(amount).to_vec()),
+            ..Default::default()
+        }
+    }
+
+    /// Queue the RPC responses for one resolved-check + Safe redeem, mined
+    /// in `inclusion_block` with `logs`, followed by `block_numbers` for
+    /// confirmation polling
+    fn push_redeem_responses(
+        mock: &MockProvider,
+        tx_hash: H256,
+        logs: Vec<Log>,
+        inclusion_block: u64,
+        block_numbers: &[u64],
+    ) {
+        push_safe_redeem_responses(mock, tx_hash, logs, inclusion_block, block_numbers);
+        // payoutDenominator(condition) = 1 -> resolved
+        mock.push::<Bytes, _>(Bytes::from(u256_to_bytes32(U256::one()).to_vec())).unwrap();
+    }
+
+    /// Queue the RPC responses for one Safe redeem without a resolution check
+    fn push_safe_redeem_responses(
+        mock: &MockProvider,
+        tx_hash: H256,
+        logs: Vec<Log>,
+        inclusion_block: u64,
+        block_numbers: &[u64],
+    ) {
+        // Responses are served last-in first-out
+        for block in block_numbers.iter().rev() {
+            mock.push(U64::from(*block)).unwrap();
+        }
+        mock.push(TransactionReceipt {
+            transaction_hash: tx_hash,
+            block_number: Some(U64::from(inclusion_block)),
+            status: Some(U64::from(1)),
+            logs,
+            ..Default::default()
+        })
+        .unwrap();
+        mock.push(Transaction {
+            hash: tx_hash,
+            block_number: Some(U64::from(inclusion_block)),
+            ..Default::default()
+        })
+        .unwrap();
+        mock.push(tx_hash).unwrap();
+        mock.push(U256::from(50_000_000_000u64)).unwrap();
+        // Safe nonce() = 0
+        mock.push::<Bytes, _>(Bytes::from(u256_to_bytes32(U256::zero()).to_vec())).unwrap();
+    }
+
+    fn mocked_scheduler(min_confirmations: usize) -> (RedeemScheduler<Provider<MockProvider>>, MockProvider) {
+        let (provider, mock) = Provider::mocked();
+        let provider = Arc::new(provider.interval(Duration::from_millis(1)));
+        let client = RedeemClient::new(provider).with_min_confirmations(min_confirmations);
+        let scheduler = RedeemScheduler::new(client, test_wallet(), SAFE.parse().unwrap());
+        (scheduler, mock)
+    }
+
+    #[tokio::test]
+    async fn test_scheduler_redeems_resolved_winning_position_via_safe() {
+        let (mut scheduler, mock) = mocked_scheduler(1);
+        let losing = "0x1111111111111111111111111111111111111111111111111111111111111111";
+        let tx_hash = H256::repeat_byte(0x42);
+
+        push_redeem_responses(&mock, tx_hash, vec![payout_log(usdc_raw(10))], 1, &[]);
+
+        let positions = vec![test_position(CONDITION_ID, 0, 1.0), test_position(losing, 1, 0.0)];
+        let results = scheduler.redeem_positions(&positions).await;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].condition_id, CONDITION_ID);
+        assert_eq!(results[0].tx_hash, Some(tx_hash));
+        assert!(results[0].error.is_none());
+
+        // Resolution was checked, then the redeem went out as a Safe
+        // execTransaction wrapping redeemPositions - not from the EOA
+        let client = &scheduler.client;
+        let resolution_call: TypedTransaction = Eip1559TransactionRequest::new()
+            .to(CTF_CONTRACT.parse::<Address>().unwrap())
+            .data(
+                client
+                    .ctf
+                    .payout_denominator(parse_condition_id(CONDITION_ID).unwrap())
+                    .calldata()
+                    .unwrap(),
+            )
+            .into();
+        mock.assert_request("eth_call", (resolution_call, "latest")).unwrap();
+
+        let safe_address: Address = SAFE.parse().unwrap();
+        let safe = GnosisSafe::new(safe_address, client.provider.clone());
+        let (ctf_address, call_data) = client.encode_redeem_call(CONDITION_ID, false).unwrap();
+        let safe_tx_hash = compute_safe_tx_hash(
+            safe_address, ctf_address, U256::zero(), call_data.clone(),
+            0, U256::zero(), U256::zero(), U256::zero(),
+            Address::zero(), Address::zero(), U256::zero(), POLYGON_CHAIN_ID,
+        );
+        let signature = test_wallet().sign_hash(H256::from(safe_tx_hash)).unwrap();
+        let expected_tx = safe
+            .exec_transaction(
+                ctf_address, U256::zero(), call_data, 0,
+                U256::zero(), U256::zero(), U256::zero(),
+                Address::zero(), Address::zero(), signature.to_vec().into(),
+            )
+            .gas(U256::from(GAS_LIMIT))
+            // Network price with GAS_PRICE_MULTIPLIER applied
+            .gas_price(U256::from(60_000_000_000u64))
+            .tx;
+        mock.assert_request("eth_call", (safe.nonce().tx, "latest")).unwrap();
+        mock.assert_request("eth_gasPrice", ()).unwrap();
+        mock.assert_request("eth_sendTransaction", [expected_tx]).unwrap();
+
+        // Already redeemed conditions are not redeemed again
+        assert!(scheduler.redeem_positions(&positions).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_settled_redeem_closes_out_tracked_positions() {
+        let tracker = Arc::new(parking_lot::RwLock::new(PositionTracker::new()));
+        tracker.write().hydrate_position("1", 10.0, 0.5);
+        tracker.write().hydrate_position("2", 10.0, 0.5);
+        let (scheduler, mock) = mocked_scheduler(1);
+        let mut scheduler = scheduler.with_position_tracker(tracker.clone());
+
+        let mut losing = test_position(CONDITION_ID, 1, 0.0);
+        losing.asset = "2".to_string();
+        let positions = vec![test_position(CONDITION_ID, 0, 1.0), losing];
+        push_redeem_responses(&mock, H256::repeat_byte(0x42), vec![payout_log(usdc_raw(10))], 1, &[]);
+        let results = scheduler.redeem_positions(&positions).await;
+        assert!(results[0].error.is_none());
+
+        let tracker = tracker.read();
+        assert_eq!(tracker.get_net_size("1"), 0.0);
+        assert_eq!(tracker.get_net_size("2"), 0.0);
+        assert_eq!(tracker.get_position("1").unwrap().realized_pnl, 5.0);
+        assert_eq!(tracker.get_position("2").unwrap().realized_pnl, -5.0);
+    }
+
+    #[tokio::test]
+    async fn test_redeem_without_payout_is_not_marked_redeemed() {
+        let (mut scheduler, mock) = mocked_scheduler(1);
+        let positions = vec![test_position(CONDITION_ID, 0, 1.0)];
+        let tx_hash = H256::repeat_byte(0x42);
+
+        // Mined and successful, but no USDC reached the Safe
+        push_redeem_responses(&mock, tx_hash, vec![], 1, &[]);
+        let results = scheduler.redeem_positions(&positions).await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].tx_hash, Some(tx_hash));
+        assert!(results[0].error.is_some());
+        assert!(!scheduler.redeemed.contains(CONDITION_ID));
+    }
+
+    #[tokio::test]
+    async fn test_redeem_not_settled_before_min_confirmations() {
+        let (mut scheduler, mock) = mocked_scheduler(3);
+        let positions = vec![test_position(CONDITION_ID, 0, 1.0)];
+        let tx_hash = H256::repeat_byte(0x42);
+        let logs = vec![payout_log(usdc_raw(10))];
+
+        // Mined in block 10, but the chain never gets past block 11 before the RPC gives up
+        push_redeem_responses(&mock, tx_hash, logs.clone(), 10, &[10, 11]);
+        let results = scheduler.redeem_positions(&positions).await;
+        assert_eq!(results.len(), 1);
+        assert!(results[0].error.is_some());
+        assert!(!scheduler.redeemed.contains(CONDITION_ID));
+
+        // Once the chain is far enough past the inclusion block the redeem settles
+        push_redeem_responses(&mock, tx_hash, logs, 10, &[11, 13]);
+        let results = scheduler.redeem_positions(&positions).await;
+        assert_eq!(results.len(), 1);
+        assert!(results[0].error.is_none());
+        assert!(scheduler.redeemed.contains(CONDITION_ID));
+    }
+
+    #[tokio::test]
+    async fn test_ctf_client_redeems_held_outcomes_via_safe() {
+        use crate::infrastructure::client::ctf::{CtfClient, CtfOperation};
+
+        let (provider, mock) = Provider::mocked();
+        let provider = Arc::new(provider.interval(Duration::from_millis(1)));
+        let safe_address: Address = SAFE.parse().unwrap();
+        let ctf = CtfClient::new(provider.clone()).with_safe(safe_address, test_wallet());
+        let tx_hash = H256::repeat_byte(0x42);
+        push_safe_redeem_responses(&mock, tx_hash, vec![payout_log(usdc_raw(10))], 1, &[]);
+
+        // Only outcome 0 is held
+        let result = ctf
+            .redeem(CONDITION_ID, false, &[usdc_raw(10), U256::zero()])
+            .await
+            .unwrap();
+        assert_eq!(result.operation, CtfOperation::Redeem);
+        assert_eq!(result.amount, usdc_raw(10));
+        assert_eq!(result.tx_hash, Some(tx_hash));
+        assert!(result.error.is_none());
+
+        // Sent as a Safe execTransaction wrapping redeemPositions([1])
+        let client = RedeemClient::new(provider.clone());
+        let (ctf_address, call_data) = client
+            .encode_redeem_outcomes_call(CONDITION_ID, false, vec![U256::one()])
+            .unwrap();
+        let safe = GnosisSafe::new(safe_address, provider);
+        let safe_tx_hash = compute_safe_tx_hash(
+            safe_address, ctf_address, U256::zero(), call_data.clone(),
+            0, U256::zero(), U256::zero(), U256::zero(),
+            Address::zero(), Address::zero(), U256::zero(), POLYGON_CHAIN_ID,
+        );
+        let signature = test_wallet().sign_hash(H256::from(safe_tx_hash)).unwrap();
+        let expected_tx = safe
+            .exec_transaction(
+                ctf_address, U256::zero(), call_data, 0,
+                U256::zero(), U256::zero(), U256::zero(),
+                Address::zero(), Address::zero(), signature.to_vec().into(),
+            )
+            .gas(U256::from(GAS_LIMIT))
+            .gas_price(U256::from(60_000_000_000u64))
+            .tx;
+        mock.assert_request("eth_call", (safe.nonce().tx, "latest")).unwrap();
+        mock.assert_request("eth_gasPrice", ()).unwrap();
+        mock.assert_request("eth_sendTransaction", [expected_tx]).unwrap();
+
+        // Nothing held, nothing sent
+        assert!(ctf.redeem(CONDITION_ID, false, &[U256::zero()]).await.is_err());
+    }
+
+    #[test]
+    fn test_usdc_received_only_counts_transfers_to_recipient() {
+        let safe: Address = SAFE.parse().unwrap();
+        let usdc: Address = USDC_ADDRESS.parse().unwrap();
+        let mut elsewhere = payout_log(usdc_raw(3));
+        elsewhere.topics[2] = H256::from(Address::repeat_byte(0x22));
+        let receipt = TransactionReceipt {
+            logs: vec![payout_log(usdc_raw(10)), elsewhere],
+            ..Default::default()
+        };
+
+        assert_eq!(usdc_received(&receipt, usdc, safe), usdc_raw(10));
+        assert!(usdc_received(&receipt, usdc, Address::repeat_byte(0x33)).is_zero());
+    }
+
+    fn usdc_raw(amount: u64) -> U256 {
+        U256::from(amount * 1_000_000)
+    }
 }
@@ -330,6 +330,14 @@ fn handle_client_event(event: ClientEvent) -> bool {
             warn!("[Sports WS] Error: {}", err);
             true
         }
+        ClientEvent::MessageTooLarge { size, max_size } => {
+            warn!("[Sports WS] Message too large: {} bytes (max {})", size, max_size);
+            true
+        }
+        ClientEvent::CircuitOpen => {
+            warn!("[Sports WS] Circuit breaker open, stopped reconnecting");
+            false
+        }
     }
 }
 
@@ -0,0 +1,220 @@
+//! Unified cross-feed message stream
+//!
+//! Strategies that react to orderbook, trade, oracle and sports data at once
+//! would otherwise juggle one channel per feed. `UnifiedFeed` hands out
+//! `FeedForwarder` handlers that plug into each feed's WebSocket client and
+//! forward every data message, typed, into a single channel:
+//!
+//! ```text
+//! CLOB market WS  ──► FeedForwarder ─┐
+//! Oracle WS       ──► FeedForwarder ─┼──► Receiver<UnifiedMessage>
+//! Sports WS       ──► FeedForwarder ─┘
+//! ```
+//!
+//! Messages from one feed arrive in the order that feed delivered them.
+//! Heartbeat and unknown messages are dropped.
+
+use super::clob::sniper_ws_types::{
+    BookSnapshot, LastTradePriceEvent, MarketResolvedEvent, PriceChangeEvent, SniperMessage,
+    TickSizeChangeEvent,
+};
+use super::oracle::{OracleMessage, OraclePriceUpdate};
+use super::sports::{SportsLiveData, SportsLiveDataMessage};
+use crossbeam_channel::{unbounded, Receiver, Sender};
+use hypersockets::MessageHandler;
+
+/// A data message from any of the supported feeds
+#[derive(Debug, Clone)]
+pub enum UnifiedMessage {
+    /// Orderbook snapshots (CLOB market channel)
+    Book(Vec<BookSnapshot>),
+    /// Incremental orderbook update (CLOB market channel)
+    PriceChange(PriceChangeEvent),
+    /// Tick size change (CLOB market channel)
+    TickSizeChange(TickSizeChangeEvent),
+    /// Trade execution (CLOB market channel)
+    Trade(LastTradePriceEvent),
+    /// Market resolved/closed (CLOB market channel)
+    MarketResolved(MarketResolvedEvent),
+    /// Oracle price update (RTDS)
+    Oracle(OraclePriceUpdate),
+    /// Sports game update
+    Sports(SportsLiveData),
+}
+
+/// Conversion of a feed message into the unified stream
+///
+/// Returns `None` for heartbeats and unknown messages, which are not forwarded.
+pub trait IntoUnified {
+    fn into_unified(self) -> Option<UnifiedMessage>;
+}
+
+impl IntoUnified for SniperMessage {
+    fn into_unified(self) -> Option<UnifiedMessage> {
+        match self {
+            SniperMessage::BookSnapshots(snapshots) => Some(UnifiedMessage::Book(snapshots)),
+            SniperMessage::PriceChange(event) => Some(UnifiedMessage::PriceChange(event)),
+            SniperMessage::TickSizeChange(event) => Some(UnifiedMessage::TickSizeChange(event)),
+            SniperMessage::LastTradePrice(event) => Some(UnifiedMessage::Trade(event)),
+            SniperMessage::MarketResolved(event) => Some(UnifiedMessage::MarketResolved(event)),
+            SniperMessage::Pong | SniperMessage::Unknown(_) => None,
+        }
+    }
+}
+
+impl IntoUnified for OracleMessage {
+    fn into_unified(self) -> Option<UnifiedMessage> {
+        match self {
+            OracleMessage::PriceUpdate(update) => Some(UnifiedMessage::Oracle(update)),
+            OracleMessage::Pong | OracleMessage::Unknown(_) => None,
+        }
+    }
+}
+
+impl IntoUnified for SportsLiveDataMessage {
+    fn into_unified(self) -> Option<UnifiedMessage> {
+        match self {
+            SportsLiveDataMessage::GameUpdate(data) => Some(UnifiedMessage::Sports(data)),
+            SportsLiveDataMessage::Unknown(_) => None,
+        }
+    }
+}
+
+/// Merges several feeds into one typed channel
+pub struct UnifiedFeed {
+    tx: Sender<UnifiedMessage>,
+    rx: Receiver<UnifiedMessage>,
+}
+
+impl UnifiedFeed {
+    pub fn new() -> Self {
+        let (tx, rx) = unbounded();
+        Self { tx, rx }
+    }
+
+    /// Create a handler to register on a feed's WebSocket client
+    pub fn forwarder(&self) -> FeedForwarder {
+        FeedForwarder {
+            tx: self.tx.clone(),
+        }
+    }
+
+    /// Receiver for the merged stream
+    pub fn receiver(&self) -> Receiver<UnifiedMessage> {
+        self.rx.clone()
+    }
+}
+
+impl Default for UnifiedFeed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Handler that forwards a feed's data messages into a `UnifiedFeed`
+#[derive(Clone)]
+pub struct FeedForwarder {
+    tx: Sender<UnifiedMessage>,
+}
+
+impl FeedForwarder {
+    fn forward(&self, message: Option<UnifiedMessage>) -> hypersockets::Result<()> {
+        if let Some(message) = message {
+            // Receiver dropped means nobody is consuming - not an error for the feed
+            let _ = self.tx.send(message);
+        }
+        Ok(())
+    }
+}
+
+impl MessageHandler<SniperMessage> for FeedForwarder {
+    fn handle(&mut self, message: SniperMessage) -> hypersockets::Result<()> {
+        self.forward(message.into_unified())
+    }
+}
+
+impl MessageHandler<OracleMessage> for FeedForwarder {
+    fn handle(&mut self, message: OracleMessage) -> hypersockets::Result<()> {
+        self.forward(message.into_unified())
+    }
+}
+
+impl MessageHandler<SportsLiveDataMessage> for FeedForwarder {
+    fn handle(&mut self, message: SportsLiveDataMessage) -> hypersockets::Result<()> {
+        self.forward(message.into_unified())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::client::oracle::OraclePricePayload;
+
+    #[test]
+    fn test_messages_arrive_typed_in_order() {
+        let feed = UnifiedFeed::new();
+        let rx = feed.receiver();
+        let mut clob = feed.forwarder();
+        let mut oracle = feed.forwarder();
+        let mut sports = feed.forwarder();
+
+        clob.handle(SniperMessage::BookSnapshots(vec![BookSnapshot {
+            market: "0xabc".to_string(),
+            asset_id: "yes".to_string(),
+            bids: vec![],
+            asks: vec![],
+            event_type: "book".to_string(),
+            timestamp: None,
+            hash: None,
+            last_trade_price: None,
+        }]))
+        .unwrap();
+        clob.handle(SniperMessage::LastTradePrice(LastTradePriceEvent {
+            event_type: "last_trade_price".to_string(),
+            asset_id: "yes".to_string(),
+            market: "0xabc".to_string(),
+            price: "0.55".to_string(),
+            size: "10".to_string(),
+            side: "BUY".to_string(),
+            timestamp: "1".to_string(),
+            fee_rate_bps: None,
+        }))
+        .unwrap();
+        // Heartbeats are not forwarded
+        clob.handle(SniperMessage::Pong).unwrap();
+        oracle
+            .handle(OracleMessage::PriceUpdate(OraclePriceUpdate {
+                topic: "crypto_prices_chainlink".to_string(),
+                msg_type: "update".to_string(),
+                timestamp: 2,
+                payload: OraclePricePayload {
+                    symbol: "btc/usd".to_string(),
+                    timestamp: 2,
+                    value: 100_000.0,
+                },
+            }))
+            .unwrap();
+        sports
+            .handle(SportsLiveDataMessage::GameUpdate(SportsLiveData {
+                game_id: 7,
+                score: "1-0".to_string(),
+                elapsed: "90".to_string(),
+                period: "FT".to_string(),
+                live: false,
+                ended: true,
+                finished_timestamp: None,
+                league_abbreviation: "epl".to_string(),
+                home_team: None,
+                away_team: None,
+                status: None,
+            }))
+            .unwrap();
+
+        let received: Vec<UnifiedMessage> = rx.try_iter().collect();
+        assert_eq!(received.len(), 4);
+        assert!(matches!(&received[0], UnifiedMessage::Book(b) if b[0].asset_id == "yes"));
+        assert!(matches!(&received[1], UnifiedMessage::Trade(t) if t.price == "0.55"));
+        assert!(matches!(&received[2], UnifiedMessage::Oracle(o) if o.payload.symbol == "btc/usd"));
+        assert!(matches!(&received[3], UnifiedMessage::Sports(s) if s.game_id == 7));
+    }
+}
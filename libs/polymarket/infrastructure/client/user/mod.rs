@@ -60,8 +60,8 @@ pub use user_ws::{
 
 // Re-export position tracker types
 pub use position_tracker::{
-    MergeOpportunity, NoOpPositionCallback, Position, PositionDiscrepancy, PositionEvent,
-    PositionEventCallback, PositionTracker, PositionTrackerBridge, ReconciliationResult,
+    MergeOpportunity, NoOpPositionCallback, Position, PositionCallbacks, PositionChange, PositionChangeReason,
+    PositionDiscrepancy, PositionEvent, PositionEventCallback, PositionTracker, PositionTrackerBridge, ReconciliationResult,
     SharedPositionTracker,
 };
 
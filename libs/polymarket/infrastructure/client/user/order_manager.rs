@@ -12,9 +12,9 @@
 use super::types::{MessageType, OrderMessage, TradeMessage};
 use chrono::{DateTime, Utc};
 use parking_lot::RwLock;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tracing::{debug, warn};
 
 /// Maximum number of trade IDs to track for deduplication
@@ -688,6 +688,14 @@ pub struct OrderStateStore {
     pending_cancels: HashMap<String, Instant>,
     /// Track insertion order for LRU cleanup
     pending_cancels_order: VecDeque<String>,
+    /// How long WebSocket fills are held so late arrivals can be applied in
+    /// exchange order (zero = apply on arrival)
+    fill_reorder_window: Duration,
+    /// Held fills keyed by (exchange time, arrival sequence)
+    buffered_fills: BTreeMap<(i64, u64), (Instant, Fill)>,
+    fill_arrival_seq: u64,
+    /// Exchange time of the last released fill, to spot fills that arrived too late
+    last_released_fill_time: i64,
 }
 
 impl std::fmt::Debug for OrderStateStore {
@@ -727,9 +735,23 @@ impl OrderStateStore {
             token_pairs: TokenPairRegistry::new(),
             pending_cancels: HashMap::new(),
             pending_cancels_order: VecDeque::new(),
+            fill_reorder_window: Duration::ZERO,
+            buffered_fills: BTreeMap::new(),
+            fill_arrival_seq: 0,
+            last_released_fill_time: i64::MIN,
         }
     }
 
+    /// Hold WebSocket fills for `window` and release them in exchange order.
+    ///
+    /// Fills can arrive out of order (reconnects, REST hydration racing the
+    /// socket), and average-cost P&L depends on the order they are applied.
+    /// Buffered fills come out of `release_ready_fills()`, not `process_trade()`.
+    pub fn with_fill_reorder_window(mut self, window: Duration) -> Self {
+        self.fill_reorder_window = window;
+        self
+    }
+
     /// Get the callback reference (for firing events outside the lock)
     pub fn callback(&self) -> &Arc<dyn OrderEventCallback> {
         &self.callback
@@ -1177,13 +1199,66 @@ impl OrderStateStore {
             owner: msg.owner.clone(),
         };
 
+        if !self.fill_reorder_window.is_zero() {
+            let key = (Self::fill_exchange_time(&fill), self.fill_arrival_seq);
+            self.fill_arrival_seq += 1;
+            self.buffered_fills.insert(key, (Instant::now(), fill));
+            return None;
+        }
+
+        Some(self.apply_fill(fill))
+    }
+
+    /// Record a fill in its asset book and build the event for it
+    fn apply_fill(&mut self, fill: Fill) -> OrderEvent {
         let event = OrderEvent::Trade(fill.clone());
 
         // Use the correct asset_id (our token for MAKER, taker's for TAKER)
         let book = self.get_or_create_asset(&fill.asset_id);
         book.add_fill(fill);
 
-        Some(event)
+        event
+    }
+
+    /// Exchange time used to order fills: match time, else message timestamp
+    fn fill_exchange_time(fill: &Fill) -> i64 {
+        match parse_timestamp_to_i64(&fill.match_time) {
+            i64::MIN => parse_timestamp_to_i64(&fill.timestamp),
+            time => time,
+        }
+    }
+
+    /// Release buffered fills that have waited out the reorder window, in
+    /// exchange order. Fire the returned events after releasing the lock.
+    pub fn release_ready_fills(&mut self) -> Vec<OrderEvent> {
+        self.release_ready_fills_at(Instant::now())
+    }
+
+    fn release_ready_fills_at(&mut self, now: Instant) -> Vec<OrderEvent> {
+        let mut events = Vec::new();
+        // The earliest fill gates the rest: anything behind it waits until it
+        // has had its full window for stragglers
+        while let Some(entry) = self.buffered_fills.first_entry() {
+            let (received_at, _) = entry.get();
+            if now.saturating_duration_since(*received_at) < self.fill_reorder_window {
+                break;
+            }
+            let ((exchange_time, _), (_, fill)) = entry.remove_entry();
+            if exchange_time < self.last_released_fill_time {
+                warn!(
+                    "[OrderState] Fill {} arrived after the reorder window, applying out of order",
+                    &fill.trade_id[..16.min(fill.trade_id.len())]
+                );
+            }
+            self.last_released_fill_time = self.last_released_fill_time.max(exchange_time);
+            events.push(self.apply_fill(fill));
+        }
+        events
+    }
+
+    /// Number of fills held for reordering
+    pub fn buffered_fill_count(&self) -> usize {
+        self.buffered_fills.len()
     }
 
     /// Fire a callback for an order event. Call after releasing the write lock.
@@ -1325,35 +1400,37 @@ impl OrderStateStore {
     /// Hydrate from REST API trades response
     /// NOTE: REST API typically returns CONFIRMED trades only, but we check status to be safe.
     pub fn hydrate_trades(&mut self, trades: &[serde_json::Value]) {
-        for trade_json in trades {
-            if let Some(fill) = Self::parse_rest_trade(trade_json) {
-                // Only process CONFIRMED trades from REST hydration
-                // This ensures position tracking is accurate from startup
-                if !matches!(fill.status, TradeStatus::Confirmed) {
-                    debug!(
-                        "[OrderState] Skipping non-CONFIRMED trade {} during hydration (status: {})",
-                        &fill.trade_id[..16.min(fill.trade_id.len())],
-                        fill.status
-                    );
-                    continue;
-                }
+        // REST pages aren't guaranteed to be in exchange order
+        let mut fills: Vec<Fill> = trades.iter().filter_map(Self::parse_rest_trade).collect();
+        fills.sort_by_key(Self::fill_exchange_time);
+
+        for fill in fills {
+            // Only process CONFIRMED trades from REST hydration
+            // This ensures position tracking is accurate from startup
+            if !matches!(fill.status, TradeStatus::Confirmed) {
+                debug!(
+                    "[OrderState] Skipping non-CONFIRMED trade {} during hydration (status: {})",
+                    &fill.trade_id[..16.min(fill.trade_id.len())],
+                    fill.status
+                );
+                continue;
+            }
 
-                // Create composite key for deduplication
-                let status_key = format!("{}:{}", fill.trade_id, fill.status);
+            // Create composite key for deduplication
+            let status_key = format!("{}:{}", fill.trade_id, fill.status);
 
-                // Skip if already seen
-                if self.seen_trade_status.contains(&status_key) {
-                    continue;
-                }
+            // Skip if already seen
+            if self.seen_trade_status.contains(&status_key) {
+                continue;
+            }
 
-                // Track for deduplication
-                self.seen_trade_status.insert(status_key.clone());
-                self.seen_trade_status_order.push_back(status_key);
-                self.terminal_trade_ids.insert(fill.trade_id.clone());
+            // Track for deduplication
+            self.seen_trade_status.insert(status_key.clone());
+            self.seen_trade_status_order.push_back(status_key);
+            self.terminal_trade_ids.insert(fill.trade_id.clone());
 
-                let book = self.get_or_create_asset(&fill.asset_id);
-                book.add_fill(fill);
-            }
+            let book = self.get_or_create_asset(&fill.asset_id);
+            book.add_fill(fill);
         }
 
         // Apply cap after hydration
@@ -2067,6 +2144,55 @@ mod tests {
         assert_eq!(store.fill_count(), 1);
     }
 
+    #[test]
+    fn test_out_of_order_fills_applied_in_exchange_order() {
+        use super::super::position_tracker::PositionTracker;
+
+        let timed_trade = |id: &str, side: &str, price: &str, matchtime: &str| {
+            let mut msg = make_trade_msg(id, "asset-1", side, "10");
+            msg.price = price.to_string();
+            msg.matchtime = Some(matchtime.to_string());
+            msg
+        };
+        let in_order = [
+            timed_trade("trade-1", "BUY", "0.40", "1705315800"),
+            timed_trade("trade-2", "BUY", "0.60", "1705315801"),
+            timed_trade("trade-3", "SELL", "0.70", "1705315802"),
+        ];
+
+        let apply_all = |events: Vec<OrderEvent>| {
+            let mut tracker = PositionTracker::new();
+            for event in events {
+                if let OrderEvent::Trade(fill) = event {
+                    tracker.apply_fill(&fill);
+                }
+            }
+            tracker.get_position("asset-1").cloned().unwrap()
+        };
+
+        let mut store = OrderStateStore::new();
+        let expected = apply_all(in_order.iter().filter_map(|t| store.process_trade(t)).collect());
+
+        // The sell arrives first and one buy arrives last
+        let window = Duration::from_millis(200);
+        let mut store = OrderStateStore::new().with_fill_reorder_window(window);
+        for trade in [&in_order[2], &in_order[0], &in_order[1]] {
+            assert!(store.process_trade(trade).is_none());
+        }
+        assert_eq!(store.buffered_fill_count(), 3);
+        assert!(store.release_ready_fills_at(Instant::now()).is_empty());
+
+        let released = store.release_ready_fills_at(Instant::now() + window);
+        assert_eq!(store.buffered_fill_count(), 0);
+        assert_eq!(store.fill_count(), 3);
+        let actual = apply_all(released);
+
+        assert!((actual.size - expected.size).abs() < 1e-9);
+        assert!((actual.avg_entry_price - expected.avg_entry_price).abs() < 1e-9);
+        assert!((actual.realized_pnl - expected.realized_pnl).abs() < 1e-9);
+        assert!((expected.realized_pnl - 2.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_multi_asset() {
         let mut store = OrderStateStore::new();
@@ -31,6 +31,7 @@
 
 use super::order_manager::{Fill, Order, OrderEventCallback, Side, TokenPairRegistry, TradeStatus};
 use chrono::{DateTime, Utc};
+use crossbeam_channel::{unbounded, Receiver, Sender};
 use parking_lot::RwLock;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
@@ -182,6 +183,34 @@ pub enum PositionEvent {
     NoOp,
 }
 
+// =============================================================================
+// PositionChange
+// =============================================================================
+
+/// What caused a position to change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionChangeReason {
+    /// A fill was applied
+    Fill,
+    /// Up/Down pairs were merged back into USDC
+    Merge,
+    /// A resolved position was redeemed
+    Redeem,
+    /// Position was corrected from REST data
+    Reconcile,
+}
+
+/// Position change pushed to subscribers (see `PositionTracker::subscribe`)
+#[derive(Debug, Clone)]
+pub struct PositionChange {
+    /// Token ID (asset_id)
+    pub token_id: String,
+    /// Net position size after the change
+    pub net_size: f64,
+    /// What caused the change
+    pub reason: PositionChangeReason,
+}
+
 // =============================================================================
 // Reconciliation Types
 // =============================================================================
@@ -248,6 +277,17 @@ impl PositionEventCallback for NoOpPositionCallback {
     fn on_position_updated(&self, _: &PositionEvent) {}
 }
 
+/// Forwards every event to each callback in order
+pub struct PositionCallbacks(pub Vec<Arc<dyn PositionEventCallback>>);
+
+impl PositionEventCallback for PositionCallbacks {
+    fn on_position_updated(&self, event: &PositionEvent) {
+        for callback in &self.0 {
+            callback.on_position_updated(event);
+        }
+    }
+}
+
 // =============================================================================
 // PositionTracker
 // =============================================================================
@@ -267,6 +307,8 @@ pub struct PositionTracker {
     seen_trades: HashSet<String>,
     /// Order of seen trades for LRU eviction
     seen_trades_order: VecDeque<String>,
+    /// Subscribers notified on every position change
+    subscribers: Vec<Sender<PositionChange>>,
 }
 
 /// Thread-safe shared position tracker
@@ -281,6 +323,7 @@ impl PositionTracker {
             callback: Arc::new(NoOpPositionCallback),
             seen_trades: HashSet::new(),
             seen_trades_order: VecDeque::new(),
+            subscribers: Vec::new(),
         }
     }
 
@@ -292,6 +335,7 @@ impl PositionTracker {
             callback,
             seen_trades: HashSet::new(),
             seen_trades_order: VecDeque::new(),
+            subscribers: Vec::new(),
         }
     }
 
@@ -362,6 +406,7 @@ impl PositionTracker {
         pos.last_fill_time = fill.timestamp.clone();
 
         let new_pos = pos.clone();
+        self.notify(&fill.asset_id, PositionChangeReason::Fill);
 
         // Create the update event
         let update_event = PositionEvent::Updated {
@@ -387,6 +432,34 @@ impl PositionTracker {
         self.callback.on_position_updated(event);
     }
 
+    // =========================================================================
+    // Subscriptions
+    // =========================================================================
+
+    /// Subscribe to position changes
+    ///
+    /// Every mutation (fill, merge, redeem, reconcile correction) sends a
+    /// `PositionChange` with the new net size. Sending never blocks, so it is
+    /// safe under the write lock. Dropped receivers are pruned on the next send.
+    pub fn subscribe(&mut self) -> Receiver<PositionChange> {
+        let (tx, rx) = unbounded();
+        self.subscribers.push(tx);
+        rx
+    }
+
+    /// Send the current net size of a token to all subscribers
+    fn notify(&mut self, token_id: &str, reason: PositionChangeReason) {
+        if self.subscribers.is_empty() {
+            return;
+        }
+        let change = PositionChange {
+            token_id: token_id.to_string(),
+            net_size: self.get_net_size(token_id),
+            reason,
+        };
+        self.subscribers.retain(|tx| tx.send(change.clone()).is_ok());
+    }
+
     /// Apply a buy fill
     fn apply_buy(pos: &mut Position, fill: &Fill) {
         if pos.size >= -POSITION_EPSILON {
@@ -557,6 +630,63 @@ impl PositionTracker {
         self.check_merge_opportunity(token_id)
     }
 
+    // =========================================================================
+    // Merge / Redeem
+    // =========================================================================
+
+    /// Apply a completed merge of `pairs` Up/Down pairs
+    ///
+    /// Reduces both legs by `pairs` at their current average price and books
+    /// the $1.00-per-pair proceeds against the merged cost as realized P&L
+    /// (split evenly across the two legs).
+    pub fn apply_merge(&mut self, token_a: &str, token_b: &str, pairs: f64) {
+        if pairs <= POSITION_EPSILON {
+            return;
+        }
+
+        let pnl_per_leg = self
+            .check_merge_opportunity(token_a)
+            .map(|opp| pairs * (1.0 - opp.avg_cost_per_pair) / 2.0)
+            .unwrap_or(0.0);
+
+        for token_id in [token_a, token_b] {
+            let pos = self
+                .positions
+                .entry(token_id.to_string())
+                .or_insert_with(|| Position::new(token_id.to_string()));
+
+            pos.size -= pairs;
+            pos.realized_pnl += pnl_per_leg;
+            if pos.is_flat() {
+                pos.size = 0.0;
+                pos.cost_basis = 0.0;
+                pos.avg_entry_price = 0.0;
+            } else {
+                pos.cost_basis = pos.size.abs() * pos.avg_entry_price;
+            }
+        }
+
+        self.notify(token_a, PositionChangeReason::Merge);
+        self.notify(token_b, PositionChangeReason::Merge);
+    }
+
+    /// Apply a redemption of a resolved token at `payout` per share
+    ///
+    /// The position is closed out; `payout` is 1.0 for the winning outcome
+    /// and 0.0 for the losing one.
+    pub fn apply_redeem(&mut self, token_id: &str, payout: f64) {
+        let Some(pos) = self.positions.get_mut(token_id) else {
+            return;
+        };
+
+        pos.realized_pnl += pos.size * (payout - pos.avg_entry_price);
+        pos.size = 0.0;
+        pos.cost_basis = 0.0;
+        pos.avg_entry_price = 0.0;
+
+        self.notify(token_id, PositionChangeReason::Redeem);
+    }
+
     // =========================================================================
     // Queries
     // =========================================================================
@@ -673,6 +803,7 @@ impl PositionTracker {
                 });
                 // Overwrite with REST data (authoritative)
                 self.hydrate_position(token_id, rest_size, rest_price);
+                self.notify(token_id, PositionChangeReason::Reconcile);
             }
         }
 
@@ -697,6 +828,7 @@ impl PositionTracker {
                 });
                 // Zero out position not in REST
                 self.hydrate_position(&token_id, 0.0, 0.0);
+                self.notify(&token_id, PositionChangeReason::Reconcile);
             }
         }
 
@@ -1146,6 +1278,66 @@ mod tests {
         assert!(opportunities.is_empty());
     }
 
+    // =========================================================================
+    // Subscription Tests
+    // =========================================================================
+
+    #[test]
+    fn test_subscribe_emits_on_fill() {
+        let mut tracker = PositionTracker::new();
+        let rx = tracker.subscribe();
+
+        tracker.apply_fill(&make_fill("token-1", Side::Buy, 0.50, 100.0));
+        tracker.apply_fill(&make_fill("token-1", Side::Sell, 0.60, 40.0));
+
+        let first = rx.try_recv().unwrap();
+        assert_eq!(first.token_id, "token-1");
+        assert_eq!(first.net_size, 100.0);
+        assert_eq!(first.reason, PositionChangeReason::Fill);
+
+        let second = rx.try_recv().unwrap();
+        assert_eq!(second.net_size, 60.0);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_subscribe_emits_on_merge_and_redeem() {
+        let mut tracker = PositionTracker::new();
+        tracker.register_token_pair("yes-token", "no-token", "condition-1");
+        tracker.apply_fill(&make_fill("yes-token", Side::Buy, 0.40, 100.0));
+        tracker.apply_fill(&make_fill("no-token", Side::Buy, 0.50, 60.0));
+
+        let rx = tracker.subscribe();
+
+        tracker.apply_merge("yes-token", "no-token", 60.0);
+        let changes: Vec<_> = rx.try_iter().collect();
+        assert_eq!(changes.len(), 2);
+        assert!(changes.iter().all(|c| c.reason == PositionChangeReason::Merge));
+        assert_eq!(tracker.get_net_size("yes-token"), 40.0);
+        assert_eq!(tracker.get_net_size("no-token"), 0.0);
+        // 60 pairs merged at $0.90 cost each: 60 * 0.10 = 6.0 profit
+        assert!((tracker.get_total_realized_pnl() - 6.0).abs() < 1e-9);
+
+        tracker.apply_redeem("yes-token", 1.0);
+        let change = rx.try_recv().unwrap();
+        assert_eq!(change.reason, PositionChangeReason::Redeem);
+        assert_eq!(change.net_size, 0.0);
+        // 40 shares redeemed at $1.00 against $0.40 entry: 40 * 0.60 = 24.0
+        assert!((tracker.get_total_realized_pnl() - 30.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_dropped_subscriber_is_pruned() {
+        let mut tracker = PositionTracker::new();
+        drop(tracker.subscribe());
+        let rx = tracker.subscribe();
+
+        tracker.apply_fill(&make_fill("token-1", Side::Buy, 0.50, 10.0));
+
+        assert_eq!(tracker.subscribers.len(), 1);
+        assert_eq!(rx.try_recv().unwrap().net_size, 10.0);
+    }
+
     // =========================================================================
     // Fee Tracking Tests
     // =========================================================================
@@ -36,6 +36,8 @@ pub struct UserConfig {
     pub api_passphrase: String,
     /// REST API base URL for re-hydration on reconnect
     pub rest_base_url: Option<String>,
+    /// How long fills are held to apply them in exchange order (zero = off)
+    pub fill_reorder_window: Duration,
 }
 
 impl UserConfig {
@@ -48,12 +50,18 @@ impl UserConfig {
         let api_passphrase = std::env::var("API_PASSPHRASE")
             .map_err(|_| anyhow::anyhow!("API_PASSPHRASE environment variable not set"))?;
         let rest_base_url = std::env::var("CLOB_URL").ok();
+        let fill_reorder_window = std::env::var("FILL_REORDER_WINDOW_MS")
+            .ok()
+            .and_then(|ms| ms.parse().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(Duration::ZERO);
 
         Ok(Self {
             api_key,
             api_secret,
             api_passphrase,
             rest_base_url,
+            fill_reorder_window,
         })
     }
 
@@ -152,8 +160,9 @@ impl UserHandler {
             self.fire_callback(&event.unwrap());
         } else if event.is_none() {
             // Log the raw message for debugging when trade was filtered (duplicate/zero-size)
+            // or held for reordering
             debug!(
-                "[UserWS] Trade filtered: {} {} {}... @ {} (raw_size: {}, you: {})",
+                "[UserWS] Trade filtered or buffered: {} {} {}... @ {} (raw_size: {}, you: {})",
                 trade.side, trade.outcome, &trade.asset_id[..8.min(trade.asset_id.len())],
                 trade.price, trade.size, trader_side
             );
@@ -179,13 +188,18 @@ impl UserHandler {
     }
 
     fn fire_callback(&self, event: &OrderEvent) {
-        match event {
-            OrderEvent::Placed(order) => self.callback.on_order_placed(order),
-            OrderEvent::Updated(order) => self.callback.on_order_updated(order),
-            OrderEvent::Filled(order) => self.callback.on_order_filled(order),
-            OrderEvent::Cancelled(order) => self.callback.on_order_cancelled(order),
-            OrderEvent::Trade(fill) => self.callback.on_trade(fill),
-        }
+        dispatch_event(&self.callback, event);
+    }
+}
+
+/// Fire the callback for an event (outside any lock on the order state)
+fn dispatch_event(callback: &Arc<dyn OrderEventCallback>, event: &OrderEvent) {
+    match event {
+        OrderEvent::Placed(order) => callback.on_order_placed(order),
+        OrderEvent::Updated(order) => callback.on_order_updated(order),
+        OrderEvent::Filled(order) => callback.on_order_filled(order),
+        OrderEvent::Cancelled(order) => callback.on_order_cancelled(order),
+        OrderEvent::Trade(fill) => callback.on_trade(fill),
     }
 }
 
@@ -265,6 +279,14 @@ fn handle_client_event(event: ClientEvent) -> bool {
             warn!("[UserWS] Error: {}", err);
             true
         }
+        ClientEvent::MessageTooLarge { size, max_size } => {
+            warn!("[UserWS] Message too large: {} bytes (max {})", size, max_size);
+            true
+        }
+        ClientEvent::CircuitOpen => {
+            warn!("[UserWS] Circuit breaker open, stopped reconnecting");
+            false
+        }
     }
 }
 
@@ -302,10 +324,13 @@ pub async fn spawn_user_order_tracker(
     );
 
     // Create shared order state store
-    let state: SharedOrderState = Arc::new(RwLock::new(match callback {
-        Some(cb) => OrderStateStore::with_callback(cb),
-        None => OrderStateStore::new(),
-    }));
+    let state: SharedOrderState = Arc::new(RwLock::new(
+        match callback {
+            Some(cb) => OrderStateStore::with_callback(cb),
+            None => OrderStateStore::new(),
+        }
+        .with_fill_reorder_window(config.fill_reorder_window),
+    ));
 
     // Hydrate from REST API
     info!("[UserWS] Hydrating orders from REST API...");
@@ -363,10 +388,13 @@ pub async fn spawn_user_order_tracker_ws_only(
         &config.api_key[..8.min(config.api_key.len())]
     );
 
-    let state: SharedOrderState = Arc::new(RwLock::new(match callback {
-        Some(cb) => OrderStateStore::with_callback(cb),
-        None => OrderStateStore::new(),
-    }));
+    let state: SharedOrderState = Arc::new(RwLock::new(
+        match callback {
+            Some(cb) => OrderStateStore::with_callback(cb),
+            None => OrderStateStore::new(),
+        }
+        .with_fill_reorder_window(config.fill_reorder_window),
+    ));
 
     let state_clone = Arc::clone(&state);
     let shutdown_clone = Arc::clone(&shutdown_flag);
@@ -394,6 +422,7 @@ async fn run_user_tracker(
 
     // Track connection state for re-hydration on reconnect
     let mut was_disconnected = false;
+    let callback = state.read().callback().clone();
 
     // Main tracking loop
     loop {
@@ -403,6 +432,14 @@ async fn run_user_tracker(
             break;
         }
 
+        // Apply fills held for reordering once their window has passed
+        if state.read().buffered_fill_count() > 0 {
+            let events = state.write().release_ready_fills();
+            for event in &events {
+                dispatch_event(&callback, event);
+            }
+        }
+
         // Handle WebSocket events
         match client.try_recv_event() {
             Some(event) => {
@@ -1,5 +1,6 @@
 pub mod strategies;
 
+use crate::infrastructure::client::clob::SignatureType;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use thiserror::Error;
@@ -81,6 +82,10 @@ pub struct RiskConfig {
     pub max_bet_per_market: f64,
     pub daily_loss_limit: f64,
     pub min_profit_cents: f64,
+    /// Hard cap on orders placed per UTC day (0 = unlimited), enforced by
+    /// `TradingClient::from_config`
+    #[serde(default)]
+    pub max_orders_per_day: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -88,7 +93,8 @@ pub struct PolymarketConfig {
     pub clob_url: String,
     pub ws_url: String,
     pub chain_id: u64,
-    pub signature_type: u8,
+    /// Validated on load: 0/1/2 or eoa/poly_proxy/poly_gnosis_safe
+    pub signature_type: SignatureType,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -349,12 +355,13 @@ mod tests {
                 max_bet_per_market: 100.0,
                 daily_loss_limit: 500.0,
                 min_profit_cents: 50.0,
+                max_orders_per_day: 0,
             },
             polymarket: PolymarketConfig {
                 clob_url: "https://clob.polymarket.com".to_string(),
                 ws_url: "wss://ws-subscriptions-clob.polymarket.com/ws/".to_string(),
                 chain_id: 137,
-                signature_type: 0,
+                signature_type: SignatureType::Eoa,
             },
             scanner: ScannerConfig {
                 poll_interval_secs: 30,
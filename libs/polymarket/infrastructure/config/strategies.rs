@@ -8,6 +8,9 @@ use std::path::Path;
 use tracing::info;
 
 use crate::application::strategies::inventory_mm::InventoryMMConfig;
+use crate::domain::price::PriceScale;
+use crate::domain::ParseStrictness;
+use crate::infrastructure::client::clob::{RateLimiter, SniperConnectionConfig};
 
 /// Main strategies configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,12 +46,17 @@ pub struct ComponentsConfig {
     /// Balance manager configuration
     #[serde(default)]
     pub balance_manager: BalanceManagerConfig,
+
+    /// CLOB API rate limits
+    #[serde(default)]
+    pub rate_limits: RateLimitsConfig,
 }
 
 impl Default for ComponentsConfig {
     fn default() -> Self {
         Self {
             balance_manager: BalanceManagerConfig::default(),
+            rate_limits: RateLimitsConfig::default(),
         }
     }
 }
@@ -73,6 +81,53 @@ impl Default for BalanceManagerConfig {
     }
 }
 
+/// CLOB API rate limits per endpoint class (0 per second = unlimited)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RateLimitsConfig {
+    /// Market data, order/trade queries and auth lookups per second
+    #[serde(default)]
+    pub read_per_second: f64,
+
+    /// Reads that may go out back to back
+    #[serde(default)]
+    pub read_burst: u32,
+
+    /// Order placements and cancellations per second
+    #[serde(default)]
+    pub order_per_second: f64,
+
+    /// Order requests that may go out back to back
+    #[serde(default)]
+    pub order_burst: u32,
+}
+
+impl RateLimitsConfig {
+    pub fn read_limiter(&self) -> RateLimiter {
+        Self::limiter(self.read_per_second, self.read_burst)
+    }
+
+    pub fn order_limiter(&self) -> RateLimiter {
+        Self::limiter(self.order_per_second, self.order_burst)
+    }
+
+    fn limiter(per_second: f64, burst: u32) -> RateLimiter {
+        if per_second > 0.0 {
+            RateLimiter::new(burst.max(1), per_second)
+        } else {
+            RateLimiter::unlimited()
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.read_per_second < 0.0 || self.order_per_second < 0.0 {
+            return Err(ConfigError::ValidationError(
+                "components.rate_limits per-second rates must be >= 0".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
 fn default_log_level() -> String {
     "info".to_string()
 }
@@ -112,6 +167,31 @@ pub struct UpOrDownConfig {
     /// distance of price_to_beat. Never bypassed, runs until market timer ends.
     #[serde(default = "default_guardian_safety_bps")]
     pub guardian_safety_bps: f64,
+
+    /// Block new orders on an asset while its ChainLink and Binance prices
+    /// are further apart than this many basis points (0 = disabled)
+    #[serde(default = "default_max_oracle_divergence_bps")]
+    pub max_oracle_divergence_bps: f64,
+
+    /// Cheaper-side mode: instead of sniping the side with no asks, buy
+    /// whichever outcome has the lowest best ask, at that ask, provided it is
+    /// below this probability (None = disabled)
+    #[serde(default)]
+    pub cheaper_side_max_price: Option<f64>,
+
+    /// Malformed outcomes/token_ids: `lenient` skips the market with a
+    /// warning, `strict` stops the strategy with an error naming it
+    #[serde(default)]
+    pub outcomes_parse: ParseStrictness,
+
+    /// Denomination of the market feed's prices: unit (0-1), cents (0-100)
+    /// or auto. Prices are canonicalized to [0, 1] on ingestion.
+    #[serde(default)]
+    pub price_scale: PriceScale,
+
+    /// Market WebSocket health settings, read from `SNIPER_WS_*` env vars on load
+    #[serde(skip, default = "SniperConnectionConfig::from_env")]
+    pub connection: SniperConnectionConfig,
 }
 
 fn default_order_pct() -> f64 {
@@ -122,6 +202,10 @@ fn default_guardian_safety_bps() -> f64 {
     2.0 // 2 basis points (0.02%)
 }
 
+fn default_max_oracle_divergence_bps() -> f64 {
+    30.0 // 30 basis points (0.3%)
+}
+
 fn default_delta_t() -> f64 {
     300.0 // 5 minutes
 }
@@ -177,6 +261,10 @@ pub struct SportsSnipingConfig {
     /// Minimum best_bid price required to execute an order
     #[serde(default = "default_sports_bid_threshold")]
     pub bid_threshold: f64,
+
+    /// Market WebSocket health settings, read from `SNIPER_WS_*` env vars on load
+    #[serde(skip, default = "SniperConnectionConfig::from_env")]
+    pub connection: SniperConnectionConfig,
 }
 
 impl Default for SportsSnipingConfig {
@@ -186,6 +274,7 @@ impl Default for SportsSnipingConfig {
             enabled: true,
             order_pct_of_collateral: default_sports_order_pct(),
             bid_threshold: default_sports_bid_threshold(),
+            connection: SniperConnectionConfig::from_env(),
         }
     }
 }
@@ -520,6 +609,11 @@ impl Default for UpOrDownConfig {
             threshold_tau: default_threshold_tau(),
             order_pct_of_collateral: default_order_pct(),
             guardian_safety_bps: default_guardian_safety_bps(),
+            max_oracle_divergence_bps: default_max_oracle_divergence_bps(),
+            cheaper_side_max_price: None,
+            outcomes_parse: ParseStrictness::default(),
+            price_scale: PriceScale::default(),
+            connection: SniperConnectionConfig::from_env(),
         }
     }
 }
@@ -557,6 +651,8 @@ impl StrategiesConfig {
             )));
         }
 
+        self.components.rate_limits.validate()?;
+
         // Validate up_or_down config
         self.up_or_down.validate()?;
 
@@ -566,6 +662,11 @@ impl StrategiesConfig {
         // Validate market_merger config
         self.market_merger.validate()?;
 
+        // Validate inventory_mm config
+        self.inventory_mm
+            .validate()
+            .map_err(|e| ConfigError::ValidationError(format!("inventory_mm.{}", e)))?;
+
         Ok(())
     }
 
@@ -578,6 +679,17 @@ impl StrategiesConfig {
             "  Balance manager threshold: {:.0}%",
             self.components.balance_manager.threshold * 100.0
         );
+        let limits = &self.components.rate_limits;
+        for (class, per_second, burst) in [
+            ("read", limits.read_per_second, limits.read_burst),
+            ("order", limits.order_per_second, limits.order_burst),
+        ] {
+            if per_second > 0.0 {
+                info!("  CLOB {} rate limit: {}/s (burst {})", class, per_second, burst.max(1));
+            } else {
+                info!("  CLOB {} rate limit: unlimited", class);
+            }
+        }
         info!("Up or Down Strategy:");
         info!("  Delta T: {} seconds", self.up_or_down.delta_t_seconds);
         info!(
@@ -588,6 +700,10 @@ impl StrategiesConfig {
             "  Oracle BPS threshold: {} bps",
             self.up_or_down.oracle_bps_price_threshold
         );
+        info!(
+            "  Max oracle divergence: {} bps",
+            self.up_or_down.max_oracle_divergence_bps
+        );
         info!(
             "  Threshold min: {} seconds",
             self.up_or_down.threshold_min
@@ -638,6 +754,7 @@ impl StrategiesConfig {
         info!("    Base spread: {:.3}", self.inventory_mm.solver.base_spread);
         info!("    Max imbalance: {:.1}%", self.inventory_mm.solver.max_imbalance * 100.0);
         info!("    Max delta: {:.0} tokens (0=unlimited)", self.inventory_mm.solver.max_delta);
+        info!("    Max committed: ${:.0} (0=unlimited)", self.inventory_mm.solver.max_committed);
         info!("    Max position: {:.1} (0=unlimited)", self.inventory_mm.solver.max_position);
         info!("    Oracle sensitivity: {:.1}", self.inventory_mm.solver.oracle_sensitivity);
         info!("    Gamma inv: {:.1}", self.inventory_mm.solver.gamma_inv);
@@ -666,6 +783,20 @@ impl UpOrDownConfig {
             ));
         }
 
+        if self.max_oracle_divergence_bps < 0.0 {
+            return Err(ConfigError::ValidationError(
+                "up_or_down.max_oracle_divergence_bps must be >= 0".to_string(),
+            ));
+        }
+
+        if let Some(max_price) = self.cheaper_side_max_price {
+            if !(max_price > 0.0 && max_price < 1.0) {
+                return Err(ConfigError::ValidationError(
+                    "up_or_down.cheaper_side_max_price must be between 0 and 1".to_string(),
+                ));
+            }
+        }
+
         if self.threshold_min <= 0.0 {
             return Err(ConfigError::ValidationError(
                 "up_or_down.threshold_min must be greater than 0".to_string(),
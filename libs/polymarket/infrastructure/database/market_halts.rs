@@ -0,0 +1,41 @@
+//! Per-market kill switches shared between processes
+//!
+//! `market_halts` holds the condition ids an operator halted (e.g. from the
+//! visualizer). Trading processes mirror it into their in-memory
+//! `MarketHalts` with `MarketHalts::spawn_db_sync`.
+
+use super::{MarketDatabase, Result};
+use chrono::Utc;
+
+impl MarketDatabase {
+    /// Halt or resume placements on a market for every process sharing the database
+    pub async fn set_market_halted(&self, condition_id: &str, halted: bool) -> Result<()> {
+        if halted {
+            sqlx::query(
+                "INSERT INTO market_halts (condition_id, halted_at) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+            )
+            .bind(condition_id)
+            .bind(Utc::now().to_rfc3339())
+            .execute(&self.pool)
+            .await?;
+        } else {
+            sqlx::query("DELETE FROM market_halts WHERE condition_id = $1")
+                .bind(condition_id)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Condition ids of every market halted in the database
+    pub async fn get_halted_markets(&self) -> Result<Vec<String>> {
+        let rows = self
+            .read_with_retry("get_halted_markets", || {
+                sqlx::query_as::<_, (String,)>("SELECT condition_id FROM market_halts ORDER BY condition_id")
+                    .fetch_all(&self.pool)
+            })
+            .await?;
+
+        Ok(rows.into_iter().map(|(condition_id,)| condition_id).collect())
+    }
+}
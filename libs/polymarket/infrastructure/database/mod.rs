@@ -1,19 +1,38 @@
+pub mod market_halts;
 pub mod models;
+pub mod orders_log;
+pub mod retry;
 pub mod schema;
+pub mod store;
+pub mod sync_state;
 
+use crate::infrastructure::backoff::BackoffPolicy;
 use chrono::{DateTime, Duration, Utc};
-use sqlx::{postgres::PgPoolOptions, PgPool, Postgres, QueryBuilder};
+use futures::stream::{BoxStream, StreamExt};
+use sqlx::postgres::{PgArguments, PgPoolOptions};
+use sqlx::query::QueryScalar;
+use sqlx::{PgPool, Postgres, QueryBuilder};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use thiserror::Error;
-use tracing::{debug, info};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
 
 // Re-export main types
-pub use models::{DbEvent, DbMarket, MarketFilters, SyncStats};
+pub use models::{DbEvent, DbMarket, MarketFilters, SyncStats, TagQuery};
+pub use orders_log::{LoggedOrder, OrderLog, OrderLogEntry};
+pub use retry::DEFAULT_READ_RETRY;
 pub use schema::{get_schema_version, initialize_schema};
+pub use store::MarketStore;
+pub use sync_state::MARKET_SYNC;
 
 #[derive(Error, Debug)]
 pub enum DatabaseError {
     #[error("Database connection error: {0}")]
-    ConnectionError(#[from] sqlx::Error),
+    ConnectionError(#[source] sqlx::Error),
+
+    #[error("Timed out waiting for a pooled database connection")]
+    PoolTimeout,
 
     #[error("Schema error: {0}")]
     SchemaError(#[from] schema::SchemaError),
@@ -26,26 +45,115 @@ pub enum DatabaseError {
 
     #[error("Event not found: {0}")]
     EventNotFound(String),
+
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
 }
 
 pub type Result<T> = std::result::Result<T, DatabaseError>;
 
+impl From<sqlx::Error> for DatabaseError {
+    fn from(err: sqlx::Error) -> Self {
+        match err {
+            sqlx::Error::PoolTimedOut => DatabaseError::PoolTimeout,
+            other => DatabaseError::ConnectionError(other),
+        }
+    }
+}
+
+/// Connection pool settings for `MarketDatabase::with_options`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PoolOptions {
+    pub max_connections: u32,
+    /// Connections kept open even when idle
+    pub min_connections: u32,
+    /// How long to wait for a free connection before `PoolTimeout`
+    pub acquire_timeout: std::time::Duration,
+    /// Idle connections above `min_connections` are closed after this
+    pub idle_timeout: std::time::Duration,
+    /// Retries of read queries on transient connection errors
+    pub read_retry: BackoffPolicy,
+}
+
+impl Default for PoolOptions {
+    fn default() -> Self {
+        Self {
+            max_connections: 50,
+            min_connections: 5,
+            acquire_timeout: std::time::Duration::from_secs(10),
+            idle_timeout: std::time::Duration::from_secs(300),
+            read_retry: DEFAULT_READ_RETRY,
+        }
+    }
+}
+
+/// Whether an upsert created a new row, changed an existing one, or found
+/// it identical and skipped the write
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpsertOutcome {
+    Inserted,
+    Updated,
+    Unchanged,
+}
+
+impl UpsertOutcome {
+    /// From `RETURNING (xmax = 0)`: a freshly inserted row has no deleting
+    /// transaction, and a skipped no-op update returns no row at all
+    fn from_returned(inserted: Option<bool>) -> Self {
+        match inserted {
+            Some(true) => Self::Inserted,
+            Some(false) => Self::Updated,
+            None => Self::Unchanged,
+        }
+    }
+}
+
+/// Per-outcome row counts of a batch upsert
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UpsertCounts {
+    pub inserted: usize,
+    pub updated: usize,
+    /// Rows identical to what was stored, not rewritten
+    pub unchanged: usize,
+}
+
+impl UpsertCounts {
+    pub fn record(&mut self, outcome: UpsertOutcome) {
+        match outcome {
+            UpsertOutcome::Inserted => self.inserted += 1,
+            UpsertOutcome::Updated => self.updated += 1,
+            UpsertOutcome::Unchanged => self.unchanged += 1,
+        }
+    }
+
+    /// Rows actually written
+    pub fn written(&self) -> usize {
+        self.inserted + self.updated
+    }
+}
+
 /// Market database manager
 pub struct MarketDatabase {
     pool: PgPool,
+    read_retry: BackoffPolicy,
 }
 
 impl MarketDatabase {
     /// Create new database connection and initialize schema
     pub async fn new(db_url: &str) -> Result<Self> {
+        Self::with_options(db_url, PoolOptions::default()).await
+    }
+
+    /// Create new database connection with custom pool settings and initialize schema
+    pub async fn with_options(db_url: &str, opts: PoolOptions) -> Result<Self> {
         info!("Connecting to database: {}", db_url);
 
         // Connect to database with robust pool settings
         let pool = PgPoolOptions::new()
-            .max_connections(50)              // Increased from 20
-            .min_connections(5)               // Keep some connections warm
-            .acquire_timeout(std::time::Duration::from_secs(10))  // Don't wait forever for a connection
-            .idle_timeout(std::time::Duration::from_secs(300))    // Close idle connections after 5 min
+            .max_connections(opts.max_connections)
+            .min_connections(opts.min_connections)       // Keep some connections warm
+            .acquire_timeout(opts.acquire_timeout)       // Don't wait forever for a connection
+            .idle_timeout(opts.idle_timeout)             // Close idle connections
             .max_lifetime(std::time::Duration::from_secs(1800))   // Recycle connections after 30 min
             .after_connect(|conn, _meta| Box::pin(async move {
                 // Set statement timeout to 30 seconds to prevent slow queries from holding connections
@@ -60,74 +168,29 @@ impl MarketDatabase {
         // Initialize schema
         schema::initialize_schema(&pool).await?;
 
-        info!("Database initialized successfully (pool: max=50, min=5, acquire_timeout=10s, statement_timeout=30s)");
+        info!(
+            "Database initialized successfully (pool: max={}, min={}, acquire_timeout={:?}, statement_timeout=30s)",
+            opts.max_connections, opts.min_connections, opts.acquire_timeout
+        );
 
-        Ok(Self { pool })
+        Ok(Self {
+            pool,
+            read_retry: opts.read_retry,
+        })
     }
 
     // ==================== MARKET OPERATIONS ====================
 
     /// Insert a single market (or replace if exists)
-    pub async fn upsert_market(&self, market: DbMarket) -> Result<()> {
+    pub async fn upsert_market(&self, market: DbMarket) -> Result<UpsertOutcome> {
         debug!(
             market_id = %market.id,
             question = %market.question,
             "Upserting market"
         );
-        sqlx::query(
-            r#"
-            INSERT INTO markets (
-                id, condition_id, question, description, slug, start_date, end_date, resolution_time,
-                active, closed, archived, market_type, category, liquidity, volume,
-                outcomes, token_ids, tags, last_updated, created_at, game_id
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21)
-            ON CONFLICT (id) DO UPDATE SET
-                condition_id = EXCLUDED.condition_id,
-                question = EXCLUDED.question,
-                description = EXCLUDED.description,
-                slug = EXCLUDED.slug,
-                start_date = EXCLUDED.start_date,
-                end_date = EXCLUDED.end_date,
-                resolution_time = EXCLUDED.resolution_time,
-                active = EXCLUDED.active,
-                closed = EXCLUDED.closed,
-                archived = EXCLUDED.archived,
-                market_type = EXCLUDED.market_type,
-                category = EXCLUDED.category,
-                liquidity = EXCLUDED.liquidity,
-                volume = EXCLUDED.volume,
-                outcomes = EXCLUDED.outcomes,
-                token_ids = EXCLUDED.token_ids,
-                tags = EXCLUDED.tags,
-                last_updated = EXCLUDED.last_updated,
-                game_id = EXCLUDED.game_id
-            "#,
-        )
-        .bind(&market.id)
-        .bind(&market.condition_id)
-        .bind(&market.question)
-        .bind(&market.description)
-        .bind(&market.slug)
-        .bind(&market.start_date)
-        .bind(&market.end_date)
-        .bind(&market.resolution_time)
-        .bind(market.active)
-        .bind(market.closed)
-        .bind(market.archived)
-        .bind(&market.market_type)
-        .bind(&market.category)
-        .bind(&market.liquidity)
-        .bind(&market.volume)
-        .bind(&market.outcomes)
-        .bind(&market.token_ids)
-        .bind(&market.tags)
-        .bind(&market.last_updated)
-        .bind(&market.created_at)
-        .bind(market.game_id)
-        .execute(&self.pool)
-        .await?;
+        let inserted = upsert_market_query(&market).fetch_optional(&self.pool).await?;
 
-        Ok(())
+        Ok(UpsertOutcome::from_returned(inserted))
     }
 
     /// Batch insert markets (legacy - uses individual upserts)
@@ -143,16 +206,38 @@ impl MarketDatabase {
         Ok(count)
     }
 
+    /// Insert markets inside a single transaction
+    ///
+    /// All-or-nothing: every row is upserted against one transaction that is
+    /// committed once at the end. If any row fails (say row 5000 of 10k), the
+    /// error is returned and the transaction is rolled back, so none of the
+    /// batch is committed.
+    pub async fn insert_markets_tx(&self, markets: Vec<DbMarket>) -> Result<usize> {
+        let mut tx = self.pool.begin().await?;
+        let mut count = 0;
+
+        for market in &markets {
+            // Dropping `tx` on the early return rolls it back
+            upsert_market_query(market).fetch_optional(&mut *tx).await?;
+            count += 1;
+        }
+
+        tx.commit().await?;
+
+        debug!("Inserted {} markets in one transaction", count);
+        Ok(count)
+    }
+
     /// Batch upsert multiple markets efficiently using multi-value INSERT
-    /// Returns the number of markets upserted
-    pub async fn batch_upsert_markets(&self, markets: &[DbMarket]) -> Result<usize> {
+    /// Rows identical to the stored ones are skipped and counted as unchanged
+    pub async fn batch_upsert_markets(&self, markets: &[DbMarket]) -> Result<UpsertCounts> {
+        let mut counts = UpsertCounts::default();
         if markets.is_empty() {
-            return Ok(0);
+            return Ok(counts);
         }
 
         // PostgreSQL has a limit on parameters, so we batch in chunks
         const BATCH_SIZE: usize = 100;
-        let mut total_upserted = 0;
 
         for chunk in markets.chunks(BATCH_SIZE) {
             let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(
@@ -205,49 +290,113 @@ impl MarketDatabase {
                     volume = EXCLUDED.volume,
                     outcomes = EXCLUDED.outcomes,
                     token_ids = EXCLUDED.token_ids,
-                    tags = EXCLUDED.tags,
+                    -- A tag-less sync keeps the stored (event-enriched) tags
+                    tags = COALESCE(EXCLUDED.tags, markets.tags),
                     last_updated = EXCLUDED.last_updated,
-                    game_id = EXCLUDED.game_id"#,
+                    game_id = EXCLUDED.game_id
+                WHERE (markets.condition_id, markets.question, markets.description, markets.slug,
+                       markets.start_date, markets.end_date, markets.resolution_time, markets.active,
+                       markets.closed, markets.archived, markets.market_type, markets.category,
+                       markets.liquidity, markets.volume, markets.outcomes, markets.token_ids,
+                       markets.tags, markets.game_id)
+                IS DISTINCT FROM
+                      (EXCLUDED.condition_id, EXCLUDED.question, EXCLUDED.description, EXCLUDED.slug,
+                       EXCLUDED.start_date, EXCLUDED.end_date, EXCLUDED.resolution_time, EXCLUDED.active,
+                       EXCLUDED.closed, EXCLUDED.archived, EXCLUDED.market_type, EXCLUDED.category,
+                       EXCLUDED.liquidity, EXCLUDED.volume, EXCLUDED.outcomes, EXCLUDED.token_ids,
+                       COALESCE(EXCLUDED.tags, markets.tags), EXCLUDED.game_id)
+                RETURNING (xmax = 0) AS inserted"#,
             );
 
-            let query = query_builder.build();
-            query.execute(&self.pool).await?;
-            total_upserted += chunk.len();
+            let returned: Vec<bool> = query_builder
+                .build_query_scalar()
+                .fetch_all(&self.pool)
+                .await?;
+            for inserted in &returned {
+                counts.record(UpsertOutcome::from_returned(Some(*inserted)));
+            }
+            // No-op updates return no row
+            for _ in returned.len()..chunk.len() {
+                counts.record(UpsertOutcome::Unchanged);
+            }
         }
 
-        Ok(total_upserted)
+        Ok(counts)
     }
 
     /// Get all active markets
     pub async fn get_active_markets(&self) -> Result<Vec<DbMarket>> {
-        let markets = sqlx::query_as::<_, DbMarket>(
-            "SELECT * FROM markets WHERE active = true AND closed = false ORDER BY resolution_time ASC",
-        )
-        .fetch_all(&self.pool)
-        .await?;
+        let markets = self
+            .read_with_retry("get_active_markets", || {
+                sqlx::query_as::<_, DbMarket>(
+                    "SELECT * FROM markets WHERE active = true AND closed = false ORDER BY resolution_time ASC",
+                )
+                .fetch_all(&self.pool)
+            })
+            .await?;
+
+        Ok(markets)
+    }
+
+    /// Get one page of active markets.
+    ///
+    /// Same set as `get_active_markets`, ordered by resolution time with the
+    /// market id as tiebreaker so pages never overlap or skip rows. An offset
+    /// past the end yields an empty page.
+    pub async fn get_active_markets_paged(&self, limit: i64, offset: i64) -> Result<Vec<DbMarket>> {
+        validate_page(limit, offset)?;
+
+        let markets = self
+            .read_with_retry("get_active_markets_paged", || {
+                sqlx::query_as::<_, DbMarket>(
+                    r#"
+                    SELECT * FROM markets
+                    WHERE active = true AND closed = false
+                    ORDER BY resolution_time ASC, id ASC
+                    LIMIT $1 OFFSET $2
+                    "#,
+                )
+                .bind(limit)
+                .bind(offset)
+                .fetch_all(&self.pool)
+            })
+            .await?;
 
         Ok(markets)
     }
 
+    /// Stream active markets row by row instead of loading them all at once
+    pub fn get_active_markets_stream(&self) -> BoxStream<'_, Result<DbMarket>> {
+        sqlx::query_as::<_, DbMarket>(
+            "SELECT * FROM markets WHERE active = true AND closed = false ORDER BY resolution_time ASC, id ASC",
+        )
+        .fetch(&self.pool)
+        .map(|row| row.map_err(DatabaseError::from))
+        .boxed()
+    }
+
     /// Get markets resolving within the next X hours
     pub async fn get_upcoming_markets(&self, within_hours: u64) -> Result<Vec<DbMarket>> {
         let now = Utc::now();
         let cutoff = now + Duration::hours(within_hours as i64);
 
-        let markets = sqlx::query_as::<_, DbMarket>(
-            r#"
-            SELECT * FROM markets
-            WHERE active = true
-            AND closed = false
-            AND resolution_time > $1
-            AND resolution_time <= $2
-            ORDER BY resolution_time ASC
-            "#,
-        )
-        .bind(now.to_rfc3339())
-        .bind(cutoff.to_rfc3339())
-        .fetch_all(&self.pool)
-        .await?;
+        let markets = self
+            .read_with_retry("get_upcoming_markets", || {
+                sqlx::query_as::<_, DbMarket>(
+                    r#"
+                    SELECT * FROM markets
+                    WHERE active = true
+                    AND closed = false
+                    AND resolution_time > $1
+                    AND resolution_time <= $2
+                    ORDER BY resolution_time ASC
+                    "#,
+                )
+                .bind(now.to_rfc3339())
+                .bind(cutoff.to_rfc3339())
+                .fetch_all(&self.pool)
+            })
+            .await?;
 
         Ok(markets)
     }
@@ -257,41 +406,83 @@ impl MarketDatabase {
         let now = Utc::now();
         let cutoff = now + Duration::milliseconds((within_seconds * 1000.0) as i64);
 
-        let markets = sqlx::query_as::<_, DbMarket>(
-            r#"
-            SELECT * FROM markets
-            WHERE active = true
-            AND closed = false
-            AND resolution_time > $1
-            AND resolution_time <= $2
-            ORDER BY resolution_time ASC
-            "#,
-        )
-        .bind(now.to_rfc3339())
-        .bind(cutoff.to_rfc3339())
-        .fetch_all(&self.pool)
-        .await?;
+        let markets = self
+            .read_with_retry("get_markets_expiring_soon", || {
+                sqlx::query_as::<_, DbMarket>(
+                    r#"
+                    SELECT * FROM markets
+                    WHERE active = true
+                    AND closed = false
+                    AND resolution_time > $1
+                    AND resolution_time <= $2
+                    ORDER BY resolution_time ASC
+                    "#,
+                )
+                .bind(now.to_rfc3339())
+                .bind(cutoff.to_rfc3339())
+                .fetch_all(&self.pool)
+            })
+            .await?;
 
         Ok(markets)
     }
 
     /// Get markets updated since timestamp
     pub async fn get_updated_since(&self, since: DateTime<Utc>) -> Result<Vec<DbMarket>> {
-        let markets = sqlx::query_as::<_, DbMarket>(
-            "SELECT * FROM markets WHERE last_updated > $1 ORDER BY last_updated DESC",
-        )
-        .bind(since.to_rfc3339())
-        .fetch_all(&self.pool)
-        .await?;
+        let markets = self
+            .read_with_retry("get_updated_since", || {
+                sqlx::query_as::<_, DbMarket>(
+                    "SELECT * FROM markets WHERE last_updated > $1 ORDER BY last_updated DESC",
+                )
+                .bind(since.to_rfc3339())
+                .fetch_all(&self.pool)
+            })
+            .await?;
+
+        Ok(markets)
+    }
+
+    /// Search markets by keywords in their question, best matches first
+    ///
+    /// The query is split into words and every word must match (stemmed, so
+    /// "elections" finds "election"). Punctuation and quotes are dropped
+    /// rather than interpreted, so user input can't produce a syntax error.
+    /// An empty query returns no markets.
+    pub async fn search_markets(&self, query: &str, limit: i64) -> Result<Vec<DbMarket>> {
+        validate_page(limit, 0)?;
+
+        let terms = search_terms(query);
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let markets = self
+            .read_with_retry("search_markets", || {
+                sqlx::query_as::<_, DbMarket>(
+                    r#"
+                    SELECT * FROM markets
+                    WHERE question_tsv @@ plainto_tsquery('english', $1)
+                    ORDER BY ts_rank(question_tsv, plainto_tsquery('english', $1)) DESC, id ASC
+                    LIMIT $2
+                    "#,
+                )
+                .bind(&terms)
+                .bind(limit)
+                .fetch_all(&self.pool)
+            })
+            .await?;
 
         Ok(markets)
     }
 
     /// Get market by ID
     pub async fn get_market(&self, id: &str) -> Result<DbMarket> {
-        let market = sqlx::query_as::<_, DbMarket>("SELECT * FROM markets WHERE id = $1")
-            .bind(id)
-            .fetch_optional(&self.pool)
+        let market = self
+            .read_with_retry("get_market", || {
+                sqlx::query_as::<_, DbMarket>("SELECT * FROM markets WHERE id = $1")
+                    .bind(id)
+                    .fetch_optional(&self.pool)
+            })
             .await?
             .ok_or_else(|| DatabaseError::MarketNotFound(id.to_string()))?;
 
@@ -300,9 +491,12 @@ impl MarketDatabase {
 
     /// Get market by condition ID
     pub async fn get_market_by_condition(&self, condition_id: &str) -> Result<DbMarket> {
-        let market = sqlx::query_as::<_, DbMarket>("SELECT * FROM markets WHERE condition_id = $1")
-            .bind(condition_id)
-            .fetch_optional(&self.pool)
+        let market = self
+            .read_with_retry("get_market_by_condition", || {
+                sqlx::query_as::<_, DbMarket>("SELECT * FROM markets WHERE condition_id = $1")
+                    .bind(condition_id)
+                    .fetch_optional(&self.pool)
+            })
             .await?
             .ok_or_else(|| DatabaseError::MarketNotFound(condition_id.to_string()))?;
 
@@ -318,21 +512,28 @@ impl MarketDatabase {
             where_clause
         );
 
-        let mut query_builder = sqlx::query_as::<_, DbMarket>(&query);
+        let markets = self
+            .read_with_retry("query_markets", || {
+                let mut query_builder = sqlx::query_as::<_, DbMarket>(&query);
 
-        for param in params {
-            query_builder = query_builder.bind(param);
-        }
+                for param in &params {
+                    query_builder = query_builder.bind(param.clone());
+                }
 
-        let markets = query_builder.fetch_all(&self.pool).await?;
+                query_builder.fetch_all(&self.pool)
+            })
+            .await?;
 
         Ok(markets)
     }
 
     /// Get total number of markets
     pub async fn market_count(&self) -> Result<i64> {
-        let (count,) = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM markets")
-            .fetch_one(&self.pool)
+        let (count,) = self
+            .read_with_retry("market_count", || {
+                sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM markets")
+                    .fetch_one(&self.pool)
+            })
             .await?;
 
         Ok(count)
@@ -340,15 +541,36 @@ impl MarketDatabase {
 
     /// Get number of active markets
     pub async fn active_market_count(&self) -> Result<i64> {
-        let (count,) = sqlx::query_as::<_, (i64,)>(
-            "SELECT COUNT(*) FROM markets WHERE active = true AND closed = false",
-        )
-        .fetch_one(&self.pool)
-        .await?;
+        let (count,) = self
+            .read_with_retry("active_market_count", || {
+                sqlx::query_as::<_, (i64,)>(
+                    "SELECT COUNT(*) FROM markets WHERE active = true AND closed = false",
+                )
+                .fetch_one(&self.pool)
+            })
+            .await?;
 
         Ok(count)
     }
 
+    /// Most recent `last_updated` across all markets, `None` if the table is empty
+    pub async fn latest_market_update(&self) -> Result<Option<DateTime<Utc>>> {
+        let (latest,) = self
+            .read_with_retry("latest_market_update", || {
+                sqlx::query_as::<_, (Option<String>,)>("SELECT MAX(last_updated) FROM markets")
+                    .fetch_one(&self.pool)
+            })
+            .await?;
+
+        latest
+            .map(|ts| {
+                DateTime::parse_from_rfc3339(&ts)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .map_err(|e| DatabaseError::InvalidArgument(format!("bad last_updated '{}': {}", ts, e)))
+            })
+            .transpose()
+    }
+
     /// Delete resolved markets older than cutoff date
     pub async fn cleanup_resolved(&self, before: DateTime<Utc>) -> Result<u64> {
         let result =
@@ -360,6 +582,43 @@ impl MarketDatabase {
         Ok(result.rows_affected())
     }
 
+    /// Periodically delete resolved markets older than `retention`
+    ///
+    /// Runs a cleanup every `interval` and emits the `cleanup_resolved_count`
+    /// field on each cycle. Exits once `running` flips to false, checked at
+    /// least every `SHUTDOWN_POLL` so shutdown doesn't wait a full interval.
+    pub fn spawn_cleanup_task(
+        self: Arc<Self>,
+        interval: std::time::Duration,
+        retention: std::time::Duration,
+        running: Arc<AtomicBool>,
+    ) -> JoinHandle<()> {
+        const SHUTDOWN_POLL: std::time::Duration = std::time::Duration::from_millis(100);
+
+        tokio::spawn(async move {
+            info!("Starting resolved-market cleanup (interval: {:?}, retention: {:?})", interval, retention);
+
+            while running.load(Ordering::Acquire) {
+                let cutoff = Duration::from_std(retention)
+                    .ok()
+                    .and_then(|retention| Utc::now().checked_sub_signed(retention))
+                    .unwrap_or(DateTime::<Utc>::MIN_UTC);
+                match self.cleanup_resolved(cutoff).await {
+                    Ok(removed) => info!(cleanup_resolved_count = removed, "Removed {} resolved markets", removed),
+                    Err(e) => warn!("Resolved-market cleanup failed: {}", e),
+                }
+
+                let next_run = tokio::time::Instant::now() + interval;
+                while running.load(Ordering::Acquire) && tokio::time::Instant::now() < next_run {
+                    let remaining = next_run - tokio::time::Instant::now();
+                    tokio::time::sleep(remaining.min(SHUTDOWN_POLL)).await;
+                }
+            }
+
+            info!("Resolved-market cleanup stopped");
+        })
+    }
+
     // ==================== EVENT OPERATIONS ====================
 
     /// Insert or update an event
@@ -401,7 +660,7 @@ impl MarketDatabase {
                 icon = EXCLUDED.icon,
                 category = EXCLUDED.category,
                 competitive = EXCLUDED.competitive,
-                tags = EXCLUDED.tags,
+                tags = COALESCE(EXCLUDED.tags, events.tags),
                 comment_count = EXCLUDED.comment_count,
                 updated_at = EXCLUDED.updated_at,
                 last_synced = EXCLUDED.last_synced,
@@ -522,7 +781,7 @@ impl MarketDatabase {
                     icon = EXCLUDED.icon,
                     category = EXCLUDED.category,
                     competitive = EXCLUDED.competitive,
-                    tags = EXCLUDED.tags,
+                    tags = COALESCE(EXCLUDED.tags, events.tags),
                     comment_count = EXCLUDED.comment_count,
                     updated_at = EXCLUDED.updated_at,
                     last_synced = EXCLUDED.last_synced,
@@ -607,9 +866,12 @@ impl MarketDatabase {
 
     /// Check if event exists in database
     pub async fn event_exists(&self, event_id: &str) -> Result<bool> {
-        let result = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM events WHERE id = $1")
-            .bind(event_id)
-            .fetch_one(&self.pool)
+        let result = self
+            .read_with_retry("event_exists", || {
+                sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM events WHERE id = $1")
+                    .bind(event_id)
+                    .fetch_one(&self.pool)
+            })
             .await?;
 
         Ok(result.0 > 0)
@@ -617,9 +879,12 @@ impl MarketDatabase {
 
     /// Get event by ID
     pub async fn get_event(&self, event_id: &str) -> Result<DbEvent> {
-        let event = sqlx::query_as::<_, DbEvent>("SELECT * FROM events WHERE id = $1")
-            .bind(event_id)
-            .fetch_optional(&self.pool)
+        let event = self
+            .read_with_retry("get_event", || {
+                sqlx::query_as::<_, DbEvent>("SELECT * FROM events WHERE id = $1")
+                    .bind(event_id)
+                    .fetch_optional(&self.pool)
+            })
             .await?
             .ok_or_else(|| DatabaseError::EventNotFound(event_id.to_string()))?;
 
@@ -628,23 +893,29 @@ impl MarketDatabase {
 
     /// Get all active (non-closed) events
     pub async fn get_active_events(&self) -> Result<Vec<DbEvent>> {
-        let events = sqlx::query_as::<_, DbEvent>(
-            "SELECT * FROM events WHERE closed = false ORDER BY end_date ASC",
-        )
-        .fetch_all(&self.pool)
-        .await?;
+        let events = self
+            .read_with_retry("get_active_events", || {
+                sqlx::query_as::<_, DbEvent>(
+                    "SELECT * FROM events WHERE closed = false ORDER BY end_date ASC",
+                )
+                .fetch_all(&self.pool)
+            })
+            .await?;
 
         Ok(events)
     }
 
     /// Get events by category
     pub async fn get_events_by_category(&self, category: &str) -> Result<Vec<DbEvent>> {
-        let events = sqlx::query_as::<_, DbEvent>(
-            "SELECT * FROM events WHERE category = $1 AND closed = false ORDER BY end_date ASC",
-        )
-        .bind(category)
-        .fetch_all(&self.pool)
-        .await?;
+        let events = self
+            .read_with_retry("get_events_by_category", || {
+                sqlx::query_as::<_, DbEvent>(
+                    "SELECT * FROM events WHERE category = $1 AND closed = false ORDER BY end_date ASC",
+                )
+                .bind(category)
+                .fetch_all(&self.pool)
+            })
+            .await?;
 
         Ok(events)
     }
@@ -674,56 +945,56 @@ impl MarketDatabase {
             tag_labels.len() + 1
         );
 
-        let mut query_builder = sqlx::query_as::<_, DbEvent>(&query);
-        for label in tag_labels {
-            query_builder = query_builder.bind(*label);
-        }
-        query_builder = query_builder.bind(tag_labels.len() as i64);
+        let events = self
+            .read_with_retry("get_events_by_tags", || {
+                let mut query_builder = sqlx::query_as::<_, DbEvent>(&query);
+                for label in tag_labels {
+                    query_builder = query_builder.bind(*label);
+                }
+                query_builder = query_builder.bind(tag_labels.len() as i64);
 
-        let events = query_builder.fetch_all(&self.pool).await?;
+                query_builder.fetch_all(&self.pool)
+            })
+            .await?;
         Ok(events)
     }
 
-    /// Get markets by tag labels (matches markets that have ALL specified tags)
-    pub async fn get_markets_by_tags(&self, tag_labels: &[&str]) -> Result<Vec<DbMarket>> {
-        if tag_labels.is_empty() {
-            return Ok(vec![]);
-        }
-
-        // Build placeholders: $1, $2, $3, ...
-        let placeholders: Vec<String> = (1..=tag_labels.len()).map(|i| format!("${}", i)).collect();
-        let placeholders_str = placeholders.join(", ");
+    /// Get markets by tag labels (matches markets that have ALL specified tags
+    /// and none of `exclude`)
+    pub async fn get_markets_by_tags(&self, tag_labels: &[&str], exclude: &[&str]) -> Result<Vec<DbMarket>> {
+        self.get_markets_by_tag_query(&TagQuery::all(tag_labels).excluding(exclude))
+            .await
+    }
 
-        let query = format!(
-            r#"
-            SELECT m.*
-            FROM markets m
-            WHERE m.closed = false
-              AND m.tags IS NOT NULL
-              AND m.end_date::timestamptz > NOW()
-              AND (SELECT COUNT(DISTINCT tag->>'label')
-                   FROM jsonb_array_elements(m.tags::jsonb) AS tag
-                   WHERE tag->>'label' IN ({})) = ${}
-            ORDER BY m.end_date ASC
-            "#,
-            placeholders_str,
-            tag_labels.len() + 1
-        );
+    /// Get markets by tag labels (matches markets that have ANY specified tag)
+    pub async fn get_markets_by_tags_any(&self, tag_labels: &[&str]) -> Result<Vec<DbMarket>> {
+        self.get_markets_by_tag_query(&TagQuery::any(tag_labels)).await
+    }
 
-        let mut query_builder = sqlx::query_as::<_, DbMarket>(&query);
-        for label in tag_labels {
-            query_builder = query_builder.bind(*label);
+    /// Get open, unexpired markets matching a composed tag query
+    pub async fn get_markets_by_tag_query(&self, tags: &TagQuery) -> Result<Vec<DbMarket>> {
+        if tags.is_empty() {
+            return Ok(vec![]);
         }
-        query_builder = query_builder.bind(tag_labels.len() as i64);
 
-        let markets = query_builder.fetch_all(&self.pool).await?;
+        let markets = self
+            .read_with_retry("get_markets_by_tag_query", || async {
+                tag_query_builder(tags)
+                    .build_query_as::<DbMarket>()
+                    .fetch_all(&self.pool)
+                    .await
+            })
+            .await?;
         Ok(markets)
     }
 
     /// Get total event count
     pub async fn event_count(&self) -> Result<i64> {
-        let (count,) = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM events")
-            .fetch_one(&self.pool)
+        let (count,) = self
+            .read_with_retry("event_count", || {
+                sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM events")
+                    .fetch_one(&self.pool)
+            })
             .await?;
 
         Ok(count)
@@ -731,50 +1002,104 @@ impl MarketDatabase {
 
     /// Get active event count
     pub async fn active_event_count(&self) -> Result<i64> {
-        let (count,) =
-            sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM events WHERE closed = false")
-                .fetch_one(&self.pool)
-                .await?;
+        let (count,) = self
+            .read_with_retry("active_event_count", || {
+                sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM events WHERE closed = false")
+                    .fetch_one(&self.pool)
+            })
+            .await?;
 
         Ok(count)
     }
 
     /// Get markets for a specific event
     pub async fn get_event_markets(&self, event_id: &str) -> Result<Vec<DbMarket>> {
-        let markets = sqlx::query_as::<_, DbMarket>(
-            r#"
-            SELECT m.* FROM markets m
-            INNER JOIN event_markets em ON m.id = em.market_id
-            WHERE em.event_id = $1
-            "#,
-        )
-        .bind(event_id)
-        .fetch_all(&self.pool)
-        .await?;
+        let markets = self
+            .read_with_retry("get_event_markets", || {
+                sqlx::query_as::<_, DbMarket>(
+                    r#"
+                    SELECT m.* FROM markets m
+                    INNER JOIN event_markets em ON m.id = em.market_id
+                    WHERE em.event_id = $1
+                    "#,
+                )
+                .bind(event_id)
+                .fetch_all(&self.pool)
+            })
+            .await?;
 
         Ok(markets)
     }
 
     /// Get event ID for a specific market (reverse lookup)
     pub async fn get_market_event_id(&self, market_id: &str) -> Result<Option<String>> {
-        let result = sqlx::query_as::<_, (String,)>(
-            "SELECT event_id FROM event_markets WHERE market_id = $1",
+        let result = self
+            .read_with_retry("get_market_event_id", || {
+                sqlx::query_as::<_, (String,)>(
+                    "SELECT event_id FROM event_markets WHERE market_id = $1",
+                )
+                .bind(market_id)
+                .fetch_optional(&self.pool)
+            })
+            .await?;
+
+        Ok(result.map(|(event_id,)| event_id))
+    }
+
+    /// Merge the tags of each market's Gamma event into the market's `tags`
+    ///
+    /// Markets synced on their own carry no tags, so tag lookups miss them
+    /// until they inherit their event's. Event tags whose label the market
+    /// already has are skipped, and the market's own tags are kept. Returns
+    /// the number of markets that gained tags; running it again is a no-op.
+    pub async fn enrich_market_tags(&self) -> Result<u64> {
+        let result = sqlx::query(
+            r#"
+            WITH candidates AS (
+                -- One tag per label, even when several events share it
+                SELECT DISTINCT ON (em.market_id, et.tag->>'label') em.market_id, et.tag
+                FROM event_markets em
+                JOIN events e ON e.id = em.event_id
+                JOIN markets m ON m.id = em.market_id
+                CROSS JOIN LATERAL jsonb_array_elements(e.tags::jsonb) AS et(tag)
+                WHERE e.tags IS NOT NULL
+                  AND et.tag->>'label' IS NOT NULL
+                  AND NOT EXISTS (
+                      SELECT 1
+                      FROM jsonb_array_elements(COALESCE(m.tags, '[]')::jsonb) AS mt(tag)
+                      WHERE mt.tag->>'label' = et.tag->>'label'
+                  )
+                ORDER BY em.market_id, et.tag->>'label'
+            ),
+            missing AS (
+                SELECT market_id, jsonb_agg(tag) AS tags
+                FROM candidates
+                GROUP BY market_id
+            )
+            UPDATE markets m
+            SET tags = (COALESCE(m.tags, '[]')::jsonb || missing.tags)::text
+            FROM missing
+            WHERE m.id = missing.market_id
+            "#,
         )
-        .bind(market_id)
-        .fetch_optional(&self.pool)
+        .execute(&self.pool)
         .await?;
 
-        Ok(result.map(|(event_id,)| event_id))
+        debug!("Enriched tags of {} markets from their events", result.rows_affected());
+        Ok(result.rows_affected())
     }
 
     /// Get markets by game ID (for sports events)
     pub async fn get_markets_by_game_id(&self, game_id: i64) -> Result<Vec<DbMarket>> {
-        let markets = sqlx::query_as::<_, DbMarket>(
-            "SELECT * FROM markets WHERE game_id = $1 ORDER BY end_date ASC",
-        )
-        .bind(game_id)
-        .fetch_all(&self.pool)
-        .await?;
+        let markets = self
+            .read_with_retry("get_markets_by_game_id", || {
+                sqlx::query_as::<_, DbMarket>(
+                    "SELECT * FROM markets WHERE game_id = $1 ORDER BY end_date ASC",
+                )
+                .bind(game_id)
+                .fetch_all(&self.pool)
+            })
+            .await?;
 
         Ok(markets)
     }
@@ -783,43 +1108,46 @@ impl MarketDatabase {
     /// Returns up to n markets per category (BTC_15M, BTC_1H, ETH_15M, ETH_1H)
     /// Total returned: up to 4 * n markets
     pub async fn get_sliding_window_markets(&self, n: i64) -> Result<Vec<DbMarket>> {
-        let markets = sqlx::query_as::<_, DbMarket>(
-            r#"
-            SELECT id, condition_id, question, description, slug, start_date, end_date,
-                   resolution_time, active, closed, archived, market_type, category,
-                   liquidity, volume, outcomes, token_ids, tags, last_updated, created_at, game_id
-            FROM (
-                SELECT sub.*,
-                       ROW_NUMBER() OVER (PARTITION BY sub.market_category ORDER BY sub.end_date::timestamptz ASC) as rn
-                FROM (
-                    SELECT m.*,
-                           CASE
-                               WHEN EXISTS (SELECT 1 FROM jsonb_array_elements(m.tags::jsonb) t WHERE t->>'label' = 'Bitcoin')
-                                    AND EXISTS (SELECT 1 FROM jsonb_array_elements(m.tags::jsonb) t WHERE t->>'label' = '15M')
-                               THEN 'BTC_15M'
-                               WHEN EXISTS (SELECT 1 FROM jsonb_array_elements(m.tags::jsonb) t WHERE t->>'label' = 'Bitcoin')
-                                    AND EXISTS (SELECT 1 FROM jsonb_array_elements(m.tags::jsonb) t WHERE t->>'label' = '1H')
-                               THEN 'BTC_1H'
-                               WHEN EXISTS (SELECT 1 FROM jsonb_array_elements(m.tags::jsonb) t WHERE t->>'label' = 'Ethereum')
-                                    AND EXISTS (SELECT 1 FROM jsonb_array_elements(m.tags::jsonb) t WHERE t->>'label' = '15M')
-                               THEN 'ETH_15M'
-                               WHEN EXISTS (SELECT 1 FROM jsonb_array_elements(m.tags::jsonb) t WHERE t->>'label' = 'Ethereum')
-                                    AND EXISTS (SELECT 1 FROM jsonb_array_elements(m.tags::jsonb) t WHERE t->>'label' = '1H')
-                               THEN 'ETH_1H'
-                           END as market_category
-                    FROM markets m
-                    WHERE m.closed = false
-                      AND m.end_date::timestamptz > NOW()
-                ) sub
-                WHERE sub.market_category IS NOT NULL
-            ) ranked
-            WHERE rn <= $1
-            ORDER BY end_date::timestamptz
-            "#,
-        )
-        .bind(n)
-        .fetch_all(&self.pool)
-        .await?;
+        let markets = self
+            .read_with_retry("get_sliding_window_markets", || {
+                sqlx::query_as::<_, DbMarket>(
+                    r#"
+                    SELECT id, condition_id, question, description, slug, start_date, end_date,
+                           resolution_time, active, closed, archived, market_type, category,
+                           liquidity, volume, outcomes, token_ids, tags, last_updated, created_at, game_id
+                    FROM (
+                        SELECT sub.*,
+                               ROW_NUMBER() OVER (PARTITION BY sub.market_category ORDER BY sub.end_date::timestamptz ASC) as rn
+                        FROM (
+                            SELECT m.*,
+                                   CASE
+                                       WHEN EXISTS (SELECT 1 FROM jsonb_array_elements(m.tags::jsonb) t WHERE t->>'label' = 'Bitcoin')
+                                            AND EXISTS (SELECT 1 FROM jsonb_array_elements(m.tags::jsonb) t WHERE t->>'label' = '15M')
+                                       THEN 'BTC_15M'
+                                       WHEN EXISTS (SELECT 1 FROM jsonb_array_elements(m.tags::jsonb) t WHERE t->>'label' = 'Bitcoin')
+                                            AND EXISTS (SELECT 1 FROM jsonb_array_elements(m.tags::jsonb) t WHERE t->>'label' = '1H')
+                                       THEN 'BTC_1H'
+                                       WHEN EXISTS (SELECT 1 FROM jsonb_array_elements(m.tags::jsonb) t WHERE t->>'label' = 'Ethereum')
+                                            AND EXISTS (SELECT 1 FROM jsonb_array_elements(m.tags::jsonb) t WHERE t->>'label' = '15M')
+                                       THEN 'ETH_15M'
+                                       WHEN EXISTS (SELECT 1 FROM jsonb_array_elements(m.tags::jsonb) t WHERE t->>'label' = 'Ethereum')
+                                            AND EXISTS (SELECT 1 FROM jsonb_array_elements(m.tags::jsonb) t WHERE t->>'label' = '1H')
+                                       THEN 'ETH_1H'
+                                   END as market_category
+                            FROM markets m
+                            WHERE m.closed = false
+                              AND m.end_date::timestamptz > NOW()
+                        ) sub
+                        WHERE sub.market_category IS NOT NULL
+                    ) ranked
+                    WHERE rn <= $1
+                    ORDER BY end_date::timestamptz
+                    "#,
+                )
+                .bind(n)
+                .fetch_all(&self.pool)
+            })
+            .await?;
 
         Ok(markets)
     }
@@ -837,11 +1165,770 @@ impl MarketDatabase {
     }
 }
 
+/// Upsert statement for one market, shared by the pooled and transactional paths
+///
+/// `last_updated` and `created_at` aren't compared, so an identical market is
+/// not rewritten (no row is returned) and keeps its stored `last_updated`.
+fn upsert_market_query(market: &DbMarket) -> QueryScalar<'_, Postgres, bool, PgArguments> {
+    sqlx::query_scalar(
+        r#"
+        INSERT INTO markets (
+            id, condition_id, question, description, slug, start_date, end_date, resolution_time,
+            active, closed, archived, market_type, category, liquidity, volume,
+            outcomes, token_ids, tags, last_updated, created_at, game_id
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21)
+        ON CONFLICT (id) DO UPDATE SET
+            condition_id = EXCLUDED.condition_id,
+            question = EXCLUDED.question,
+            description = EXCLUDED.description,
+            slug = EXCLUDED.slug,
+            start_date = EXCLUDED.start_date,
+            end_date = EXCLUDED.end_date,
+            resolution_time = EXCLUDED.resolution_time,
+            active = EXCLUDED.active,
+            closed = EXCLUDED.closed,
+            archived = EXCLUDED.archived,
+            market_type = EXCLUDED.market_type,
+            category = EXCLUDED.category,
+            liquidity = EXCLUDED.liquidity,
+            volume = EXCLUDED.volume,
+            outcomes = EXCLUDED.outcomes,
+            token_ids = EXCLUDED.token_ids,
+            -- A tag-less sync keeps the stored (event-enriched) tags
+            tags = COALESCE(EXCLUDED.tags, markets.tags),
+            last_updated = EXCLUDED.last_updated,
+            game_id = EXCLUDED.game_id
+        -- Skip the write when no content column changed
+        WHERE (markets.condition_id, markets.question, markets.description, markets.slug,
+               markets.start_date, markets.end_date, markets.resolution_time, markets.active,
+               markets.closed, markets.archived, markets.market_type, markets.category,
+               markets.liquidity, markets.volume, markets.outcomes, markets.token_ids,
+               markets.tags, markets.game_id)
+        IS DISTINCT FROM
+              (EXCLUDED.condition_id, EXCLUDED.question, EXCLUDED.description, EXCLUDED.slug,
+               EXCLUDED.start_date, EXCLUDED.end_date, EXCLUDED.resolution_time, EXCLUDED.active,
+               EXCLUDED.closed, EXCLUDED.archived, EXCLUDED.market_type, EXCLUDED.category,
+               EXCLUDED.liquidity, EXCLUDED.volume, EXCLUDED.outcomes, EXCLUDED.token_ids,
+               COALESCE(EXCLUDED.tags, markets.tags), EXCLUDED.game_id)
+        RETURNING (xmax = 0) AS inserted
+        "#,
+    )
+    .bind(&market.id)
+    .bind(&market.condition_id)
+    .bind(&market.question)
+    .bind(&market.description)
+    .bind(&market.slug)
+    .bind(&market.start_date)
+    .bind(&market.end_date)
+    .bind(&market.resolution_time)
+    .bind(market.active)
+    .bind(market.closed)
+    .bind(market.archived)
+    .bind(&market.market_type)
+    .bind(&market.category)
+    .bind(&market.liquidity)
+    .bind(&market.volume)
+    .bind(&market.outcomes)
+    .bind(&market.token_ids)
+    .bind(&market.tags)
+    .bind(&market.last_updated)
+    .bind(&market.created_at)
+    .bind(market.game_id)
+}
+
+/// Select open, unexpired markets matching a non-empty tag query
+///
+/// Built fresh for every attempt, since running a query consumes its binds.
+fn tag_query_builder(tags: &TagQuery) -> QueryBuilder<'_, Postgres> {
+    let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(
+        r#"
+        SELECT m.*
+        FROM markets m
+        WHERE m.closed = false
+          AND m.tags IS NOT NULL
+          AND m.end_date::timestamptz > NOW()"#,
+    );
+
+    if !tags.all.is_empty() {
+        query_builder
+            .push(
+                r#"
+          AND (SELECT COUNT(DISTINCT tag->>'label')
+               FROM jsonb_array_elements(m.tags::jsonb) AS tag
+               WHERE tag->>'label' = ANY("#,
+            )
+            .push_bind(&tags.all)
+            .push(")) = ")
+            .push_bind(tags.all.len() as i64);
+    }
+
+    if !tags.any.is_empty() {
+        query_builder
+            .push(
+                r#"
+          AND EXISTS (SELECT 1
+               FROM jsonb_array_elements(m.tags::jsonb) AS tag
+               WHERE tag->>'label' = ANY("#,
+            )
+            .push_bind(&tags.any)
+            .push("))");
+    }
+
+    if !tags.exclude.is_empty() {
+        query_builder
+            .push(
+                r#"
+          AND NOT EXISTS (SELECT 1
+               FROM jsonb_array_elements(m.tags::jsonb) AS tag
+               WHERE tag->>'label' = ANY("#,
+            )
+            .push_bind(&tags.exclude)
+            .push("))");
+    }
+
+    query_builder.push("\n            ORDER BY m.end_date ASC, m.id ASC");
+
+    query_builder
+}
+
+/// Reduce a search query to space-separated alphanumeric words
+fn search_terms(query: &str) -> String {
+    query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Check paging arguments: limit must be positive, offset non-negative
+fn validate_page(limit: i64, offset: i64) -> Result<()> {
+    if limit <= 0 {
+        return Err(DatabaseError::InvalidArgument(format!(
+            "limit must be positive, got {}",
+            limit
+        )));
+    }
+    if offset < 0 {
+        return Err(DatabaseError::InvalidArgument(format!(
+            "offset must not be negative, got {}",
+            offset
+        )));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
 
     // Tests require a running Postgres instance.
     // To run tests, set DATABASE_URL and ensure the database exists.
     // For now, these tests are disabled in the Docker build environment.
+
+    #[test]
+    fn test_validate_page_rejects_bad_limits() {
+        assert!(matches!(validate_page(0, 0), Err(DatabaseError::InvalidArgument(_))));
+        assert!(matches!(validate_page(-5, 0), Err(DatabaseError::InvalidArgument(_))));
+        assert!(matches!(validate_page(10, -1), Err(DatabaseError::InvalidArgument(_))));
+        assert!(validate_page(10, 0).is_ok());
+        assert!(validate_page(1, 1_000_000).is_ok());
+    }
+
+    #[test]
+    fn test_search_terms_strips_special_characters() {
+        assert_eq!(search_terms("  "), "");
+        assert_eq!(search_terms(r#""Trump" & (win | lose)!"#), "Trump win lose");
+        assert_eq!(search_terms("bitcoin's 100k?"), "bitcoin s 100k");
+    }
+
+    /// Active market fixture shared by the database and market tracking tests
+    pub(crate) fn test_market(id: impl Into<String>) -> DbMarket {
+        DbMarket {
+            id: id.into(),
+            condition_id: None,
+            question: "Test market".to_string(),
+            description: None,
+            slug: None,
+            start_date: "2025-01-01T00:00:00Z".to_string(),
+            end_date: "2099-01-01T00:00:00Z".to_string(),
+            // Identical resolution time for every row: only the id breaks ties
+            resolution_time: "2099-01-01T00:00:00Z".to_string(),
+            active: true,
+            closed: false,
+            archived: false,
+            market_type: None,
+            category: None,
+            liquidity: None,
+            volume: None,
+            outcomes: r#"["Yes","No"]"#.to_string(),
+            token_ids: "[]".to_string(),
+            tags: None,
+            last_updated: "2025-01-01T00:00:00Z".to_string(),
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+            game_id: None,
+        }
+    }
+
+    /// Active event fixture shared by the database tests
+    pub(super) fn test_event(id: impl Into<String>) -> DbEvent {
+        DbEvent {
+            id: id.into(),
+            ticker: None,
+            slug: None,
+            title: "Test event".to_string(),
+            description: None,
+            start_date: None,
+            end_date: Some("2099-01-01T00:00:00Z".to_string()),
+            active: true,
+            closed: false,
+            archived: false,
+            featured: false,
+            restricted: false,
+            liquidity: None,
+            volume: None,
+            volume_24hr: None,
+            volume_1wk: None,
+            volume_1mo: None,
+            volume_1yr: None,
+            open_interest: None,
+            image: None,
+            icon: None,
+            category: None,
+            competitive: None,
+            tags: None,
+            comment_count: 0,
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+            updated_at: "2025-01-01T00:00:00Z".to_string(),
+            last_synced: "2025-01-01T00:00:00Z".to_string(),
+            game_id: None,
+        }
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires DATABASE_URL pointing at a Postgres instance
+    async fn test_active_market_pages_are_stable() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL not set");
+        let db = MarketDatabase::new(&url).await.unwrap();
+
+        // Insert in reverse id order so insertion order can't mask a missing tiebreaker
+        let seeded: Vec<DbMarket> = (0..25)
+            .rev()
+            .map(|i| test_market(format!("paging-test-{:02}", i)))
+            .collect();
+        db.batch_upsert_markets(&seeded).await.unwrap();
+
+        let all = db.get_active_markets_paged(i64::MAX, 0).await.unwrap();
+
+        // Walking small pages visits every row exactly once, in the same order
+        let mut paged = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = db.get_active_markets_paged(7, offset).await.unwrap();
+            if page.is_empty() {
+                break;
+            }
+            offset += page.len() as i64;
+            paged.extend(page);
+        }
+        let ids = |ms: &[DbMarket]| ms.iter().map(|m| m.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&paged), ids(&all));
+
+        // Streaming yields the same order
+        let streamed: Vec<DbMarket> = db
+            .get_active_markets_stream()
+            .map(|m| m.unwrap())
+            .collect()
+            .await;
+        assert_eq!(ids(&streamed), ids(&all));
+
+        // Past the end is empty, not an error
+        let past_end = db
+            .get_active_markets_paged(10, all.len() as i64 + 100)
+            .await
+            .unwrap();
+        assert!(past_end.is_empty());
+
+        sqlx::query("DELETE FROM markets WHERE id LIKE 'paging-test-%'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires DATABASE_URL pointing at a Postgres instance
+    async fn test_insert_markets_tx_is_all_or_nothing() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL not set");
+        let db = MarketDatabase::new(&url).await.unwrap();
+        let count_seeded = || async {
+            let (n,): (i64,) =
+                sqlx::query_as("SELECT COUNT(*) FROM markets WHERE id LIKE 'tx-test-%'")
+                    .fetch_one(&db.pool)
+                    .await
+                    .unwrap();
+            n
+        };
+
+        let markets: Vec<DbMarket> = (0..1000)
+            .map(|i| test_market(format!("tx-test-{:04}", i)))
+            .collect();
+
+        // A NUL byte is rejected by Postgres text columns
+        let mut poisoned = markets.clone();
+        poisoned[500].question = "bad\0row".to_string();
+
+        assert!(db.insert_markets_tx(poisoned).await.is_err());
+        assert_eq!(count_seeded().await, 0);
+
+        assert_eq!(db.insert_markets_tx(markets).await.unwrap(), 1000);
+        assert_eq!(count_seeded().await, 1000);
+
+        sqlx::query("DELETE FROM markets WHERE id LIKE 'tx-test-%'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires DATABASE_URL pointing at a Postgres instance
+    async fn test_search_markets_ranks_by_relevance() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL not set");
+        let db = MarketDatabase::new(&url).await.unwrap();
+
+        let questions = [
+            ("search-test-1", "Will Zorblax win the Zorblax election?"),
+            ("search-test-2", "Will Zorblax attend the summit?"),
+            ("search-test-3", "Will it rain in Paris?"),
+        ];
+        let seeded: Vec<DbMarket> = questions
+            .iter()
+            .map(|(id, question)| DbMarket {
+                question: question.to_string(),
+                ..test_market(id.to_string())
+            })
+            .collect();
+        db.batch_upsert_markets(&seeded).await.unwrap();
+
+        let ids = |ms: Vec<DbMarket>| ms.into_iter().map(|m| m.id).collect::<Vec<_>>();
+
+        let hits = db.search_markets("zorblax", 10).await.unwrap();
+        assert_eq!(ids(hits), vec!["search-test-1", "search-test-2"]);
+
+        // Stemmed, all words required
+        let hits = db.search_markets("Zorblax elections", 10).await.unwrap();
+        assert_eq!(ids(hits), vec!["search-test-1"]);
+
+        // Quotes and operators are not a syntax error
+        let hits = db.search_markets(r#""zorblax' & | !(summit"#, 10).await.unwrap();
+        assert_eq!(ids(hits), vec!["search-test-2"]);
+
+        assert!(db.search_markets("", 10).await.unwrap().is_empty());
+        assert!(db.search_markets("\"'", 10).await.unwrap().is_empty());
+
+        sqlx::query("DELETE FROM markets WHERE id LIKE 'search-test-%'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires DATABASE_URL pointing at a Postgres instance
+    async fn test_query_markets_liquidity_floor_with_category() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL not set");
+        let db = MarketDatabase::new(&url).await.unwrap();
+
+        let rows = [
+            ("filter-test-1", "FilterTestA", Some("5000.5")),
+            ("filter-test-2", "FilterTestA", Some("200")),
+            ("filter-test-3", "FilterTestA", None),
+            ("filter-test-4", "FilterTestB", Some("9000")),
+        ];
+        let seeded: Vec<DbMarket> = rows
+            .iter()
+            .map(|(id, category, liquidity)| DbMarket {
+                category: Some(category.to_string()),
+                liquidity: liquidity.map(str::to_string),
+                ..test_market(id.to_string())
+            })
+            .collect();
+        db.batch_upsert_markets(&seeded).await.unwrap();
+
+        let ids = |ms: Vec<DbMarket>| ms.into_iter().map(|m| m.id).collect::<Vec<_>>();
+
+        let filters = MarketFilters {
+            active: Some(true),
+            category: Some("FilterTestA".to_string()),
+            min_liquidity: Some(1000.0),
+            ..Default::default()
+        };
+        assert_eq!(ids(db.query_markets(filters).await.unwrap()), vec!["filter-test-1"]);
+
+        let filters = MarketFilters {
+            category: Some("FilterTestA".to_string()),
+            min_liquidity: Some(100.0),
+            max_liquidity: Some(5000.0),
+            ..Default::default()
+        };
+        assert_eq!(ids(db.query_markets(filters).await.unwrap()), vec!["filter-test-2"]);
+
+        sqlx::query("DELETE FROM markets WHERE id LIKE 'filter-test-%'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires DATABASE_URL pointing at a Postgres instance
+    async fn test_cleanup_task_stops_on_shutdown() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL not set");
+        let db = Arc::new(MarketDatabase::new(&url).await.unwrap());
+
+        let resolved = DbMarket {
+            closed: true,
+            resolution_time: "2000-01-01T00:00:00Z".to_string(),
+            ..test_market("cleanup-test-1".to_string())
+        };
+        db.upsert_market(resolved).await.unwrap();
+
+        let running = Arc::new(AtomicBool::new(true));
+        let handle = Arc::clone(&db).spawn_cleanup_task(
+            std::time::Duration::from_millis(20),
+            std::time::Duration::from_secs(3600),
+            Arc::clone(&running),
+        );
+
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        assert!(matches!(
+            db.get_market("cleanup-test-1").await,
+            Err(DatabaseError::MarketNotFound(_))
+        ));
+
+        running.store(false, Ordering::Release);
+        tokio::time::timeout(std::time::Duration::from_secs(1), handle)
+            .await
+            .expect("cleanup task did not stop")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires DATABASE_URL pointing at a Postgres instance
+    async fn test_upsert_reports_insert_vs_update() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL not set");
+        let db = MarketDatabase::new(&url).await.unwrap();
+
+        let mut market = test_market("upsert-test-0".to_string());
+        assert_eq!(db.upsert_market(market.clone()).await.unwrap(), UpsertOutcome::Inserted);
+        market.question = "Changed question".to_string();
+        assert_eq!(db.upsert_market(market).await.unwrap(), UpsertOutcome::Updated);
+
+        // One changed row plus two new ones
+        let batch: Vec<DbMarket> = (0..3)
+            .map(|i| test_market(format!("upsert-test-{}", i)))
+            .collect();
+        let counts = db.batch_upsert_markets(&batch).await.unwrap();
+        assert_eq!((counts.inserted, counts.updated, counts.unchanged), (2, 1, 0));
+
+        sqlx::query("DELETE FROM markets WHERE id LIKE 'upsert-test-%'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires DATABASE_URL pointing at a Postgres instance
+    async fn test_db_timestamp_round_trip() {
+        use crate::domain::DbTimestamp;
+
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL not set");
+        let db = MarketDatabase::new(&url).await.unwrap();
+
+        let ts = DbTimestamp::new("2025-01-02T03:04:05Z");
+        let back: DbTimestamp = sqlx::query_scalar("SELECT $1::text")
+            .bind(&ts)
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(back.as_str(), ts.as_str());
+
+        // Stored market strings read back as timestamps
+        let market = test_market("timestamp-test-0".to_string());
+        db.upsert_market(market.clone()).await.unwrap();
+        let stored: DbTimestamp =
+            sqlx::query_scalar("SELECT resolution_time FROM markets WHERE id = $1")
+                .bind(&market.id)
+                .fetch_one(&db.pool)
+                .await
+                .unwrap();
+        assert_eq!(stored, market.resolution_timestamp());
+
+        sqlx::query("DELETE FROM markets WHERE id LIKE 'timestamp-test-%'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires DATABASE_URL pointing at a Postgres instance
+    async fn test_markets_by_tag_combinations() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL not set");
+        let db = MarketDatabase::new(&url).await.unwrap();
+
+        let tagged = |id: &str, labels: &[&str]| {
+            let tags: Vec<_> = labels
+                .iter()
+                .map(|label| serde_json::json!({ "label": label }))
+                .collect();
+            DbMarket {
+                tags: Some(serde_json::Value::Array(tags).to_string()),
+                ..test_market(id.to_string())
+            }
+        };
+        let seeded = vec![
+            tagged("tags-test-1", &["TtCrypto", "TtRecurring"]),
+            tagged("tags-test-2", &["TtCrypto", "TtRecurring", "TtResolved"]),
+            tagged("tags-test-3", &["TtCrypto"]),
+            tagged("tags-test-4", &["TtSports"]),
+            tagged("tags-test-5", &["TtPolitics", "TtRecurring"]),
+        ];
+        db.batch_upsert_markets(&seeded).await.unwrap();
+
+        let ids = |ms: Vec<DbMarket>| ms.into_iter().map(|m| m.id).collect::<Vec<_>>();
+
+        // ALL
+        let all = db.get_markets_by_tags(&["TtCrypto", "TtRecurring"], &[]).await.unwrap();
+        assert_eq!(ids(all), vec!["tags-test-1", "tags-test-2"]);
+
+        // ALL but NOT
+        let not = db
+            .get_markets_by_tags(&["TtCrypto", "TtRecurring"], &["TtResolved"])
+            .await
+            .unwrap();
+        assert_eq!(ids(not), vec!["tags-test-1"]);
+
+        // ANY
+        let any = db.get_markets_by_tags_any(&["TtSports", "TtPolitics"]).await.unwrap();
+        assert_eq!(ids(any), vec!["tags-test-4", "tags-test-5"]);
+
+        // ALL and ANY and NOT composed
+        let composed = TagQuery::all(&["TtRecurring"])
+            .with_any(&["TtCrypto", "TtPolitics"])
+            .excluding(&["TtResolved"]);
+        let hits = db.get_markets_by_tag_query(&composed).await.unwrap();
+        assert_eq!(ids(hits), vec!["tags-test-1", "tags-test-5"]);
+
+        // Exclusions alone select nothing
+        let only_exclude = TagQuery::default().excluding(&["TtResolved"]);
+        assert!(db.get_markets_by_tag_query(&only_exclude).await.unwrap().is_empty());
+
+        sqlx::query("DELETE FROM markets WHERE id LIKE 'tags-test-%'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires DATABASE_URL pointing at a Postgres instance
+    async fn test_market_inherits_event_tags() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL not set");
+        let db = MarketDatabase::new(&url).await.unwrap();
+
+        let tags = |labels: &[&str]| {
+            let tags: Vec<_> = labels
+                .iter()
+                .map(|label| serde_json::json!({ "label": label }))
+                .collect();
+            Some(serde_json::Value::Array(tags).to_string())
+        };
+        let event = DbEvent {
+            title: "Tag enrichment event".to_string(),
+            tags: tags(&["EtCrypto", "EtRecurring"]),
+            ..test_event("enrich-test-event")
+        };
+        db.upsert_event(event).await.unwrap();
+
+        // Synced without tags, and with one overlapping tag of its own
+        let bare = test_market("enrich-test-1".to_string());
+        let own = DbMarket {
+            tags: tags(&["EtRecurring", "EtDaily"]),
+            ..test_market("enrich-test-2".to_string())
+        };
+        db.batch_upsert_markets(&[bare, own]).await.unwrap();
+        db.link_event_markets(
+            "enrich-test-event",
+            &["enrich-test-1".to_string(), "enrich-test-2".to_string()],
+        )
+        .await
+        .unwrap();
+
+        let ids = |ms: Vec<DbMarket>| ms.into_iter().map(|m| m.id).collect::<Vec<_>>();
+        let found = db.get_markets_by_tags(&["EtCrypto"], &[]).await.unwrap();
+        assert!(found.is_empty());
+
+        assert_eq!(db.enrich_market_tags().await.unwrap(), 2);
+
+        let found = db.get_markets_by_tags(&["EtCrypto", "EtRecurring"], &[]).await.unwrap();
+        assert_eq!(ids(found), vec!["enrich-test-1", "enrich-test-2"]);
+
+        // The market keeps its own tags and gains no duplicate labels
+        let merged = db.get_market("enrich-test-2").await.unwrap();
+        let labels: Vec<String> = serde_json::from_str::<Vec<serde_json::Value>>(&merged.tags.unwrap())
+            .unwrap()
+            .iter()
+            .map(|tag| tag["label"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(labels, vec!["EtRecurring", "EtDaily", "EtCrypto"]);
+
+        // Nothing left to enrich
+        assert_eq!(db.enrich_market_tags().await.unwrap(), 0);
+
+        // A later tag-less sync of the same markets neither wipes nor rewrites them
+        let resynced = test_market("enrich-test-1".to_string());
+        assert_eq!(
+            db.upsert_market(resynced.clone()).await.unwrap(),
+            UpsertOutcome::Unchanged
+        );
+        let counts = db.batch_upsert_markets(&[resynced]).await.unwrap();
+        assert_eq!(counts, UpsertCounts { inserted: 0, updated: 0, unchanged: 1 });
+        let found = db.get_markets_by_tags(&["EtCrypto"], &[]).await.unwrap();
+        assert_eq!(ids(found), vec!["enrich-test-1", "enrich-test-2"]);
+
+        sqlx::query("DELETE FROM event_markets WHERE event_id = 'enrich-test-event'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM events WHERE id = 'enrich-test-event'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM markets WHERE id LIKE 'enrich-test-%'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires DATABASE_URL pointing at a Postgres instance
+    async fn test_identical_resync_skips_all_upserts() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL not set");
+        let db = MarketDatabase::new(&url).await.unwrap();
+
+        let markets: Vec<DbMarket> = (0..150)
+            .map(|i| test_market(format!("noop-test-{:03}", i)))
+            .collect();
+
+        let first = db.batch_upsert_markets(&markets).await.unwrap();
+        assert_eq!(first.inserted, 150);
+
+        // Same content, new sync time: nothing is rewritten
+        let resynced: Vec<DbMarket> = markets
+            .iter()
+            .map(|m| DbMarket {
+                last_updated: "2025-06-01T00:00:00Z".to_string(),
+                ..m.clone()
+            })
+            .collect();
+        let second = db.batch_upsert_markets(&resynced).await.unwrap();
+        assert_eq!(second, UpsertCounts { inserted: 0, updated: 0, unchanged: 150 });
+        assert_eq!(
+            db.upsert_market(resynced[0].clone()).await.unwrap(),
+            UpsertOutcome::Unchanged
+        );
+
+        // Unchanged rows keep their stored last_updated
+        let stored = db.get_market("noop-test-000").await.unwrap();
+        assert_eq!(stored.last_updated, markets[0].last_updated);
+
+        sqlx::query("DELETE FROM markets WHERE id LIKE 'noop-test-%'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires DATABASE_URL pointing at a Postgres instance
+    async fn test_count_orders_since_rebuilds_daily_count() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL not set");
+        let db = MarketDatabase::new(&url).await.unwrap();
+
+        let entry = OrderLogEntry {
+            token_id: "count-test".to_string(),
+            side: "BUY".to_string(),
+            order_type: "GTC".to_string(),
+            params: "{}".to_string(),
+            signature: String::new(),
+            order_id: None,
+            success: true,
+            status: None,
+            error: None,
+        };
+        let before = Utc::now() - chrono::Duration::seconds(1);
+        db.log_order(&entry).await.unwrap();
+        db.log_order(&entry).await.unwrap();
+
+        assert!(db.count_orders_since(before).await.unwrap() >= 2);
+        let later = Utc::now() + chrono::Duration::hours(1);
+        assert_eq!(db.count_orders_since(later).await.unwrap(), 0);
+
+        sqlx::query("DELETE FROM orders_log WHERE token_id = 'count-test'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires DATABASE_URL pointing at a Postgres instance
+    async fn test_exhausted_pool_reports_pool_timeout() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL not set");
+        let opts = PoolOptions {
+            max_connections: 1,
+            min_connections: 0,
+            acquire_timeout: std::time::Duration::from_millis(200),
+            ..Default::default()
+        };
+        let db = MarketDatabase::with_options(&url, opts).await.unwrap();
+
+        // Hold the only connection
+        let _held = db.pool().acquire().await.unwrap();
+
+        let err = db.market_count().await.unwrap_err();
+        assert!(matches!(err, DatabaseError::PoolTimeout), "got {:?}", err);
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires DATABASE_URL pointing at a Postgres instance
+    async fn test_watchdog_follows_sync_runs_not_market_changes() {
+        use crate::application::sync::FreshnessWatchdog;
+
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL not set");
+        let db = Arc::new(MarketDatabase::new(&url).await.unwrap());
+        let watchdog = FreshnessWatchdog::new(Arc::clone(&db), std::time::Duration::from_secs(300));
+        let now = Utc::now();
+
+        // A sync that ran recently keeps the watchdog quiet even though
+        // it found nothing to change
+        let market = test_market("sync-state-test-1".to_string());
+        db.batch_upsert_markets(std::slice::from_ref(&market)).await.unwrap();
+        db.batch_upsert_markets(std::slice::from_ref(&market)).await.unwrap();
+        db.record_sync_success(MARKET_SYNC, now - chrono::Duration::seconds(30))
+            .await
+            .unwrap();
+        let recorded = db.last_sync_success(MARKET_SYNC).await.unwrap().unwrap();
+        assert_eq!(recorded.timestamp(), now.timestamp() - 30);
+        assert_eq!(watchdog.check(now).await.unwrap(), None);
+
+        // No sync for ten minutes: alert
+        db.record_sync_success(MARKET_SYNC, now - chrono::Duration::minutes(10))
+            .await
+            .unwrap();
+        assert!(watchdog.check(now).await.unwrap().is_some());
+
+        sqlx::query("DELETE FROM markets WHERE id = 'sync-state-test-1'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM sync_state WHERE name = $1")
+            .bind(MARKET_SYNC)
+            .execute(&db.pool)
+            .await
+            .unwrap();
+    }
 }
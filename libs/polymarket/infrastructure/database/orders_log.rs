@@ -0,0 +1,121 @@
+//! Audit log of placed orders
+//!
+//! Every order sent to the CLOB can be recorded in `orders_log`
+//! together with the exchange's answer. `RestClient::with_order_log` wires an
+//! `OrderLog` into the placement path.
+
+use super::{validate_page, MarketDatabase, Result};
+use chrono::{DateTime, Utc};
+
+/// One order placement to record
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderLogEntry {
+    pub token_id: String,
+    /// "BUY" or "SELL"
+    pub side: String,
+    pub order_type: String,
+    /// Order fields as sent to the API, without the signature (JSON)
+    pub params: String,
+    /// Empty for unsigned orders
+    pub signature: String,
+    /// Exchange order ID, if the order was accepted
+    pub order_id: Option<String>,
+    pub success: bool,
+    /// Exchange order status ("live", "matched", ...)
+    pub status: Option<String>,
+    /// Exchange error message, or the transport error if no answer came back
+    pub error: Option<String>,
+}
+
+/// A row of `orders_log`
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LoggedOrder {
+    pub id: i64,
+    pub token_id: String,
+    pub side: String,
+    pub order_type: String,
+    pub params: String,
+    pub signature: String,
+    pub order_id: Option<String>,
+    pub success: bool,
+    pub status: Option<String>,
+    pub error: Option<String>,
+    /// RFC 3339 time the placement result was recorded
+    pub placed_at: String,
+}
+
+/// Sink for order placements
+#[async_trait::async_trait]
+pub trait OrderLog: Send + Sync {
+    async fn log_order(&self, entry: OrderLogEntry) -> Result<()>;
+}
+
+impl MarketDatabase {
+    /// Record an order placement, returning the row id
+    pub async fn log_order(&self, entry: &OrderLogEntry) -> Result<i64> {
+        let (id,) = sqlx::query_as::<_, (i64,)>(
+            r#"
+            INSERT INTO orders_log (
+                token_id, side, order_type, params, signature,
+                order_id, success, status, error, placed_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING id
+            "#,
+        )
+        .bind(&entry.token_id)
+        .bind(&entry.side)
+        .bind(&entry.order_type)
+        .bind(&entry.params)
+        .bind(&entry.signature)
+        .bind(&entry.order_id)
+        .bind(entry.success)
+        .bind(&entry.status)
+        .bind(&entry.error)
+        .bind(Utc::now().to_rfc3339())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(id)
+    }
+
+    /// Most recently logged orders, newest first
+    pub async fn get_recent_orders(&self, limit: i64) -> Result<Vec<LoggedOrder>> {
+        validate_page(limit, 0)?;
+
+        let orders = self
+            .read_with_retry("get_recent_orders", || {
+                sqlx::query_as::<_, LoggedOrder>(
+                    "SELECT * FROM orders_log ORDER BY id DESC LIMIT $1",
+                )
+                .bind(limit)
+                .fetch_all(&self.pool)
+            })
+            .await?;
+
+        Ok(orders)
+    }
+
+    /// Number of orders logged at or after `since`
+    ///
+    /// Rebuilds the daily order count after a restart.
+    pub async fn count_orders_since(&self, since: DateTime<Utc>) -> Result<usize> {
+        let (count,) = self
+            .read_with_retry("count_orders_since", || {
+                sqlx::query_as::<_, (i64,)>(
+                    "SELECT COUNT(*) FROM orders_log WHERE placed_at::timestamptz >= $1::timestamptz",
+                )
+                .bind(since.to_rfc3339())
+                .fetch_one(&self.pool)
+            })
+            .await?;
+
+        Ok(count as usize)
+    }
+}
+
+#[async_trait::async_trait]
+impl OrderLog for MarketDatabase {
+    async fn log_order(&self, entry: OrderLogEntry) -> Result<()> {
+        MarketDatabase::log_order(self, &entry).await.map(|_| ())
+    }
+}
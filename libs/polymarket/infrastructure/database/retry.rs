@@ -0,0 +1,130 @@
+//! Retry of read queries across transient connection errors
+//!
+//! A Postgres restart or network blip fails every in-flight query with an
+//! I/O or connection-class error. Read queries are safe to resend, so they
+//! retry with exponential backoff before the error reaches the caller.
+//! Writes stay single-shot: a failed commit may still have been applied.
+
+use super::{MarketDatabase, Result};
+use crate::infrastructure::backoff::BackoffPolicy;
+use std::future::Future;
+use std::time::Duration;
+use tracing::warn;
+
+/// Read query retries after a transient connection error: up to 3,
+/// starting at 100ms
+pub const DEFAULT_READ_RETRY: BackoffPolicy = BackoffPolicy::new(3, Duration::from_millis(100));
+
+/// Whether an error comes from a lost or unavailable connection rather than
+/// from the query itself
+pub(crate) fn is_transient(err: &sqlx::Error) -> bool {
+    match err {
+        sqlx::Error::Io(_) | sqlx::Error::PoolTimedOut => true,
+        // SQLSTATE class 08 (connection exception), and the server shutting
+        // down or still starting up
+        sqlx::Error::Database(db) => db
+            .code()
+            .is_some_and(|code| code.starts_with("08") || matches!(code.as_ref(), "57P01" | "57P02" | "57P03")),
+        _ => false,
+    }
+}
+
+/// Run `query` until it succeeds, fails with a non-transient error, or the
+/// policy's retries are used up
+pub(crate) async fn retry_transient<T, F, Fut>(policy: &BackoffPolicy, what: &str, mut query: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = std::result::Result<T, sqlx::Error>>,
+{
+    let mut retry = 0;
+
+    loop {
+        match query().await {
+            Ok(value) => return Ok(value),
+            Err(e) if retry < policy.max_retries && is_transient(&e) => {
+                retry += 1;
+                let delay = policy.delay_for(retry);
+                warn!(
+                    "[Database] {} failed (retry {}/{} in {:?}): {}",
+                    what, retry, policy.max_retries, delay, e
+                );
+                tokio::time::sleep(delay).await;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+impl MarketDatabase {
+    /// Run a read query under the database's retry policy
+    pub(crate) async fn read_with_retry<T, F, Fut>(&self, what: &str, query: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = std::result::Result<T, sqlx::Error>>,
+    {
+        retry_transient(&self.read_retry, what, query).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database::DatabaseError;
+    use std::io;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn connection_refused() -> sqlx::Error {
+        sqlx::Error::Io(io::Error::new(io::ErrorKind::ConnectionRefused, "connection refused"))
+    }
+
+    fn fast_policy(max_retries: u32) -> BackoffPolicy {
+        DEFAULT_READ_RETRY
+            .with_max_retries(max_retries)
+            .with_base_delay(Duration::from_millis(1))
+    }
+
+    #[tokio::test]
+    async fn test_transient_connection_error_succeeds_on_retry() {
+        let attempts = AtomicU32::new(0);
+
+        let result = retry_transient(&fast_policy(3), "market_count", || async {
+            if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                Err(connection_refused())
+            } else {
+                Ok(42_i64)
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_retries() {
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<i64> = retry_transient(&fast_policy(2), "market_count", || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(connection_refused())
+        })
+        .await;
+
+        assert!(matches!(result, Err(DatabaseError::ConnectionError(sqlx::Error::Io(_)))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_query_errors_are_not_retried() {
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<i64> = retry_transient(&fast_policy(3), "get_market", || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(sqlx::Error::RowNotFound)
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+}
@@ -10,7 +10,7 @@ pub enum SchemaError {
 pub type Result<T> = std::result::Result<T, SchemaError>;
 
 /// Database schema version
-pub const SCHEMA_VERSION: i32 = 6;
+pub const SCHEMA_VERSION: i32 = 10;
 
 /// Initialize database schema
 pub async fn initialize_schema(pool: &PgPool) -> Result<()> {
@@ -244,6 +244,71 @@ pub async fn initialize_schema(pool: &PgPool) -> Result<()> {
         .execute(pool)
         .await?;
 
+    // Migration: Full-text search over market questions (v7)
+    // A generated column stays in sync with `question` on every write, and
+    // adding it backfills existing rows
+    sqlx::query(
+        r#"
+        ALTER TABLE markets ADD COLUMN IF NOT EXISTS question_tsv tsvector
+        GENERATED ALWAYS AS (to_tsvector('english', question)) STORED
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_markets_question_fts ON markets USING GIN(question_tsv)")
+        .execute(pool)
+        .await?;
+
+    // Migration: Audit log of placed orders (v8)
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS orders_log (
+            id BIGSERIAL PRIMARY KEY,
+            token_id TEXT NOT NULL,
+            side TEXT NOT NULL,
+            order_type TEXT NOT NULL,
+            params TEXT NOT NULL,
+            signature TEXT NOT NULL,
+            order_id TEXT,
+            success BOOLEAN NOT NULL,
+            status TEXT,
+            error TEXT,
+            placed_at TEXT NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_orders_log_token ON orders_log(token_id)")
+        .execute(pool)
+        .await?;
+
+    // Migration: Operator kill switches shared across processes (v9)
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS market_halts (
+            condition_id TEXT PRIMARY KEY,
+            halted_at TEXT NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Migration: Last successful run per sync service (v10)
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS sync_state (
+            name TEXT PRIMARY KEY,
+            last_success TEXT NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
     Ok(())
 }
 
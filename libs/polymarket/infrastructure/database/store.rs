@@ -0,0 +1,132 @@
+//! Backend-agnostic market storage
+//!
+//! `MarketStore` is the surface strategies and binaries need from a market
+//! database. It is object safe, so a backend can be chosen at runtime and
+//! passed around as `Arc<dyn MarketStore>`.
+
+use super::{DbEvent, DbMarket, MarketDatabase, MarketFilters, Result, UpsertOutcome};
+
+#[async_trait::async_trait]
+pub trait MarketStore: Send + Sync {
+    async fn upsert_market(&self, market: DbMarket) -> Result<UpsertOutcome>;
+
+    async fn get_active_markets(&self) -> Result<Vec<DbMarket>>;
+
+    /// Fails with `MarketNotFound` for unknown ids
+    async fn get_market(&self, id: &str) -> Result<DbMarket>;
+
+    async fn query_markets(&self, filters: MarketFilters) -> Result<Vec<DbMarket>>;
+
+    async fn upsert_event(&self, event: DbEvent) -> Result<()>;
+
+    /// Fails with `EventNotFound` for unknown ids
+    async fn get_event(&self, event_id: &str) -> Result<DbEvent>;
+
+    async fn get_active_events(&self) -> Result<Vec<DbEvent>>;
+
+    async fn link_event_markets(&self, event_id: &str, market_ids: &[String]) -> Result<()>;
+
+    async fn get_event_markets(&self, event_id: &str) -> Result<Vec<DbMarket>>;
+}
+
+#[async_trait::async_trait]
+impl MarketStore for MarketDatabase {
+    async fn upsert_market(&self, market: DbMarket) -> Result<UpsertOutcome> {
+        MarketDatabase::upsert_market(self, market).await
+    }
+
+    async fn get_active_markets(&self) -> Result<Vec<DbMarket>> {
+        MarketDatabase::get_active_markets(self).await
+    }
+
+    async fn get_market(&self, id: &str) -> Result<DbMarket> {
+        MarketDatabase::get_market(self, id).await
+    }
+
+    async fn query_markets(&self, filters: MarketFilters) -> Result<Vec<DbMarket>> {
+        MarketDatabase::query_markets(self, filters).await
+    }
+
+    async fn upsert_event(&self, event: DbEvent) -> Result<()> {
+        MarketDatabase::upsert_event(self, event).await
+    }
+
+    async fn get_event(&self, event_id: &str) -> Result<DbEvent> {
+        MarketDatabase::get_event(self, event_id).await
+    }
+
+    async fn get_active_events(&self) -> Result<Vec<DbEvent>> {
+        MarketDatabase::get_active_events(self).await
+    }
+
+    async fn link_event_markets(&self, event_id: &str, market_ids: &[String]) -> Result<()> {
+        MarketDatabase::link_event_markets(self, event_id, market_ids).await
+    }
+
+    async fn get_event_markets(&self, event_id: &str) -> Result<Vec<DbMarket>> {
+        MarketDatabase::get_event_markets(self, event_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database::tests::{test_event, test_market};
+    use crate::infrastructure::database::DatabaseError;
+    use std::sync::Arc;
+
+    fn store_event(id: &str) -> DbEvent {
+        DbEvent {
+            title: "Store trait event".to_string(),
+            ..test_event(id)
+        }
+    }
+
+    fn store_market(id: &str) -> DbMarket {
+        DbMarket {
+            question: "Store trait market".to_string(),
+            category: Some("store-trait-test".to_string()),
+            ..test_market(id)
+        }
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires DATABASE_URL pointing at a Postgres instance
+    async fn test_postgres_backend_behind_dyn_store() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL not set");
+        let db = MarketDatabase::new(&url).await.unwrap();
+        let pool = db.pool().clone();
+        let store: Arc<dyn MarketStore> = Arc::new(db);
+
+        assert!(matches!(
+            store.get_market("store-test-missing").await,
+            Err(DatabaseError::MarketNotFound(_))
+        ));
+
+        store.upsert_event(store_event("store-test-event")).await.unwrap();
+        let outcome = store.upsert_market(store_market("store-test-market")).await.unwrap();
+        assert_eq!(outcome, UpsertOutcome::Inserted);
+        store
+            .link_event_markets("store-test-event", &["store-test-market".to_string()])
+            .await
+            .unwrap();
+
+        let linked = store.get_event_markets("store-test-event").await.unwrap();
+        assert_eq!(linked.len(), 1);
+        assert_eq!(linked[0].id, "store-test-market");
+
+        let filters = MarketFilters {
+            category: Some("store-trait-test".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(store.query_markets(filters).await.unwrap().len(), 1);
+
+        for sql in [
+            "DELETE FROM event_markets WHERE event_id = 'store-test-event'",
+            "DELETE FROM markets WHERE id = 'store-test-market'",
+            "DELETE FROM events WHERE id = 'store-test-event'",
+        ] {
+            sqlx::query(sql).execute(&pool).await.unwrap();
+        }
+    }
+}
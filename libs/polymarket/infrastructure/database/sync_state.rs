@@ -0,0 +1,47 @@
+//! Last successful run of each sync service
+//!
+//! Upserts skip rows whose content didn't change, so `markets.last_updated`
+//! says when data last changed, not when the syncer last ran. `sync_state`
+//! records each successful run so the freshness watchdog can tell a quiet
+//! market from a dead syncer.
+
+use super::{DatabaseError, MarketDatabase, Result};
+use chrono::{DateTime, Utc};
+
+/// `sync_state` name of the market sync service
+pub const MARKET_SYNC: &str = "markets";
+
+impl MarketDatabase {
+    /// Record that the sync named `name` completed at `at`
+    pub async fn record_sync_success(&self, name: &str, at: DateTime<Utc>) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO sync_state (name, last_success) VALUES ($1, $2)
+            ON CONFLICT (name) DO UPDATE SET last_success = EXCLUDED.last_success
+            "#,
+        )
+        .bind(name)
+        .bind(at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// When the sync named `name` last completed, `None` if it never has
+    pub async fn last_sync_success(&self, name: &str) -> Result<Option<DateTime<Utc>>> {
+        let row = self
+            .read_with_retry("last_sync_success", || {
+                sqlx::query_as::<_, (String,)>("SELECT last_success FROM sync_state WHERE name = $1")
+                    .bind(name)
+                    .fetch_optional(&self.pool)
+            })
+            .await?;
+
+        row.map(|(ts,)| {
+            DateTime::parse_from_rfc3339(&ts)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|e| DatabaseError::InvalidArgument(format!("bad last_success '{}': {}", ts, e)))
+        })
+        .transpose()
+    }
+}
@@ -3,6 +3,7 @@
 //! Contains implementations of external interfaces (database, API clients, etc.)
 //! This layer depends on the domain layer but not on the application layer.
 
+pub mod backoff;
 pub mod balance_manager;
 pub mod client;
 pub mod config;
@@ -17,14 +18,17 @@ pub mod shutdown;
 // Re-export commonly used types from client
 pub use client::{
     clob::{
-        build_ws_client, decimal_places, handle_client_event, Market, MarketTrackerConfig,
-        OrderArgs, OrderBook, OrderType, Outcome, PriceLevel, RestClient, SharedOrderbooks,
-        SharedPrecisions, Side, SniperHandler, SniperMessage, SniperRoute, SniperRouter,
+        build_sniper_ws, build_ws_client, decimal_places, handle_client_event, is_market_closed,
+        Market, MarketTrackerConfig, OrderArgs, OrderBook, OrderType, Outcome, PriceLevel,
+        RestClient, SharedOrderbooks, SharedPrecisions, Side, SniperHandler, SniperMessage,
+        SniperRoute, SniperRouter, SniperSharedState,
         TickSizeChangeEvent, WebSocketClient,
     },
     gamma::{GammaClient, GammaEvent, GammaFilters, GammaMarket, GammaTag},
     oracle::{
-        spawn_oracle_trackers, OraclePriceManager, OracleType, PriceEntry, SharedOraclePrices,
+        spawn_oracle_trackers, spawn_oracle_trackers_for, OracleAssets, OraclePriceManager,
+        OracleType, PriceDivergence, PriceEntry, PriceFeed, PriceQuote, PriceSource,
+        SharedOraclePrices, SharedPrices,
         CandlestickApiClient,
     },
     binance::{
@@ -41,20 +45,25 @@ pub use client::{
     ctf::{
         CtfClient, CtfError, CtfOperation, CtfOperationResult,
         split_via_safe, merge_via_safe, approve_via_safe,
-        split, merge,
+        split, merge, wait_for_confirmations,
         usdc_to_raw, usdc_from_raw,
-        USDC_DECIMALS,
+        USDC_DECIMALS, DEFAULT_MIN_CONFIRMATIONS,
     },
+    redeem::{RedeemClient, RedeemScheduler, Redemption},
 };
 
 // Re-export database types
-pub use database::{DatabaseError, MarketDatabase, Result};
+pub use database::{
+    DatabaseError, LoggedOrder, MarketDatabase, MarketStore, OrderLog, OrderLogEntry, PoolOptions,
+    Result, UpsertCounts, UpsertOutcome, DEFAULT_READ_RETRY,
+};
 
 // Re-export config types
 pub use config::{BotConfig, EventsConfig, SniperConfig};
 
 // Re-export infrastructure services
-pub use balance_manager::BalanceManager;
+pub use backoff::BackoffPolicy;
+pub use balance_manager::{BalanceManager, OwnBalanceFlow};
 pub use heartbeat::Heartbeat;
 pub use logging::{init_tracing, init_tracing_with_level};
 pub use order_manager::{
@@ -62,12 +71,16 @@ pub use order_manager::{
     Side as OrderSide, TradeStatus,
 };
 pub use position_manager::PositionManager;
-pub use risk_manager::{RiskManager, RiskManagerHandle};
-pub use shutdown::ShutdownManager;
+pub use risk_manager::{
+    FlattenPosition, FlattenReport, FlattenResult, MarketHalts, RiskManager,
+    RiskManagerHandle,
+};
+pub use shutdown::{HookStatus, ShutdownHook, ShutdownManager, ShutdownReport, SubsystemReport};
 
 // Re-export user state types for strategies (uses parking_lot::RwLock)
 pub use client::user::{
     parse_timestamp_to_i64, OrderStateStore, ReconciliationConfig, SharedOrderState,
-    SharedPositionTracker, OrderStatus as UserOrderStatus, PositionTracker,
+    SharedPositionTracker, OrderStatus as UserOrderStatus, PositionTracker, PositionChange,
+    PositionChangeReason,
     spawn_order_reconciliation_task, spawn_position_reconciliation_task,
 };
@@ -1028,6 +1028,14 @@ fn handle_client_event(event: ClientEvent) -> bool {
             warn!("[OrderManager] Error: {}", err);
             true
         }
+        ClientEvent::MessageTooLarge { size, max_size } => {
+            warn!("[OrderManager] Message too large: {} bytes (max {})", size, max_size);
+            true
+        }
+        ClientEvent::CircuitOpen => {
+            warn!("[OrderManager] Circuit breaker open, stopped reconnecting");
+            false
+        }
     }
 }
 
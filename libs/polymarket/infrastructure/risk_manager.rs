@@ -4,17 +4,21 @@
 //! - Background monitoring thread that cancels orders when oracle price
 //!   approaches price_to_beat
 //! - Fast pre_placement_check() for synchronous risk validation
+//! - Per-market kill switch (condition id -> halted), toggleable at runtime
+//! - Emergency flatten_all() that cancels everything and closes every position
 //!
 //! The RiskManager runs independently on its own OS thread and can be used
 //! by any strategy that needs oracle-based risk management.
 
 use crate::application::strategies::up_or_down::types::{CryptoAsset, OracleSource};
-use crate::infrastructure::client::clob::TradingClient;
-use crate::infrastructure::SharedOraclePrices;
+use crate::application::visualizer::ExecutionBackend;
+use crate::infrastructure::client::clob::{OrderType, Side, TradingClient, TradingError};
+use crate::infrastructure::{MarketDatabase, SharedOraclePrices};
 use chrono::{DateTime, Utc};
 use crossbeam_channel::{Receiver, Sender, TryRecvError};
 use parking_lot::RwLock;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
@@ -54,6 +58,147 @@ struct RegisteredMarket {
 /// Shared registry of markets being monitored
 type SharedMarketRegistry = Arc<RwLock<HashMap<String, RegisteredMarket>>>;
 
+// =============================================================================
+// MarketHalts
+// =============================================================================
+
+/// Per-market kill switch keyed by condition id
+///
+/// Cheap to clone; all clones share the same set, so a halt toggled from one
+/// place is seen by every tracker consulting it. Halts set in another process
+/// (e.g. the visualizer) arrive through the database, see `spawn_db_sync`.
+#[derive(Debug, Clone, Default)]
+pub struct MarketHalts {
+    halted: Arc<RwLock<HashSet<String>>>,
+}
+
+impl MarketHalts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Block new placements on a market
+    pub fn halt(&self, condition_id: &str) {
+        if self.halted.write().insert(condition_id.to_string()) {
+            warn!("Market {} HALTED - placements blocked", condition_id);
+        }
+    }
+
+    /// Allow placements on a market again
+    pub fn resume(&self, condition_id: &str) {
+        if self.halted.write().remove(condition_id) {
+            info!("Market {} resumed", condition_id);
+        }
+    }
+
+    /// Flip a market's halt state, returning true if it is now halted
+    pub fn toggle(&self, condition_id: &str) -> bool {
+        if self.is_halted(condition_id) {
+            self.resume(condition_id);
+            false
+        } else {
+            self.halt(condition_id);
+            true
+        }
+    }
+
+    /// Check if placements on a market are blocked
+    pub fn is_halted(&self, condition_id: &str) -> bool {
+        self.halted.read().contains(condition_id)
+    }
+
+    /// Condition ids of every halted market
+    pub fn halted_markets(&self) -> Vec<String> {
+        self.halted.read().iter().cloned().collect()
+    }
+
+    /// Apply the change between two database snapshots of halted markets
+    ///
+    /// Only markets that entered or left the database set are touched, so
+    /// halts set locally (e.g. by `RiskManager::flatten_all`) survive a sync.
+    pub fn apply_db_change(&self, previous: &HashSet<String>, current: &HashSet<String>) {
+        for condition_id in current.difference(previous) {
+            self.halt(condition_id);
+        }
+        for condition_id in previous.difference(current) {
+            self.resume(condition_id);
+        }
+    }
+
+    /// Mirror the database's `market_halts` into this set every `interval`
+    /// until `shutdown_flag` goes false
+    pub fn spawn_db_sync(
+        &self,
+        database: Arc<MarketDatabase>,
+        interval: Duration,
+        shutdown_flag: Arc<AtomicBool>,
+    ) -> tokio::task::JoinHandle<()> {
+        let halts = self.clone();
+        tokio::spawn(async move {
+            let mut synced = HashSet::new();
+            while shutdown_flag.load(Ordering::Acquire) {
+                match database.get_halted_markets().await {
+                    Ok(current) => {
+                        let current: HashSet<String> = current.into_iter().collect();
+                        halts.apply_db_change(&synced, &current);
+                        synced = current;
+                    }
+                    Err(e) => warn!("Failed to sync market halts: {}", e),
+                }
+                tokio::time::sleep(interval).await;
+            }
+        })
+    }
+}
+
+// =============================================================================
+// Flatten
+// =============================================================================
+
+/// Worst sell price, so a closing FAK order takes whatever bids exist
+const FLATTEN_SELL_PRICE: f64 = 0.01;
+
+/// Positions smaller than this are dust and left alone
+const FLATTEN_MIN_SIZE: f64 = 1e-6;
+
+/// A held position to close
+#[derive(Debug, Clone)]
+pub struct FlattenPosition {
+    pub condition_id: String,
+    pub token_id: String,
+    pub size: f64,
+}
+
+/// Result of closing one position
+#[derive(Debug, Clone)]
+pub struct FlattenResult {
+    pub condition_id: String,
+    pub token_id: String,
+    pub size: f64,
+    /// Order id of the closing order, or the reason it failed
+    pub outcome: Result<String, String>,
+}
+
+/// What `RiskManager::flatten_all` did
+#[derive(Debug, Clone, Default)]
+pub struct FlattenReport {
+    /// Condition ids halted before anything was cancelled
+    pub halted: Vec<String>,
+    /// Order ids cancelled
+    pub cancelled: Vec<String>,
+    /// Orders the exchange refused to cancel (id -> reason)
+    pub not_cancelled: HashMap<String, String>,
+    /// One entry per position a closing order was attempted for
+    pub results: Vec<FlattenResult>,
+}
+
+impl FlattenReport {
+    /// True if every open order was cancelled and every position closed
+    pub fn is_flat(&self) -> bool {
+        self.not_cancelled.is_empty() && self.results.iter().all(|r| r.outcome.is_ok())
+    }
+}
+
 // =============================================================================
 // RiskManagerHandle
 // =============================================================================
@@ -68,6 +213,7 @@ pub struct RiskManagerHandle {
     registry: SharedMarketRegistry,
     oracle_prices: SharedOraclePrices,
     bps_threshold: f64,
+    market_halts: MarketHalts,
 }
 
 impl RiskManagerHandle {
@@ -152,6 +298,33 @@ impl RiskManagerHandle {
         is_safe
     }
 
+    /// Halt placements on a single market (by condition id)
+    pub fn halt_market(&self, condition_id: &str) {
+        self.market_halts.halt(condition_id);
+    }
+
+    /// Resume placements on a halted market
+    pub fn resume_market(&self, condition_id: &str) {
+        self.market_halts.resume(condition_id);
+    }
+
+    /// Check if a market's kill switch is engaged
+    pub fn is_market_halted(&self, condition_id: &str) -> bool {
+        self.market_halts.is_halted(condition_id)
+    }
+
+    /// Shared per-market halt set (for toggling from other components)
+    pub fn market_halts(&self) -> MarketHalts {
+        self.market_halts.clone()
+    }
+
+    /// Consult `halts` (e.g. `StrategyContext::market_halts`) instead of a
+    /// private set
+    pub fn with_market_halts(mut self, halts: MarketHalts) -> Self {
+        self.market_halts = halts;
+        self
+    }
+
     /// Send shutdown signal to the background thread
     pub fn shutdown(&self) {
         let _ = self.tx.send(RiskManagerCommand::Shutdown);
@@ -171,6 +344,112 @@ impl RiskManagerHandle {
 pub struct RiskManager;
 
 impl RiskManager {
+    /// Emergency flatten: halt every affected market, cancel all open
+    /// orders, then close every position with a taker order.
+    ///
+    /// Markets holding a position or an open order are halted in `halts`
+    /// (and in `database`, so the halts survive a restart) before anything
+    /// is cancelled, so nothing re-quotes them until an operator resumes
+    /// them. Fails only if the cancel-all request itself fails; per-position
+    /// failures are reported.
+    pub async fn flatten_all<B: ExecutionBackend + ?Sized>(
+        backend: &B,
+        positions: &[FlattenPosition],
+        halts: &MarketHalts,
+        database: Option<&MarketDatabase>,
+    ) -> Result<FlattenReport, TradingError> {
+        let mut affected: BTreeSet<String> =
+            positions.iter().map(|p| p.condition_id.clone()).collect();
+        match backend.get_orders().await {
+            Ok(orders) => affected.extend(
+                orders
+                    .iter()
+                    .filter_map(|order| order.get("market").and_then(|m| m.as_str()))
+                    .map(str::to_string),
+            ),
+            Err(e) => warn!(
+                "FLATTEN: failed to list open orders, halting position markets only: {}",
+                e
+            ),
+        }
+
+        warn!(
+            "FLATTEN ALL: halting {} markets, cancelling all orders and closing {} positions",
+            affected.len(),
+            positions.len()
+        );
+
+        // Halt first so no strategy re-quotes between the cancel and the closes
+        for condition_id in &affected {
+            halts.halt(condition_id);
+            if let Some(database) = database {
+                if let Err(e) = database.set_market_halted(condition_id, true).await {
+                    error!("FLATTEN: failed to persist halt for {}: {}", condition_id, e);
+                }
+            }
+        }
+
+        let cancel = backend.cancel_all().await?;
+        let mut report = FlattenReport {
+            halted: affected.into_iter().collect(),
+            cancelled: cancel.canceled,
+            not_cancelled: cancel.not_canceled,
+            results: Vec::new(),
+        };
+
+        for position in positions {
+
+            if position.size <= FLATTEN_MIN_SIZE {
+                continue;
+            }
+
+            let outcome = match backend
+                .place_order(
+                    &position.token_id,
+                    FLATTEN_SELL_PRICE,
+                    position.size,
+                    Side::Sell,
+                    OrderType::FAK,
+                )
+                .await
+            {
+                Ok(response) if response.success => {
+                    Ok(response.order_id.unwrap_or_default())
+                }
+                Ok(response) => Err(response
+                    .error_msg
+                    .unwrap_or_else(|| "Unknown error".to_string())),
+                Err(e) => Err(e.to_string()),
+            };
+
+            match &outcome {
+                Ok(order_id) => info!(
+                    "FLATTEN: closed {:.2} of {} in {} ({})",
+                    position.size, position.token_id, position.condition_id, order_id
+                ),
+                Err(e) => error!(
+                    "FLATTEN: failed to close {:.2} of {} in {}: {}",
+                    position.size, position.token_id, position.condition_id, e
+                ),
+            }
+
+            report.results.push(FlattenResult {
+                condition_id: position.condition_id.clone(),
+                token_id: position.token_id.clone(),
+                size: position.size,
+                outcome,
+            });
+        }
+
+        info!(
+            "FLATTEN ALL done: {} orders cancelled, {}/{} positions closed",
+            report.cancelled.len(),
+            report.results.iter().filter(|r| r.outcome.is_ok()).count(),
+            report.results.len()
+        );
+        Ok(report)
+    }
+
     /// Spawn the risk manager on a dedicated OS thread
     ///
     /// Returns a handle for communication and control.
@@ -192,6 +471,7 @@ impl RiskManager {
             registry: registry.clone(),
             oracle_prices: oracle_prices.clone(),
             bps_threshold,
+            market_halts: MarketHalts::new(),
         };
 
         // Spawn background monitoring thread
@@ -386,6 +666,8 @@ fn cancel_orders_for_token(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::infrastructure::client::clob::{CancelResponse, OpenOrder, OrderPlacementResponse};
+    use parking_lot::Mutex;
 
     // Basic compile-time test to ensure types are correct
     #[test]
@@ -393,4 +675,142 @@ mod tests {
         // Just ensure the types are correct
         let _: fn(CryptoAsset) -> Option<&'static str> = |ca| ca.oracle_symbol();
     }
+
+    /// Backend with canned open orders that records every call
+    struct MockFlattenBackend {
+        open_orders: Vec<(String, String)>,
+        halts: MarketHalts,
+        halted_at_cancel: Mutex<Option<Vec<String>>>,
+        closes: Mutex<Vec<(String, f64, Side, OrderType)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl ExecutionBackend for MockFlattenBackend {
+        async fn place_order(
+            &self,
+            token_id: &str,
+            _price: f64,
+            size: f64,
+            side: Side,
+            order_type: OrderType,
+        ) -> Result<OrderPlacementResponse, TradingError> {
+            self.closes.lock().push((token_id.to_string(), size, side, order_type));
+            Ok(OrderPlacementResponse {
+                order_id: Some(format!("close-{}", token_id)),
+                success: true,
+                error_msg: None,
+                status: None,
+                order_hashes: None,
+            })
+        }
+
+        async fn get_orders(&self) -> Result<Vec<OpenOrder>, TradingError> {
+            Ok(self
+                .open_orders
+                .iter()
+                .map(|(id, market)| serde_json::json!({ "id": id, "market": market }))
+                .collect())
+        }
+
+        async fn cancel_all(&self) -> Result<CancelResponse, TradingError> {
+            let mut halted = self.halts.halted_markets();
+            halted.sort();
+            *self.halted_at_cancel.lock() = Some(halted);
+            Ok(CancelResponse {
+                canceled: self.open_orders.iter().map(|(id, _)| id.clone()).collect(),
+                not_canceled: HashMap::new(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flatten_all_halts_then_cancels_then_closes_positions() {
+        let halts = MarketHalts::new();
+        let backend = MockFlattenBackend {
+            // 0xd has a resting order but no position
+            open_orders: vec![
+                ("o1".to_string(), "0xa".to_string()),
+                ("o2".to_string(), "0xd".to_string()),
+            ],
+            halts: halts.clone(),
+            halted_at_cancel: Mutex::new(None),
+            closes: Mutex::new(Vec::new()),
+        };
+        let positions = vec![
+            FlattenPosition {
+                condition_id: "0xa".to_string(),
+                token_id: "a-yes".to_string(),
+                size: 12.5,
+            },
+            FlattenPosition {
+                condition_id: "0xb".to_string(),
+                token_id: "b-no".to_string(),
+                size: 4.0,
+            },
+            FlattenPosition {
+                condition_id: "0xc".to_string(),
+                token_id: "c-yes".to_string(),
+                size: 0.0,
+            },
+        ];
+
+        let report = RiskManager::flatten_all(&backend, &positions, &halts, None)
+            .await
+            .unwrap();
+
+        // Every affected market was already halted when the cancel went out
+        let expected: Vec<String> =
+            ["0xa", "0xb", "0xc", "0xd"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(*backend.halted_at_cancel.lock(), Some(expected.clone()));
+        assert_eq!(report.halted, expected);
+
+        assert_eq!(report.cancelled, vec!["o1".to_string(), "o2".to_string()]);
+        assert_eq!(
+            *backend.closes.lock(),
+            vec![
+                ("a-yes".to_string(), 12.5, Side::Sell, OrderType::FAK),
+                ("b-no".to_string(), 4.0, Side::Sell, OrderType::FAK),
+            ]
+        );
+        assert_eq!(report.results.len(), 2);
+        assert_eq!(report.results[0].outcome, Ok("close-a-yes".to_string()));
+        assert!(report.is_flat());
+    }
+
+    #[test]
+    fn test_halting_one_market_blocks_only_that_market() {
+        let halts = MarketHalts::new();
+        let shared = halts.clone();
+
+        shared.halt("0xbad");
+
+        assert!(halts.is_halted("0xbad"));
+        assert!(!halts.is_halted("0xgood"));
+        assert_eq!(halts.halted_markets(), vec!["0xbad".to_string()]);
+
+        // Toggle back at runtime
+        assert!(!halts.toggle("0xbad"));
+        assert!(!shared.is_halted("0xbad"));
+        assert!(halts.toggle("0xgood"));
+        assert!(shared.is_halted("0xgood"));
+    }
+
+    #[test]
+    fn test_db_sync_only_touches_markets_that_changed_in_the_db() {
+        let halts = MarketHalts::new();
+        let set = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<HashSet<_>>();
+
+        // Halted locally, never in the database
+        halts.halt("0xlocal");
+
+        halts.apply_db_change(&set(&[]), &set(&["0xa", "0xb"]));
+        assert!(halts.is_halted("0xa"));
+        assert!(halts.is_halted("0xb"));
+
+        // Operator resumed 0xa elsewhere
+        halts.apply_db_change(&set(&["0xa", "0xb"]), &set(&["0xb"]));
+        assert!(!halts.is_halted("0xa"));
+        assert!(halts.is_halted("0xb"));
+        assert!(halts.is_halted("0xlocal"));
+    }
 }
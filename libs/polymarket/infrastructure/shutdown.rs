@@ -1,15 +1,127 @@
 //! Graceful shutdown management
+//!
+//! Subsystems register a `ShutdownHook`; `ShutdownManager::run_hooks` runs
+//! them in registration order, times each one and logs a single
+//! `ShutdownReport` summarising what every subsystem did.
 
+use super::client::clob::TradingClient;
+use super::database::MarketDatabase;
+use parking_lot::Mutex;
+use std::fmt;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::signal;
 use tokio::time::sleep;
-use tracing::info;
+use tracing::{info, warn};
+
+/// Outcome of one subsystem's shutdown
+#[derive(Debug, Clone, PartialEq)]
+pub enum HookStatus {
+    /// Finished; the detail says what was done (e.g. "3 orders canceled")
+    Completed(String),
+    Failed(String),
+}
+
+/// A subsystem that needs to clean up on shutdown
+#[async_trait::async_trait]
+pub trait ShutdownHook: Send + Sync {
+    /// Subsystem name shown in the report
+    fn name(&self) -> &str;
+
+    async fn shutdown(&self) -> HookStatus;
+}
+
+#[async_trait::async_trait]
+impl ShutdownHook for MarketDatabase {
+    fn name(&self) -> &str {
+        "database"
+    }
+
+    async fn shutdown(&self) -> HookStatus {
+        self.pool().close().await;
+        HookStatus::Completed("pool closed".to_string())
+    }
+}
+
+/// Cancels the open orders this client placed
+///
+/// Orders on the account placed by other processes are left alone.
+#[async_trait::async_trait]
+impl ShutdownHook for TradingClient {
+    fn name(&self) -> &str {
+        "orders"
+    }
+
+    async fn shutdown(&self) -> HookStatus {
+        match self.cancel_placed_orders().await {
+            Ok(response) if response.not_canceled.is_empty() => {
+                HookStatus::Completed(format!("{} orders canceled", response.canceled.len()))
+            }
+            Ok(response) => HookStatus::Failed(format!(
+                "{} orders canceled, {} not canceled",
+                response.canceled.len(),
+                response.not_canceled.len()
+            )),
+            Err(e) => HookStatus::Failed(e.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubsystemReport {
+    pub name: String,
+    pub status: HookStatus,
+    pub elapsed: Duration,
+}
+
+/// Aggregated result of all shutdown hooks, in the order they ran
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShutdownReport {
+    pub subsystems: Vec<SubsystemReport>,
+    pub total: Duration,
+}
+
+impl ShutdownReport {
+    pub fn all_completed(&self) -> bool {
+        self.subsystems
+            .iter()
+            .all(|s| matches!(s.status, HookStatus::Completed(_)))
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &SubsystemReport> {
+        self.subsystems
+            .iter()
+            .filter(|s| matches!(s.status, HookStatus::Failed(_)))
+    }
+}
+
+impl fmt::Display for ShutdownReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "shutdown finished in {:?} ({} subsystems)",
+            self.total,
+            self.subsystems.len()
+        )?;
+        for s in &self.subsystems {
+            match &s.status {
+                HookStatus::Completed(detail) => {
+                    write!(f, "\n  {}: ok, {} ({:?})", s.name, detail, s.elapsed)?
+                }
+                HookStatus::Failed(reason) => {
+                    write!(f, "\n  {}: FAILED, {} ({:?})", s.name, reason, s.elapsed)?
+                }
+            }
+        }
+        Ok(())
+    }
+}
 
 /// Manages graceful shutdown for long-running processes
 pub struct ShutdownManager {
     flag: Arc<AtomicBool>,
+    hooks: Mutex<Vec<Arc<dyn ShutdownHook>>>,
 }
 
 impl ShutdownManager {
@@ -17,7 +129,44 @@ impl ShutdownManager {
     pub fn new() -> Self {
         Self {
             flag: Arc::new(AtomicBool::new(true)),
+            hooks: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Register a subsystem to be shut down by `run_hooks`.
+    ///
+    /// Hooks run in registration order, so register things that depend on
+    /// others (e.g. order cancellation) before what they depend on (the
+    /// connection or pool).
+    pub fn register_hook(&self, hook: Arc<dyn ShutdownHook>) {
+        self.hooks.lock().push(hook);
+    }
+
+    /// Run every registered hook once, then log and return the report.
+    ///
+    /// Hooks are drained, so a second call runs nothing.
+    pub async fn run_hooks(&self) -> ShutdownReport {
+        let hooks = std::mem::take(&mut *self.hooks.lock());
+        let start = Instant::now();
+        let mut report = ShutdownReport::default();
+
+        for hook in hooks {
+            let hook_start = Instant::now();
+            let status = hook.shutdown().await;
+            report.subsystems.push(SubsystemReport {
+                name: hook.name().to_string(),
+                status,
+                elapsed: hook_start.elapsed(),
+            });
         }
+        report.total = start.elapsed();
+
+        if report.all_completed() {
+            info!("{}", report);
+        } else {
+            warn!("{}", report);
+        }
+        report
     }
 
     /// Spawn a Ctrl+C signal handler that triggers shutdown
@@ -60,3 +209,60 @@ impl Default for ShutdownManager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestHook {
+        name: &'static str,
+        status: HookStatus,
+        delay: Duration,
+    }
+
+    #[async_trait::async_trait]
+    impl ShutdownHook for TestHook {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn shutdown(&self) -> HookStatus {
+            sleep(self.delay).await;
+            self.status.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_hooks_aggregates_report() {
+        let manager = ShutdownManager::new();
+        manager.register_hook(Arc::new(TestHook {
+            name: "orders",
+            status: HookStatus::Completed("2 orders canceled".to_string()),
+            delay: Duration::from_millis(20),
+        }));
+        manager.register_hook(Arc::new(TestHook {
+            name: "websocket",
+            status: HookStatus::Failed("close frame timed out".to_string()),
+            delay: Duration::ZERO,
+        }));
+
+        let report = manager.run_hooks().await;
+
+        let names: Vec<_> = report.subsystems.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["orders", "websocket"]);
+        assert_eq!(
+            report.subsystems[0].status,
+            HookStatus::Completed("2 orders canceled".to_string())
+        );
+        assert!(report.subsystems[0].elapsed >= Duration::from_millis(20));
+        assert!(report.total >= report.subsystems[0].elapsed);
+
+        assert!(!report.all_completed());
+        let failed: Vec<_> = report.failures().map(|s| s.name.as_str()).collect();
+        assert_eq!(failed, ["websocket"]);
+        assert!(report.to_string().contains("websocket: FAILED, close frame timed out"));
+
+        // Hooks only run once
+        assert!(manager.run_hooks().await.subsystems.is_empty());
+    }
+}
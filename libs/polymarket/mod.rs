@@ -9,3 +9,13 @@
 pub mod application;
 pub mod domain;
 pub mod infrastructure;
+
+/// Mock CLOB server shared with the integration tests
+#[cfg(test)]
+#[path = "tests/common/mock_clob.rs"]
+mod mock_clob;
+
+/// Mock market-channel WebSocket server shared with the integration tests
+#[cfg(test)]
+#[path = "tests/common/mock_market_ws.rs"]
+mod mock_market_ws;
@@ -0,0 +1,306 @@
+//! Mock CLOB REST server for integration tests
+//!
+//! Serves the endpoints `RestClient` talks to with in-memory state:
+//! `GET /markets`, `GET /book`, `GET /neg-risk`, `GET /data/orders`, `POST /order`,
+//! `POST /orders`, `DELETE /order`, `DELETE /orders` and `DELETE /cancel-all`. Placed orders
+//! stay open until cancelled, so place/cancel round trips behave like the
+//! real exchange. Any `(method, path)` can be overridden with a fixed
+//! response or a sequence of responses, and every request is recorded for
+//! assertions.
+//!
+//! The library's unit tests include this file as `crate::mock_clob`.
+
+// Not every test binary that declares `mod common` uses the mock server
+#![allow(dead_code)]
+
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::sync::Notify;
+
+/// Fixed response returned for an overridden endpoint
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    pub body: String,
+    /// Extra headers besides `Content-Type: application/json`
+    pub headers: Vec<(String, String)>,
+}
+
+impl MockResponse {
+    pub fn json(status: u16, body: Value) -> Self {
+        Self::text(status, body.to_string())
+    }
+
+    /// Response with a raw body, e.g. malformed JSON
+    pub fn text(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: body.into(),
+            headers: Vec::new(),
+        }
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// A request as seen by the server
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: Method,
+    pub path: String,
+    pub query: Option<String>,
+    /// Header names are lowercase
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_ascii_lowercase()).map(String::as_str)
+    }
+}
+
+#[derive(Default)]
+struct MockState {
+    /// Fixed response and how many requests the built-in handler serves first
+    overrides: HashMap<(Method, String), (MockResponse, usize)>,
+    /// Responses served once each, in order, ahead of any override
+    sequences: HashMap<(Method, String), VecDeque<MockResponse>>,
+    requests: Vec<RecordedRequest>,
+    markets: Vec<Value>,
+    books: HashMap<String, Value>,
+    neg_risk: HashMap<String, bool>,
+    open_orders: BTreeSet<String>,
+    next_order: u64,
+}
+
+pub struct MockClobServer {
+    pub addr: SocketAddr,
+    state: Arc<Mutex<MockState>>,
+    connections: Arc<AtomicUsize>,
+    shutdown: Arc<Notify>,
+}
+
+impl MockClobServer {
+    /// Start the server on an ephemeral localhost port
+    pub async fn start() -> Self {
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let connections = Arc::new(AtomicUsize::new(0));
+        let shutdown = Arc::new(Notify::new());
+
+        let service_state = Arc::clone(&state);
+        let accepted = Arc::clone(&connections);
+        let make_svc = make_service_fn(move |_| {
+            let state = Arc::clone(&service_state);
+            accepted.fetch_add(1, Ordering::SeqCst);
+            async move {
+                Ok::<_, Infallible>(service_fn(move |req| {
+                    let state = Arc::clone(&state);
+                    async move { Ok::<_, Infallible>(Self::handle(req, &state).await) }
+                }))
+            }
+        });
+
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_svc);
+        let addr = server.local_addr();
+        let signal = Arc::clone(&shutdown);
+        tokio::spawn(server.with_graceful_shutdown(async move { signal.notified().await }));
+
+        Self {
+            addr,
+            state,
+            connections,
+            shutdown,
+        }
+    }
+
+    /// Base URL to hand to `RestClient::new`
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Entries returned by `GET /markets` (simplified market JSON)
+    pub fn set_markets(&self, markets: Vec<Value>) {
+        self.state.lock().markets = markets;
+    }
+
+    /// Book returned by `GET /book?token_id=...`; unknown tokens get an empty book
+    pub fn set_book(&self, token_id: &str, book: Value) {
+        self.state.lock().books.insert(token_id.to_string(), book);
+    }
+
+    /// Value returned by `GET /neg-risk?token_id=...`; defaults to false
+    pub fn set_neg_risk(&self, token_id: &str, neg_risk: bool) {
+        self.state.lock().neg_risk.insert(token_id.to_string(), neg_risk);
+    }
+
+    /// Always answer `method path` with `response`, bypassing the built-in handler
+    pub fn respond(&self, method: Method, path: &str, response: MockResponse) {
+        self.respond_after(method, path, 0, response);
+    }
+
+    /// Serve the first `calls` requests to `method path` normally, then answer with `response`
+    pub fn respond_after(&self, method: Method, path: &str, calls: usize, response: MockResponse) {
+        self.state
+            .lock()
+            .overrides
+            .insert((method, path.to_string()), (response, calls));
+    }
+
+    /// Answer the next requests to `method path` with `responses` in order,
+    /// then fall back to any fixed response or the built-in handler
+    pub fn respond_sequence(&self, method: Method, path: &str, responses: Vec<MockResponse>) {
+        self.state
+            .lock()
+            .sequences
+            .insert((method, path.to_string()), responses.into());
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.lock().requests.clone()
+    }
+
+    /// TCP connections accepted so far (keep-alive requests share one)
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+
+    /// IDs of orders placed and not yet cancelled
+    pub fn open_orders(&self) -> Vec<String> {
+        self.state.lock().open_orders.iter().cloned().collect()
+    }
+
+    async fn handle(req: Request<Body>, state: &Mutex<MockState>) -> Response<Body> {
+        let (parts, body) = req.into_parts();
+        let body = hyper::body::to_bytes(body).await.unwrap_or_default();
+        let request = RecordedRequest {
+            method: parts.method,
+            path: parts.uri.path().to_string(),
+            query: parts.uri.query().map(str::to_string),
+            headers: parts
+                .headers
+                .iter()
+                .map(|(k, v)| (k.as_str().to_string(), v.to_str().unwrap_or_default().to_string()))
+                .collect(),
+            body: String::from_utf8_lossy(&body).into_owned(),
+        };
+
+        let mut state = state.lock();
+        let key = (request.method.clone(), request.path.clone());
+        let queued = state.sequences.get_mut(&key).and_then(VecDeque::pop_front);
+        let fixed = queued.or_else(|| match state.overrides.get_mut(&key) {
+            Some((fixed, 0)) => Some(fixed.clone()),
+            Some((_, calls)) => {
+                *calls -= 1;
+                None
+            }
+            None => None,
+        });
+        let response = match fixed {
+            Some(fixed) => fixed,
+            None => {
+                let (status, body) = Self::route(&mut state, &request);
+                MockResponse::json(status, body)
+            }
+        };
+        state.requests.push(request);
+
+        let mut builder = Response::builder()
+            .status(StatusCode::from_u16(response.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR))
+            .header("Content-Type", "application/json");
+        for (name, value) in &response.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        builder.body(Body::from(response.body)).unwrap()
+    }
+
+    fn route(state: &mut MockState, req: &RecordedRequest) -> (u16, Value) {
+        let token_id = req.query.as_deref().and_then(|q| query_param(q, "token_id"));
+
+        match (&req.method, req.path.as_str()) {
+            (&Method::GET, "/markets") => (200, Value::Array(state.markets.clone())),
+            (&Method::GET, "/book") => {
+                let token_id = token_id.unwrap_or_default();
+                let book = state.books.get(&token_id).cloned().unwrap_or_else(|| {
+                    json!({ "market": "", "asset_id": token_id, "bids": [], "asks": [] })
+                });
+                (200, book)
+            }
+            (&Method::GET, "/neg-risk") => {
+                let neg_risk = token_id
+                    .and_then(|id| state.neg_risk.get(&id).copied())
+                    .unwrap_or(false);
+                (200, json!({ "neg_risk": neg_risk }))
+            }
+            (&Method::GET, "/data/orders") => {
+                let data: Vec<Value> = state.open_orders.iter().map(|id| json!({ "id": id })).collect();
+                (200, json!({ "data": data, "next_cursor": "LTE=" }))
+            }
+            (&Method::POST, "/order") => (200, Self::place(state)),
+            (&Method::POST, "/orders") => {
+                let count = serde_json::from_str::<Vec<Value>>(&req.body).map_or(0, |o| o.len());
+                let placed = (0..count).map(|_| Self::place(state)).collect();
+                (200, Value::Array(placed))
+            }
+            (&Method::DELETE, "/order") => {
+                let order_id = serde_json::from_str::<Value>(&req.body)
+                    .ok()
+                    .and_then(|v| v["orderID"].as_str().map(str::to_string))
+                    .unwrap_or_default();
+                (200, Self::cancel(state, vec![order_id]))
+            }
+            (&Method::DELETE, "/orders") => {
+                let ids = serde_json::from_str::<Vec<String>>(&req.body).unwrap_or_default();
+                (200, Self::cancel(state, ids))
+            }
+            (&Method::DELETE, "/cancel-all") => {
+                let ids = state.open_orders.iter().cloned().collect();
+                (200, Self::cancel(state, ids))
+            }
+            _ => (404, json!({ "error": "not found" })),
+        }
+    }
+
+    fn place(state: &mut MockState) -> Value {
+        state.next_order += 1;
+        let order_id = format!("0xmock{:04}", state.next_order);
+        state.open_orders.insert(order_id.clone());
+        json!({ "orderID": order_id, "success": true, "status": "live" })
+    }
+
+    fn cancel(state: &mut MockState, ids: Vec<String>) -> Value {
+        let mut canceled = Vec::new();
+        let mut not_canceled = serde_json::Map::new();
+        for id in ids {
+            if state.open_orders.remove(&id) {
+                canceled.push(id);
+            } else {
+                not_canceled.insert(id, json!("order not found"));
+            }
+        }
+        json!({ "canceled": canceled, "not_canceled": not_canceled })
+    }
+}
+
+impl Drop for MockClobServer {
+    fn drop(&mut self) {
+        self.shutdown.notify_one();
+    }
+}
+
+fn query_param(query: &str, name: &str) -> Option<String> {
+    query.split('&').find_map(|pair| {
+        let (key, value) = pair.split_once('=')?;
+        (key == name).then(|| value.to_string())
+    })
+}
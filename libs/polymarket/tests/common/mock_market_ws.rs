@@ -0,0 +1,86 @@
+//! Mock market-channel WebSocket server for tests
+//!
+//! Answers `PING` with `PONG` and every other text frame (a subscription)
+//! with the next scripted reply, the last reply repeating.
+//!
+//! The library's unit tests include this file as `crate::mock_market_ws`.
+
+// Not every test binary that declares `mod common` uses the mock server
+#![allow(dead_code)]
+
+use futures::{SinkExt, StreamExt};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::Message;
+
+/// One two-sided book snapshot for asset `yes`
+pub const SNAPSHOT: &str = r#"[{"market":"0xabc","asset_id":"yes","bids":[{"price":"0.45","size":"10"}],"asks":[{"price":"0.55","size":"10"}],"event_type":"book"}]"#;
+
+pub struct MockMarketWsServer {
+    pub addr: SocketAddr,
+    subscriptions: Arc<AtomicUsize>,
+    accept_task: JoinHandle<()>,
+}
+
+impl MockMarketWsServer {
+    /// Answer every subscription with `SNAPSHOT`
+    pub async fn start() -> Self {
+        Self::scripted(vec![SNAPSHOT]).await
+    }
+
+    /// Answer the nth subscription with `replies[n]` (the last reply repeats)
+    pub async fn scripted(replies: Vec<&'static str>) -> Self {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let subscriptions = Arc::new(AtomicUsize::new(0));
+
+        let count = Arc::clone(&subscriptions);
+        let accept_task = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let replies = replies.clone();
+                let count = Arc::clone(&count);
+                tokio::spawn(async move {
+                    let Ok(mut ws) = tokio_tungstenite::accept_async(stream).await else {
+                        return;
+                    };
+                    while let Some(Ok(msg)) = ws.next().await {
+                        let Message::Text(text) = msg else { continue };
+                        let reply = if text == "PING" {
+                            "PONG".to_string()
+                        } else {
+                            let n = count.fetch_add(1, Ordering::SeqCst);
+                            replies[n.min(replies.len() - 1)].to_string()
+                        };
+                        if ws.send(Message::Text(reply)).await.is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        Self {
+            addr,
+            subscriptions,
+            accept_task,
+        }
+    }
+
+    /// URL to hand to the WebSocket client
+    pub fn url(&self) -> String {
+        format!("ws://{}", self.addr)
+    }
+
+    /// Subscription messages received so far, across connections
+    pub fn subscriptions(&self) -> usize {
+        self.subscriptions.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for MockMarketWsServer {
+    fn drop(&mut self) {
+        self.accept_task.abort();
+    }
+}
@@ -2,6 +2,9 @@
 //!
 //! This module provides shared utilities, fixtures, and helpers for testing.
 
+pub mod mock_clob;
+pub mod mock_market_ws;
+
 /// Macro for verbose test output (controlled by TEST_VERBOSE env var)
 #[macro_export]
 macro_rules! verbose_println {
@@ -0,0 +1,473 @@
+//! REST client tests against the in-process mock CLOB server
+//!
+//! Unlike `integration_clob`, these need no credentials or network access:
+//!
+//! ```bash
+//! cargo test -p polymarket --test integration_mock_clob
+//! ```
+
+mod common;
+
+use common::mock_clob::{MockClobServer, MockResponse};
+use hyper::Method;
+use polymarket::application::strategies::inventory_mm::{Executor, LimitOrder, SolverOutput};
+use polymarket::domain::RiskError;
+use polymarket::infrastructure::client::clob::types::{ApiCredentials, SignatureType};
+use polymarket::infrastructure::client::clob::rest::{
+    EndpointClass, HttpPoolConfig, RateLimiter, RestError,
+};
+use polymarket::infrastructure::client::{
+    OrderArgs, OrderType, PolymarketAuth, RestClient, Side, TradingClient, TradingError,
+};
+use polymarket::infrastructure::shutdown::{HookStatus, ShutdownManager};
+use polymarket::infrastructure::MarketHalts;
+use serde_json::json;
+use std::sync::Arc;
+
+fn test_auth() -> PolymarketAuth {
+    PolymarketAuth::from_api_credentials(test_credentials())
+}
+
+fn test_credentials() -> ApiCredentials {
+    ApiCredentials {
+        key: "test-key".to_string(),
+        // URL-safe base64 of "test-secret"
+        secret: "dGVzdC1zZWNyZXQ=".to_string(),
+        passphrase: "test-passphrase".to_string(),
+    }
+}
+
+/// `TradingClient` against `server`, with the clock and nonce endpoints answered
+async fn trading_client(server: &MockClobServer) -> TradingClient {
+    trading_client_with_pool(server, HttpPoolConfig::default()).await
+}
+
+async fn trading_client_with_pool(server: &MockClobServer, pool: HttpPoolConfig) -> TradingClient {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    server.respond(Method::GET, "/time", MockResponse::json(200, json!(now)));
+    server.respond(Method::GET, "/nonce", MockResponse::json(200, json!({ "nonce": "0" })));
+
+    TradingClient::with_pool_config(
+        "0x257091039adf0d3df1f3171508f7db838782ee9b4f6ad61054be773e7541d90a",
+        None,
+        &server.url(),
+        Some(test_credentials()),
+        pool,
+    )
+    .await
+    .unwrap()
+}
+
+fn buy_args(token_id: &str) -> OrderArgs {
+    OrderArgs {
+        token_id: token_id.to_string(),
+        price: 0.45,
+        size: 10.0,
+        side: Side::Buy,
+        fee_rate_bps: None,
+        nonce: None,
+        expiration: None,
+        post_only: false,
+    }
+}
+
+#[tokio::test]
+async fn test_place_then_cancel_order() {
+    let server = MockClobServer::start().await;
+    let client = RestClient::new(server.url());
+    let auth = test_auth();
+
+    let placed = client
+        .place_order(&auth, &buy_args("token-1"), OrderType::GTC)
+        .await
+        .unwrap();
+    assert!(placed.success);
+    assert_eq!(server.open_orders(), vec![placed.order_id.clone()]);
+
+    let canceled = client.cancel_order(&auth, &placed.order_id).await.unwrap();
+    assert_eq!(canceled.canceled, vec![placed.order_id.clone()]);
+    assert!(server.open_orders().is_empty());
+
+    // A second cancel finds nothing open
+    let again = client.cancel_order(&auth, &placed.order_id).await.unwrap();
+    assert!(again.canceled.is_empty());
+    assert!(again.not_canceled.contains_key(&placed.order_id));
+
+    // Both calls carried L2 auth headers
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].method, Method::POST);
+    assert_eq!(requests[1].method, Method::DELETE);
+    for req in &requests {
+        assert_eq!(req.path, "/order");
+        assert_eq!(req.header("POLY_API_KEY"), Some("test-key"));
+        assert!(req.header("POLY_SIGNATURE").is_some());
+    }
+}
+
+#[tokio::test]
+async fn test_market_data_endpoints() {
+    let server = MockClobServer::start().await;
+    let client = RestClient::new(server.url());
+
+    server.set_markets(vec![json!({
+        "condition_id": "0xcond",
+        "question": "Will it rain?",
+        "end_date_iso": "2099-01-01T00:00:00Z",
+        "tokens": [
+            { "token_id": "yes-token", "outcome": "Yes" },
+            { "token_id": "no-token", "outcome": "No" }
+        ],
+        "active": true,
+        "closed": false
+    })]);
+    server.set_book(
+        "yes-token",
+        json!({
+            "market": "0xcond",
+            "asset_id": "yes-token",
+            "bids": [{ "price": "0.40", "size": "100" }],
+            "asks": [{ "price": "0.42", "size": "50" }]
+        }),
+    );
+    server.set_neg_risk("yes-token", true);
+
+    let markets = client.get_markets().await.unwrap();
+    assert_eq!(markets.len(), 1);
+    assert_eq!(markets[0].outcomes.len(), 2);
+
+    let book = client.get_orderbook("yes-token").await.unwrap();
+    assert_eq!(book.asks[0].price_f64(), 0.42);
+    assert!(client.get_orderbook("other").await.unwrap().bids.is_empty());
+
+    assert!(client.get_neg_risk("yes-token").await.unwrap());
+    assert!(!client.get_neg_risk("no-token").await.unwrap());
+}
+
+#[tokio::test]
+async fn test_overridden_response_surfaces_as_error() {
+    let server = MockClobServer::start().await;
+    let client = RestClient::new(server.url());
+    server.respond(
+        Method::POST,
+        "/order",
+        MockResponse::json(400, json!({ "error": "not enough balance" })),
+    );
+
+    let err = client
+        .place_order(&test_auth(), &buy_args("token-1"), OrderType::GTC)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("not enough balance"));
+    assert!(server.open_orders().is_empty());
+}
+
+#[tokio::test]
+async fn test_post_only_flag_is_sent() {
+    let server = MockClobServer::start().await;
+    let client = RestClient::new(server.url());
+
+    let args = OrderArgs {
+        post_only: true,
+        ..buy_args("token-1")
+    };
+    client.place_order(&test_auth(), &args, OrderType::GTC).await.unwrap();
+    client
+        .place_order(&test_auth(), &buy_args("token-2"), OrderType::GTC)
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+    assert_eq!(body["postOnly"], true);
+    assert!(body["order"].get("post_only").is_none());
+    let body: serde_json::Value = serde_json::from_str(&requests[1].body).unwrap();
+    assert!(body.get("postOnly").is_none());
+}
+
+#[tokio::test]
+async fn test_post_only_rejection_is_recognized() {
+    let server = MockClobServer::start().await;
+    let client = RestClient::new(server.url());
+    server.respond(
+        Method::POST,
+        "/order",
+        MockResponse::json(400, json!({ "error": "invalid post-only order: order crosses book" })),
+    );
+
+    let args = OrderArgs {
+        price: 0.55,
+        post_only: true,
+        ..buy_args("token-1")
+    };
+    let err = client
+        .place_order(&test_auth(), &args, OrderType::GTC)
+        .await
+        .unwrap_err();
+    assert!(err.is_post_only_rejection());
+    assert_eq!(err.status(), Some(400));
+    assert!(server.open_orders().is_empty());
+
+    // Other rejections are not mistaken for it
+    server.respond(
+        Method::POST,
+        "/order",
+        MockResponse::json(400, json!({ "error": "not enough balance" })),
+    );
+    let err = client
+        .place_order(&test_auth(), &args, OrderType::GTC)
+        .await
+        .unwrap_err();
+    assert!(!err.is_post_only_rejection());
+}
+
+#[tokio::test]
+async fn test_trading_client_uses_pool_config() {
+    let server = MockClobServer::start().await;
+    let pool = HttpPoolConfig::default()
+        .with_max_idle_per_host(4)
+        .with_idle_timeout(std::time::Duration::from_secs(30));
+
+    let client = trading_client_with_pool(&server, pool).await;
+    assert_eq!(client.rest().pool_config(), pool);
+}
+
+#[tokio::test]
+async fn test_trading_client_order_rate_limit_spaces_out_orders() {
+    let server = MockClobServer::start().await;
+    let client = trading_client(&server)
+        .await
+        .with_rate_limiter(EndpointClass::Order, RateLimiter::new(1, 20.0));
+
+    let start = std::time::Instant::now();
+    for _ in 0..3 {
+        client.buy("111", 0.45, 10.0).await.unwrap();
+    }
+
+    // One permit up front, then one every 50ms
+    assert!(start.elapsed() >= std::time::Duration::from_millis(100));
+    assert_eq!(server.open_orders().len(), 3);
+}
+
+#[tokio::test]
+async fn test_gtd_orders_carry_their_expiration() {
+    let server = MockClobServer::start().await;
+    let client = trading_client(&server).await;
+    let expiration = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 3600;
+
+    client
+        .place_gtd_order("111", 0.45, 10.0, Side::Buy, expiration)
+        .await
+        .unwrap();
+    client
+        .order("111")
+        .price(0.40)
+        .size(10.0)
+        .buy()
+        .gtd(expiration)
+        .post_only()
+        .execute()
+        .await
+        .unwrap();
+
+    let posted: Vec<serde_json::Value> = server
+        .requests()
+        .iter()
+        .filter(|r| r.method == Method::POST && r.path == "/order")
+        .map(|r| serde_json::from_str(&r.body).unwrap())
+        .collect();
+    assert_eq!(posted.len(), 2);
+    for body in &posted {
+        assert_eq!(body["orderType"], "GTD");
+        assert_eq!(body["order"]["expiration"], expiration.to_string());
+    }
+    assert_eq!(posted[1]["postOnly"], true);
+
+    // An expiration in the past never reaches the exchange
+    assert!(client.place_gtd_order("111", 0.45, 10.0, Side::Buy, 1).await.is_err());
+    assert_eq!(server.open_orders().len(), 2);
+}
+
+#[tokio::test]
+async fn test_daily_order_cap_rejects_before_placing() {
+    let server = MockClobServer::start().await;
+    let client = trading_client(&server).await.with_daily_order_limit(3);
+
+    client.buy("111", 0.45, 10.0).await.unwrap();
+    client.sell("111", 0.55, 10.0).await.unwrap();
+
+    // A batch that would overshoot the cap is rejected whole
+    let batch = vec![
+        ("111".to_string(), 0.40, 10.0, Side::Buy, OrderType::GTC),
+        ("222".to_string(), 0.40, 10.0, Side::Buy, OrderType::GTC),
+    ];
+    let err = client.place_batch_orders(batch, None).await.unwrap_err();
+    assert!(matches!(
+        err,
+        TradingError::RiskLimit(RiskError::DailyOrderLimitReached(3))
+    ));
+
+    client.buy("222", 0.45, 10.0).await.unwrap();
+    let err = client.buy("333", 0.45, 10.0).await.unwrap_err();
+    assert!(matches!(
+        err,
+        TradingError::RiskLimit(RiskError::DailyOrderLimitReached(3))
+    ));
+
+    // Only the admitted orders reached the exchange
+    assert_eq!(server.open_orders().len(), 3);
+    let posted = server
+        .requests()
+        .iter()
+        .filter(|r| r.method == Method::POST)
+        .count();
+    assert_eq!(posted, 3);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_inventory_mm_executor_rejects_orders_on_a_halted_market() {
+    let server = MockClobServer::start().await;
+    let trading = Arc::new(trading_client(&server).await);
+    let halts = MarketHalts::new();
+    halts.halt("0xcondition");
+
+    let executor = Executor::spawn_with_rate_limit(
+        trading,
+        None,
+        None,
+        None,
+        Some((halts.clone(), "0xcondition".to_string())),
+        None,
+        None,
+    );
+    let handle = executor.quoter_handle();
+    let quote = || SolverOutput {
+        cancellations: vec![],
+        limit_orders: vec![LimitOrder::buy("111".to_string(), 0.45, 10.0)],
+    };
+
+    let executor_handle = handle.clone();
+    let halted = tokio::task::spawn_blocking(move || executor_handle.execute_with_feedback(quote()))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(halted.placed_count, 0);
+    assert_eq!(halted.errors.len(), 1);
+    assert_eq!(halted.errors[0].0, "halted");
+    assert!(server.requests().iter().all(|r| r.method != Method::POST));
+
+    halts.resume("0xcondition");
+    let resumed = tokio::task::spawn_blocking(move || handle.execute_with_feedback(quote()))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(resumed.placed_count, 1);
+    assert_eq!(server.open_orders().len(), 1);
+
+    tokio::task::spawn_blocking(move || executor.shutdown()).await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn test_daily_order_cap_counts_orders_placed_before_restart() {
+    let server = MockClobServer::start().await;
+    let client = trading_client(&server)
+        .await
+        .with_daily_order_limit(3)
+        .with_orders_placed_today(2);
+
+    client.buy("111", 0.45, 10.0).await.unwrap();
+    let err = client.buy("222", 0.45, 10.0).await.unwrap_err();
+    assert!(matches!(
+        err,
+        TradingError::RiskLimit(RiskError::DailyOrderLimitReached(3))
+    ));
+    assert_eq!(server.open_orders().len(), 1);
+}
+
+#[tokio::test]
+async fn test_failed_batch_chunk_reports_orders_already_placed() {
+    let server = MockClobServer::start().await;
+    let client = trading_client(&server).await;
+    server.respond_after(
+        Method::POST,
+        "/orders",
+        1,
+        MockResponse::json(500, json!({ "error": "internal error" })),
+    );
+
+    // 20 orders go out as a batch of 15 and a batch of 5; the second fails
+    let batch = (0..20)
+        .map(|i| (format!("{}", 100 + i), 0.40, 10.0, Side::Buy, OrderType::GTC))
+        .collect();
+    let err = client.place_batch_orders(batch, None).await.unwrap_err();
+
+    match err {
+        TradingError::RestError(RestError::PartialBatch { placed, source }) => {
+            assert_eq!(placed.len(), 15);
+            assert!(placed.iter().all(|r| r.success));
+            assert_eq!(source.status(), Some(500));
+        }
+        other => panic!("expected PartialBatch, got {:?}", other),
+    }
+    assert_eq!(server.open_orders().len(), 15);
+}
+
+#[tokio::test]
+async fn test_shutdown_hook_cancels_only_orders_it_placed() {
+    let server = MockClobServer::start().await;
+    let trading = trading_client(&server).await;
+    for token_id in ["111", "222"] {
+        assert!(trading.buy(token_id, 0.45, 10.0).await.unwrap().success);
+    }
+    // Placed on the same account by another process
+    let foreign = RestClient::new(server.url())
+        .place_order(&test_auth(), &buy_args("333"), OrderType::GTC)
+        .await
+        .unwrap()
+        .order_id;
+    assert_eq!(server.open_orders().len(), 3);
+
+    let shutdown = ShutdownManager::new();
+    shutdown.register_hook(Arc::new(trading));
+    let report = shutdown.run_hooks().await;
+
+    assert_eq!(report.subsystems.len(), 1);
+    assert_eq!(report.subsystems[0].name, "orders");
+    assert_eq!(
+        report.subsystems[0].status,
+        HookStatus::Completed("2 orders canceled".to_string())
+    );
+    assert_eq!(server.open_orders(), vec![foreign]);
+    assert!(!server.requests().iter().any(|r| r.path == "/cancel-all"));
+}
+
+#[tokio::test]
+async fn test_usd_balance_uses_client_signature_type() {
+    let server = MockClobServer::start().await;
+    server.respond(
+        Method::GET,
+        "/balance-allowance",
+        MockResponse::json(200, json!({ "balance": "12500000", "allowance": "0" })),
+    );
+    let client = trading_client(&server)
+        .await
+        .with_signature_type(SignatureType::Eoa);
+
+    assert_eq!(client.get_usd_balance().await.unwrap(), 12.5);
+
+    let request = server
+        .requests()
+        .into_iter()
+        .find(|r| r.path == "/balance-allowance")
+        .unwrap();
+    let query = request.query.unwrap();
+    assert!(query.contains("asset_type=COLLATERAL"), "{}", query);
+    assert!(query.contains("signature_type=0"), "{}", query);
+}
@@ -10,16 +10,13 @@ use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{self, EnableMouseCapture, Event, KeyCode, KeyEventKind},
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal::{enable_raw_mode, EnterAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
 
-use polymarket::application::visualizer::{ui, App};
-
-/// Interval for auto-refreshing markets (check for new orders/markets)
-const MARKET_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+use polymarket::application::visualizer::{terminal, ui, App, PromptAction, VisualizerConfig};
 
 fn main() -> Result<()> {
     // Load environment variables
@@ -32,32 +29,32 @@ fn main() -> Result<()> {
         .or_else(|_| std::env::var("DATABASE_URL"))
         .map_err(|_| anyhow::anyhow!("VISUALIZER_DATABASE_URL or DATABASE_URL environment variable is required"))?;
 
+    // Refresh intervals (VISUALIZER_REFRESH_MS, VISUALIZER_MARKET_REFRESH_SECS)
+    let config = VisualizerConfig::from_env();
+
     // Create tokio runtime
     let runtime = tokio::runtime::Runtime::new()?;
 
     // Initialize the app (connects to database, WebSockets, etc.)
     let mut app = runtime.block_on(async {
-        App::initialize(runtime.handle().clone(), &database_url).await
+        App::initialize(runtime.handle().clone(), &database_url, config).await
     })?;
 
+    // Restore the terminal if anything panics while we own it
+    terminal::install_panic_hook();
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+    let mut tui = Terminal::new(backend)?;
 
     // Run the main loop
-    let result = run_app(&mut terminal, &mut app);
+    let result = run_app(&mut tui, &mut app);
 
     // Restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    terminal::restore_terminal()?;
 
     // Shutdown app
     app.shutdown();
@@ -74,13 +71,20 @@ fn run_app<B: ratatui::backend::Backend>(
     app: &mut App,
 ) -> Result<()> {
     let mut last_market_refresh = Instant::now();
+    let mut last_tick = Instant::now();
 
     loop {
+        // Auto-refresh tick: recompute header counts and P&L even when quiet
+        if last_tick.elapsed() >= app.config.refresh_interval {
+            app.tick();
+            last_tick = Instant::now();
+        }
+
         // Draw UI
         terminal.draw(|frame| ui::draw(frame, app))?;
 
         // Auto-refresh markets periodically (add new markets, remove inactive ones)
-        if last_market_refresh.elapsed() >= MARKET_REFRESH_INTERVAL {
+        if last_market_refresh.elapsed() >= app.config.market_refresh_interval {
             app.refresh_markets();
             last_market_refresh = Instant::now();
         }
@@ -90,6 +94,45 @@ fn run_app<B: ratatui::backend::Backend>(
             if let Event::Key(key) = event::read()? {
                 // Only handle key press events (not release)
                 if key.kind == KeyEventKind::Press {
+                    // Manual order prompt captures all input while open
+                    if let Some(prompt) = app.order_prompt.as_mut() {
+                        let action = match key.code {
+                            KeyCode::Esc => PromptAction::Cancelled,
+                            KeyCode::Enter => prompt.advance(),
+                            KeyCode::Backspace => {
+                                prompt.backspace();
+                                PromptAction::Pending
+                            }
+                            KeyCode::Tab => {
+                                prompt.toggle_side();
+                                PromptAction::Pending
+                            }
+                            KeyCode::Char('y') => prompt.answer(true),
+                            KeyCode::Char('n') => prompt.answer(false),
+                            KeyCode::Char(c) => {
+                                prompt.push_char(c);
+                                PromptAction::Pending
+                            }
+                            _ => PromptAction::Pending,
+                        };
+                        app.apply_prompt_action(action);
+                        continue;
+                    }
+
+                    // Sidebar search query captures input while being typed
+                    if app.search.editing {
+                        match key.code {
+                            KeyCode::Enter => app.commit_search(),
+                            KeyCode::Esc => app.clear_search(),
+                            KeyCode::Backspace => {
+                                app.search.query.pop();
+                            }
+                            KeyCode::Char(c) => app.search.query.push(c),
+                            _ => {}
+                        }
+                        continue;
+                    }
+
                     match key.code {
                         KeyCode::Char('q') => {
                             app.should_quit = true;
@@ -112,6 +155,36 @@ fn run_app<B: ratatui::backend::Backend>(
                             // Dump all inventory for selected market
                             app.dump_inventory();
                         }
+                        KeyCode::Char('/') => {
+                            // Start a sidebar search
+                            app.start_search();
+                        }
+                        KeyCode::Char('n') => {
+                            app.next_search_match();
+                        }
+                        KeyCode::Char('N') => {
+                            app.prev_search_match();
+                        }
+                        KeyCode::Esc => {
+                            // Clear search filter
+                            app.clear_search();
+                        }
+                        KeyCode::Char('h') => {
+                            // Toggle orderbook table/heatmap view
+                            app.toggle_orderbook_view();
+                        }
+                        KeyCode::Char('o') => {
+                            // Manual order on the UP token
+                            app.start_manual_order(true);
+                        }
+                        KeyCode::Char('O') => {
+                            // Manual order on the DOWN token
+                            app.start_manual_order(false);
+                        }
+                        KeyCode::Char('K') => {
+                            // Toggle kill switch for selected market
+                            app.toggle_market_halt();
+                        }
                         _ => {}
                     }
                 }
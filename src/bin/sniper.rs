@@ -9,18 +9,20 @@
 use anyhow::{bail, Result};
 use polymarket::application::{
     create_strategy, init_logging_with_level, BalanceManager, PositionManager,
-    Strategy, StrategyContext, StrategyType,
+    Strategy, StrategyContext, StrategyMetrics, StrategyType,
 };
 use polymarket::infrastructure::client::user::{
-    spawn_user_order_tracker, PositionTracker, PositionTrackerBridge,
+    spawn_user_order_tracker, PositionCallbacks, PositionTracker, PositionTrackerBridge,
 };
 use parking_lot::RwLock;
-use polymarket::infrastructure::client::clob::TradingClient;
+use polymarket::infrastructure::client::clob::{EndpointClass, TradingClient};
 use polymarket::infrastructure::config::StrategiesConfig;
 use polymarket::infrastructure::database::MarketDatabase;
 use polymarket::infrastructure::shutdown::ShutdownManager;
+use polymarket::infrastructure::MarketHalts;
 use polymarket_arb_bot::bin_common::{load_config_from_env, parse_args, ConfigType};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{error, info};
 
 #[tokio::main]
@@ -79,7 +81,13 @@ async fn main() -> Result<()> {
 
     // Initialize trading client (loads credentials from env)
     info!("Initializing trading client...");
-    let trading = Arc::new(TradingClient::from_env().await?);
+    let rate_limits = &config.components.rate_limits;
+    let trading = Arc::new(
+        TradingClient::from_env()
+            .await?
+            .with_rate_limiter(EndpointClass::Read, rate_limits.read_limiter())
+            .with_rate_limiter(EndpointClass::Order, rate_limits.order_limiter()),
+    );
     info!(
         "Trading client initialized: signer={:?}, maker={:?}",
         trading.signer_address(),
@@ -96,7 +104,16 @@ async fn main() -> Result<()> {
 
     // Initialize position tracker (receives fills from user WebSocket)
     info!("Initializing position tracker...");
-    let position_tracker = Arc::new(RwLock::new(PositionTracker::new()));
+    let metrics = Arc::new(StrategyMetrics::new());
+    // Fills feed the session metrics and the balance manager's own-flow, so
+    // the balance watcher only reports external deposits and withdrawals
+    let position_callbacks = PositionCallbacks(vec![
+        metrics.clone(),
+        Arc::new(balance_manager.read().own_flow()),
+    ]);
+    let position_tracker = Arc::new(RwLock::new(PositionTracker::with_callback(Arc::new(
+        position_callbacks,
+    ))));
     let bridge = Arc::new(PositionTrackerBridge::new(position_tracker.clone()));
 
     // Initialize order state with WebSocket tracker
@@ -118,6 +135,14 @@ async fn main() -> Result<()> {
     let mut position_manager = PositionManager::from_env()?;
     position_manager.start(shutdown.flag());
 
+    // Mirror kill switches set from other processes (e.g. the visualizer)
+    let market_halts = MarketHalts::new();
+    market_halts.spawn_db_sync(Arc::clone(&database), Duration::from_secs(2), shutdown.flag());
+
+    // Cancel anything the strategy left open, then close the pool
+    shutdown.register_hook(trading.clone());
+    shutdown.register_hook(database.clone());
+
     // Create strategy context
     let ctx = StrategyContext::new(
         database,
@@ -126,7 +151,9 @@ async fn main() -> Result<()> {
         balance_manager.clone(),
         order_state,
         position_tracker,
-    );
+    )
+    .with_metrics(metrics)
+    .with_market_halts(market_halts);
 
     // Run strategy lifecycle
     info!("Initializing strategy: {}", strategy.name());
@@ -146,12 +173,18 @@ async fn main() -> Result<()> {
         error!("Strategy stop failed: {}", e);
     }
 
+    if let Err(e) = strategy.shutdown(&ctx.metrics).await {
+        error!("Strategy shutdown failed: {}", e);
+    }
+
     // Stop balance manager
     balance_manager.write().stop().await;
 
     // Stop position manager
     position_manager.stop().await;
 
+    shutdown.run_hooks().await;
+
     print_shutdown(strategy.name());
     Ok(())
 }
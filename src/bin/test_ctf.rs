@@ -18,7 +18,7 @@ use anyhow::Result;
 use ethers::prelude::*;
 use polymarket::infrastructure::{
     split_via_safe, merge_via_safe, usdc_to_raw, usdc_from_raw,
-    CtfClient,
+    CtfClient, DEFAULT_MIN_CONFIRMATIONS,
 };
 use polymarket::infrastructure::client::data::DataApiClient;
 use std::env;
@@ -190,6 +190,7 @@ async fn do_split(condition_id: &str, amount: f64, neg_risk: bool) -> Result<()>
         raw_amount,
         &wallet,
         POLYGON_RPC_URL,
+        DEFAULT_MIN_CONFIRMATIONS,
     ).await {
         Ok(tx_hash) => {
             println!("Split successful!");
@@ -228,6 +229,7 @@ async fn do_merge(condition_id: &str, amount: f64, neg_risk: bool) -> Result<()>
         raw_amount,
         &wallet,
         POLYGON_RPC_URL,
+        DEFAULT_MIN_CONFIRMATIONS,
     ).await {
         Ok(tx_hash) => {
             println!("Merge successful!");
@@ -190,6 +190,9 @@ async fn main() -> Result<()> {
     println!("════════════════════════════════════════════════════════════════");
     println!();
 
+    // Orders must carry the maker's current exchange nonce
+    let nonce = rest_client.get_nonce(&auth, wallet_addr).await?;
+
     // Place the order
     let result = rest_client
         .place_signed_order(
@@ -201,6 +204,7 @@ async fn main() -> Result<()> {
             side,
             order_type,
             None, // default fee rate
+            nonce,
         )
         .await;
 